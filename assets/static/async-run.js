@@ -0,0 +1,23 @@
+function formatAsyncTimer(ms) {
+    const totalSeconds = Math.floor(ms / 1000);
+    const hours = Math.floor(totalSeconds / 3600);
+    const minutes = Math.floor((totalSeconds % 3600) / 60);
+    const seconds = totalSeconds % 60;
+    return `${hours}:${String(minutes).padStart(2, '0')}:${String(seconds).padStart(2, '0')}`;
+}
+
+document.addEventListener('DOMContentLoaded', function() {
+    document.querySelectorAll('.async-timer[data-start]').forEach(function(timer) {
+        const start = parseInt(timer.getAttribute('data-start'), 10);
+        function update() {
+            const now = Date.now();
+            if (now < start) {
+                timer.textContent = `Starting in ${Math.ceil((start - now) / 1000)}…`;
+            } else {
+                timer.textContent = `Elapsed: ${formatAsyncTimer(now - start)}`;
+            }
+        }
+        update();
+        setInterval(update, 250);
+    });
+});
@@ -0,0 +1,385 @@
+// Structured editor for the enter flow on the event setup page.
+// The container must carry:
+//   data-textarea      — id of the textarea holding the enter flow JSON, which is what gets submitted
+//   data-preview-path  — POST endpoint rendering a preview of the signup form
+//
+// The textarea stays editable and is kept in sync with the editor in both directions, so the JSON can still be edited directly.
+
+// Field kinds:
+//   text / optText  — single-line string (optional ones are omitted when empty)
+//   html / optHtml  — multi-line string which may contain HTML
+//   bool / boolTrue — checkbox (boolTrue defaults to checked)
+//   int / optInt    — number
+//   lines           — list of strings, one per line
+//   options         — dropdown options, one “value | label” per line
+//   json            — arbitrary JSON value
+var ENTER_FLOW_REQUIREMENTS = {
+    raceTime: { label: 'racetime.gg account', fields: [] },
+    raceTimeInvite: { label: 'Invited racetime.gg users', fields: [
+        { name: 'invites', kind: 'lines', label: 'racetime.gg user IDs (one per line)' },
+        { name: 'text', kind: 'optHtml', label: 'Text' },
+        { name: 'errorText', kind: 'optText', label: 'Error text' },
+    ] },
+    twitch: { label: 'Twitch account', fields: [] },
+    discord: { label: 'Discord account', fields: [] },
+    discordGuild: { label: 'Discord server membership', fields: [
+        { name: 'name', kind: 'text', label: 'Server name' },
+        { name: 'roleId', kind: 'optText', label: 'Required role ID', placeholder: 'e.g. 123456789012345678' },
+    ] },
+    challonge: { label: 'Challonge account', fields: [] },
+    startGG: { label: 'start.gg account', fields: [
+        { name: 'optional', kind: 'bool', label: 'Optional' },
+    ] },
+    startGGEventSignup: { label: 'start.gg event signup', fields: [
+        { name: 'eventSlug', kind: 'text', label: 'Event slug' },
+        { name: 'text', kind: 'optHtml', label: 'Text' },
+        { name: 'errorText', kind: 'optText', label: 'Error text' },
+    ] },
+    textField: { label: 'Text field', fields: [
+        { name: 'label', kind: 'html', label: 'Label' },
+        { name: 'long', kind: 'bool', label: 'Multi-line' },
+        { name: 'regex', kind: 'text', label: 'Regex the answer must match', placeholder: '.*' },
+        { name: 'regexErrorMessages', kind: 'json', label: 'Error messages by regex (JSON object)', empty: {} },
+        { name: 'fallbackErrorMessage', kind: 'text', label: 'Fallback error message' },
+    ] },
+    textField2: { label: 'Second text field', fields: [
+        { name: 'label', kind: 'html', label: 'Label' },
+        { name: 'long', kind: 'bool', label: 'Multi-line' },
+        { name: 'regex', kind: 'text', label: 'Regex the answer must match', placeholder: '.*' },
+        { name: 'regexErrorMessages', kind: 'json', label: 'Error messages by regex (JSON object)', empty: {} },
+        { name: 'fallbackErrorMessage', kind: 'text', label: 'Fallback error message' },
+    ] },
+    yesNo: { label: 'Yes/no question', fields: [
+        { name: 'label', kind: 'html', label: 'Question' },
+    ] },
+    booleanChoice: { label: 'Yes/no choice', fields: [
+        { name: 'key', kind: 'text', label: 'Key' },
+        { name: 'label', kind: 'html', label: 'Label' },
+        { name: 'prompt', kind: 'optHtml', label: 'Prompt' },
+        { name: 'locked', kind: 'bool', label: 'Locked after signup' },
+    ] },
+    radioChoice: { label: 'Never/random/always choice', fields: [
+        { name: 'key', kind: 'text', label: 'Key' },
+        { name: 'label', kind: 'html', label: 'Label' },
+        { name: 'prompt', kind: 'optHtml', label: 'Prompt' },
+        { name: 'locked', kind: 'bool', label: 'Locked after signup' },
+    ] },
+    dropdown: { label: 'Dropdown', fields: [
+        { name: 'key', kind: 'text', label: 'Key' },
+        { name: 'label', kind: 'html', label: 'Label' },
+        { name: 'prompt', kind: 'optHtml', label: 'Prompt' },
+        { name: 'options', kind: 'options', label: 'Options (one “value | label” per line)' },
+        { name: 'multiple', kind: 'bool', label: 'Allow selecting multiple options' },
+    ] },
+    rules: { label: 'Rules agreement', fields: [
+        { name: 'document', kind: 'optText', label: 'Rules document URL' },
+    ] },
+    rulesChecklist: { label: 'Rules checklist', fields: [
+        { name: 'items', kind: 'lines', label: 'Items (one per line)' },
+        { name: 'document', kind: 'optText', label: 'Rules document URL' },
+    ] },
+    poll: { label: 'Poll', fields: [
+        { name: 'document', kind: 'optText', label: 'Poll URL' },
+    ] },
+    restreamConsent: { label: 'Restream consent', fields: [
+        { name: 'optional', kind: 'bool', label: 'Optional' },
+        { name: 'note', kind: 'optHtml', label: 'Note' },
+    ] },
+    qualifier: { label: 'Qualifier', fields: [
+        { name: 'asyncStart', kind: 'text', label: 'Async start', placeholder: '2024-01-15T18:00:00Z' },
+        { name: 'asyncEnd', kind: 'text', label: 'Async end', placeholder: '2024-01-15T18:00:00Z' },
+        { name: 'liveStart', kind: 'text', label: 'Live start', placeholder: '2024-01-15T18:00:00Z' },
+    ] },
+    tripleQualifier: { label: 'Triple qualifier', fields: [
+        { name: 'asyncStarts', kind: 'lines', label: 'Async starts (3 lines)' },
+        { name: 'asyncEnds', kind: 'lines', label: 'Async ends (3 lines)' },
+        { name: 'liveStarts', kind: 'lines', label: 'Live starts (3 lines)' },
+    ] },
+    qualifierPlacement: { label: 'Qualifier placement', fields: [
+        { name: 'numPlayers', kind: 'int', label: 'Number of players who qualify' },
+        { name: 'minRaces', kind: 'int', label: 'Minimum races' },
+        { name: 'needFinish', kind: 'bool', label: 'Only finished races count' },
+        { name: 'event', kind: 'optText', label: 'Qualifier event (if different)' },
+        { name: 'excludePlayers', kind: 'int', label: 'Exclude top n players' },
+    ] },
+    rslLeaderboard: { label: 'RSL leaderboard', fields: [] },
+    raceTimeFinishes: { label: 'Finished racetime.gg races', fields: [
+        { name: 'minRaces', kind: 'int', label: 'Minimum finished races' },
+        { name: 'category', kind: 'optText', label: 'Category (defaults to the game’s category)' },
+    ] },
+    accountAge: { label: 'Account age', fields: [
+        { name: 'discordDays', kind: 'optInt', label: 'Minimum Discord account age (days)' },
+        { name: 'racetimeDays', kind: 'optInt', label: 'Minimum racetime.gg account age (days)' },
+    ] },
+    external: { label: 'External requirement', fields: [
+        { name: 'html', kind: 'optHtml', label: 'Description (HTML)' },
+        { name: 'text', kind: 'optText', label: 'Description (plain text)' },
+        { name: 'blocksSubmit', kind: 'boolTrue', label: 'Blocks submitting the form' },
+    ] },
+};
+
+// Discord IDs don't fit into a JavaScript number, so they're kept as strings while editing.
+function enterFlowParse(json) {
+    return JSON.parse(json.replace(/"roleId"\s*:\s*(\d+)/g, '"roleId": "$1"'));
+}
+
+function enterFlowStringify(flow) {
+    return JSON.stringify(flow, null, 2).replace(/"roleId": "(\d+)"/g, '"roleId": $1');
+}
+
+function enterFlowEscape(text) {
+    var div = document.createElement('div');
+    div.textContent = text;
+    return div.innerHTML.replace(/"/g, '&quot;');
+}
+
+function enterFlowFieldValue(field, value) {
+    switch (field.kind) {
+        case 'bool': return !!value;
+        case 'boolTrue': return value !== false;
+        case 'int': case 'optInt': return value === undefined || value === null ? '' : String(value);
+        case 'lines': return (value || []).join('\n');
+        case 'options': return (value || []).map(function(option) { return option.value + ' | ' + option.label; }).join('\n');
+        case 'json': return JSON.stringify(value === undefined ? field.empty : value, null, 2);
+        default: return value === undefined || value === null ? '' : String(value);
+    }
+}
+
+// Returns the value to store for a field, or undefined to omit it. Invalid input also returns undefined and is marked using the input's custom validity.
+function enterFlowReadField(field, input) {
+    switch (field.kind) {
+        case 'bool': return input.checked || undefined;
+        case 'boolTrue': return input.checked ? undefined : false;
+        case 'int': case 'optInt':
+            input.setCustomValidity('');
+            // number inputs report non-numeric input as an empty value
+            if (input.validity.badInput) return undefined;
+            if (input.value.trim() === '') return field.kind === 'int' ? 0 : undefined;
+            var number = Number(input.value);
+            if (!Number.isInteger(number) || number < 0) {
+                input.setCustomValidity('Must be a whole number.');
+                return undefined;
+            }
+            return number;
+        case 'lines': return input.value.split('\n').map(function(line) { return line.trim(); }).filter(Boolean);
+        case 'options': return input.value.split('\n').map(function(line) { return line.trim(); }).filter(Boolean).map(function(line) {
+            var sep = line.indexOf('|');
+            if (sep < 0) return { value: line, label: line };
+            return { value: line.slice(0, sep).trim(), label: line.slice(sep + 1).trim() };
+        });
+        case 'json':
+            try {
+                input.setCustomValidity('');
+                return JSON.parse(input.value);
+            } catch (e) {
+                input.setCustomValidity(e.message);
+                return undefined;
+            }
+        case 'optText': case 'optHtml': return input.value.trim() === '' ? undefined : input.value;
+        default: return input.value;
+    }
+}
+
+function EnterFlowEditor(container) {
+    this.container = container;
+    this.textarea = document.getElementById(container.getAttribute('data-textarea'));
+    this.previewPath = container.getAttribute('data-preview-path');
+    this.preview = document.getElementById('enter-flow-preview');
+    this.form = this.textarea.form;
+    this.previewTimeout = null;
+    this.flow = null;
+    var editor = this;
+    this.textarea.addEventListener('input', function() {
+        editor.loadFromTextarea();
+        editor.schedulePreview();
+    });
+    this.loadFromTextarea();
+    this.updatePreview();
+}
+
+EnterFlowEditor.prototype.loadFromTextarea = function() {
+    var json = this.textarea.value.trim();
+    if (json === '') {
+        this.flow = { requirements: [] };
+    } else {
+        try {
+            this.flow = enterFlowParse(json);
+        } catch (e) {
+            this.flow = null;
+            this.container.innerHTML = '<p class="error">The JSON below is invalid, fix it to use the editor again: ' + enterFlowEscape(e.message) + '</p>';
+            return;
+        }
+        if (!this.flow || typeof this.flow !== 'object' || !Array.isArray(this.flow.requirements)) {
+            this.flow = null;
+            this.container.innerHTML = '<p class="error">The JSON below must be an object with a “requirements” list to use the editor.</p>';
+            return;
+        }
+    }
+    this.render();
+};
+
+EnterFlowEditor.prototype.save = function() {
+    // an empty list of requirements still opens signups, unlike an empty textarea
+    this.textarea.value = enterFlowStringify(this.flow);
+    this.schedulePreview();
+};
+
+EnterFlowEditor.prototype.render = function() {
+    var editor = this;
+    var html = '';
+    this.flow.requirements.forEach(function(requirement, idx) {
+        var kind = ENTER_FLOW_REQUIREMENTS[requirement.type];
+        html += '<fieldset class="enter-flow-requirement" data-idx="' + idx + '" style="max-width: 800px; margin-bottom: 10px;">';
+        html += '<legend>' + (idx + 1) + '. ' + enterFlowEscape(kind ? kind.label : String(requirement.type)) + '</legend>';
+        if (kind) {
+            kind.fields.forEach(function(field) {
+                var id = 'enter-flow-' + idx + '-' + field.name;
+                var value = enterFlowFieldValue(field, requirement[field.name]);
+                var placeholder = field.placeholder ? ' placeholder="' + enterFlowEscape(field.placeholder) + '"' : '';
+                html += '<div>';
+                switch (field.kind) {
+                    case 'bool': case 'boolTrue':
+                        html += '<input type="checkbox" id="' + id + '" data-field="' + field.name + '"' + (value ? ' checked' : '') + '> <label for="' + id + '">' + enterFlowEscape(field.label) + '</label>';
+                        break;
+                    case 'int': case 'optInt':
+                        html += '<label for="' + id + '">' + enterFlowEscape(field.label) + '</label> <input type="number" min="0" id="' + id + '" data-field="' + field.name + '" value="' + enterFlowEscape(value) + '">';
+                        break;
+                    case 'text': case 'optText':
+                        html += '<label for="' + id + '">' + enterFlowEscape(field.label) + '</label> <input type="text" id="' + id + '" data-field="' + field.name + '" value="' + enterFlowEscape(value) + '"' + placeholder + ' style="width: 100%;">';
+                        break;
+                    default:
+                        html += '<label for="' + id + '">' + enterFlowEscape(field.label) + '</label><textarea id="' + id + '" data-field="' + field.name + '" rows="3" style="width: 100%;' + (field.kind === 'json' ? ' font-family: monospace;' : '') + '">' + enterFlowEscape(value) + '</textarea>';
+                }
+                html += '</div>';
+            });
+        } else {
+            html += '<p class="help">This requirement type isn’t supported by the editor, edit it as JSON.</p>';
+            html += '<textarea data-raw="true" rows="5" style="width: 100%; font-family: monospace;">' + enterFlowEscape(JSON.stringify(requirement, null, 2)) + '</textarea>';
+        }
+        html += '<div style="display: flex; gap: 8px; margin-top: 5px;">';
+        html += '<button type="button" class="button" data-action="up"' + (idx === 0 ? ' disabled' : '') + '>Move up</button>';
+        html += '<button type="button" class="button" data-action="down"' + (idx === editor.flow.requirements.length - 1 ? ' disabled' : '') + '>Move down</button>';
+        html += '<button type="button" class="button" data-action="remove">Remove</button>';
+        html += '</div></fieldset>';
+    });
+    html += '<div style="display: flex; gap: 8px; margin-bottom: 10px;"><select class="enter-flow-add-type">';
+    Object.keys(ENTER_FLOW_REQUIREMENTS).forEach(function(type) {
+        html += '<option value="' + type + '">' + enterFlowEscape(ENTER_FLOW_REQUIREMENTS[type].label) + '</option>';
+    });
+    html += '</select><button type="button" class="button" data-action="add">Add requirement</button></div>';
+    html += '<div><label for="enter-flow-closes">Signups close</label> <input type="text" id="enter-flow-closes" class="enter-flow-closes" placeholder="2024-01-15T23:59:59Z" value="' + enterFlowEscape(this.flow.closes || '') + '"></div>';
+    this.container.innerHTML = html;
+
+    this.container.querySelectorAll('.enter-flow-requirement').forEach(function(fieldset) {
+        var idx = parseInt(fieldset.getAttribute('data-idx'), 10);
+        fieldset.querySelectorAll('[data-field]').forEach(function(input) {
+            input.addEventListener(input.type === 'checkbox' ? 'change' : 'input', function() {
+                editor.updateField(idx, input);
+            });
+        });
+        var raw = fieldset.querySelector('[data-raw]');
+        if (raw) {
+            raw.addEventListener('input', function() {
+                try {
+                    raw.setCustomValidity('');
+                    editor.flow.requirements[idx] = JSON.parse(raw.value);
+                    editor.save();
+                } catch (e) {
+                    raw.setCustomValidity(e.message);
+                }
+            });
+        }
+        fieldset.querySelectorAll('button[data-action]').forEach(function(button) {
+            button.addEventListener('click', function() {
+                editor.move(idx, button.getAttribute('data-action'));
+            });
+        });
+    });
+    this.container.querySelector('button[data-action="add"]').addEventListener('click', function() {
+        editor.add(editor.container.querySelector('.enter-flow-add-type').value);
+    });
+    this.container.querySelector('.enter-flow-closes').addEventListener('input', function(event) {
+        var closes = event.target.value.trim();
+        if (closes === '') {
+            delete editor.flow.closes;
+        } else {
+            editor.flow.closes = closes;
+        }
+        editor.save();
+    });
+};
+
+EnterFlowEditor.prototype.updateField = function(idx, input) {
+    var requirement = this.flow.requirements[idx];
+    var field = ENTER_FLOW_REQUIREMENTS[requirement.type].fields.find(function(field) { return field.name === input.getAttribute('data-field'); });
+    var value = enterFlowReadField(field, input);
+    if (value === undefined) {
+        if (!input.checkValidity()) return; // keep the last valid value until the input is fixed
+        delete requirement[field.name];
+    } else {
+        requirement[field.name] = value;
+    }
+    this.save();
+};
+
+EnterFlowEditor.prototype.move = function(idx, action) {
+    var requirements = this.flow.requirements;
+    if (action === 'up' && idx > 0) {
+        requirements.splice(idx - 1, 0, requirements.splice(idx, 1)[0]);
+    } else if (action === 'down' && idx < requirements.length - 1) {
+        requirements.splice(idx + 1, 0, requirements.splice(idx, 1)[0]);
+    } else if (action === 'remove') {
+        requirements.splice(idx, 1);
+    }
+    this.render();
+    this.save();
+};
+
+EnterFlowEditor.prototype.add = function(type) {
+    var requirement = { type: type };
+    ENTER_FLOW_REQUIREMENTS[type].fields.forEach(function(field) {
+        switch (field.kind) {
+            case 'text': case 'html': requirement[field.name] = ''; break;
+            case 'int': requirement[field.name] = 0; break;
+            case 'lines': case 'options': requirement[field.name] = []; break;
+            case 'json': requirement[field.name] = field.empty; break;
+        }
+    });
+    if (type === 'textField' || type === 'textField2') requirement.regex = '.*';
+    this.flow.requirements.push(requirement);
+    this.render();
+    this.save();
+};
+
+EnterFlowEditor.prototype.schedulePreview = function() {
+    var editor = this;
+    clearTimeout(this.previewTimeout);
+    this.previewTimeout = setTimeout(function() { editor.updatePreview(); }, 500);
+};
+
+EnterFlowEditor.prototype.updatePreview = function() {
+    if (!this.preview) return;
+    var editor = this;
+    var formData = new FormData();
+    var csrf = this.form ? this.form.querySelector('input[name="csrf"]') : null;
+    formData.append('csrf', csrf ? csrf.value : '');
+    formData.append('enter_flow_json', this.textarea.value);
+    var json = this.textarea.value;
+    fetch(this.previewPath, { method: 'POST', body: formData })
+        .then(function(response) {
+            if (!response.ok) throw new Error(response.statusText);
+            return response.text();
+        })
+        .then(function(html) {
+            // ignore responses for outdated JSON
+            if (editor.textarea.value === json) editor.preview.innerHTML = html;
+        })
+        .catch(function() {
+            editor.preview.innerHTML = '<p class="error">Failed to load the preview.</p>';
+        });
+};
+
+document.addEventListener('DOMContentLoaded', function() {
+    var container = document.getElementById('enter-flow-editor');
+    if (container) new EnterFlowEditor(container);
+});
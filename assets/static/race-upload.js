@@ -0,0 +1,14 @@
+document.addEventListener('DOMContentLoaded', function() {
+    var fileInput = document.getElementById('race-upload-file');
+    var table = document.getElementById('race-upload-table');
+    if (!fileInput || !table) return;
+    fileInput.addEventListener('change', function() {
+        var file = fileInput.files[0];
+        if (!file) return;
+        var reader = new FileReader();
+        reader.onload = function() {
+            table.value = reader.result;
+        };
+        reader.readAsText(file);
+    });
+});
@@ -0,0 +1 @@
+ALTER TABLE events ADD COLUMN discord_standings_channel BIGINT;
@@ -0,0 +1,30 @@
+CREATE TYPE public.race_change_source AS ENUM (
+    'web',
+    'discord',
+    'api'
+);
+
+CREATE TABLE public.race_audit_log (
+    id          bigserial PRIMARY KEY,
+    race        bigint NOT NULL REFERENCES public.races(id) ON DELETE CASCADE,
+    actor       bigint REFERENCES public.users(id),
+    source      public.race_change_source NOT NULL,
+    field       text NOT NULL,
+    old_value   text,
+    new_value   text,
+    created_at  timestamptz NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX race_audit_log_race_idx ON public.race_audit_log (race, created_at);
+
+CREATE TABLE public.race_walkovers (
+    race         bigint PRIMARY KEY REFERENCES public.races(id) ON DELETE CASCADE,
+    winner       bigint NOT NULL REFERENCES public.teams(id),
+    reason       text,
+    recorded_by  bigint NOT NULL REFERENCES public.users(id),
+    recorded_at  timestamptz NOT NULL DEFAULT NOW()
+);
+
+ALTER TYPE public.race_change_source OWNER TO mido;
+ALTER TABLE public.race_audit_log OWNER TO mido;
+ALTER TABLE public.race_walkovers OWNER TO mido;
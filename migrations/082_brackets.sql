@@ -0,0 +1,63 @@
+CREATE TYPE public.bracket_kind AS ENUM (
+    'single_elimination',
+    'double_elimination'
+);
+
+CREATE TYPE public.bracket_side AS ENUM (
+    'winners',
+    'losers',
+    'grand_final'
+);
+
+ALTER TABLE public.events ADD COLUMN native_bracket boolean NOT NULL DEFAULT FALSE;
+
+CREATE TABLE public.brackets (
+    id                 bigint PRIMARY KEY,
+    series             character varying(8) NOT NULL,
+    event              character varying(8) NOT NULL,
+    kind               public.bracket_kind NOT NULL,
+    grand_final_reset  boolean NOT NULL DEFAULT TRUE,
+    phase              text,
+    created_at         timestamptz NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (series, event) REFERENCES public.events(series, event) ON DELETE CASCADE
+);
+
+CREATE TABLE public.bracket_seeds (
+    bracket  bigint NOT NULL REFERENCES public.brackets(id) ON DELETE CASCADE,
+    seed     smallint NOT NULL,
+    team     bigint NOT NULL REFERENCES public.teams(id) ON DELETE CASCADE,
+    PRIMARY KEY (bracket, seed),
+    UNIQUE (bracket, team)
+);
+
+CREATE TABLE public.bracket_matches (
+    id           bigserial PRIMARY KEY,
+    bracket      bigint NOT NULL REFERENCES public.brackets(id) ON DELETE CASCADE,
+    side         public.bracket_side NOT NULL,
+    round        smallint NOT NULL,
+    position     smallint NOT NULL,
+    best_of      smallint NOT NULL DEFAULT 1,
+    -- slot states: NULL team with bye = FALSE means still pending
+    team1        bigint REFERENCES public.teams(id) ON DELETE SET NULL,
+    team1_bye    boolean NOT NULL DEFAULT FALSE,
+    team2        bigint REFERENCES public.teams(id) ON DELETE SET NULL,
+    team2_bye    boolean NOT NULL DEFAULT FALSE,
+    winner_slot  smallint,
+    skipped      boolean NOT NULL DEFAULT FALSE,
+    UNIQUE (bracket, side, round, position)
+);
+
+CREATE TABLE public.bracket_match_races (
+    race           bigint PRIMARY KEY REFERENCES public.races(id) ON DELETE CASCADE,
+    bracket_match  bigint NOT NULL REFERENCES public.bracket_matches(id) ON DELETE CASCADE,
+    winner         bigint REFERENCES public.teams(id) ON DELETE SET NULL
+);
+
+CREATE INDEX bracket_match_races_bracket_match_idx ON public.bracket_match_races (bracket_match);
+
+ALTER TYPE public.bracket_kind OWNER TO mido;
+ALTER TYPE public.bracket_side OWNER TO mido;
+ALTER TABLE public.brackets OWNER TO mido;
+ALTER TABLE public.bracket_seeds OWNER TO mido;
+ALTER TABLE public.bracket_matches OWNER TO mido;
+ALTER TABLE public.bracket_match_races OWNER TO mido;
@@ -0,0 +1,10 @@
+ALTER TYPE public.bracket_kind ADD VALUE 'swiss';
+ALTER TYPE public.bracket_kind ADD VALUE 'round_robin';
+ALTER TYPE public.bracket_side ADD VALUE 'pool';
+
+-- number of rounds for Swiss brackets
+ALTER TABLE public.brackets ADD COLUMN rounds smallint;
+
+ALTER TABLE public.bracket_matches ADD COLUMN pool smallint NOT NULL DEFAULT 0;
+ALTER TABLE public.bracket_matches DROP CONSTRAINT bracket_matches_bracket_side_round_position_key;
+ALTER TABLE public.bracket_matches ADD CONSTRAINT bracket_matches_bracket_side_pool_round_position_key UNIQUE (bracket, side, pool, round, position);
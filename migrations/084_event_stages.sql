@@ -0,0 +1,29 @@
+CREATE TYPE public.stage_kind AS ENUM (
+    'qualifier',
+    'bracket'
+);
+
+CREATE TABLE public.event_stages (
+    series             character varying(8) NOT NULL,
+    event              character varying(8) NOT NULL,
+    stage              smallint NOT NULL,
+    name               text NOT NULL,
+    kind               public.stage_kind NOT NULL,
+    -- bracket settings, only used for bracket stages
+    bracket_kind       public.bracket_kind,
+    grand_final_reset  boolean NOT NULL DEFAULT TRUE,
+    rounds             smallint,
+    pools              smallint NOT NULL DEFAULT 1,
+    best_of            smallint NOT NULL DEFAULT 1,
+    -- advancement from the previous stage into this one, NULL means everyone advances
+    advance_count      smallint,
+    advance_per_pool   boolean NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (series, event, stage),
+    FOREIGN KEY (series, event) REFERENCES public.events(series, event) ON DELETE CASCADE
+);
+
+ALTER TABLE public.brackets ADD COLUMN stage smallint;
+ALTER TABLE public.brackets ADD CONSTRAINT brackets_series_event_stage_key UNIQUE (series, event, stage);
+
+ALTER TYPE public.stage_kind OWNER TO mido;
+ALTER TABLE public.event_stages OWNER TO mido;
@@ -0,0 +1,3 @@
+ALTER TABLE public.races ADD COLUMN p3_discord bigint;
+ALTER TABLE public.races ADD COLUMN p3_racetime text;
+ALTER TABLE public.races ADD COLUMN p3_twitch text;
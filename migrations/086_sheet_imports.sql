@@ -0,0 +1,20 @@
+CREATE TABLE public.sheet_imports (
+    series           character varying(8) NOT NULL,
+    event            character varying(8) NOT NULL,
+    sheet_id         text NOT NULL,
+    sheet_range      text NOT NULL,
+    -- columns are given in A1 notation, e.g. 'B' or 'AA'
+    key_column       text,
+    entrant_columns  text[] NOT NULL,
+    start_column     text,
+    start_format     text NOT NULL DEFAULT '%Y-%m-%d %H:%M',
+    timezone         text NOT NULL DEFAULT 'UTC',
+    round_column     text,
+    restream_column  text,
+    PRIMARY KEY (series, event),
+    FOREIGN KEY (series, event) REFERENCES public.events(series, event) ON DELETE CASCADE
+);
+
+ALTER TABLE public.races ADD COLUMN sheet_key text;
+
+ALTER TABLE public.sheet_imports OWNER TO mido;
@@ -0,0 +1,14 @@
+CREATE TABLE public.ics_imports (
+    series               character varying(8) NOT NULL,
+    event                character varying(8) NOT NULL,
+    url                  text NOT NULL,
+    -- regular expressions with named groups entrant1, entrant2, entrant3, round, and restream
+    summary_pattern      text NOT NULL,
+    description_pattern  text,
+    PRIMARY KEY (series, event),
+    FOREIGN KEY (series, event) REFERENCES public.events(series, event) ON DELETE CASCADE
+);
+
+ALTER TABLE public.races ADD COLUMN ics_uid text;
+
+ALTER TABLE public.ics_imports OWNER TO mido;
@@ -0,0 +1,65 @@
+ALTER TYPE public.race_change_source ADD VALUE 'import';
+ALTER TYPE public.race_change_source ADD VALUE 'race_bot';
+ALTER TYPE public.race_change_source ADD VALUE 'system';
+
+-- all changes to a race made in the same transaction share a version number
+ALTER TABLE public.race_audit_log ADD COLUMN version integer;
+
+UPDATE public.race_audit_log SET version = numbered.version FROM (
+    SELECT id, dense_rank() OVER (PARTITION BY race ORDER BY created_at) AS version FROM public.race_audit_log
+) AS numbered WHERE race_audit_log.id = numbered.id;
+
+ALTER TABLE public.race_audit_log ALTER COLUMN version SET NOT NULL;
+
+CREATE FUNCTION public.race_change_version(race_id bigint) RETURNS integer
+    LANGUAGE sql
+    AS $$
+    SELECT COALESCE(
+        (SELECT MAX(version) FROM public.race_audit_log WHERE race = race_id AND created_at = NOW()),
+        (SELECT MAX(version) + 1 FROM public.race_audit_log WHERE race = race_id),
+        1
+    )
+$$;
+
+-- The actor and source of a change are read from the transaction-local settings midos_house.change_actor and midos_house.change_source.
+-- Changes made without setting these are attributed to the system.
+CREATE FUNCTION public.log_race_change() RETURNS trigger
+    LANGUAGE plpgsql
+    AS $$
+DECLARE
+    change_actor bigint := NULLIF(current_setting('midos_house.change_actor', TRUE), '')::bigint;
+    change_source public.race_change_source := COALESCE(NULLIF(current_setting('midos_house.change_source', TRUE), ''), 'system')::public.race_change_source;
+    change_version integer := public.race_change_version(NEW.id);
+    old_row jsonb;
+    new_row jsonb := to_jsonb(NEW);
+    tracked_field text;
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO public.race_audit_log (race, actor, source, field, version) VALUES (NEW.id, change_actor, change_source, 'created', change_version);
+        RETURN NULL;
+    END IF;
+    old_row := to_jsonb(OLD);
+    FOREACH tracked_field IN ARRAY TG_ARGV LOOP
+        IF old_row -> tracked_field IS DISTINCT FROM new_row -> tracked_field THEN
+            INSERT INTO public.race_audit_log (race, actor, source, field, old_value, new_value, version)
+            VALUES (NEW.id, change_actor, change_source, tracked_field, old_row ->> tracked_field, new_row ->> tracked_field, change_version);
+        END IF;
+    END LOOP;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER log_race_change AFTER INSERT OR UPDATE ON public.races FOR EACH ROW EXECUTE FUNCTION public.log_race_change(
+    'start', 'async_start1', 'async_start2', 'async_start3',
+    'end_time', 'async_end1', 'async_end2', 'async_end3',
+    'room', 'async_room1', 'async_room2', 'async_room3',
+    'team1', 'team2', 'team3', 'p1', 'p2', 'p3',
+    'p1_discord', 'p2_discord', 'p3_discord', 'p1_racetime', 'p2_racetime', 'p3_racetime', 'p1_twitch', 'p2_twitch', 'p3_twitch',
+    'total', 'finished',
+    'phase', 'round', 'game', 'custom_title',
+    'video_url', 'video_url_fr', 'video_url_de', 'video_url_pt', 'restreamer', 'restreamer_fr', 'restreamer_de', 'restreamer_pt',
+    'ignored', 'schedule_locked', 'scheduling_deadline'
+);
+
+ALTER FUNCTION public.race_change_version(bigint) OWNER TO mido;
+ALTER FUNCTION public.log_race_change() OWNER TO mido;
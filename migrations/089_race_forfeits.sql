@@ -0,0 +1,12 @@
+CREATE TYPE public.forfeit_kind AS ENUM (
+    'walkover',
+    'disqualification',
+    'double_forfeit'
+);
+
+ALTER TABLE public.race_walkovers ADD COLUMN kind public.forfeit_kind NOT NULL DEFAULT 'walkover';
+-- a double forfeit has no winner
+ALTER TABLE public.race_walkovers ALTER COLUMN winner DROP NOT NULL;
+ALTER TABLE public.race_walkovers ADD CONSTRAINT race_walkovers_winner_check CHECK ((kind = 'double_forfeit') = (winner IS NULL));
+
+ALTER TYPE public.forfeit_kind OWNER TO mido;
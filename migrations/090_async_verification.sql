@@ -0,0 +1,40 @@
+INSERT INTO public.role_types (name) VALUES ('Async Verification') ON CONFLICT (name) DO NOTHING;
+
+CREATE TYPE public.async_verification_status AS ENUM (
+    'pending',
+    'verified',
+    'flagged'
+);
+
+-- existing results predate verification and keep counting
+ALTER TABLE public.async_players ADD COLUMN verification_status public.async_verification_status NOT NULL DEFAULT 'verified';
+-- the time as originally submitted, kept when a verifier adjusts it
+ALTER TABLE public.async_players ADD COLUMN submitted_time interval;
+ALTER TABLE public.async_players ADD COLUMN verified_by bigint REFERENCES public.users(id);
+ALTER TABLE public.async_players ADD COLUMN verified_at timestamp with time zone;
+ALTER TABLE public.async_players ADD COLUMN verification_notes text;
+
+ALTER TABLE public.events ADD COLUMN async_verification boolean NOT NULL DEFAULT FALSE;
+
+-- Results are submitted from several places (Discord threads, slash commands, the web form).
+-- Rather than changing each of them, a time submitted for an event requiring verification is queued here.
+-- Only the first time is a submission: later changes (adjustments by verifiers or organizers) don't reset the review.
+CREATE FUNCTION public.queue_async_verification() RETURNS trigger
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    IF (TG_OP = 'INSERT' OR OLD.time IS NULL) AND NEW.time IS NOT NULL AND EXISTS (SELECT 1 FROM public.events WHERE series = NEW.series AND event = NEW.event AND async_verification) THEN
+        NEW.verification_status := 'pending';
+        NEW.submitted_time := NEW.time;
+        NEW.verified_by := NULL;
+        NEW.verified_at := NULL;
+        NEW.verification_notes := NULL;
+    END IF;
+    RETURN NEW;
+END
+$$;
+
+CREATE TRIGGER queue_async_verification BEFORE INSERT OR UPDATE OF time ON public.async_players FOR EACH ROW EXECUTE FUNCTION public.queue_async_verification();
+
+ALTER TYPE public.async_verification_status OWNER TO mido;
+ALTER FUNCTION public.queue_async_verification() OWNER TO mido;
@@ -0,0 +1,5 @@
+-- Runs of automated asyncs done on the website instead of in a Discord thread.
+-- No thread is created for these, but a team can still use a thread which was created before switching.
+ALTER TABLE public.async_teams ADD COLUMN web_run boolean NOT NULL DEFAULT FALSE;
+-- When the seed was first shown to the team, used for the force start deadline on the website.
+ALTER TABLE public.async_teams ADD COLUMN seed_revealed_at timestamp with time zone;
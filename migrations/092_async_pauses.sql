@@ -0,0 +1,31 @@
+CREATE TYPE public.async_pause_status AS ENUM (
+    'pending',
+    'approved',
+    'rejected'
+);
+
+-- Pauses logged by runners during an async (crash, emergency, etc.).
+-- Approved pauses are deducted from the finish time.
+-- Like AsyncRun, a pause belongs either to one part of a bracket async or to a team's qualifier async.
+CREATE TABLE public.async_pauses (
+    id bigserial PRIMARY KEY,
+    race_id bigint REFERENCES public.races(id) ON DELETE CASCADE,
+    async_part integer,
+    team bigint REFERENCES public.teams(id) ON DELETE CASCADE,
+    kind public.async_kind,
+    started_at timestamp with time zone NOT NULL DEFAULT NOW(),
+    ended_at timestamp with time zone,
+    reason text,
+    status public.async_pause_status NOT NULL DEFAULT 'pending',
+    reviewed_by bigint REFERENCES public.users(id),
+    reviewed_at timestamp with time zone,
+    CHECK ((race_id IS NOT NULL AND async_part IS NOT NULL AND team IS NULL AND kind IS NULL) OR (race_id IS NULL AND async_part IS NULL AND team IS NOT NULL AND kind IS NOT NULL)),
+    CHECK (ended_at IS NULL OR ended_at >= started_at)
+);
+
+-- at most one pause can be running at a time
+CREATE UNIQUE INDEX async_pauses_open_bracket ON public.async_pauses (race_id, async_part) WHERE ended_at IS NULL AND race_id IS NOT NULL;
+CREATE UNIQUE INDEX async_pauses_open_qualifier ON public.async_pauses (team, kind) WHERE ended_at IS NULL AND team IS NOT NULL;
+
+ALTER TYPE public.async_pause_status OWNER TO mido;
+ALTER TABLE public.async_pauses OWNER TO mido;
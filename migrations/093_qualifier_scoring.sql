@@ -0,0 +1,11 @@
+-- Scoring formula for points-based qualifiers (par calculation, point limits, which qualifiers count, etc.).
+-- NULL means qualifiers are not scored with points.
+ALTER TABLE public.events ADD COLUMN qualifier_scoring jsonb;
+
+-- events which previously had their formulas hardcoded
+UPDATE public.events SET qualifier_scoring = '{"par_size":7,"large_race_entrants":null,"large_race_par_size":7,"partial_par":true,"standard_adjustments":true,"points_at_par":1000.0,"bonus_points":0.0,"round_down":false,"min_points":100.0,"max_points":1100.0,"dnf_points":0.0,"required_qualifiers":5,"require_finishes":true,"max_counted":8,"list_uncounted":false,"finished_only":true,"drop_best_from":2,"drop_worst_from":null,"keep_best":4,"average":false,"count_pending_asyncs":false}'::jsonb WHERE series = 's' AND event = '8';
+UPDATE public.events SET qualifier_scoring = '{"par_size":3,"large_race_entrants":20,"large_race_par_size":4,"partial_par":false,"standard_adjustments":false,"points_at_par":100.0,"bonus_points":0.0,"round_down":false,"min_points":10.0,"max_points":110.0,"dnf_points":0.0,"required_qualifiers":3,"require_finishes":false,"max_counted":5,"list_uncounted":false,"finished_only":false,"drop_best_from":4,"drop_worst_from":5,"keep_best":null,"average":true,"count_pending_asyncs":false}'::jsonb WHERE series = 'sgl' AND event = '2023onl';
+UPDATE public.events SET qualifier_scoring = '{"par_size":3,"large_race_entrants":20,"large_race_par_size":4,"partial_par":false,"standard_adjustments":false,"points_at_par":100.0,"bonus_points":0.0,"round_down":false,"min_points":10.0,"max_points":110.0,"dnf_points":0.0,"required_qualifiers":3,"require_finishes":false,"max_counted":6,"list_uncounted":false,"finished_only":false,"drop_best_from":null,"drop_worst_from":4,"keep_best":null,"average":true,"count_pending_asyncs":false}'::jsonb WHERE series = 'sgl' AND event = '2024onl';
+UPDATE public.events SET qualifier_scoring = '{"par_size":3,"large_race_entrants":20,"large_race_par_size":4,"partial_par":false,"standard_adjustments":false,"points_at_par":100.0,"bonus_points":0.0,"round_down":false,"min_points":10.0,"max_points":110.0,"dnf_points":0.0,"required_qualifiers":3,"require_finishes":true,"max_counted":6,"list_uncounted":false,"finished_only":false,"drop_best_from":null,"drop_worst_from":4,"keep_best":null,"average":true,"count_pending_asyncs":false}'::jsonb WHERE series = 'sgl' AND event = '2025onl';
+UPDATE public.events SET qualifier_scoring = '{"par_size":3,"large_race_entrants":null,"large_race_par_size":3,"partial_par":false,"standard_adjustments":false,"points_at_par":1000.0,"bonus_points":0.0,"round_down":false,"min_points":100.0,"max_points":null,"dnf_points":0.0,"required_qualifiers":2,"require_finishes":true,"max_counted":4,"list_uncounted":true,"finished_only":true,"drop_best_from":null,"drop_worst_from":null,"keep_best":2,"average":false,"count_pending_asyncs":true}'::jsonb WHERE series = 'twwrmain' AND event = 's9';
+UPDATE public.events SET qualifier_scoring = '{"par_size":3,"large_race_entrants":null,"large_race_par_size":3,"partial_par":false,"standard_adjustments":false,"points_at_par":1000.0,"bonus_points":2000.0,"round_down":true,"min_points":100.0,"max_points":null,"dnf_points":0.0,"required_qualifiers":2,"require_finishes":true,"max_counted":4,"list_uncounted":true,"finished_only":true,"drop_best_from":null,"drop_worst_from":null,"keep_best":2,"average":false,"count_pending_asyncs":true}'::jsonb WHERE series = 'twwrmain' AND event = 'miniblins26';
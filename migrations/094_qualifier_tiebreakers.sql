@@ -0,0 +1,4 @@
+-- Ordered list of tiebreakers for entrants with the same qualifier score, e.g. ["best_score", "head_to_head"].
+ALTER TABLE public.events ADD COLUMN qualifier_tiebreakers jsonb NOT NULL DEFAULT '[]'::jsonb;
+-- Number of entrants who qualify, used for projecting the points needed in remaining qualifiers.
+ALTER TABLE public.events ADD COLUMN qualifier_cutoff integer CHECK (qualifier_cutoff > 0);
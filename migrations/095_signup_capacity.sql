@@ -0,0 +1,9 @@
+-- Maximum number of entrants. Further signups are added to a waitlist. NULL means no limit.
+ALTER TABLE public.events ADD COLUMN max_entrants integer CHECK (max_entrants > 0);
+-- How long a team promoted from the waitlist has to confirm its spot.
+ALTER TABLE public.events ADD COLUMN waitlist_confirm_hours integer NOT NULL DEFAULT 48 CHECK (waitlist_confirm_hours > 0);
+-- When the team joined the waitlist, used for ordering. NULL means the team is not waitlisted.
+ALTER TABLE public.teams ADD COLUMN waitlisted_at timestamp with time zone;
+-- Deadline for a team promoted from the waitlist to confirm its spot. NULL means the team doesn't need to confirm.
+ALTER TABLE public.teams ADD COLUMN waitlist_deadline timestamp with time zone;
+ALTER TYPE public.notification_kind ADD VALUE 'waitlist_promoted';
@@ -0,0 +1,40 @@
+-- Alternates registered by a team who can substitute for one of its members.
+CREATE TABLE public.team_alternates (
+    team bigint NOT NULL REFERENCES public.teams(id) ON DELETE CASCADE,
+    member bigint NOT NULL REFERENCES public.users(id) ON DELETE CASCADE,
+    added_at timestamp with time zone NOT NULL DEFAULT NOW(),
+    confirmed boolean NOT NULL DEFAULT FALSE, -- whether the alternate has accepted being added
+    PRIMARY KEY (team, member)
+);
+
+CREATE TYPE public.substitution_status AS ENUM (
+    'pending',
+    'approved',
+    'rejected'
+);
+
+-- Requests to replace a team member with one of the team's alternates, either for a single race or permanently.
+-- Approved race substitutions are applied when looking up the team's members for that race.
+-- Approved permanent substitutions are applied directly to team_members.
+CREATE TABLE public.team_substitutions (
+    id bigserial PRIMARY KEY,
+    team bigint NOT NULL REFERENCES public.teams(id) ON DELETE CASCADE,
+    race bigint REFERENCES public.races(id) ON DELETE CASCADE, -- NULL for permanent roster changes
+    member_out bigint NOT NULL REFERENCES public.users(id) ON DELETE CASCADE,
+    member_in bigint NOT NULL REFERENCES public.users(id) ON DELETE CASCADE,
+    requested_by bigint REFERENCES public.users(id) ON DELETE SET NULL, -- NULL if the user has been deleted
+    requested_at timestamp with time zone NOT NULL DEFAULT NOW(),
+    status public.substitution_status NOT NULL DEFAULT 'pending',
+    decided_by bigint REFERENCES public.users(id) ON DELETE SET NULL,
+    decided_at timestamp with time zone,
+    CHECK (member_out <> member_in)
+);
+
+-- a member can only be substituted once per race
+CREATE UNIQUE INDEX team_substitutions_race ON public.team_substitutions (team, race, member_out) WHERE race IS NOT NULL AND status <> 'rejected';
+-- and an alternate can only substitute for one member per race
+CREATE UNIQUE INDEX team_substitutions_race_member_in ON public.team_substitutions (team, race, member_in) WHERE race IS NOT NULL AND status <> 'rejected';
+
+ALTER TABLE public.team_alternates OWNER TO mido;
+ALTER TYPE public.substitution_status OWNER TO mido;
+ALTER TABLE public.team_substitutions OWNER TO mido;
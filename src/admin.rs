@@ -31,6 +31,8 @@ pub(crate) enum Error {
     #[error(transparent)]
     Page(#[from] PageError),
     #[error(transparent)]
+    RoleReconciler(#[from] crate::discord_role_manager::Error),
+    #[error(transparent)]
     Sql(#[from] sqlx::Error),
     #[error("unauthorized")]
     Unauthorized,
@@ -173,6 +175,11 @@ pub(crate) async fn index(
                 a(href = uri!(api_keys)) : "Manage API Keys";
             }
 
+            h2 : "Discord Roles";
+            p {
+                a(href = uri!(discord_roles)) : "Reconcile Discord Roles";
+            }
+
             script(src = static_url!("game-edit.js")) {}
         }
     };
@@ -1642,6 +1649,223 @@ pub(crate) async fn delete_restream_channel(
     Ok(Redirect::to(uri!(list_restream_channels)))
 }
 
+// ─── Discord Role Reconciliation ─────────────────────────────────────────────
+
+#[rocket::get("/admin/discord-roles")]
+pub(crate) async fn discord_roles(
+    pool: &State<PgPool>,
+    discord_ctx: &State<RwFuture<DiscordCtx>>,
+    me: Option<User>,
+    uri: Origin<'_>,
+) -> Result<RawHtml<String>, StatusOrError<Error>> {
+    let me = me.ok_or(Error::Unauthorized)?;
+    if !me.is_global_admin() {
+        return Err(Error::Unauthorized.into());
+    }
+
+    let guilds = sqlx::query_scalar!(
+        r#"SELECT discord_guild AS "discord_guild!: PgSnowflake<GuildId>" FROM events WHERE discord_guild IS NOT NULL AND (end_time IS NULL OR end_time > NOW())
+           UNION
+           SELECT discord_guild AS "discord_guild!: PgSnowflake<GuildId>" FROM games WHERE discord_guild IS NOT NULL"#
+    )
+    .fetch_all(pool.inner())
+    .await
+    .map_err(Error::from)?;
+    let discord_ctx = discord_ctx.read().await;
+    let guilds = guilds.into_iter()
+        .map(|PgSnowflake(guild_id)| (guild_id, guild_id.name(&*discord_ctx).unwrap_or_else(|| guild_id.to_string())))
+        .sorted_unstable_by(|(_, name1), (_, name2)| name1.cmp(name2))
+        .collect_vec();
+
+    let content = html! {
+        article {
+            h1 : "Discord Roles";
+            p : "Hyrule Town Hall can compare the roles of each member of a Discord server with its participants, team roles, role bindings, and event overrides, and fix any differences. Self-assigned pronoun, racing, and watch roles are never changed.";
+            @if guilds.is_empty() {
+                p : "No Discord servers are associated with ongoing events or games.";
+            } else {
+                ul {
+                    @for (guild_id, name) in &guilds {
+                        li {
+                            a(href = uri!(discord_roles_preview(guild_id.get(), false))) : name;
+                        }
+                    }
+                }
+            }
+
+            p {
+                a(href = uri!(index)) : "Back to Admin Panel";
+            }
+        }
+    };
+
+    Ok(page(
+        pool.begin().await.map_err(Error::from)?,
+        &Some(me),
+        &uri,
+        PageStyle { kind: PageKind::Other, ..PageStyle::default() },
+        "Discord Roles — Hyrule Town Hall",
+        content,
+    ).await.map_err(Error::from)?)
+}
+
+/// Shows the role changes the reconciler would make in a guild without applying them.
+#[rocket::get("/admin/discord-roles/<guild>?<stale>")]
+pub(crate) async fn discord_roles_preview(
+    pool: &State<PgPool>,
+    discord_ctx: &State<RwFuture<DiscordCtx>>,
+    me: Option<User>,
+    uri: Origin<'_>,
+    csrf: Option<CsrfToken>,
+    guild: u64,
+    stale: bool,
+) -> Result<RawHtml<String>, StatusOrError<Error>> {
+    let me = me.ok_or(Error::Unauthorized)?;
+    if !me.is_global_admin() {
+        return Err(Error::Unauthorized.into());
+    }
+    let guild_id = GuildId::from(NonZero::new(guild).ok_or(StatusOrError::Status(Status::NotFound))?);
+
+    let discord_ctx = discord_ctx.read().await;
+    let mut transaction = pool.begin().await.map_err(Error::from)?;
+    let plan = crate::discord_role_manager::plan(&mut transaction, &*discord_ctx, guild_id, None).await.map_err(Error::from)?;
+    transaction.commit().await.map_err(Error::from)?;
+    let guild_name = guild_id.name(&*discord_ctx).unwrap_or_else(|| guild_id.to_string());
+    let role_name = |role_id: &RoleId| plan.role_names.get(role_id).cloned().unwrap_or_else(|| role_id.to_string());
+    let managed = plan.managed.iter()
+        .map(|(role_id, source)| (role_name(role_id), source))
+        .sorted_unstable()
+        .collect_vec();
+    let num_additions = plan.changes.iter().map(|change| change.add.len()).sum::<usize>();
+    let num_removals = plan.changes.iter().map(|change| change.remove.len()).sum::<usize>();
+
+    let content = html! {
+        article {
+            h1 {
+                : "Discord Roles: ";
+                : &guild_name;
+            }
+            h2 : "Dry Run";
+            @if stale {
+                p : "The role changes were not applied because they changed since they were previewed. Please review the updated changes below.";
+            }
+            @if plan.changes.is_empty() {
+                p : "All managed roles are up to date.";
+            } else {
+                p : format!("Applying would add {num_additions} role{} and remove {num_removals} role{} across {} member{}.", if num_additions == 1 { "" } else { "s" }, if num_removals == 1 { "" } else { "s" }, plan.changes.len(), if plan.changes.len() == 1 { "" } else { "s" });
+                table {
+                    thead {
+                        tr {
+                            th : "Member";
+                            th : "Add";
+                            th : "Remove";
+                        }
+                    }
+                    tbody {
+                        @for change in &plan.changes {
+                            tr {
+                                td : &change.name;
+                                td : change.add.iter().map(role_name).join(", ");
+                                td : change.remove.iter().map(role_name).join(", ");
+                            }
+                        }
+                    }
+                }
+                : full_form_confirm(uri!(apply_discord_roles(guild)), csrf.as_ref(), html! {
+                    input(type = "hidden", name = "plan", value = plan.fingerprint());
+                    p : "Changes are applied in the background in small batches to respect Discord's rate limits, so large servers may take a few minutes.";
+                }, Vec::new(), "Apply Changes", "Apply these role changes?");
+            }
+
+            h2 : "Managed Roles";
+            @if managed.is_empty() {
+                p : "Hyrule Town Hall doesn't manage any roles in this server.";
+            } else {
+                table {
+                    thead {
+                        tr {
+                            th : "Role";
+                            th : "Source";
+                        }
+                    }
+                    tbody {
+                        @for (name, source) in &managed {
+                            tr {
+                                td : name;
+                                td : *source;
+                            }
+                        }
+                    }
+                }
+            }
+            @if !plan.self_assigned.is_empty() {
+                p {
+                    : "Self-assigned roles (never changed): ";
+                    : plan.self_assigned.iter().map(role_name).join(", ");
+                }
+            }
+
+            p {
+                a(href = uri!(discord_roles)) : "Back to Discord Roles";
+            }
+        }
+    };
+
+    Ok(page(
+        pool.begin().await.map_err(Error::from)?,
+        &Some(me),
+        &uri,
+        PageStyle { kind: PageKind::Other, ..PageStyle::default() },
+        &format!("Discord Roles: {guild_name} — Hyrule Town Hall"),
+        content,
+    ).await.map_err(Error::from)?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct ApplyDiscordRolesForm {
+    #[field(default = String::new())]
+    csrf: String,
+    /// The [`fingerprint`](crate::discord_role_manager::Plan::fingerprint) of the previewed plan.
+    plan: String,
+}
+
+#[rocket::post("/admin/discord-roles/<guild>/apply", data = "<form>")]
+pub(crate) async fn apply_discord_roles(
+    pool: &State<PgPool>,
+    discord_ctx: &State<RwFuture<DiscordCtx>>,
+    me: User,
+    csrf: Option<CsrfToken>,
+    guild: u64,
+    form: Form<Contextual<'_, ApplyDiscordRolesForm>>,
+) -> Result<Redirect, StatusOrError<Error>> {
+    if !me.is_global_admin() {
+        return Err(Error::Unauthorized.into());
+    }
+    let guild_id = GuildId::from(NonZero::new(guild).ok_or(StatusOrError::Status(Status::NotFound))?);
+
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+
+    if let Some(ref value) = form.value {
+        // the plan is computed again to make sure the changes being applied are exactly the ones that were previewed
+        let mut transaction = pool.begin().await.map_err(Error::from)?;
+        let plan = crate::discord_role_manager::plan(&mut transaction, &*discord_ctx.read().await, guild_id, None).await.map_err(Error::from)?;
+        transaction.commit().await.map_err(Error::from)?;
+        if plan.fingerprint() != value.plan {
+            return Ok(Redirect::to(uri!(discord_roles_preview(guild, true))))
+        }
+        let discord_ctx = discord_ctx.inner().clone();
+        tokio::spawn(async move {
+            match crate::discord_role_manager::apply(&*discord_ctx.read().await, &plan).await {
+                Ok(edited) => eprintln!("Role reconciliation for guild {guild_id} edited {edited} members"),
+                Err(e) => eprintln!("Role reconciliation for guild {guild_id} failed: {e}"),
+            }
+        });
+    }
+
+    Ok(Redirect::to(uri!(discord_roles_preview(guild, false))))
+}
+
 // ─── Game Ping Workflow CRUD ─────────────────────────────────────────────────
 
 #[derive(FromForm, CsrfForm)]
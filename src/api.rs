@@ -252,7 +252,9 @@ pub(crate) struct Mutation;
     async fn set_race_restream_url(&self, ctx: &Context<'_>, id: GqlId, language: Language, restream_url: String) -> Result<Race> {
         db!(db = ctx; {
             let mut race = cal::Race::from_id(&mut *db, ctx.data_unchecked(), id.try_into()?).await?;
-            race.video_urls.insert(language, restream_url.parse()?);
+            let restream_url = restream_url.parse::<Url>()?;
+            let old_video_urls = race.video_urls.clone();
+            race.video_urls.insert(language, restream_url);
             let me = &ctx.data::<ApiKey>().map_err(|e| Error {
                 message: format!("This query requires an API key. Provide one using the X-API-Key header."),
                 source: Some(Arc::new(e)),
@@ -260,37 +262,13 @@ pub(crate) struct Mutation;
             })?.user;
             race.last_edited_by = Some(me.id);
             race.last_edited_at = Some(Utc::now());
+            cal::set_change_context(&mut *db, Some(me.id), cal::ChangeSource::Api).await?;
             race.save(&mut *db).await?;
 
-            // Update Discord scheduled event if applicable
-            let event = race.event(&mut *db).await?;
-            if let Some(discord_ctx) = ctx.data_opt::<crate::prelude::RwFuture<crate::discord_scheduled_events::DiscordCtx>>() {
-                let http_client = ctx.data_unchecked::<reqwest::Client>();
-                if let Err(e) = crate::discord_scheduled_events::update_discord_scheduled_event(&*discord_ctx.read().await, &mut *db, &race, &event, http_client).await {
-                    eprintln!("Failed to update Discord scheduled event for race {}: {}", race.id, e);
-                }
-
-                // Update volunteer info post if applicable
-                if let Some(pool) = ctx.data_opt::<sqlx::PgPool>() {
-                    if let Err(e) = crate::volunteer_requests::update_volunteer_post_for_race(
-                        pool,
-                        &*discord_ctx.read().await,
-                        race.id,
-                    ).await {
-                        eprintln!("Failed to update volunteer info post for race {} after restream change: {}", race.id, e);
-                    }
-                }
-            }
-
-            // Trigger ZSR volunteer API when the restream URL is set to a zeldaspeedruns channel
-            if restream_url.contains("zeldaspeedruns") {
-                if let Some(pool) = ctx.data_opt::<sqlx::PgPool>() {
-                    crate::zsr_export::schedule_volunteer_api_call(
-                        pool.clone(),
-                        ctx.data_unchecked::<reqwest::Client>().clone(),
-                        race.id,
-                    );
-                }
+            // Update Discord scheduled event, volunteer info post, and ZSR volunteer API if applicable
+            if let (Some(discord_ctx), Some(pool)) = (ctx.data_opt::<crate::prelude::RwFuture<crate::discord_scheduled_events::DiscordCtx>>(), ctx.data_opt::<sqlx::PgPool>()) {
+                let event = race.event(&mut *db).await?;
+                cal::restream_updated(&mut *db, &*discord_ctx.read().await, pool, ctx.data_unchecked(), &event, &old_video_urls, &race).await;
             }
 
             Ok(Race(race))
@@ -303,7 +281,9 @@ pub(crate) struct Mutation;
     async fn set_race_restreamer(&self, ctx: &Context<'_>, id: GqlId, language: Language, restreamer: String) -> Result<Race> {
         db!(db = ctx; {
             let mut race = cal::Race::from_id(&mut *db, ctx.data_unchecked(), id.try_into()?).await?;
-            race.restreamers.insert(language, crate::racetime_bot::parse_user(&mut *db, ctx.data_unchecked(), &restreamer).await?);
+            let restreamer = crate::racetime_bot::parse_user(&mut *db, ctx.data_unchecked(), &restreamer).await?;
+            let old_video_urls = race.video_urls.clone();
+            race.restreamers.insert(language, restreamer);
             let me = &ctx.data::<ApiKey>().map_err(|e| Error {
                 message: format!("This query requires an API key. Provide one using the X-API-Key header."),
                 source: Some(Arc::new(e)),
@@ -311,26 +291,13 @@ pub(crate) struct Mutation;
             })?.user;
             race.last_edited_by = Some(me.id);
             race.last_edited_at = Some(Utc::now());
+            cal::set_change_context(&mut *db, Some(me.id), cal::ChangeSource::Api).await?;
             race.save(&mut *db).await?;
 
-            // Update Discord scheduled event if applicable
-            let event = race.event(&mut *db).await?;
-            if let Some(discord_ctx) = ctx.data_opt::<crate::prelude::RwFuture<crate::discord_scheduled_events::DiscordCtx>>() {
-                let http_client = ctx.data_unchecked::<reqwest::Client>();
-                if let Err(e) = crate::discord_scheduled_events::update_discord_scheduled_event(&*discord_ctx.read().await, &mut *db, &race, &event, http_client).await {
-                    eprintln!("Failed to update Discord scheduled event for race {}: {}", race.id, e);
-                }
-
-                // Update volunteer info post if applicable
-                if let Some(pool) = ctx.data_opt::<sqlx::PgPool>() {
-                    if let Err(e) = crate::volunteer_requests::update_volunteer_post_for_race(
-                        pool,
-                        &*discord_ctx.read().await,
-                        race.id,
-                    ).await {
-                        eprintln!("Failed to update volunteer info post for race {} after restream change: {}", race.id, e);
-                    }
-                }
+            // Update Discord scheduled event and volunteer info post if applicable
+            if let (Some(discord_ctx), Some(pool)) = (ctx.data_opt::<crate::prelude::RwFuture<crate::discord_scheduled_events::DiscordCtx>>(), ctx.data_opt::<sqlx::PgPool>()) {
+                let event = race.event(&mut *db).await?;
+                cal::restream_updated(&mut *db, &*discord_ctx.read().await, pool, ctx.data_unchecked(), &event, &old_video_urls, &race).await;
             }
 
             Ok(Race(race))
@@ -9,7 +9,7 @@ use {
         ActionRowComponent, ButtonKind,
         CreateModal, CreateInputText, InputTextStyle,
     },
-    sqlx::{PgPool, Transaction, Postgres},
+    sqlx::{PgExecutor, PgPool, Transaction, Postgres},
     tokio::time::{sleep, Duration},
 
     crate::{
@@ -615,6 +615,7 @@ impl AsyncRaceManager {
             WHERE at.requested IS NOT NULL
               AND at.submitted IS NULL
               AND at.discord_thread IS NULL
+              AND NOT at.web_run
               AND e.automated_asyncs = true
               AND e.discord_async_channel IS NOT NULL
               AND (a.web_id IS NOT NULL OR a.tfb_uuid IS NOT NULL OR a.xkeys_uuid IS NOT NULL OR a.file_stem IS NOT NULL OR a.seed_data IS NOT NULL)
@@ -999,6 +1000,63 @@ pub(crate) enum Error {
     AlreadyFinished,
     #[error("this async part has been reset")]
     ResetAsyncPart,
+    #[error("the run is paused")]
+    Paused,
+    #[error("the run is not paused")]
+    NotPaused,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "async_pause_status", rename_all = "lowercase")]
+pub(crate) enum PauseStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A pause logged by a runner during an async, e.g. because of a crash or an emergency.
+pub(crate) struct Pause {
+    pub(crate) id: i64,
+    pub(crate) started_at: DateTime<Utc>,
+    pub(crate) ended_at: Option<DateTime<Utc>>,
+    pub(crate) reason: Option<String>,
+    pub(crate) status: PauseStatus,
+    pub(crate) reviewed_by: Option<Id<Users>>,
+}
+
+impl Pause {
+    pub(crate) fn duration(&self) -> Option<TimeDelta> {
+        Some(self.ended_at? - self.started_at)
+    }
+}
+
+/// The time to deduct from a run's finish time. Only pauses approved by an organizer count.
+pub(crate) fn approved_pause_total(pauses: &[Pause]) -> TimeDelta {
+    pauses.iter().filter(|pause| pause.status == PauseStatus::Approved).filter_map(Pause::duration).sum()
+}
+
+/// The time between starting and finishing a run, minus approved pauses.
+///
+/// Clamped to zero since a pause requested during the countdown can be longer than the run itself.
+pub(crate) fn finish_time(start_time: DateTime<Utc>, finished_at: DateTime<Utc>, pauses: &[Pause]) -> TimeDelta {
+    (finished_at - start_time - approved_pause_total(pauses)).max(TimeDelta::zero())
+}
+
+pub(crate) fn format_hms(duration: TimeDelta) -> String {
+    let total_seconds = duration.num_seconds();
+    let sign = if total_seconds < 0 { "-" } else { "" };
+    let total_seconds = total_seconds.unsigned_abs();
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    format!("{sign}{:02}:{:02}:{:02}", hours, minutes, seconds)
+}
+
+/// The result of [`AsyncRun::review_pause`].
+pub(crate) struct PauseReview {
+    pub(crate) duration: TimeDelta,
+    /// Whether an already recorded bracket race result was adjusted, meaning the race's result has to be recomputed.
+    pub(crate) bracket_result_adjusted: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -1078,6 +1136,27 @@ impl AsyncRun {
         if is_member { Ok(()) } else { Err(Error::UnauthorizedUser) }
     }
 
+    /// Like [`Self::verify_user`] but for users signed in on the website.
+    pub(crate) async fn verify_member(&self, pool: &PgPool, user_id: Id<Users>) -> Result<(), Error> {
+        let is_member = match self {
+            AsyncRun::BracketRace { race_id, async_part } => {
+                let mut transaction = pool.begin().await?;
+                let race = Race::from_id(&mut transaction, &reqwest::Client::new(), Id::from(*race_id as u64)).await?;
+                let team = AsyncRaceManager::get_team_for_async_part(&race, *async_part)?;
+                let members = team.members(&mut transaction).await?;
+                members.iter().any(|m| m.id == user_id)
+            }
+            AsyncRun::Qualifier { team_id, .. } => {
+                sqlx::query_scalar!(
+                    r#"SELECT EXISTS (SELECT 1 FROM team_members WHERE team = $1 AND member = $2) AS "exists!""#,
+                    *team_id,
+                    user_id as _
+                ).fetch_one(pool).await?
+            }
+        };
+        if is_member { Ok(()) } else { Err(Error::UnauthorizedUser) }
+    }
+
     pub(crate) async fn is_started(&self, pool: &PgPool) -> Result<bool, Error> {
         match self {
             AsyncRun::BracketRace { race_id, async_part } => {
@@ -1099,19 +1178,19 @@ impl AsyncRun {
         }
     }
 
-    pub(crate) async fn record_start_time(&self, pool: &PgPool) -> Result<(), Error> {
-        let now = Utc::now();
+    /// Records when the run starts. This is the end of the countdown, which is in the future for countdowns shown on the website.
+    pub(crate) async fn record_start_time(&self, pool: &PgPool, start: DateTime<Utc>) -> Result<(), Error> {
         match self {
             AsyncRun::BracketRace { race_id, async_part } => {
                 sqlx::query!(
                     "UPDATE async_times SET start_time = $1 WHERE race_id = $2 AND async_part = $3",
-                    now, *race_id, *async_part as i32,
+                    start, *race_id, *async_part as i32,
                 ).execute(pool).await?;
             }
             AsyncRun::Qualifier { team_id, async_kind } => {
                 sqlx::query!(
                     "UPDATE async_teams SET start_time = $1 WHERE team = $2 AND kind = $3",
-                    now, *team_id, *async_kind as _,
+                    start, *team_id, *async_kind as _,
                 ).execute(pool).await?;
             }
         }
@@ -1119,6 +1198,9 @@ impl AsyncRun {
     }
 
     pub(crate) async fn check_finish_allowed(&self, pool: &PgPool) -> Result<(), Error> {
+        if self.is_paused(pool).await? {
+            return Err(Error::Paused);
+        }
         match self {
             AsyncRun::BracketRace { race_id, async_part } => {
                 let record = sqlx::query!(
@@ -1154,28 +1236,155 @@ impl AsyncRun {
         }
     }
 
-    pub(crate) async fn calculate_finish_time(&self, pool: &PgPool) -> Result<String, Error> {
-        let start_time = match self {
+    /// The finish time of the run as calculated by [`finish_time`], using the recorded time of clicking finish unless `finished_at` is given.
+    /// `None` if the run isn't finished.
+    pub(crate) async fn adjusted_finish_time(&self, pool: &PgPool, finished_at: Option<DateTime<Utc>>) -> Result<Option<TimeDelta>, Error> {
+        let row = match self {
+            AsyncRun::BracketRace { race_id, async_part } => {
+                sqlx::query!(
+                    "SELECT start_time, player_finished_at FROM async_times WHERE race_id = $1 AND async_part = $2",
+                    *race_id, *async_part as i32
+                ).fetch_optional(pool).await?.map(|row| (row.start_time, row.player_finished_at))
+            }
+            AsyncRun::Qualifier { team_id, async_kind } => {
+                sqlx::query!(
+                    "SELECT start_time, player_finished_at FROM async_teams WHERE team = $1 AND kind = $2",
+                    *team_id, *async_kind as _
+                ).fetch_optional(pool).await?.map(|row| (row.start_time, row.player_finished_at))
+            }
+        };
+        let Some((start_time, player_finished_at)) = row else { return Err(Error::NotStarted) };
+        let start_time = start_time.ok_or(Error::NotStarted)?;
+        let Some(finished_at) = finished_at.or(player_finished_at) else { return Ok(None) };
+        Ok(Some(finish_time(start_time, finished_at, &self.pauses(pool).await?)))
+    }
+
+    pub(crate) async fn pauses(&self, pool: impl PgExecutor<'_>) -> sqlx::Result<Vec<Pause>> {
+        match self {
+            AsyncRun::BracketRace { race_id, async_part } => {
+                sqlx::query_as!(Pause, r#"
+                    SELECT id, started_at, ended_at, reason, status AS "status: PauseStatus", reviewed_by AS "reviewed_by: Id<Users>"
+                    FROM async_pauses WHERE race_id = $1 AND async_part = $2 ORDER BY started_at
+                "#, *race_id, *async_part as i32).fetch_all(pool).await
+            }
+            AsyncRun::Qualifier { team_id, async_kind } => {
+                sqlx::query_as!(Pause, r#"
+                    SELECT id, started_at, ended_at, reason, status AS "status: PauseStatus", reviewed_by AS "reviewed_by: Id<Users>"
+                    FROM async_pauses WHERE team = $1 AND kind = $2 ORDER BY started_at
+                "#, *team_id, *async_kind as _).fetch_all(pool).await
+            }
+        }
+    }
+
+    pub(crate) async fn is_paused(&self, pool: &PgPool) -> Result<bool, Error> {
+        Ok(match self {
             AsyncRun::BracketRace { race_id, async_part } => {
                 sqlx::query_scalar!(
-                    "SELECT start_time FROM async_times WHERE race_id = $1 AND async_part = $2",
+                    r#"SELECT EXISTS (SELECT 1 FROM async_pauses WHERE race_id = $1 AND async_part = $2 AND ended_at IS NULL) AS "exists!""#,
                     *race_id, *async_part as i32
                 ).fetch_one(pool).await?
             }
             AsyncRun::Qualifier { team_id, async_kind } => {
                 sqlx::query_scalar!(
-                    "SELECT start_time FROM async_teams WHERE team = $1 AND kind = $2",
+                    r#"SELECT EXISTS (SELECT 1 FROM async_pauses WHERE team = $1 AND kind = $2 AND ended_at IS NULL) AS "exists!""#,
                     *team_id, *async_kind as _
                 ).fetch_one(pool).await?
             }
+        })
+    }
+
+    /// Starts a pause. Only possible while the run is in progress.
+    pub(crate) async fn start_pause(&self, pool: &PgPool, reason: Option<&str>) -> Result<(), Error> {
+        self.check_finish_allowed(pool).await?;
+        match self {
+            AsyncRun::BracketRace { race_id, async_part } => {
+                sqlx::query!(
+                    "INSERT INTO async_pauses (race_id, async_part, reason) VALUES ($1, $2, $3)",
+                    *race_id, *async_part as i32, reason
+                ).execute(pool).await?;
+            }
+            AsyncRun::Qualifier { team_id, async_kind } => {
+                sqlx::query!(
+                    "INSERT INTO async_pauses (team, kind, reason) VALUES ($1, $2, $3)",
+                    *team_id, *async_kind as _, reason
+                ).execute(pool).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Ends the running pause and returns its ID.
+    pub(crate) async fn end_pause(&self, pool: &PgPool) -> Result<i64, Error> {
+        let id = match self {
+            AsyncRun::BracketRace { race_id, async_part } => {
+                sqlx::query_scalar!(
+                    "UPDATE async_pauses SET ended_at = NOW() WHERE race_id = $1 AND async_part = $2 AND ended_at IS NULL RETURNING id",
+                    *race_id, *async_part as i32
+                ).fetch_optional(pool).await?
+            }
+            AsyncRun::Qualifier { team_id, async_kind } => {
+                sqlx::query_scalar!(
+                    "UPDATE async_pauses SET ended_at = NOW() WHERE team = $1 AND kind = $2 AND ended_at IS NULL RETURNING id",
+                    *team_id, *async_kind as _
+                ).fetch_optional(pool).await?
+            }
         };
-        let start_time = start_time.ok_or(Error::NotStarted)?;
-        let duration = Utc::now().signed_duration_since(start_time);
-        let total_seconds = duration.num_seconds();
-        let hours = total_seconds / 3600;
-        let minutes = (total_seconds % 3600) / 60;
-        let seconds = total_seconds % 60;
-        Ok(format!("{:02}:{:02}:{:02}", hours, minutes, seconds))
+        id.ok_or(Error::NotPaused)
+    }
+
+    /// Approves or rejects a finished pause of this run. Returns `None` if there is no such pause.
+    ///
+    /// If the run's result has already been recorded, it's adjusted to match.
+    /// For bracket races, the caller has to recompute the race's result using [`crate::discord_bot::finalize_async_if_complete`] if [`PauseReview::bracket_result_adjusted`] is set.
+    pub(crate) async fn review_pause(&self, transaction: &mut Transaction<'_, Postgres>, pause_id: i64, status: PauseStatus, reviewer: Id<Users>) -> sqlx::Result<Option<PauseReview>> {
+        let row = match self {
+            AsyncRun::BracketRace { race_id, async_part } => {
+                sqlx::query!(
+                    r#"SELECT status AS "status: PauseStatus", started_at, ended_at AS "ended_at!" FROM async_pauses WHERE id = $1 AND race_id = $2 AND async_part = $3 AND ended_at IS NOT NULL FOR UPDATE"#,
+                    pause_id, *race_id, *async_part as i32
+                ).fetch_optional(&mut **transaction).await?.map(|row| (row.status, row.ended_at - row.started_at))
+            }
+            AsyncRun::Qualifier { team_id, async_kind } => {
+                sqlx::query!(
+                    r#"SELECT status AS "status: PauseStatus", started_at, ended_at AS "ended_at!" FROM async_pauses WHERE id = $1 AND team = $2 AND kind = $3 AND ended_at IS NOT NULL FOR UPDATE"#,
+                    pause_id, *team_id, *async_kind as _
+                ).fetch_optional(&mut **transaction).await?.map(|row| (row.status, row.ended_at - row.started_at))
+            }
+        };
+        let Some((old_status, duration)) = row else { return Ok(None) };
+        sqlx::query!(
+            "UPDATE async_pauses SET status = $1, reviewed_by = $2, reviewed_at = NOW() WHERE id = $3",
+            status as _, reviewer as _, pause_id
+        ).execute(&mut **transaction).await?;
+        let adjustment = match (old_status == PauseStatus::Approved, status == PauseStatus::Approved) {
+            (false, true) => -duration,
+            (true, false) => duration,
+            (false, false) | (true, true) => TimeDelta::zero(),
+        };
+        let mut bracket_result_adjusted = false;
+        if !adjustment.is_zero() {
+            let adjustment = sqlx::postgres::types::PgInterval::try_from(adjustment).map_err(sqlx::Error::Encode)?;
+            match self {
+                AsyncRun::BracketRace { race_id, async_part } => {
+                    bracket_result_adjusted = sqlx::query!(
+                        "UPDATE async_times SET finish_time = finish_time + $1 WHERE race_id = $2 AND async_part = $3 AND recorded_by IS NOT NULL AND finish_time IS NOT NULL",
+                        adjustment, *race_id, *async_part as i32
+                    ).execute(&mut **transaction).await?.rows_affected() > 0;
+                }
+                AsyncRun::Qualifier { team_id, async_kind } => {
+                    sqlx::query!(
+                        "UPDATE async_teams SET finish_time = finish_time + $1 WHERE team = $2 AND kind = $3 AND submitted IS NOT NULL AND finish_time IS NOT NULL",
+                        adjustment.clone(), *team_id, *async_kind as _
+                    ).execute(&mut **transaction).await?;
+                    // the verification trigger only queues first submissions, so this keeps the run's review
+                    sqlx::query!(
+                        "UPDATE async_players SET time = time + $1 FROM teams WHERE teams.id = $2 AND async_players.series = teams.series AND async_players.event = teams.event AND async_players.kind = $3 AND async_players.time IS NOT NULL AND async_players.player IN (SELECT member FROM team_members WHERE team = $2)",
+                        adjustment, *team_id, *async_kind as _
+                    ).execute(&mut **transaction).await?;
+                }
+            }
+        }
+        Ok(Some(PauseReview { duration, bracket_result_adjusted }))
     }
 
     pub(crate) async fn set_player_finished_at(&self, pool: &PgPool) -> Result<(), Error> {
@@ -1225,6 +1434,36 @@ impl AsyncRun {
 
 }
 
+/// Records the official result of a qualifier async for each member of the team, with `None` as the time for a forfeit.
+///
+/// Members without an entry in `vods` keep any VoD which was previously recorded for them.
+pub(crate) async fn record_qualifier_result(
+    transaction: &mut Transaction<'_, Postgres>,
+    team: &Team,
+    async_kind: AsyncKind,
+    time: Option<Duration>,
+    vods: &HashMap<Id<Users>, String>,
+) -> sqlx::Result<()> {
+    sqlx::query!(
+        "UPDATE async_teams SET submitted = NOW(), finish_time = $1 WHERE team = $2 AND kind = $3",
+        time as _,
+        team.id as _,
+        async_kind as _
+    ).execute(&mut **transaction).await?;
+    for member in team.members(transaction).await? {
+        sqlx::query!(
+            "INSERT INTO async_players (series, event, player, kind, time, vod) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT (series, event, player, kind) DO UPDATE SET time = EXCLUDED.time, vod = COALESCE(EXCLUDED.vod, async_players.vod)",
+            team.series as _,
+            team.event,
+            member.id as _,
+            async_kind as _,
+            time as _,
+            vods.get(&member.id)
+        ).execute(&mut **transaction).await?;
+    }
+    Ok(())
+}
+
 pub(crate) async fn clear_message_with_button(http: impl CacheHttp, channel_id: ChannelId, button_id: &str) {
     if let Ok(messages) = channel_id.messages(&http, serenity::all::GetMessages::new().limit(20)).await {
         for message in messages {
@@ -1245,6 +1484,9 @@ pub(crate) fn create_finish_forfeit_buttons(run: &AsyncRun) -> CreateActionRow {
         CreateButton::new(run.button_id("finish"))
             .label("FINISH")
             .style(ButtonStyle::Danger),
+        CreateButton::new(run.button_id("pause"))
+            .label("Pause")
+            .style(ButtonStyle::Secondary),
         CreateButton::new(run.button_id("forfeit"))
             .label("Forfeit this async")
             .style(ButtonStyle::Secondary),
@@ -1271,7 +1513,7 @@ pub(crate) async fn run_countdown(
 
     channel_id.say(http, "**GO!** \u{1F3C3}\u{200D}\u{2642}\u{FE0F}").await?;
 
-    run.record_start_time(pool).await?;
+    run.record_start_time(pool, Utc::now()).await?;
 
     let race_buttons = create_finish_forfeit_buttons(run);
     channel_id.send_message(http, CreateMessage::new()
@@ -1453,6 +1695,12 @@ pub(crate) async fn handle_ready_qualifier(
         return Err(Error::NoSeedAvailable);
     };
 
+    sqlx::query!(
+        "UPDATE async_teams SET seed_revealed_at = COALESCE(seed_revealed_at, NOW()) WHERE team = $1 AND kind = $2",
+        *team_id,
+        *async_kind as _
+    ).execute(&mut *transaction).await?;
+
     let mut seed_msg = MessageBuilder::default();
     seed_msg.push("**Your seed is ready!**\n\n");
 
@@ -1593,7 +1841,7 @@ pub(crate) async fn handle_finish(
 
     run.check_finish_allowed(pool).await?;
 
-    let formatted_time = run.calculate_finish_time(pool).await?;
+    let formatted_time = format_hms(run.adjusted_finish_time(pool, Some(Utc::now())).await?.ok_or(Error::NotStarted)?);
 
     let revert_nonce = Utc::now().timestamp_millis();
     let revert_button_id = run.button_id_with_nonce("revert", revert_nonce);
@@ -1726,6 +1974,116 @@ pub(crate) async fn handle_forfeit_cancel(
     Ok(())
 }
 
+pub(crate) async fn handle_pause(
+    ctx: &DiscordCtx,
+    interaction: &ComponentInteraction,
+    pool: &PgPool,
+    run: &AsyncRun,
+) -> Result<(), Error> {
+    run.verify_user(pool, interaction.user.id).await?;
+
+    run.start_pause(pool, None).await?;
+
+    let resume_button = CreateActionRow::Buttons(vec![
+        CreateButton::new(run.button_id("resume"))
+            .label("RESUME")
+            .style(ButtonStyle::Primary),
+    ]);
+    let mut msg = MessageBuilder::default();
+    msg.push("\u{23F8}\u{FE0F} **Paused** by ");
+    msg.mention(&interaction.user);
+    msg.push(". Please explain the reason for the pause in this thread and click RESUME once you continue playing.\nAn organizer will review the pause before it is deducted from your time.");
+    interaction.create_response(ctx, CreateInteractionResponse::UpdateMessage(
+        CreateInteractionResponseMessage::new()
+            .content(msg.build())
+            .components(vec![resume_button])
+    )).await?;
+    Ok(())
+}
+
+pub(crate) async fn handle_resume(
+    ctx: &DiscordCtx,
+    interaction: &ComponentInteraction,
+    pool: &PgPool,
+    run: &AsyncRun,
+) -> Result<(), Error> {
+    run.verify_user(pool, interaction.user.id).await?;
+
+    let pause_id = run.end_pause(pool).await?;
+    let duration = run.pauses(pool).await?.into_iter().find(|pause| pause.id == pause_id).and_then(|pause| pause.duration()).unwrap_or_default();
+
+    let race_buttons = create_finish_forfeit_buttons(run);
+    interaction.create_response(ctx, CreateInteractionResponse::UpdateMessage(
+        CreateInteractionResponseMessage::new()
+            .content("**Resumed!** Click the FINISH button once you have completed your run.\nIf you need to forfeit, click the Forfeit button.")
+            .components(vec![race_buttons])
+    )).await?;
+
+    let review_buttons = CreateActionRow::Buttons(vec![
+        CreateButton::new(run.button_id_with_nonce("pause_approve", pause_id))
+            .label("Approve deduction")
+            .style(ButtonStyle::Success),
+        CreateButton::new(run.button_id_with_nonce("pause_reject", pause_id))
+            .label("Reject")
+            .style(ButtonStyle::Secondary),
+    ]);
+    interaction.channel_id.send_message(ctx, CreateMessage::new()
+        .content(format!("A pause of {} was logged.\n**Organizers:** should it be deducted from the finish time?", format_hms(duration)))
+        .components(vec![review_buttons])
+    ).await?;
+    Ok(())
+}
+
+pub(crate) async fn handle_pause_review(
+    ctx: &DiscordCtx,
+    interaction: &ComponentInteraction,
+    pool: &PgPool,
+    run: &AsyncRun,
+    pause_id: i64,
+    status: PauseStatus,
+) -> Result<(), Error> {
+    let is_organizer = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM organizers eo JOIN users u ON eo.organizer = u.id WHERE u.discord_id = $1) AS "exists!""#,
+        interaction.user.id.get() as i64
+    ).fetch_one(pool).await?;
+
+    if !is_organizer {
+        interaction.create_response(ctx, CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new().ephemeral(true)
+                .content("You must be an event organizer to use this.")
+        )).await?;
+        return Ok(());
+    }
+
+    let mut transaction = pool.begin().await?;
+    let user = User::from_discord(&mut *transaction, interaction.user.id).await?.ok_or(Error::UnauthorizedUser)?;
+    let Some(PauseReview { duration, bracket_result_adjusted }) = run.review_pause(&mut transaction, pause_id, status, user.id).await? else {
+        return Err(Error::NotPaused);
+    };
+    if let (AsyncRun::BracketRace { race_id, .. }, true) = (run, bracket_result_adjusted) {
+        let race = Race::from_id(&mut transaction, &reqwest::Client::new(), Id::from(*race_id as u64)).await?;
+        crate::discord_bot::finalize_async_if_complete(ctx, &mut transaction, *race_id, &race).await?;
+    }
+    transaction.commit().await?;
+
+    let mut msg = MessageBuilder::default();
+    msg.push(format!("Pause of {} ", format_hms(duration)));
+    msg.push(match status {
+        PauseStatus::Approved => "approved",
+        PauseStatus::Rejected => "rejected",
+        PauseStatus::Pending => "reopened",
+    });
+    msg.push(" by ");
+    msg.mention(&interaction.user);
+    msg.push(".");
+    interaction.create_response(ctx, CreateInteractionResponse::UpdateMessage(
+        CreateInteractionResponseMessage::new()
+            .content(msg.build())
+            .components(vec![])
+    )).await?;
+    Ok(())
+}
+
 /// Looks for a twitch.tv/youtube.com/youtu.be link posted by the async player in the last 20
 /// thread messages, so the org_result modal can be pre-filled with it. Any lookup failure
 /// (DB error, Discord API error, no linked Discord account, no link found) just yields `None`
@@ -1790,6 +2148,13 @@ pub(crate) async fn handle_org_result(
         }
     };
 
+    let mut time_input = CreateInputText::new(InputTextStyle::Short, "Finish time", "time")
+        .placeholder("H:MM:SS or HH:MM:SS")
+        .required(true);
+    // approved pauses are already deducted here
+    if let Ok(Ok(Some(time))) = tokio::time::timeout(Duration::from_millis(500), run.adjusted_finish_time(pool, None)).await {
+        time_input = time_input.value(format_hms(time));
+    }
     let mut link_input = CreateInputText::new(InputTextStyle::Short, "VOD link (optional)", "link")
         .placeholder("https://...")
         .required(false);
@@ -1805,11 +2170,7 @@ pub(crate) async fn handle_org_result(
     interaction.create_response(ctx, CreateInteractionResponse::Modal(
         CreateModal::new(modal_id, "Confirm Result")
             .components(vec![
-                CreateActionRow::InputText(
-                    CreateInputText::new(InputTextStyle::Short, "Finish time", "time")
-                        .placeholder("H:MM:SS or HH:MM:SS")
-                        .required(true)
-                ),
+                CreateActionRow::InputText(time_input),
                 CreateActionRow::InputText(link_input),
             ])
     )).await?;
@@ -1885,23 +2246,7 @@ pub(crate) async fn handle_org_forfeit_yes(
             let team = Team::from_id(&mut transaction, Id::from(*team_id)).await?.ok_or(sqlx::Error::RowNotFound)?;
             let team_name = team.name(&mut transaction).await?.unwrap_or_else(|| "Unknown Team".to_string().into());
 
-            sqlx::query!(
-                "UPDATE async_teams SET submitted = NOW(), finish_time = NULL WHERE team = $1 AND kind = $2",
-                *team_id as _,
-                *async_kind as _
-            ).execute(&mut *transaction).await?;
-
-            let members = team.members(&mut transaction).await?;
-            for member in members {
-                sqlx::query!(
-                    "INSERT INTO async_players (series, event, player, kind, time, vod) VALUES ($1, $2, $3, $4, NULL, NULL) ON CONFLICT (series, event, player, kind) DO UPDATE SET time = EXCLUDED.time, vod = COALESCE(EXCLUDED.vod, async_players.vod)",
-                    team.series as _,
-                    team.event,
-                    member.id as _,
-                    *async_kind as _
-                ).execute(&mut *transaction).await?;
-            }
-
+            record_qualifier_result(&mut transaction, &team, *async_kind, None, &HashMap::default()).await?;
             transaction.commit().await?;
 
             clear_message_with_button(ctx, interaction.channel_id, &run.button_id("org_forfeit")).await;
@@ -1964,7 +2309,7 @@ pub(crate) async fn dispatch_button(
     pool: &PgPool,
     custom_id: &str,
 ) -> Result<bool, Error> {
-    let Some((action, run, _nonce)) = AsyncRun::parse_button(custom_id) else { return Ok(false) };
+    let Some((action, run, nonce)) = AsyncRun::parse_button(custom_id) else { return Ok(false) };
 
     match action.as_str() {
         "ready" => {
@@ -1982,6 +2327,17 @@ pub(crate) async fn dispatch_button(
         "revert" => {
             handle_revert(ctx, interaction, &run).await?;
         }
+        "pause" => {
+            handle_pause(ctx, interaction, pool, &run).await?;
+        }
+        "resume" => {
+            handle_resume(ctx, interaction, pool, &run).await?;
+        }
+        "pause_approve" | "pause_reject" => {
+            let Some(pause_id) = nonce else { return Ok(false) };
+            let status = if action == "pause_approve" { PauseStatus::Approved } else { PauseStatus::Rejected };
+            handle_pause_review(ctx, interaction, pool, &run, pause_id, status).await?;
+        }
         "forfeit" => {
             handle_forfeit(ctx, interaction, pool, &run).await?;
         }
@@ -2007,3 +2363,41 @@ pub(crate) async fn dispatch_button(
     }
     Ok(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pause(started_at: DateTime<Utc>, minutes: i64, status: PauseStatus) -> Pause {
+        Pause { id: 0, started_at, ended_at: Some(started_at + TimeDelta::minutes(minutes)), reason: None, status, reviewed_by: None }
+    }
+
+    #[test]
+    fn finish_time_subtracts_approved_pauses() {
+        let start = Utc::now();
+        let finish = start + TimeDelta::hours(2);
+        let pauses = [
+            pause(start + TimeDelta::minutes(10), 5, PauseStatus::Approved),
+            pause(start + TimeDelta::minutes(30), 7, PauseStatus::Rejected),
+            pause(start + TimeDelta::minutes(50), 11, PauseStatus::Pending),
+            pause(start + TimeDelta::minutes(70), 3, PauseStatus::Approved),
+            Pause { ended_at: None, ..pause(start + TimeDelta::minutes(90), 0, PauseStatus::Approved) },
+        ];
+        assert_eq!(finish_time(start, finish, &pauses), TimeDelta::minutes(112));
+        assert_eq!(finish_time(start, finish, &[]), TimeDelta::hours(2));
+    }
+
+    #[test]
+    fn finish_time_is_clamped_to_zero() {
+        let start = Utc::now();
+        let pauses = [pause(start - TimeDelta::minutes(5), 10, PauseStatus::Approved)];
+        assert_eq!(finish_time(start, start + TimeDelta::minutes(1), &pauses), TimeDelta::zero());
+    }
+
+    #[test]
+    fn format_hms_signs() {
+        assert_eq!(format_hms(TimeDelta::seconds(3723)), "01:02:03");
+        assert_eq!(format_hms(TimeDelta::zero()), "00:00:00");
+        assert_eq!(format_hms(TimeDelta::seconds(-3723)), "-01:02:03");
+    }
+}
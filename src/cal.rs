@@ -155,6 +155,14 @@ pub(crate) enum Source {
     Sheet {
         timestamp: NaiveDateTime,
     },
+    /// A row of a Google sheet configured via [`event::sheet_import`], identified by its key.
+    SheetRow {
+        key: String,
+    },
+    /// An event in an iCalendar feed configured via [`event::ics_import`], identified by its UID.
+    Ics {
+        uid: String,
+    },
     StartGG {
         event: String,
         set: startgg::ID,
@@ -205,7 +213,7 @@ impl Entrant {
         self.to_html_with_optional_discord(transaction, Some(discord_ctx), None, running_text).await
     }
 
-    async fn to_html_with_optional_discord(
+    pub(crate) async fn to_html_with_optional_discord(
         &self,
         transaction: &mut Transaction<'_, Postgres>,
         discord_ctx: Option<&DiscordCtx>,
@@ -285,41 +293,29 @@ pub(crate) enum Entrants {
 }
 
 impl Entrants {
-    fn to_db(&self) -> ([Option<Id<Teams>>; 3], [Option<&String>; 3], [Option<UserId>; 2], [Option<&String>; 2], [Option<&String>; 2], [Option<u32>; 2]) {
+    fn to_db(&self) -> ([Option<Id<Teams>>; 3], [Option<&String>; 3], [Option<UserId>; 3], [Option<&String>; 3], [Option<&String>; 3], [Option<u32>; 2]) {
+        fn entrant_to_db(entrant: &Entrant) -> (Option<Id<Teams>>, Option<&String>, Option<UserId>, Option<&String>, Option<&String>) {
+            match entrant {
+                Entrant::MidosHouseTeam(team) => (Some(team.id), None, None, None, None),
+                Entrant::Discord { id, racetime_id, twitch_username } => (None, None, Some(*id), racetime_id.as_ref(), twitch_username.as_ref()),
+                Entrant::Named { name, racetime_id, twitch_username } => (None, Some(name), None, racetime_id.as_ref(), twitch_username.as_ref()),
+            }
+        }
+
         match *self {
-            Entrants::Open => ([None; 3], [None; 3], [None; 2], [None; 2], [None; 2], [None; 2]),
-            Entrants::Count { total, finished } => ([None; 3], [None; 3], [None; 2], [None; 2], [None; 2], [Some(total), Some(finished)]),
-            Entrants::Named(ref entrants) => ([None; 3], [Some(entrants), None, None], [None; 2], [None; 2], [None; 2], [None; 2]),
+            Entrants::Open => ([None; 3], [None; 3], [None; 3], [None; 3], [None; 3], [None; 2]),
+            Entrants::Count { total, finished } => ([None; 3], [None; 3], [None; 3], [None; 3], [None; 3], [Some(total), Some(finished)]),
+            Entrants::Named(ref entrants) => ([None; 3], [Some(entrants), None, None], [None; 3], [None; 3], [None; 3], [None; 2]),
             Entrants::Two([ref p1, ref p2]) => {
-                let (team1, p1, p1_discord, p1_racetime, p1_twitch) = match p1 {
-                    Entrant::MidosHouseTeam(team) => (Some(team.id), None, None, None, None),
-                    Entrant::Discord { id, racetime_id, twitch_username } => (None, None, Some(*id), racetime_id.as_ref(), twitch_username.as_ref()),
-                    Entrant::Named { name, racetime_id, twitch_username } => (None, Some(name), None, racetime_id.as_ref(), twitch_username.as_ref()),
-                };
-                let (team2, p2, p2_discord, p2_racetime, p2_twitch) = match p2 {
-                    Entrant::MidosHouseTeam(team) => (Some(team.id), None, None, None, None),
-                    Entrant::Discord { id, racetime_id, twitch_username } => (None, None, Some(*id), racetime_id.as_ref(), twitch_username.as_ref()),
-                    Entrant::Named { name, racetime_id, twitch_username } => (None, Some(name), None, racetime_id.as_ref(), twitch_username.as_ref()),
-                };
-                ([team1, team2, None], [p1, p2, None], [p1_discord, p2_discord], [p1_racetime, p2_racetime], [p1_twitch, p2_twitch], [None; 2])
+                let (team1, p1, p1_discord, p1_racetime, p1_twitch) = entrant_to_db(p1);
+                let (team2, p2, p2_discord, p2_racetime, p2_twitch) = entrant_to_db(p2);
+                ([team1, team2, None], [p1, p2, None], [p1_discord, p2_discord, None], [p1_racetime, p2_racetime, None], [p1_twitch, p2_twitch, None], [None; 2])
             }
             Entrants::Three([ref p1, ref p2, ref p3]) => {
-                let (team1, p1) = match p1 {
-                    Entrant::MidosHouseTeam(team) => (Some(team.id), None),
-                    Entrant::Named { name, racetime_id: None, twitch_username: None } => (None, Some(name)),
-                    _ => unimplemented!(), //TODO
-                };
-                let (team2, p2) = match p2 {
-                    Entrant::MidosHouseTeam(team) => (Some(team.id), None),
-                    Entrant::Named { name, racetime_id: None, twitch_username: None } => (None, Some(name)),
-                    _ => unimplemented!(), //TODO
-                };
-                let (team3, p3) = match p3 {
-                    Entrant::MidosHouseTeam(team) => (Some(team.id), None),
-                    Entrant::Named { name, racetime_id: None, twitch_username: None } => (None, Some(name)),
-                    _ => unimplemented!(), //TODO
-                };
-                ([team1, team2, team3], [p1, p2, p3], [None; 2], [None; 2], [None; 2], [None; 2])
+                let (team1, p1, p1_discord, p1_racetime, p1_twitch) = entrant_to_db(p1);
+                let (team2, p2, p2_discord, p2_racetime, p2_twitch) = entrant_to_db(p2);
+                let (team3, p3, p3_discord, p3_racetime, p3_twitch) = entrant_to_db(p3);
+                ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord, p3_discord], [p1_racetime, p2_racetime, p3_racetime], [p1_twitch, p2_twitch, p3_twitch], [None; 2])
             }
         }
     }
@@ -627,6 +623,8 @@ impl Race {
             challonge_match,
             league_id,
             sheet_timestamp,
+            sheet_key,
+            ics_uid,
             startgg_event,
             startgg_set AS "startgg_set: startgg::ID",
             speedgaming_id,
@@ -639,10 +637,13 @@ impl Race {
             p3,
             p1_discord AS "p1_discord: PgSnowflake<UserId>",
             p2_discord AS "p2_discord: PgSnowflake<UserId>",
+            p3_discord AS "p3_discord: PgSnowflake<UserId>",
             p1_racetime,
             p2_racetime,
+            p3_racetime,
             p1_twitch,
             p2_twitch,
+            p3_twitch,
             total,
             finished,
             r.phase,
@@ -714,6 +715,10 @@ impl Race {
             Source::League { id }
         } else if let Some(timestamp) = row.sheet_timestamp {
             Source::Sheet { timestamp }
+        } else if let Some(key) = row.sheet_key {
+            Source::SheetRow { key }
+        } else if let Some(uid) = row.ics_uid {
+            Source::Ics { uid }
         } else if let (Some(event), Some(set)) = (row.startgg_event, row.startgg_set) {
             Source::StartGG { event, set }
         } else if let Some(id) = row.speedgaming_id {
@@ -776,8 +781,18 @@ impl Race {
                     team_cache.insert(team3, team.clone());
                     team
                 }))
+            } else if let Some(PgSnowflake(id)) = row.p3_discord {
+                Some(Entrant::Discord {
+                    racetime_id: row.p3_racetime,
+                    twitch_username: row.p3_twitch,
+                    id,
+                })
             } else if let Some(name) = row.p3 {
-                Some(Entrant::Named { racetime_id: None, twitch_username: None, name })
+                Some(Entrant::Named {
+                    racetime_id: row.p3_racetime,
+                    twitch_username: row.p3_twitch,
+                    name,
+                })
             } else {
                 None
             };
@@ -1054,7 +1069,7 @@ impl Race {
     }
 
     pub(crate) async fn game_count(&self, transaction: &mut Transaction<'_, Postgres>) -> Result<i16, Error> {
-        let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord], [p1_racetime, p2_racetime], [p1_twitch, p2_twitch], [total, finished]) = self.entrants.to_db();
+        let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord, p3_discord], [p1_racetime, p2_racetime, p3_racetime], [p1_twitch, p2_twitch, p3_twitch], [total, finished]) = self.entrants.to_db();
         Ok(sqlx::query_scalar!(r#"SELECT game AS "game!" FROM races WHERE
             NOT ignored
             AND series = $1
@@ -1070,12 +1085,15 @@ impl Race {
             AND p3 IS NOT DISTINCT FROM $10
             AND p1_discord IS NOT DISTINCT FROM $11
             AND p2_discord IS NOT DISTINCT FROM $12
-            AND p1_racetime IS NOT DISTINCT FROM $13
-            AND p2_racetime IS NOT DISTINCT FROM $14
-            AND p1_twitch IS NOT DISTINCT FROM $15
-            AND p2_twitch IS NOT DISTINCT FROM $16
-            AND total IS NOT DISTINCT FROM $17
-            AND finished IS NOT DISTINCT FROM $18
+            AND p3_discord IS NOT DISTINCT FROM $13
+            AND p1_racetime IS NOT DISTINCT FROM $14
+            AND p2_racetime IS NOT DISTINCT FROM $15
+            AND p3_racetime IS NOT DISTINCT FROM $16
+            AND p1_twitch IS NOT DISTINCT FROM $17
+            AND p2_twitch IS NOT DISTINCT FROM $18
+            AND p3_twitch IS NOT DISTINCT FROM $19
+            AND total IS NOT DISTINCT FROM $20
+            AND finished IS NOT DISTINCT FROM $21
             ORDER BY game DESC LIMIT 1
         "#,
             self.series as _,
@@ -1090,10 +1108,13 @@ impl Race {
             p3,
             p1_discord.map(PgSnowflake) as _,
             p2_discord.map(PgSnowflake) as _,
+            p3_discord.map(PgSnowflake) as _,
             p1_racetime,
             p2_racetime,
+            p3_racetime,
             p1_twitch,
             p2_twitch,
+            p3_twitch,
             total.map(|total| total as i32),
             finished.map(|finished| finished as i32),
         ).fetch_optional(&mut **transaction).await?.unwrap_or(1))
@@ -1102,7 +1123,7 @@ impl Race {
     pub(crate) async fn next_game(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client) -> Result<Option<Self>, Error> {
         Ok(if_chain! {
             if let Some(game) = self.game;
-            let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord], [p1_racetime, p2_racetime], [p1_twitch, p2_twitch], [total, finished]) = self.entrants.to_db();
+            let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord, p3_discord], [p1_racetime, p2_racetime, p3_racetime], [p1_twitch, p2_twitch, p3_twitch], [total, finished]) = self.entrants.to_db();
             if let Some(id) = sqlx::query_scalar!(r#"SELECT id AS "id: Id<Races>" FROM races WHERE
                 NOT ignored
                 AND series = $1
@@ -1118,12 +1139,15 @@ impl Race {
                 AND p3 IS NOT DISTINCT FROM $11
                 AND p1_discord IS NOT DISTINCT FROM $12
                 AND p2_discord IS NOT DISTINCT FROM $13
-                AND p1_racetime IS NOT DISTINCT FROM $14
-                AND p2_racetime IS NOT DISTINCT FROM $15
-                AND p1_twitch IS NOT DISTINCT FROM $16
-                AND p2_twitch IS NOT DISTINCT FROM $17
-                AND total IS NOT DISTINCT FROM $18
-                AND finished IS NOT DISTINCT FROM $19
+                AND p3_discord IS NOT DISTINCT FROM $14
+                AND p1_racetime IS NOT DISTINCT FROM $15
+                AND p2_racetime IS NOT DISTINCT FROM $16
+                AND p3_racetime IS NOT DISTINCT FROM $17
+                AND p1_twitch IS NOT DISTINCT FROM $18
+                AND p2_twitch IS NOT DISTINCT FROM $19
+                AND p3_twitch IS NOT DISTINCT FROM $20
+                AND total IS NOT DISTINCT FROM $21
+                AND finished IS NOT DISTINCT FROM $22
             "#,
                 self.series as _,
                 self.event,
@@ -1138,10 +1162,13 @@ impl Race {
                 p3,
                 p1_discord.map(PgSnowflake) as _,
                 p2_discord.map(PgSnowflake) as _,
+                p3_discord.map(PgSnowflake) as _,
                 p1_racetime,
                 p2_racetime,
+                p3_racetime,
                 p1_twitch,
                 p2_twitch,
+                p3_twitch,
                 total.map(|total| total as i32),
                 finished.map(|finished| finished as i32),
             ).fetch_optional(&mut **transaction).await?;
@@ -1155,7 +1182,7 @@ impl Race {
 
     pub(crate) async fn copy_draft_to_remaining_games(&self, transaction: &mut Transaction<'_, Postgres>, draft: &Draft) -> Result<(), Error> {
         if let Some(game) = self.game {
-            let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord], [p1_racetime, p2_racetime], [p1_twitch, p2_twitch], [total, finished]) = self.entrants.to_db();
+            let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord, p3_discord], [p1_racetime, p2_racetime, p3_racetime], [p1_twitch, p2_twitch, p3_twitch], [total, finished]) = self.entrants.to_db();
             sqlx::query!(r#"UPDATE races SET draft_state = $1 WHERE
                 NOT ignored
                 AND series = $2
@@ -1171,12 +1198,15 @@ impl Race {
                 AND p3 IS NOT DISTINCT FROM $12
                 AND p1_discord IS NOT DISTINCT FROM $13
                 AND p2_discord IS NOT DISTINCT FROM $14
-                AND p1_racetime IS NOT DISTINCT FROM $15
-                AND p2_racetime IS NOT DISTINCT FROM $16
-                AND p1_twitch IS NOT DISTINCT FROM $17
-                AND p2_twitch IS NOT DISTINCT FROM $18
-                AND total IS NOT DISTINCT FROM $19
-                AND finished IS NOT DISTINCT FROM $20
+                AND p3_discord IS NOT DISTINCT FROM $15
+                AND p1_racetime IS NOT DISTINCT FROM $16
+                AND p2_racetime IS NOT DISTINCT FROM $17
+                AND p3_racetime IS NOT DISTINCT FROM $18
+                AND p1_twitch IS NOT DISTINCT FROM $19
+                AND p2_twitch IS NOT DISTINCT FROM $20
+                AND p3_twitch IS NOT DISTINCT FROM $21
+                AND total IS NOT DISTINCT FROM $22
+                AND finished IS NOT DISTINCT FROM $23
             "#,
                 Json(draft) as _,
                 self.series as _,
@@ -1192,10 +1222,13 @@ impl Race {
                 p3,
                 p1_discord.map(PgSnowflake) as _,
                 p2_discord.map(PgSnowflake) as _,
+                p3_discord.map(PgSnowflake) as _,
                 p1_racetime,
                 p2_racetime,
+                p3_racetime,
                 p1_twitch,
                 p2_twitch,
+                p3_twitch,
                 total.map(|total| total as i32),
                 finished.map(|finished| finished as i32),
             ).execute(&mut **transaction).await?;
@@ -1205,7 +1238,7 @@ impl Race {
 
     pub(crate) async fn ignore_remaining_games(&self, transaction: &mut Transaction<'_, Postgres>) -> Result<Vec<Id<Races>>, Error> {
         if let Some(game) = self.game {
-            let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord], [p1_racetime, p2_racetime], [p1_twitch, p2_twitch], [total, finished]) = self.entrants.to_db();
+            let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord, p3_discord], [p1_racetime, p2_racetime, p3_racetime], [p1_twitch, p2_twitch, p3_twitch], [total, finished]) = self.entrants.to_db();
             let ids = sqlx::query_scalar!(r#"UPDATE races SET ignored = true WHERE
                 NOT ignored
                 AND series = $1
@@ -1221,12 +1254,15 @@ impl Race {
                 AND p3 IS NOT DISTINCT FROM $11
                 AND p1_discord IS NOT DISTINCT FROM $12
                 AND p2_discord IS NOT DISTINCT FROM $13
-                AND p1_racetime IS NOT DISTINCT FROM $14
-                AND p2_racetime IS NOT DISTINCT FROM $15
-                AND p1_twitch IS NOT DISTINCT FROM $16
-                AND p2_twitch IS NOT DISTINCT FROM $17
-                AND total IS NOT DISTINCT FROM $18
-                AND finished IS NOT DISTINCT FROM $19
+                AND p3_discord IS NOT DISTINCT FROM $14
+                AND p1_racetime IS NOT DISTINCT FROM $15
+                AND p2_racetime IS NOT DISTINCT FROM $16
+                AND p3_racetime IS NOT DISTINCT FROM $17
+                AND p1_twitch IS NOT DISTINCT FROM $18
+                AND p2_twitch IS NOT DISTINCT FROM $19
+                AND p3_twitch IS NOT DISTINCT FROM $20
+                AND total IS NOT DISTINCT FROM $21
+                AND finished IS NOT DISTINCT FROM $22
                 RETURNING id AS "id: Id<Races>"
             "#,
                 self.series as _,
@@ -1242,10 +1278,13 @@ impl Race {
                 p3,
                 p1_discord.map(PgSnowflake) as _,
                 p2_discord.map(PgSnowflake) as _,
+                p3_discord.map(PgSnowflake) as _,
                 p1_racetime,
                 p2_racetime,
+                p3_racetime,
                 p1_twitch,
                 p2_twitch,
+                p3_twitch,
                 total.map(|total| total as i32),
                 finished.map(|finished| finished as i32),
             ).fetch_all(&mut **transaction).await?;
@@ -1324,10 +1363,10 @@ impl Race {
     }
 
     pub(crate) async fn multistream_url_prerace(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>) -> Result<Option<Url>, Error> {
-        async fn entrant_twitch_names<'a>(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, entrant: &'a Entrant) -> Result<Option<Vec<Cow<'a, str>>>, Error> {
+        async fn entrant_twitch_names<'a>(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, race: Id<Races>, entrant: &'a Entrant) -> Result<Option<Vec<Cow<'a, str>>>, Error> {
             let mut channels = Vec::default();
             match entrant {
-                Entrant::MidosHouseTeam(team) => for (member, role) in team.members_roles(&mut *transaction).await? {
+                Entrant::MidosHouseTeam(team) => for (member, role) in team.members_roles_for_race(&mut *transaction, race).await? {
                     if event.team_config.role_is_racing(role) {
                         if let Some(twitch_name) = member.racetime_user_data(http_client).await?.and_then(identity).and_then(|racetime_user_data| racetime_user_data.twitch_name) {
                             channels.push(Cow::Owned(twitch_name));
@@ -1365,7 +1404,7 @@ impl Race {
             Entrants::Two(ref entrants) => {
                 let mut channels = Vec::default();
                 for entrant in entrants {
-                    if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, entrant).await? {
+                    if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, self.id, entrant).await? {
                         channels.extend(twitch_names);
                     } else {
                         return Ok(None)
@@ -1384,7 +1423,7 @@ impl Race {
             Entrants::Three(ref entrants) => {
                 let mut channels = Vec::default();
                 for entrant in entrants {
-                    if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, entrant).await? {
+                    if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, self.id, entrant).await? {
                         channels.extend(twitch_names);
                     } else {
                         return Ok(None)
@@ -1403,10 +1442,10 @@ impl Race {
     }
 
     pub(crate) async fn multistream_url(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>) -> Result<Option<Url>, Error> {
-        async fn entrant_twitch_names<'a>(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, entrant: &'a Entrant) -> Result<Option<Vec<Cow<'a, str>>>, Error> {
+        async fn entrant_twitch_names<'a>(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, race: Id<Races>, entrant: &'a Entrant) -> Result<Option<Vec<Cow<'a, str>>>, Error> {
             let mut channels = Vec::default();
             match entrant {
-                Entrant::MidosHouseTeam(team) => for (member, role) in team.members_roles(&mut *transaction).await? {
+                Entrant::MidosHouseTeam(team) => for (member, role) in team.members_roles_for_race(&mut *transaction, race).await? {
                     if event.team_config.role_is_racing(role) {
                         if let Some(twitch_name) = member.racetime_user_data(http_client).await?.and_then(identity).and_then(|racetime_user_data| racetime_user_data.twitch_name) {
                             channels.push(Cow::Owned(twitch_name));
@@ -1445,7 +1484,7 @@ impl Race {
                 Entrants::Two(ref entrants) => {
                     let mut channels = Vec::default();
                     for entrant in entrants {
-                        if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, entrant).await? {
+                        if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, self.id, entrant).await? {
                             channels.extend(twitch_names);
                         } else {
                             return Ok(None)
@@ -1464,7 +1503,7 @@ impl Race {
                 Entrants::Three(ref entrants) => {
                     let mut channels = Vec::default();
                     for entrant in entrants {
-                        if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, entrant).await? {
+                        if let Some(twitch_names) = entrant_twitch_names(&mut *transaction, http_client, event, self.id, entrant).await? {
                             channels.extend(twitch_names);
                         } else {
                             return Ok(None)
@@ -1563,10 +1602,11 @@ impl Race {
             Source::Challonge { ref id } => (Some(id), None, None, None, None, None),
             Source::League { id } => (None, Some(id), None, None, None, None),
             Source::Sheet { timestamp } => (None, None, Some(timestamp), None, None, None),
+            Source::SheetRow { .. } | Source::Ics { .. } => (None, None, None, None, None, None),
             Source::StartGG { ref event, ref set } => (None, None, None, Some(event), Some(set), None),
             Source::SpeedGaming { id } => (None, None, None, None, None, Some(id)),
         };
-        let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord], [p1_racetime, p2_racetime], [p1_twitch, p2_twitch], [total, finished]) = self.entrants.to_db();
+        let ([team1, team2, team3], [p1, p2, p3], [p1_discord, p2_discord, p3_discord], [p1_racetime, p2_racetime, p3_racetime], [p1_twitch, p2_twitch, p3_twitch], [total, finished]) = self.entrants.to_db();
         let (start, [async_start1, async_start2, async_start3], end, [async_end1, async_end2, async_end3], room, [async_room1, async_room2, async_room3]) = match self.schedule {
             RaceSchedule::Unscheduled => (None, [None; 3], None, [None; 3], None, [None; 3]),
             RaceSchedule::Live { start, end, ref room } => (Some(start), [None; 3], end, [None; 3], room.as_ref(), [None; 3]),
@@ -1666,6 +1706,16 @@ impl Race {
         sqlx::query!("UPDATE races SET custom_title = $1, custom_create_room = $2, companion_race_id = $3 WHERE id = $4", self.custom_title.as_deref(), self.custom_create_room, self.companion_race_id.map(i64::from), self.id as _)
             .execute(&mut **transaction)
             .await?;
+        sqlx::query!(
+            "UPDATE races SET p3_discord = $1, p3_racetime = $2, p3_twitch = $3 WHERE id = $4",
+            p3_discord.map(PgSnowflake) as _, p3_racetime, p3_twitch, self.id as _,
+        ).execute(&mut **transaction).await?;
+        let (sheet_key, ics_uid) = match self.source {
+            Source::SheetRow { ref key } => (Some(key), None),
+            Source::Ics { ref uid } => (None, Some(uid)),
+            _ => (None, None),
+        };
+        sqlx::query!("UPDATE races SET sheet_key = $1, ics_uid = $2 WHERE id = $3", sheet_key, ics_uid, self.id as _).execute(&mut **transaction).await?;
         Ok(())
     }
 
@@ -1865,7 +1915,7 @@ impl Event {
         };
         for entrant in entrants {
             match entrant {
-                Entrant::MidosHouseTeam(team) => for (member, role) in team.members_roles(&mut *transaction).await? {
+                Entrant::MidosHouseTeam(team) => for (member, role) in team.members_roles_for_race(&mut *transaction, self.race.id).await? {
                     if event.team_config.role_is_racing(role) {
                         buf.push(if let Some(member) = member.racetime {
                             Ok(member.id)
@@ -2300,6 +2350,145 @@ pub(crate) async fn for_event(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &
     Ok(Response(cal))
 }
 
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum StartDateError {
+    #[error("Start date must be in format YYYY-MM-DD HH:MM")]
+    Format,
+    #[error("Invalid datetime for timezone {timezone}: {start_date}")]
+    NonexistentLocalTime {
+        timezone: Tz,
+        start_date: String,
+    },
+    #[error("Invalid timezone: {0}. Use format like America/New_York or Europe/London")]
+    Timezone(String),
+}
+
+impl StartDateError {
+    pub(crate) fn field(&self) -> &'static str {
+        match self {
+            Self::Format | Self::NonexistentLocalTime { .. } => "start_date",
+            Self::Timezone(_) => "timezone",
+        }
+    }
+}
+
+/// Parses a start date in `YYYY-MM-DD HH:MM` format in the given timezone, or in UTC if the timezone is empty.
+pub(crate) fn parse_start_date(start_date: &str, timezone: &str) -> Result<DateTime<Utc>, StartDateError> {
+    let naive_datetime = NaiveDateTime::parse_from_str(start_date.trim(), "%Y-%m-%d %H:%M").map_err(|_| StartDateError::Format)?;
+    if timezone.is_empty() {
+        Ok(DateTime::<Utc>::from_naive_utc_and_offset(naive_datetime, Utc))
+    } else {
+        let tz = timezone.parse::<Tz>().map_err(|_| StartDateError::Timezone(timezone.to_owned()))?;
+        match tz.from_local_datetime(&naive_datetime) {
+            LocalResult::Single(dt) => Ok(dt.with_timezone(&Utc)),
+            LocalResult::Ambiguous(dt1, _) => Ok(dt1.with_timezone(&Utc)),
+            LocalResult::None => Err(StartDateError::NonexistentLocalTime { timezone: tz, start_date: start_date.to_owned() }),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum NewRaceTeamError {
+    #[error("There is no team with this ID.")]
+    UnknownTeam,
+    #[error("This team is for a different event.")]
+    WrongEvent,
+    #[error("Can't choose the same team twice.")]
+    Duplicate,
+}
+
+/// Checks that a team chosen for a new race exists, belongs to the event, and isn't one of the teams already chosen for the race.
+pub(crate) fn check_new_race_team(event: &event::Data<'_>, team: Option<&Team>, other_teams: &[&Team]) -> Result<(), NewRaceTeamError> {
+    let team = team.ok_or(NewRaceTeamError::UnknownTeam)?;
+    if team.series != event.series || team.event != event.event {
+        return Err(NewRaceTeamError::WrongEvent)
+    }
+    if other_teams.iter().any(|other| other.id == team.id) {
+        return Err(NewRaceTeamError::Duplicate)
+    }
+    Ok(())
+}
+
+/// Creates the races of a match between 2 or 3 teams, sharing a single scheduling thread.
+///
+/// `games` has the game number (if the match has more than one game) and optional start time for each race.
+pub(crate) async fn create_match<'a>(mut transaction: Transaction<'a, Postgres>, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, event: &event::Data<'_>, teams: &[Team], phase: Option<String>, round: Option<String>, games: &[(Option<i16>, Option<DateTime<Utc>>)]) -> Result<Transaction<'a, Postgres>, event::Error> {
+    let entrants = match teams {
+        [team1, team2] => Entrants::Two([
+            Entrant::MidosHouseTeam(team1.clone()),
+            Entrant::MidosHouseTeam(team2.clone()),
+        ]),
+        [team1, team2, team3] => Entrants::Three([
+            Entrant::MidosHouseTeam(team1.clone()),
+            Entrant::MidosHouseTeam(team2.clone()),
+            Entrant::MidosHouseTeam(team3.clone()),
+        ]),
+        _ => unreachable!("match must have 2 or 3 teams"),
+    };
+    let draft = if let ([team1, team2], Some(draft_kind)) = (teams, event.draft_kind()) {
+        Some(Draft::for_game1(&mut transaction, http_client, draft_kind, event, phase.as_deref(), [team1, team2]).await?)
+    } else {
+        None
+    };
+    let phase_deadline = if let Some(ref round) = round {
+        sqlx::query_scalar!(
+            "SELECT scheduling_deadline FROM event_round_configs WHERE series = $1 AND event = $2 AND round = $3",
+            event.series as _, &event.event, round
+        ).fetch_optional(&mut *transaction).await?.flatten()
+    } else {
+        None
+    };
+    let game_count = games.len().try_into().unwrap_or(i16::MAX);
+    let mut scheduling_thread = None;
+    for (idx, &(game, start)) in games.iter().enumerate() {
+        let mut race = Race {
+            id: Id::<Races>::new(&mut transaction).await?,
+            series: event.series,
+            event: event.event.to_string(),
+            source: Source::Manual,
+            entrants: entrants.clone(),
+            phase: phase.clone(),
+            round: round.clone(),
+            schedule: if let Some(start) = start {
+                RaceSchedule::Live { start, end: None, room: None }
+            } else {
+                RaceSchedule::Unscheduled
+            },
+            schedule_updated_at: start.map(|_| Utc::now()),
+            fpa_invoked: false,
+            breaks_used: false,
+            draft: draft.clone(),
+            seed: seed::Data::default(),
+            video_urls: HashMap::default(),
+            restreamers: HashMap::default(),
+            last_edited_by: None,
+            last_edited_at: None,
+            ignored: false,
+            schedule_locked: false,
+            notified: false,
+            async_notified_1: false,
+            async_notified_2: false,
+            async_notified_3: false,
+            discord_scheduled_event_id: None,
+            volunteer_request_sent: false,
+            volunteer_request_message_id: None,
+            scheduling_deadline: phase_deadline,
+            restream_consent_required: false,
+            custom_title: None,
+            custom_create_room: true,
+            companion_race_id: None,
+            scheduling_thread,
+            game,
+        };
+        if idx == 0 {
+            transaction = discord_bot::create_scheduling_thread(discord_ctx, transaction, &mut race, game_count).await?;
+            scheduling_thread = race.scheduling_thread;
+        }
+        race.save(&mut transaction).await?;
+    }
+    Ok(transaction)
+}
+
 pub(crate) async fn create_race_form(mut transaction: Transaction<'_, Postgres>, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: event::Data<'_>, ctx: Context<'_>, is_3p: bool) -> Result<RawHtml<String>, event::Error> {
     let header = event.header(&mut transaction, me.as_ref(), Tab::Races, true).await?;
     let form = if me.is_some() {
@@ -2479,27 +2668,10 @@ pub(crate) async fn create_race_post(pool: &State<PgPool>, discord_ctx: &State<R
                 form.context.push_error(form::Error::validation("Custom races need a start date.").with_name("start_date"));
                 None
             } else {
-                match NaiveDateTime::parse_from_str(&value.start_date, "%Y-%m-%d %H:%M") {
-                    Ok(naive_datetime) => if value.timezone.is_empty() {
-                        Some(DateTime::<Utc>::from_naive_utc_and_offset(naive_datetime, Utc))
-                    } else {
-                        match value.timezone.parse::<Tz>() {
-                            Ok(tz) => match tz.from_local_datetime(&naive_datetime) {
-                                LocalResult::Single(dt) => Some(dt.with_timezone(&Utc)),
-                                LocalResult::Ambiguous(dt1, _) => Some(dt1.with_timezone(&Utc)),
-                                LocalResult::None => {
-                                    form.context.push_error(form::Error::validation(format!("Invalid datetime for timezone {}: {}", value.timezone, value.start_date)).with_name("start_date"));
-                                    None
-                                }
-                            },
-                            Err(_) => {
-                                form.context.push_error(form::Error::validation(format!("Invalid timezone: {}. Use format like America/New_York or Europe/London", value.timezone)).with_name("timezone"));
-                                None
-                            }
-                        }
-                    },
-                    Err(_) => {
-                        form.context.push_error(form::Error::validation("Start date must be in format YYYY-MM-DD HH:MM").with_name("start_date"));
+                match parse_start_date(&value.start_date, &value.timezone) {
+                    Ok(start) => Some(start),
+                    Err(e) => {
+                        form.context.push_error(form::Error::validation(e.to_string()).with_name(e.field()));
                         None
                     }
                 }
@@ -2548,48 +2720,32 @@ pub(crate) async fn create_race_post(pool: &State<PgPool>, discord_ctx: &State<R
             };
             race.save(&mut transaction).await?;
             transaction.commit().await?;
-            return Ok(RedirectOrContent::Redirect(Redirect::to(uri!(event::races(event.series, &*event.event)))))
+            return Ok(RedirectOrContent::Redirect(Redirect::to(uri!(event::races(event.series, &*event.event, _)))))
         }
         let team1 = if let Some(team1) = value.team1 {
-            Team::from_id(&mut transaction, team1).await?
+            let team1 = Team::from_id(&mut transaction, team1).await?;
+            if let Err(e) = check_new_race_team(&event, team1.as_ref(), &[]) {
+                form.context.push_error(form::Error::validation(e.to_string()).with_name("team1"));
+            }
+            team1
         } else {
             form.context.push_error(form::Error::validation("Please choose a team.").with_name("team1"));
             None
         };
-        if let Some(team1) = &team1 {
-            if team1.series != event.series || team1.event != event.event {
-                form.context.push_error(form::Error::validation("This team is for a different event.").with_name("team1"));
-            }
-        } else {
-            form.context.push_error(form::Error::validation("There is no team with this ID.").with_name("team1"));
-        }
         let team2 = if let Some(team2) = value.team2 {
-            Team::from_id(&mut transaction, team2).await?
+            let team2 = Team::from_id(&mut transaction, team2).await?;
+            if let Err(e) = check_new_race_team(&event, team2.as_ref(), &team1.iter().collect_vec()) {
+                form.context.push_error(form::Error::validation(e.to_string()).with_name("team2"));
+            }
+            team2
         } else {
             form.context.push_error(form::Error::validation("Please choose a team.").with_name("team2"));
             None
         };
-        if let Some(team2) = &team2 {
-            if team2.series != event.series || team2.event != event.event {
-                form.context.push_error(form::Error::validation("This team is for a different event.").with_name("team2"));
-            }
-        } else {
-            form.context.push_error(form::Error::validation("There is no team with this ID.").with_name("team2"));
-        }
-        if team1 == team2 {
-            form.context.push_error(form::Error::validation("Can't choose the same team twice.").with_name("team2"));
-        }
         let team3 = if let Some(team3) = value.team3 {
             let team3 = Team::from_id(&mut transaction, team3).await?;
-            if let Some(team3) = &team3 {
-                if team3.series != event.series || team3.event != event.event {
-                    form.context.push_error(form::Error::validation("This team is for a different event.").with_name("team3"));
-                }
-            } else {
-                form.context.push_error(form::Error::validation("There is no team with this ID.").with_name("team3"));
-            }
-            if team1 == team3 || team2 == team3 {
-                form.context.push_error(form::Error::validation("Can't choose the same team twice.").with_name("team3"));
+            if let Err(e) = check_new_race_team(&event, team3.as_ref(), &team1.iter().chain(&team2).collect_vec()) {
+                form.context.push_error(form::Error::validation(e.to_string()).with_name("team3"));
             }
             team3
         } else {
@@ -2610,78 +2766,11 @@ pub(crate) async fn create_race_post(pool: &State<PgPool>, discord_ctx: &State<R
                     .map(|row| (Some(row.phase), Some(row.round)))
                     .unwrap_or_else(|| (None, Some(value.phase_round.clone())))
             };
-            let [team1, team2] = [team1, team2].map(|team| team.expect("validated"));
-            let draft = if team3.is_some() {
-                None
-            } else if let Some(draft_kind) = event.draft_kind() {
-                Some(Draft::for_game1(&mut transaction, http_client, draft_kind, &event, phase.as_deref(), [&team1, &team2]).await?)
-            } else {
-                None
-            };
-            let phase_deadline = if let Some(ref round) = round {
-                sqlx::query_scalar!(
-                    "SELECT scheduling_deadline FROM event_round_configs WHERE series = $1 AND event = $2 AND round = $3",
-                    event.series as _, &event.event, round
-                ).fetch_optional(&mut *transaction).await?.flatten()
-            } else {
-                None
-            };
-            let mut scheduling_thread = None;
-            for game in 1..=value.game_count {
-                let mut race = Race {
-                    id: Id::<Races>::new(&mut transaction).await?,
-                    series: event.series,
-                    event: event.event.to_string(),
-                    source: Source::Manual,
-                    entrants: if let Some(ref team3) = team3 {
-                        Entrants::Three([
-                            Entrant::MidosHouseTeam(team1.clone()),
-                            Entrant::MidosHouseTeam(team2.clone()),
-                            Entrant::MidosHouseTeam(team3.clone()),
-                        ])
-                    } else {
-                        Entrants::Two([
-                            Entrant::MidosHouseTeam(team1.clone()),
-                            Entrant::MidosHouseTeam(team2.clone()),
-                        ])
-                    },
-                    phase: phase.clone(),
-                    round: round.clone(),
-                    game: (value.game_count > 1).then_some(game),
-                    schedule: RaceSchedule::Unscheduled,
-                    schedule_updated_at: None,
-                    fpa_invoked: false,
-                    breaks_used: false,
-                    draft: draft.clone(),
-                    seed: seed::Data::default(),
-                    video_urls: HashMap::default(),
-                    restreamers: HashMap::default(),
-                    last_edited_by: None,
-                    last_edited_at: None,
-                    ignored: false,
-                    schedule_locked: false,
-                    notified: false,
-                    async_notified_1: false,
-                    async_notified_2: false,
-                    async_notified_3: false,
-                    discord_scheduled_event_id: None,
-                    volunteer_request_sent: false,
-                    volunteer_request_message_id: None,
-                    scheduling_deadline: phase_deadline,
-                    restream_consent_required: false,
-                    custom_title: None,
-                    custom_create_room: true,
-                    companion_race_id: None,
-                    scheduling_thread,
-                };
-                if game == 1 {
-                    transaction = discord_bot::create_scheduling_thread(&*discord_ctx.read().await, transaction, &mut race, value.game_count).await?;
-                    scheduling_thread = race.scheduling_thread;
-                }
-                race.save(&mut transaction).await?;
-            }
+            let teams = [team1, team2].into_iter().map(|team| team.expect("validated")).chain(team3).collect_vec();
+            let games = (1..=value.game_count).map(|game| ((value.game_count > 1).then_some(game), None)).collect_vec();
+            transaction = create_match(transaction, &*discord_ctx.read().await, http_client, &event, &teams, phase, round, &games).await?;
             transaction.commit().await?;
-            RedirectOrContent::Redirect(Redirect::to(uri!(event::races(event.series, &*event.event))))
+            RedirectOrContent::Redirect(Redirect::to(uri!(event::races(event.series, &*event.event, _))))
         }
     } else {
         let is_3p = form.context.field_value("team3").is_some();
@@ -3493,9 +3582,48 @@ pub(crate) type RaceImportJobs = Arc<tokio::sync::RwLock<HashMap<Uuid, RaceImpor
 pub(crate) async fn import_races_form(mut transaction: Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &DiscordCtx, config: &Config, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: event::Data<'_>, ctx: Context<'_>) -> Result<RawHtml<String>, event::Error> {
     let header = event.header(&mut transaction, me.as_ref(), Tab::Races, true).await?;
     let form = match event.match_source() {
-        MatchSource::Manual => html! {
+        MatchSource::Manual => {
+            let has_sheet_import = event::sheet_import::Config::for_event(&mut transaction, event.series, &event.event).await?.is_some();
+            let has_ics_import = event::ics_import::Config::for_event(&mut transaction, event.series, &event.event).await?.is_some();
+            if has_sheet_import || has_ics_import {
+                html! {
+                    article {
+                        p {
+                            @if event.auto_import {
+                                : "Races for this event are imported automatically from the configured sources:";
+                            } else {
+                                : "Automatic imports are disabled for this event. Races will be imported from these sources once they are enabled:";
+                            }
+                        }
+                        ul {
+                            @if has_sheet_import {
+                                li {
+                                    a(href = uri!(event::sheet_import::get(event.series, &*event.event))) : "Sheet import";
+                                }
+                            }
+                            @if has_ics_import {
+                                li {
+                                    a(href = uri!(event::ics_import::get(event.series, &*event.event))) : "iCalendar import";
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                html! {
+                    article {
+                        p : "This event has no source for importing races configured.";
+                    }
+                }
+            }
+        }
+        MatchSource::Bracket => html! {
             article {
-                p : "This event has no source for importing races configured.";
+                p {
+                    : "Races for this event are created automatically from the ";
+                    a(href = uri!(event::bracket::get(event.series, &*event.event))) : "bracket";
+                    : ".";
+                }
             }
         },
         MatchSource::Challonge { community, tournament } => if me.is_some() {
@@ -3672,7 +3800,11 @@ pub(crate) async fn import_races_post(discord_ctx: &State<RwFuture<DiscordCtx>>,
     Ok(if let Some(ref value) = form.value {
         let races = match event.match_source() {
             MatchSource::Manual => {
-                form.context.push_error(form::Error::validation("This event has no source for importing races configured."));
+                form.context.push_error(form::Error::validation("This event has no source for manually importing races configured. Sheet and iCalendar imports run automatically."));
+                Vec::default()
+            }
+            MatchSource::Bracket => {
+                form.context.push_error(form::Error::validation("Races for this event are created automatically from the bracket."));
                 Vec::default()
             }
             MatchSource::Challonge { community, tournament } => {
@@ -3793,7 +3925,7 @@ pub(crate) async fn import_races_status(pool: &State<PgPool>, race_import_jobs:
                     }
                     p : "You can try importing again — matches that already imported successfully will be skipped.";
                 }
-                a(href = uri!(event::races(series, event)).to_string()) : "Back to races";
+                a(href = uri!(event::races(series, event, _)).to_string()) : "Back to races";
             }
         }
     } else {
@@ -3815,8 +3947,9 @@ pub(crate) async fn import_races_status(pool: &State<PgPool>, race_import_jobs:
 /// this match is saved. This keeps a failure partway through a larger batch from rolling back
 /// matches that already imported successfully, and lets already-imported matches be visible
 /// (and usable by race room commands) without waiting for the rest of the batch.
-async fn import_race(pool: &PgPool, discord_ctx: &DiscordCtx, race: Race) -> Result<(), event::Error> {
+pub(crate) async fn import_race(pool: &PgPool, discord_ctx: &DiscordCtx, race: Race) -> Result<(), event::Error> {
     let mut transaction = pool.begin().await?;
+    set_change_context(&mut transaction, None, ChangeSource::Import).await?;
     // Race discovery happens before background manual jobs acquire race_import_lock. Recheck under
     // that lock before creating the Discord thread so a queued job cannot import a stale result.
     let already_imported = match &race.source {
@@ -3828,6 +3961,18 @@ async fn import_race(pool: &PgPool, discord_ctx: &DiscordCtx, race: Race) -> Res
             .bind(set)
             .fetch_one(&mut *transaction)
             .await?,
+        Source::SheetRow { key } => sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM races WHERE series = $1 AND event = $2 AND sheet_key = $3)")
+            .bind(race.series)
+            .bind(&race.event)
+            .bind(key)
+            .fetch_one(&mut *transaction)
+            .await?,
+        Source::Ics { uid } => sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM races WHERE series = $1 AND event = $2 AND ics_uid = $3)")
+            .bind(race.series)
+            .bind(&race.event)
+            .bind(uid)
+            .fetch_one(&mut *transaction)
+            .await?,
         Source::Manual | Source::League { .. } | Source::Sheet { .. } | Source::SpeedGaming { .. } => false,
     };
     if already_imported {
@@ -3854,13 +3999,112 @@ async fn import_race(pool: &PgPool, discord_ctx: &DiscordCtx, race: Race) -> Res
     Ok(())
 }
 
+/// Builds a race for a match read from an external schedule, to be created using [`import_race`].
+///
+/// `teams` must have 2 or 3 entries. A draft is only started for 2-team matches.
+fn imported_entrants(teams: Vec<Team>) -> Entrants {
+    match <[_; 2]>::try_from(teams) {
+        Ok([team1, team2]) => Entrants::Two([Entrant::MidosHouseTeam(team1), Entrant::MidosHouseTeam(team2)]),
+        Err(teams) => {
+            let [team1, team2, team3] = <[_; 3]>::try_from(teams).unwrap_or_else(|_| unreachable!("match must have 2 or 3 teams"));
+            Entrants::Three([Entrant::MidosHouseTeam(team1), Entrant::MidosHouseTeam(team2), Entrant::MidosHouseTeam(team3)])
+        }
+    }
+}
+
+async fn imported_draft(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, entrants: &Entrants) -> Result<Option<Draft>, Error> {
+    Ok(if let (Entrants::Two([Entrant::MidosHouseTeam(team1), Entrant::MidosHouseTeam(team2)]), Some(draft_kind)) = (entrants, event.draft_kind()) {
+        Some(Draft::for_game1(transaction, http_client, draft_kind, event, None, [team1, team2]).await?)
+    } else {
+        None
+    })
+}
+
+pub(crate) async fn new_imported_race(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, source: Source, teams: Vec<Team>, round: Option<String>, start: Option<DateTime<Utc>>, video_urls: HashMap<Language, Url>) -> Result<Race, Error> {
+    let entrants = imported_entrants(teams);
+    let draft = imported_draft(transaction, http_client, event, &entrants).await?;
+    let scheduling_deadline = if let Some(ref round) = round {
+        sqlx::query_scalar!(
+            "SELECT scheduling_deadline FROM event_round_configs WHERE series = $1 AND event = $2 AND round = $3",
+            event.series as _, &event.event, round
+        ).fetch_optional(&mut **transaction).await?.flatten()
+    } else {
+        None
+    };
+    Ok(Race {
+        id: Id::dummy(),
+        series: event.series,
+        event: event.event.to_string(),
+        phase: None,
+        game: None,
+        scheduling_thread: None,
+        schedule: if let Some(start) = start {
+            RaceSchedule::Live { start, end: None, room: None }
+        } else {
+            RaceSchedule::Unscheduled
+        },
+        schedule_updated_at: start.map(|_| Utc::now()),
+        fpa_invoked: false,
+        breaks_used: false,
+        seed: seed::Data::default(),
+        restreamers: HashMap::default(),
+        last_edited_by: None,
+        last_edited_at: None,
+        ignored: false,
+        schedule_locked: false,
+        notified: false,
+        async_notified_1: false,
+        async_notified_2: false,
+        async_notified_3: false,
+        discord_scheduled_event_id: None,
+        volunteer_request_sent: false,
+        volunteer_request_message_id: None,
+        restream_consent_required: false,
+        custom_title: None,
+        custom_create_room: true,
+        companion_race_id: None,
+        source, entrants, round, draft, scheduling_deadline, video_urls,
+    })
+}
+
+/// Applies changes from an external schedule to a race which was imported from it earlier, and returns whether anything changed.
+///
+/// A start time which is missing from the schedule doesn't unschedule the race, since it may have been scheduled some other way.
+/// If the entrants changed, the draft starts over for the new entrants.
+/// Callers should skip races whose schedule is locked or which already have a room.
+pub(crate) async fn update_imported_race(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>, race: &mut Race, teams: Vec<Team>, round: Option<String>, start: Option<DateTime<Utc>>, video_urls: HashMap<Language, Url>) -> Result<bool, Error> {
+    let mut changed = false;
+    let entrants = imported_entrants(teams);
+    if race.entrants != entrants {
+        race.draft = imported_draft(transaction, http_client, event, &entrants).await?;
+        race.entrants = entrants;
+        changed = true;
+    }
+    if race.round != round {
+        race.round = round;
+        changed = true;
+    }
+    if let Some(start) = start && !matches!(race.schedule, RaceSchedule::Live { start: old_start, .. } if old_start == start) {
+        race.schedule = RaceSchedule::Live { start, end: None, room: None };
+        race.schedule_updated_at = Some(Utc::now());
+        changed = true;
+    }
+    if race.video_urls != video_urls {
+        race.video_urls = video_urls;
+        changed = true;
+    }
+    Ok(changed)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum AutoImportError {
     #[error(transparent)] Calendar(#[from] Error),
     #[error(transparent)] Discord(#[from] discord_bot::Error),
     #[error(transparent)] Event(#[from] event::Error),
     #[error(transparent)] EventData(#[from] event::DataError),
+    #[error(transparent)] IcsImport(#[from] event::ics_import::Error),
     #[error(transparent)] Serenity(#[from] serenity::Error),
+    #[error(transparent)] SheetImport(#[from] event::sheet_import::Error),
     #[error(transparent)] Sql(#[from] sqlx::Error),
     #[error(transparent)] StartGG(#[from] startgg::Error),
     #[error(transparent)] Url(#[from] url::ParseError),
@@ -3876,7 +4120,9 @@ impl IsNetworkError for AutoImportError {
             Self::Discord(_) => false,
             Self::Event(e) => e.is_network_error(),
             Self::EventData(_) => false,
+            Self::IcsImport(e) => e.is_network_error(),
             Self::Serenity(_) => false,
+            Self::SheetImport(e) => e.is_network_error(),
             Self::Sql(_) => false,
             Self::StartGG(e) => e.is_network_error(),
             Self::Url(_) => false,
@@ -3886,6 +4132,88 @@ impl IsNetworkError for AutoImportError {
     }
 }
 
+/// A schedule import which can be configured for events with [`MatchSource::Manual`], see [`event::sheet_import`] and [`event::ics_import`].
+trait ScheduleImport: Sized {
+    type Error: fmt::Display + IsNetworkError + Into<AutoImportError>;
+
+    /// Describes the import in log messages.
+    const NAME: &'static str;
+
+    async fn load(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> Result<Option<Self>, Self::Error>;
+    /// Database errors abort the import loop, all other errors only skip the event.
+    fn is_sql_error(e: &Self::Error) -> bool;
+    async fn races_to_import(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>) -> Result<Vec<Race>, Self::Error>;
+}
+
+impl ScheduleImport for event::sheet_import::Config {
+    type Error = event::sheet_import::Error;
+
+    const NAME: &'static str = "sheet import";
+
+    async fn load(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> Result<Option<Self>, Self::Error> {
+        Self::for_event(transaction, series, event).await
+    }
+
+    fn is_sql_error(e: &Self::Error) -> bool {
+        matches!(e, event::sheet_import::Error::Sql(_))
+    }
+
+    async fn races_to_import(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>) -> Result<Vec<Race>, Self::Error> {
+        event::sheet_import::races_to_import(transaction, http_client, event, self).await
+    }
+}
+
+impl ScheduleImport for event::ics_import::Config {
+    type Error = event::ics_import::Error;
+
+    const NAME: &'static str = "iCalendar import";
+
+    async fn load(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> Result<Option<Self>, Self::Error> {
+        Self::for_event(transaction, series, event).await
+    }
+
+    fn is_sql_error(e: &Self::Error) -> bool {
+        matches!(e, event::ics_import::Error::Sql(_))
+    }
+
+    async fn races_to_import(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &event::Data<'_>) -> Result<Vec<Race>, Self::Error> {
+        event::ics_import::races_to_import(transaction, http_client, event, self).await
+    }
+}
+
+/// Runs the given schedule import for the event if it's configured.
+///
+/// If it is, the transaction is committed or rolled back and a new one is returned.
+async fn auto_import_schedule<'a, I: ScheduleImport>(db_pool: &'a PgPool, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, new_room_lock: &Arc<Mutex<()>>, mut transaction: Transaction<'a, Postgres>, event: &event::Data<'_>) -> Result<Transaction<'a, Postgres>, AutoImportError> {
+    let import = match I::load(&mut transaction, event.series, &event.event).await {
+        Ok(import) => import,
+        Err(e) if I::is_sql_error(&e) => return Err(e.into()),
+        Err(e) => {
+            log::warn!("skipping {} for {}/{}: {e}", I::NAME, event.series.slug(), &event.event);
+            None
+        }
+    };
+    let Some(import) = import else { return Ok(transaction) };
+    lock!(new_room_lock = new_room_lock; {
+        match import.races_to_import(&mut transaction, http_client, event).await {
+            Ok(races) => {
+                transaction.commit().await?;
+                for race in races {
+                    import_race(db_pool, &*discord_ctx.read().await, race).await?;
+                }
+            }
+            Err(e) => {
+                transaction.rollback().await?;
+                if e.is_network_error() { return Err(e.into()) }
+                log::warn!("skipping {} for {}/{}: {e}", I::NAME, event.series.slug(), &event.event);
+            }
+        }
+    });
+    let mut transaction = db_pool.begin().await?;
+    set_change_context(&mut transaction, None, ChangeSource::Import).await?;
+    Ok(transaction)
+}
+
 async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client, config: Config, mut shutdown: rocket::Shutdown, discord_ctx: RwFuture<DiscordCtx>, new_room_lock: Arc<Mutex<()>>, race_import_lock: Arc<Mutex<()>>) -> Result<(), AutoImportError> {
     loop {
         // Refresh potentially slow, rate-limited start.gg pages before serializing imports. This
@@ -3912,6 +4240,7 @@ async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client,
 
         lock!(race_import_lock = race_import_lock; {
             let mut transaction = db_pool.begin().await?;
+            set_change_context(&mut transaction, None, ChangeSource::Import).await?;
             for row in sqlx::query!(
                 r#"SELECT series, event FROM events WHERE (auto_import OR $1) AND (end_time IS NULL OR end_time > NOW())"#,
                 speedgaming_export::LEGACY_IMPORT_ENABLED,
@@ -3926,7 +4255,11 @@ async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client,
                 let event = event::Data::new(&mut transaction, series, row.event).await?.expect("event deleted during transaction");
                 if event.auto_import {
                     match event.match_source() {
-                        MatchSource::Manual => {}
+                        MatchSource::Manual => {
+                            transaction = auto_import_schedule::<event::sheet_import::Config>(&db_pool, &http_client, &discord_ctx, &new_room_lock, transaction, &event).await?;
+                            transaction = auto_import_schedule::<event::ics_import::Config>(&db_pool, &http_client, &discord_ctx, &new_room_lock, transaction, &event).await?;
+                        }
+                        MatchSource::Bracket => {}
                         MatchSource::Challonge { .. } => {} // Challonge's API doesn't provide enough data to automate race imports
                         MatchSource::League => if event.is_started(&mut transaction).await? {
                             lock!(new_room_lock = new_room_lock; {
@@ -4023,6 +4356,7 @@ async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client,
                             transaction.commit().await?;
                             });
                             transaction = db_pool.begin().await?;
+                            set_change_context(&mut transaction, None, ChangeSource::Import).await?;
                         },
                         MatchSource::StartGG(event_slug) => loop {
                             let import_started_at = Instant::now();
@@ -4075,6 +4409,7 @@ async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client,
                                         sqlx::query!("UPDATE teams SET startgg_id = $1 WHERE id = $2", entrant as _, team.id as _).execute(&mut *transaction).await?;
                                         transaction.commit().await?;
                                         transaction = db_pool.begin().await?;
+                                        set_change_context(&mut transaction, None, ChangeSource::Import).await?;
                                         None
                                     };
                                     if let Some(msg) = notification_msg {
@@ -4217,6 +4552,7 @@ async fn auto_import_races_inner(db_pool: PgPool, http_client: reqwest::Client,
                     transaction.commit().await?;
                     });
                     transaction = db_pool.begin().await?;
+                    set_change_context(&mut transaction, None, ChangeSource::Import).await?;
                 }
             }
             transaction.commit().await?;
@@ -4774,6 +5110,46 @@ pub(crate) async fn edit_race_form(mut transaction: Transaction<'_, Postgres>, d
             }
         }
     };
+    let history = if me.is_some() {
+        race_history(&mut transaction, &event, &race, is_organizer || is_admin, csrf).await?
+    } else {
+        RawHtml(String::default())
+    };
+    let forfeit = if can_edit_race_room && !race.is_ended() && race.teams().count() >= 2 {
+        let mut teams = Vec::default();
+        for team in race.teams() {
+            teams.push((team.id, team.name(&mut transaction).await?.map_or_else(|| format!("(unnamed team)"), Cow::into_owned)));
+        }
+        html! {
+            h2 : "Record forfeit";
+            p : "Use this if an entrant didn't show up, forfeited before the race, or was disqualified. The race will be marked as ended, the result will be announced and reported where applicable, and any open racetime.gg room will be cancelled.";
+            : full_form_confirm(uri!(forfeit_race(event.series, &*event.event, race.id)), csrf, html! {
+                : form_field("kind", &mut Vec::default(), html! {
+                    label(for = "kind") : "Kind:";
+                    select(name = "kind", id = "kind") {
+                        @for kind in all::<ForfeitKind>() {
+                            option(value = kind.as_str(), selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("kind")).is_some_and(|value| value == kind.as_str())) : kind.display_name();
+                        }
+                    }
+                });
+                : form_field("winner", &mut Vec::default(), html! {
+                    label(for = "winner") : "Winner:";
+                    select(name = "winner", id = "winner") {
+                        option(value = "") : "(none, for a double forfeit)";
+                        @for (id, name) in &teams {
+                            option(value = id.to_string(), selected? = ctx.as_ref().and_then(|ctx| ctx.field_value("winner")).is_some_and(|value| value == id.to_string())) : name;
+                        }
+                    }
+                });
+                : form_field("reason", &mut Vec::default(), html! {
+                    label(for = "reason") : "Reason (optional):";
+                    input(type = "text", name = "reason", id = "reason", value? = ctx.as_ref().and_then(|ctx| ctx.field_value("reason")));
+                });
+            }, Vec::default(), "Record forfeit", "This will end the race and report the result. Are you sure?")
+        }
+    } else {
+        RawHtml(String::default())
+    };
     let content = html! {
         : header;
         h2 : "Edit race";
@@ -4802,6 +5178,14 @@ pub(crate) async fn edit_race_form(mut transaction: Transaction<'_, Postgres>, d
                 : timestamp.format("%d/%m/%Y %H:%M:%S").to_string();
                 : " (unknown time zone)";
             }
+            Source::SheetRow { key } => p {
+                : "Google sheet row: ";
+                : key;
+            }
+            Source::Ics { uid } => p {
+                : "iCalendar event: ";
+                : uid;
+            }
             Source::StartGG { event, set: startgg::ID(set) } => {
                 p {
                     : "start.gg event: ";
@@ -5079,6 +5463,8 @@ pub(crate) async fn edit_race_form(mut transaction: Transaction<'_, Postgres>, d
             }
         }
         : form;
+        : forfeit;
+        : history;
         script(src = static_url!("restream-autocomplete.js")) {}
     };
     Ok(page(transaction, &me, &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Edit Race — {}", event.display_name), content).await?)
@@ -5145,26 +5531,584 @@ pub(crate) async fn edit_race(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &
     Ok(RedirectOrContent::Content(edit_race_form(transaction, &*discord_ctx.read().await, me, uri, csrf.as_ref(), event, race, redirect_to, None).await?))
 }
 
-#[derive(FromForm, CsrfForm)]
-pub(crate) struct EditRaceForm {
-    #[field(default = String::new())]
-    csrf: String,
-    #[field(default = String::new())]
-    room: String,
-    #[field(default = String::new())]
-    async_room1: String,
-    #[field(default = String::new())]
-    async_room2: String,
-    #[field(default = String::new())]
-    async_room3: String,
-    #[field(default = HashMap::new())]
-    video_urls: HashMap<Language, String>,
-    #[field(default = HashMap::new())]
-    restreamers: HashMap<Language, String>,
-    #[field(default = false)]
-    is_canceled: bool,
-    #[field(default = String::new())]
-    start_date: String,
+/// Where a change recorded in the race audit log was made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "race_change_source", rename_all = "snake_case")]
+pub(crate) enum ChangeSource {
+    Web,
+    Discord,
+    Api,
+    Import,
+    RaceBot,
+    System,
+}
+
+impl ChangeSource {
+    fn description(&self) -> &'static str {
+        match self {
+            Self::Web => "website",
+            Self::Discord => "Discord",
+            Self::Api => "API",
+            Self::Import => "automatic import",
+            Self::RaceBot => "racetime.gg bot",
+            Self::System => "system",
+        }
+    }
+}
+
+/// Attributes changes to races made later in this transaction.
+///
+/// Changes to the columns of the `races` table are recorded in the audit log by a database trigger.
+/// Changes made in a transaction where this hasn't been called are attributed to [`ChangeSource::System`].
+pub(crate) async fn set_change_context(transaction: &mut Transaction<'_, Postgres>, actor: Option<Id<Users>>, source: ChangeSource) -> sqlx::Result<()> {
+    sqlx::query!(
+        "SELECT set_config('midos_house.change_actor', COALESCE($1::bigint::text, ''), TRUE), set_config('midos_house.change_source', $2::race_change_source::text, TRUE)",
+        actor as _,
+        source as _,
+    ).fetch_one(&mut **transaction).await?;
+    Ok(())
+}
+
+/// Records a change to a race in the audit log.
+///
+/// Only needed for changes that aren't stored in the `races` table, see [`set_change_context`].
+pub(crate) async fn log_change(transaction: &mut Transaction<'_, Postgres>, race: Id<Races>, actor: Option<Id<Users>>, source: ChangeSource, field: &str, old_value: Option<String>, new_value: Option<String>) -> sqlx::Result<()> {
+    sqlx::query!(
+        "INSERT INTO race_audit_log (race, actor, source, field, old_value, new_value, version) VALUES ($1, $2, $3, $4, $5, $6, race_change_version($1))",
+        race as _,
+        actor as _,
+        source as _,
+        field,
+        old_value,
+        new_value,
+    ).execute(&mut **transaction).await?;
+    Ok(())
+}
+
+/// How a race was decided without being played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Sequence, FromFormField, sqlx::Type)]
+#[sqlx(type_name = "forfeit_kind", rename_all = "snake_case")]
+pub(crate) enum ForfeitKind {
+    /// The other entrants didn't show up or forfeited before the race.
+    Walkover,
+    /// The other entrants were disqualified.
+    Disqualification,
+    /// None of the entrants played the race, so there is no winner.
+    #[field(value = "double_forfeit")]
+    DoubleForfeit,
+}
+
+impl ForfeitKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Walkover => "walkover",
+            Self::Disqualification => "disqualification",
+            Self::DoubleForfeit => "double_forfeit",
+        }
+    }
+
+    pub(crate) fn display_name(&self) -> &'static str {
+        match self {
+            Self::Walkover => "walkover",
+            Self::Disqualification => "disqualification",
+            Self::DoubleForfeit => "double forfeit",
+        }
+    }
+
+    fn has_winner(&self) -> bool {
+        match self {
+            Self::Walkover | Self::Disqualification => true,
+            Self::DoubleForfeit => false,
+        }
+    }
+}
+
+/// Records that a race was decided without being played, and marks the race as having ended now.
+///
+/// Announcing and reporting the result is done separately using [`racetime_bot::report::report_forfeit`].
+pub(crate) async fn record_forfeit(transaction: &mut Transaction<'_, Postgres>, race: &mut Race, actor: Id<Users>, source: ChangeSource, kind: ForfeitKind, winner: Option<&Team>, reason: Option<&str>) -> sqlx::Result<()> {
+    let now = Utc::now();
+    set_change_context(&mut *transaction, Some(actor), source).await?;
+    let old = sqlx::query!(r#"SELECT kind AS "kind: ForfeitKind", winner AS "winner: Id<Teams>" FROM race_walkovers WHERE race = $1"#, race.id as _).fetch_optional(&mut **transaction).await?;
+    sqlx::query!("
+        INSERT INTO race_walkovers (race, kind, winner, reason, recorded_by) VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (race) DO UPDATE SET kind = EXCLUDED.kind, winner = EXCLUDED.winner, reason = EXCLUDED.reason, recorded_by = EXCLUDED.recorded_by, recorded_at = NOW()
+    ", race.id as _, kind as _, winner.map(|team| team.id) as _, reason, actor as _).execute(&mut **transaction).await?;
+    log_change(&mut *transaction, race.id, Some(actor), source, "forfeit", old.as_ref().map(|old| old.kind.display_name().to_owned()), Some(kind.display_name().to_owned())).await?;
+    log_change(&mut *transaction, race.id, Some(actor), source, "walkover", old.and_then(|old| old.winner).map(|team| team.to_string()), winner.map(|team| team.id.to_string())).await?;
+    let has_third_part = matches!(race.entrants, Entrants::Three(_));
+    race.schedule = match race.schedule {
+        RaceSchedule::Unscheduled => RaceSchedule::Live { start: now, end: Some(now), room: None },
+        RaceSchedule::Live { start, end, ref room } => RaceSchedule::Live { start: start.min(now), end: Some(end.unwrap_or(now)), room: room.clone() },
+        RaceSchedule::Async { start1, start2, start3, end1, end2, end3, ref room1, ref room2, ref room3 } => RaceSchedule::Async {
+            start1: Some(start1.map_or(now, |start| start.min(now))),
+            start2: Some(start2.map_or(now, |start| start.min(now))),
+            start3: if has_third_part { Some(start3.map_or(now, |start| start.min(now))) } else { start3 },
+            end1: Some(end1.unwrap_or(now)),
+            end2: Some(end2.unwrap_or(now)),
+            end3: if has_third_part { Some(end3.unwrap_or(now)) } else { end3 },
+            room1: room1.clone(),
+            room2: room2.clone(),
+            room3: room3.clone(),
+        },
+    };
+    race.last_edited_by = Some(actor);
+    race.last_edited_at = Some(now);
+    race.save(transaction).await
+}
+
+/// Race fields which organizers can roll back to an earlier value from the race's change history.
+const REVERTIBLE_FIELDS: [&str; 25] = [
+    "start", "async_start1", "async_start2", "async_start3",
+    "team1", "team2", "team3", "p1", "p2", "p3",
+    "phase", "round", "game", "custom_title",
+    "video_url", "video_url_fr", "video_url_de", "video_url_pt", "restreamer", "restreamer_fr", "restreamer_de", "restreamer_pt",
+    "ignored", "schedule_locked", "scheduling_deadline",
+];
+
+/// Race columns which together make up the entrants of a race.
+const ENTRANT_FIELDS: [&str; 15] = [
+    "team1", "team2", "team3", "p1", "p2", "p3",
+    "p1_discord", "p2_discord", "p3_discord", "p1_racetime", "p2_racetime", "p3_racetime", "p1_twitch", "p2_twitch", "p3_twitch",
+];
+
+fn change_field_label(field: &str) -> Cow<'static, str> {
+    Cow::Borrowed(match field {
+        "created" => "race created",
+        "start" => "start",
+        "async_start1" => "start (team A)",
+        "async_start2" => "start (team B)",
+        "async_start3" => "start (team C)",
+        "end_time" => "end",
+        "async_end1" => "end (team A)",
+        "async_end2" => "end (team B)",
+        "async_end3" => "end (team C)",
+        "room" => "racetime.gg room",
+        "async_room1" => "racetime.gg room (team A)",
+        "async_room2" => "racetime.gg room (team B)",
+        "async_room3" => "racetime.gg room (team C)",
+        "team1" | "p1" => "entrant 1",
+        "team2" | "p2" => "entrant 2",
+        "team3" | "p3" => "entrant 3",
+        "total" => "number of entrants",
+        "finished" => "number of finishers",
+        "game" => "game number",
+        "custom_title" => "title",
+        "video_url" | "video_url_en" => "restream (English)",
+        "video_url_fr" => "restream (French)",
+        "video_url_de" => "restream (German)",
+        "video_url_pt" => "restream (Portuguese)",
+        "restreamer" | "restreamer_en" => "restreamer (English)",
+        "restreamer_fr" => "restreamer (French)",
+        "restreamer_de" => "restreamer (German)",
+        "restreamer_pt" => "restreamer (Portuguese)",
+        "ignored" => "canceled",
+        "schedule_locked" => "schedule locked",
+        "scheduling_deadline" => "scheduling deadline",
+        "forfeit" => "result without race",
+        "walkover" => "walkover winner",
+        _ => return Cow::Owned(match field.split_once('_') {
+            Some((entrant @ ("p1" | "p2" | "p3"), "discord")) => format!("entrant {} Discord account", &entrant[1..]),
+            Some((entrant @ ("p1" | "p2" | "p3"), "racetime")) => format!("entrant {} racetime.gg account", &entrant[1..]),
+            Some((entrant @ ("p1" | "p2" | "p3"), "twitch")) => format!("entrant {} Twitch account", &entrant[1..]),
+            _ => field.replace('_', " "),
+        }),
+    })
+}
+
+async fn change_value_html(transaction: &mut Transaction<'_, Postgres>, field: &str, value: Option<&str>) -> sqlx::Result<RawHtml<String>> {
+    let Some(value) = value else { return Ok(html! { i : "(none)"; }) };
+    if matches!(field, "team1" | "team2" | "team3" | "walkover") && let Ok(id) = value.parse() && let Some(team) = Team::from_id(&mut *transaction, id).await? {
+        return team.to_html(&mut *transaction, false).await
+    }
+    Ok(if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        format_datetime(datetime, DateTimeFormat { long: false, running_text: false })
+    } else {
+        html! { : value; }
+    })
+}
+
+/// The change history panel on the race edit page, with buttons to roll back individual fields if `can_revert` is set.
+async fn race_history(transaction: &mut Transaction<'_, Postgres>, event: &event::Data<'_>, race: &Race, can_revert: bool, csrf: Option<&CsrfToken>) -> Result<RawHtml<String>, event::Error> {
+    let changes = sqlx::query!(r#"
+        SELECT id, actor AS "actor: Id<Users>", source AS "source: ChangeSource", field, old_value, new_value, version, created_at
+        FROM race_audit_log
+        WHERE race = $1
+        ORDER BY version DESC, id
+    "#, race.id as _).fetch_all(&mut **transaction).await?;
+    if changes.is_empty() {
+        return Ok(RawHtml(String::default()))
+    }
+    let version_sizes = changes.iter().counts_by(|change| change.version);
+    let mut actors = HashMap::<Id<Users>, Option<User>>::default();
+    let mut rows = Vec::with_capacity(changes.len());
+    let mut prev_version = None;
+    for change in &changes {
+        let version_cells = if prev_version == Some(change.version) {
+            None
+        } else {
+            prev_version = Some(change.version);
+            let actor = if let Some(actor) = change.actor {
+                if !actors.contains_key(&actor) {
+                    actors.insert(actor, User::from_id(&mut **transaction, actor).await?);
+                }
+                actors[&actor].clone()
+            } else {
+                None
+            };
+            Some((version_sizes[&change.version], actor))
+        };
+        let old_value = change_value_html(&mut *transaction, &change.field, change.old_value.as_deref()).await?;
+        let new_value = change_value_html(&mut *transaction, &change.field, change.new_value.as_deref()).await?;
+        let revert = (can_revert && REVERTIBLE_FIELDS.contains(&&*change.field)).then(|| {
+            let (errors, button) = button_form(uri!(revert_race_change(event.series, &*event.event, race.id, change.id)), csrf, Vec::default(), "Revert");
+            html! {
+                : errors;
+                : button;
+            }
+        });
+        rows.push((change, version_cells, old_value, new_value, revert));
+    }
+    Ok(html! {
+        h2 : "History";
+        table {
+            thead {
+                tr {
+                    th : "Version";
+                    th : "Time";
+                    th : "Changed by";
+                    th : "Field";
+                    th : "Old value";
+                    th : "New value";
+                    @if can_revert {
+                        th {}
+                    }
+                }
+            }
+            tbody {
+                @for (change, version_cells, old_value, new_value, revert) in rows {
+                    tr {
+                        @if let Some((rowspan, actor)) = version_cells {
+                            td(rowspan = rowspan) : change.version;
+                            td(rowspan = rowspan) : format_datetime(change.created_at, DateTimeFormat { long: false, running_text: false });
+                            td(rowspan = rowspan) {
+                                @if let Some(actor) = actor {
+                                    : actor;
+                                    : " via ";
+                                }
+                                : change.source.description();
+                            }
+                        }
+                        td : change_field_label(&change.field);
+                        td : old_value;
+                        td : new_value;
+                        @if can_revert {
+                            td {
+                                @if let Some(revert) = revert {
+                                    : revert;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Sets a field of a race back to the value it had before the given change, see [`REVERTIBLE_FIELDS`].
+///
+/// Changes to the entrants are rolled back together with the other entrant columns changed in the same version, see [`ENTRANT_FIELDS`].
+#[rocket::post("/event/<series>/<event>/races/<id>/history/<change>/revert", data = "<form>")]
+pub(crate) async fn revert_race_change(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, global_state: &State<Arc<racetime_bot::GlobalState>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, id: Id<Races>, change: i64, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = event::Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let race = Race::from_id(&mut transaction, http_client, id).await?;
+    let change = sqlx::query!("SELECT field, old_value, version FROM race_audit_log WHERE id = $1 AND race = $2", change, race.id as _).fetch_optional(&mut *transaction).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if form.value.is_some() {
+        if race.series != event.series || race.event != event.event {
+            form.context.push_error(form::Error::validation("This race is not part of this event."));
+        }
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to roll back changes to this race."));
+        }
+        let is_schedule_field = matches!(&*change.field, "start" | "async_start1" | "async_start2" | "async_start3");
+        if !REVERTIBLE_FIELDS.contains(&&*change.field) {
+            form.context.push_error(form::Error::validation("Changes to this field can't be rolled back."));
+        } else if is_schedule_field && race.has_any_room() {
+            form.context.push_error(form::Error::validation("The schedule of this race can't be rolled back since a racetime.gg room has already been opened."));
+        } else if change.old_value.is_some() && match (&*change.field, &race.schedule) {
+            ("start", RaceSchedule::Async { .. }) => true,
+            ("async_start1" | "async_start2" | "async_start3", RaceSchedule::Live { .. }) => true,
+            (_, _) => false,
+        } {
+            form.context.push_error(form::Error::validation("This change can't be rolled back since the race has been switched between a live and an async race since."));
+        }
+        let reverted_fields = if ENTRANT_FIELDS.contains(&&*change.field) {
+            sqlx::query!("SELECT field, old_value FROM race_audit_log WHERE race = $1 AND version = $2 AND field = ANY($3) ORDER BY id", race.id as _, change.version, &ENTRANT_FIELDS[..] as _)
+                .fetch_all(&mut *transaction).await?
+                .into_iter()
+                .map(|change| (change.field, change.old_value))
+                .collect_vec()
+        } else {
+            vec![(change.field, change.old_value)]
+        };
+        if race.is_ended() {
+            form.context.push_error(form::Error::validation("This race has already ended, so changes to it can't be rolled back."));
+        } else {
+            // both the current entrants and any entrants restored by the rollback must still be active
+            let team_ids = race.teams().map(|team| i64::from(team.id))
+                .chain(reverted_fields.iter().filter(|(field, _)| matches!(&**field, "team1" | "team2" | "team3")).filter_map(|(_, old_value)| old_value.as_deref()?.parse().ok()))
+                .collect_vec();
+            if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM teams WHERE id = ANY($1) AND resigned) AS "exists!""#, &team_ids).fetch_one(&mut *transaction).await? {
+                form.context.push_error(form::Error::validation("This change can't be rolled back since an entrant of this race has resigned."));
+            }
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(edit_race_form(transaction, &*discord_ctx.read().await, Some(me), uri, csrf.as_ref(), event, race, None, Some(form.context)).await?)
+        } else {
+            set_change_context(&mut transaction, Some(me.id), ChangeSource::Web).await?;
+            for (field, old_value) in &reverted_fields {
+                // the field name is from the fixed lists of revertible and entrant fields, so it's safe to use in the query
+                sqlx::query(&format!("UPDATE races SET {0} = (jsonb_populate_record(NULL::races, jsonb_build_object($1::text, $2::text))).{0} WHERE id = $3", field))
+                    .bind(field)
+                    .bind(old_value)
+                    .bind(race.id)
+                    .execute(&mut *transaction).await?;
+            }
+            // reload the race so the rolled back columns are applied the same way as an edit from the form
+            let old_race = race;
+            let mut race = Race::from_id(&mut transaction, http_client, id).await?;
+            if is_schedule_field {
+                race.schedule_updated_at = Some(Utc::now());
+            }
+            race.last_edited_by = Some(me.id);
+            race.last_edited_at = Some(Utc::now());
+            race.save(&mut transaction).await?;
+            finish_race_edit(transaction, discord_ctx, pool, http_client, global_state, &event, &old_race, race, true).await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(edit_race(series, &*event.event, id, _))))
+        }
+    } else {
+        RedirectOrContent::Content(edit_race_form(transaction, &*discord_ctx.read().await, Some(me), uri, csrf.as_ref(), event, race, None, Some(form.context)).await?)
+    })
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct ForfeitForm {
+    #[field(default = String::new())]
+    csrf: String,
+    kind: ForfeitKind,
+    winner: Option<Id<Teams>>,
+    #[field(default = String::new())]
+    reason: String,
+}
+
+/// Records a walkover, disqualification, or double forfeit, see [`record_forfeit`].
+#[rocket::post("/event/<series>/<event>/races/<id>/forfeit", data = "<form>")]
+pub(crate) async fn forfeit_race(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, global_state: &State<Arc<racetime_bot::GlobalState>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, id: Id<Races>, form: Form<Contextual<'_, ForfeitForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = event::Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut race = Race::from_id(&mut transaction, http_client, id).await?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if race.series != event.series || race.event != event.event {
+            form.context.push_error(form::Error::validation("This race is not part of this event."));
+        }
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to record a forfeit for this race."));
+        }
+        if race.is_ended() {
+            form.context.push_error(form::Error::validation("This race has already ended."));
+        }
+        if race.teams().count() < 2 {
+            form.context.push_error(form::Error::validation("Forfeits can only be recorded for races between teams."));
+        }
+        let winner = race.teams().find(|team| Some(team.id) == value.winner).cloned();
+        match (value.kind.has_winner(), value.winner) {
+            (true, None) => form.context.push_error(form::Error::validation("Please select the winner.").with_name("winner")),
+            (true, Some(_)) => if winner.is_none() {
+                form.context.push_error(form::Error::validation("The winner must be an entrant in this race.").with_name("winner"));
+            },
+            (false, Some(_)) => form.context.push_error(form::Error::validation("A double forfeit has no winner.").with_name("winner")),
+            (false, None) => {}
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(edit_race_form(transaction, &*discord_ctx.read().await, Some(me), uri, csrf.as_ref(), event, race, None, Some(form.context)).await?)
+        } else {
+            let reason = Some(value.reason.trim()).filter(|reason| !reason.is_empty());
+            record_forfeit(&mut transaction, &mut race, me.id, ChangeSource::Web, value.kind, winner.as_ref(), reason).await?;
+            racetime_bot::report::report_forfeit(transaction, global_state, &race, &event, value.kind, winner.as_ref(), reason).await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(edit_race(series, &*event.event, id, _))))
+        }
+    } else {
+        RedirectOrContent::Content(edit_race_form(transaction, &*discord_ctx.read().await, Some(me), uri, csrf.as_ref(), event, race, None, Some(form.context)).await?)
+    })
+}
+
+/// Sends a DM to each confirmed volunteer of a race that has just been canceled.
+pub(crate) async fn notify_race_canceled(transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, event: &event::Data<'_>, race: &Race) {
+    if let Ok(description) = race.notification_description(&mut *transaction).await {
+        let signups = Signup::for_race(&mut *transaction, race.id).await.unwrap_or_default();
+        for signup in signups.iter().filter(|s| matches!(s.status, VolunteerSignupStatus::Confirmed)) {
+            if let Ok(Some(user)) = User::from_id(&mut **transaction, signup.user_id).await {
+                if let Some(discord) = user.discord {
+                    let discord_user_id = UserId::new(discord.id.get());
+                    let mut msg = MessageBuilder::default();
+                    msg.push("**Race Canceled**\n\nThe race ");
+                    msg.push_mono(&description);
+                    msg.push(" in ");
+                    msg.push(&event.display_name);
+                    msg.push(" has been canceled.");
+                    if let Ok(dm) = discord_user_id.create_dm_channel(discord_ctx).await {
+                        let _ = dm.say(discord_ctx, msg.build()).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Propagates a change to a race's restream URL or restreamer made outside of the race edit form.
+///
+/// Updates the Discord scheduled event, notifies volunteers of new restream URLs, updates the volunteer info post, and triggers the ZSR volunteer API for a changed ZSR channel.
+pub(crate) async fn restream_updated(transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, pool: &PgPool, http_client: &reqwest::Client, event: &event::Data<'_>, old_video_urls: &HashMap<Language, Url>, race: &Race) {
+    if let Err(e) = crate::discord_scheduled_events::update_discord_scheduled_event(discord_ctx, transaction, race, event, http_client).await {
+        eprintln!("Failed to update Discord scheduled event for race {}: {}", race.id, e);
+    }
+    if let Err(e) = notify_restream_volunteers(transaction, discord_ctx, event, old_video_urls, race).await {
+        eprintln!("Failed to notify volunteers of restream change for race {}: {}", race.id, e);
+    }
+    refresh_restream_info(pool, discord_ctx, http_client, old_video_urls, race).await;
+}
+
+/// Sends DMs to confirmed volunteers whose language's restream URL was newly assigned or changed.
+pub(crate) async fn notify_restream_volunteers(transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, event: &event::Data<'_>, old_video_urls: &HashMap<Language, Url>, race: &Race) -> Result<(), event::Error> {
+    if !race.video_urls.is_empty() && race.video_urls != *old_video_urls {
+        let new_languages: Vec<_> = race.video_urls.keys()
+            .filter(|lang| !old_video_urls.contains_key(*lang))
+            .collect();
+        let changed_languages: Vec<_> = race.video_urls.keys()
+            .filter(|lang| old_video_urls.get(*lang).is_some_and(|old_url| old_url != race.video_urls.get(*lang).unwrap()))
+            .collect();
+
+        if !new_languages.is_empty() || !changed_languages.is_empty() {
+            let race_description = race.notification_description(&mut *transaction).await?;
+
+            if let RaceSchedule::Live { start: race_start_time, .. } = race.schedule {
+                let signups = Signup::for_race(&mut *transaction, race.id).await?;
+                let role_bindings = EffectiveRoleBinding::for_event(&mut *transaction, event.series, &event.event).await?;
+
+                for signup in signups.iter().filter(|s| matches!(s.status, VolunteerSignupStatus::Confirmed)) {
+                    if let Some(binding) = role_bindings.iter().find(|b| b.id == signup.role_binding_id) {
+                        if let Some(video_url) = race.video_urls.get(&binding.language) {
+                            let is_new = new_languages.contains(&&binding.language);
+                            let is_changed = changed_languages.contains(&&binding.language);
+                            if !is_new && !is_changed {
+                                continue;
+                            }
+
+                            let discord_invite = {
+                                let pattern = crate::admin::normalize_restream_url_pattern(&video_url.to_string());
+                                sqlx::query_scalar!(
+                                    "SELECT discord_invite_url FROM restream_channels WHERE url_pattern = $1",
+                                    pattern
+                                )
+                                .fetch_optional(&mut *transaction)
+                                .await
+                                .ok()
+                                .flatten()
+                                .flatten()
+                            };
+
+                            if let Ok(Some(user)) = User::from_id(&mut *transaction, signup.user_id).await {
+                                if let Some(discord) = user.discord {
+                                    let discord_user_id = UserId::new(discord.id.get());
+
+                                    let mut msg = MessageBuilder::default();
+                                    if is_new {
+                                        msg.push("A restream channel has been assigned for ");
+                                    } else {
+                                        msg.push("The restream channel for ");
+                                    }
+                                    msg.push_mono(&race_description);
+                                    msg.push(" in ");
+                                    msg.push(&event.display_name);
+                                    if is_new {
+                                        msg.push("!\n\n");
+                                    } else {
+                                        msg.push(" has been updated.\n\n");
+                                    }
+                                    msg.push("**Restream (");
+                                    msg.push(&binding.language.to_string());
+                                    msg.push("):** <");
+                                    msg.push(&video_url.to_string());
+                                    msg.push(">\n");
+                                    if let Some(ref invite) = discord_invite {
+                                        msg.push("**Restream Discord:** <");
+                                        msg.push(invite);
+                                        msg.push(">\nPlease make sure to join the Discord server before the race!\n");
+                                    }
+                                    msg.push("**When:** ");
+                                    msg.push_timestamp(race_start_time, serenity_utils::message::TimestampStyle::LongDateTime);
+
+                                    if let Ok(dm_channel) = discord_user_id.create_dm_channel(discord_ctx).await {
+                                        if let Err(e) = dm_channel.say(discord_ctx, msg.build()).await {
+                                            eprintln!("Failed to send restream notification DM: {}", e);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Updates the volunteer info post and triggers the ZSR volunteer API for a changed ZSR restream URL.
+///
+/// The volunteer info post is updated using a separate connection, so this must be called after committing the change.
+pub(crate) async fn refresh_restream_info(pool: &PgPool, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, old_video_urls: &HashMap<Language, Url>, race: &Race) {
+    if let Err(e) = crate::volunteer_requests::update_volunteer_post_for_race(pool, discord_ctx, race.id).await {
+        eprintln!("Failed to update volunteer info post for race {} after restream change: {}", race.id, e);
+    }
+    if race.video_urls.iter().any(|(lang, url)| {
+        url.as_str().contains("zeldaspeedruns")
+            && old_video_urls.get(lang).map_or(true, |old_url| old_url != url)
+    }) {
+        crate::zsr_export::schedule_volunteer_api_call(
+            pool.clone(),
+            http_client.clone(),
+            race.id,
+        );
+    }
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct EditRaceForm {
+    #[field(default = String::new())]
+    csrf: String,
+    #[field(default = String::new())]
+    room: String,
+    #[field(default = String::new())]
+    async_room1: String,
+    #[field(default = String::new())]
+    async_room2: String,
+    #[field(default = String::new())]
+    async_room3: String,
+    #[field(default = HashMap::new())]
+    video_urls: HashMap<Language, String>,
+    #[field(default = HashMap::new())]
+    restreamers: HashMap<Language, String>,
+    #[field(default = false)]
+    is_canceled: bool,
+    #[field(default = String::new())]
+    start_date: String,
     #[field(default = String::new())]
     async_start1_date: String,
     #[field(default = String::new())]
@@ -5180,15 +6124,149 @@ pub(crate) struct EditRaceForm {
     companion_race_id: Option<Id<Races>>,
 }
 
+/// Notifies volunteers and updates Discord after a race has been saved as part of an edit, then commits the transaction.
+///
+/// Shared by [`edit_race_post`] and [`revert_race_change`] so both paths have the same side effects.
+/// `old` is the race as it was before the edit. Reschedule notifications are only sent if `notify_reschedule` is set.
+async fn finish_race_edit(mut transaction: Transaction<'_, Postgres>, discord_ctx: &RwFuture<DiscordCtx>, pool: &PgPool, http_client: &reqwest::Client, global_state: &racetime_bot::GlobalState, event: &event::Data<'_>, old: &Race, mut race: Race, notify_reschedule: bool) -> Result<(), event::Error> {
+    let old_companion_race_id = old.companion_race_id;
+    let old_schedule_start = match &old.schedule {
+        RaceSchedule::Live { start, .. } => Some(*start),
+        _ => None,
+    };
+    let old_room_urls = schedule_room_urls(&old.schedule).into_iter().collect::<HashSet<_>>();
+    let manually_added_room_urls = schedule_room_urls(&race.schedule).into_iter()
+        .filter(|room| !old_room_urls.contains(room))
+        .collect_vec();
+
+    // Send cancel DMs to confirmed volunteers if race was just canceled
+    if !old.ignored && race.ignored {
+        notify_race_canceled(&mut transaction, &*discord_ctx.read().await, event, &race).await;
+    }
+
+    // Send reschedule DMs to pending+confirmed volunteers if organizer changed the start time
+    let new_schedule_start = match &race.schedule {
+        RaceSchedule::Live { start, .. } => Some(*start),
+        _ => None,
+    };
+    if notify_reschedule && !race.ignored
+        && new_schedule_start.is_some()
+        && new_schedule_start != old_schedule_start
+    {
+        let start = new_schedule_start.unwrap();
+        if let Ok(description) = race.notification_description(&mut transaction).await {
+            let signups = Signup::for_race(&mut transaction, race.id).await.unwrap_or_default();
+            let discord_ctx = discord_ctx.read().await;
+            for signup in signups.iter().filter(|s| matches!(s.status,
+                VolunteerSignupStatus::Pending | VolunteerSignupStatus::Confirmed))
+            {
+                if let Ok(Some(user)) = User::from_id(&mut *transaction, signup.user_id).await {
+                    if let Some(discord) = user.discord {
+                        let discord_user_id = UserId::new(discord.id.get());
+                        let mut msg = MessageBuilder::default();
+                        msg.push("**Race Rescheduled**\n\nThe race ");
+                        msg.push_mono(&description);
+                        msg.push(" in ");
+                        msg.push(&event.display_name);
+                        msg.push(" has been rescheduled.\n\n**New time (in your timezone):** ");
+                        msg.push_timestamp(start, serenity_utils::message::TimestampStyle::LongDateTime);
+                        msg.push(" (");
+                        msg.push_timestamp(start, serenity_utils::message::TimestampStyle::Relative);
+                        msg.push(")\n\nIf you're no longer available, you can withdraw your signup here: <");
+                        msg.push(&format!("{}/event/{}/{}/races/{}/signups",
+                            base_uri(), race.series.slug(), race.event, u64::from(race.id)));
+                        msg.push(">");
+                        let button = CreateButton::new(format!("volunteer_withdraw_{}", u64::from(signup.id)))
+                            .label("Withdraw Signup")
+                            .style(ButtonStyle::Danger);
+                        let row = CreateActionRow::Buttons(vec![button]);
+                        if let Ok(dm) = discord_user_id.create_dm_channel(&*discord_ctx).await {
+                            let _ = dm.send_message(&*discord_ctx,
+                                CreateMessage::new().content(msg.build()).components(vec![row])
+                            ).await;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Update Discord scheduled event if restream URLs or race name changed
+    if race.video_urls != old.video_urls || race.custom_title != old.custom_title {
+        if let Err(e) = crate::discord_scheduled_events::update_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &race, event, http_client).await {
+            eprintln!("Failed to update Discord scheduled event for race {}: {}", race.id, e);
+        }
+    }
+    if race.companion_race_id != old_companion_race_id {
+        if let Err(e) = crate::discord_scheduled_events::create_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &mut race, event, http_client).await {
+            eprintln!("Failed to update Discord scheduled event for companion race primary {}: {}", race.id, e);
+        }
+        if let Some(old_companion_race_id) = old_companion_race_id {
+            if Some(old_companion_race_id) != race.companion_race_id {
+                match Race::from_id(&mut transaction, http_client, old_companion_race_id).await {
+                    Ok(mut old_companion) => {
+                        if let Err(e) = crate::discord_scheduled_events::create_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &mut old_companion, event, http_client).await {
+                            eprintln!("Failed to restore Discord scheduled event for old companion race {}: {}", old_companion.id, e);
+                        }
+                        if let Err(e) = old_companion.save(&mut transaction).await {
+                            eprintln!("Failed to save old companion race {} after Discord event update: {}", old_companion.id, e);
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to load old companion race {} for Discord event update: {}", old_companion_race_id, e),
+                }
+            }
+        }
+        if let Some(new_companion_race_id) = race.companion_race_id {
+            match Race::from_id(&mut transaction, http_client, new_companion_race_id).await {
+                Ok(mut new_companion) => {
+                    if let Err(e) = crate::discord_scheduled_events::create_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &mut new_companion, event, http_client).await {
+                        eprintln!("Failed to suppress Discord scheduled event for new companion race {}: {}", new_companion.id, e);
+                    }
+                    if let Err(e) = new_companion.save(&mut transaction).await {
+                        eprintln!("Failed to save new companion race {} after Discord event update: {}", new_companion.id, e);
+                    }
+                }
+                Err(e) => eprintln!("Failed to load new companion race {} for Discord event update: {}", new_companion_race_id, e),
+            }
+        }
+    }
+
+    notify_restream_volunteers(&mut transaction, &*discord_ctx.read().await, event, &old.video_urls, &race).await?;
+
+    let new_companion_race_id = race.companion_race_id;
+    transaction.commit().await?;
+    for room in &manually_added_room_urls {
+        notify_racetime_bot_of_manual_room(global_state, room).await;
+    }
+    if old_companion_race_id != new_companion_race_id {
+        if let Err(e) = notify_companion_race_change(
+            pool,
+            &*discord_ctx.read().await,
+            http_client,
+            race.id,
+            old_companion_race_id,
+            new_companion_race_id,
+        ).await {
+            eprintln!("Failed to send companion race notification for race {}: {}", race.id, e);
+        }
+    }
+
+    // Update the volunteer info post to reflect restream or race name changes
+    if race.video_urls != old.video_urls || race.custom_title != old.custom_title {
+        refresh_restream_info(pool, &*discord_ctx.read().await, http_client, &old.video_urls, &race).await;
+    }
+    Ok(())
+}
+
 #[rocket::post("/event/<series>/<event>/races/<id>/edit?<redirect_to>", data = "<form>")]
 pub(crate) async fn edit_race_post(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, global_state: &State<Arc<racetime_bot::GlobalState>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, id: Id<Races>, redirect_to: Option<Origin<'_>>, form: Form<Contextual<'_, EditRaceForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
     let mut transaction = pool.begin().await?;
     let event = event::Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
     let mut race = Race::from_id(&mut transaction, http_client, id).await?;
     let old_companion_race_id = race.companion_race_id;
-    let old_room_urls = schedule_room_urls(&race.schedule).into_iter().collect::<HashSet<_>>();
     let mut form = form.into_inner();
     form.verify(&csrf);
+    set_change_context(&mut transaction, Some(me.id), ChangeSource::Web).await?;
     if race.series != event.series || race.event != event.event {
         form.context.push_error(form::Error::validation("This race is not part of this event."));
     }
@@ -5650,10 +6728,7 @@ pub(crate) async fn edit_race_post(discord_ctx: &State<RwFuture<DiscordCtx>>, po
         if form.context.errors().next().is_some() {
             RedirectOrContent::Content(edit_race_form(transaction, &*discord_ctx.read().await, Some(me), uri, csrf.as_ref(), event, race, redirect_to, Some(form.context)).await?)
         } else {
-            let old_schedule_start = match &race.schedule {
-                RaceSchedule::Live { start, .. } => Some(*start),
-                _ => None,
-            };
+            let old_race = race.clone();
 
             // Update race schedule with new start dates if organizer or global admin
             if is_organizer || is_admin {
@@ -5696,11 +6771,9 @@ pub(crate) async fn edit_race_post(discord_ctx: &State<RwFuture<DiscordCtx>>, po
             }
             race.last_edited_by = Some(me.id);
             race.last_edited_at = Some(Utc::now());
-            let was_ignored = race.ignored;
             if is_organizer || is_admin {
                 race.ignored = value.is_canceled;
             }
-            let original_custom_title = race.custom_title.clone();
             if (is_organizer || is_admin) && race.is_custom() {
                 race.custom_title = Some(value.custom_title.trim().to_owned());
                 race.custom_create_room = value.custom_create_room;
@@ -5709,9 +6782,6 @@ pub(crate) async fn edit_race_post(discord_ctx: &State<RwFuture<DiscordCtx>>, po
                 race.companion_race_id = new_companion_race_id;
             }
             
-            // Save original video URLs to check if they changed
-            let original_video_urls = race.video_urls.clone();
-            
             if !uses_primary_restream_settings && (race.series != Series::League || race.has_any_room()) {
                 race.video_urls = value.video_urls.iter().filter(|(_, video_url)| !video_url.is_empty()).map(|(language, video_url)| (*language, Url::parse(video_url).expect("validated"))).collect();
                 race.restreamers = restreamers;
@@ -5722,249 +6792,9 @@ pub(crate) async fn edit_race_post(discord_ctx: &State<RwFuture<DiscordCtx>>, po
             if let (Some(id), Some(gen_time), Some(file_stem)) = (web_id, web_gen_time, file_stem) {
                 race.seed.files = Some(seed::Files::OotrWeb { id, gen_time, file_stem: Cow::Owned(file_stem) });
             }
-            let manually_added_room_urls = schedule_room_urls(&race.schedule).into_iter()
-                .filter(|room| !old_room_urls.contains(room))
-                .collect_vec();
             race.save(&mut transaction).await?;
-
-            // Send cancel DMs to confirmed volunteers if race was just canceled
-            if !was_ignored && race.ignored {
-                if let Ok(description) = race.notification_description(&mut transaction).await {
-                    let signups = Signup::for_race(&mut transaction, race.id).await.unwrap_or_default();
-                    let discord_ctx = discord_ctx.read().await;
-                    for signup in signups.iter().filter(|s| matches!(s.status, VolunteerSignupStatus::Confirmed)) {
-                        if let Ok(Some(user)) = User::from_id(&mut *transaction, signup.user_id).await {
-                            if let Some(discord) = user.discord {
-                                let discord_user_id = UserId::new(discord.id.get());
-                                let mut msg = MessageBuilder::default();
-                                msg.push("**Race Canceled**\n\nThe race ");
-                                msg.push_mono(&description);
-                                msg.push(" in ");
-                                msg.push(&event.display_name);
-                                msg.push(" has been canceled.");
-                                if let Ok(dm) = discord_user_id.create_dm_channel(&*discord_ctx).await {
-                                    let _ = dm.say(&*discord_ctx, msg.build()).await;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Send reschedule DMs to pending+confirmed volunteers if organizer changed the start time
-            let new_schedule_start = match &race.schedule {
-                RaceSchedule::Live { start, .. } => Some(*start),
-                _ => None,
-            };
-            if (is_organizer || is_admin) && !race.ignored
-                && new_schedule_start.is_some()
-                && new_schedule_start != old_schedule_start
-            {
-                let start = new_schedule_start.unwrap();
-                if let Ok(description) = race.notification_description(&mut transaction).await {
-                    let signups = Signup::for_race(&mut transaction, race.id).await.unwrap_or_default();
-                    let discord_ctx = discord_ctx.read().await;
-                    for signup in signups.iter().filter(|s| matches!(s.status,
-                        VolunteerSignupStatus::Pending | VolunteerSignupStatus::Confirmed))
-                    {
-                        if let Ok(Some(user)) = User::from_id(&mut *transaction, signup.user_id).await {
-                            if let Some(discord) = user.discord {
-                                let discord_user_id = UserId::new(discord.id.get());
-                                let mut msg = MessageBuilder::default();
-                                msg.push("**Race Rescheduled**\n\nThe race ");
-                                msg.push_mono(&description);
-                                msg.push(" in ");
-                                msg.push(&event.display_name);
-                                msg.push(" has been rescheduled.\n\n**New time (in your timezone):** ");
-                                msg.push_timestamp(start, serenity_utils::message::TimestampStyle::LongDateTime);
-                                msg.push(" (");
-                                msg.push_timestamp(start, serenity_utils::message::TimestampStyle::Relative);
-                                msg.push(")\n\nIf you're no longer available, you can withdraw your signup here: <");
-                                msg.push(&format!("{}/event/{}/{}/races/{}/signups",
-                                    base_uri(), race.series.slug(), race.event, u64::from(race.id)));
-                                msg.push(">");
-                                let button = CreateButton::new(format!("volunteer_withdraw_{}", u64::from(signup.id)))
-                                    .label("Withdraw Signup")
-                                    .style(ButtonStyle::Danger);
-                                let row = CreateActionRow::Buttons(vec![button]);
-                                if let Ok(dm) = discord_user_id.create_dm_channel(&*discord_ctx).await {
-                                    let _ = dm.send_message(&*discord_ctx,
-                                        CreateMessage::new().content(msg.build()).components(vec![row])
-                                    ).await;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Update Discord scheduled event if restream URLs or race name changed
-            if race.video_urls != original_video_urls || race.custom_title != original_custom_title {
-                if let Err(e) = crate::discord_scheduled_events::update_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &race, &event, http_client.inner()).await {
-                    eprintln!("Failed to update Discord scheduled event for race {}: {}", race.id, e);
-                }
-            }
-            if race.companion_race_id != old_companion_race_id {
-                if let Err(e) = crate::discord_scheduled_events::create_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &mut race, &event, http_client.inner()).await {
-                    eprintln!("Failed to update Discord scheduled event for companion race primary {}: {}", race.id, e);
-                }
-                if let Some(old_companion_race_id) = old_companion_race_id {
-                    if Some(old_companion_race_id) != race.companion_race_id {
-                        match Race::from_id(&mut transaction, http_client, old_companion_race_id).await {
-                            Ok(mut old_companion) => {
-                                if let Err(e) = crate::discord_scheduled_events::create_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &mut old_companion, &event, http_client.inner()).await {
-                                    eprintln!("Failed to restore Discord scheduled event for old companion race {}: {}", old_companion.id, e);
-                                }
-                                if let Err(e) = old_companion.save(&mut transaction).await {
-                                    eprintln!("Failed to save old companion race {} after Discord event update: {}", old_companion.id, e);
-                                }
-                            }
-                            Err(e) => eprintln!("Failed to load old companion race {} for Discord event update: {}", old_companion_race_id, e),
-                        }
-                    }
-                }
-                if let Some(new_companion_race_id) = race.companion_race_id {
-                    match Race::from_id(&mut transaction, http_client, new_companion_race_id).await {
-                        Ok(mut new_companion) => {
-                            if let Err(e) = crate::discord_scheduled_events::create_discord_scheduled_event(&*discord_ctx.read().await, &mut transaction, &mut new_companion, &event, http_client.inner()).await {
-                                eprintln!("Failed to suppress Discord scheduled event for new companion race {}: {}", new_companion.id, e);
-                            }
-                            if let Err(e) = new_companion.save(&mut transaction).await {
-                                eprintln!("Failed to save new companion race {} after Discord event update: {}", new_companion.id, e);
-                            }
-                        }
-                        Err(e) => eprintln!("Failed to load new companion race {} for Discord event update: {}", new_companion_race_id, e),
-                    }
-                }
-            }
-
-            // Send DM notifications to confirmed volunteers when restream URLs are newly assigned or changed
-            if !race.video_urls.is_empty() && race.video_urls != original_video_urls {
-                let new_languages: Vec<_> = race.video_urls.keys()
-                    .filter(|lang| !original_video_urls.contains_key(*lang))
-                    .collect();
-                let changed_languages: Vec<_> = race.video_urls.keys()
-                    .filter(|lang| original_video_urls.get(*lang).is_some_and(|old| old != race.video_urls.get(*lang).unwrap()))
-                    .collect();
-
-                if !new_languages.is_empty() || !changed_languages.is_empty() {
-                    let race_description = race.notification_description(&mut transaction).await?;
-
-                    if let RaceSchedule::Live { start: race_start_time, .. } = race.schedule {
-                        let signups = Signup::for_race(&mut transaction, race.id).await?;
-                        let role_bindings = EffectiveRoleBinding::for_event(&mut transaction, event.series, &event.event).await?;
-
-                        let discord_ctx = discord_ctx.read().await;
-
-                        for signup in signups.iter().filter(|s| matches!(s.status, VolunteerSignupStatus::Confirmed)) {
-                            if let Some(binding) = role_bindings.iter().find(|b| b.id == signup.role_binding_id) {
-                                if let Some(video_url) = race.video_urls.get(&binding.language) {
-                                    let is_new = new_languages.contains(&&binding.language);
-                                    let is_changed = changed_languages.contains(&&binding.language);
-                                    if !is_new && !is_changed {
-                                        continue;
-                                    }
-
-                                    let discord_invite = {
-                                        let pattern = crate::admin::normalize_restream_url_pattern(&video_url.to_string());
-                                        sqlx::query_scalar!(
-                                            "SELECT discord_invite_url FROM restream_channels WHERE url_pattern = $1",
-                                            pattern
-                                        )
-                                        .fetch_optional(&mut *transaction)
-                                        .await
-                                        .ok()
-                                        .flatten()
-                                        .flatten()
-                                    };
-
-                                    if let Ok(Some(user)) = User::from_id(&mut *transaction, signup.user_id).await {
-                                        if let Some(discord) = user.discord {
-                                            let discord_user_id = UserId::new(discord.id.get());
-
-                                            let mut msg = MessageBuilder::default();
-                                            if is_new {
-                                                msg.push("A restream channel has been assigned for ");
-                                            } else {
-                                                msg.push("The restream channel for ");
-                                            }
-                                            msg.push_mono(&race_description);
-                                            msg.push(" in ");
-                                            msg.push(&event.display_name);
-                                            if is_new {
-                                                msg.push("!\n\n");
-                                            } else {
-                                                msg.push(" has been updated.\n\n");
-                                            }
-                                            msg.push("**Restream (");
-                                            msg.push(&binding.language.to_string());
-                                            msg.push("):** <");
-                                            msg.push(&video_url.to_string());
-                                            msg.push(">\n");
-                                            if let Some(ref invite) = discord_invite {
-                                                msg.push("**Restream Discord:** <");
-                                                msg.push(invite);
-                                                msg.push(">\nPlease make sure to join the Discord server before the race!\n");
-                                            }
-                                            msg.push("**When:** ");
-                                            msg.push_timestamp(race_start_time, serenity_utils::message::TimestampStyle::LongDateTime);
-
-                                            if let Ok(dm_channel) = discord_user_id.create_dm_channel(&*discord_ctx).await {
-                                                if let Err(e) = dm_channel.say(&*discord_ctx, msg.build()).await {
-                                                    eprintln!("Failed to send restream notification DM: {}", e);
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-
-            let new_companion_race_id = race.companion_race_id;
-            transaction.commit().await?;
-            for room in &manually_added_room_urls {
-                notify_racetime_bot_of_manual_room(global_state, room).await;
-            }
-            if old_companion_race_id != new_companion_race_id {
-                if let Err(e) = notify_companion_race_change(
-                    pool,
-                    &*discord_ctx.read().await,
-                    http_client,
-                    race.id,
-                    old_companion_race_id,
-                    new_companion_race_id,
-                ).await {
-                    eprintln!("Failed to send companion race notification for race {}: {}", race.id, e);
-                }
-            }
-
-            // Update the volunteer info post to reflect restream or race name changes
-            // (Must be done after commit so the new transaction can see the changes)
-            if race.video_urls != original_video_urls || race.custom_title != original_custom_title {
-                if let Err(e) = crate::volunteer_requests::update_volunteer_post_for_race(
-                    pool,
-                    &*discord_ctx.read().await,
-                    race.id,
-                ).await {
-                    eprintln!("Failed to update volunteer info post for race {} after restream URL change: {}", race.id, e);
-                }
-            }
-
-            // Trigger ZSR volunteer API when a restream URL changes to or within a zeldaspeedruns channel
-            if race.video_urls.iter().any(|(lang, url)| {
-                url.as_str().contains("zeldaspeedruns")
-                    && original_video_urls.get(lang).map_or(true, |old| old != url)
-            }) {
-                crate::zsr_export::schedule_volunteer_api_call(
-                    pool.inner().clone(),
-                    http_client.inner().clone(),
-                    race.id,
-                );
-            }
-            RedirectOrContent::Redirect(Redirect::to(redirect_to.map(|Origin(uri)| uri.into_owned()).unwrap_or_else(|| uri!(event::races(event.series, &*event.event)))))
+            finish_race_edit(transaction, discord_ctx, pool, http_client, global_state, &event, &old_race, race, is_organizer || is_admin).await?;
+            RedirectOrContent::Redirect(Redirect::to(redirect_to.map(|Origin(uri)| uri.into_owned()).unwrap_or_else(|| uri!(event::races(event.series, &*event.event, _)))))
         }
     } else {
         RedirectOrContent::Content(edit_race_form(transaction, &*discord_ctx.read().await, Some(me), uri, csrf.as_ref(), event, race, redirect_to, Some(form.context)).await?)
@@ -6145,7 +6975,7 @@ pub(crate) async fn add_file_hash_post(pool: &State<PgPool>, http_client: &State
                 hash1.unwrap(), hash2.unwrap(), hash3.unwrap(), hash4.unwrap(), hash5.unwrap(), id as _,
             ).execute(&mut *transaction).await?;
             transaction.commit().await?;
-            RedirectOrContent::Redirect(Redirect::to(uri!(event::races(event.series, &*event.event))))
+            RedirectOrContent::Redirect(Redirect::to(uri!(event::races(event.series, &*event.event, _))))
         }
     } else {
         RedirectOrContent::Content(add_file_hash_form(transaction, Some(me), uri, csrf.as_ref(), event, race, form.context).await?)
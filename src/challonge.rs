@@ -131,3 +131,42 @@ pub(crate) async fn races_to_import(transaction: &mut Transaction<'_, Postgres>,
     }
     Ok((races, skips))
 }
+
+/// Reports the winner of a match, e.g. for a walkover or disqualification where no scores are available.
+pub(crate) async fn report_winner(http_client: &reqwest::Client, challonge_api_key: &str, community: Option<&str>, tournament: &str, match_id: &str, winner: &str, loser: &str) -> Result<(), cal::Error> {
+    let endpoint: Url = if let Some(community) = community {
+        format!("https://api.challonge.com/v2/communities/{community}/tournaments/{tournament}/matches/{match_id}.json")
+    } else {
+        format!("https://api.challonge.com/v2/tournaments/{tournament}/matches/{match_id}.json")
+    }.parse()?;
+    println!("Challonge: Reporting winner to API endpoint {endpoint}");
+    http_client.put(endpoint)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .header(reqwest::header::CONTENT_TYPE, "application/vnd.api+json")
+        .header("Authorization-Type", "v1")
+        .header(reqwest::header::AUTHORIZATION, challonge_api_key)
+        .json(&json!({
+            "data": {
+                "type": "Match",
+                "attributes": {
+                    "match": [
+                        {
+                            "participant_id": winner,
+                            "score_set": "1",
+                            "rank": 1,
+                            "advancing": true,
+                        },
+                        {
+                            "participant_id": loser,
+                            "score_set": "0",
+                            "rank": 2,
+                            "advancing": false,
+                        },
+                    ],
+                },
+            },
+        }))
+        .send().await?
+        .detailed_error_for_status().await?;
+    Ok(())
+}
@@ -46,6 +46,10 @@ impl Config {
     }
 }
 
+impl TypeMapKey for Config {
+    type Value = Self;
+}
+
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ConfigRaceTime {
@@ -65,6 +69,14 @@ pub(crate) struct ConfigDiscord {
     pub(crate) client_id: ApplicationId,
     pub(crate) client_secret: String,
     pub(crate) bot_token: String,
+    /// Names of roles members assign to themselves, e.g. using the buttons posted by `/pronoun-roles`, `/racing-role`, and `/watch-roles`.
+    /// The role reconciler never adds or removes these.
+    #[serde(default = "default_self_assigned_roles")]
+    pub(crate) self_assigned_roles: Vec<String>,
+}
+
+fn default_self_assigned_roles() -> Vec<String> {
+    ["he/him", "she/her", "they/them", "other pronouns", "racing", "restream watcher", "watch party watcher"].into_iter().map(String::from).collect()
 }
 
 #[derive(Clone, Deserialize)]
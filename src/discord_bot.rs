@@ -5,6 +5,12 @@ use {
         racetime_bot::{AlttprDeRaceOptions, CleanShutdown, CrosskeysRaceOptions, GlobalState, RadioChoiceValue},
         speedgaming_export,
         async_race::{self, Error as AsyncRaceError},
+        discord_localization::{
+            self,
+            LocalizedDescription as _,
+            descriptions,
+            responses,
+        },
         volunteer_requests,
     }, serenity::all::{
         CacheHttp,
@@ -14,7 +20,6 @@ use {
         CreateAllowedMentions,
         CreateButton,
         CreateCommand,
-        CreateCommandOption,
         CreateForumPost,
         CreateInputText,
         CreateInteractionResponse,
@@ -209,8 +214,10 @@ pub(crate) struct CommandIds {
     pub(crate) first: Option<CommandId>,
     pub(crate) no: Option<CommandId>,
     pub(crate) pick: Option<CommandId>,
+    post_signup: CommandId,
     post_status: CommandId,
     pronoun_roles: CommandId,
+    race: CommandId,
     racing_role: CommandId,
     reset_async: CommandId,
     reset_race: CommandId,
@@ -222,6 +229,7 @@ pub(crate) struct CommandIds {
     pub(crate) schedule_remove: CommandId,
     pub(crate) second: Option<CommandId>,
     pub(crate) skip: Option<CommandId>,
+    standings: CommandId,
     status: CommandId,
     watch_roles: CommandId,
     pub(crate) yes: Option<CommandId>,
@@ -266,6 +274,7 @@ trait GenericInteraction {
     fn channel_id(&self) -> ChannelId;
     fn guild_id(&self) -> Option<GuildId>;
     fn user_id(&self) -> UserId;
+    fn locale(&self) -> &str;
     async fn create_response(&self, cache_http: impl CacheHttp, builder: CreateInteractionResponse) -> serenity::Result<()>;
     async fn edit_response(&self, cache_http: impl CacheHttp, builder: EditInteractionResponse) -> serenity::Result<Message>;
 }
@@ -275,6 +284,7 @@ impl GenericInteraction for CommandInteraction {
     fn channel_id(&self) -> ChannelId { self.channel_id }
     fn guild_id(&self) -> Option<GuildId> { self.guild_id }
     fn user_id(&self) -> UserId { self.user.id }
+    fn locale(&self) -> &str { &self.locale }
 
     async fn create_response(&self, cache_http: impl CacheHttp, builder: CreateInteractionResponse) -> serenity::Result<()> {
         self.create_response(cache_http, builder).await
@@ -289,6 +299,7 @@ impl GenericInteraction for ComponentInteraction {
     fn channel_id(&self) -> ChannelId { self.channel_id }
     fn guild_id(&self) -> Option<GuildId> { self.guild_id }
     fn user_id(&self) -> UserId { self.user.id }
+    fn locale(&self) -> &str { &self.locale }
 
     async fn create_response(&self, cache_http: impl CacheHttp, builder: CreateInteractionResponse) -> serenity::Result<()> {
         self.create_response(cache_http, builder).await
@@ -641,6 +652,352 @@ fn canceled_async_thread_name(name: &str) -> String {
     canceled_name
 }
 
+/// Finds the event whose channel a command was used in, or the only ongoing event in the guild.
+///
+/// Returns the response to show if there is no such event.
+async fn channel_event(transaction: &mut Transaction<'_, Postgres>, interaction: &CommandInteraction) -> Result<Result<event::Data<'static>, discord_localization::Text>, event::DataError> {
+    let guild_id = interaction.guild_id.expect("event command called outside of a guild");
+    let parent_channel = interaction.channel.as_ref().and_then(|channel| channel.parent_id);
+    let event_rows = sqlx::query!(r#"SELECT series AS "series: Series", event FROM events WHERE discord_guild = $1 AND (end_time IS NULL OR end_time > NOW())"#, PgSnowflake(guild_id) as _).fetch_all(&mut **transaction).await?;
+    let mut events = Vec::with_capacity(event_rows.len());
+    for row in event_rows {
+        events.push(event::Data::new(&mut *transaction, row.series, row.event).await?.expect("just received from database"));
+    }
+    Ok(match events.iter().position(|event| [
+        event.discord_standings_channel,
+        event.discord_race_results_channel,
+        event.discord_scheduling_channel,
+        event.discord_organizer_channel,
+    ].into_iter().flatten().any(|channel| channel == interaction.channel_id || Some(channel) == parent_channel)) {
+        Some(idx) => Ok(events.swap_remove(idx)),
+        None => if events.len() == 1 {
+            Ok(events.remove(0))
+        } else if events.is_empty() {
+            Err(responses::NO_ONGOING_EVENTS)
+        } else {
+            Err(responses::MULTIPLE_ONGOING_EVENTS)
+        },
+    })
+}
+
+/// Shows the standings of the event whose channel the command was used in, or of the only ongoing event in the guild.
+async fn standings_command(ctx: &DiscordCtx, interaction: &CommandInteraction) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    interaction.create_response(ctx, CreateInteractionResponse::Defer(CreateInteractionResponseMessage::new()
+        .ephemeral(true)
+    )).await?;
+    let (mut transaction, http_client, config, startgg_token) = {
+        let data = ctx.data.read().await;
+        (
+            data.get::<DbPool>().expect("database connection pool missing from Discord context").begin().await?,
+            data.get::<HttpClient>().expect("HTTP client missing from Discord context").clone(),
+            data.get::<Config>().expect("config missing from Discord context").clone(),
+            data.get::<StartggToken>().expect("start.gg auth token missing from Discord context").clone(),
+        )
+    };
+    let event = match channel_event(&mut transaction, interaction).await? {
+        Ok(event) => event,
+        Err(response) => {
+            interaction.edit_response(ctx, EditInteractionResponse::new()
+                .content(response.get(discord_localization::response_language(None, &interaction.locale)))
+            ).await?;
+            transaction.rollback().await?;
+            return Ok(())
+        }
+    };
+    let language = discord_localization::response_language(Some(event.language), &interaction.locale);
+    let response_content = match event::standings::standings(&mut transaction, &http_client, &config, &startgg_token, &event, false).await? {
+        event::standings::Standings::Unavailable => responses::NO_STANDINGS.get(language).to_owned(),
+        event::standings::Standings::Hidden => responses::STANDINGS_HIDDEN.get(language).to_owned(),
+        event::standings::Standings::Table(table) => event::standings::message(&event, &table),
+    };
+    interaction.edit_response(ctx, EditInteractionResponse::new()
+        .content(response_content)
+    ).await?;
+    transaction.commit().await?;
+    Ok(())
+}
+
+/// Posts a button in the current channel that lets users enter the event via Discord.
+async fn post_signup_command(ctx: &DiscordCtx, interaction: &CommandInteraction) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut transaction = ctx.data.read().await.get::<DbPool>().expect("database connection pool missing from Discord context").begin().await?;
+    let event = match channel_event(&mut transaction, interaction).await? {
+        Ok(event) => event,
+        Err(response) => {
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(true)
+                .content(response.get(discord_localization::response_language(None, &interaction.locale)))
+            )).await?;
+            transaction.rollback().await?;
+            return Ok(())
+        }
+    };
+    let language = discord_localization::response_language(Some(event.language), &interaction.locale);
+    if !event.organizers(&mut transaction).await?.into_iter().any(|organizer| organizer.discord.is_some_and(|discord| discord.id == interaction.user.id)) {
+        interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+            .ephemeral(true)
+            .content(responses::ONLY_ORGANIZERS.get(language))
+        )).await?;
+        transaction.rollback().await?;
+        return Ok(())
+    }
+    if event.enter_flow.is_none() {
+        interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+            .ephemeral(true)
+            .content(responses::NO_ENTER_FLOW.get(language))
+        )).await?;
+        transaction.rollback().await?;
+        return Ok(())
+    }
+    interaction.channel_id.send_message(ctx, CreateMessage::new()
+        .content(MessageBuilder::default()
+            .push("Click the button below to enter ")
+            .push_bold_safe(&event.display_name)
+            .push(". You can also enter on the website: <")
+            .push(uri!(base_uri(), event::info(event.series, &*event.event)).to_string())
+            .push(">")
+            .build()
+        )
+        .button(CreateButton::new(format!("{}{}_{}", event::enter::DISCORD_BUTTON_PREFIX, event.series.slug(), event.event)).label("Enter"))
+    ).await?;
+    interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+        .ephemeral(true)
+        .content("done")
+    )).await?;
+    transaction.commit().await?;
+    Ok(())
+}
+
+/// Parses the series slug and event name from the custom ID of a Discord signup button or modal.
+async fn signup_event(transaction: &mut Transaction<'_, Postgres>, params: &str) -> Result<Option<event::Data<'static>>, event::DataError> {
+    let Some((series, event)) = params.split_once('_') else { return Ok(None) };
+    let Ok(series) = series.parse::<Series>() else { return Ok(None) };
+    event::Data::new(transaction, series, event.to_owned()).await
+}
+
+/// Handles the `/race` subcommands that let organizers moderate the race in a scheduling thread.
+async fn race_command(ctx: &DiscordCtx, interaction: &CommandInteraction) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let subcommand = interaction.data.options.first().expect("missing subcommand for /race");
+    let CommandDataOptionValue::SubCommand(ref options) = subcommand.value else { panic!("unexpected slash command option type") };
+    let mut game = None;
+    let mut locked = None;
+    let mut language = None;
+    let mut url = None;
+    let mut restreamer = None;
+    let mut winner = None;
+    let mut reason = None;
+    let mut deadline = None;
+    for option in options {
+        match (&*option.name, &option.value) {
+            ("game", CommandDataOptionValue::Integer(value)) => game = Some(i16::try_from(*value).expect("game number out of range")),
+            ("locked", CommandDataOptionValue::Boolean(value)) => locked = Some(*value),
+            ("language", CommandDataOptionValue::String(value)) => language = Some(all::<Language>().find(|language| language.short_code() == *value).expect("unexpected restream language")),
+            ("url", CommandDataOptionValue::String(value)) => url = Some(value.clone()),
+            ("restreamer", CommandDataOptionValue::String(value)) => restreamer = Some(value.clone()),
+            ("winner", CommandDataOptionValue::User(user_id)) => winner = Some(*user_id),
+            ("reason", CommandDataOptionValue::String(value)) => reason = Some(value.clone()),
+            ("deadline", CommandDataOptionValue::String(value)) => deadline = Some(value.clone()),
+            (name, _) => panic!("unexpected option for /race {}: {name}", subcommand.name),
+        }
+    }
+    if interaction.channel.as_ref().and_then(|thread| thread.parent_id).is_none() {
+        interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+            .ephemeral(true)
+            .content(responses::THREADS_ONLY.get(discord_localization::response_language(None, &interaction.locale)))
+        )).await?;
+        return Ok(())
+    }
+    let (pool, http_client) = {
+        let data = ctx.data.read().await;
+        (
+            data.get::<DbPool>().expect("database connection pool missing from Discord context").clone(),
+            data.get::<HttpClient>().expect("HTTP client missing from Discord context").clone(),
+        )
+    };
+    let mut transaction = pool.begin().await?;
+    let races = Race::for_scheduling_channel(&mut transaction, &http_client, interaction.channel_id, game, true).await?;
+    let mut race = match races.into_iter().at_most_one() {
+        Ok(Some(race)) => race,
+        Ok(None) => {
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(true)
+                .content(if game.is_some() {
+                    responses::NO_RACE_WITH_GAME_NUMBER
+                } else {
+                    responses::NOT_SCHEDULING_THREAD
+                }.get(discord_localization::response_language(None, &interaction.locale)))
+            )).await?;
+            transaction.rollback().await?;
+            return Ok(())
+        }
+        Err(_) => {
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(true)
+                .content("This thread has multiple games. Please specify the game number.")
+            )).await?;
+            transaction.rollback().await?;
+            return Ok(())
+        }
+    };
+    let event = race.event(&mut transaction).await?;
+    let response_language = discord_localization::response_language(Some(event.language), &interaction.locale);
+    let Some(me) = event.organizers(&mut transaction).await?.into_iter().find(|organizer| organizer.discord.is_some_and(|discord| discord.id == interaction.user.id)) else {
+        interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+            .ephemeral(true)
+            .content(responses::ONLY_ORGANIZERS.get(response_language))
+        )).await?;
+        transaction.rollback().await?;
+        return Ok(())
+    };
+    cal::set_change_context(&mut transaction, Some(me.id), cal::ChangeSource::Discord).await?;
+    macro_rules! reject {
+        ($content:expr) => {{
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(true)
+                .content($content)
+            )).await?;
+            transaction.rollback().await?;
+            return Ok(())
+        }};
+    }
+
+    match &*subcommand.name {
+        "ignore" => {
+            if race.ignored {
+                reject!("This race has already been canceled.");
+            }
+            race.ignored = true;
+            race.last_edited_by = Some(me.id);
+            race.last_edited_at = Some(Utc::now());
+            race.save(&mut transaction).await?;
+            cal::notify_race_canceled(&mut transaction, ctx, &event, &race).await;
+            transaction.commit().await?;
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(false)
+                .content("This race has been canceled by a tournament organizer.")
+            )).await?;
+        }
+        "lock-schedule" => {
+            let locked = locked.unwrap_or(true);
+            if race.schedule_locked == locked {
+                reject!(if locked { "The schedule of this race is already locked." } else { "The schedule of this race is not locked." });
+            }
+            race.schedule_locked = locked;
+            race.last_edited_by = Some(me.id);
+            race.last_edited_at = Some(Utc::now());
+            race.save(&mut transaction).await?;
+            transaction.commit().await?;
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(true)
+                .content(if locked {
+                    "The schedule of this race is now locked and will not be changed by automatic imports."
+                } else {
+                    "The schedule of this race is now unlocked."
+                })
+            )).await?;
+        }
+        "restream" => {
+            let language = language.expect("required language missing from /race restream");
+            if url.is_none() && restreamer.is_none() {
+                reject!("Please specify a restream URL, a restreamer, or both.");
+            }
+            let url = match url.as_deref().map(Url::parse).transpose() {
+                Ok(url) => url,
+                Err(e) => reject!(responses::invalid_url(response_language, e)),
+            };
+            let restreamer = match restreamer {
+                Some(restreamer) => match racetime_bot::parse_user(&mut transaction, &http_client, &restreamer).await {
+                    Ok(restreamer) => Some(restreamer),
+                    Err(e) => reject!(responses::unknown_restreamer(response_language, e)),
+                },
+                None => None,
+            };
+            let old_video_urls = race.video_urls.clone();
+            if let Some(url) = url {
+                race.video_urls.insert(language, url);
+            }
+            if let Some(ref restreamer) = restreamer {
+                race.restreamers.insert(language, restreamer.clone());
+            }
+            race.last_edited_by = Some(me.id);
+            race.last_edited_at = Some(Utc::now());
+            race.save(&mut transaction).await?;
+            transaction.commit().await?;
+            // the volunteer info post is updated using a separate connection, so this must happen after the commit
+            let mut transaction = pool.begin().await?;
+            cal::restream_updated(&mut transaction, ctx, &pool, &http_client, &event, &old_video_urls, &race).await;
+            transaction.commit().await?;
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(true)
+                .content(format!("The {language} restream info for this race has been updated."))
+            )).await?;
+        }
+        "walkover" => {
+            let winner = winner.expect("required winner missing from /race walkover");
+            if race.is_ended() {
+                reject!("This race has already ended.");
+            }
+            let mut winning_team = None;
+            for team in race.teams() {
+                if team.members(&mut transaction).await?.into_iter().any(|member| member.discord.is_some_and(|discord| discord.id == winner)) {
+                    winning_team = Some(team.clone());
+                    break
+                }
+            }
+            let Some(winning_team) = winning_team else {
+                reject!("That user is not an entrant in this race.");
+            };
+            cal::record_forfeit(&mut transaction, &mut race, me.id, cal::ChangeSource::Discord, cal::ForfeitKind::Walkover, Some(&winning_team), reason.as_deref()).await?;
+            let mut content = MessageBuilder::default();
+            content.mention_team(&mut transaction, interaction.guild_id, &winning_team).await?;
+            content.push(if winning_team.name_is_plural() { " win this race by walkover" } else { " wins this race by walkover" });
+            if let Some(ref reason) = reason {
+                content.push(" (");
+                content.push_safe(reason);
+                content.push(")");
+            }
+            content.push('.');
+            // reporting to start.gg or Challonge can take longer than Discord's interaction response timeout
+            interaction.create_response(ctx, CreateInteractionResponse::Defer(CreateInteractionResponseMessage::new()
+                .ephemeral(false)
+            )).await?;
+            let global_state = ctx.data.read().await.get::<GlobalState>().expect("global state missing from Discord context").clone();
+            racetime_bot::report::report_forfeit(transaction, &global_state, &race, &event, cal::ForfeitKind::Walkover, Some(&winning_team), reason.as_deref()).await?;
+            interaction.edit_response(ctx, EditInteractionResponse::new()
+                .content(content.build())
+            ).await?;
+        }
+        "set-deadline" => {
+            let deadline = deadline.expect("required deadline missing from /race set-deadline");
+            let deadline = if deadline.trim().eq_ignore_ascii_case("none") {
+                None
+            } else if let Some(deadline) = parse_timestamp(deadline.trim()) {
+                if deadline <= Utc::now() {
+                    reject!(responses::DEADLINE_IN_PAST.get(response_language));
+                }
+                Some(deadline)
+            } else {
+                reject!(responses::INVALID_DEADLINE.get(response_language));
+            };
+            race.scheduling_deadline = deadline;
+            race.last_edited_by = Some(me.id);
+            race.last_edited_at = Some(Utc::now());
+            race.save(&mut transaction).await?;
+            sqlx::query!("UPDATE races SET deadline_reminded_3d = FALSE, deadline_reminded_24h = FALSE, deadline_organizer_notified = FALSE WHERE id = $1", race.id as _).execute(&mut *transaction).await?;
+            transaction.commit().await?;
+            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                .ephemeral(false)
+                .content(if let Some(deadline) = deadline {
+                    format!("This race must now be scheduled by <t:{}:F>.", deadline.timestamp())
+                } else {
+                    format!("This race no longer has a scheduling deadline.")
+                })
+            )).await?;
+        }
+        name => panic!("unexpected subcommand for /race: {name}"),
+    }
+    Ok(())
+}
+
 async fn reset_async_command(ctx: &DiscordCtx, interaction: &CommandInteraction) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let mut participant = None;
     let mut game = None;
@@ -667,10 +1024,10 @@ async fn reset_async_command(ctx: &DiscordCtx, interaction: &CommandInteraction)
             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                 .ephemeral(true)
                 .content(if game.is_some() {
-                    "Sorry, there is no race with that game number in this scheduling thread."
+                    responses::NO_RACE_WITH_GAME_NUMBER
                 } else {
-                    "Sorry, this is not a race scheduling thread."
-                })
+                    responses::NOT_SCHEDULING_THREAD
+                }.get(discord_localization::response_language(None, &interaction.locale)))
             )).await?;
             transaction.rollback().await?;
             return Ok(())
@@ -685,16 +1042,17 @@ async fn reset_async_command(ctx: &DiscordCtx, interaction: &CommandInteraction)
         }
     };
     let event = race.event(&mut transaction).await?;
-    let is_organizer = event.organizers(&mut transaction).await?.into_iter()
-        .any(|organizer| organizer.discord.is_some_and(|discord| discord.id == interaction.user.id));
-    if !is_organizer {
+    let Some(me) = event.organizers(&mut transaction).await?.into_iter()
+        .find(|organizer| organizer.discord.is_some_and(|discord| discord.id == interaction.user.id))
+    else {
         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
             .ephemeral(true)
-            .content("Sorry, only event organizers can use this command.")
+            .content(responses::ONLY_ORGANIZERS.get(discord_localization::response_language(Some(event.language), interaction.locale())))
         )).await?;
         transaction.rollback().await?;
         return Ok(())
-    }
+    };
+    cal::set_change_context(&mut transaction, Some(me.id), cal::ChangeSource::Discord).await?;
     if !matches!(race.schedule, RaceSchedule::Async { .. }) {
         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
             .ephemeral(true)
@@ -780,7 +1138,7 @@ async fn reset_async_command(ctx: &DiscordCtx, interaction: &CommandInteraction)
 }
 
 //TODO refactor (MH admins should have permissions, room already being open should not remove permissions but only remove the team from return)
-async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interaction: &impl GenericInteraction, game: Option<i16>, allow_rooms_for_other_teams: bool, alternative_instructions: Option<&str>, already_deferred: bool, skip_room_check: bool, race_filter: SchedulingRaceFilter) -> Result<Option<(Transaction<'a, Postgres>, Race, Option<Team>)>, Box<dyn std::error::Error + Send + Sync>> {
+async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interaction: &impl GenericInteraction, game: Option<i16>, allow_rooms_for_other_teams: bool, alternative_instructions: Option<discord_localization::Text>, already_deferred: bool, skip_room_check: bool, race_filter: SchedulingRaceFilter) -> Result<Option<(Transaction<'a, Postgres>, Race, Option<Team>)>, Box<dyn std::error::Error + Send + Sync>> {
     let (mut transaction, http_client) = {
         let data = ctx.data.read().await;
         (
@@ -821,7 +1179,7 @@ async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interactio
                 .expect("interaction called from guild with conflicting draft kinds");
             let mut content = MessageBuilder::default();
             if filtered_out_played_async {
-                content.push("Sorry, you don't have any remaining unplayed async games in this thread.");
+                content.push(responses::NO_UNPLAYED_ASYNCS.get(discord_localization::response_language(None, interaction.locale())));
                 if already_deferred {
                     interaction.edit_response(ctx, EditInteractionResponse::new()
                         .content(content.build())
@@ -835,32 +1193,8 @@ async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interactio
                 transaction.rollback().await?;
                 return Ok(None)
             }
-            match (Race::for_scheduling_channel(&mut transaction, &http_client, interaction.channel_id(), game, true).await?.is_empty(), game.is_some()) {
-                (false, false) => {
-                    content.push("Sorry, this thread is not associated with any upcoming races. ");
-                    if let Some(alternative_instructions) = alternative_instructions {
-                        content.push(alternative_instructions);
-                        content.push(", or tournament organizers can use ");
-                    } else {
-                        content.push("Tournament organizers can use ");
-                    }
-                    content.mention_command(command_ids.reset_race, "reset-race");
-                    content.push(" if necessary.");
-                }
-                (false, true) => {
-                    content.push("Sorry, there don't seem to be any upcoming races with that game number associated with this thread. ");
-                    if let Some(alternative_instructions) = alternative_instructions {
-                        content.push(alternative_instructions);
-                        content.push(", or tournament organizers can use ");
-                    } else {
-                        content.push("Tournament organizers can use ");
-                    }
-                    content.mention_command(command_ids.reset_race, "reset-race");
-                    content.push(" if necessary.");
-                }
-                (true, false) => { content.push("Sorry, this thread is not associated with any upcoming races. Please contact a tournament organizer to fix this."); }
-                (true, true) => { content.push("Sorry, there don't seem to be any upcoming races with that game number associated with this thread. If this seems wrong, please contact a tournament organizer to fix this."); }
-            }
+            let has_races = !Race::for_scheduling_channel(&mut transaction, &http_client, interaction.channel_id(), game, true).await?.is_empty();
+            content.push(responses::no_upcoming_races(discord_localization::response_language(None, interaction.locale()), game.is_some(), has_races, alternative_instructions, command_ids.reset_race));
             if already_deferred {
                 interaction.edit_response(ctx, EditInteractionResponse::new()
                     .content(content.build())
@@ -875,6 +1209,8 @@ async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interactio
             None
         }
         Ok(Some(race)) => {
+            let actor = User::from_discord(&mut *transaction, interaction.user_id()).await?;
+            cal::set_change_context(&mut transaction, actor.map(|actor| actor.id), cal::ChangeSource::Discord).await?;
             let mut team = None;
             for iter_team in race.teams() {
                 if iter_team.members(&mut transaction).await?.into_iter().any(|member| member.discord.is_some_and(|discord| discord.id == interaction.user_id())) {
@@ -889,14 +1225,15 @@ async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interactio
                     race.has_any_room()
                 };
                 if blocked && !skip_room_check {
+                    let language = discord_localization::response_language(None, interaction.locale());
                     if already_deferred {
                         interaction.edit_response(ctx, EditInteractionResponse::new()
-                            .content("Sorry, this command can't be used since a race room is already open. Please contact a tournament organizer if necessary.")
+                            .content(responses::ROOM_ALREADY_OPEN.get(language))
                         ).await?;
                     } else {
                         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                             .ephemeral(true)
-                            .content("Sorry, this command can't be used since a race room is already open. Please contact a tournament organizer if necessary.")
+                            .content(responses::ROOM_ALREADY_OPEN.get(language))
                         )).await?;
                     }
                     transaction.rollback().await?;
@@ -906,14 +1243,15 @@ async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interactio
             Some((transaction, race, team))
         }
         Err(_) => {
+            let language = discord_localization::response_language(None, interaction.locale());
             if already_deferred {
                 interaction.edit_response(ctx, EditInteractionResponse::new()
-                    .content("Sorry, this thread is associated with multiple upcoming races. Please contact a tournament organizer to fix this.")
+                    .content(responses::MULTIPLE_UPCOMING_RACES.get(language))
                 ).await?;
             } else {
                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                     .ephemeral(true)
-                    .content("Sorry, this thread is associated with multiple upcoming races. Please contact a tournament organizer to fix this.")
+                    .content(responses::MULTIPLE_UPCOMING_RACES.get(language))
                 )).await?;
             }
             transaction.rollback().await?;
@@ -923,7 +1261,7 @@ async fn check_scheduling_thread_permissions<'a>(ctx: &'a DiscordCtx, interactio
 }
 
 async fn check_draft_permissions<'a>(ctx: &'a DiscordCtx, interaction: &impl GenericInteraction) -> Result<Option<(event::Data<'static>, Race, draft::Kind, draft::MessageContext<'a>)>, Box<dyn std::error::Error + Send + Sync>> {
-    let Some((mut transaction, race, team)) = check_scheduling_thread_permissions(ctx, interaction, None, false, Some("You can continue the draft in the race room"), false, true, SchedulingRaceFilter::Upcoming).await? else { return Ok(None) };
+    let Some((mut transaction, race, team)) = check_scheduling_thread_permissions(ctx, interaction, None, false, Some(responses::CONTINUE_DRAFT_IN_ROOM), false, true, SchedulingRaceFilter::Upcoming).await? else { return Ok(None) };
     let guild_id = interaction.guild_id().expect("Received interaction from outside of a guild");
     let event = race.event(&mut transaction).await?;
     Ok(if let Some(team) = team {
@@ -939,11 +1277,7 @@ async fn check_draft_permissions<'a>(ctx: &'a DiscordCtx, interaction: &impl Gen
                     };
                     Some((event, race, draft_kind, msg_ctx))
                 } else {
-                    let response_content = if let French = event.language {
-                        format!("Désolé, mais ce n'est pas votre tour.")
-                    } else {
-                        format!("Sorry, it's not {} turn in the settings draft.", if let TeamConfig::Solo = event.team_config { "your" } else { "your team's" })
-                    };
+                    let response_content = responses::not_your_turn(discord_localization::response_language(Some(event.language), interaction.locale()), matches!(event.team_config, TeamConfig::Solo));
                     interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                         .ephemeral(true)
                         .content(response_content)
@@ -954,7 +1288,7 @@ async fn check_draft_permissions<'a>(ctx: &'a DiscordCtx, interaction: &impl Gen
             } else {
                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                     .ephemeral(true)
-                    .content("Sorry, this race's settings draft has not been initialized. Please contact a tournament organizer to fix this.")
+                    .content(responses::DRAFT_NOT_INITIALIZED.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                 )).await?;
                 transaction.rollback().await?;
                 None
@@ -962,17 +1296,13 @@ async fn check_draft_permissions<'a>(ctx: &'a DiscordCtx, interaction: &impl Gen
         } else {
             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                 .ephemeral(true)
-                .content("Sorry, there is no settings draft for this event.")
+                .content(responses::NO_SETTINGS_DRAFT.get(discord_localization::response_language(Some(event.language), interaction.locale())))
             )).await?;
             transaction.rollback().await?;
             None
         }
     } else {
-        let response_content = if let French = event.language {
-            "Désolé, seuls les participants de la race peuvent utiliser cette commande."
-        } else {
-            "Sorry, only participants in this race can use this command."
-        };
+        let response_content = responses::ONLY_PARTICIPANTS.get(discord_localization::response_language(Some(event.language), interaction.locale()));
         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
             .ephemeral(true)
             .content(response_content)
@@ -1351,6 +1681,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
             hostname: Cow::Borrowed(racetime_host()),
             ..racetime::HostInfo::default()
         })
+        .data::<Config>(config.clone())
         .data::<ConfigRaceTime>(config.racetime_bot.clone())
         .data::<StartggToken>(config.startgg)
         .data::<ChallongeApiKey>(config.challonge_api_key.clone())
@@ -1387,22 +1718,20 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::S7 | draft::Kind::MultiworldS3 | draft::Kind::MultiworldS4 | draft::Kind::MultiworldS5 => CreateCommand::new("ban")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Locks a setting for this race to its default value."),
+                        .localized_description(descriptions::BAN),
                     draft::Kind::RslS7 => CreateCommand::new("block")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Blocks the weights of a setting from being changed."),
+                        .localized_description(descriptions::BLOCK_WEIGHTS),
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("ban")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Verrouille un setting à sa valeur par défaut.")
-                        .description_localized("en-GB", "Locks a setting for this race to its default value.")
-                        .description_localized("en-US", "Locks a setting for this race to its default value."),
+                        .localized_description_in(French, descriptions::BAN),
                     draft::Kind::PickOnly { .. } => return None, // no bans in pick-only draft
                     draft::Kind::BanPick { .. } | draft::Kind::BanOnly { .. } => CreateCommand::new("ban")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Bans a preset from being played in this match."),
+                        .localized_description(descriptions::BAN_PRESET),
                 });
                 Some(idx)
             });
@@ -1411,12 +1740,8 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 commands.push(CreateCommand::new("delete-after")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Deletes games of the match that are not required.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "game",
-                        "The last game number within the match that should be kept.",
-                    )
+                    .localized_description(descriptions::DELETE_AFTER)
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "game", descriptions::DELETE_AFTER_GAME)
                         .min_int_value(1)
                         .max_int_value(255)
                         .required(true)
@@ -1430,18 +1755,16 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::S7 | draft::Kind::MultiworldS3 | draft::Kind::MultiworldS4 | draft::Kind::MultiworldS5 => CreateCommand::new("draft")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Chooses a setting for this race (same as /pick)."),
+                        .localized_description(descriptions::DRAFT),
                     draft::Kind::RslS7 => return None, // command is called /ban, no alias necessary
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("draft")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Choisit un setting pour la race (identique à /pick).")
-                        .description_localized("en-GB", "Chooses a setting for this race (same as /pick).")
-                        .description_localized("en-US", "Chooses a setting for this race (same as /pick)."),
+                        .localized_description_in(French, descriptions::DRAFT),
                     draft::Kind::PickOnly { .. } | draft::Kind::BanPick { .. } => CreateCommand::new("draft")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Picks a preset for a game (same as /pick)."),
+                        .localized_description(descriptions::DRAFT_PRESET),
                     draft::Kind::BanOnly { .. } => return None, // no picks in ban-only draft
                 });
                 Some(idx)
@@ -1453,31 +1776,19 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::S7 | draft::Kind::MultiworldS3 | draft::Kind::MultiworldS4 | draft::Kind::MultiworldS5 => CreateCommand::new("first")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Go first in the settings draft."),
+                        .localized_description(descriptions::FIRST),
                     draft::Kind::RslS7 => CreateCommand::new("first")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Go first in the weights draft.")
-                        .add_option(CreateCommandOption::new(
-                            CommandOptionType::Boolean,
-                            "lite",
-                            "Use RSL-Lite weights",
-                        )
+                        .localized_description(descriptions::FIRST_WEIGHTS)
+                        .add_option(discord_localization::option(CommandOptionType::Boolean, "lite", descriptions::LITE)
                             .required(false)
                         ),
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("first")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Partir premier dans la phase de pick&ban.")
-                        .description_localized("en-GB", "Go first in the settings draft.")
-                        .description_localized("en-US", "Go first in the settings draft.")
-                        .add_option(CreateCommandOption::new(
-                            CommandOptionType::Integer,
-                            "mq",
-                            "Nombre de donjons MQ",
-                        )
-                            .description_localized("en-GB", "Number of MQ dungeons")
-                            .description_localized("en-US", "Number of MQ dungeons")
+                        .localized_description_in(French, descriptions::FIRST)
+                        .add_option(discord_localization::option_in(French, CommandOptionType::Integer, "mq", descriptions::MQ)
                             .min_int_value(0)
                             .max_int_value(12)
                             .required(false)
@@ -1492,9 +1803,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("no")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Répond à la négative dans une question fermée.")
-                        .description_localized("en-GB", "Answers no to a yes/no question in the settings draft.")
-                        .description_localized("en-US", "Answers no to a yes/no question in the settings draft."),
+                        .localized_description_in(French, descriptions::NO),
                 });
                 Some(idx)
             });
@@ -1505,30 +1814,38 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::S7 | draft::Kind::MultiworldS3 | draft::Kind::MultiworldS4 | draft::Kind::MultiworldS5 => CreateCommand::new("pick")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Chooses a setting for this race."),
+                        .localized_description(descriptions::PICK),
                     draft::Kind::RslS7 => CreateCommand::new("ban")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Sets a weight of a setting to 0."),
+                        .localized_description(descriptions::BAN_WEIGHTS),
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("pick")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Choisit un setting pour la race.")
-                        .description_localized("en-GB", "Chooses a setting for this race.")
-                        .description_localized("en-US", "Chooses a setting for this race."),
+                        .localized_description_in(French, descriptions::PICK),
                     draft::Kind::PickOnly { .. } | draft::Kind::BanPick { .. } => CreateCommand::new("pick")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Picks a preset for a game in the match."),
+                        .localized_description(descriptions::PICK_PRESET),
                 });
                 Some(idx)
             });
+            let post_signup = {
+                let idx = commands.len();
+                commands.push(CreateCommand::new("post-signup")
+                    .kind(CommandType::ChatInput)
+                    .default_member_permissions(Permissions::ADMINISTRATOR)
+                    .add_context(InteractionContext::Guild)
+                    .localized_description(descriptions::POST_SIGNUP)
+                );
+                idx
+            };
             let post_status = {
                 let idx = commands.len();
                 commands.push(CreateCommand::new("post-status")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Posts this race's status to the thread, pinging the team whose turn it is in the settings draft.")
+                    .localized_description(descriptions::POST_STATUS)
                 );
                 idx
             };
@@ -1538,7 +1855,59 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     .kind(CommandType::ChatInput)
                     .default_member_permissions(Permissions::ADMINISTRATOR)
                     .add_context(InteractionContext::Guild)
-                    .description("Creates gender pronoun roles and posts a message here that allows members to self-assign them.")
+                    .localized_description(descriptions::PRONOUN_ROLES)
+                );
+                idx
+            };
+            let race = {
+                let idx = commands.len();
+                let game_option = || discord_localization::option(CommandOptionType::Integer, "game", descriptions::GAME)
+                    .min_int_value(1)
+                    .max_int_value(255)
+                    .required(false);
+                let mut language_option = discord_localization::option(CommandOptionType::String, "language", descriptions::RESTREAM_LANGUAGE)
+                    .required(true);
+                for language in all::<Language>() {
+                    language_option = language_option.add_string_choice(language.to_string(), language.short_code());
+                }
+                commands.push(CreateCommand::new("race")
+                    .kind(CommandType::ChatInput)
+                    .add_context(InteractionContext::Guild)
+                    .localized_description(descriptions::RACE)
+                    .add_option(discord_localization::option(CommandOptionType::SubCommand, "ignore", descriptions::RACE_IGNORE)
+                        .add_sub_option(game_option())
+                    )
+                    .add_option(discord_localization::option(CommandOptionType::SubCommand, "lock-schedule", descriptions::RACE_LOCK_SCHEDULE)
+                        .add_sub_option(discord_localization::option(CommandOptionType::Boolean, "locked", descriptions::RACE_LOCKED)
+                            .required(false)
+                        )
+                        .add_sub_option(game_option())
+                    )
+                    .add_option(discord_localization::option(CommandOptionType::SubCommand, "restream", descriptions::RACE_RESTREAM)
+                        .add_sub_option(language_option)
+                        .add_sub_option(discord_localization::option(CommandOptionType::String, "url", descriptions::RESTREAM_URL)
+                            .required(false)
+                        )
+                        .add_sub_option(discord_localization::option(CommandOptionType::String, "restreamer", descriptions::RESTREAMER)
+                            .required(false)
+                        )
+                        .add_sub_option(game_option())
+                    )
+                    .add_option(discord_localization::option(CommandOptionType::SubCommand, "walkover", descriptions::RACE_WALKOVER)
+                        .add_sub_option(discord_localization::option(CommandOptionType::User, "winner", descriptions::WALKOVER_WINNER)
+                            .required(true)
+                        )
+                        .add_sub_option(discord_localization::option(CommandOptionType::String, "reason", descriptions::WALKOVER_REASON)
+                            .required(false)
+                        )
+                        .add_sub_option(game_option())
+                    )
+                    .add_option(discord_localization::option(CommandOptionType::SubCommand, "set-deadline", descriptions::RACE_SET_DEADLINE)
+                        .add_sub_option(discord_localization::option(CommandOptionType::String, "deadline", descriptions::DEADLINE)
+                            .required(true)
+                        )
+                        .add_sub_option(game_option())
+                    )
                 );
                 idx
             };
@@ -1548,12 +1917,8 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     .kind(CommandType::ChatInput)
                     .default_member_permissions(Permissions::ADMINISTRATOR)
                     .add_context(InteractionContext::Guild)
-                    .description("Creates a racing role and posts a message here that allows members to self-assign it.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Channel,
-                        "race-planning-channel",
-                        "Will be linked to from the description message.",
-                    )
+                    .localized_description(descriptions::RACING_ROLE)
+                    .add_option(discord_localization::option(CommandOptionType::Channel, "race-planning-channel", descriptions::LINKED_CHANNEL)
                         .required(true)
                         .channel_types(vec![ChannelType::Text, ChannelType::News])
                     )
@@ -1565,30 +1930,18 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 let mut command = CreateCommand::new("reset-race")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Deletes selected data from a race.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "game",
-                        "The game number within the match.",
-                    )
+                    .localized_description(descriptions::RESET_RACE)
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "game", descriptions::GAME)
                         .min_int_value(1)
                         .max_int_value(255)
                         .required(false)
                     );
                 if draft_kind.is_some() || has_button_draft {
-                    command = command.add_option(CreateCommandOption::new(
-                        CommandOptionType::Boolean,
-                        "draft",
-                        "Reset the settings draft.",
-                    )
+                    command = command.add_option(discord_localization::option(CommandOptionType::Boolean, "draft", descriptions::RESET_DRAFT)
                         .required(false)
                     );
                 }
-                command = command.add_option(CreateCommandOption::new(
-                    CommandOptionType::Boolean,
-                    "schedule",
-                    "Reset the schedule, race room, and seed.",
-                )
+                command = command.add_option(discord_localization::option(CommandOptionType::Boolean, "schedule", descriptions::RESET_SCHEDULE)
                     .required(false)
                 );
                 commands.push(command);
@@ -1599,19 +1952,11 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 commands.push(CreateCommand::new("reset-async")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Resets one participant's unplayed async part. Only for organizers.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::User,
-                        "participant",
-                        "The participant whose async part should be reset.",
-                    )
+                    .localized_description(descriptions::RESET_ASYNC)
+                    .add_option(discord_localization::option(CommandOptionType::User, "participant", descriptions::RESET_ASYNC_PARTICIPANT)
                         .required(true)
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "game",
-                        "The game number within the match. Required when the thread has multiple games.",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "game", descriptions::RESET_ASYNC_GAME)
                         .min_int_value(1)
                         .max_int_value(255)
                         .required(false)
@@ -1625,7 +1970,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     CreateCommand::new("restart-room")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Recreates a race room that was cancelled due to inactivity."),
+                        .localized_description(descriptions::RESTART_ROOM),
                 );
                 idx
             };
@@ -1634,21 +1979,11 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 commands.push(CreateCommand::new("schedule")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Submits a starting time for this race.")
-                    .description_localized("fr", "Planifie une date/heure pour une race.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::String,
-                        "start",
-                        "The starting time as a Discord timestamp",
-                    )
-                        .description_localized("fr", "La date de début comme timestamp de Discord")
+                    .localized_description(descriptions::SCHEDULE)
+                    .add_option(discord_localization::option(CommandOptionType::String, "start", descriptions::SCHEDULE_START)
                         .required(true)
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "game",
-                        "The game number within the match. Defaults to the next upcoming game.",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "game", descriptions::NEXT_GAME)
                         .min_int_value(1)
                         .max_int_value(255)
                         .required(false)
@@ -1661,21 +1996,11 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 commands.push(CreateCommand::new("schedule-async")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Submits a starting time for your half of this race.")
-                    .description_localized("fr", "Planifie votre partie de l'async.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::String,
-                        "start",
-                        "The starting time as a Discord timestamp",
-                    )
-                        .description_localized("fr", "La date de début comme timestamp de Discord")
+                    .localized_description(descriptions::SCHEDULE_ASYNC)
+                    .add_option(discord_localization::option(CommandOptionType::String, "start", descriptions::SCHEDULE_START)
                         .required(true)
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "game",
-                        "The game number within the match. Defaults to the next upcoming game.",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "game", descriptions::NEXT_GAME)
                         .min_int_value(1)
                         .max_int_value(255)
                         .required(false)
@@ -1688,35 +2013,19 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 commands.push(CreateCommand::new("result-async")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Records finish time for async race part. Only time needed in async thread.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::String,
-                        "time",
-                        "Finish time in format hh:mm:ss",
-                    )
+                    .localized_description(descriptions::RESULT_ASYNC)
+                    .add_option(discord_localization::option(CommandOptionType::String, "time", descriptions::FINISH_TIME)
                         .required(true)
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::String,
-                        "race_id",
-                        "The ID of the race (optional when used in async thread)",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::String, "race_id", descriptions::RACE_ID)
                         .required(false)
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "async_part",
-                        "The async part number (1, 2, or 3) (optional when used in async thread)",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "async_part", descriptions::ASYNC_PART)
                         .min_int_value(1)
                         .max_int_value(3)
                         .required(false)
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::String,
-                        "link",
-                        "Link to the recording/VoD for this async part (optional)",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::String, "link", descriptions::VOD_LINK)
                         .required(false)
                     )
                 );
@@ -1727,19 +2036,11 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 commands.push(CreateCommand::new("forfeit-async")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Marks a player as forfeiting in an async race part. Only for organizers.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::String,
-                        "race_id",
-                        "The ID of the race (optional when used in async thread)",
-                    )
+                    .localized_description(descriptions::FORFEIT_ASYNC)
+                    .add_option(discord_localization::option(CommandOptionType::String, "race_id", descriptions::RACE_ID)
                         .required(false)
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "async_part",
-                        "The async part number (1, 2, or 3) (optional when used in async thread)",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "async_part", descriptions::ASYNC_PART)
                         .min_int_value(1)
                         .max_int_value(3)
                         .required(false)
@@ -1752,13 +2053,8 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 commands.push(CreateCommand::new("schedule-remove")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Removes the starting time(s) for this race from the schedule.")
-                    .description_localized("fr", "Supprime le(s) date(s) de début sur le document des races planifiées.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Integer,
-                        "game",
-                        "The game number within the match. Defaults to the next upcoming game.",
-                    )
+                    .localized_description(descriptions::SCHEDULE_REMOVE)
+                    .add_option(discord_localization::option(CommandOptionType::Integer, "game", descriptions::NEXT_GAME)
                         .min_int_value(1)
                         .max_int_value(255)
                         .required(false)
@@ -1773,31 +2069,19 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::S7 | draft::Kind::MultiworldS3 | draft::Kind::MultiworldS4 | draft::Kind::MultiworldS5 => CreateCommand::new("second")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Go second in the settings draft."),
+                        .localized_description(descriptions::SECOND),
                     draft::Kind::RslS7 => CreateCommand::new("second")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Go second in the weights draft.")
-                        .add_option(CreateCommandOption::new(
-                            CommandOptionType::Boolean,
-                            "lite",
-                            "Use RSL-Lite weights",
-                        )
+                        .localized_description(descriptions::SECOND_WEIGHTS)
+                        .add_option(discord_localization::option(CommandOptionType::Boolean, "lite", descriptions::LITE)
                             .required(false)
                         ),
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("second")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Partir second dans la phase de pick&ban.")
-                        .description_localized("en-GB", "Go second in the settings draft.")
-                        .description_localized("en-US", "Go second in the settings draft.")
-                        .add_option(CreateCommandOption::new(
-                            CommandOptionType::Integer,
-                            "mq",
-                            "Nombre de donjons MQ",
-                        )
-                            .description_localized("en-GB", "Number of MQ dungeons")
-                            .description_localized("en-US", "Number of MQ dungeons")
+                        .localized_description_in(French, descriptions::SECOND)
+                        .add_option(discord_localization::option_in(French, CommandOptionType::Integer, "mq", descriptions::MQ)
                             .min_int_value(0)
                             .max_int_value(12)
                             .required(false)
@@ -1812,27 +2096,33 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::S7 | draft::Kind::MultiworldS3 | draft::Kind::MultiworldS4 | draft::Kind::MultiworldS5 => CreateCommand::new("skip")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Skips your current turn of the settings draft."),
+                        .localized_description(descriptions::SKIP),
                     draft::Kind::RslS7 => CreateCommand::new("skip")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Skips your current turn of the weights draft."),
+                        .localized_description(descriptions::SKIP_WEIGHTS),
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("skip")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Skip le dernier pick du draft.")
-                        .description_localized("en-GB", "Skips the final pick of the settings draft.")
-                        .description_localized("en-US", "Skips the final pick of the settings draft."),
+                        .localized_description_in(French, descriptions::SKIP_FINAL_PICK),
                 });
                 Some(idx)
             });
+            let standings = {
+                let idx = commands.len();
+                commands.push(CreateCommand::new("standings")
+                    .kind(CommandType::ChatInput)
+                    .add_context(InteractionContext::Guild)
+                    .localized_description(descriptions::STANDINGS)
+                );
+                idx
+            };
             let status = {
                 let idx = commands.len();
                 commands.push(CreateCommand::new("status")
                     .kind(CommandType::ChatInput)
                     .add_context(InteractionContext::Guild)
-                    .description("Shows you this race's current scheduling and settings draft status.")
-                    .description_localized("fr", "Montre l'avancement de la planification de votre race, avec les détails.")
+                    .localized_description(descriptions::STATUS)
                 );
                 idx
             };
@@ -1842,20 +2132,12 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     .kind(CommandType::ChatInput)
                     .default_member_permissions(Permissions::ADMINISTRATOR)
                     .add_context(InteractionContext::Guild)
-                    .description("Creates watch notification roles and posts a message here that allows members to self-assign them.")
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Channel,
-                        "watch-party-channel",
-                        "Will be linked to from the description message.",
-                    )
+                    .localized_description(descriptions::WATCH_ROLES)
+                    .add_option(discord_localization::option(CommandOptionType::Channel, "watch-party-channel", descriptions::LINKED_CHANNEL)
                         .required(true)
                         .channel_types(vec![ChannelType::Voice, ChannelType::Stage])
                     )
-                    .add_option(CreateCommandOption::new(
-                        CommandOptionType::Channel,
-                        "race-rooms-channel",
-                        "Will be linked to from the description message.",
-                    )
+                    .add_option(discord_localization::option(CommandOptionType::Channel, "race-rooms-channel", descriptions::LINKED_CHANNEL)
                         .required(true)
                         .channel_types(vec![ChannelType::Text, ChannelType::News])
                     )
@@ -1869,9 +2151,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                     draft::Kind::TournoiFrancoS3 | draft::Kind::TournoiFrancoS4 | draft::Kind::TournoiFrancoS5 => CreateCommand::new("yes")
                         .kind(CommandType::ChatInput)
                         .add_context(InteractionContext::Guild)
-                        .description("Répond à l'affirmative dans une question fermée.")
-                        .description_localized("en-GB", "Answers yes to a yes/no question in the settings draft.")
-                        .description_localized("en-US", "Answers yes to a yes/no question in the settings draft."),
+                        .localized_description_in(French, descriptions::YES),
                 });
                 Some(idx)
             });
@@ -1883,8 +2163,10 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 first: first.map(|idx| commands[idx].id),
                 no: no.map(|idx| commands[idx].id),
                 pick: pick.map(|idx| commands[idx].id),
+                post_signup: commands[post_signup].id,
                 post_status: commands[post_status].id,
                 pronoun_roles: commands[pronoun_roles].id,
+                race: commands[race].id,
                 racing_role: commands[racing_role].id,
                 reset_async: commands[reset_async].id,
                 reset_race: commands[reset_race].id,
@@ -1896,6 +2178,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                 schedule_remove: commands[schedule_remove].id,
                 second: second.map(|idx| commands[idx].id),
                 skip: skip.map(|idx| commands[idx].id),
+                standings: commands[standings].id,
                 status: commands[status].id,
                 watch_roles: commands[watch_roles].id,
                 yes: yes.map(|idx| commands[idx].id),
@@ -1928,7 +2211,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             let Some(parent_channel) = interaction.channel.as_ref().and_then(|thread| thread.parent_id) else {
                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                     .ephemeral(true)
-                                    .content("Sorry, this command can only be used inside threads and forum posts.")
+                                    .content(responses::THREADS_ONLY.get(discord_localization::response_language(None, interaction.locale())))
                                 )).await?;
                                 return Ok(())
                             };
@@ -1936,12 +2219,12 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             if let Some(event_row) = sqlx::query!(r#"SELECT series AS "series: Series", event FROM events WHERE discord_scheduling_channel = $1 AND end_time IS NULL"#, PgSnowflake(parent_channel) as _).fetch_optional(&mut *transaction).await? {
                                 let event = event::Data::new(&mut transaction, event_row.series, event_row.event).await?.expect("just received from database");
                                 match event.match_source() {
-                                    MatchSource::Manual | MatchSource::Challonge { .. } => {}
+                                    MatchSource::Manual | MatchSource::Challonge { .. } | MatchSource::Bracket => {}
                                     MatchSource::StartGG(_) => {} //TODO automate
                                     MatchSource::League => {
                                         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                             .ephemeral(true)
-                                            .content("Sorry, this command is not available for events sourcing their match schedule from league.ootrandomizer.com")
+                                            .content(responses::LEAGUE_SCHEDULE.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                         )).await?;
                                         return Ok(())
                                     }
@@ -1949,7 +2232,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 if !event.organizers(&mut transaction).await?.into_iter().any(|organizer| organizer.discord.is_some_and(|discord| discord.id == interaction.user.id)) {
                                     interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                         .ephemeral(true)
-                                        .content("Sorry, only event organizers can use this command.")
+                                        .content(responses::ONLY_ORGANIZERS.get(discord_localization::response_language(None, interaction.locale())))
                                     )).await?;
                                     return Ok(())
                                 }
@@ -1963,7 +2246,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                     .ephemeral(true)
                                     .content(if races_deleted == 0 {
-                                        format!("Sorry, looks like that didn't delete any races.")
+                                        responses::NO_RACES_DELETED.get(discord_localization::response_language(Some(event.language), interaction.locale())).to_owned()
                                     } else {
                                         format!("{races_deleted} race{} deleted from the schedule.", if races_deleted == 1 { "" } else { "s" })
                                     })
@@ -1971,13 +2254,13 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             } else {
                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                     .ephemeral(true)
-                                    .content("Sorry, this channel is not configured as the scheduling channel for any ongoing Hyrule Town Hall events.")
+                                    .content(responses::NOT_HTH_SCHEDULING_CHANNEL.get(discord_localization::response_language(None, interaction.locale())))
                                 )).await?;
                             }
                         } else if Some(interaction.data.id) == command_ids.draft || Some(interaction.data.id) == command_ids.pick {
                             send_draft_settings_page(ctx, interaction, "draft", 0).await?;
                         } else if Some(interaction.data.id) == command_ids.first {
-                              if let Some((_, mut race, draft_kind, msg_ctx)) = check_draft_permissions(ctx, interaction).await? {
+                              if let Some((event, mut race, draft_kind, msg_ctx)) = check_draft_permissions(ctx, interaction).await? {
                                 match draft_kind {
                                     draft::Kind::RslS7 => {
                                         let settings = &mut race.draft.as_mut().unwrap().settings;
@@ -1994,7 +2277,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                             } else {
                                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                     .ephemeral(true)
-                                                    .content(MessageBuilder::default().push("Sorry, please specify the ").push_mono("lite").push(" parameter.").build())
+                                                    .content(responses::specify_lite(discord_localization::response_language(Some(event.language), interaction.locale())))
                                                 )).await?;
                                                 transaction.rollback().await?;
                                                 return Ok(())
@@ -2004,7 +2287,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                 //TODO different error messages depending on which player(s) didn't opt into RSL-Lite
                                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                     .ephemeral(true)
-                                                    .content("Sorry, either you or your opponent didn't opt into RSL-Lite.")
+                                                    .content(responses::NO_RSL_LITE_OPT_IN.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                                 )).await?;
                                                 return Ok(())
                                             }
@@ -2047,6 +2330,8 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             }
                         } else if Some(interaction.data.id) == command_ids.no {
                             draft_action(ctx, interaction, draft::Action::BooleanChoice(false), None).await?;
+                        } else if interaction.data.id == command_ids.post_signup {
+                            post_signup_command(ctx, interaction).await?;
                         } else if interaction.data.id == command_ids.post_status {
                             if let Some((mut transaction, race, team)) = check_scheduling_thread_permissions(ctx, interaction, None, true, None, false, false, SchedulingRaceFilter::Upcoming).await? {
                                 let event = race.event(&mut transaction).await?;
@@ -2073,25 +2358,21 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                         } else {
                                             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                 .ephemeral(true)
-                                                .content("Sorry, this race's settings draft has not been initialized. Please contact a tournament organizer to fix this.")
+                                                .content(responses::DRAFT_NOT_INITIALIZED.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                             )).await?;
                                             transaction.rollback().await?;
                                         }
                                     } else {
                                         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                             .ephemeral(true)
-                                            .content("Sorry, this command is currently only available for events with settings drafts.") //TODO
+                                            .content(responses::SETTINGS_DRAFTS_ONLY.get(discord_localization::response_language(Some(event.language), interaction.locale()))) //TODO
                                         )).await?;
                                         transaction.rollback().await?;
                                     }
                                 } else {
                                     interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                         .ephemeral(true)
-                                        .content(if let French = event.language {
-                                            "Désolé, seuls les organisateurs du tournoi peuvent utiliser cette commande."
-                                        } else {
-                                            "Sorry, only organizers can use this command."
-                                        })
+                                        .content(responses::ONLY_ORGANIZERS.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                     )).await?;
                                     transaction.rollback().await?;
                                 }
@@ -2129,6 +2410,8 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 .button(CreateButton::new("pronouns_they").label("they/them"))
                                 .button(CreateButton::new("pronouns_other").label("other"))
                             )).await?;
+                        } else if interaction.data.id == command_ids.race {
+                            race_command(ctx, interaction).await?;
                         } else if interaction.data.id == command_ids.racing_role {
                             let race_planning_channel = match interaction.data.options[0].value {
                                 CommandDataOptionValue::Channel(channel) => channel,
@@ -2156,7 +2439,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             let Some(_parent_channel) = interaction.channel.as_ref().and_then(|thread| thread.parent_id) else {
                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                     .ephemeral(true)
-                                    .content("Sorry, this command can only be used inside threads and forum posts.")
+                                    .content(responses::THREADS_ONLY.get(discord_localization::response_language(None, interaction.locale())))
                                 )).await?;
                                 return Ok(())
                             };
@@ -2202,7 +2485,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 if !event.organizers(&mut transaction).await?.into_iter().any(|organizer| organizer.discord.is_some_and(|discord| discord.id == interaction.user.id)) {
                                     interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                         .ephemeral(true)
-                                        .content("Sorry, only event organizers can use this command.")
+                                        .content(responses::ONLY_ORGANIZERS.get(discord_localization::response_language(None, interaction.locale())))
                                     )).await?;
                                     return Ok(())
                                 }
@@ -2250,7 +2533,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                     } else {
                                         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                             .ephemeral(true)
-                                            .content("Sorry, unable to reset draft for this race.")
+                                            .content(responses::DRAFT_RESET_FAILED.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                         )).await?;
                                         transaction.rollback().await?;
                                     }
@@ -2260,10 +2543,10 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                 .ephemeral(true)
                                                 .content(if game.is_some() {
-                                                    "Sorry, there don't seem to be any races with that game number associated with this thread."
+                                                    responses::NO_RACES_WITH_GAME_NUMBER
                                                 } else {
-                                                    "Sorry, this thread is not associated with any races."
-                                                })
+                                                    responses::NO_RACES_IN_THREAD
+                                                }.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                             )).await?;
                                             transaction.rollback().await?;
                                         }
@@ -2382,7 +2665,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                             let bracket_platform_name = match event.match_source() {
                                                 MatchSource::Challonge { .. } => Some("Challonge"),
                                                 MatchSource::StartGG(_) => Some("start.gg"),
-                                                MatchSource::Manual | MatchSource::League => None,
+                                                MatchSource::Manual | MatchSource::League | MatchSource::Bracket => None,
                                             };
                                             if let Some(platform) = bracket_platform_name {
                                                 response_builder.push(format!("\n\n**Note:** This race had a recorded result. Please reset it manually on {platform} as well."));
@@ -2405,7 +2688,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                         Err(_) => {
                                             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                 .ephemeral(true)
-                                                .content("Sorry, this thread is associated with multiple races. Please specify the game number.")
+                                                .content(responses::SPECIFY_GAME_NUMBER.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                             )).await?;
                                             transaction.rollback().await?;
                                         }
@@ -2414,7 +2697,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             } else {
                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                     .ephemeral(true)
-                                    .content("Sorry, this thread is not associated with any races.")
+                                    .content(responses::NO_RACES_IN_THREAD.get(discord_localization::response_language(None, interaction.locale())))
                                 )).await?;
                                 transaction.rollback().await?;
                             }
@@ -2630,27 +2913,15 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                         });
                                         if (start - Utc::now()).to_std().map_or(false, |schedule_notice| schedule_notice > Duration::from_secs(365 * 24 * 60 * 60)) {
                                             interaction.edit_response(ctx, EditInteractionResponse::new()
-                                                .content(if let French = event.language {
-                                                    format!("Désolé, les races ne peuvent pas être planifiées plus d'un an à l'avance.")
-                                                } else {
-                                                    format!("Sorry, races cannot be scheduled more than 1 year in advance.")
-                                                })
+                                                .content(responses::SCHEDULE_TOO_FAR.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                             ).await?;
                                             transaction.rollback().await?;
                                         } else if (start - Utc::now()).to_std().map_or(true, |schedule_notice| schedule_notice < event.min_schedule_notice) {
                                             interaction.edit_response(ctx, EditInteractionResponse::new()
                                                 .content(if event.min_schedule_notice <= Duration::default() {
-                                                    if let French = event.language {
-                                                        format!("Désolé mais cette date est dans le passé.")
-                                                    } else {
-                                                        format!("Sorry, that timestamp is in the past.")
-                                                    }
+                                                    responses::SCHEDULE_IN_PAST.get(discord_localization::response_language(Some(event.language), interaction.locale()))
                                                 } else {
-                                                    if let French = event.language {
-                                                        format!("Désolé, les races doivent être planifiées au moins {} en avance.", French.format_duration(event.min_schedule_notice, true))
-                                                    } else {
-                                                        format!("Sorry, races must be scheduled at least {} in advance.", English.format_duration(event.min_schedule_notice, true))
-                                                    }
+                                                    responses::min_schedule_notice(discord_localization::response_language(Some(event.language), interaction.locale()), event.min_schedule_notice)
                                                 })
                                             ).await?;
                                             transaction.rollback().await?;
@@ -2708,21 +2979,13 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                         }
                                     } else {
                                             interaction.edit_response(ctx, EditInteractionResponse::new()
-                                                .content(if let French = event.language {
-                                                    "Désolé, cela n'est pas un timestamp au format de Discord. Vous pouvez utiliser <https://hammertime.cyou/> pour en générer un, ou entrer directement la date — par exemple `March 2nd 8PM UTC`, `tomorrow 15:00 EST` ou `friday 20h Europe/Paris`."
-                                                } else {
-                                                    "Sorry, I couldn't parse that time. Try natural english language like `friday 8pm UTC`, `tomorrow 15:00 EST`, or `friday 20:00 CET`, or use <https://hammertime.cyou/> to generate a Discord timestamp."
-                                                })
+                                                .content(responses::UNPARSEABLE_TIME.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                         ).await?;
                                         transaction.rollback().await?;
                                     }
                                 } else {
                                     interaction.edit_response(ctx, EditInteractionResponse::new()
-                                        .content(if let French = event.language {
-                                            "Désolé, seuls les participants de cette race et les organisateurs peuvent utiliser cette commande."
-                                        } else {
-                                            "Sorry, only participants in this race and organizers can use this command."
-                                        })
+                                        .content(responses::ONLY_PARTICIPANTS_AND_ORGANIZERS.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                     ).await?;
                                     transaction.rollback().await?;
                                 }
@@ -2743,11 +3006,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 if event.automated_asyncs && event.discord_async_channel.is_none() {
                                     interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                         .ephemeral(true)
-                                        .content(if let French = event.language {
-                                            format!("Désolé, la planification automatique des asyncs est mal configurée pour cet événement (canal Discord manquant). Merci de contacter un organisateur du tournoi.")
-                                        } else {
-                                            format!("Sorry, automated async scheduling is misconfigured for this event (missing Discord channel). Please contact a tournament organizer.")
-                                        })
+                                        .content(responses::ASYNC_CHANNEL_MISSING.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                     )).await?;
                                     transaction.rollback().await?;
                                 } else if speedgaming_export::LEGACY_IMPORT_ENABLED && let Some(speedgaming_slug) = &event.speedgaming_slug {
@@ -2771,11 +3030,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                             if async_part_has_been_played(&mut transaction, &race, async_part).await? {
                                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                     .ephemeral(true)
-                                                    .content(if let Some(game) = race.game {
-                                                        format!("Sorry, your part of Game {game} has already started and can no longer be rescheduled.")
-                                                    } else {
-                                                        "Sorry, your part of this async has already started and can no longer be rescheduled.".to_owned()
-                                                    })
+                                                    .content(responses::async_part_started(discord_localization::response_language(Some(event.language), interaction.locale()), race.game, false))
                                                 )).await?;
                                                 transaction.rollback().await?;
                                                 return Ok(())
@@ -2813,28 +3068,16 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                         if (start - Utc::now()).to_std().map_or(false, |schedule_notice| schedule_notice > Duration::from_secs(365 * 24 * 60 * 60)) {
                                             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                 .ephemeral(true)
-                                                .content(if let French = event.language {
-                                                    format!("Désolé, les races ne peuvent pas être planifiées plus d'un an à l'avance.")
-                                                } else {
-                                                    format!("Sorry, races cannot be scheduled more than 1 year in advance.")
-                                                })
+                                                .content(responses::SCHEDULE_TOO_FAR.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                             )).await?;
                                             transaction.rollback().await?;
                                         } else if (start - Utc::now()).to_std().map_or(true, |schedule_notice| schedule_notice < event.min_schedule_notice) {
                                             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                 .ephemeral(true)
                                                 .content(if event.min_schedule_notice <= Duration::default() {
-                                                    if let French = event.language {
-                                                        format!("Désolé mais cette date est dans le passé.")
-                                                    } else {
-                                                        format!("Sorry, that timestamp is in the past.")
-                                                    }
+                                                    responses::SCHEDULE_IN_PAST.get(discord_localization::response_language(Some(event.language), interaction.locale()))
                                                 } else {
-                                                    if let French = event.language {
-                                                        format!("Désolé, les races doivent être planifiées au moins {} en avance.", French.format_duration(event.min_schedule_notice, true))
-                                                    } else {
-                                                        format!("Sorry, races must be scheduled at least {} in advance.", English.format_duration(event.min_schedule_notice, true))
-                                                    }
+                                                    responses::min_schedule_notice(discord_localization::response_language(Some(event.language), interaction.locale()), event.min_schedule_notice)
                                                 })
                                             )).await?;
                                             transaction.rollback().await?;
@@ -2854,7 +3097,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                     } else {
                                                         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                             .ephemeral(false)
-                                                            .content("Sorry, only participants in this race can use this command for now. Please contact TreZ to edit the schedule.") //TODO allow TOs to schedule as async (with team parameter)
+                                                            .content(responses::ONLY_PARTICIPANTS_ASYNC_SCHEDULE.get(discord_localization::response_language(Some(event.language), interaction.locale()))) //TODO allow TOs to schedule as async (with team parameter)
                                                         )).await?;
                                                         transaction.rollback().await?;
                                                         return Ok(())
@@ -2879,7 +3122,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                     } else {
                                                         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                             .ephemeral(false)
-                                                            .content("Sorry, only participants in this race can use this command for now. Please contact TreZ to edit the schedule.") //TODO allow TOs to schedule as async (with team parameter)
+                                                            .content(responses::ONLY_PARTICIPANTS_ASYNC_SCHEDULE.get(discord_localization::response_language(Some(event.language), interaction.locale()))) //TODO allow TOs to schedule as async (with team parameter)
                                                         )).await?;
                                                         transaction.rollback().await?;
                                                         return Ok(())
@@ -3214,11 +3457,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                     } else {
                                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                     .ephemeral(true)
-                                                    .content(if let French = event.language {
-                                                        "Désolé, cela n'est pas un timestamp au format de Discord. Vous pouvez utiliser <https://hammertime.cyou/> pour en générer un, ou entrer directement la date — par exemple `vendredi 20h UTC`, `demain 15h EST` ou `vendredi 20h Europe/Paris`."
-                                                    } else {
-                                                        "Sorry, I couldn't parse that time. Try natural language like `friday 8pm UTC`, `tomorrow 15:00 EST`, or `friday 20:00 Europe/Berlin`, or use <https://hammertime.cyou/> to generate a Discord timestamp."
-                                                    })
+                                                    .content(responses::UNPARSEABLE_TIME.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                         )).await?;
                                         transaction.rollback().await?;
                                     }
@@ -3226,13 +3465,9 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                     interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                         .ephemeral(true)
                                         .content(if event.asyncs_allowed() {
-                                            if let French = event.language {
-                                                "Désolé, seuls les participants de cette race et les organisateurs peuvent utiliser cette commande."
-                                            } else {
-                                                "Sorry, only participants in this race and organizers can use this command."
-                                            }
+                                            responses::ONLY_PARTICIPANTS_AND_ORGANIZERS.get(discord_localization::response_language(Some(event.language), interaction.locale()))
                                         } else {
-                                            "Sorry, asyncing races is not allowed for this event."
+                                            responses::ASYNCS_NOT_ALLOWED.get(discord_localization::response_language(Some(event.language), interaction.locale()))
                                         })
                                     )).await?;
                                     transaction.rollback().await?;
@@ -3258,11 +3493,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                     match race.schedule {
                                         RaceSchedule::Unscheduled => {
                                             interaction.edit_response(ctx, EditInteractionResponse::new()
-                                                .content(if let French = event.language {
-                                                    "Désolé, cette race n'a pas de date de début prévue."
-                                                } else {
-                                                    "Sorry, this race already doesn't have a starting time."
-                                                })
+                                                .content(responses::NO_START_TIME.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                             ).await?;
                                             transaction.rollback().await?;
                                         }
@@ -3316,11 +3547,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                 .content(if let Some(game) = race.game {
                                                     format!("Game {game}'s starting time has been removed from the schedule.")
                                                 } else {
-                                                    if let French = event.language {
-                                                        format!("L'horaire pour cette race ou cette async a été correctement retirée.")
-                                                    } else {
-                                                        format!("This race's starting time has been removed from the schedule.")
-                                                    }
+                                                    responses::SCHEDULE_REMOVED.get(discord_localization::response_language(Some(event.language), interaction.locale())).to_owned()
                                                 })
                                             ).await?;
                                         }
@@ -3329,11 +3556,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                 let async_part = async_part_for_team(&race, team).expect("participant team missing from race entrants");
                                                 if async_part_has_been_played(&mut transaction, &race, async_part).await? {
                                                     interaction.edit_response(ctx, EditInteractionResponse::new()
-                                                        .content(if let Some(game) = race.game {
-                                                            format!("Sorry, your part of Game {game} has already started and can no longer be removed from the schedule.")
-                                                        } else {
-                                                            "Sorry, your part of this async has already started and can no longer be removed from the schedule.".to_owned()
-                                                        })
+                                                        .content(responses::async_part_started(discord_localization::response_language(Some(event.language), interaction.locale()), race.game, true))
                                                     ).await?;
                                                     transaction.rollback().await?;
                                                     return Ok(())
@@ -3347,7 +3570,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                     sqlx::query!("UPDATE races SET async_start2 = NULL, schedule_updated_at = NOW() WHERE id = $1", race.id as _).execute(&mut *transaction).await?;
                                                 } else {
                                                     interaction.edit_response(ctx, EditInteractionResponse::new()
-                                                        .content("Sorry, only participants in this race can use this command for now. Please contact TreZ to edit the schedule.") //TODO allow TOs to edit asynced schedules (with team parameter)
+                                                        .content(responses::ONLY_PARTICIPANTS_ASYNC_SCHEDULE.get(discord_localization::response_language(Some(event.language), interaction.locale()))) //TODO allow TOs to edit asynced schedules (with team parameter)
                                                     ).await?;
                                                     transaction.rollback().await?;
                                                     return Ok(())
@@ -3370,7 +3593,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                     sqlx::query!("UPDATE races SET async_start3 = NULL, schedule_updated_at = NOW() WHERE id = $1", race.id as _).execute(&mut *transaction).await?;
                                                 } else {
                                                     interaction.edit_response(ctx, EditInteractionResponse::new()
-                                                        .content("Sorry, only participants in this race can use this command for now. Please contact TreZ to edit the schedule.") //TODO allow TOs to edit asynced schedules (with team parameter)
+                                                        .content(responses::ONLY_PARTICIPANTS_ASYNC_SCHEDULE.get(discord_localization::response_language(Some(event.language), interaction.locale()))) //TODO allow TOs to edit asynced schedules (with team parameter)
                                                     ).await?;
                                                     transaction.rollback().await?;
                                                     return Ok(())
@@ -3390,17 +3613,13 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                     }
                                 } else {
                                     interaction.edit_response(ctx, EditInteractionResponse::new()
-                                        .content(if let French = event.language {
-                                            "Désolé, seuls les participants de cette race et les organisateurs peuvent utiliser cette commande."
-                                        } else {
-                                            "Sorry, only participants in this race and organizers can use this command."
-                                        })
+                                        .content(responses::ONLY_PARTICIPANTS_AND_ORGANIZERS.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                     ).await?;
                                     transaction.rollback().await?;
                                 }
                             }
                         } else if Some(interaction.data.id) == command_ids.second {
-                            if let Some((_, mut race, draft_kind, msg_ctx)) = check_draft_permissions(ctx, interaction).await? {
+                            if let Some((event, mut race, draft_kind, msg_ctx)) = check_draft_permissions(ctx, interaction).await? {
                                 match draft_kind {
                                     draft::Kind::RslS7 => {
                                         let settings = &mut race.draft.as_mut().unwrap().settings;
@@ -3417,7 +3636,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                             } else {
                                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                     .ephemeral(true)
-                                                    .content(MessageBuilder::default().push("Sorry, please specify the ").push_mono("lite").push(" parameter.").build())
+                                                    .content(responses::specify_lite(discord_localization::response_language(Some(event.language), interaction.locale())))
                                                 )).await?;
                                                 transaction.rollback().await?;
                                                 return Ok(())
@@ -3427,7 +3646,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                                 //TODO different error messages depending on which player(s) didn't opt into RSL-Lite
                                                 interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                                     .ephemeral(true)
-                                                    .content("Sorry, either you or your opponent didn't opt into RSL-Lite.")
+                                                    .content(responses::NO_RSL_LITE_OPT_IN.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                                 )).await?;
                                                 return Ok(())
                                             }
@@ -3470,6 +3689,8 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             }
                         } else if Some(interaction.data.id) == command_ids.skip {
                             draft_action(ctx, interaction, draft::Action::Skip, None).await?;
+                        } else if interaction.data.id == command_ids.standings {
+                            standings_command(ctx, interaction).await?;
                         } else if interaction.data.id == command_ids.status {
                             if let Some((mut transaction, race, team)) = check_scheduling_thread_permissions(ctx, interaction, None, true, None, false, false, SchedulingRaceFilter::Upcoming).await? {
                                 let event = race.event(&mut transaction).await?;
@@ -3492,14 +3713,14 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                     } else {
                                         interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                             .ephemeral(true)
-                                            .content("Sorry, this race's settings draft has not been initialized. Please contact a tournament organizer to fix this.")
+                                            .content(responses::DRAFT_NOT_INITIALIZED.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                         )).await?;
                                         transaction.rollback().await?;
                                     }
                                 } else {
                                     interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                         .ephemeral(true)
-                                        .content("Sorry, this command is currently only available for events with settings drafts.") //TODO
+                                        .content(responses::SETTINGS_DRAFTS_ONLY.get(discord_localization::response_language(Some(event.language), interaction.locale()))) //TODO
                                     )).await?;
                                     transaction.rollback().await?;
                                 }
@@ -3743,7 +3964,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                         if team.is_none() {
                             interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
                                 .ephemeral(true)
-                                .content("Sorry, only participants in this race can start the draft.")
+                                .content(responses::ONLY_PARTICIPANTS_START_DRAFT.get(discord_localization::response_language(None, interaction.locale())))
                             )).await?;
                             transaction.rollback().await?;
                         } else {
@@ -3835,6 +4056,8 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 AsyncRaceError::NotStarted => "You must start the countdown before finishing.",
                                 AsyncRaceError::AlreadyFinished => "You have already finished this race.",
                                 AsyncRaceError::ResetAsyncPart => "This async part was reset. Please schedule a new time in the match thread.",
+                                AsyncRaceError::Paused => "Your run is paused. Please resume it first.",
+                                AsyncRaceError::NotPaused => "This pause has already ended.",
                                 _ => { eprintln!("Async button error: {:?}", e); "An error occurred." },
                             };
                             if interaction.edit_response(ctx, EditInteractionResponse::new().content(error_msg)).await.is_err() {
@@ -4057,6 +4280,47 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 }
                             }
                         }
+                    } else if let Some(params) = custom_id.strip_prefix(event::enter::DISCORD_BUTTON_PREFIX) {
+                        // a modal can't be shown after deferring, so the modal is shown right away and all requirements are checked once it's submitted
+                        let (mut transaction, http_client, config, discord_ctx) = {
+                            let data = ctx.data.read().await;
+                            (
+                                data.get::<DbPool>().expect("database connection pool missing from Discord context").begin().await?,
+                                data.get::<HttpClient>().expect("HTTP client missing from Discord context").clone(),
+                                data.get::<Config>().expect("config missing from Discord context").clone(),
+                                data.get::<GlobalState>().expect("global state missing from Discord context").discord_ctx.clone(),
+                            )
+                        };
+                        let Some(event) = signup_event(&mut transaction, params).await? else {
+                            interaction.create_response(ctx, CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                                .ephemeral(true)
+                                .content(responses::EVENT_NO_LONGER_EXISTS.get(discord_localization::response_language(None, interaction.locale())))
+                            )).await?;
+                            transaction.rollback().await?;
+                            return Ok(())
+                        };
+                        if let Some(modal) = event::enter::discord_signup_modal(&event) {
+                            interaction.create_response(ctx, CreateInteractionResponse::Modal(modal)).await?;
+                            transaction.rollback().await?;
+                            return Ok(())
+                        }
+                        interaction.create_response(ctx, CreateInteractionResponse::Defer(CreateInteractionResponseMessage::new()
+                            .ephemeral(true)
+                        )).await?;
+                        match event::enter::discord_button(&mut transaction, &http_client, &discord_ctx, &config, interaction.user.id, &event).await? {
+                            event::enter::DiscordEntry::Unavailable(message) => {
+                                transaction.rollback().await?;
+                                interaction.edit_response(ctx, EditInteractionResponse::new()
+                                    .content(message)
+                                ).await?;
+                            }
+                            event::enter::DiscordEntry::Entered { waitlisted } => {
+                                transaction.commit().await?;
+                                interaction.edit_response(ctx, EditInteractionResponse::new()
+                                    .content(responses::entered(discord_localization::response_language(Some(event.language), &interaction.locale), &event.display_name, waitlisted))
+                                ).await?;
+                            }
+                        }
                     } else if let Some(race_id_str) = custom_id.strip_prefix("volunteer_signup_") {
                         // Handle volunteer signup button - shows available roles for the user
                         // Defer immediately: the DB queries and possible racetime.gg call below
@@ -4468,7 +4732,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                             if race_started {
                                 interaction.create_response(ctx, CreateInteractionResponse::UpdateMessage(
                                     CreateInteractionResponseMessage::new()
-                                        .content("Sorry, you cannot withdraw after the race has started.")
+                                        .content(responses::WITHDRAW_AFTER_START.get(discord_localization::response_language(None, interaction.locale())))
                                         .components(vec![])
                                 )).await?;
                                 return Ok(());
@@ -4548,7 +4812,7 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 apply_live_schedule(ctx, interaction, transaction, race, event, was_scheduled, start, None).await?;
                             } else {
                                 interaction.edit_response(ctx, EditInteractionResponse::new()
-                                    .content("Sorry, only participants in this race and organizers can use this command.")
+                                    .content(responses::ONLY_PARTICIPANTS_AND_ORGANIZERS.get(discord_localization::response_language(Some(event.language), interaction.locale())))
                                 ).await?;
                                 transaction.rollback().await?;
                             }
@@ -5059,6 +5323,48 @@ pub(crate) fn configure_builder(discord_builder: serenity_utils::Builder, global
                                 }
                             }
                         }
+                    } else if let Some(params) = interaction.data.custom_id.strip_prefix(event::enter::DISCORD_MODAL_PREFIX) {
+                        let answers = interaction.data.components.iter()
+                            .flat_map(|row| &row.components)
+                            .filter_map(|component| if let ActionRowComponent::InputText(input) = component {
+                                Some((input.custom_id.clone(), input.value.clone().unwrap_or_default()))
+                            } else {
+                                None
+                            })
+                            .collect::<HashMap<_, _>>();
+                        interaction.create_response(ctx, CreateInteractionResponse::Defer(CreateInteractionResponseMessage::new()
+                            .ephemeral(true)
+                        )).await?;
+                        let (mut transaction, http_client, config, discord_ctx) = {
+                            let data = ctx.data.read().await;
+                            (
+                                data.get::<DbPool>().expect("database connection pool missing from Discord context").begin().await?,
+                                data.get::<HttpClient>().expect("HTTP client missing from Discord context").clone(),
+                                data.get::<Config>().expect("config missing from Discord context").clone(),
+                                data.get::<GlobalState>().expect("global state missing from Discord context").discord_ctx.clone(),
+                            )
+                        };
+                        let Some(event) = signup_event(&mut transaction, params).await? else {
+                            interaction.edit_response(ctx, EditInteractionResponse::new()
+                                .content(responses::EVENT_NO_LONGER_EXISTS.get(discord_localization::response_language(None, &interaction.locale)))
+                            ).await?;
+                            transaction.rollback().await?;
+                            return Ok(())
+                        };
+                        match event::enter::discord_modal(&mut transaction, &http_client, &discord_ctx, &config, interaction.user.id, &event, &answers).await? {
+                            Ok(waitlisted) => {
+                                transaction.commit().await?;
+                                interaction.edit_response(ctx, EditInteractionResponse::new()
+                                    .content(responses::entered(discord_localization::response_language(Some(event.language), &interaction.locale), &event.display_name, waitlisted))
+                                ).await?;
+                            }
+                            Err(message) => {
+                                transaction.rollback().await?;
+                                interaction.edit_response(ctx, EditInteractionResponse::new()
+                                    .content(format!("{}\n{message}", responses::ENTRY_NOT_SUBMITTED.get(discord_localization::response_language(Some(event.language), &interaction.locale))))
+                                ).await?;
+                            }
+                        }
                     }
                 },
                 _ => {}
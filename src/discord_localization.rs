@@ -0,0 +1,889 @@
+//! Translations of Discord slash command descriptions and common command responses.
+//!
+//! Command and option names are not localized so that instructions referring to e.g. `/schedule` work for everyone.
+
+use {
+    serenity::all::{
+        CreateCommand,
+        CreateCommandOption,
+    },
+    crate::prelude::*,
+};
+
+/// A string in each language supported by the Discord bot.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Text {
+    pub(crate) en: &'static str,
+    pub(crate) fr: &'static str,
+    pub(crate) de: &'static str,
+    pub(crate) pt: &'static str,
+}
+
+impl Text {
+    pub(crate) fn get(&self, language: Language) -> &'static str {
+        match language {
+            English => self.en,
+            French => self.fr,
+            German => self.de,
+            Portuguese => self.pt,
+        }
+    }
+}
+
+/// Discord locales for each language, see <https://discord.com/developers/docs/reference#locales>.
+const LOCALES: [(&str, Language); 5] = [
+    ("en-GB", English),
+    ("en-US", English),
+    ("fr", French),
+    ("de", German),
+    ("pt-BR", Portuguese),
+];
+
+pub(crate) fn language_from_locale(locale: &str) -> Option<Language> {
+    LOCALES.iter().find(|(iter_locale, _)| *iter_locale == locale).map(|&(_, language)| language)
+}
+
+/// Events configured with a non-English language always get responses in that language.
+/// Otherwise, the invoking user's Discord locale is used if it's supported, with English as the fallback.
+pub(crate) fn response_language(event_language: Option<Language>, locale: &str) -> Language {
+    match event_language {
+        None | Some(English) => language_from_locale(locale).unwrap_or(English),
+        Some(language) => language,
+    }
+}
+
+pub(crate) trait LocalizedDescription: Sized {
+    /// Sets the English description and the Discord localizations for the other supported languages.
+    fn localized_description(self, text: Text) -> Self {
+        self.localized_description_in(English, text)
+    }
+
+    /// Sets the description in the given default language and the Discord localizations for the other supported languages.
+    fn localized_description_in(self, default: Language, text: Text) -> Self;
+}
+
+impl LocalizedDescription for CreateCommand {
+    fn localized_description_in(self, default: Language, text: Text) -> Self {
+        LOCALES.iter()
+            .filter(|&&(_, language)| language != default)
+            .fold(self.description(text.get(default)), |command, &(locale, language)| command.description_localized(locale, text.get(language)))
+    }
+}
+
+impl LocalizedDescription for CreateCommandOption {
+    fn localized_description_in(self, default: Language, text: Text) -> Self {
+        LOCALES.iter()
+            .filter(|&&(_, language)| language != default)
+            .fold(self, |option, &(locale, language)| option.description_localized(locale, text.get(language)))
+    }
+}
+
+/// Creates a command option with a localized description.
+pub(crate) fn option(kind: CommandOptionType, name: &str, description: Text) -> CreateCommandOption {
+    option_in(English, kind, name, description)
+}
+
+/// Creates a command option with a description in the given default language and localizations for the other supported languages.
+pub(crate) fn option_in(default: Language, kind: CommandOptionType, name: &str, description: Text) -> CreateCommandOption {
+    CreateCommandOption::new(kind, name, description.get(default)).localized_description_in(default, description)
+}
+
+/// Formats a duration for running text in a command response.
+///
+/// [`Language::format_duration`] falls back to English for languages other than French, which is kept as is for existing messages.
+fn format_duration(language: Language, duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let hours = secs / 3600;
+    let mins = (secs % 3600) / 60;
+    let secs = secs % 60;
+    match language {
+        English | French => language.format_duration(duration, true),
+        German => {
+            let parts = (hours > 0).then(|| format!("{hours} Stunde{}", if hours == 1 { "" } else { "n" })).into_iter()
+                .chain((mins > 0).then(|| format!("{mins} Minute{}", if mins == 1 { "" } else { "n" })))
+                .chain((secs > 0).then(|| format!("{secs} Sekunde{}", if secs == 1 { "" } else { "n" })));
+            German.join_str_opt(parts).unwrap_or_else(|| format!("0 Sekunden"))
+        }
+        Portuguese => {
+            let parts = (hours > 0).then(|| format!("{hours} hora{}", if hours == 1 { "" } else { "s" })).into_iter()
+                .chain((mins > 0).then(|| format!("{mins} minuto{}", if mins == 1 { "" } else { "s" })))
+                .chain((secs > 0).then(|| format!("{secs} segundo{}", if secs == 1 { "" } else { "s" })));
+            Portuguese.join_str_opt(parts).unwrap_or_else(|| format!("0 segundos"))
+        }
+    }
+}
+
+pub(crate) mod descriptions {
+    use super::Text;
+
+    pub(crate) const BAN: Text = Text {
+        en: "Locks a setting for this race to its default value.",
+        fr: "Verrouille un setting à sa valeur par défaut.",
+        de: "Sperrt eine Einstellung für dieses Rennen auf ihren Standardwert.",
+        pt: "Trava uma configuração desta corrida no valor padrão.",
+    };
+    pub(crate) const BAN_PRESET: Text = Text {
+        en: "Bans a preset from being played in this match.",
+        fr: "Bannit un preset pour ce match.",
+        de: "Sperrt ein Preset für dieses Match.",
+        pt: "Bane um preset desta partida.",
+    };
+    pub(crate) const BLOCK_WEIGHTS: Text = Text {
+        en: "Blocks the weights of a setting from being changed.",
+        fr: "Empêche la modification des poids d'un setting.",
+        de: "Verhindert, dass die Gewichtung einer Einstellung geändert wird.",
+        pt: "Impede que os pesos de uma configuração sejam alterados.",
+    };
+    pub(crate) const DELETE_AFTER: Text = Text {
+        en: "Deletes games of the match that are not required.",
+        fr: "Supprime les parties du match qui ne sont pas nécessaires.",
+        de: "Löscht nicht benötigte Spiele des Matches.",
+        pt: "Exclui os jogos da partida que não são necessários.",
+    };
+    pub(crate) const DELETE_AFTER_GAME: Text = Text {
+        en: "The last game number within the match that should be kept.",
+        fr: "Le numéro de la dernière partie du match à conserver.",
+        de: "Die Nummer des letzten Spiels des Matches, das behalten werden soll.",
+        pt: "O número do último jogo da partida que deve ser mantido.",
+    };
+    pub(crate) const DRAFT: Text = Text {
+        en: "Chooses a setting for this race (same as /pick).",
+        fr: "Choisit un setting pour la race (identique à /pick).",
+        de: "Wählt eine Einstellung für dieses Rennen (wie /pick).",
+        pt: "Escolhe uma configuração para esta corrida (igual a /pick).",
+    };
+    pub(crate) const DRAFT_PRESET: Text = Text {
+        en: "Picks a preset for a game (same as /pick).",
+        fr: "Choisit un preset pour une partie (identique à /pick).",
+        de: "Wählt ein Preset für ein Spiel (wie /pick).",
+        pt: "Escolhe um preset para um jogo (igual a /pick).",
+    };
+    pub(crate) const FIRST: Text = Text {
+        en: "Go first in the settings draft.",
+        fr: "Partir premier dans la phase de pick&ban.",
+        de: "Im Einstellungs-Draft als Erstes wählen.",
+        pt: "Ser o primeiro no draft de configurações.",
+    };
+    pub(crate) const FIRST_WEIGHTS: Text = Text {
+        en: "Go first in the weights draft.",
+        fr: "Partir premier dans le draft des poids.",
+        de: "Im Gewichtungs-Draft als Erstes wählen.",
+        pt: "Ser o primeiro no draft de pesos.",
+    };
+    pub(crate) const LITE: Text = Text {
+        en: "Use RSL-Lite weights",
+        fr: "Utiliser les poids RSL-Lite",
+        de: "RSL-Lite-Gewichtungen verwenden",
+        pt: "Usar os pesos do RSL-Lite",
+    };
+    pub(crate) const MQ: Text = Text {
+        en: "Number of MQ dungeons",
+        fr: "Nombre de donjons MQ",
+        de: "Anzahl der MQ-Dungeons",
+        pt: "Número de dungeons MQ",
+    };
+    pub(crate) const NO: Text = Text {
+        en: "Answers no to a yes/no question in the settings draft.",
+        fr: "Répond à la négative dans une question fermée.",
+        de: "Beantwortet eine Ja/Nein-Frage im Einstellungs-Draft mit Nein.",
+        pt: "Responde não a uma pergunta de sim/não no draft de configurações.",
+    };
+    pub(crate) const PICK: Text = Text {
+        en: "Chooses a setting for this race.",
+        fr: "Choisit un setting pour la race.",
+        de: "Wählt eine Einstellung für dieses Rennen.",
+        pt: "Escolhe uma configuração para esta corrida.",
+    };
+    pub(crate) const PICK_PRESET: Text = Text {
+        en: "Picks a preset for a game in the match.",
+        fr: "Choisit un preset pour une partie du match.",
+        de: "Wählt ein Preset für ein Spiel des Matches.",
+        pt: "Escolhe um preset para um jogo da partida.",
+    };
+    pub(crate) const BAN_WEIGHTS: Text = Text {
+        en: "Sets a weight of a setting to 0.",
+        fr: "Met le poids d'un setting à 0.",
+        de: "Setzt die Gewichtung einer Einstellung auf 0.",
+        pt: "Define o peso de uma configuração como 0.",
+    };
+    pub(crate) const POST_SIGNUP: Text = Text {
+        en: "Posts a button that lets users enter this event via Discord.",
+        fr: "Publie un bouton permettant de s'inscrire à cet événement via Discord.",
+        de: "Postet einen Button, mit dem man sich über Discord für dieses Event anmelden kann.",
+        pt: "Publica um botão que permite se inscrever neste evento pelo Discord.",
+    };
+    pub(crate) const POST_STATUS: Text = Text {
+        en: "Posts this race's status to the thread, pinging the team whose turn it is in the settings draft.",
+        fr: "Publie l'état de cette race dans le fil, en mentionnant l'équipe dont c'est le tour dans le draft.",
+        de: "Postet den Status dieses Rennens im Thread und pingt das Team, das im Einstellungs-Draft am Zug ist.",
+        pt: "Publica o status desta corrida no tópico, mencionando a equipe da vez no draft de configurações.",
+    };
+    pub(crate) const PRONOUN_ROLES: Text = Text {
+        en: "Creates gender pronoun roles and posts a message here that allows members to self-assign them.",
+        fr: "Crée des rôles de pronoms et publie ici un message permettant aux membres de se les attribuer.",
+        de: "Erstellt Pronomen-Rollen und postet hier eine Nachricht, mit der Mitglieder sie sich selbst zuweisen können.",
+        pt: "Cria cargos de pronomes e publica aqui uma mensagem que permite aos membros atribuí-los a si mesmos.",
+    };
+    pub(crate) const RACE: Text = Text {
+        en: "Moderates the race in this scheduling thread. Only for organizers.",
+        fr: "Modère la race de ce fil de planification. Réservé aux organisateurs.",
+        de: "Moderiert das Rennen in diesem Terminplanungs-Thread. Nur für Organisatoren.",
+        pt: "Modera a corrida deste tópico de agendamento. Apenas para organizadores.",
+    };
+    pub(crate) const RACE_IGNORE: Text = Text {
+        en: "Cancels the race, hiding it from the schedule.",
+        fr: "Annule la race et la masque du calendrier.",
+        de: "Sagt das Rennen ab und blendet es im Zeitplan aus.",
+        pt: "Cancela a corrida, ocultando-a da programação.",
+    };
+    pub(crate) const RACE_LOCK_SCHEDULE: Text = Text {
+        en: "Prevents the schedule of the race from being overwritten by automatic imports.",
+        fr: "Empêche les imports automatiques de modifier l'horaire de la race.",
+        de: "Verhindert, dass automatische Importe den Termin des Rennens überschreiben.",
+        pt: "Impede que importações automáticas alterem o horário da corrida.",
+    };
+    pub(crate) const RACE_LOCKED: Text = Text {
+        en: "Set to false to unlock the schedule. Defaults to true.",
+        fr: "Mettez à false pour déverrouiller l'horaire. Par défaut : true.",
+        de: "Auf false setzen, um den Termin zu entsperren. Standard: true.",
+        pt: "Defina como false para desbloquear o horário. Padrão: true.",
+    };
+    pub(crate) const RACE_RESTREAM: Text = Text {
+        en: "Sets the restream URL and/or restreamer of the race.",
+        fr: "Définit l'URL de la rediffusion et/ou le restreamer de la race.",
+        de: "Legt die Restream-URL und/oder den Restreamer des Rennens fest.",
+        pt: "Define a URL da retransmissão e/ou o restreamer da corrida.",
+    };
+    pub(crate) const RESTREAM_LANGUAGE: Text = Text {
+        en: "The language of the restream.",
+        fr: "La langue de la rediffusion.",
+        de: "Die Sprache des Restreams.",
+        pt: "O idioma da retransmissão.",
+    };
+    pub(crate) const RESTREAM_URL: Text = Text {
+        en: "The URL of the restream channel.",
+        fr: "L'URL de la chaîne de rediffusion.",
+        de: "Die URL des Restream-Kanals.",
+        pt: "A URL do canal de retransmissão.",
+    };
+    pub(crate) const RESTREAMER: Text = Text {
+        en: "racetime.gg profile URL, racetime.gg user ID, or Mido's House user ID of the restreamer.",
+        fr: "URL du profil racetime.gg, ID racetime.gg ou ID Mido's House du restreamer.",
+        de: "racetime.gg-Profil-URL, racetime.gg-Nutzer-ID oder Mido's-House-Nutzer-ID des Restreamers.",
+        pt: "URL do perfil racetime.gg, ID racetime.gg ou ID Mido's House do restreamer.",
+    };
+    pub(crate) const RACE_WALKOVER: Text = Text {
+        en: "Records a walkover win for the race.",
+        fr: "Enregistre une victoire par forfait pour la race.",
+        de: "Trägt einen kampflosen Sieg für das Rennen ein.",
+        pt: "Registra uma vitória por W.O. na corrida.",
+    };
+    pub(crate) const WALKOVER_WINNER: Text = Text {
+        en: "A member of the team that wins by walkover.",
+        fr: "Un membre de l'équipe qui gagne par forfait.",
+        de: "Ein Mitglied des Teams, das kampflos gewinnt.",
+        pt: "Um membro da equipe que vence por W.O.",
+    };
+    pub(crate) const WALKOVER_REASON: Text = Text {
+        en: "Why the race is a walkover, e.g. a no-show.",
+        fr: "La raison du forfait, par exemple une absence.",
+        de: "Der Grund für den kampflosen Sieg, z. B. Nichterscheinen.",
+        pt: "O motivo do W.O., por exemplo uma ausência.",
+    };
+    pub(crate) const RACE_SET_DEADLINE: Text = Text {
+        en: "Sets the deadline by which the race must be scheduled.",
+        fr: "Définit la date limite de planification de la race.",
+        de: "Legt die Frist fest, bis zu der das Rennen geplant sein muss.",
+        pt: "Define o prazo para agendar a corrida.",
+    };
+    pub(crate) const DEADLINE: Text = Text {
+        en: "The deadline as a Discord timestamp, or “none” to remove it.",
+        fr: "La date limite sous forme d'horodatage Discord, ou « none » pour la retirer.",
+        de: "Die Frist als Discord-Zeitstempel, oder „none“, um sie zu entfernen.",
+        pt: "O prazo como timestamp do Discord, ou “none” para removê-lo.",
+    };
+    pub(crate) const RACING_ROLE: Text = Text {
+        en: "Creates a racing role and posts a message here that allows members to self-assign it.",
+        fr: "Crée un rôle de course et publie ici un message permettant aux membres de se l'attribuer.",
+        de: "Erstellt eine Racing-Rolle und postet hier eine Nachricht, mit der Mitglieder sie sich selbst zuweisen können.",
+        pt: "Cria um cargo de corrida e publica aqui uma mensagem que permite aos membros atribuí-lo a si mesmos.",
+    };
+    pub(crate) const LINKED_CHANNEL: Text = Text {
+        en: "Will be linked to from the description message.",
+        fr: "Sera mentionné dans le message de description.",
+        de: "Wird in der Beschreibungsnachricht verlinkt.",
+        pt: "Será mencionado na mensagem de descrição.",
+    };
+    pub(crate) const RESET_RACE: Text = Text {
+        en: "Deletes selected data from a race.",
+        fr: "Supprime les données sélectionnées d'une race.",
+        de: "Löscht ausgewählte Daten eines Rennens.",
+        pt: "Exclui os dados selecionados de uma corrida.",
+    };
+    pub(crate) const GAME: Text = Text {
+        en: "The game number within the match.",
+        fr: "Le numéro de la partie dans le match.",
+        de: "Die Nummer des Spiels innerhalb des Matches.",
+        pt: "O número do jogo dentro da partida.",
+    };
+    pub(crate) const RESET_DRAFT: Text = Text {
+        en: "Reset the settings draft.",
+        fr: "Réinitialiser le draft des settings.",
+        de: "Den Einstellungs-Draft zurücksetzen.",
+        pt: "Redefinir o draft de configurações.",
+    };
+    pub(crate) const RESET_SCHEDULE: Text = Text {
+        en: "Reset the schedule, race room, and seed.",
+        fr: "Réinitialiser l'horaire, la room et la seed.",
+        de: "Zeitplan, Race-Raum und Seed zurücksetzen.",
+        pt: "Redefinir o horário, a sala da corrida e a seed.",
+    };
+    pub(crate) const RESET_ASYNC: Text = Text {
+        en: "Resets one participant's unplayed async part. Only for organizers.",
+        fr: "Réinitialise la partie async non jouée d'un participant. Réservé aux organisateurs.",
+        de: "Setzt den ungespielten Async-Teil eines Teilnehmers zurück. Nur für Organisatoren.",
+        pt: "Redefine a parte assíncrona não jogada de um participante. Somente para organizadores.",
+    };
+    pub(crate) const RESET_ASYNC_PARTICIPANT: Text = Text {
+        en: "The participant whose async part should be reset.",
+        fr: "Le participant dont la partie async doit être réinitialisée.",
+        de: "Der Teilnehmer, dessen Async-Teil zurückgesetzt werden soll.",
+        pt: "O participante cuja parte assíncrona deve ser redefinida.",
+    };
+    pub(crate) const RESET_ASYNC_GAME: Text = Text {
+        en: "The game number within the match. Required when the thread has multiple games.",
+        fr: "Le numéro de la partie dans le match. Requis si le fil contient plusieurs parties.",
+        de: "Die Nummer des Spiels innerhalb des Matches. Erforderlich, wenn der Thread mehrere Spiele hat.",
+        pt: "O número do jogo dentro da partida. Obrigatório quando o tópico tem vários jogos.",
+    };
+    pub(crate) const RESTART_ROOM: Text = Text {
+        en: "Recreates a race room that was cancelled due to inactivity.",
+        fr: "Recrée une room annulée pour cause d'inactivité.",
+        de: "Erstellt einen wegen Inaktivität abgebrochenen Race-Raum neu.",
+        pt: "Recria uma sala de corrida cancelada por inatividade.",
+    };
+    pub(crate) const SCHEDULE: Text = Text {
+        en: "Submits a starting time for this race.",
+        fr: "Planifie une date/heure pour une race.",
+        de: "Legt eine Startzeit für dieses Rennen fest.",
+        pt: "Envia um horário de início para esta corrida.",
+    };
+    pub(crate) const SCHEDULE_START: Text = Text {
+        en: "The starting time as a Discord timestamp",
+        fr: "La date de début comme timestamp de Discord",
+        de: "Die Startzeit als Discord-Zeitstempel",
+        pt: "O horário de início como timestamp do Discord",
+    };
+    pub(crate) const NEXT_GAME: Text = Text {
+        en: "The game number within the match. Defaults to the next upcoming game.",
+        fr: "Le numéro de la partie dans le match. Par défaut, la prochaine partie.",
+        de: "Die Nummer des Spiels innerhalb des Matches. Standardmäßig das nächste anstehende Spiel.",
+        pt: "O número do jogo dentro da partida. Por padrão, o próximo jogo.",
+    };
+    pub(crate) const SCHEDULE_ASYNC: Text = Text {
+        en: "Submits a starting time for your half of this race.",
+        fr: "Planifie votre partie de l'async.",
+        de: "Legt eine Startzeit für deine Hälfte dieses Rennens fest.",
+        pt: "Envia um horário de início para a sua metade desta corrida.",
+    };
+    pub(crate) const RESULT_ASYNC: Text = Text {
+        en: "Records finish time for async race part. Only time needed in async thread.",
+        fr: "Enregistre le temps final d'une partie async. Seul le temps est requis dans le fil async.",
+        de: "Erfasst die Endzeit eines Async-Teils. Im Async-Thread wird nur die Zeit benötigt.",
+        pt: "Registra o tempo final de uma parte assíncrona. No tópico assíncrono, só o tempo é necessário.",
+    };
+    pub(crate) const FINISH_TIME: Text = Text {
+        en: "Finish time in format hh:mm:ss",
+        fr: "Temps final au format hh:mm:ss",
+        de: "Endzeit im Format hh:mm:ss",
+        pt: "Tempo final no formato hh:mm:ss",
+    };
+    pub(crate) const RACE_ID: Text = Text {
+        en: "The ID of the race (optional when used in async thread)",
+        fr: "L'identifiant de la race (facultatif dans le fil async)",
+        de: "Die ID des Rennens (optional im Async-Thread)",
+        pt: "O ID da corrida (opcional no tópico assíncrono)",
+    };
+    pub(crate) const ASYNC_PART: Text = Text {
+        en: "The async part number (1, 2, or 3) (optional when used in async thread)",
+        fr: "Le numéro de la partie async (1, 2 ou 3) (facultatif dans le fil async)",
+        de: "Die Nummer des Async-Teils (1, 2 oder 3) (optional im Async-Thread)",
+        pt: "O número da parte assíncrona (1, 2 ou 3) (opcional no tópico assíncrono)",
+    };
+    pub(crate) const VOD_LINK: Text = Text {
+        en: "Link to the recording/VoD for this async part (optional)",
+        fr: "Lien vers l'enregistrement/la VOD de cette partie async (facultatif)",
+        de: "Link zur Aufnahme/VoD dieses Async-Teils (optional)",
+        pt: "Link para a gravação/VOD desta parte assíncrona (opcional)",
+    };
+    pub(crate) const FORFEIT_ASYNC: Text = Text {
+        en: "Marks a player as forfeiting in an async race part. Only for organizers.",
+        fr: "Marque un joueur comme ayant abandonné une partie async. Réservé aux organisateurs.",
+        de: "Markiert einen Spieler in einem Async-Teil als aufgegeben. Nur für Organisatoren.",
+        pt: "Marca um jogador como desistente em uma parte assíncrona. Somente para organizadores.",
+    };
+    pub(crate) const SCHEDULE_REMOVE: Text = Text {
+        en: "Removes the starting time(s) for this race from the schedule.",
+        fr: "Supprime le(s) date(s) de début sur le document des races planifiées.",
+        de: "Entfernt die Startzeit(en) dieses Rennens aus dem Zeitplan.",
+        pt: "Remove o(s) horário(s) de início desta corrida da programação.",
+    };
+    pub(crate) const SECOND: Text = Text {
+        en: "Go second in the settings draft.",
+        fr: "Partir second dans la phase de pick&ban.",
+        de: "Im Einstellungs-Draft als Zweites wählen.",
+        pt: "Ser o segundo no draft de configurações.",
+    };
+    pub(crate) const SECOND_WEIGHTS: Text = Text {
+        en: "Go second in the weights draft.",
+        fr: "Partir second dans le draft des poids.",
+        de: "Im Gewichtungs-Draft als Zweites wählen.",
+        pt: "Ser o segundo no draft de pesos.",
+    };
+    pub(crate) const SKIP: Text = Text {
+        en: "Skips your current turn of the settings draft.",
+        fr: "Passe votre tour actuel dans le draft des settings.",
+        de: "Überspringt deinen aktuellen Zug im Einstellungs-Draft.",
+        pt: "Pula a sua vez atual no draft de configurações.",
+    };
+    pub(crate) const SKIP_WEIGHTS: Text = Text {
+        en: "Skips your current turn of the weights draft.",
+        fr: "Passe votre tour actuel dans le draft des poids.",
+        de: "Überspringt deinen aktuellen Zug im Gewichtungs-Draft.",
+        pt: "Pula a sua vez atual no draft de pesos.",
+    };
+    pub(crate) const SKIP_FINAL_PICK: Text = Text {
+        en: "Skips the final pick of the settings draft.",
+        fr: "Skip le dernier pick du draft.",
+        de: "Überspringt den letzten Pick im Einstellungs-Draft.",
+        pt: "Pula a última escolha do draft de configurações.",
+    };
+    pub(crate) const STANDINGS: Text = Text {
+        en: "Shows the current standings of this event.",
+        fr: "Affiche le classement actuel de cet événement.",
+        de: "Zeigt den aktuellen Stand dieses Events.",
+        pt: "Mostra a classificação atual deste evento.",
+    };
+    pub(crate) const STATUS: Text = Text {
+        en: "Shows you this race's current scheduling and settings draft status.",
+        fr: "Montre l'avancement de la planification de votre race, avec les détails.",
+        de: "Zeigt dir den aktuellen Planungs- und Draft-Status dieses Rennens.",
+        pt: "Mostra o status atual de agendamento e do draft de configurações desta corrida.",
+    };
+    pub(crate) const WATCH_ROLES: Text = Text {
+        en: "Creates watch notification roles and posts a message here that allows members to self-assign them.",
+        fr: "Crée des rôles de notification de visionnage et publie ici un message permettant aux membres de se les attribuer.",
+        de: "Erstellt Rollen für Zuschauer-Benachrichtigungen und postet hier eine Nachricht, mit der Mitglieder sie sich selbst zuweisen können.",
+        pt: "Cria cargos de notificação para espectadores e publica aqui uma mensagem que permite aos membros atribuí-los a si mesmos.",
+    };
+    pub(crate) const YES: Text = Text {
+        en: "Answers yes to a yes/no question in the settings draft.",
+        fr: "Répond à l'affirmative dans une question fermée.",
+        de: "Beantwortet eine Ja/Nein-Frage im Einstellungs-Draft mit Ja.",
+        pt: "Responde sim a uma pergunta de sim/não no draft de configurações.",
+    };
+}
+
+pub(crate) mod responses {
+    use {
+        crate::prelude::*,
+        super::Text,
+    };
+
+    pub(crate) const ONLY_ORGANIZERS: Text = Text {
+        en: "Sorry, only organizers can use this command.",
+        fr: "Désolé, seuls les organisateurs du tournoi peuvent utiliser cette commande.",
+        de: "Entschuldigung, nur Organisatoren können diesen Befehl verwenden.",
+        pt: "Desculpe, apenas organizadores podem usar este comando.",
+    };
+    pub(crate) const ONLY_PARTICIPANTS: Text = Text {
+        en: "Sorry, only participants in this race can use this command.",
+        fr: "Désolé, seuls les participants de la race peuvent utiliser cette commande.",
+        de: "Entschuldigung, nur Teilnehmer dieses Rennens können diesen Befehl verwenden.",
+        pt: "Desculpe, apenas participantes desta corrida podem usar este comando.",
+    };
+    pub(crate) const ONLY_PARTICIPANTS_AND_ORGANIZERS: Text = Text {
+        en: "Sorry, only participants in this race and organizers can use this command.",
+        fr: "Désolé, seuls les participants de cette race et les organisateurs peuvent utiliser cette commande.",
+        de: "Entschuldigung, nur Teilnehmer dieses Rennens und Organisatoren können diesen Befehl verwenden.",
+        pt: "Desculpe, apenas participantes desta corrida e organizadores podem usar este comando.",
+    };
+    pub(crate) const THREADS_ONLY: Text = Text {
+        en: "Sorry, this command can only be used inside threads and forum posts.",
+        fr: "Désolé, cette commande ne peut être utilisée que dans des fils et des posts de forum.",
+        de: "Entschuldigung, dieser Befehl kann nur in Threads und Forenbeiträgen verwendet werden.",
+        pt: "Desculpe, este comando só pode ser usado em tópicos e publicações de fórum.",
+    };
+    pub(crate) const ROOM_ALREADY_OPEN: Text = Text {
+        en: "Sorry, this command can't be used since a race room is already open. Please contact a tournament organizer if necessary.",
+        fr: "Désolé, cette commande ne peut pas être utilisée car une room est déjà ouverte. Merci de contacter un organisateur du tournoi si nécessaire.",
+        de: "Entschuldigung, dieser Befehl kann nicht verwendet werden, da bereits ein Race-Raum geöffnet ist. Bitte wende dich bei Bedarf an die Turnierorganisation.",
+        pt: "Desculpe, este comando não pode ser usado porque uma sala de corrida já está aberta. Entre em contato com a organização do torneio se necessário.",
+    };
+    pub(crate) const MULTIPLE_UPCOMING_RACES: Text = Text {
+        en: "Sorry, this thread is associated with multiple upcoming races. Please contact a tournament organizer to fix this.",
+        fr: "Désolé, ce fil est associé à plusieurs races à venir. Merci de contacter un organisateur du tournoi pour corriger cela.",
+        de: "Entschuldigung, dieser Thread ist mit mehreren anstehenden Rennen verknüpft. Bitte wende dich an die Turnierorganisation, um das zu beheben.",
+        pt: "Desculpe, este tópico está associado a várias corridas futuras. Entre em contato com a organização do torneio para corrigir isso.",
+    };
+    pub(crate) const NO_UNPLAYED_ASYNCS: Text = Text {
+        en: "Sorry, you don't have any remaining unplayed async games in this thread.",
+        fr: "Désolé, vous n'avez plus de parties async à jouer dans ce fil.",
+        de: "Entschuldigung, du hast in diesem Thread keine ungespielten Async-Spiele mehr.",
+        pt: "Desculpe, você não tem mais jogos assíncronos pendentes neste tópico.",
+    };
+    pub(crate) const DRAFT_NOT_INITIALIZED: Text = Text {
+        en: "Sorry, this race's settings draft has not been initialized. Please contact a tournament organizer to fix this.",
+        fr: "Désolé, le draft de cette race n'a pas été initialisé. Merci de contacter un organisateur du tournoi pour corriger cela.",
+        de: "Entschuldigung, der Einstellungs-Draft dieses Rennens wurde nicht initialisiert. Bitte wende dich an die Turnierorganisation, um das zu beheben.",
+        pt: "Desculpe, o draft de configurações desta corrida não foi inicializado. Entre em contato com a organização do torneio para corrigir isso.",
+    };
+    pub(crate) const SCHEDULE_TOO_FAR: Text = Text {
+        en: "Sorry, races cannot be scheduled more than 1 year in advance.",
+        fr: "Désolé, les races ne peuvent pas être planifiées plus d'un an à l'avance.",
+        de: "Entschuldigung, Rennen können nicht mehr als 1 Jahr im Voraus geplant werden.",
+        pt: "Desculpe, as corridas não podem ser agendadas com mais de 1 ano de antecedência.",
+    };
+    pub(crate) const SCHEDULE_IN_PAST: Text = Text {
+        en: "Sorry, that timestamp is in the past.",
+        fr: "Désolé mais cette date est dans le passé.",
+        de: "Entschuldigung, dieser Zeitpunkt liegt in der Vergangenheit.",
+        pt: "Desculpe, esse horário está no passado.",
+    };
+    pub(crate) const NO_START_TIME: Text = Text {
+        en: "Sorry, this race already doesn't have a starting time.",
+        fr: "Désolé, cette race n'a pas de date de début prévue.",
+        de: "Entschuldigung, dieses Rennen hat bereits keine Startzeit.",
+        pt: "Desculpe, esta corrida já não tem um horário de início.",
+    };
+    pub(crate) const SCHEDULE_REMOVED: Text = Text {
+        en: "This race's starting time has been removed from the schedule.",
+        fr: "L'horaire pour cette race ou cette async a été correctement retirée.",
+        de: "Die Startzeit dieses Rennens wurde aus dem Zeitplan entfernt.",
+        pt: "O horário de início desta corrida foi removido da programação.",
+    };
+    pub(crate) const NO_ONGOING_EVENTS: Text = Text {
+        en: "Sorry, there are no ongoing events in this server.",
+        fr: "Désolé, il n'y a aucun événement en cours sur ce serveur.",
+        de: "Entschuldigung, auf diesem Server laufen derzeit keine Events.",
+        pt: "Desculpe, não há eventos em andamento neste servidor.",
+    };
+    pub(crate) const MULTIPLE_ONGOING_EVENTS: Text = Text {
+        en: "Sorry, there are multiple ongoing events in this server. Please use this command in one of the event's channels.",
+        fr: "Désolé, il y a plusieurs événements en cours sur ce serveur. Merci d'utiliser cette commande dans l'un des salons de l'événement.",
+        de: "Entschuldigung, auf diesem Server laufen mehrere Events. Bitte verwende diesen Befehl in einem der Kanäle des Events.",
+        pt: "Desculpe, há vários eventos em andamento neste servidor. Use este comando em um dos canais do evento.",
+    };
+    pub(crate) const NO_STANDINGS: Text = Text {
+        en: "Sorry, there are no standings available for this event.",
+        fr: "Désolé, aucun classement n'est disponible pour cet événement.",
+        de: "Entschuldigung, für dieses Event ist kein Stand verfügbar.",
+        pt: "Desculpe, não há classificação disponível para este evento.",
+    };
+    pub(crate) const STANDINGS_HIDDEN: Text = Text {
+        en: "Standings will not be published until after the qualifier stage has ended.",
+        fr: "Le classement ne sera publié qu'à la fin de la phase de qualification.",
+        de: "Der Stand wird erst nach dem Ende der Qualifikationsphase veröffentlicht.",
+        pt: "A classificação só será publicada após o fim da fase classificatória.",
+    };
+    pub(crate) const NO_ENTER_FLOW: Text = Text {
+        en: "Sorry, this event doesn't have an enter flow, so it can't be entered via Discord.",
+        fr: "Désolé, cet événement n'a pas de formulaire d'inscription, il n'est donc pas possible de s'y inscrire via Discord.",
+        de: "Entschuldigung, dieses Event hat keinen Anmeldeablauf, daher ist keine Anmeldung über Discord möglich.",
+        pt: "Desculpe, este evento não tem um fluxo de inscrição, então não é possível se inscrever pelo Discord.",
+    };
+    pub(crate) const EVENT_NO_LONGER_EXISTS: Text = Text {
+        en: "Sorry, this event no longer exists.",
+        fr: "Désolé, cet événement n'existe plus.",
+        de: "Entschuldigung, dieses Event existiert nicht mehr.",
+        pt: "Desculpe, este evento não existe mais.",
+    };
+    pub(crate) const NOT_SCHEDULING_THREAD: Text = Text {
+        en: "Sorry, this is not a race scheduling thread.",
+        fr: "Désolé, ce fil n'est pas un fil de planification de race.",
+        de: "Entschuldigung, dies ist kein Thread zur Rennplanung.",
+        pt: "Desculpe, este não é um tópico de agendamento de corrida.",
+    };
+    pub(crate) const NO_RACE_WITH_GAME_NUMBER: Text = Text {
+        en: "Sorry, there is no race with that game number in this scheduling thread.",
+        fr: "Désolé, il n'y a pas de race avec ce numéro de partie dans ce fil de planification.",
+        de: "Entschuldigung, in diesem Planungs-Thread gibt es kein Rennen mit dieser Spielnummer.",
+        pt: "Desculpe, não há corrida com esse número de jogo neste tópico de agendamento.",
+    };
+    pub(crate) const NO_RACES_IN_THREAD: Text = Text {
+        en: "Sorry, this thread is not associated with any races.",
+        fr: "Désolé, ce fil n'est associé à aucune race.",
+        de: "Entschuldigung, dieser Thread ist mit keinen Rennen verknüpft.",
+        pt: "Desculpe, este tópico não está associado a nenhuma corrida.",
+    };
+    pub(crate) const NO_RACES_WITH_GAME_NUMBER: Text = Text {
+        en: "Sorry, there don't seem to be any races with that game number associated with this thread.",
+        fr: "Désolé, il ne semble y avoir aucune race avec ce numéro de partie associée à ce fil.",
+        de: "Entschuldigung, mit diesem Thread scheinen keine Rennen mit dieser Spielnummer verknüpft zu sein.",
+        pt: "Desculpe, não parece haver corridas com esse número de jogo associadas a este tópico.",
+    };
+    pub(crate) const SPECIFY_GAME_NUMBER: Text = Text {
+        en: "Sorry, this thread is associated with multiple races. Please specify the game number.",
+        fr: "Désolé, ce fil est associé à plusieurs races. Merci de préciser le numéro de la partie.",
+        de: "Entschuldigung, dieser Thread ist mit mehreren Rennen verknüpft. Bitte gib die Spielnummer an.",
+        pt: "Desculpe, este tópico está associado a várias corridas. Especifique o número do jogo.",
+    };
+    pub(crate) const CONTINUE_DRAFT_IN_ROOM: Text = Text {
+        en: "You can continue the draft in the race room",
+        fr: "Vous pouvez continuer le draft dans la room",
+        de: "Du kannst den Draft im Race-Raum fortsetzen",
+        pt: "Você pode continuar o draft na sala da corrida",
+    };
+    pub(crate) const INVALID_DEADLINE: Text = Text {
+        en: "Sorry, that doesn't look like a Discord timestamp. You can use <https://hammertime.cyou/> to generate one, or enter “none” to remove the deadline.",
+        fr: "Désolé, cela n'est pas un timestamp au format de Discord. Vous pouvez utiliser <https://hammertime.cyou/> pour en générer un, ou entrer « none » pour retirer la date limite.",
+        de: "Entschuldigung, das sieht nicht wie ein Discord-Zeitstempel aus. Du kannst <https://hammertime.cyou/> verwenden, um einen zu erstellen, oder „none“ eingeben, um die Frist zu entfernen.",
+        pt: "Desculpe, isso não parece um timestamp do Discord. Você pode usar <https://hammertime.cyou/> para gerar um, ou digitar “none” para remover o prazo.",
+    };
+    pub(crate) const DEADLINE_IN_PAST: Text = Text {
+        en: "Sorry, the scheduling deadline must be in the future.",
+        fr: "Désolé, la date limite de planification doit être dans le futur.",
+        de: "Entschuldigung, die Planungsfrist muss in der Zukunft liegen.",
+        pt: "Desculpe, o prazo de agendamento deve estar no futuro.",
+    };
+    pub(crate) const NO_SETTINGS_DRAFT: Text = Text {
+        en: "Sorry, there is no settings draft for this event.",
+        fr: "Désolé, il n'y a pas de draft pour cet événement.",
+        de: "Entschuldigung, für dieses Event gibt es keinen Einstellungs-Draft.",
+        pt: "Desculpe, não há draft de configurações para este evento.",
+    };
+    pub(crate) const SETTINGS_DRAFTS_ONLY: Text = Text {
+        en: "Sorry, this command is currently only available for events with settings drafts.",
+        fr: "Désolé, cette commande n'est actuellement disponible que pour les événements avec un draft.",
+        de: "Entschuldigung, dieser Befehl ist derzeit nur für Events mit Einstellungs-Draft verfügbar.",
+        pt: "Desculpe, este comando está disponível apenas para eventos com draft de configurações no momento.",
+    };
+    pub(crate) const NO_RSL_LITE_OPT_IN: Text = Text {
+        en: "Sorry, either you or your opponent didn't opt into RSL-Lite.",
+        fr: "Désolé, vous ou votre adversaire n'avez pas accepté RSL-Lite.",
+        de: "Entschuldigung, du oder dein Gegner habt RSL-Lite nicht zugestimmt.",
+        pt: "Desculpe, você ou seu oponente não optou pelo RSL-Lite.",
+    };
+    pub(crate) const DRAFT_RESET_FAILED: Text = Text {
+        en: "Sorry, unable to reset draft for this race.",
+        fr: "Désolé, impossible de réinitialiser le draft de cette race.",
+        de: "Entschuldigung, der Draft dieses Rennens konnte nicht zurückgesetzt werden.",
+        pt: "Desculpe, não foi possível reiniciar o draft desta corrida.",
+    };
+    pub(crate) const ONLY_PARTICIPANTS_START_DRAFT: Text = Text {
+        en: "Sorry, only participants in this race can start the draft.",
+        fr: "Désolé, seuls les participants de la race peuvent lancer le draft.",
+        de: "Entschuldigung, nur Teilnehmer dieses Rennens können den Draft starten.",
+        pt: "Desculpe, apenas participantes desta corrida podem iniciar o draft.",
+    };
+    pub(crate) const LEAGUE_SCHEDULE: Text = Text {
+        en: "Sorry, this command is not available for events sourcing their match schedule from league.ootrandomizer.com",
+        fr: "Désolé, cette commande n'est pas disponible pour les événements dont le programme des matchs provient de league.ootrandomizer.com",
+        de: "Entschuldigung, dieser Befehl ist nicht für Events verfügbar, deren Spielplan von league.ootrandomizer.com stammt.",
+        pt: "Desculpe, este comando não está disponível para eventos cuja programação de partidas vem de league.ootrandomizer.com",
+    };
+    pub(crate) const NO_RACES_DELETED: Text = Text {
+        en: "Sorry, looks like that didn't delete any races.",
+        fr: "Désolé, aucune race n'a été supprimée.",
+        de: "Entschuldigung, dabei wurden anscheinend keine Rennen gelöscht.",
+        pt: "Desculpe, parece que nenhuma corrida foi excluída.",
+    };
+    pub(crate) const NOT_HTH_SCHEDULING_CHANNEL: Text = Text {
+        en: "Sorry, this channel is not configured as the scheduling channel for any ongoing Hyrule Town Hall events.",
+        fr: "Désolé, ce salon n'est configuré comme salon de planification pour aucun événement Hyrule Town Hall en cours.",
+        de: "Entschuldigung, dieser Kanal ist für kein laufendes Hyrule-Town-Hall-Event als Planungskanal eingerichtet.",
+        pt: "Desculpe, este canal não está configurado como canal de agendamento de nenhum evento Hyrule Town Hall em andamento.",
+    };
+    pub(crate) const UNPARSEABLE_TIME: Text = Text {
+        en: "Sorry, I couldn't parse that time. Try natural language like `friday 8pm UTC`, `tomorrow 15:00 EST`, or `friday 20:00 Europe/Berlin`, or use <https://hammertime.cyou/> to generate a Discord timestamp.",
+        fr: "Désolé, cela n'est pas un timestamp au format de Discord. Vous pouvez utiliser <https://hammertime.cyou/> pour en générer un, ou entrer directement la date — par exemple `vendredi 20h UTC`, `demain 15h EST` ou `vendredi 20h Europe/Paris`.",
+        de: "Entschuldigung, diese Zeitangabe konnte ich nicht verstehen. Versuche es mit natürlicher Sprache wie `friday 8pm UTC`, `tomorrow 15:00 EST` oder `friday 20:00 Europe/Berlin`, oder verwende <https://hammertime.cyou/>, um einen Discord-Zeitstempel zu erstellen.",
+        pt: "Desculpe, não consegui entender esse horário. Tente linguagem natural como `friday 8pm UTC`, `tomorrow 15:00 EST` ou `friday 20:00 Europe/Berlin`, ou use <https://hammertime.cyou/> para gerar um timestamp do Discord.",
+    };
+    pub(crate) const ASYNC_CHANNEL_MISSING: Text = Text {
+        en: "Sorry, automated async scheduling is misconfigured for this event (missing Discord channel). Please contact a tournament organizer.",
+        fr: "Désolé, la planification automatique des asyncs est mal configurée pour cet événement (canal Discord manquant). Merci de contacter un organisateur du tournoi.",
+        de: "Entschuldigung, die automatische Async-Planung ist für dieses Event falsch eingerichtet (Discord-Kanal fehlt). Bitte wende dich an die Turnierorganisation.",
+        pt: "Desculpe, o agendamento automático de assíncronas está mal configurado para este evento (canal do Discord ausente). Entre em contato com a organização do torneio.",
+    };
+    pub(crate) const ASYNCS_NOT_ALLOWED: Text = Text {
+        en: "Sorry, asyncing races is not allowed for this event.",
+        fr: "Désolé, les asyncs ne sont pas autorisées pour cet événement.",
+        de: "Entschuldigung, Asyncs sind für dieses Event nicht erlaubt.",
+        pt: "Desculpe, corridas assíncronas não são permitidas neste evento.",
+    };
+    pub(crate) const ONLY_PARTICIPANTS_ASYNC_SCHEDULE: Text = Text {
+        en: "Sorry, only participants in this race can use this command for now. Please contact TreZ to edit the schedule.",
+        fr: "Désolé, seuls les participants de la race peuvent utiliser cette commande pour le moment. Merci de contacter TreZ pour modifier l'horaire.",
+        de: "Entschuldigung, derzeit können nur Teilnehmer dieses Rennens diesen Befehl verwenden. Bitte wende dich an TreZ, um den Zeitplan zu ändern.",
+        pt: "Desculpe, por enquanto apenas participantes desta corrida podem usar este comando. Entre em contato com TreZ para editar a programação.",
+    };
+    pub(crate) const WITHDRAW_AFTER_START: Text = Text {
+        en: "Sorry, you cannot withdraw after the race has started.",
+        fr: "Désolé, vous ne pouvez pas vous retirer après le début de la race.",
+        de: "Entschuldigung, nach dem Start des Rennens kannst du dich nicht mehr abmelden.",
+        pt: "Desculpe, você não pode desistir depois que a corrida começou.",
+    };
+
+    pub(crate) const ENTRY_NOT_SUBMITTED: Text = Text {
+        en: "Your entry could not be submitted:",
+        fr: "Votre inscription n'a pas pu être envoyée :",
+        de: "Deine Anmeldung konnte nicht abgeschickt werden:",
+        pt: "Sua inscrição não pôde ser enviada:",
+    };
+
+    pub(crate) fn entered(language: Language, event_name: &str, waitlisted: bool) -> String {
+        let mut builder = MessageBuilder::default();
+        match (language, waitlisted) {
+            (English, false) => builder.push("You have entered ").push_safe(event_name).push("."),
+            (English, true) => builder.push("This event is full, so you have been added to the waitlist for ").push_safe(event_name).push("."),
+            (French, false) => builder.push("Vous êtes inscrit à ").push_safe(event_name).push("."),
+            (French, true) => builder.push("Ce tournoi est complet, vous avez donc été ajouté à la liste d'attente de ").push_safe(event_name).push("."),
+            (German, false) => builder.push("Du hast dich für ").push_safe(event_name).push(" angemeldet."),
+            (German, true) => builder.push("Dieses Event ist voll, daher wurdest du auf die Warteliste für ").push_safe(event_name).push(" gesetzt."),
+            (Portuguese, false) => builder.push("Você se inscreveu em ").push_safe(event_name).push("."),
+            (Portuguese, true) => builder.push("Este evento está cheio, então você foi adicionado à lista de espera de ").push_safe(event_name).push("."),
+        };
+        builder.build()
+    }
+
+    pub(crate) fn min_schedule_notice(language: Language, min_schedule_notice: Duration) -> String {
+        let notice = super::format_duration(language, min_schedule_notice);
+        match language {
+            English => format!("Sorry, races must be scheduled at least {notice} in advance."),
+            French => format!("Désolé, les races doivent être planifiées au moins {notice} en avance."),
+            German => format!("Entschuldigung, Rennen müssen mindestens {notice} im Voraus geplant werden."),
+            Portuguese => format!("Desculpe, as corridas devem ser agendadas com pelo menos {notice} de antecedência."),
+        }
+    }
+
+    pub(crate) fn invalid_url(language: Language, e: impl fmt::Display) -> String {
+        match language {
+            English => format!("Sorry, that doesn't look like a valid URL: {e}"),
+            French => format!("Désolé, cela ne ressemble pas à une URL valide : {e}"),
+            German => format!("Entschuldigung, das sieht nicht wie eine gültige URL aus: {e}"),
+            Portuguese => format!("Desculpe, isso não parece uma URL válida: {e}"),
+        }
+    }
+
+    pub(crate) fn unknown_restreamer(language: Language, e: impl fmt::Display) -> String {
+        match language {
+            English => format!("Sorry, I couldn't find that restreamer: {e}"),
+            French => format!("Désolé, je n'ai pas trouvé ce restreamer : {e}"),
+            German => format!("Entschuldigung, ich konnte diesen Restreamer nicht finden: {e}"),
+            Portuguese => format!("Desculpe, não consegui encontrar esse restreamer: {e}"),
+        }
+    }
+
+    pub(crate) fn not_your_turn(language: Language, solo: bool) -> &'static str {
+        match (language, solo) {
+            (English, true) => "Sorry, it's not your turn in the settings draft.",
+            (English, false) => "Sorry, it's not your team's turn in the settings draft.",
+            (French, _) => "Désolé, mais ce n'est pas votre tour.",
+            (German, true) => "Entschuldigung, du bist im Einstellungs-Draft nicht an der Reihe.",
+            (German, false) => "Entschuldigung, dein Team ist im Einstellungs-Draft nicht an der Reihe.",
+            (Portuguese, true) => "Desculpe, não é a sua vez no draft de configurações.",
+            (Portuguese, false) => "Desculpe, não é a vez da sua equipe no draft de configurações.",
+        }
+    }
+
+    pub(crate) fn specify_lite(language: Language) -> String {
+        let mut builder = MessageBuilder::default();
+        match language {
+            English => builder.push("Sorry, please specify the ").push_mono("lite").push(" parameter."),
+            French => builder.push("Désolé, merci de préciser le paramètre ").push_mono("lite").push("."),
+            German => builder.push("Entschuldigung, bitte gib den Parameter ").push_mono("lite").push(" an."),
+            Portuguese => builder.push("Desculpe, especifique o parâmetro ").push_mono("lite").push("."),
+        };
+        builder.build()
+    }
+
+    /// `removed` distinguishes removing the part from the schedule from rescheduling it.
+    pub(crate) fn async_part_started(language: Language, game: Option<i16>, removed: bool) -> String {
+        match (language, game, removed) {
+            (English, Some(game), false) => format!("Sorry, your part of Game {game} has already started and can no longer be rescheduled."),
+            (English, Some(game), true) => format!("Sorry, your part of Game {game} has already started and can no longer be removed from the schedule."),
+            (English, None, false) => format!("Sorry, your part of this async has already started and can no longer be rescheduled."),
+            (English, None, true) => format!("Sorry, your part of this async has already started and can no longer be removed from the schedule."),
+            (French, Some(game), false) => format!("Désolé, votre partie de la game {game} a déjà commencé et ne peut plus être replanifiée."),
+            (French, Some(game), true) => format!("Désolé, votre partie de la game {game} a déjà commencé et ne peut plus être retirée du planning."),
+            (French, None, false) => format!("Désolé, votre partie de cette async a déjà commencé et ne peut plus être replanifiée."),
+            (French, None, true) => format!("Désolé, votre partie de cette async a déjà commencé et ne peut plus être retirée du planning."),
+            (German, Some(game), false) => format!("Entschuldigung, dein Teil von Spiel {game} hat bereits begonnen und kann nicht mehr verschoben werden."),
+            (German, Some(game), true) => format!("Entschuldigung, dein Teil von Spiel {game} hat bereits begonnen und kann nicht mehr aus dem Zeitplan entfernt werden."),
+            (German, None, false) => format!("Entschuldigung, dein Teil dieses Asyncs hat bereits begonnen und kann nicht mehr verschoben werden."),
+            (German, None, true) => format!("Entschuldigung, dein Teil dieses Asyncs hat bereits begonnen und kann nicht mehr aus dem Zeitplan entfernt werden."),
+            (Portuguese, Some(game), false) => format!("Desculpe, sua parte do jogo {game} já começou e não pode mais ser reagendada."),
+            (Portuguese, Some(game), true) => format!("Desculpe, sua parte do jogo {game} já começou e não pode mais ser removida da programação."),
+            (Portuguese, None, false) => format!("Desculpe, sua parte desta assíncrona já começou e não pode mais ser reagendada."),
+            (Portuguese, None, true) => format!("Desculpe, sua parte desta assíncrona já começou e não pode mais ser removida da programação."),
+        }
+    }
+
+    /// Explains that no upcoming race matched, pointing to `/reset-race` if the thread does have races.
+    pub(crate) fn no_upcoming_races(language: Language, game_specified: bool, has_races: bool, alternative_instructions: Option<Text>, reset_race: CommandId) -> String {
+        let mut builder = MessageBuilder::default();
+        builder.push(match (language, game_specified) {
+            (English, false) => "Sorry, this thread is not associated with any upcoming races.",
+            (English, true) => "Sorry, there don't seem to be any upcoming races with that game number associated with this thread.",
+            (French, false) => "Désolé, ce fil n'est associé à aucune race à venir.",
+            (French, true) => "Désolé, il ne semble y avoir aucune race à venir avec ce numéro de partie associée à ce fil.",
+            (German, false) => "Entschuldigung, dieser Thread ist mit keinen anstehenden Rennen verknüpft.",
+            (German, true) => "Entschuldigung, mit diesem Thread scheinen keine anstehenden Rennen mit dieser Spielnummer verknüpft zu sein.",
+            (Portuguese, false) => "Desculpe, este tópico não está associado a nenhuma corrida futura.",
+            (Portuguese, true) => "Desculpe, não parece haver corridas futuras com esse número de jogo associadas a este tópico.",
+        });
+        builder.push(' ');
+        if has_races {
+            match (language, alternative_instructions) {
+                (English, Some(alternative_instructions)) => builder.push(alternative_instructions.en).push(", or tournament organizers can use "),
+                (English, None) => builder.push("Tournament organizers can use "),
+                (French, Some(alternative_instructions)) => builder.push(alternative_instructions.fr).push(", ou les organisateurs du tournoi peuvent utiliser "),
+                (French, None) => builder.push("Les organisateurs du tournoi peuvent utiliser "),
+                (German, Some(alternative_instructions)) => builder.push(alternative_instructions.de).push(", oder die Turnierorganisation kann "),
+                (German, None) => builder.push("Die Turnierorganisation kann "),
+                (Portuguese, Some(alternative_instructions)) => builder.push(alternative_instructions.pt).push(", ou a organização do torneio pode usar "),
+                (Portuguese, None) => builder.push("A organização do torneio pode usar "),
+            };
+            builder.mention_command(reset_race, "reset-race");
+            builder.push(match language {
+                English | Portuguese => " if necessary.",
+                French => " si nécessaire.",
+                German => " verwenden, falls nötig.",
+            });
+        } else {
+            builder.push(match (language, game_specified) {
+                (English, false) => "Please contact a tournament organizer to fix this.",
+                (English, true) => "If this seems wrong, please contact a tournament organizer to fix this.",
+                (French, false) => "Merci de contacter un organisateur du tournoi pour corriger cela.",
+                (French, true) => "Si cela semble incorrect, merci de contacter un organisateur du tournoi pour corriger cela.",
+                (German, false) => "Bitte wende dich an die Turnierorganisation, um das zu beheben.",
+                (German, true) => "Falls das falsch erscheint, wende dich bitte an die Turnierorganisation, um das zu beheben.",
+                (Portuguese, false) => "Entre em contato com a organização do torneio para corrigir isso.",
+                (Portuguese, true) => "Se isso parecer errado, entre em contato com a organização do torneio para corrigir isso.",
+            });
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_language_takes_precedence() {
+        assert_eq!(response_language(Some(French), "de"), French);
+        assert_eq!(response_language(Some(English), "de"), German);
+        assert_eq!(response_language(None, "pt-BR"), Portuguese);
+        assert_eq!(response_language(None, "ja"), English);
+    }
+
+    #[test]
+    fn durations_are_formatted_in_response_language() {
+        let duration = Duration::from_secs(2 * 60 * 60 + 60);
+        assert_eq!(format_duration(German, duration), "2 Stunden und 1 Minute");
+        assert_eq!(format_duration(Portuguese, duration), "2 horas e 1 minuto");
+        assert_eq!(format_duration(English, duration), English.format_duration(duration, true));
+    }
+}
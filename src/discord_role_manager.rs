@@ -1,4 +1,245 @@
-use crate::prelude::*;
+use {
+    serenity::all::EditMember,
+    crate::prelude::*,
+};
+
+/// Number of members whose roles are edited before pausing, to stay well clear of Discord's rate limits.
+const BATCH_SIZE: usize = 10;
+const BATCH_PAUSE: Duration = Duration::from_secs(5);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum Error {
+    #[error(transparent)] Serenity(#[from] serenity::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+/// The Discord roles Hyrule Town Hall manages in a guild, and which members should have them.
+#[derive(Default)]
+struct Desired {
+    /// Each managed role along with a description of what it represents.
+    managed: HashMap<RoleId, String>,
+    assigned: HashMap<UserId, HashSet<RoleId>>,
+    /// Roles granted by events that have ended. These are never added, but they're not removed either, even if an ongoing event uses the same role.
+    kept: HashMap<UserId, HashSet<RoleId>>,
+}
+
+impl Desired {
+    fn insert(&mut self, role: RoleId, user: Option<UserId>, ongoing: bool, source: impl FnOnce() -> String) {
+        if ongoing {
+            self.managed.entry(role).or_insert_with(source);
+            if let Some(user) = user {
+                self.assigned.entry(user).or_default().insert(role);
+            }
+        } else if let Some(user) = user {
+            self.kept.entry(user).or_default().insert(role);
+        }
+    }
+}
+
+/// Computes the desired role assignments in a guild from event participants, team roles, role bindings, and event overrides of game role bindings.
+async fn desired_roles(transaction: &mut Transaction<'_, Postgres>, guild_id: GuildId) -> sqlx::Result<Desired> {
+    let mut desired = Desired::default();
+    // participant roles, assigned to members of confirmed teams
+    for row in sqlx::query!(r#"
+        SELECT dr.id AS "role: PgSnowflake<RoleId>", u.discord_id AS "discord_id?: PgSnowflake<UserId>", e.display_name, (e.end_time IS NULL OR e.end_time > NOW()) AS "ongoing!"
+        FROM discord_roles dr
+        JOIN events e ON e.series = dr.series AND e.event = dr.event
+        LEFT JOIN (teams t JOIN team_members tm ON tm.team = t.id JOIN users u ON u.id = tm.member)
+            ON t.series = e.series AND t.event = e.event AND NOT t.resigned
+            AND NOT EXISTS (SELECT 1 FROM team_members WHERE team = t.id AND status = 'unconfirmed')
+        WHERE dr.guild = $1 AND dr.role IS NULL AND dr.racetime_team IS NULL
+    "#, PgSnowflake(guild_id) as _).fetch_all(&mut **transaction).await? {
+        desired.insert(row.role.0, row.discord_id.map(|PgSnowflake(id)| id), row.ongoing, || format!("participant in {}", row.display_name));
+    }
+    // team position and racetime.gg team roles, assigned to members of confirmed teams
+    for row in sqlx::query!(r#"
+        SELECT dr.id AS "id: PgSnowflake<RoleId>", dr.role::text, dr.racetime_team, (e.end_time IS NULL OR e.end_time > NOW()) AS "ongoing!"
+        FROM discord_roles dr
+        LEFT JOIN events e ON e.series = dr.series AND e.event = dr.event
+        WHERE dr.guild = $1 AND (dr.role IS NOT NULL OR dr.racetime_team IS NOT NULL)
+    "#, PgSnowflake(guild_id) as _).fetch_all(&mut **transaction).await? {
+        desired.insert(row.id.0, None, row.ongoing, || if let Some(role) = row.role {
+            format!("team role {role}")
+        } else {
+            format!("racetime.gg team {}", row.racetime_team.unwrap_or_default())
+        });
+    }
+    for row in sqlx::query!(r#"
+        SELECT dr.id AS "role: PgSnowflake<RoleId>", u.discord_id AS "discord_id!: PgSnowflake<UserId>", (e.end_time IS NULL OR e.end_time > NOW()) AS "ongoing!"
+        FROM discord_roles dr, events e, teams t, team_members tm, users u
+        WHERE dr.guild = $1 AND e.discord_guild = $1
+        AND t.series = e.series AND t.event = e.event AND NOT t.resigned
+        AND NOT EXISTS (SELECT 1 FROM team_members WHERE team = t.id AND status = 'unconfirmed')
+        AND tm.team = t.id AND u.id = tm.member AND u.discord_id IS NOT NULL
+        AND (dr.role = tm.role OR dr.racetime_team = t.racetime_slug)
+    "#, PgSnowflake(guild_id) as _).fetch_all(&mut **transaction).await? {
+        desired.insert(row.role.0, Some(row.discord_id.0), row.ongoing, String::default);
+    }
+    // role bindings of events, and role bindings of games whose Discord guild this is
+    for row in sqlx::query!(r#"
+        SELECT rb.discord_role_id AS "role!: PgSnowflake<RoleId>", u.discord_id AS "discord_id?: PgSnowflake<UserId>", rt.name AS "role_type", COALESCE(e.display_name, g.display_name) AS "source!", (g.id IS NOT NULL OR e.end_time IS NULL OR e.end_time > NOW()) AS "ongoing!"
+        FROM role_bindings rb
+        JOIN role_types rt ON rt.id = rb.role_type_id
+        LEFT JOIN events e ON e.series = rb.series AND e.event = rb.event
+        LEFT JOIN games g ON g.id = rb.game_id AND rb.series IS NULL AND rb.event IS NULL
+        LEFT JOIN (role_requests rr JOIN users u ON u.id = rr.user_id) ON rr.role_binding_id = rb.id AND rr.status = 'approved'
+        WHERE rb.discord_role_id IS NOT NULL
+        AND (e.discord_guild = $1 OR g.discord_guild = $1)
+    "#, PgSnowflake(guild_id) as _).fetch_all(&mut **transaction).await? {
+        desired.insert(row.role.0, row.discord_id.map(|PgSnowflake(id)| id), row.ongoing, || format!("{} in {}", row.role_type, row.source));
+    }
+    // event overrides of game role bindings
+    for row in sqlx::query!(r#"
+        SELECT o.discord_role_id AS "role!: PgSnowflake<RoleId>", u.discord_id AS "discord_id?: PgSnowflake<UserId>", rt.name AS "role_type", e.display_name, (e.end_time IS NULL OR e.end_time > NOW()) AS "ongoing!"
+        FROM event_role_binding_overrides o
+        JOIN events e ON e.series = o.series AND e.event = o.event
+        JOIN role_bindings rb ON rb.id = o.role_binding_id
+        JOIN role_types rt ON rt.id = rb.role_type_id
+        LEFT JOIN (role_requests rr JOIN users u ON u.id = rr.user_id) ON rr.role_binding_id = rb.id AND rr.status = 'approved'
+        WHERE e.discord_guild = $1
+        AND NOT e.force_custom_role_binding
+        AND o.discord_role_id IS NOT NULL
+    "#, PgSnowflake(guild_id) as _).fetch_all(&mut **transaction).await? {
+        desired.insert(row.role.0, row.discord_id.map(|PgSnowflake(id)| id), row.ongoing, || format!("{} in {}", row.role_type, row.display_name));
+    }
+    Ok(desired)
+}
+
+/// Returns the roles to add to and remove from a member so that their managed roles match the desired state.
+fn diff(current: &[RoleId], desired: Option<&HashSet<RoleId>>, kept: Option<&HashSet<RoleId>>, managed: &HashMap<RoleId, String>) -> (Vec<RoleId>, Vec<RoleId>) {
+    let mut add = desired.into_iter().flatten().copied().filter(|role| managed.contains_key(role) && !current.contains(role)).collect_vec();
+    let mut remove = current.iter().copied().filter(|role| managed.contains_key(role) && !desired.is_some_and(|desired| desired.contains(role)) && !kept.is_some_and(|kept| kept.contains(role))).collect_vec();
+    add.sort_unstable();
+    remove.sort_unstable();
+    (add, remove)
+}
+
+pub(crate) struct MemberChange {
+    pub(crate) user_id: UserId,
+    pub(crate) name: String,
+    pub(crate) add: Vec<RoleId>,
+    pub(crate) remove: Vec<RoleId>,
+}
+
+/// The changes needed to bring a guild's Discord roles in line with the database.
+pub(crate) struct Plan {
+    pub(crate) guild_id: GuildId,
+    pub(crate) role_names: HashMap<RoleId, String>,
+    /// Managed roles along with a description of what they represent.
+    pub(crate) managed: HashMap<RoleId, String>,
+    pub(crate) self_assigned: Vec<RoleId>,
+    pub(crate) changes: Vec<MemberChange>,
+}
+
+impl Plan {
+    /// A summary of the changes, used to check that the plan applied from the admin page is the one that was previewed.
+    pub(crate) fn fingerprint(&self) -> String {
+        self.changes.iter()
+            .map(|MemberChange { user_id, add, remove, .. }| format!("{user_id}+{}-{}", add.iter().join(","), remove.iter().join(",")))
+            .join(";")
+    }
+}
+
+/// Computes the role changes for all members of a guild, or only for the given users.
+///
+/// Roles that no longer exist in the guild, roles managed by integrations, and self-assigned roles (configured by name in `discord.selfAssignedRoles`) are never touched.
+pub(crate) async fn plan(transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, guild_id: GuildId, only_users: Option<&[UserId]>) -> Result<Plan, Error> {
+    let Desired { mut managed, assigned, kept } = desired_roles(transaction, guild_id).await?;
+    let self_assigned_names = discord_ctx.data.read().await.get::<Config>().expect("config missing from Discord context").discord.self_assigned_roles.clone();
+    let roles = guild_id.roles(discord_ctx).await?;
+    let self_assigned = roles.values().filter(|role| self_assigned_names.contains(&role.name)).map(|role| role.id).sorted_unstable().collect_vec();
+    managed.retain(|role_id, _| roles.get(role_id).is_some_and(|role| !role.managed && role.id.get() != guild_id.get()) && !self_assigned.contains(role_id));
+    let members = if let Some(user_ids) = only_users {
+        let mut members = Vec::with_capacity(user_ids.len());
+        for &user_id in user_ids {
+            // the user may not be a member of this guild
+            if let Ok(member) = guild_id.member(discord_ctx, user_id).await {
+                members.push(member);
+            }
+        }
+        members
+    } else {
+        pin!(guild_id.members_iter(discord_ctx)).try_collect::<Vec<_>>().await?
+    };
+    let mut changes = Vec::default();
+    for member in members {
+        if member.user.bot { continue }
+        let (add, remove) = diff(&member.roles, assigned.get(&member.user.id), kept.get(&member.user.id), &managed);
+        if !add.is_empty() || !remove.is_empty() {
+            changes.push(MemberChange {
+                user_id: member.user.id,
+                name: member.display_name().to_owned(),
+                add, remove,
+            });
+        }
+    }
+    changes.sort_unstable_by(|change1, change2| change1.name.cmp(&change2.name));
+    Ok(Plan {
+        guild_id,
+        role_names: roles.into_iter().map(|(id, role)| (id, role.name)).collect(),
+        managed, self_assigned, changes,
+    })
+}
+
+/// Applies a plan with one member edit per changed member, pausing between batches.
+///
+/// Each member's current roles are fetched again right before editing, so roles changed since the plan was computed are kept.
+/// Returns the number of members whose roles were edited.
+pub(crate) async fn apply(discord_ctx: &DiscordCtx, plan: &Plan) -> Result<usize, Error> {
+    let mut edited = 0;
+    for (batch_idx, batch) in plan.changes.chunks(BATCH_SIZE).enumerate() {
+        if batch_idx > 0 {
+            sleep(BATCH_PAUSE).await;
+        }
+        for change in batch {
+            let Ok(member) = plan.guild_id.member(discord_ctx, change.user_id).await else { continue }; // member left the guild
+            let mut roles = member.roles.iter().copied().filter(|role| !change.remove.contains(role)).collect::<HashSet<_>>();
+            roles.extend(change.add.iter().copied());
+            if roles == member.roles.iter().copied().collect() { continue }
+            plan.guild_id.edit_member(discord_ctx, change.user_id, EditMember::new()
+                .roles(roles)
+                .audit_log_reason("Hyrule Town Hall role reconciliation")
+            ).await?;
+            edited += 1;
+        }
+    }
+    Ok(edited)
+}
+
+/// Brings the managed roles of the given users in line with the database, either in the given guild or in every guild where Hyrule Town Hall manages roles.
+///
+/// Call this after committing the change that affects the users' roles. Errors are logged rather than returned since the change itself has already been saved.
+pub(crate) async fn reconcile_members(db_pool: &PgPool, discord_ctx: &DiscordCtx, guild_id: Option<GuildId>, user_ids: &[UserId]) {
+    if user_ids.is_empty() { return }
+    let mut plans = Vec::default();
+    let result = async {
+        let mut transaction = db_pool.begin().await?;
+        let guilds = if let Some(guild_id) = guild_id {
+            vec![guild_id]
+        } else {
+            sqlx::query_scalar!(r#"
+                SELECT discord_guild AS "discord_guild!: PgSnowflake<GuildId>" FROM events WHERE discord_guild IS NOT NULL AND (end_time IS NULL OR end_time > NOW())
+                UNION SELECT discord_guild FROM games WHERE discord_guild IS NOT NULL
+            "#).fetch_all(&mut *transaction).await?.into_iter().map(|PgSnowflake(guild_id)| guild_id).collect()
+        };
+        for guild_id in guilds {
+            match plan(&mut transaction, discord_ctx, guild_id, Some(user_ids)).await {
+                Ok(plan) => plans.push(plan),
+                Err(e) => eprintln!("Failed to compute Discord roles in guild {guild_id}: {e}"),
+            }
+        }
+        transaction.commit().await
+    }.await;
+    if let Err(e) = result {
+        eprintln!("Failed to compute Discord roles: {e}");
+        return
+    }
+    for plan in plans {
+        if let Err(e) = apply(discord_ctx, &plan).await {
+            eprintln!("Failed to update Discord roles in guild {}: {e}", plan.guild_id);
+        }
+    }
+}
 
 /// Handle Discord role assignment for users who join the server
 pub(crate) async fn handle_member_join(
@@ -45,8 +286,24 @@ pub(crate) async fn handle_member_join(
         eprintln!("Successfully assigned Discord role {} to user {} for role request {}", 
                  invite.discord_role_id, user_id, invite.role_request_id);
     }
-    
+
     transaction.commit().await?;
+
+    // Assign any other roles the new member should have, e.g. if they signed up for an event before joining.
+    // This is best-effort so a Discord error doesn't undo the invites handled above.
+    let mut transaction = db_pool.begin().await?;
+    match plan(&mut transaction, discord_ctx, guild_id, Some(&[user_id])).await {
+        Ok(plan) => {
+            transaction.commit().await?;
+            if let Err(e) = apply(discord_ctx, &plan).await {
+                eprintln!("Failed to reconcile Discord roles of new member {user_id} in guild {guild_id}: {e}");
+            }
+        }
+        Err(e) => {
+            transaction.rollback().await?;
+            eprintln!("Failed to reconcile Discord roles of new member {user_id} in guild {guild_id}: {e}");
+        }
+    }
     Ok(())
 }
 
@@ -71,4 +328,28 @@ pub(crate) async fn cleanup_expired_invites(
     
     transaction.commit().await?;
     Ok(())
-} 
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_only_touches_managed_roles() {
+        let [participant, volunteer, team, unmanaged] = [1, 2, 3, 4].map(RoleId::new);
+        let managed = [participant, volunteer, team].into_iter().map(|role| (role, String::default())).collect();
+        let desired = [participant, volunteer].into_iter().collect::<HashSet<_>>();
+        assert_eq!(diff(&[volunteer, team, unmanaged], Some(&desired), None, &managed), (vec![participant], vec![team]));
+        assert_eq!(diff(&[volunteer, unmanaged], None, None, &managed), (vec![], vec![volunteer]));
+        assert_eq!(diff(&[participant, volunteer], Some(&desired), None, &managed), (vec![], vec![]));
+    }
+
+    #[test]
+    fn diff_keeps_roles_granted_by_ended_events() {
+        let [participant, team] = [1, 2].map(RoleId::new);
+        let managed = [participant, team].into_iter().map(|role| (role, String::default())).collect();
+        let kept = [team].into_iter().collect::<HashSet<_>>();
+        assert_eq!(diff(&[participant, team], None, Some(&kept), &managed), (vec![], vec![participant]));
+        assert_eq!(diff(&[], None, Some(&kept), &managed), (vec![], vec![]));
+    }
+}
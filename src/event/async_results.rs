@@ -1,5 +1,5 @@
 use crate::{
-    event::{AsyncKind, Data, Series, Tab, teams::{QualifierKind, QualifierScoreKind}},
+    event::{AsyncKind, Data, Series, Tab, async_verification, teams::QualifierKind},
     prelude::*,
     time::decode_pginterval,
 };
@@ -8,6 +8,8 @@ struct AsyncResultRow {
     place: usize,
     display_name: String,
     time: Duration,
+    /// Approved pauses which were deducted from the time.
+    paused: Option<Duration>,
     points: Option<f64>,
     vod: Option<String>,
 }
@@ -32,8 +34,8 @@ pub(crate) async fn get(
         .ok_or(StatusOrError::Status(Status::NotFound))?;
 
     let qualifier_kind = data.qualifier_kind(&mut transaction).await?;
-    let score_kind = match qualifier_kind {
-        QualifierKind::Score(k) => k,
+    let scoring = match qualifier_kind {
+        QualifierKind::Score(scoring) => scoring,
         _ => return Err(StatusOrError::Status(Status::NotFound)),
     };
 
@@ -55,11 +57,16 @@ pub(crate) async fn get(
             player: Id<Users>,
             time: sqlx::postgres::types::PgInterval,
             vod: Option<String>,
+            paused: Option<sqlx::postgres::types::PgInterval>,
         }
 
         let rows = sqlx::query_as!(
             PlayerRow,
-            r#"SELECT ap.player AS "player: Id<Users>", ap.time AS "time!", ap.vod FROM async_players ap WHERE ap.series = $1 AND ap.event = $2 AND ap.kind = $3 AND ap.time IS NOT NULL ORDER BY ap.time ASC"#,
+            r#"SELECT ap.player AS "player: Id<Users>", ap.time AS "time!", ap.vod, (
+                SELECT SUM(p.ended_at - p.started_at)
+                FROM async_pauses p JOIN teams t ON t.id = p.team JOIN team_members tm ON tm.team = t.id
+                WHERE tm.member = ap.player AND t.series = ap.series AND t.event = ap.event AND p.kind = ap.kind AND p.status = 'approved'
+            ) AS paused FROM async_players ap WHERE ap.series = $1 AND ap.event = $2 AND ap.kind = $3 AND ap.time IS NOT NULL AND ap.verification_status = 'verified' ORDER BY ap.time ASC"#,
             data.series as _,
             &data.event,
             async_kind as _
@@ -78,19 +85,7 @@ pub(crate) async fn get(
             .collect();
 
         let num_entrants = finish_times.len();
-        let par_cutoff = match score_kind {
-            QualifierScoreKind::Standard => 7usize.min(num_entrants),
-            QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => {
-                if num_entrants < 20 { 3 } else { 4 }
-            }
-            QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain => 3,
-        };
-
-        let par_time_opt = if finish_times.len() >= par_cutoff {
-            Some(finish_times[..par_cutoff].iter().sum::<Duration>() / par_cutoff as u32)
-        } else {
-            None
-        };
+        let par_times = scoring.par_times(&finish_times, num_entrants);
 
         let title = match async_kind {
             AsyncKind::Qualifier1 => "Qualifier 1",
@@ -107,22 +102,7 @@ pub(crate) async fn get(
                 .await?
                 .expect("async player not found");
 
-            let points = par_time_opt.and_then(|par_time| match score_kind {
-                QualifierScoreKind::TwwrMiniblins26 => {
-                    Some((2000.0 + ((1.0 - (time.as_secs_f64() - par_time.as_secs_f64()) / par_time.as_secs_f64()) * 1000.0).floor()).max(100.0))
-                }
-                QualifierScoreKind::TwwrMain => {
-                    Some(((1.0 - (time.as_secs_f64() - par_time.as_secs_f64()) / par_time.as_secs_f64()) * 1000.0).max(100.0))
-                }
-                QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => {
-                    Some((100.0 * (2.0 - (time.as_secs_f64() / par_time.as_secs_f64()))).clamp(10.0, 110.0))
-                }
-                QualifierScoreKind::Standard => {
-                    // Standard formula requires per-qualifier par calculation with complex adjustments;
-                    // points are shown on the standings page instead
-                    None
-                }
-            });
+            let points = par_times.map(|par_times| scoring.points(*time, par_times));
 
             if row.vod.is_some() {
                 has_vod = true;
@@ -132,6 +112,7 @@ pub(crate) async fn get(
                 place: place + 1,
                 display_name: user.display_name().to_owned(),
                 time: *time,
+                paused: row.paused.clone().and_then(|paused| decode_pginterval(paused).ok()).filter(|paused| !paused.is_zero()),
                 points,
                 vod: row.vod.clone(),
             });
@@ -140,8 +121,23 @@ pub(crate) async fn get(
         sections.push(AsyncResultSection { title, rows: result_rows, has_vod });
     }
 
+    let is_verifier = if data.async_verification && let Some(ref me) = me {
+        async_verification::is_verifier(&mut transaction, &data, me).await?
+    } else {
+        false
+    };
+
     let content = html! {
         : header;
+        @if data.async_verification {
+            p {
+                : "Runs are listed once their VoD has been verified.";
+                @if is_verifier {
+                    : " ";
+                    a(href = uri!(async_verification::get(data.series, &*data.event)).to_string()) : "Review pending runs";
+                }
+            }
+        }
         @if sections.is_empty() {
             p : "No async qualifier results are available yet.";
         } else {
@@ -177,7 +173,13 @@ pub(crate) async fn get(
                             tr {
                                 td : row.place;
                                 td : &row.display_name;
-                                td : English.format_duration(row.time, false);
+                                td {
+                                    : English.format_duration(row.time, false);
+                                    @if let Some(paused) = row.paused {
+                                        br;
+                                        small : format!("{} of pauses deducted", English.format_duration(paused, false));
+                                    }
+                                }
                                 @if section.rows.iter().any(|r| r.points.is_some()) {
                                     td {
                                         @if let Some(pts) = row.points {
@@ -0,0 +1,420 @@
+//! Review of submitted async runs for events which require verification.
+//!
+//! While an event has async verification enabled, newly submitted async times are queued as pending by a database trigger and don't count toward results until
+//! an organizer or a volunteer with an approved request for the “Async Verification” role has watched the VOD and verified (optionally adjusting) or flagged the run.
+
+use crate::{
+    event::{
+        AsyncKind,
+        Data,
+        Series,
+        Tab,
+        roles::{
+            EffectiveRoleBinding,
+            RoleRequest,
+        },
+    },
+    prelude::*,
+};
+
+pub(crate) const ROLE_TYPE_NAME: &str = "Async Verification";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "async_verification_status", rename_all = "lowercase")]
+pub(crate) enum VerificationStatus {
+    Pending,
+    Verified,
+    Flagged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromFormField)]
+pub(crate) enum ReviewAction {
+    Verify,
+    Flag,
+}
+
+/// Whether the user may review async runs for this event.
+pub(crate) async fn is_verifier(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, user: &User) -> Result<bool, event::Error> {
+    if user.is_global_admin() || event.organizers(&mut *transaction).await?.contains(user) {
+        return Ok(true)
+    }
+    for binding in EffectiveRoleBinding::for_event(&mut *transaction, event.series, &event.event).await? {
+        if !binding.is_disabled && binding.role_type_name == ROLE_TYPE_NAME && RoleRequest::approved_for_user(&mut *transaction, binding.id, user.id).await? {
+            return Ok(true)
+        }
+    }
+    Ok(false)
+}
+
+/// The heading of the section of the review queue which lists runs with the given status.
+fn section_title(status: VerificationStatus) -> &'static str {
+    match status {
+        VerificationStatus::Pending => "Pending",
+        VerificationStatus::Flagged => "Flagged",
+        VerificationStatus::Verified => "Recently verified",
+    }
+}
+
+/// Groups the runs in the review queue by status, keeping the order of both sections and runs.
+fn queue_sections<T>(runs: impl IntoIterator<Item = (VerificationStatus, T)>) -> Vec<(&'static str, Vec<T>)> {
+    let mut sections = Vec::<(&'static str, Vec<T>)>::default();
+    for (status, run) in runs {
+        let title = section_title(status);
+        if let Some((_, runs)) = sections.iter_mut().find(|(section_title, _)| *section_title == title) {
+            runs.push(run);
+        } else {
+            sections.push((title, vec![run]));
+        }
+    }
+    sections
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Review<'a> {
+    Verify {
+        time: Duration,
+        notes: Option<&'a str>,
+    },
+    Flag {
+        notes: &'a str,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ReviewError {
+    Time,
+    MissingNotes,
+}
+
+impl ReviewError {
+    fn to_form_error(&self) -> form::Error<'static> {
+        match self {
+            Self::Time => form::Error::validation("Duration must be formatted like '1:23:45' or '1h 23m 45s'.").with_name("time"),
+            Self::MissingNotes => form::Error::validation("Please explain why the run is being flagged.").with_name("notes"),
+        }
+    }
+}
+
+fn parse_review<'a>(action: ReviewAction, time: &str, notes: &'a str) -> Result<Review<'a>, ReviewError> {
+    let notes = Some(notes.trim()).filter(|notes| !notes.is_empty());
+    match action {
+        // the time is only updated when verifying, so flagging a run doesn't require one
+        ReviewAction::Flag => Ok(Review::Flag { notes: notes.ok_or(ReviewError::MissingNotes)? }),
+        ReviewAction::Verify => Ok(Review::Verify { time: parse_duration(time, None).ok_or(ReviewError::Time)?, notes }),
+    }
+}
+
+struct Run {
+    player: Id<Users>,
+    kind: AsyncKind,
+    time: Option<sqlx::postgres::types::PgInterval>,
+    submitted_time: Option<sqlx::postgres::types::PgInterval>,
+    vod: Option<String>,
+    status: VerificationStatus,
+    verified_by: Option<Id<Users>>,
+    verified_at: Option<DateTime<Utc>>,
+    notes: Option<String>,
+}
+
+async fn queue_page(mut transaction: Transaction<'_, Postgres>, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, ctx: Option<Context<'_>>) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let header = event.header(&mut transaction, me.as_ref(), Tab::Asyncs, true).await?;
+    let is_verifier = if let Some(ref me) = me { is_verifier(&mut transaction, &event, me).await? } else { false };
+    let content = if !event.async_verification {
+        html! {
+            article {
+                p : "This event does not require async runs to be verified.";
+            }
+        }
+    } else if is_verifier {
+        let runs = sqlx::query_as!(Run, r#"
+            SELECT
+                player AS "player: Id<Users>",
+                kind AS "kind: AsyncKind",
+                time,
+                submitted_time,
+                vod,
+                verification_status AS "status: VerificationStatus",
+                verified_by AS "verified_by: Id<Users>",
+                verified_at,
+                verification_notes AS notes
+            FROM async_players
+            WHERE series = $1 AND event = $2 AND time IS NOT NULL AND (verification_status <> 'verified' OR verified_at > NOW() - INTERVAL '14 days')
+            ORDER BY verification_status, verified_at DESC NULLS LAST, kind, time
+        "#, event.series as _, &event.event).fetch_all(&mut *transaction).await?;
+        let errors = ctx.as_ref().map(|ctx| ctx.errors().collect_vec()).unwrap_or_default();
+        let mut rows = Vec::with_capacity(runs.len());
+        for run in runs {
+            let runner = User::from_id(&mut *transaction, run.player).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+            let reviewer = if let Some(verified_by) = run.verified_by { User::from_id(&mut *transaction, verified_by).await? } else { None };
+            // errors from a failed review are shown on the form that was submitted
+            let run_errors = if ctx.as_ref().is_some_and(|ctx| ctx.field_value("player") == Some(&*run.player.to_string()) && ctx.field_value("kind") == Some(&*format!("{:?}", run.kind))) {
+                errors.clone()
+            } else {
+                Vec::default()
+            };
+            rows.push((run.status, (run, runner, reviewer, run_errors)));
+        }
+        let sections = queue_sections(rows);
+        html! {
+            @if sections.is_empty() {
+                p : "There are no async runs to review.";
+            }
+            @for (title, runs) in &sections {
+                h2 : *title;
+                table {
+                    thead {
+                        tr {
+                            th : "Runner";
+                            th : "Async";
+                            th : "Submitted time";
+                            th : "VoD";
+                            th : "Review";
+                        }
+                    }
+                    tbody {
+                        @for (run, runner, reviewer, run_errors) in runs {
+                            tr {
+                                td : runner;
+                                td : run.kind.display_name();
+                                td {
+                                    @if let Some(time) = run.submitted_time.clone().or_else(|| run.time.clone()).and_then(|time| decode_pginterval(time).ok()) {
+                                        : English.format_duration(time, false);
+                                    }
+                                }
+                                td {
+                                    @if let Some(ref vod) = run.vod {
+                                        @if let Ok(vod_url) = Url::parse(vod) {
+                                            a(href = vod_url.to_string()) : "VoD";
+                                        } else {
+                                            : vod;
+                                        }
+                                    } else {
+                                        : "none";
+                                    }
+                                }
+                                td {
+                                    @if let (Some(reviewer), Some(verified_at)) = (reviewer, run.verified_at) {
+                                        p {
+                                            @match run.status {
+                                                VerificationStatus::Flagged => : "Flagged by ";
+                                                VerificationStatus::Pending | VerificationStatus::Verified => : "Verified by ";
+                                            }
+                                            : reviewer;
+                                            : " ";
+                                            : format_datetime(verified_at, DateTimeFormat { long: false, running_text: true });
+                                            @if let Some(ref notes) = run.notes {
+                                                : ": ";
+                                                : notes;
+                                            }
+                                        }
+                                    }
+                                    : full_form(uri!(review(event.series, &*event.event)), csrf, html! {
+                                        input(type = "hidden", name = "player", value = run.player.to_string());
+                                        input(type = "hidden", name = "kind", value = format!("{:?}", run.kind));
+                                        input(type = "text", name = "time", value = run.time.clone().and_then(|time| decode_pginterval(time).ok()).map(unparse_duration).unwrap_or_default());
+                                        input(type = "text", name = "notes", value = run.notes.clone().unwrap_or_default(), placeholder = "Notes (sent to the runner)");
+                                        select(name = "action") {
+                                            option(value = "verify") : "Verify";
+                                            option(value = "flag", selected? = (run.status == VerificationStatus::Flagged).then_some("selected")) : "Flag";
+                                        }
+                                    }, run_errors.clone(), "Save");
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        html! {
+            article {
+                p : "This page is only available for event organizers and async verifiers.";
+            }
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Async Verification — {}", event.display_name), html! {
+        : header;
+        h2 : "Async verification";
+        : content;
+    }).await?)
+}
+
+/// The verification state of a team's async runs, shown on the team's status page.
+///
+/// This is how runners without a connected Discord account learn about a review.
+pub(crate) async fn status_section(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, team: Id<Teams>) -> Result<RawHtml<String>, event::Error> {
+    let runs = sqlx::query!(r#"
+        SELECT
+            player AS "player: Id<Users>",
+            kind AS "kind: AsyncKind",
+            time,
+            verification_status AS "status: VerificationStatus",
+            verification_notes AS notes
+        FROM async_players JOIN team_members ON member = player
+        WHERE team = $1 AND series = $2 AND event = $3 AND time IS NOT NULL
+        ORDER BY kind, role
+    "#, team as _, event.series as _, &event.event).fetch_all(&mut **transaction).await?;
+    if runs.is_empty() {
+        return Ok(RawHtml(String::default()))
+    }
+    let mut rows = Vec::with_capacity(runs.len());
+    for run in runs {
+        rows.push((User::from_id(&mut **transaction, run.player).await?, run));
+    }
+    Ok(html! {
+        h3 : "Async verification";
+        ul {
+            @for (runner, run) in &rows {
+                li {
+                    @if let Some(runner) = runner {
+                        : runner;
+                        : ", ";
+                    }
+                    : run.kind.display_name();
+                    : ": ";
+                    @match run.status {
+                        VerificationStatus::Pending => : "pending verification";
+                        VerificationStatus::Verified => {
+                            : "verified";
+                            @if let Some(time) = run.time.clone().and_then(|time| decode_pginterval(time).ok()) {
+                                : " with a time of ";
+                                : English.format_duration(time, false);
+                            }
+                        }
+                        VerificationStatus::Flagged => : "flagged, this run won't count until it's resolved. Please reach out to the organizers.";
+                    }
+                    @if let Some(ref notes) = run.notes {
+                        : " Notes: ";
+                        : notes;
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[rocket::get("/event/<series>/<event>/async-verification")]
+pub(crate) async fn get(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok(queue_page(transaction, me, uri, csrf.as_ref(), event, None).await?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct ReviewForm {
+    #[field(default = String::new())]
+    csrf: String,
+    player: Id<Users>,
+    kind: AsyncKind,
+    time: String,
+    notes: String,
+    action: ReviewAction,
+}
+
+#[rocket::post("/event/<series>/<event>/async-verification", data = "<form>")]
+pub(crate) async fn review(pool: &State<PgPool>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, ReviewForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if !event.async_verification {
+            form.context.push_error(form::Error::validation("This event does not require async runs to be verified."));
+        }
+        if !is_verifier(&mut transaction, &event, &me).await? {
+            form.context.push_error(form::Error::validation("You must be an organizer or async verifier to review async runs."));
+        }
+        let submitted = sqlx::query_scalar!("SELECT EXISTS (SELECT 1 FROM async_players WHERE series = $1 AND event = $2 AND player = $3 AND kind = $4 AND time IS NOT NULL)", event.series as _, &event.event, value.player as _, value.kind as _).fetch_one(&mut *transaction).await?;
+        if !submitted.unwrap_or(false) {
+            form.context.push_error(form::Error::validation("There is no submitted time for this async run."));
+        }
+        let runner = User::from_id(&mut *transaction, value.player).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+        let review = parse_review(value.action, &value.time, &value.notes);
+        if let Err(ref e) = review {
+            form.context.push_error(e.to_form_error());
+        }
+        if let Ok(review) = review && form.context.errors().next().is_none() {
+            let notes = match review {
+                Review::Flag { notes } => {
+                    sqlx::query!("
+                        UPDATE async_players SET verification_status = 'flagged', verified_by = $1, verified_at = NOW(), verification_notes = $2
+                        WHERE series = $3 AND event = $4 AND player = $5 AND kind = $6
+                    ", me.id as _, notes, event.series as _, &event.event, value.player as _, value.kind as _).execute(&mut *transaction).await?;
+                    Some(notes)
+                }
+                Review::Verify { time, notes } => {
+                    sqlx::query!("
+                        UPDATE async_players SET time = $1, verification_status = 'verified', verified_by = $2, verified_at = NOW(), verification_notes = $3
+                        WHERE series = $4 AND event = $5 AND player = $6 AND kind = $7
+                    ", time as _, me.id as _, notes, event.series as _, &event.event, value.player as _, value.kind as _).execute(&mut *transaction).await?;
+                    notes
+                }
+            };
+            transaction.commit().await?;
+            // runners without a connected Discord account see the result in the verification section of their status page
+            if let Some(discord) = runner.discord {
+                let discord_ctx = discord_ctx.read().await;
+                let mut msg = MessageBuilder::default();
+                msg.push("Your ");
+                msg.push(value.kind.display_name());
+                msg.push(" run for ");
+                msg.push_safe(&event.display_name);
+                match review {
+                    Review::Flag { .. } => {
+                        msg.push(" has been flagged by ");
+                        msg.mention_user(&me);
+                        msg.push(" and won't count until it's resolved. Please reach out to the organizers.");
+                    }
+                    Review::Verify { time, .. } => {
+                        msg.push(" has been verified with a time of ");
+                        msg.push(English.format_duration(time, false));
+                        msg.push(".");
+                    }
+                }
+                if let Some(notes) = notes {
+                    msg.push("\nNotes: ");
+                    msg.push_safe(notes);
+                }
+                if let Ok(dm) = UserId::new(discord.id.get()).create_dm_channel(&*discord_ctx).await {
+                    let _ = dm.say(&*discord_ctx, msg.build()).await;
+                }
+            }
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        } else {
+            RedirectOrContent::Content(queue_page(transaction, Some(me), uri, csrf.as_ref(), event, Some(form.context)).await?)
+        }
+    } else {
+        RedirectOrContent::Content(queue_page(transaction, Some(me), uri, csrf.as_ref(), event, Some(form.context)).await?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_sections_keep_order() {
+        let sections = queue_sections([
+            (VerificationStatus::Pending, 1),
+            (VerificationStatus::Pending, 2),
+            (VerificationStatus::Flagged, 3),
+            (VerificationStatus::Verified, 4),
+            (VerificationStatus::Verified, 5),
+        ]);
+        assert_eq!(sections, [("Pending", vec![1, 2]), ("Flagged", vec![3]), ("Recently verified", vec![4, 5])]);
+        assert!(queue_sections::<()>([]).is_empty());
+    }
+
+    #[test]
+    fn verify_requires_time() {
+        assert_eq!(parse_review(ReviewAction::Verify, "1:23:45", ""), Ok(Review::Verify { time: Duration::from_secs(5025), notes: None }));
+        assert_eq!(parse_review(ReviewAction::Verify, "1:23:45", " adjusted for a pause "), Ok(Review::Verify { time: Duration::from_secs(5025), notes: Some("adjusted for a pause") }));
+        assert_eq!(parse_review(ReviewAction::Verify, "abc", "notes"), Err(ReviewError::Time));
+    }
+
+    #[test]
+    fn flag_requires_notes() {
+        assert_eq!(parse_review(ReviewAction::Flag, "", "VoD is missing the finish"), Ok(Review::Flag { notes: "VoD is missing the finish" }));
+        assert_eq!(parse_review(ReviewAction::Flag, "1:23:45", "  "), Err(ReviewError::MissingNotes));
+    }
+}
@@ -141,6 +141,11 @@ async fn asyncs_form(
                 : header;
                 article {
                     h2 : "Async Qualifiers";
+                    @if event.automated_asyncs {
+                        p {
+                            a(href = uri!(crate::event::web_async::runs(event.series, &*event.event))) : "Manage team runs";
+                        }
+                    }
                     @if asyncs.is_empty() {
                         p : "No asyncs defined.";
                     } else {
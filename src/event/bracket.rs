@@ -0,0 +1,1163 @@
+//! Brackets run by Hyrule Town Hall itself, for events that don't use an external bracket site.
+//!
+//! An elimination bracket's structure is fully determined by its kind and size, so only the state of each match is stored.
+//! Swiss and round-robin brackets are paired by the [`swiss`] module.
+//! An event can have a single bracket, or one bracket per stage as configured in the [`stages`] module.
+//! Races are created for a match as soon as both of its entrants are known, and [`record_game`] moves entrants on when a result is reported.
+
+use crate::{
+    event::{
+        Data,
+        Series,
+        Tab,
+        stages::{
+            self,
+            Stage,
+        },
+        swiss,
+    },
+    id::Brackets,
+    prelude::*,
+    seed,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "bracket_kind", rename_all = "snake_case")]
+pub(crate) enum Kind {
+    #[field(value = "single_elimination")]
+    SingleElimination,
+    #[field(value = "double_elimination")]
+    DoubleElimination,
+    #[field(value = "swiss")]
+    Swiss,
+    #[field(value = "round_robin")]
+    RoundRobin,
+}
+
+impl Kind {
+    pub(crate) fn is_elimination(&self) -> bool {
+        matches!(self, Self::SingleElimination | Self::DoubleElimination)
+    }
+
+    pub(crate) fn display_name(&self) -> &'static str {
+        match self {
+            Self::SingleElimination => "single elimination",
+            Self::DoubleElimination => "double elimination",
+            Self::Swiss => "Swiss",
+            Self::RoundRobin => "round robin",
+        }
+    }
+}
+
+/// How to lay out a new bracket.
+pub(crate) struct Settings {
+    pub(crate) kind: Kind,
+    pub(crate) grand_final_reset: bool,
+    /// Number of rounds of a Swiss bracket.
+    pub(crate) rounds: Option<i16>,
+    /// Number of pools of a round-robin bracket.
+    pub(crate) pools: usize,
+    pub(crate) best_of: i16,
+}
+
+impl Settings {
+    /// Checks whether a bracket with these settings can be created for the given number of entrants.
+    pub(crate) fn validate(&self, num_entrants: usize) -> Result<(), SettingsError> {
+        if num_entrants < 2 {
+            Err(SettingsError::NotEnoughEntrants)
+        } else if self.best_of < 1 || self.best_of % 2 == 0 {
+            Err(SettingsError::BestOf)
+        } else if self.kind == Kind::Swiss && !self.rounds.is_some_and(|rounds| rounds >= 1 && (rounds as usize) < num_entrants) {
+            Err(SettingsError::Rounds)
+        } else if self.kind == Kind::RoundRobin && (self.pools < 1 || self.pools > 26 || num_entrants < 2 * self.pools) {
+            Err(SettingsError::Pools)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SettingsError {
+    #[error("A bracket needs at least 2 entrants.")]
+    NotEnoughEntrants,
+    #[error("Matches must be best of an odd number of games.")]
+    BestOf,
+    #[error("A Swiss bracket needs at least 1 round and fewer rounds than entrants.")]
+    Rounds,
+    #[error("Round robin pools need at least 2 entrants each, and there can be at most 26 pools.")]
+    Pools,
+}
+
+impl SettingsError {
+    /// The form field this error should be displayed on.
+    pub(crate) fn field(&self) -> Option<&'static str> {
+        match self {
+            Self::NotEnoughEntrants => None,
+            Self::BestOf => Some("best_of"),
+            Self::Rounds => Some("rounds"),
+            Self::Pools => Some("pools"),
+        }
+    }
+
+    pub(crate) fn to_form_error(&self) -> form::Error<'static> {
+        let error = form::Error::validation(self.to_string());
+        if let Some(field) = self.field() { error.with_name(field) } else { error }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, sqlx::Type)]
+#[sqlx(type_name = "bracket_side", rename_all = "snake_case")]
+pub(crate) enum Side {
+    Winners,
+    Losers,
+    GrandFinal,
+    /// All matches of Swiss and round-robin brackets.
+    Pool,
+}
+
+impl Side {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Winners => "winners",
+            Self::Losers => "losers",
+            Self::GrandFinal => "grand_final",
+            Self::Pool => "pool",
+        }
+    }
+}
+
+impl FromStr for Side {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "winners" => Ok(Self::Winners),
+            "losers" => Ok(Self::Losers),
+            "grand_final" => Ok(Self::GrandFinal),
+            "pool" => Ok(Self::Pool),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Slot<T> {
+    /// Waiting for the result of an earlier match.
+    Pending,
+    Entrant(T),
+    /// No entrant will ever fill this slot, so the opponent advances without playing.
+    Bye,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Match<T> {
+    pub(crate) side: Side,
+    /// Always 0 except in round-robin brackets with multiple pools.
+    pub(crate) pool: i16,
+    pub(crate) round: i16,
+    pub(crate) position: i16,
+    pub(crate) best_of: i16,
+    pub(crate) slots: [Slot<T>; 2],
+    pub(crate) winner_slot: Option<usize>,
+    /// Set on the grand final reset if the winners bracket champion won the first grand final.
+    pub(crate) skipped: bool,
+}
+
+impl<T: Copy> Match<T> {
+    fn is_decided(&self) -> bool {
+        self.winner_slot.is_some() || self.skipped
+    }
+
+    pub(crate) fn entrants(&self) -> Option<[T; 2]> {
+        match self.slots {
+            [Slot::Entrant(team1), Slot::Entrant(team2)] => Some([team1, team2]),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Bracket<T> {
+    pub(crate) kind: Kind,
+    pub(crate) grand_final_reset: bool,
+    /// Sorted by side, pool, round, and position.
+    pub(crate) matches: Vec<Match<T>>,
+}
+
+/// The order in which seeds are placed into the first round, so that the top seeds can only meet in the latest possible rounds.
+fn seed_order(size: usize) -> Vec<usize> {
+    let mut order = vec![1];
+    while order.len() < size {
+        let len = order.len();
+        order = order.into_iter().flat_map(|seed| [seed, 2 * len + 1 - seed]).collect();
+    }
+    order
+}
+
+/// Number of matches in the given losers bracket round of a bracket for `size` entrants.
+///
+/// Losers bracket rounds come in pairs: the odd round halves the field, and the following even round brings in the losers of the next winners bracket round.
+fn losers_round_size(size: usize, round: i16) -> usize {
+    let pair = round / 2;
+    if round % 2 == 1 { size >> (pair + 2) } else { size >> (pair + 1) }
+}
+
+impl<T: Copy + Eq> Bracket<T> {
+    /// Lays out an elimination bracket for the given entrants, best seed first. Missing seeds up to the next power of two become byes.
+    pub(crate) fn new(kind: Kind, grand_final_reset: bool, seeds: &[T], best_of: i16) -> Self {
+        let size = seeds.len().next_power_of_two().max(2);
+        let winners_rounds = size.trailing_zeros() as i16;
+        let order = seed_order(size);
+        let mut matches = Vec::default();
+        for round in 1..=winners_rounds {
+            for position in 0..size >> round {
+                let slots = if round == 1 {
+                    [0, 1].map(|i| seeds.get(order[2 * position + i] - 1).map_or(Slot::Bye, |&team| Slot::Entrant(team)))
+                } else {
+                    [Slot::Pending; 2]
+                };
+                matches.push(Match { side: Side::Winners, pool: 0, round, position: position as i16, best_of, slots, winner_slot: None, skipped: false });
+            }
+        }
+        if let Kind::DoubleElimination = kind {
+            for round in 1..=2 * (winners_rounds - 1) {
+                for position in 0..losers_round_size(size, round) {
+                    matches.push(Match { side: Side::Losers, pool: 0, round, position: position as i16, best_of, slots: [Slot::Pending; 2], winner_slot: None, skipped: false });
+                }
+            }
+            matches.push(Match { side: Side::GrandFinal, pool: 0, round: 1, position: 0, best_of, slots: [Slot::Pending; 2], winner_slot: None, skipped: false });
+            if grand_final_reset {
+                matches.push(Match { side: Side::GrandFinal, pool: 0, round: 2, position: 0, best_of, slots: [Slot::Pending; 2], winner_slot: None, skipped: false });
+            }
+        }
+        let mut bracket = Self { kind, grand_final_reset, matches };
+        bracket.advance_byes();
+        bracket
+    }
+
+    fn rounds(&self, side: Side) -> i16 {
+        self.matches.iter().filter(|m| m.side == side).map(|m| m.round).max().unwrap_or(0)
+    }
+
+    pub(crate) fn find(&self, side: Side, round: i16, position: i16) -> Option<usize> {
+        self.matches.iter().position(|m| m.side == side && m.round == round && m.position == position)
+    }
+
+    /// Indices of the matches in each round of the given side and pool of the bracket.
+    pub(crate) fn round_matches(&self, side: Side, pool: i16) -> Vec<Vec<usize>> {
+        (1..=self.rounds(side)).map(|round| self.matches.iter().enumerate().filter(|(_, m)| m.side == side && m.pool == pool && m.round == round).map(|(idx, _)| idx).collect_vec()).filter(|round| !round.is_empty()).collect()
+    }
+
+    pub(crate) fn pools(&self) -> i16 {
+        self.matches.iter().map(|m| m.pool + 1).max().unwrap_or(1)
+    }
+
+    /// Where the winner and the loser of the given match go next, as match index and slot.
+    fn targets(&self, idx: usize) -> [Option<(usize, usize)>; 2] {
+        let m = &self.matches[idx];
+        let winners_rounds = self.rounds(Side::Winners);
+        let double = self.kind == Kind::DoubleElimination;
+        let [winner, loser] = match m.side {
+            Side::Winners => [
+                if m.round < winners_rounds {
+                    Some((Side::Winners, m.round + 1, m.position / 2, (m.position % 2) as usize))
+                } else if double {
+                    Some((Side::GrandFinal, 1, 0, 0))
+                } else {
+                    None
+                },
+                if !double {
+                    None
+                } else if winners_rounds == 1 {
+                    Some((Side::GrandFinal, 1, 0, 1))
+                } else if m.round == 1 {
+                    Some((Side::Losers, 1, m.position / 2, (m.position % 2) as usize))
+                } else {
+                    // drop losers in reverse order to make early rematches less likely
+                    let count = (1 << winners_rounds) >> m.round;
+                    Some((Side::Losers, 2 * (m.round - 1), count - 1 - m.position, 1))
+                },
+            ],
+            Side::Losers => [
+                if m.round == self.rounds(Side::Losers) {
+                    Some((Side::GrandFinal, 1, 0, 1))
+                } else if m.round % 2 == 1 {
+                    Some((Side::Losers, m.round + 1, m.position, 0))
+                } else {
+                    Some((Side::Losers, m.round + 1, m.position / 2, (m.position % 2) as usize))
+                },
+                None,
+            ],
+            // the grand final reset is handled separately in set_winner
+            Side::GrandFinal => [None, None],
+            // pool matches don't feed into other matches
+            Side::Pool => [None, None],
+        };
+        [winner, loser].map(|target| target.and_then(|(side, round, position, slot)| Some((self.find(side, round, position)?, slot))))
+    }
+
+    fn set_winner(&mut self, idx: usize, winner_slot: usize) {
+        self.matches[idx].winner_slot = Some(winner_slot);
+        let Match { side, round, slots, .. } = self.matches[idx];
+        if side == Side::GrandFinal && round == 1 {
+            if let Some(reset) = self.find(Side::GrandFinal, 2, 0) {
+                if winner_slot == 0 {
+                    self.matches[reset].skipped = true;
+                } else {
+                    self.matches[reset].slots = slots;
+                }
+            }
+            return
+        }
+        let [winner_target, loser_target] = self.targets(idx);
+        if let Some((target, slot)) = winner_target {
+            self.matches[target].slots[slot] = slots[winner_slot];
+        }
+        if let Some((target, slot)) = loser_target {
+            self.matches[target].slots[slot] = slots[1 - winner_slot];
+        }
+    }
+
+    /// Decides every match that has a bye in one of its slots, repeatedly, since a bye can cause further byes later in the bracket.
+    fn advance_byes(&mut self) {
+        while let Some(idx) = self.matches.iter().position(|m| !m.is_decided() && !m.slots.contains(&Slot::Pending) && m.slots.contains(&Slot::Bye)) {
+            let winner_slot = if let Slot::Bye = self.matches[idx].slots[0] { 1 } else { 0 };
+            self.set_winner(idx, winner_slot);
+        }
+    }
+
+    /// Records the winner of a match and moves both entrants on.
+    pub(crate) fn decide(&mut self, idx: usize, winner_slot: usize) {
+        self.set_winner(idx, winner_slot);
+        self.advance_byes();
+    }
+
+    /// Matches whose entrants are both known but which haven't been decided yet.
+    pub(crate) fn playable(&self) -> Vec<usize> {
+        self.matches.iter().enumerate().filter(|(_, m)| !m.is_decided() && m.entrants().is_some()).map(|(idx, _)| idx).collect()
+    }
+
+    /// The winner of an elimination bracket. Swiss and round-robin brackets are ranked by [`swiss::standings`] instead.
+    pub(crate) fn champion(&self) -> Option<T> {
+        let final_match = match self.kind {
+            Kind::SingleElimination => self.find(Side::Winners, self.rounds(Side::Winners), 0)?,
+            Kind::DoubleElimination => match self.find(Side::GrandFinal, 2, 0) {
+                Some(reset) if !self.matches[reset].skipped => reset,
+                _ => self.find(Side::GrandFinal, 1, 0)?,
+            },
+            Kind::Swiss | Kind::RoundRobin => return None,
+        };
+        let m = &self.matches[final_match];
+        match m.slots[m.winner_slot?] {
+            Slot::Entrant(team) => Some(team),
+            Slot::Pending | Slot::Bye => None,
+        }
+    }
+
+    /// Entrants ranked by how far they got in an elimination bracket, best first. Entrants eliminated in the same round are ranked by seed.
+    pub(crate) fn placements(&self, seeds: &[T]) -> Vec<T> {
+        let champion = self.champion();
+        let mut placements = seeds.to_vec();
+        // losers bracket rounds are numbered so that each one is at least as late as the winners bracket round its entrants dropped down from
+        placements.sort_by_key(|&team| Reverse((
+            Some(team) == champion,
+            self.matches.iter().filter(|m| m.slots.contains(&Slot::Entrant(team))).map(|m| (m.side == Side::GrandFinal, m.round)).max(),
+        )));
+        placements
+    }
+
+    /// The display name of the round of the given match, used as the round of its races.
+    pub(crate) fn round_name(&self, idx: usize) -> String {
+        let m = &self.matches[idx];
+        match m.side {
+            Side::Winners => if let Kind::DoubleElimination = self.kind {
+                if m.round == self.rounds(Side::Winners) {
+                    format!("Winners Final")
+                } else {
+                    format!("Winners Round {}", m.round)
+                }
+            } else {
+                match self.rounds(Side::Winners) - m.round {
+                    0 => format!("Final"),
+                    1 => format!("Semifinals"),
+                    2 => format!("Quarterfinals"),
+                    _ => format!("Round {}", m.round),
+                }
+            },
+            Side::Losers => if m.round == self.rounds(Side::Losers) {
+                format!("Losers Final")
+            } else {
+                format!("Losers Round {}", m.round)
+            },
+            Side::GrandFinal => if m.round == 1 {
+                format!("Grand Final")
+            } else {
+                format!("Grand Final Reset")
+            },
+            Side::Pool => if self.pools() > 1 {
+                format!("Pool {} Round {}", swiss::pool_name(m.pool), m.round)
+            } else {
+                format!("Round {}", m.round)
+            },
+        }
+    }
+}
+
+fn slot_from_db(team: Option<Id<Teams>>, bye: bool) -> Slot<Id<Teams>> {
+    match (team, bye) {
+        (Some(team), _) => Slot::Entrant(team),
+        (None, true) => Slot::Bye,
+        (None, false) => Slot::Pending,
+    }
+}
+
+fn slot_to_db(slot: Slot<Id<Teams>>) -> (Option<Id<Teams>>, bool) {
+    match slot {
+        Slot::Pending => (None, false),
+        Slot::Entrant(team) => (Some(team), false),
+        Slot::Bye => (None, true),
+    }
+}
+
+pub(crate) struct StoredBracket {
+    pub(crate) id: Id<Brackets>,
+    /// The event stage this bracket belongs to, if the event has stages.
+    pub(crate) stage: Option<i16>,
+    pub(crate) phase: Option<String>,
+    /// Number of rounds of a Swiss bracket.
+    pub(crate) rounds: Option<i16>,
+    /// Best seed first.
+    pub(crate) seeds: Vec<Id<Teams>>,
+    pub(crate) bracket: Bracket<Id<Teams>>,
+    /// Database IDs of the matches that have been saved so far, in the same order as `bracket.matches`.
+    match_ids: Vec<i64>,
+}
+
+impl StoredBracket {
+    async fn load(transaction: &mut Transaction<'_, Postgres>, id: Id<Brackets>) -> sqlx::Result<Self> {
+        let row = sqlx::query!(r#"SELECT stage, kind AS "kind: Kind", grand_final_reset, phase, rounds FROM brackets WHERE id = $1"#, id as _).fetch_one(&mut **transaction).await?;
+        let seeds = sqlx::query_scalar!(r#"SELECT team AS "team: Id<Teams>" FROM bracket_seeds WHERE bracket = $1 ORDER BY seed"#, id as _).fetch_all(&mut **transaction).await?;
+        let match_rows = sqlx::query!(r#"SELECT
+            id,
+            side AS "side: Side",
+            pool,
+            round,
+            position,
+            best_of,
+            team1 AS "team1: Id<Teams>",
+            team1_bye,
+            team2 AS "team2: Id<Teams>",
+            team2_bye,
+            winner_slot,
+            skipped
+        FROM bracket_matches WHERE bracket = $1 ORDER BY side, pool, round, position"#, id as _).fetch_all(&mut **transaction).await?;
+        let mut match_ids = Vec::with_capacity(match_rows.len());
+        let mut matches = Vec::with_capacity(match_rows.len());
+        for match_row in match_rows {
+            match_ids.push(match_row.id);
+            matches.push(Match {
+                side: match_row.side,
+                pool: match_row.pool,
+                round: match_row.round,
+                position: match_row.position,
+                best_of: match_row.best_of,
+                slots: [slot_from_db(match_row.team1, match_row.team1_bye), slot_from_db(match_row.team2, match_row.team2_bye)],
+                winner_slot: match_row.winner_slot.map(|slot| slot as usize),
+                skipped: match_row.skipped,
+            });
+        }
+        Ok(Self {
+            stage: row.stage,
+            phase: row.phase,
+            rounds: row.rounds,
+            bracket: Bracket { kind: row.kind, grand_final_reset: row.grand_final_reset, matches },
+            id, seeds, match_ids,
+        })
+    }
+
+    /// Returns all brackets of the given event, ordered by stage.
+    pub(crate) async fn for_event(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> sqlx::Result<Vec<Self>> {
+        let ids = sqlx::query_scalar!(r#"SELECT id AS "id: Id<Brackets>" FROM brackets WHERE series = $1 AND event = $2 ORDER BY stage NULLS FIRST"#, series as _, event).fetch_all(&mut **transaction).await?;
+        let mut brackets = Vec::with_capacity(ids.len());
+        for id in ids {
+            brackets.push(Self::load(transaction, id).await?);
+        }
+        Ok(brackets)
+    }
+
+    pub(crate) async fn for_stage(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str, stage: i16) -> sqlx::Result<Option<Self>> {
+        let Some(id) = sqlx::query_scalar!(r#"SELECT id AS "id: Id<Brackets>" FROM brackets WHERE series = $1 AND event = $2 AND stage = $3"#, series as _, event, stage).fetch_optional(&mut **transaction).await? else { return Ok(None) };
+        Ok(Some(Self::load(transaction, id).await?))
+    }
+
+    /// Returns the bracket the given race belongs to, along with the index of its match.
+    async fn for_race(transaction: &mut Transaction<'_, Postgres>, race: Id<Races>) -> sqlx::Result<Option<(Self, usize)>> {
+        let Some(row) = sqlx::query!(r#"SELECT
+            bracket_matches.bracket AS "bracket: Id<Brackets>",
+            bracket_match AS match_id
+        FROM bracket_match_races
+        JOIN bracket_matches ON bracket_matches.id = bracket_match
+        WHERE race = $1"#, race as _).fetch_optional(&mut **transaction).await? else { return Ok(None) };
+        let stored = Self::load(transaction, row.bracket).await?;
+        let idx = stored.match_ids.iter().position(|&id| id == row.match_id).expect("bracket match not found in its bracket");
+        Ok(Some((stored, idx)))
+    }
+
+    pub(crate) async fn create(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, stage: Option<i16>, phase: Option<String>, settings: &Settings, seeds: Vec<Id<Teams>>) -> sqlx::Result<Self> {
+        let Settings { kind, grand_final_reset, rounds, pools, best_of } = *settings;
+        let id = Id::<Brackets>::new(&mut *transaction).await?;
+        sqlx::query!("INSERT INTO brackets (id, series, event, stage, kind, grand_final_reset, phase, rounds) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)", id as _, event.series as _, &event.event, stage, kind as _, grand_final_reset, phase, rounds).execute(&mut **transaction).await?;
+        for (idx, &team) in seeds.iter().enumerate() {
+            sqlx::query!("INSERT INTO bracket_seeds (bracket, seed, team) VALUES ($1, $2, $3)", id as _, idx as i16 + 1, team as _).execute(&mut **transaction).await?;
+        }
+        let bracket = match kind {
+            Kind::SingleElimination | Kind::DoubleElimination => Bracket::new(kind, grand_final_reset, &seeds, best_of),
+            Kind::Swiss => swiss::first_round(&seeds, best_of),
+            Kind::RoundRobin => swiss::round_robin(&seeds, pools, best_of),
+        };
+        sqlx::query!("UPDATE events SET native_bracket = TRUE WHERE series = $1 AND event = $2", event.series as _, &event.event).execute(&mut **transaction).await?;
+        let mut stored = Self { match_ids: Vec::with_capacity(bracket.matches.len()), id, stage, phase, rounds, seeds, bracket };
+        stored.save(transaction).await?;
+        Ok(stored)
+    }
+
+    /// Whether all matches have been decided and, for a Swiss bracket, all rounds have been paired.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.bracket.matches.iter().all(Match::is_decided) && self.rounds.is_none_or(|rounds| self.bracket.matches.iter().any(|m| m.round >= rounds))
+    }
+
+    pub(crate) fn pool_standings(&self, pool: i16) -> Vec<swiss::Standing<Id<Teams>>> {
+        let pool_matches = self.bracket.matches.iter().filter(|m| m.side == Side::Pool && m.pool == pool).cloned().collect_vec();
+        let entrants = self.seeds.iter().copied().filter(|&team| pool_matches.iter().any(|m| m.slots.contains(&Slot::Entrant(team)))).collect_vec();
+        swiss::standings(&entrants, &pool_matches)
+    }
+
+    /// The current ranking of each pool, best first. Elimination brackets have a single pool.
+    pub(crate) fn ranking(&self) -> Vec<Vec<Id<Teams>>> {
+        if self.bracket.kind.is_elimination() {
+            vec![self.bracket.placements(&self.seeds)]
+        } else {
+            (0..self.bracket.pools()).map(|pool| self.pool_standings(pool).into_iter().map(|standing| standing.team).collect()).collect()
+        }
+    }
+
+    /// Updates all saved matches and inserts any that were added since the bracket was loaded.
+    async fn save(&mut self, transaction: &mut Transaction<'_, Postgres>) -> sqlx::Result<()> {
+        for (idx, m) in self.bracket.matches.iter().enumerate() {
+            let ((team1, team1_bye), (team2, team2_bye)) = (slot_to_db(m.slots[0]), slot_to_db(m.slots[1]));
+            if let Some(&id) = self.match_ids.get(idx) {
+                sqlx::query!(
+                    "UPDATE bracket_matches SET best_of = $2, team1 = $3, team1_bye = $4, team2 = $5, team2_bye = $6, winner_slot = $7, skipped = $8 WHERE id = $1",
+                    id, m.best_of, team1 as _, team1_bye, team2 as _, team2_bye, m.winner_slot.map(|slot| slot as i16), m.skipped,
+                ).execute(&mut **transaction).await?;
+            } else {
+                self.match_ids.push(sqlx::query_scalar!("INSERT INTO bracket_matches
+                    (bracket, side, pool, round, position, best_of, team1, team1_bye, team2, team2_bye, winner_slot, skipped)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                    RETURNING id
+                ", self.id as _, m.side as _, m.pool, m.round, m.position, m.best_of, team1 as _, team1_bye, team2 as _, team2_bye, m.winner_slot.map(|slot| slot as i16), m.skipped).fetch_one(&mut **transaction).await?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates races for all matches which have become playable and don't have any races yet.
+    pub(crate) async fn create_races<'a>(&self, mut transaction: Transaction<'a, Postgres>, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, event: &Data<'_>) -> Result<Transaction<'a, Postgres>, event::Error> {
+        for idx in self.bracket.playable() {
+            let match_id = self.match_ids[idx];
+            if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM bracket_match_races WHERE bracket_match = $1) AS "exists!""#, match_id).fetch_one(&mut *transaction).await? { continue }
+            let m = &self.bracket.matches[idx];
+            let [team1, team2] = m.entrants().expect("playable match without entrants");
+            let (Some(team1), Some(team2)) = (Team::from_id(&mut transaction, team1).await?, Team::from_id(&mut transaction, team2).await?) else { continue };
+            let round = self.bracket.round_name(idx);
+            let draft = if let Some(draft_kind) = event.draft_kind() {
+                Some(Draft::for_game1(&mut transaction, http_client, draft_kind, event, self.phase.as_deref(), [&team1, &team2]).await?)
+            } else {
+                None
+            };
+            let round_deadline = sqlx::query_scalar!(
+                "SELECT scheduling_deadline FROM event_round_configs WHERE series = $1 AND event = $2 AND round = $3",
+                event.series as _, &event.event, round,
+            ).fetch_optional(&mut *transaction).await?.flatten();
+            let mut scheduling_thread = None;
+            for game in 1..=m.best_of {
+                let mut race = Race {
+                    id: Id::<Races>::new(&mut transaction).await?,
+                    series: event.series,
+                    event: event.event.to_string(),
+                    source: cal::Source::Manual,
+                    entrants: Entrants::Two([
+                        Entrant::MidosHouseTeam(team1.clone()),
+                        Entrant::MidosHouseTeam(team2.clone()),
+                    ]),
+                    phase: self.phase.clone(),
+                    round: Some(round.clone()),
+                    game: (m.best_of > 1).then_some(game),
+                    schedule: RaceSchedule::Unscheduled,
+                    schedule_updated_at: None,
+                    fpa_invoked: false,
+                    breaks_used: false,
+                    draft: draft.clone(),
+                    seed: seed::Data::default(),
+                    video_urls: HashMap::default(),
+                    restreamers: HashMap::default(),
+                    last_edited_by: None,
+                    last_edited_at: None,
+                    ignored: false,
+                    schedule_locked: false,
+                    notified: false,
+                    async_notified_1: false,
+                    async_notified_2: false,
+                    async_notified_3: false,
+                    discord_scheduled_event_id: None,
+                    volunteer_request_sent: false,
+                    volunteer_request_message_id: None,
+                    scheduling_deadline: round_deadline,
+                    restream_consent_required: false,
+                    custom_title: None,
+                    custom_create_room: true,
+                    companion_race_id: None,
+                    scheduling_thread,
+                };
+                if game == 1 {
+                    transaction = crate::discord_bot::create_scheduling_thread(discord_ctx, transaction, &mut race, m.best_of).await?;
+                    scheduling_thread = race.scheduling_thread;
+                }
+                race.save(&mut transaction).await?;
+                sqlx::query!("INSERT INTO bracket_match_races (race, bracket_match) VALUES ($1, $2)", race.id as _, match_id).execute(&mut *transaction).await?;
+            }
+        }
+        Ok(transaction)
+    }
+}
+
+pub(crate) struct GameRecorded {
+    /// Whether this game decided its match, so the bracket has advanced.
+    pub(crate) match_decided: bool,
+    /// Remaining games of the match which no longer need to be played.
+    pub(crate) ignored_races: Vec<Id<Races>>,
+}
+
+/// Counts a finished game of a bracket race towards its match. Once a team has won the majority of the match's games, the match is decided and races are created for any matches that became playable, including the next round of a Swiss bracket or the next stage of the event.
+///
+/// Returns `None` if the race isn't part of a bracket. Bracket matches are always between Hyrule Town Hall teams, so any other winner is an error.
+pub(crate) async fn record_game<'a>(mut transaction: Transaction<'a, Postgres>, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, event: &Data<'_>, race: &Race, winner: &Entrant) -> Result<(Transaction<'a, Postgres>, Option<GameRecorded>), event::Error> {
+    let Some((mut stored, idx)) = StoredBracket::for_race(&mut transaction, race.id).await? else { return Ok((transaction, None)) };
+    let Entrant::MidosHouseTeam(winner) = winner else { return Err(event::Error::BracketWinner(race.id)) };
+    let winner = winner.id;
+    sqlx::query!("UPDATE bracket_match_races SET winner = $1 WHERE race = $2", winner as _, race.id as _).execute(&mut *transaction).await?;
+    let m = &stored.bracket.matches[idx];
+    let undecided = GameRecorded { match_decided: false, ignored_races: Vec::default() };
+    if m.is_decided() { return Ok((transaction, Some(undecided))) }
+    let Some(winner_slot) = m.slots.iter().position(|&slot| slot == Slot::Entrant(winner)) else { return Ok((transaction, Some(undecided))) };
+    let wins = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM bracket_match_races WHERE bracket_match = $1 AND winner = $2"#, stored.match_ids[idx], winner as _).fetch_one(&mut *transaction).await?;
+    if wins < i64::from(m.best_of / 2 + 1) { return Ok((transaction, Some(undecided))) }
+    let ignored_races = race.ignore_remaining_games(&mut transaction).await?;
+    stored.bracket.decide(idx, winner_slot);
+    if let (Kind::Swiss, Some(rounds)) = (stored.bracket.kind, stored.rounds) {
+        if let Some(next_round) = swiss::next_round(&stored.bracket, &stored.seeds, rounds) {
+            stored.bracket.matches.extend(next_round);
+        }
+    }
+    stored.save(&mut transaction).await?;
+    let mut transaction = stored.create_races(transaction, discord_ctx, http_client, event).await?;
+    if let Some(stage) = stored.stage {
+        if stored.is_complete() {
+            transaction = stages::start_next(transaction, discord_ctx, http_client, event, stage).await?;
+        }
+    }
+    Ok((transaction, Some(GameRecorded { match_decided: true, ignored_races })))
+}
+
+async fn is_organizer(transaction: &mut Transaction<'_, Postgres>, me: Option<&User>, event: &Data<'_>) -> Result<bool, event::Error> {
+    Ok(if let Some(me) = me {
+        me.is_global_admin() || event.organizers(&mut *transaction).await?.contains(me)
+    } else {
+        false
+    })
+}
+
+fn match_view(m: &Match<Id<Teams>>, names: &HashMap<Id<Teams>, RawHtml<String>>) -> RawHtml<String> {
+    html! {
+        div(class = "bracket-match") {
+            @for (slot_idx, slot) in m.slots.iter().enumerate() {
+                div(class? = (m.winner_slot == Some(slot_idx)).then_some("winner")) {
+                    @match slot {
+                        Slot::Pending => i : "TBD";
+                        Slot::Bye => i : "bye";
+                        Slot::Entrant(team) => {
+                            @if let Some(name) = names.get(team) {
+                                : name;
+                            } else {
+                                i : "(deleted team)";
+                            }
+                        }
+                    }
+                }
+            }
+            @if m.skipped {
+                small : "not needed";
+            } else if m.best_of > 1 {
+                small : format!("best of {}", m.best_of);
+            }
+        }
+    }
+}
+
+async fn bracket_view(transaction: &mut Transaction<'_, Postgres>, stored: &StoredBracket, show_stage_name: bool, is_organizer: bool, csrf: Option<&CsrfToken>, event: &Data<'_>, ctx: &Context<'_>) -> Result<RawHtml<String>, event::Error> {
+    let mut names = HashMap::new();
+    for m in &stored.bracket.matches {
+        for slot in m.slots {
+            if let Slot::Entrant(team) = slot {
+                if !names.contains_key(&team) {
+                    if let Some(team_data) = Team::from_id(&mut *transaction, team).await? {
+                        names.insert(team, team_data.to_html(&mut *transaction, false).await?);
+                    }
+                }
+            }
+        }
+    }
+    let champion = stored.bracket.champion();
+    let pools = (0..stored.bracket.pools()).map(|pool| (pool, stored.pool_standings(pool))).collect_vec();
+    // only show errors on the form that was submitted
+    let mut errors = if ctx.field_value("bracket") == Some(&*stored.id.to_string()) { ctx.errors().collect_vec() } else { Vec::default() };
+    Ok(html! {
+        @if show_stage_name {
+            h2 : stored.phase.as_deref().unwrap_or("Bracket");
+        }
+        @if let Some(rounds) = stored.rounds {
+            p : format!("Swiss, {rounds} rounds. Each round is paired automatically once all matches of the previous round are decided.");
+        }
+        @if !stored.bracket.kind.is_elimination() {
+            @for (pool, standings) in pools {
+                @if stored.bracket.pools() > 1 {
+                    h3 : format!("Pool {}", swiss::pool_name(pool));
+                }
+                table {
+                    thead {
+                        tr {
+                            th : "Rank";
+                            th : "Entrant";
+                            th : "Wins";
+                            th : "Losses";
+                            th : "Buchholz";
+                            th : "Opponents' win %";
+                        }
+                    }
+                    tbody {
+                        @for (rank, standing) in standings.into_iter().enumerate() {
+                            tr {
+                                td : rank + 1;
+                                td {
+                                    @if let Some(name) = names.get(&standing.team) {
+                                        : name;
+                                    }
+                                }
+                                td : standing.wins;
+                                td : standing.losses;
+                                td : standing.buchholz;
+                                td : format!("{:.1}%", standing.opponent_win_percentage * 100.0);
+                            }
+                        }
+                    }
+                }
+                @let rounds = stored.bracket.round_matches(Side::Pool, pool);
+                div(class = "bracket") {
+                    @for round in rounds {
+                        div(class = "bracket-round") {
+                            h3 : format!("Round {}", stored.bracket.matches[round[0]].round);
+                            @for idx in round {
+                                : match_view(&stored.bracket.matches[idx], &names);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        @if let Some(champion) = champion {
+            p {
+                : "Champion: ";
+                @if let Some(name) = names.get(&champion) {
+                    : name;
+                }
+            }
+        }
+        @for side in [Side::Winners, Side::Losers, Side::GrandFinal] {
+            @let rounds = stored.bracket.round_matches(side, 0);
+            @if !rounds.is_empty() {
+                @if let Kind::DoubleElimination = stored.bracket.kind {
+                    h3 {
+                        @match side {
+                            Side::Winners => : "Winners Bracket";
+                            Side::Losers => : "Losers Bracket";
+                            Side::GrandFinal => : "Grand Final";
+                            Side::Pool => {}
+                        }
+                    }
+                }
+                div(class = "bracket") {
+                    @for round in rounds {
+                        div(class = "bracket-round") {
+                            h3 : stored.bracket.round_name(round[0]);
+                            @for idx in round {
+                                : match_view(&stored.bracket.matches[idx], &names);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        @if is_organizer && !event.is_ended() && !stored.is_complete() {
+            h3 : "Match Length";
+            p {
+                : "Changes only apply to matches whose races haven't been created yet.";
+                @if let Kind::Swiss = stored.bracket.kind {
+                    : " Each Swiss round is paired with the match length of the previous round.";
+                }
+            }
+            : full_form(uri!(best_of(event.series, &*event.event)), csrf, html! {
+                input(type = "hidden", name = "bracket", value = stored.id.to_string());
+                : form_field("round", &mut errors, html! {
+                    label(for = "round") : "Round:";
+                    select(name = "round") {
+                        @for side in [Side::Winners, Side::Losers, Side::GrandFinal, Side::Pool] {
+                            @for round in stored.bracket.round_matches(side, 0) {
+                                @let m = &stored.bracket.matches[round[0]];
+                                @let value = format!("{}-{}", side.as_str(), m.round);
+                                option(value = &value, selected? = ctx.field_value("round") == Some(&*value)) {
+                                    @if let Side::Pool = side {
+                                        : format!("Round {}", m.round);
+                                    } else {
+                                        : stored.bracket.round_name(round[0]);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                });
+                : form_field("best_of", &mut errors, html! {
+                    label(for = "best_of") : "Best of:";
+                    input(type = "number", min = "1", step = "2", name = "best_of", value = ctx.field_value("best_of").unwrap_or("1"));
+                });
+            }, errors, "Save");
+        }
+    })
+}
+
+async fn bracket_page(mut transaction: Transaction<'_, Postgres>, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, ctx: Context<'_>) -> Result<RawHtml<String>, event::Error> {
+    let header = event.header(&mut transaction, me.as_ref(), Tab::Bracket, false).await?;
+    let is_organizer = is_organizer(&mut transaction, me.as_ref(), &event).await?;
+    let brackets = StoredBracket::for_event(&mut transaction, event.series, &event.event).await?;
+    let has_stages = !Stage::for_event(&mut transaction, event.series, &event.event).await?.is_empty();
+    let content = if has_stages {
+        let mut views = Vec::with_capacity(brackets.len());
+        for stored in &brackets {
+            views.push(bracket_view(&mut transaction, stored, true, is_organizer, csrf, &event, &ctx).await?);
+        }
+        html! {
+            p {
+                : "This event has multiple stages. See ";
+                a(href = uri!(stages::get(event.series, &*event.event))) : "the stages overview";
+                : " for how entrants advance between them.";
+            }
+            @if views.is_empty() {
+                p : "No bracket stage has started yet.";
+            }
+            @for view in views {
+                : view;
+            }
+        }
+    } else if let Some(stored) = brackets.first() {
+        bracket_view(&mut transaction, stored, false, is_organizer, csrf, &event, &ctx).await?
+    } else if is_organizer && matches!(event.match_source(), MatchSource::Manual | MatchSource::Bracket) {
+        let mut teams = Team::for_event(&mut transaction, event.series, &event.event).await?;
+        let mut team_names = HashMap::new();
+        for team in &teams {
+            team_names.insert(team.id, team.name(&mut transaction).await?.map(|name| name.into_owned()).unwrap_or_default());
+        }
+        teams.sort_by(|team1, team2| team1.qualifier_rank.is_none().cmp(&team2.qualifier_rank.is_none())
+            .then_with(|| team1.qualifier_rank.cmp(&team2.qualifier_rank))
+            .then_with(|| team_names[&team1.id].to_lowercase().cmp(&team_names[&team2.id].to_lowercase()))
+        );
+        let entrant_label = if let TeamConfig::Solo = event.team_config { "Entrant" } else { "Team" };
+        let mut errors = ctx.errors().collect_vec();
+        html! {
+            p : "This event does not have a bracket yet. Once it is created, races are created automatically whenever both entrants of a match are known, and results reported by the race room bot advance the bracket. Swiss rounds are paired by record, avoiding rematches where possible.";
+            p {
+                : "For events with multiple stages, such as groups followed by a top cut, ";
+                a(href = uri!(stages::get(event.series, &*event.event))) : "set up stages";
+                : " instead.";
+            }
+            : full_form(uri!(create(event.series, &*event.event)), csrf, html! {
+                : form_field("kind", &mut errors, html! {
+                    label(for = "kind") : "Format:";
+                    select(name = "kind") {
+                        option(value = "single_elimination", selected? = ctx.field_value("kind") == Some("single_elimination")) : "Single elimination";
+                        option(value = "double_elimination", selected? = ctx.field_value("kind") == Some("double_elimination")) : "Double elimination";
+                        option(value = "swiss", selected? = ctx.field_value("kind") == Some("swiss")) : "Swiss";
+                        option(value = "round_robin", selected? = ctx.field_value("kind") == Some("round_robin")) : "Round robin";
+                    }
+                });
+                : form_field("rounds", &mut errors, html! {
+                    label(for = "rounds") : "Rounds:";
+                    input(type = "number", min = "1", name = "rounds", value? = ctx.field_value("rounds"));
+                    label(class = "help") : " (Swiss only)";
+                });
+                : form_field("pools", &mut errors, html! {
+                    label(for = "pools") : "Pools:";
+                    input(type = "number", min = "1", name = "pools", value = ctx.field_value("pools").unwrap_or("1"));
+                    label(class = "help") : " (round robin only, entrants are split into pools by seed)";
+                });
+                : form_field("grand_final_reset", &mut errors, html! {
+                    input(type = "checkbox", id = "grand_final_reset", name = "grand_final_reset", checked? = ctx.field_value("grand_final_reset").map_or(true, |value| value == "on"));
+                    label(for = "grand_final_reset") : "Grand final reset (double elimination only)";
+                });
+                : form_field("best_of", &mut errors, html! {
+                    label(for = "best_of") : "Best of:";
+                    input(type = "number", min = "1", step = "2", name = "best_of", value = ctx.field_value("best_of").map_or_else(|| event.default_game_count.to_string(), |best_of| best_of.to_owned()));
+                    label(class = "help") : " (can be changed per round later)";
+                });
+                : form_field("phase", &mut errors, html! {
+                    label(for = "phase") : "Phase:";
+                    input(type = "text", name = "phase", value = ctx.field_value("phase").unwrap_or("Bracket"));
+                });
+                h3 : "Seeding";
+                p : "Lower numbers are better seeds. Leave the field empty to leave an entrant out of the bracket. Entrants are prefilled by qualifier rank.";
+                table {
+                    thead {
+                        tr {
+                            th : "Seed";
+                            th : entrant_label;
+                        }
+                    }
+                    tbody {
+                        @for (idx, team) in teams.iter().enumerate() {
+                            @let field_name = format!("seed[{}]", team.id);
+                            tr {
+                                : form_table_cell(&field_name, &mut errors, html! {
+                                    input(type = "number", min = "1", name = &field_name, value = ctx.field_value(&*field_name).map_or_else(|| (idx + 1).to_string(), |seed| seed.to_owned()));
+                                });
+                                td : team.to_html(&mut transaction, false).await?;
+                            }
+                        }
+                    }
+                }
+            }, errors, "Create Bracket");
+        }
+    } else {
+        html! {
+            p : "This event does not have a bracket yet.";
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Bracket — {}", event.display_name), html! {
+        : header;
+        : content;
+    }).await?)
+}
+
+#[rocket::get("/event/<series>/<event>/bracket")]
+pub(crate) async fn get(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok(bracket_page(transaction, me, uri, csrf.as_ref(), event, Context::default()).await?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct CreateForm {
+    #[field(default = String::new())]
+    csrf: String,
+    kind: Kind,
+    #[field(default = false)]
+    grand_final_reset: bool,
+    rounds: Option<i16>,
+    #[field(default = 1)]
+    pools: i16,
+    best_of: i16,
+    #[field(default = String::new())]
+    phase: String,
+    #[field(default = HashMap::new())]
+    seed: HashMap<String, String>,
+}
+
+#[rocket::post("/event/<series>/<event>/bracket", data = "<form>")]
+pub(crate) async fn create(pool: &State<PgPool>, discord_ctx: &State<RwFuture<DiscordCtx>>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, CreateForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    if !is_organizer(&mut transaction, Some(&me), &event).await? {
+        form.context.push_error(form::Error::validation("You must be an organizer to create a bracket."));
+    }
+    if !matches!(event.match_source(), MatchSource::Manual | MatchSource::Bracket) {
+        form.context.push_error(form::Error::validation("This event's matches are managed on an external site."));
+    }
+    if !StoredBracket::for_event(&mut transaction, event.series, &event.event).await?.is_empty() {
+        form.context.push_error(form::Error::validation("This event already has a bracket."));
+    }
+    if !Stage::for_event(&mut transaction, event.series, &event.event).await?.is_empty() {
+        form.context.push_error(form::Error::validation("This event has stages, so its brackets are created when each stage starts."));
+    }
+    Ok(if let Some(ref value) = form.value {
+        let mut seeds = Vec::default();
+        for (team_id, seed) in &value.seed {
+            let seed = seed.trim();
+            if seed.is_empty() { continue }
+            let field_name = format!("seed[{team_id}]");
+            let Ok(seed) = seed.parse::<u16>() else {
+                form.context.push_error(form::Error::validation("Seeds must be positive numbers.").with_name(field_name));
+                continue
+            };
+            let team = if let Ok(team_id) = team_id.parse::<u64>() { Team::from_id(&mut transaction, Id::from(team_id)).await? } else { None };
+            let Some(team) = team.filter(|team| team.series == event.series && team.event == event.event) else {
+                form.context.push_error(form::Error::validation("There is no team with this ID in this event.").with_name(field_name));
+                continue
+            };
+            if seeds.iter().any(|&(existing_seed, _)| existing_seed == seed) {
+                form.context.push_error(form::Error::validation("This seed is used more than once.").with_name(field_name));
+            }
+            seeds.push((seed, team.id));
+        }
+        let settings = Settings {
+            kind: value.kind,
+            grand_final_reset: value.grand_final_reset,
+            rounds: value.rounds.filter(|_| value.kind == Kind::Swiss),
+            pools: if let Kind::RoundRobin = value.kind { usize::try_from(value.pools).unwrap_or_default() } else { 1 },
+            best_of: value.best_of,
+        };
+        if let Err(e) = settings.validate(seeds.len()) {
+            form.context.push_error(e.to_form_error());
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(bracket_page(transaction, Some(me), uri, csrf.as_ref(), event, form.context).await?)
+        } else {
+            seeds.sort_unstable();
+            let seeds = seeds.into_iter().map(|(_, team)| team).collect_vec();
+            let phase = Some(value.phase.trim()).filter(|phase| !phase.is_empty()).map(|phase| phase.to_owned());
+            let stored = StoredBracket::create(&mut transaction, &event, None, phase, &settings, seeds).await?;
+            let transaction = stored.create_races(transaction, &*discord_ctx.read().await, http_client, &event).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        }
+    } else {
+        RedirectOrContent::Content(bracket_page(transaction, Some(me), uri, csrf.as_ref(), event, form.context).await?)
+    })
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct BestOfForm {
+    #[field(default = String::new())]
+    csrf: String,
+    bracket: Id<Brackets>,
+    round: String,
+    best_of: i16,
+}
+
+#[rocket::post("/event/<series>/<event>/bracket/best-of", data = "<form>")]
+pub(crate) async fn best_of(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, BestOfForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    if !is_organizer(&mut transaction, Some(&me), &event).await? {
+        form.context.push_error(form::Error::validation("You must be an organizer to edit the bracket."));
+    }
+    Ok(if let Some(ref value) = form.value {
+        let Some(stored) = StoredBracket::for_event(&mut transaction, event.series, &event.event).await?.into_iter().find(|stored| stored.id == value.bracket) else { return Err(StatusOrError::Status(Status::NotFound)) };
+        if value.best_of < 1 || value.best_of % 2 == 0 {
+            form.context.push_error(form::Error::validation("Matches must be best of an odd number of games.").with_name("best_of"));
+        }
+        let round = value.round.rsplit_once('-').and_then(|(side, round)| Some((side.parse::<Side>().ok()?, round.parse::<i16>().ok()?)));
+        if round.is_none() {
+            form.context.push_error(form::Error::validation("No such round.").with_name("round"));
+        }
+        if let (Some((side, round)), None) = (round, form.context.errors().next()) {
+            sqlx::query!("UPDATE bracket_matches SET best_of = $1 WHERE bracket = $2 AND side = $3 AND round = $4 AND NOT EXISTS (SELECT 1 FROM bracket_match_races WHERE bracket_match = bracket_matches.id)", value.best_of, stored.id as _, side as _, round).execute(&mut *transaction).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        } else {
+            RedirectOrContent::Content(bracket_page(transaction, Some(me), uri, csrf.as_ref(), event, form.context).await?)
+        }
+    } else {
+        RedirectOrContent::Content(bracket_page(transaction, Some(me), uri, csrf.as_ref(), event, form.context).await?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_seeds_meet_last() {
+        assert_eq!(seed_order(2), [1, 2]);
+        assert_eq!(seed_order(4), [1, 4, 2, 3]);
+        assert_eq!(seed_order(8), [1, 8, 4, 5, 2, 7, 3, 6]);
+    }
+
+    #[test]
+    fn byes_advance_top_seeds() {
+        let bracket = Bracket::new(Kind::SingleElimination, false, &[1, 2, 3], 1);
+        assert_eq!(bracket.matches.len(), 3);
+        assert_eq!(bracket.matches[0].winner_slot, Some(0));
+        assert_eq!(bracket.playable(), [1]);
+        assert_eq!(bracket.matches[2].slots, [Slot::Entrant(1), Slot::Pending]);
+    }
+
+    #[test]
+    fn double_elimination_with_reset() {
+        let mut bracket = Bracket::new(Kind::DoubleElimination, true, &[1, 2, 3, 4], 1);
+        // 3 winners bracket, 2 losers bracket, and 2 grand final matches
+        assert_eq!(bracket.matches.len(), 7);
+        let [w1, w2] = [bracket.find(Side::Winners, 1, 0).unwrap(), bracket.find(Side::Winners, 1, 1).unwrap()];
+        assert_eq!(bracket.matches[w1].entrants(), Some([1, 4]));
+        bracket.decide(w1, 0);
+        bracket.decide(w2, 0);
+        let l1 = bracket.find(Side::Losers, 1, 0).unwrap();
+        assert_eq!(bracket.matches[l1].entrants(), Some([4, 3]));
+        bracket.decide(bracket.find(Side::Winners, 2, 0).unwrap(), 0);
+        bracket.decide(l1, 1);
+        let l2 = bracket.find(Side::Losers, 2, 0).unwrap();
+        assert_eq!(bracket.matches[l2].entrants(), Some([3, 2]));
+        bracket.decide(l2, 0);
+        let gf = bracket.find(Side::GrandFinal, 1, 0).unwrap();
+        assert_eq!(bracket.matches[gf].entrants(), Some([1, 3]));
+        bracket.decide(gf, 1);
+        assert_eq!(bracket.champion(), None);
+        let reset = bracket.find(Side::GrandFinal, 2, 0).unwrap();
+        assert_eq!(bracket.playable(), [reset]);
+        bracket.decide(reset, 1);
+        assert_eq!(bracket.champion(), Some(3));
+    }
+
+    #[test]
+    fn reset_skipped_if_winners_champion_wins() {
+        let mut bracket = Bracket::new(Kind::DoubleElimination, true, &[1, 2], 1);
+        bracket.decide(bracket.find(Side::Winners, 1, 0).unwrap(), 0);
+        let gf = bracket.find(Side::GrandFinal, 1, 0).unwrap();
+        assert_eq!(bracket.matches[gf].entrants(), Some([1, 2]));
+        bracket.decide(gf, 0);
+        assert!(bracket.playable().is_empty());
+        assert_eq!(bracket.champion(), Some(1));
+    }
+
+    #[test]
+    fn placements_follow_elimination_round() {
+        let mut bracket = Bracket::new(Kind::SingleElimination, false, &[1, 2, 3, 4], 1);
+        // 4 upsets 1, then beats 2 in the final
+        bracket.decide(bracket.find(Side::Winners, 1, 0).unwrap(), 1);
+        bracket.decide(bracket.find(Side::Winners, 1, 1).unwrap(), 0);
+        bracket.decide(bracket.find(Side::Winners, 2, 0).unwrap(), 0);
+        assert_eq!(bracket.champion(), Some(4));
+        assert_eq!(bracket.placements(&[1, 2, 3, 4]), [4, 2, 1, 3]);
+    }
+
+    #[test]
+    fn byes_cascade_into_losers_bracket() {
+        let bracket = Bracket::new(Kind::DoubleElimination, false, &[1, 2, 3, 4, 5], 1);
+        let playable = bracket.playable().into_iter().map(|idx| bracket.matches[idx].entrants().unwrap()).collect_vec();
+        assert_eq!(playable, [[4, 5], [2, 3]]);
+        // both first round losers of the bottom half are byes
+        assert!(bracket.matches[bracket.find(Side::Losers, 1, 1).unwrap()].is_decided());
+        assert_eq!(bracket.matches[bracket.find(Side::Losers, 2, 1).unwrap()].slots, [Slot::Bye, Slot::Pending]);
+    }
+}
@@ -82,7 +82,7 @@ async fn configure_form(mut transaction: Transaction<'_, Postgres>, http_client:
                     .filter(|s| match (&qualifier_kind, &s.qualification) {
                         (QualifierKind::None, _) => true,
                         (_, super::teams::Qualification::Single { qualified } | super::teams::Qualification::TriforceBlitz { qualified, .. }) => *qualified,
-                        (QualifierKind::Score(score_kind), super::teams::Qualification::Multiple { num_finished, .. }) => *num_finished >= score_kind.required_qualifiers(),
+                        (QualifierKind::Score(scoring), super::teams::Qualification::Multiple { num_finished, .. }) => *num_finished >= scoring.required_qualifiers(),
                         (_, super::teams::Qualification::Multiple { num_finished, .. }) => *num_finished >= 1,
                     })
                     .flat_map(|s| s.members.into_iter().filter_map(|m| match m.user {
@@ -216,6 +216,18 @@ async fn configure_form(mut transaction: Transaction<'_, Postgres>, http_client:
                             label(for = "fpa_enabled") : "FPA Enabled";
                             label(class = "help") : "(Announce fair play agreement when official race rooms open)";
                         });
+                        : form_field("max_entrants", &mut errors, html! {
+                            label(for = "max_entrants") : "Maximum number of entrants:";
+                            input(type = "number", id = "max_entrants", name = "max_entrants", min = "1", value = ctx.field_value("max_entrants").unwrap_or(
+                                &event.max_entrants.map(|max_entrants| max_entrants.to_string()).unwrap_or_default()
+                            ), style = "width: 100%; max-width: 200px;");
+                            label(class = "help") : " (Once this many entrants have signed up, further signups are added to a waitlist. Leave empty for no limit.)";
+                        });
+                        : form_field("waitlist_confirm_hours", &mut errors, html! {
+                            label(for = "waitlist_confirm_hours") : "Waitlist confirmation window (hours):";
+                            input(type = "number", id = "waitlist_confirm_hours", name = "waitlist_confirm_hours", min = "1", value = ctx.field_value("waitlist_confirm_hours").map(Cow::Borrowed).unwrap_or_else(|| Cow::Owned(event.waitlist_confirm_hours.to_string())), style = "width: 100%; max-width: 200px;");
+                            label(class = "help") : " (Entrants promoted from the waitlist must confirm their spot within this time, otherwise it is passed on to the next entrant.)";
+                        });
                         @if event.discord_guild.is_some() {
                             : form_field("asyncs_active", &mut errors, html! {
                                 input(type = "checkbox", id = "asyncs_active", name = "asyncs_active", checked? = ctx.field_value("asyncs_active").map_or(event.asyncs_active, |value| value == "on"));
@@ -319,10 +331,14 @@ pub(crate) struct ConfigureForm {
     discord_events_require_restream: bool,
     fpa_enabled: bool,
     settings_string: Option<String>,
+    #[field(default = None)]
+    max_entrants: Option<i32>,
+    #[field(default = 48)]
+    waitlist_confirm_hours: i32,
 }
 
 #[rocket::post("/event/<series>/<event>/configure", data = "<form>")]
-pub(crate) async fn post(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, ConfigureForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+pub(crate) async fn post(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, ConfigureForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
     let mut transaction = pool.begin().await?;
     let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
     let mut form = form.into_inner();
@@ -340,6 +356,12 @@ pub(crate) async fn post(pool: &State<PgPool>, http_client: &State<reqwest::Clie
             form.context.push_error(form::Error::validation("Duration must be formatted like '1:23:45' or '1h 23m 45s'.").with_name("min_schedule_notice"));
             None
         };
+        if value.max_entrants.is_some_and(|max_entrants| max_entrants <= 0) {
+            form.context.push_error(form::Error::validation("The maximum number of entrants must be positive.").with_name("max_entrants"));
+        }
+        if value.waitlist_confirm_hours <= 0 {
+            form.context.push_error(form::Error::validation("The confirmation window must be at least 1 hour.").with_name("waitlist_confirm_hours"));
+        }
         let retime_window = if let Some(retime_window) = &value.retime_window {
             if let Some(time) = parse_duration(retime_window, None) {
                 Some(time)
@@ -416,6 +438,17 @@ pub(crate) async fn post(pool: &State<PgPool>, http_client: &State<reqwest::Clie
             if value.fpa_enabled != data.fpa_enabled {
                 sqlx::query!("UPDATE events SET fpa_enabled = $1 WHERE series = $2 AND event = $3", value.fpa_enabled, data.series as _, &data.event).execute(&mut *transaction).await?;
             }
+            if value.waitlist_confirm_hours != data.waitlist_confirm_hours {
+                sqlx::query!("UPDATE events SET waitlist_confirm_hours = $1 WHERE series = $2 AND event = $3", value.waitlist_confirm_hours, data.series as _, &data.event).execute(&mut *transaction).await?;
+            }
+            let waitlist_messages = if value.max_entrants != data.max_entrants {
+                sqlx::query!("UPDATE events SET max_entrants = $1 WHERE series = $2 AND event = $3", value.max_entrants, data.series as _, &data.event).execute(&mut *transaction).await?;
+                // raising or removing the limit may free up spots for waitlisted entrants
+                let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+                super::waitlist::promote(&mut transaction, &data).await?
+            } else {
+                super::waitlist::Messages::default()
+            };
             if matches!(data.rando_version, Some(VersionedBranch::Tww { .. })) {
                 let new_settings_string = value.settings_string.as_deref().filter(|s| !s.trim().is_empty()).map(|s| s.trim().to_owned());
                 if new_settings_string != data.settings_string {
@@ -423,6 +456,7 @@ pub(crate) async fn post(pool: &State<PgPool>, http_client: &State<reqwest::Clie
                 }
             }
             transaction.commit().await?;
+            waitlist_messages.send(&*discord_ctx.read().await).await;
             RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event))))
         }
     } else {
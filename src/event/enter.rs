@@ -1,7 +1,16 @@
 use {
-    lazy_regex::Regex,
+    lazy_regex::{
+        Regex,
+        regex_replace_all,
+    },
     racetime::model::EntrantStatusValue,
     serde_with::DeserializeAs,
+    serenity::all::{
+        CreateActionRow,
+        CreateInputText,
+        CreateModal,
+        InputTextStyle,
+    },
     crate::{
         discord_bot::ADMIN_USER,
         event::{
@@ -140,10 +149,31 @@ pub(crate) enum Requirement {
         #[serde(default)]
         locked: bool,
     },
+    /// Must pick one or more options from a custom dropdown, stored in `custom_choices`
+    ///
+    /// If `multiple` is set, the values of the selected options are stored separated by commas, so option values must not contain commas.
+    #[serde(rename_all = "camelCase")]
+    Dropdown {
+        key: String,
+        #[serde_as(as = "DeserializeRawHtml")]
+        label: RawHtml<String>,
+        #[serde_as(as = "Option<DeserializeRawHtml>")]
+        #[serde(default)]
+        prompt: Option<RawHtml<String>>,
+        options: Vec<DropdownOption>,
+        #[serde(default)]
+        multiple: bool,
+    },
     /// Must agree to the event rules
     Rules {
         document: Option<Url>,
     },
+    /// Must acknowledge each item of a list of rules individually
+    RulesChecklist {
+        #[serde_as(as = "Vec<DeserializeRawHtml>")]
+        items: Vec<RawHtml<String>>,
+        document: Option<Url>,
+    },
     /// Must submit a response to a custom poll
     Poll {
         document: Option<Url>,
@@ -187,6 +217,20 @@ pub(crate) enum Requirement {
     },
     /// Must finish at least 3 races on the RSL leaderboard for this season
     RslLeaderboard,
+    /// Must have finished a minimum number of races in a racetime.gg category
+    #[serde(rename_all = "camelCase")]
+    RaceTimeFinishes {
+        min_races: usize,
+        /// Defaults to the racetime.gg category of the event's game.
+        category: Option<String>,
+    },
+    /// Must have connected accounts which are at least this many days old
+    #[serde(rename_all = "camelCase")]
+    AccountAge {
+        discord_days: Option<i64>,
+        /// Measured from the user's first racetime.gg race since racetime.gg doesn't expose account creation dates.
+        racetime_days: Option<i64>,
+    },
     /// A signup requirement that cannot be checked automatically
     #[serde(rename_all = "camelCase")]
     External {
@@ -199,11 +243,105 @@ pub(crate) enum Requirement {
     },
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct DropdownOption {
+    pub(crate) value: String,
+    pub(crate) label: String,
+}
+
+/// The labels of the dropdown options selected in the given `custom_choices` value, for display.
+pub(crate) fn dropdown_labels<'a>(options: &'a [DropdownOption], value: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    value.split(',').filter(|value| !value.is_empty()).map(|value| options.iter().find(|option| option.value == value).map_or(value, |option| &*option.label))
+}
+
+/// Parses an enter flow from the JSON entered on the setup page.
+///
+/// Unlike deserializing the flow directly, this reports every invalid requirement along with its position, and also catches mistakes which are valid JSON
+/// but would break the signup form, such as two choices saving to the same `custom_choices` key.
+pub(super) fn validate_flow(json: &str) -> Result<Flow, Vec<String>> {
+    let value = serde_json::from_str::<serde_json::Value>(json).map_err(|e| vec![format!("Invalid JSON: {e}")])?;
+    let Some(requirements) = value.get("requirements").and_then(|requirements| requirements.as_array()) else {
+        return Err(vec![format!("The enter flow must be an object with a “requirements” list.")])
+    };
+    let mut problems = Vec::default();
+    for (idx, requirement) in requirements.iter().enumerate() {
+        if let Err(e) = Requirement::deserialize(requirement) {
+            match requirement.get("type").and_then(|kind| kind.as_str()) {
+                Some(kind) => problems.push(format!("Requirement {} ({kind}): {e}", idx + 1)),
+                None => problems.push(format!("Requirement {}: {e}", idx + 1)),
+            }
+        }
+    }
+    if !problems.is_empty() {
+        return Err(problems)
+    }
+    let flow = Flow::deserialize(&value).map_err(|e| vec![e.to_string()])?;
+    let mut keys = HashSet::new();
+    for (idx, requirement) in flow.requirements.iter().enumerate() {
+        let pos = idx + 1;
+        match requirement {
+            Requirement::BooleanChoice { key, .. } | Requirement::RadioChoice { key, .. } | Requirement::Dropdown { key, .. } => {
+                if key.is_empty() {
+                    problems.push(format!("Requirement {pos}: the key must not be empty."));
+                } else if !keys.insert(key) {
+                    problems.push(format!("Requirement {pos}: the key “{key}” is already used by an earlier requirement."));
+                }
+                if let Requirement::Dropdown { options, multiple, .. } = requirement {
+                    if options.is_empty() {
+                        problems.push(format!("Requirement {pos}: a dropdown needs at least one option."));
+                    }
+                    let mut values = HashSet::new();
+                    for option in options {
+                        if option.value.is_empty() {
+                            problems.push(format!("Requirement {pos}: option values must not be empty."));
+                        } else if !values.insert(&option.value) {
+                            problems.push(format!("Requirement {pos}: the option value “{}” is used more than once.", option.value));
+                        } else if *multiple && option.value.contains(',') {
+                            problems.push(format!("Requirement {pos}: the option value “{}” must not contain a comma since multiple options can be selected.", option.value));
+                        }
+                    }
+                }
+            }
+            Requirement::RulesChecklist { items, .. } => if items.is_empty() {
+                problems.push(format!("Requirement {pos}: a rules checklist needs at least one item."));
+            },
+            Requirement::Qualifier { async_start, async_end, .. } => if async_end <= async_start {
+                problems.push(format!("Requirement {pos}: the async qualifier must end after it starts."));
+            },
+            Requirement::AccountAge { discord_days: None, racetime_days: None } => problems.push(format!("Requirement {pos}: set a minimum age for at least one account.")),
+            _ => {}
+        }
+    }
+    if problems.is_empty() { Ok(flow) } else { Err(problems) }
+}
+
+/// The racetime.gg category of the event's game, or the default category if the game doesn't have one.
+async fn racetime_category(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>) -> sqlx::Result<String> {
+    Ok(sqlx::query_scalar!("SELECT category_slug FROM game_racetime_connection JOIN game_series USING (game_id) WHERE game_series.series = $1 ORDER BY game_racetime_connection.id LIMIT 1", data.series as _).fetch_optional(&mut **transaction).await?.unwrap_or_else(|| racetime_bot::CATEGORY.to_owned()))
+}
+
 struct RequirementStatus {
     blocks_submit: bool,
     html_content: Box<dyn FnOnce(&mut Vec<&form::Error<'_>>) -> RawHtml<String> + Send>,
 }
 
+/// A requirement that is not met, as reported by [`Requirement::check_value`].
+struct RequirementError {
+    /// The form field the error applies to, or `None` if it applies to the form as a whole.
+    field: Option<String>,
+    message: Cow<'static, str>,
+}
+
+impl RequirementError {
+    fn form(message: impl Into<Cow<'static, str>>) -> Self {
+        Self { field: None, message: message.into() }
+    }
+
+    fn field(field: impl Into<String>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self { field: Some(field.into()), message: message.into() }
+    }
+}
+
 impl Requirement {
     async fn is_checked(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, me: &User, data: &Data<'_>, config: &Config) -> Result<Option<bool>, Error> {
         let mut cache = teams::Cache::new(http_client.clone());
@@ -247,7 +385,9 @@ impl Requirement {
             Self::YesNo { .. } => Some(false),
             Self::BooleanChoice { .. } => Some(false),
             Self::RadioChoice { .. } => Some(false),
+            Self::Dropdown { .. } => Some(false),
             Self::Rules { .. } => Some(false),
+            Self::RulesChecklist { .. } => Some(false),
             Self::Poll { .. } => Some(false),
             Self::RestreamConsent { .. } => Some(false),
             Self::Qualifier { .. } => Some(false),
@@ -298,6 +438,25 @@ impl Requirement {
             } else {
                 false
             }),
+            Self::RaceTimeFinishes { min_races, category } => Some(if let Some(racetime) = &me.racetime {
+                let category = if let Some(category) = category { category.clone() } else { racetime_category(transaction, data).await? };
+                racetime_bot::count_finished_races(http_client, &racetime.id, &category, *min_races).await? >= *min_races
+            } else {
+                false
+            }),
+            Self::AccountAge { discord_days, racetime_days } => Some('checked: {
+                let now = Utc::now();
+                if let Some(discord_days) = discord_days {
+                    let Some(discord) = &me.discord else { break 'checked false };
+                    if discord.id.created_at().unix_timestamp() > (now - TimeDelta::days(*discord_days)).timestamp() { break 'checked false }
+                }
+                if let Some(racetime_days) = racetime_days {
+                    let Some(racetime) = &me.racetime else { break 'checked false };
+                    let Some(first_race) = racetime_bot::first_race_opened_at(http_client, &racetime.id).await? else { break 'checked false };
+                    if first_race > now - TimeDelta::days(*racetime_days) { break 'checked false }
+                }
+                true
+            }),
             Self::External { .. } => None,
         })
     }
@@ -575,6 +734,30 @@ impl Requirement {
                     }),
                 }
             }
+            Self::Dropdown { key, label, prompt, options, multiple } => {
+                let display = prompt.as_ref().unwrap_or(label).clone();
+                let options = options.clone();
+                let multiple = *multiple;
+                let field_name = if multiple { format!("custom_multi_choices[{key}]") } else { format!("custom_choices[{key}]") };
+                let selected = defaults.field_values(&field_name).into_iter().map(str::to_owned).collect::<HashSet<_>>();
+                RequirementStatus {
+                    blocks_submit: false,
+                    html_content: Box::new(move |errors| html! {
+                        : form_field(&field_name, errors, html! {
+                            label(for = &field_name) : display;
+                            br;
+                            select(id = &field_name, name = &field_name, multiple? = multiple) {
+                                @if !multiple {
+                                    option(value = "", selected? = selected.is_empty()) : "—";
+                                }
+                                @for option in &options {
+                                    option(value = &option.value, selected? = selected.contains(&option.value)) : &option.label;
+                                }
+                            }
+                        });
+                    }),
+                }
+            }
             Self::Rules { document } => {
                 let checked = defaults.field_value("confirm").is_some_and(|value| value == "on");
                 let team_config = data.team_config;
@@ -601,6 +784,32 @@ impl Requirement {
                     }),
                 }
             }
+            Self::RulesChecklist { items, document } => {
+                let items = items.iter().enumerate().map(|(idx, item)| (item.clone(), defaults.field_value(&format!("rules_checklist[{idx}]")).is_some_and(|value| value == "on"))).collect_vec();
+                let rules_url = if let Some(document) = document {
+                    document.to_string()
+                } else {
+                    uri!(crate::event::info(data.series, &*data.event)).to_string()
+                };
+                RequirementStatus {
+                    blocks_submit: false,
+                    html_content: Box::new(move |errors| html! {
+                        : form_field("rules_checklist", errors, html! {
+                            p {
+                                : "Please confirm each of the following points from ";
+                                a(href = rules_url, target = "_blank") : "the event rules";
+                                : ":";
+                            }
+                            @for (idx, (item, checked)) in items.into_iter().enumerate() {
+                                @let field_name = format!("rules_checklist[{idx}]");
+                                input(type = "checkbox", id = &field_name, name = &field_name, checked? = checked);
+                                label(for = &field_name) : item;
+                                br;
+                            }
+                        });
+                    }),
+                }
+            }
             Self::Poll { document } => {
                 let checked = defaults.field_value("confirm").is_some_and(|value| value == "on");
                 let team_config = data.team_config;
@@ -802,6 +1011,48 @@ impl Requirement {
                     }),
                 }
             }
+            &Self::RaceTimeFinishes { min_races, ref category } => {
+                let mut html_content = html! {
+                    : "Finish at least ";
+                    : min_races;
+                    : if min_races == 1 { " race" } else { " races" };
+                    @if let Some(category) = category {
+                        : " in the ";
+                        a(href = format!("https://{}/{category}", racetime_host()), target = "_blank") : category;
+                        : " category";
+                    }
+                    : " on racetime.gg";
+                };
+                if me.racetime.is_none() {
+                    html_content = html! {
+                        a(href = uri!(crate::auth::racetime_login(Some(redirect_uri))), target = "_blank") : "Connect a racetime.gg account to your Hyrule Town Hall account";
+                        : " and ";
+                        : html_content;
+                    };
+                }
+                RequirementStatus {
+                    blocks_submit: !is_checked.unwrap(),
+                    html_content: Box::new(move |_| html_content),
+                }
+            }
+            &Self::AccountAge { discord_days, racetime_days } => RequirementStatus {
+                blocks_submit: !is_checked.unwrap(),
+                html_content: Box::new(move |_| html! {
+                    @if let Some(discord_days) = discord_days {
+                        : "Have a Discord account that is at least ";
+                        : discord_days;
+                        : " days old";
+                        @if racetime_days.is_some() {
+                            : " and ";
+                        }
+                    }
+                    @if let Some(racetime_days) = racetime_days {
+                        : if discord_days.is_some() { "have raced on racetime.gg for at least " } else { "Have raced on racetime.gg for at least " };
+                        : racetime_days;
+                        : " days";
+                    }
+                }),
+            },
             Self::External { html, text, blocks_submit } => {
                 let html = html.clone();
                 let text = text.clone();
@@ -816,20 +1067,20 @@ impl Requirement {
         })
     }
 
-    async fn check_form(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, me: &User, data: &Data<'_>, form_ctx: &mut Context<'_>, value: &EnterForm, config: &Config) -> Result<(), Error> {
+    async fn check_value(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, me: &User, data: &Data<'_>, value: &EnterForm, config: &Config) -> Result<Option<RequirementError>, Error> {
         match self {
             Self::StartGG { optional: false } => if !self.is_checked(transaction, http_client, discord_ctx, me, data, config).await?.unwrap_or(false) {
-                form_ctx.push_error(form::Error::validation("A start.gg account is required to enter this event.")); //TODO link to /login/startgg
+                return Ok(Some(RequirementError::form("A start.gg account is required to enter this event."))); //TODO link to /login/startgg
             },
             Self::StartGG { optional: true } => match value.startgg_radio {
                 Some(BoolRadio::Yes) => if !self.is_checked(transaction, http_client, discord_ctx, me, data, config).await?.unwrap_or(false) {
-                    form_ctx.push_error(form::Error::validation("Sign in with start.gg or opt out of start.gg integration.").with_name("startgg_radio")); //TODO link to /login/startgg
+                    return Ok(Some(RequirementError::field("startgg_radio", "Sign in with start.gg or opt out of start.gg integration."))); //TODO link to /login/startgg
                 },
                 Some(BoolRadio::No) => {}
-                None => form_ctx.push_error(form::Error::validation("Please select one of the options.").with_name("startgg_radio")),
+                None => return Ok(Some(RequirementError::field("startgg_radio", "Please select one of the options."))),
             },
             Self::StartGGEventSignup { .. } => if !self.is_checked(transaction, http_client, discord_ctx, me, data, config).await?.unwrap_or(false) {
-                form_ctx.push_error(form::Error::validation("You must be signed up to the event on start.gg to enter."));
+                return Ok(Some(RequirementError::form("You must be signed up to the event on start.gg to enter.")));
             },
             Self::TextField { regex, regex_error_messages, fallback_error_message, .. } => if !regex.is_match(&value.text_field) {
                 let error_message = if let Some((_, error_message)) = regex_error_messages.iter().find(|(regex, _)| regex.is_match(&value.text_field)) {
@@ -837,7 +1088,7 @@ impl Requirement {
                 } else {
                     fallback_error_message.clone()
                 };
-                form_ctx.push_error(form::Error::validation(error_message).with_name("text_field"));
+                return Ok(Some(RequirementError::field("text_field", error_message)));
             },
             Self::TextField2 { regex, regex_error_messages, fallback_error_message, .. } => if !regex.is_match(&value.text_field2) {
                 let error_message = if let Some((_, error_message)) = regex_error_messages.iter().find(|(regex, _)| regex.is_match(&value.text_field2)) {
@@ -845,54 +1096,66 @@ impl Requirement {
                 } else {
                     fallback_error_message.clone()
                 };
-                form_ctx.push_error(form::Error::validation(error_message).with_name("text_field2"));
+                return Ok(Some(RequirementError::field("text_field2", error_message)));
             },
             Self::YesNo { .. } => if value.yes_no.is_none() {
-                form_ctx.push_error(form::Error::validation("Please select one of the options.").with_name("yes_no"));
+                return Ok(Some(RequirementError::field("yes_no", "Please select one of the options.")));
             },
             Self::BooleanChoice { key, .. } => if !value.custom_choices.contains_key(key) {
-                form_ctx.push_error(form::Error::validation("Please select one of the options.").with_name(format!("custom_choices[{key}]")));
+                return Ok(Some(RequirementError::field(format!("custom_choices[{key}]"), "Please select one of the options.")));
             },
             Self::RadioChoice { key, .. } => if !value.custom_choices.get(key).is_some_and(|v| matches!(v.as_str(), "never" | "random" | "always")) {
-                form_ctx.push_error(form::Error::validation("Please select one of the options.").with_name(format!("custom_choices[{key}]")));
+                return Ok(Some(RequirementError::field(format!("custom_choices[{key}]"), "Please select one of the options.")));
+            },
+            Self::Dropdown { key, options, multiple: false, .. } => if !value.custom_choices.get(key).is_some_and(|selected| options.iter().any(|option| option.value == *selected)) {
+                return Ok(Some(RequirementError::field(format!("custom_choices[{key}]"), "Please select one of the options.")));
+            },
+            Self::Dropdown { key, options, multiple: true, .. } => match value.custom_multi_choices.get(key) {
+                Some(selected) if !selected.is_empty() => if !selected.iter().all(|selected| options.iter().any(|option| option.value == *selected)) {
+                    return Ok(Some(RequirementError::field(format!("custom_multi_choices[{key}]"), "Please select only options from the list.")));
+                },
+                _ => return Ok(Some(RequirementError::field(format!("custom_multi_choices[{key}]"), "Please select at least one of the options."))),
             },
             Self::Rules { .. } => if !value.confirm {
-                form_ctx.push_error(form::Error::validation("This field is required.").with_name("confirm"));
+                return Ok(Some(RequirementError::field("confirm", "This field is required.")));
+            },
+            Self::RulesChecklist { items, .. } => if !(0..items.len()).all(|idx| value.rules_checklist.get(&idx).copied().unwrap_or(false)) {
+                return Ok(Some(RequirementError::field("rules_checklist", "Please confirm each of the points.")));
             },
             Self::Poll { .. } => if !value.confirm {
-                form_ctx.push_error(form::Error::validation("This field is required.").with_name("confirm"));
+                return Ok(Some(RequirementError::field("confirm", "This field is required.")));
             },
             Self::RestreamConsent { optional: false, .. } => if !value.restream_consent {
-                form_ctx.push_error(form::Error::validation("Restream consent is required to enter this event.").with_name("restream_consent"));
+                return Ok(Some(RequirementError::field("restream_consent", "Restream consent is required to enter this event.")));
             },
             Self::RestreamConsent { optional: true, .. } => if value.restream_consent_radio.is_none() {
-                form_ctx.push_error(form::Error::validation("Please select one of the options.").with_name("restream_consent_radio"));
+                return Ok(Some(RequirementError::field("restream_consent_radio", "Please select one of the options.")));
             },
             Self::Qualifier { async_start, async_end, .. } => {
                 let now = Utc::now();
                 if now >= *async_start && now < *async_end {
                     if !value.confirm {
-                        form_ctx.push_error(form::Error::validation("This field is required.").with_name("confirm"));
+                        return Ok(Some(RequirementError::field("confirm", "This field is required.")));
                     }
                 } else {
-                    form_ctx.push_error(form::Error::validation("The qualifier seed is not yet available."));
+                    return Ok(Some(RequirementError::form("The qualifier seed is not yet available.")));
                 }
             }
             Self::TripleQualifier { async_starts, async_ends, .. } => if !self.is_checked(transaction, http_client, discord_ctx, me, data, config).await?.unwrap_or(false) {
                 let now = Utc::now();
                 if (*async_starts).into_iter().zip_eq(*async_ends).any(|(async_start, async_end)| now >= async_start && now < async_end) {
                     if !value.confirm {
-                        form_ctx.push_error(form::Error::validation("This field is required.").with_name("confirm"));
+                        return Ok(Some(RequirementError::field("confirm", "This field is required.")));
                     }
                 } else {
-                    form_ctx.push_error(form::Error::validation("No qualifier seed is currently available."));
+                    return Ok(Some(RequirementError::form("No qualifier seed is currently available.")));
                 }
             },
             Self::External { blocks_submit, .. } => if *blocks_submit {
-                form_ctx.push_error(form::Error::validation("Please complete event entry via the external method."));
+                return Ok(Some(RequirementError::form("Please complete event entry via the external method.")));
             },
             _ => if !self.is_checked(transaction, http_client, discord_ctx, me, data, config).await?.unwrap_or(false) {
-                form_ctx.push_error(form::Error::validation(match self {
+                return Ok(Some(RequirementError::form(match self {
                     Self::RaceTime => Cow::Borrowed("A racetime.gg account is required to enter this event. Go to your Hyrule Town Hall profile and select 'Connect a racetime.gg account'."), //TODO direct link?
                     Self::RaceTimeInvite { error_text, .. } => if me.racetime.is_some() {
                         if let Some(error_text) = error_text {
@@ -922,6 +1185,12 @@ impl Requirement {
                     },
                     Self::QualifierPlacement { .. } => Cow::Borrowed("You have not secured a qualifying placement."), //TODO different message if the player has overqualified or overqualifying due to opt-outs is still possible
                     Self::RslLeaderboard => Cow::Borrowed("You have not finished the required number of races on the RSL leaderboard."), //TODO link to rsl.one
+                    Self::RaceTimeFinishes { min_races, .. } => if me.racetime.is_some() {
+                        Cow::Owned(format!("You must have finished at least {min_races} race{} in this event's racetime.gg category to enter.", if *min_races == 1 { "" } else { "s" }))
+                    } else {
+                        Cow::Borrowed("This event requires a minimum number of finished racetime.gg races. Go to your Hyrule Town Hall profile and select 'Connect a racetime.gg account'.")
+                    },
+                    Self::AccountAge { .. } => Cow::Borrowed("Your connected accounts don't meet this event's minimum account age."),
                     | Self::StartGG { .. }
                     | Self::TextField { .. }
                     | Self::TextField2 { .. }
@@ -933,11 +1202,21 @@ impl Requirement {
                     | Self::TripleQualifier { .. }
                     | Self::BooleanChoice { .. }
                     | Self::RadioChoice { .. }
+                    | Self::Dropdown { .. }
+                    | Self::RulesChecklist { .. }
                     | Self::External { .. }
                         => unreachable!(),
-                }));
+                })));
             }
         }
+        Ok(None)
+    }
+
+    async fn check_form(&self, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, me: &User, data: &Data<'_>, form_ctx: &mut Context<'_>, value: &EnterForm, config: &Config) -> Result<(), Error> {
+        if let Some(RequirementError { field, message }) = self.check_value(transaction, http_client, discord_ctx, me, data, value, config).await? {
+            let error = form::Error::validation(message);
+            form_ctx.push_error(if let Some(field) = field { error.with_name(field) } else { error });
+        }
         Ok(())
     }
 
@@ -1021,6 +1300,19 @@ pub(crate) struct EnterForm {
     #[field(default = String::new())]
     text_field2: String,
     custom_choices: HashMap<String, String>,
+    custom_multi_choices: HashMap<String, Vec<String>>,
+    rules_checklist: HashMap<usize, bool>,
+}
+
+impl EnterForm {
+    /// The custom choices to store for the entrant, with the answers to multi-select dropdowns joined by commas.
+    fn all_custom_choices(&self) -> HashMap<String, String> {
+        let mut custom_choices = self.custom_choices.clone();
+        for (key, selected) in &self.custom_multi_choices {
+            custom_choices.insert(key.clone(), selected.join(","));
+        }
+        custom_choices
+    }
 }
 
 pub(crate) async fn enter_form(mut transaction: Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, data: Data<'_>, defaults: pic::EnterFormDefaults<'_>, config: &Config) -> Result<RawHtml<String>, Error> {
@@ -1202,6 +1494,7 @@ pub(crate) async fn enter_form(mut transaction: Transaction<'_, Postgres>, http_
         }
     };
     let header = data.header(&mut transaction, me.as_ref(), Tab::Enter, false).await?;
+    let is_full = !data.is_started(&mut transaction).await? && super::waitlist::is_full(&mut transaction, &data).await?;
     let invites = html! {
         @for team_id in my_invites {
             : crate::notification::team_invite(&mut transaction, me.as_ref().expect("got a team invite while not logged in"), csrf, defaults.errors(), crate::notification::TeamInviteSource::Enter, team_id).await?;
@@ -1210,10 +1503,54 @@ pub(crate) async fn enter_form(mut transaction: Transaction<'_, Postgres>, http_
     Ok(page(transaction, &me, &uri, PageStyle { chests: data.chests().await?, ..PageStyle::default() }, &format!("Enter — {}", data.display_name), html! {
         : header;
         : invites;
+        @if is_full {
+            p : "This event has reached its maximum number of entrants. If you enter now, you will be added to the waitlist and notified if a spot opens up.";
+        }
         : content;
     }).await?)
 }
 
+/// Renders the requirements of a flow the way the signup form would show them to the given user, for previewing changes on the setup page.
+pub(super) async fn preview(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, me: &User, data: &Data<'_>, flow: &Flow, config: &Config) -> Result<RawHtml<String>, Error> {
+    let defaults = pic::EnterFormDefaults::Context(Context::default());
+    let mut can_submit = true;
+    let mut requirements_display = Vec::with_capacity(flow.requirements.len());
+    for requirement in &flow.requirements {
+        let is_checked = requirement.is_checked(&mut *transaction, http_client, discord_ctx, me, data, config).await?;
+        let status = requirement.check_get(http_client, discord_ctx, data, is_checked, uri!(get(data.series, &*data.event, _, _)), &defaults, me, config).await?;
+        if status.blocks_submit { can_submit = false }
+        requirements_display.push((is_checked, status.html_content));
+    }
+    Ok(html! {
+        @if flow.requirements.is_empty() {
+            p : "This event has no signup requirements, so the signup form will only have an “Enter” button.";
+        } else {
+            @for (is_checked, html_content) in requirements_display {
+                div(class = "check-item") {
+                    div(class = "checkmark") {
+                        @match is_checked {
+                            Some(true) => : "✓";
+                            Some(false) => {}
+                            None => : "?";
+                        }
+                    }
+                    div : html_content(&mut Vec::default());
+                }
+            }
+            @if !can_submit {
+                p : "You couldn't submit this form yet, since you don't meet all of the requirements.";
+            }
+        }
+        @if let Some(closes) = flow.closes {
+            p {
+                : "Signups close ";
+                : format_datetime(closes, DateTimeFormat { long: true, running_text: true });
+                : ".";
+            }
+        }
+    })
+}
+
 fn enter_form_step2<'a, 'b: 'a, 'c: 'a, 'd: 'a>(mut transaction: Transaction<'a, Postgres>, me: Option<User>, uri: Origin<'b>, http_client: &reqwest::Client, csrf: Option<&'a CsrfToken>, data: Data<'c>, defaults: mw::EnterFormStep2Defaults<'d>) -> Pin<Box<dyn Future<Output = Result<RawHtml<String>, Error>> + Send + 'a>> {
     let team_members = defaults.racetime_members(http_client);
     Box::pin(async move {
@@ -1321,6 +1658,160 @@ pub(crate) async fn get(config: &State<Config>, pool: &State<PgPool>, http_clien
     Ok(enter_form(transaction, http_client, discord_ctx, me, uri, csrf.as_ref(), data, pic::EnterFormDefaults::Values { my_role, teammate }, config).await?)
 }
 
+/// Creates or reactivates a solo signup after all of the event's entry requirements have been checked.
+///
+/// This also assigns the participant role and notifies organizers about start.gg entries, which have to be handled manually.
+/// If the event is full, the signup is added to the waitlist instead and the return value is `true`.
+async fn create_solo_signup(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, config: &Config, me: &User, data: &Data<'_>, value: &EnterForm, request_qualifier: Option<AsyncKind>) -> Result<bool, Error> {
+    super::waitlist::lock(&mut *transaction, data).await?;
+    let waitlisted = super::waitlist::is_full(&mut *transaction, data).await?;
+    // Check if there's an existing resigned team to reactivate
+    let existing_resigned_team = sqlx::query_scalar!(r#"SELECT id AS "id: Id<Teams>" FROM teams, team_members WHERE
+        id = team
+        AND series = $1
+        AND event = $2
+        AND member = $3
+        AND resigned
+        AND NOT EXISTS (SELECT 1 FROM team_members WHERE team = id AND status = 'unconfirmed')
+    "#, data.series as _, &data.event, me.id as _).fetch_optional(&mut *transaction).await?;
+
+    let custom_choices = value.all_custom_choices();
+
+    let id = if let Some(existing_id) = existing_resigned_team {
+        // Reactivate the existing resigned team
+        sqlx::query!(
+            "UPDATE teams SET resigned = FALSE, restream_consent = $2, text_field = $3, text_field2 = $4, yes_no = $5, mw_impl = $6, custom_choices = $7, waitlisted_at = $8, waitlist_deadline = NULL WHERE id = $1",
+            existing_id as _,
+            value.restream_consent || value.restream_consent_radio == Some(BoolRadio::Yes),
+            value.text_field,
+            value.text_field2,
+            value.yes_no == Some(BoolRadio::Yes),
+            value.mw_impl as _,
+            sqlx::types::Json(&custom_choices) as _,
+            waitlisted.then(Utc::now),
+        ).execute(&mut *transaction).await?;
+        existing_id
+    } else {
+        // Create a new team
+        let id = Id::<Teams>::new(&mut *transaction).await?;
+        sqlx::query!(
+            "INSERT INTO teams (id, series, event, plural_name, restream_consent, text_field, text_field2, yes_no, mw_impl, custom_choices, waitlisted_at) VALUES ($1, $2, $3, FALSE, $4, $5, $6, $7, $8, $9, $10)",
+            id as _,
+            data.series as _,
+            &data.event,
+            value.restream_consent || value.restream_consent_radio == Some(BoolRadio::Yes),
+            value.text_field,
+            value.text_field2,
+            value.yes_no == Some(BoolRadio::Yes),
+            value.mw_impl as _,
+            sqlx::types::Json(&custom_choices) as _,
+            waitlisted.then(Utc::now),
+        ).execute(&mut *transaction).await?;
+        sqlx::query!("INSERT INTO team_members (team, member, status, role) VALUES ($1, $2, 'created', 'none')", id as _, me.id as _).execute(&mut *transaction).await?;
+        id
+    };
+    if waitlisted {
+        // the participant role is assigned once the entrant is promoted and confirms their spot, qualifier asyncs can be requested from the status page
+        return Ok(true)
+    }
+    if let Some(async_kind) = request_qualifier {
+        // Only insert if not already requested for this team
+        sqlx::query!("INSERT INTO async_teams (team, kind, requested) VALUES ($1, $2, NOW()) ON CONFLICT DO NOTHING", id as _, async_kind as _).execute(&mut *transaction).await?;
+    }
+    if let (Some(discord_user), Some(discord_guild)) = (me.discord.as_ref(), data.discord_guild) {
+        let discord_ctx = discord_ctx.read().await;
+        if let Some(PgSnowflake(participant_role)) = sqlx::query_scalar!(r#"SELECT id AS "id: PgSnowflake<RoleId>" FROM discord_roles WHERE guild = $1 AND series = $2 AND event = $3"#, PgSnowflake(discord_guild) as _, data.series as _, &data.event).fetch_optional(&mut *transaction).await? {
+            if let Ok(member) = discord_guild.member(&*discord_ctx, discord_user.id).await {
+                if let Err(e) = member.add_role(&*discord_ctx, participant_role).await {
+                    let msg = MessageBuilder::default()
+                        .push("Failed to assign participant role ")
+                        .mention(&participant_role)
+                        .push(" to ")
+                        .mention_user(me)
+                        .push(" when entering ")
+                        .push_safe(&data.display_name)
+                        .push(": ")
+                        .push_safe(e.to_string())
+                        .build();
+                    if let Ok(ch) = ADMIN_USER.create_dm_channel(&*discord_ctx).await {
+                        let _ = ch.say(&*discord_ctx, msg).await;
+                    }
+                }
+            }
+        }
+    }
+    let Flow { requirements, .. } = data.enter_flow.as_ref().expect("checked above");
+    for requirement in requirements {
+        if let Requirement::StartGG { optional } = requirement {
+            let discord_ctx = discord_ctx.read().await;
+            if !optional || value.startgg_radio == Some(BoolRadio::Yes) {
+                // enter event on start.gg with user ID
+                // this is currently not possible to automate, see conversation ending at <https://discord.com/channels/339548254704369677/541015301618401301/1346621619787006083> for details
+                // temporary workaround until this is automated:
+                let startgg_id = me.startgg_id.as_ref().expect("checked by requirement");
+                let mut msg = MessageBuilder::default();
+                msg.mention_user(me);
+                msg.push(" signed up for ");
+                msg.push_safe(&data.display_name);
+                let response = startgg::query_cached::<startgg::UserSlugQuery>(http_client, &config.startgg, startgg::user_slug_query::Variables { id: startgg_id.clone() }).await?;
+                if let startgg::user_slug_query::ResponseData { user: Some(startgg::user_slug_query::UserSlugQueryUser { discriminator: Some(slug), .. }) } = response {
+                    msg.push(" with start.gg user slug ");
+                    msg.push_mono_safe(slug);
+                    msg.push(".");
+                } else {
+                    msg.push(" with unknown start.gg user ID ");
+                    msg.push_mono_safe(&startgg_id.0);
+                    msg.push(". ");
+                    msg.mention(&ADMIN_USER);
+                    msg.push(" please investigate.");
+                }
+                let built_msg = msg.build();
+                if let Some(organizer_channel) = data.discord_organizer_channel {
+                    if let Err(e) = organizer_channel.say(&*discord_ctx, &built_msg).await {
+                        let dm_msg = MessageBuilder::default()
+                            .push("Failed to post to organizer channel (")
+                            .push_safe(e.to_string())
+                            .push("). Please handle manually: ")
+                            .push_safe(&built_msg)
+                            .build();
+                        if let Ok(ch) = ADMIN_USER.create_dm_channel(&*discord_ctx).await {
+                            let _ = ch.say(&*discord_ctx, dm_msg).await;
+                        }
+                    }
+                } else {
+                    ADMIN_USER.create_dm_channel(&*discord_ctx).await?.say(&*discord_ctx, built_msg).await?;
+                }
+            } else {
+                // enter event on start.gg anonymously
+                // this is currently not possible to automate, see conversation ending at <https://discord.com/channels/339548254704369677/541015301618401301/1346621619787006083> for details
+                // temporary workaround until this is automated:
+                let msg = MessageBuilder::default()
+                    .mention_user(me)
+                    .push(" signed up for ")
+                    .push_safe(&data.display_name)
+                    .push(" without start.gg account.")
+                    .build();
+                if let Some(organizer_channel) = data.discord_organizer_channel {
+                    if let Err(e) = organizer_channel.say(&*discord_ctx, &msg).await {
+                        let dm_msg = MessageBuilder::default()
+                            .push("Failed to post to organizer channel (")
+                            .push_safe(e.to_string())
+                            .push("). Please handle manually: ")
+                            .push_safe(&msg)
+                            .build();
+                        if let Ok(ch) = ADMIN_USER.create_dm_channel(&*discord_ctx).await {
+                            let _ = ch.say(&*discord_ctx, dm_msg).await;
+                        }
+                    }
+                } else {
+                    ADMIN_USER.create_dm_channel(&*discord_ctx).await?.say(&*discord_ctx, msg).await?;
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
 #[rocket::post("/event/<series>/<event>/enter", data = "<form>")]
 pub(crate) async fn post(config: &State<Config>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, EnterForm>>) -> Result<RedirectOrContent, StatusOrError<Error>> {
     let mut transaction = pool.begin().await?;
@@ -1363,144 +1854,7 @@ pub(crate) async fn post(config: &State<Config>, pool: &State<PgPool>, http_clie
                     form.context.push_error(form::Error::validation("You are already signed up for this event."));
                 }
                 if form.context.errors().next().is_none() {
-                    // Check if there's an existing resigned team to reactivate
-                    let existing_resigned_team = sqlx::query_scalar!(r#"SELECT id AS "id: Id<Teams>" FROM teams, team_members WHERE
-                        id = team
-                        AND series = $1
-                        AND event = $2
-                        AND member = $3
-                        AND resigned
-                        AND NOT EXISTS (SELECT 1 FROM team_members WHERE team = id AND status = 'unconfirmed')
-                    "#, series as _, event, me.id as _).fetch_optional(&mut *transaction).await?;
-
-                    let custom_choices = value.custom_choices.clone();
-
-                    let id = if let Some(existing_id) = existing_resigned_team {
-                        // Reactivate the existing resigned team
-                        sqlx::query!(
-                            "UPDATE teams SET resigned = FALSE, restream_consent = $2, text_field = $3, text_field2 = $4, yes_no = $5, mw_impl = $6, custom_choices = $7 WHERE id = $1",
-                            existing_id as _,
-                            value.restream_consent || value.restream_consent_radio == Some(BoolRadio::Yes),
-                            value.text_field,
-                            value.text_field2,
-                            value.yes_no == Some(BoolRadio::Yes),
-                            value.mw_impl as _,
-                            sqlx::types::Json(&custom_choices) as _,
-                        ).execute(&mut *transaction).await?;
-                        existing_id
-                    } else {
-                        // Create a new team
-                        let id = Id::<Teams>::new(&mut transaction).await?;
-                        sqlx::query!(
-                            "INSERT INTO teams (id, series, event, plural_name, restream_consent, text_field, text_field2, yes_no, mw_impl, custom_choices) VALUES ($1, $2, $3, FALSE, $4, $5, $6, $7, $8, $9)",
-                            id as _,
-                            series as _,
-                            event,
-                            value.restream_consent || value.restream_consent_radio == Some(BoolRadio::Yes),
-                            value.text_field,
-                            value.text_field2,
-                            value.yes_no == Some(BoolRadio::Yes),
-                            value.mw_impl as _,
-                            sqlx::types::Json(&custom_choices) as _,
-                        ).execute(&mut *transaction).await?;
-                        sqlx::query!("INSERT INTO team_members (team, member, status, role) VALUES ($1, $2, 'created', 'none')", id as _, me.id as _).execute(&mut *transaction).await?;
-                        id
-                    };
-                    if let Some(async_kind) = request_qualifier {
-                        // Only insert if not already requested for this team
-                        sqlx::query!("INSERT INTO async_teams (team, kind, requested) VALUES ($1, $2, NOW()) ON CONFLICT DO NOTHING", id as _, async_kind as _).execute(&mut *transaction).await?;
-                    }
-                    if let (Some(discord_user), Some(discord_guild)) = (me.discord.as_ref(), data.discord_guild) {
-                        let discord_ctx = discord_ctx.read().await;
-                        if let Some(PgSnowflake(participant_role)) = sqlx::query_scalar!(r#"SELECT id AS "id: PgSnowflake<RoleId>" FROM discord_roles WHERE guild = $1 AND series = $2 AND event = $3"#, PgSnowflake(discord_guild) as _, series as _, event).fetch_optional(&mut *transaction).await? {
-                            if let Ok(member) = discord_guild.member(&*discord_ctx, discord_user.id).await {
-                                if let Err(e) = member.add_role(&*discord_ctx, participant_role).await {
-                                    let msg = MessageBuilder::default()
-                                        .push("Failed to assign participant role ")
-                                        .mention(&participant_role)
-                                        .push(" to ")
-                                        .mention_user(&me)
-                                        .push(" when entering ")
-                                        .push_safe(&data.display_name)
-                                        .push(": ")
-                                        .push_safe(e.to_string())
-                                        .build();
-                                    if let Ok(ch) = ADMIN_USER.create_dm_channel(&*discord_ctx).await {
-                                        let _ = ch.say(&*discord_ctx, msg).await;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    let Flow { ref requirements, .. } = data.enter_flow.expect("checked above");
-                    for requirement in requirements {
-                        if let Requirement::StartGG { optional } = requirement {
-                            let discord_ctx = discord_ctx.read().await;
-                            if !optional || value.startgg_radio == Some(BoolRadio::Yes) {
-                                // enter event on start.gg with user ID
-                                // this is currently not possible to automate, see conversation ending at <https://discord.com/channels/339548254704369677/541015301618401301/1346621619787006083> for details
-                                // temporary workaround until this is automated:
-                                let startgg_id = me.startgg_id.as_ref().expect("checked by requirement");
-                                let mut msg = MessageBuilder::default();
-                                msg.mention_user(&me);
-                                msg.push(" signed up for ");
-                                msg.push_safe(&data.display_name);
-                                let response = startgg::query_cached::<startgg::UserSlugQuery>(http_client, &config.startgg, startgg::user_slug_query::Variables { id: startgg_id.clone() }).await?;
-                                if let startgg::user_slug_query::ResponseData { user: Some(startgg::user_slug_query::UserSlugQueryUser { discriminator: Some(slug), .. }) } = response {
-                                    msg.push(" with start.gg user slug ");
-                                    msg.push_mono_safe(slug);
-                                    msg.push(".");
-                                } else {
-                                    msg.push(" with unknown start.gg user ID ");
-                                    msg.push_mono_safe(&startgg_id.0);
-                                    msg.push(". ");
-                                    msg.mention(&ADMIN_USER);
-                                    msg.push(" please investigate.");
-                                }
-                                let built_msg = msg.build();
-                                if let Some(organizer_channel) = data.discord_organizer_channel {
-                                    if let Err(e) = organizer_channel.say(&*discord_ctx, &built_msg).await {
-                                        let dm_msg = MessageBuilder::default()
-                                            .push("Failed to post to organizer channel (")
-                                            .push_safe(e.to_string())
-                                            .push("). Please handle manually: ")
-                                            .push_safe(&built_msg)
-                                            .build();
-                                        if let Ok(ch) = ADMIN_USER.create_dm_channel(&*discord_ctx).await {
-                                            let _ = ch.say(&*discord_ctx, dm_msg).await;
-                                        }
-                                    }
-                                } else {
-                                    ADMIN_USER.create_dm_channel(&*discord_ctx).await?.say(&*discord_ctx, built_msg).await?;
-                                }
-                            } else {
-                                // enter event on start.gg anonymously
-                                // this is currently not possible to automate, see conversation ending at <https://discord.com/channels/339548254704369677/541015301618401301/1346621619787006083> for details
-                                // temporary workaround until this is automated:
-                                let msg = MessageBuilder::default()
-                                    .mention_user(&me)
-                                    .push(" signed up for ")
-                                    .push_safe(&data.display_name)
-                                    .push(" without start.gg account.")
-                                    .build();
-                                if let Some(organizer_channel) = data.discord_organizer_channel {
-                                    if let Err(e) = organizer_channel.say(&*discord_ctx, &msg).await {
-                                        let dm_msg = MessageBuilder::default()
-                                            .push("Failed to post to organizer channel (")
-                                            .push_safe(e.to_string())
-                                            .push("). Please handle manually: ")
-                                            .push_safe(&msg)
-                                            .build();
-                                        if let Ok(ch) = ADMIN_USER.create_dm_channel(&*discord_ctx).await {
-                                            let _ = ch.say(&*discord_ctx, dm_msg).await;
-                                        }
-                                    }
-                                } else {
-                                    ADMIN_USER.create_dm_channel(&*discord_ctx).await?.say(&*discord_ctx, msg).await?;
-                                }
-                            }
-                        }
-                    }
+                    create_solo_signup(&mut transaction, http_client, discord_ctx, config, &me, &data, value, request_qualifier).await?;
                     transaction.commit().await?;
                     return Ok(RedirectOrContent::Redirect(Redirect::to(uri!(super::status(series, event)))))
                 }
@@ -1575,9 +1929,11 @@ pub(crate) async fn post(config: &State<Config>, pool: &State<PgPool>, http_clie
                 };
                 if form.context.errors().next().is_none() {
                     let id = Id::<Teams>::new(&mut transaction).await?;
-                    let custom_choices = value.custom_choices.clone();
+                    let custom_choices = value.all_custom_choices();
+                    super::waitlist::lock(&mut transaction, &data).await?;
+                    let waitlisted_at = super::waitlist::is_full(&mut transaction, &data).await?.then(Utc::now);
                     sqlx::query!(
-                    "INSERT INTO teams (id, series, event, name, restream_consent, text_field, text_field2, yes_no, mw_impl, custom_choices) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+                    "INSERT INTO teams (id, series, event, name, restream_consent, text_field, text_field2, yes_no, mw_impl, custom_choices, waitlisted_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
                         id as _,
                         series as _,
                         event,
@@ -1588,6 +1944,7 @@ pub(crate) async fn post(config: &State<Config>, pool: &State<PgPool>, http_clie
                         value.yes_no == Some(BoolRadio::Yes),
                         value.mw_impl as _,
                         sqlx::types::Json(&custom_choices) as _,
+                        waitlisted_at,
                     ).execute(&mut *transaction).await?;
                     sqlx::query!("INSERT INTO team_members (team, member, status, role) VALUES ($1, $2, 'created', $3)", id as _, me.id as _, Role::from(my_role.expect("validated")) as _).execute(&mut *transaction).await?;
                     sqlx::query!("INSERT INTO team_members (team, member, status, role) VALUES ($1, $2, 'unconfirmed', $3)", id as _, teammate.expect("validated") as _, match my_role.expect("validated") { pic::Role::Sheikah => Role::Gerudo, pic::Role::Gerudo => Role::Sheikah } as _).execute(&mut *transaction).await?;
@@ -1746,9 +2103,11 @@ pub(crate) async fn post(config: &State<Config>, pool: &State<PgPool>, http_clie
                 if form.context.errors().next().is_none() {
                     return Ok(if value.step2 {
                         let id = Id::<Teams>::new(&mut transaction).await?;
-                        let custom_choices = value.custom_choices.clone();
+                        let custom_choices = value.all_custom_choices();
+                        super::waitlist::lock(&mut transaction, &data).await?;
+                        let waitlisted_at = super::waitlist::is_full(&mut transaction, &data).await?.then(Utc::now);
                         sqlx::query!(
-                            "INSERT INTO teams (id, series, event, name, racetime_slug, restream_consent, text_field, text_field2, yes_no, mw_impl, custom_choices) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+                            "INSERT INTO teams (id, series, event, name, racetime_slug, restream_consent, text_field, text_field2, yes_no, mw_impl, custom_choices, waitlisted_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
                             id as _,
                             series as _,
                             event,
@@ -1760,6 +2119,7 @@ pub(crate) async fn post(config: &State<Config>, pool: &State<PgPool>, http_clie
                             value.yes_no == Some(BoolRadio::Yes),
                             value.mw_impl as _,
                         sqlx::types::Json(&custom_choices) as _,
+                            waitlisted_at,
                         ).execute(&mut *transaction).await?;
                         for ((user, role), startgg_id) in users.into_iter().zip_eq(roles).zip_eq(startgg_ids) {
                             sqlx::query!(
@@ -1781,3 +2141,402 @@ pub(crate) async fn post(config: &State<Config>, pool: &State<PgPool>, http_clie
     }
     Ok(RedirectOrContent::Content(enter_form(transaction, http_client, discord_ctx, Some(me), uri, csrf.as_ref(), data, pic::EnterFormDefaults::Context(form.context), config).await?))
 }
+
+/// Custom ID prefix for the Discord signup button, followed by the series slug and event name separated by an underscore.
+pub(crate) const DISCORD_BUTTON_PREFIX: &str = "enter_";
+/// Custom ID prefix for the Discord signup modal, with the same format as [`DISCORD_BUTTON_PREFIX`].
+pub(crate) const DISCORD_MODAL_PREFIX: &str = "enter_modal_";
+/// Discord allows at most 5 text inputs per modal.
+const DISCORD_MAX_INPUTS: usize = 5;
+const DISCORD_MAX_LABEL_LEN: usize = 45;
+const DISCORD_MAX_PLACEHOLDER_LEN: usize = 100;
+
+pub(crate) enum DiscordEntry {
+    /// The user can't enter via Discord. The message explains why and what to do instead.
+    Unavailable(String),
+    /// No questions needed to be answered, so the user has been signed up directly, or added to the waitlist if the event is full.
+    Entered {
+        waitlisted: bool,
+    },
+}
+
+/// A question from the enter flow, asked as a text input in the Discord signup modal.
+struct DiscordInput {
+    /// The name of the corresponding field of [`EnterForm`].
+    field: String,
+    label: String,
+    placeholder: Cow<'static, str>,
+    long: bool,
+    /// Whether Discord requires an answer. Optional questions are answered with “no” if left empty.
+    required: bool,
+}
+
+/// Converts a label from the enter flow, which may contain HTML, to plain text suitable for a Discord text input label.
+fn discord_label(label: &RawHtml<String>) -> String {
+    let text = regex_replace_all!("<[^>]*>", &label.0, "");
+    let text = text.replace("&amp;", "&").replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&#39;", "'");
+    let text = text.split_whitespace().join(" ");
+    if text.chars().count() > DISCORD_MAX_LABEL_LEN {
+        format!("{}…", text.chars().take(DISCORD_MAX_LABEL_LEN - 1).collect::<String>())
+    } else {
+        text
+    }
+}
+
+/// Lists the options of a dropdown for the placeholder of a Discord text input, shortened to fit Discord's length limit.
+fn discord_dropdown_placeholder(options: &[DropdownOption], multiple: bool) -> String {
+    let text = format!("{}{}", if multiple { "one or more of, separated by commas: " } else { "one of: " }, options.iter().map(|option| &option.label).join(", "));
+    if text.chars().count() > DISCORD_MAX_PLACEHOLDER_LEN {
+        format!("{}…", text.chars().take(DISCORD_MAX_PLACEHOLDER_LEN - 1).collect::<String>())
+    } else {
+        text
+    }
+}
+
+/// Finds the dropdown option matching a Discord answer by value or label, ignoring case.
+fn parse_discord_dropdown<'a>(options: &'a [DropdownOption], answer: &str) -> Option<&'a DropdownOption> {
+    let answer = answer.trim();
+    options.iter().find(|option| option.value.eq_ignore_ascii_case(answer) || option.label.eq_ignore_ascii_case(answer))
+}
+
+fn parse_discord_bool(answer: &str) -> Option<BoolRadio> {
+    match &*answer.trim().to_lowercase() {
+        "yes" | "y" => Some(BoolRadio::Yes),
+        "no" | "n" => Some(BoolRadio::No),
+        _ => None,
+    }
+}
+
+/// Returns the questions to ask in the Discord signup modal, or `None` if the enter flow has requirements that can only be completed on the website.
+fn discord_inputs(requirements: &[Requirement]) -> Option<Vec<DiscordInput>> {
+    let mut inputs = Vec::<DiscordInput>::default();
+    for requirement in requirements {
+        let input = match requirement {
+            Requirement::StartGG { optional: true } => DiscordInput {
+                field: format!("startgg_radio"),
+                label: format!("Enter with your start.gg account?"),
+                placeholder: Cow::Borrowed("yes or no (no if left empty)"),
+                long: false,
+                required: false,
+            },
+            &Requirement::TextField { ref label, long, .. } => DiscordInput {
+                field: format!("text_field"),
+                label: discord_label(label),
+                placeholder: Cow::Borrowed(""),
+                long,
+                required: true,
+            },
+            &Requirement::TextField2 { ref label, long, .. } => DiscordInput {
+                field: format!("text_field2"),
+                label: discord_label(label),
+                placeholder: Cow::Borrowed(""),
+                long,
+                required: true,
+            },
+            Requirement::YesNo { label } => DiscordInput {
+                field: format!("yes_no"),
+                label: discord_label(label),
+                placeholder: Cow::Borrowed("yes or no"),
+                long: false,
+                required: true,
+            },
+            Requirement::BooleanChoice { key, label, prompt, .. } => DiscordInput {
+                field: format!("custom_choices[{key}]"),
+                label: discord_label(prompt.as_ref().unwrap_or(label)),
+                placeholder: Cow::Borrowed("yes or no"),
+                long: false,
+                required: true,
+            },
+            Requirement::RadioChoice { key, label, prompt, .. } => DiscordInput {
+                field: format!("custom_choices[{key}]"),
+                label: discord_label(prompt.as_ref().unwrap_or(label)),
+                placeholder: Cow::Borrowed("never, random, or always"),
+                long: false,
+                required: true,
+            },
+            &Requirement::Dropdown { ref key, ref label, ref prompt, ref options, multiple } => DiscordInput {
+                field: if multiple { format!("custom_multi_choices[{key}]") } else { format!("custom_choices[{key}]") },
+                label: discord_label(prompt.as_ref().unwrap_or(label)),
+                placeholder: Cow::Owned(discord_dropdown_placeholder(options, multiple)),
+                long: false,
+                required: true,
+            },
+            Requirement::Rules { .. } => DiscordInput {
+                field: format!("confirm"),
+                label: format!("I have read and agree to the event rules"),
+                placeholder: Cow::Borrowed("yes"),
+                long: false,
+                required: true,
+            },
+            Requirement::Poll { .. } => DiscordInput {
+                field: format!("confirm"),
+                label: format!("I have submitted a response to the poll"),
+                placeholder: Cow::Borrowed("yes"),
+                long: false,
+                required: true,
+            },
+            Requirement::RestreamConsent { optional: false, .. } => DiscordInput {
+                field: format!("restream_consent"),
+                label: format!("I agree to be restreamed"),
+                placeholder: Cow::Borrowed("yes"),
+                long: false,
+                required: true,
+            },
+            Requirement::RestreamConsent { optional: true, .. } => DiscordInput {
+                field: format!("restream_consent_radio"),
+                label: format!("Do you consent to being restreamed?"),
+                placeholder: Cow::Borrowed("yes or no (no if left empty)"),
+                long: false,
+                required: false,
+            },
+            // requesting a qualifier seed shows the seed on the event status page
+            Requirement::Qualifier { .. } | Requirement::TripleQualifier { .. } => return None,
+            | Requirement::RaceTime
+            | Requirement::RaceTimeInvite { .. }
+            | Requirement::Twitch
+            | Requirement::Discord
+            | Requirement::DiscordGuild { .. }
+            | Requirement::Challonge
+            | Requirement::StartGG { optional: false }
+            | Requirement::StartGGEventSignup { .. }
+            | Requirement::QualifierPlacement { .. }
+            | Requirement::RslLeaderboard
+            | Requirement::RaceTimeFinishes { .. }
+            | Requirement::AccountAge { .. }
+            | Requirement::External { .. }
+            // each item has to be acknowledged individually, which a single text input can't do
+            | Requirement::RulesChecklist { .. }
+                => continue,
+        };
+        if !inputs.iter().any(|iter_input| iter_input.field == input.field) {
+            inputs.push(input);
+        }
+    }
+    (inputs.len() <= DISCORD_MAX_INPUTS).then_some(inputs)
+}
+
+/// Builds the form value that the website would have submitted for the given Discord modal answers, keyed by [`EnterForm`] field name.
+fn discord_form_value(requirements: &[Requirement], answers: &HashMap<String, String>) -> EnterForm {
+    let answer = |field: &str| answers.get(field).map(|answer| answer.trim()).filter(|answer| !answer.is_empty());
+    // optional questions which were left empty count as “no”, but other answers still have to be valid
+    let optional_bool = |field: &str| answer(field).map_or(Some(BoolRadio::No), parse_discord_bool);
+    let mut custom_choices = HashMap::default();
+    let mut custom_multi_choices = HashMap::default();
+    for requirement in requirements {
+        match requirement {
+            Requirement::BooleanChoice { key, .. } => if let Some(value) = answer(&format!("custom_choices[{key}]")).and_then(parse_discord_bool) {
+                custom_choices.insert(key.clone(), match value {
+                    BoolRadio::Yes => format!("yes"),
+                    BoolRadio::No => format!("no"),
+                });
+            },
+            Requirement::RadioChoice { key, .. } => if let Some(value) = answer(&format!("custom_choices[{key}]")) {
+                custom_choices.insert(key.clone(), value.to_lowercase());
+            },
+            Requirement::Dropdown { key, options, multiple: false, .. } => if let Some(value) = answer(&format!("custom_choices[{key}]")) {
+                // unknown answers are passed on as-is so they fail validation
+                custom_choices.insert(key.clone(), parse_discord_dropdown(options, value).map_or_else(|| value.to_owned(), |option| option.value.clone()));
+            },
+            Requirement::Dropdown { key, options, multiple: true, .. } => if let Some(value) = answer(&format!("custom_multi_choices[{key}]")) {
+                custom_multi_choices.insert(key.clone(), value.split(',').filter(|value| !value.trim().is_empty()).map(|value| parse_discord_dropdown(options, value).map_or_else(|| value.trim().to_owned(), |option| option.value.clone())).collect());
+            },
+            _ => {}
+        }
+    }
+    EnterForm {
+        csrf: String::default(),
+        confirm: answer("confirm").and_then(parse_discord_bool) == Some(BoolRadio::Yes),
+        racetime_team: None,
+        team_name: String::default(),
+        my_role: None,
+        teammate: None,
+        step2: false,
+        roles: HashMap::default(),
+        startgg_id: HashMap::default(),
+        mw_impl: None,
+        startgg_radio: optional_bool("startgg_radio"),
+        restream_consent: answer("restream_consent").and_then(parse_discord_bool) == Some(BoolRadio::Yes),
+        restream_consent_radio: optional_bool("restream_consent_radio"),
+        yes_no: answer("yes_no").and_then(parse_discord_bool),
+        text_field: answers.get("text_field").cloned().unwrap_or_default(),
+        text_field2: answers.get("text_field2").cloned().unwrap_or_default(),
+        custom_choices,
+        custom_multi_choices,
+        rules_checklist: HashMap::default(),
+    }
+}
+
+/// Checks whether the given Discord user can enter the event via Discord at all, independently of their answers.
+///
+/// Returns the user's Hyrule Town Hall account and the event's requirements, or a message explaining why entering via Discord isn't possible.
+async fn discord_preconditions<'a>(transaction: &mut Transaction<'_, Postgres>, discord_user: UserId, data: &'a Data<'_>) -> Result<Result<(User, &'a [Requirement]), String>, Error> {
+    let enter_url = uri!(base_uri(), get(data.series, &*data.event, _, _));
+    let Some(me) = User::from_discord(&mut **transaction, discord_user).await? else {
+        return Ok(Err(format!("To enter this event, please connect your Discord account to a Hyrule Town Hall account first: <{enter_url}>")))
+    };
+    if data.is_started(&mut *transaction).await? {
+        return Ok(Err(format!("You can no longer enter this event since it has already started.")))
+    }
+    let TeamConfig::Solo = data.team_config else {
+        return Ok(Err(format!("Team signups aren't available via Discord. Please enter this event on the website: <{enter_url}>")))
+    };
+    let Some(Flow { ref requirements, closes }) = data.enter_flow else {
+        return Ok(Err(format!("Signups for this event aren't open yet.")))
+    };
+    if closes.is_some_and(|closes| closes <= Utc::now()) {
+        return Ok(Err(format!("The deadline to enter this event has passed.")))
+    }
+    if requirements.is_empty() {
+        return Ok(Err(format!("Please enter this event on the website: <{enter_url}>")))
+    }
+    if let Some(ref racetime) = me.racetime {
+        if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM event_blocks WHERE series = $1 AND event = $2 AND racetime_id = $3) AS "exists!""#, data.series as _, &data.event, racetime.id).fetch_one(&mut **transaction).await? {
+            return Ok(Err(format!("You have been blocked from entering this event. Please contact the organizers if you believe this is an error.")))
+        }
+    }
+    if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM teams, team_members WHERE
+        id = team
+        AND series = $1
+        AND event = $2
+        AND member = $3
+        AND NOT resigned
+        AND NOT EXISTS (SELECT 1 FROM team_members WHERE team = id AND status = 'unconfirmed')
+    ) AS "exists!""#, data.series as _, &data.event, me.id as _).fetch_one(&mut **transaction).await? {
+        return Ok(Err(format!("You are already signed up for this event.")))
+    }
+    Ok(Ok((me, requirements)))
+}
+
+/// The modal asking the event's questions when the Discord signup button is clicked, if the event has any questions which can be answered via Discord.
+///
+/// Discord only allows showing a modal as the immediate response to the click, so this doesn't check anything else. All requirements are checked once the modal is submitted.
+pub(crate) fn discord_signup_modal(data: &Data<'_>) -> Option<CreateModal> {
+    let TeamConfig::Solo = data.team_config else { return None };
+    let Flow { ref requirements, .. } = data.enter_flow.as_ref()?;
+    let inputs = discord_inputs(requirements).filter(|inputs| !inputs.is_empty())?;
+    Some(CreateModal::new(format!("{DISCORD_MODAL_PREFIX}{}_{}", data.series.slug(), data.event), format!("Enter {}", data.display_name).chars().take(DISCORD_MAX_LABEL_LEN).collect::<String>())
+        .components(inputs.into_iter().map(|input| {
+            let mut text_input = CreateInputText::new(if input.long { InputTextStyle::Paragraph } else { InputTextStyle::Short }, input.label, input.field)
+                .required(input.required);
+            if !input.placeholder.is_empty() {
+                text_input = text_input.placeholder(input.placeholder.into_owned());
+            }
+            CreateActionRow::InputText(text_input)
+        }).collect())
+    )
+}
+
+/// Handles a click on the Discord signup button for an event which doesn't have a [`discord_signup_modal`].
+///
+/// The interaction should be deferred before calling this since the requirements are checked right away, which may take a while.
+pub(crate) async fn discord_button(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, config: &Config, discord_user: UserId, data: &Data<'_>) -> Result<DiscordEntry, Error> {
+    let enter_url = uri!(base_uri(), get(data.series, &*data.event, _, _));
+    let (me, requirements) = match discord_preconditions(&mut *transaction, discord_user, data).await? {
+        Ok(preconditions) => preconditions,
+        Err(msg) => return Ok(DiscordEntry::Unavailable(msg)),
+    };
+    if !discord_inputs(requirements).is_some_and(|inputs| inputs.is_empty()) {
+        return Ok(DiscordEntry::Unavailable(format!("This event can't be entered via Discord. Please enter on the website: <{enter_url}>")))
+    }
+    let value = discord_form_value(requirements, &HashMap::default());
+    for requirement in requirements {
+        if let Some(RequirementError { message, .. }) = requirement.check_value(&mut *transaction, http_client, discord_ctx, &me, data, &value, config).await? {
+            return Ok(DiscordEntry::Unavailable(format!("{message}\nOnce you've done this, click the button again or enter on the website: <{enter_url}>")))
+        }
+    }
+    let waitlisted = create_solo_signup(&mut *transaction, http_client, discord_ctx, config, &me, data, &value, None).await?;
+    Ok(DiscordEntry::Entered { waitlisted })
+}
+
+/// Handles a submitted Discord signup modal, validating the answers with the same rules as the website.
+///
+/// Returns an error message listing the requirements that aren't met, if any, or whether the user was added to the waitlist.
+pub(crate) async fn discord_modal(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, discord_ctx: &RwFuture<DiscordCtx>, config: &Config, discord_user: UserId, data: &Data<'_>, answers: &HashMap<String, String>) -> Result<Result<bool, String>, Error> {
+    let (me, requirements) = match discord_preconditions(&mut *transaction, discord_user, data).await? {
+        Ok(preconditions) => preconditions,
+        Err(msg) => return Ok(Err(msg)),
+    };
+    let value = discord_form_value(requirements, answers);
+    let mut errors = Vec::default();
+    let mut request_qualifier = None;
+    for requirement in requirements {
+        if let Some(RequirementError { message, .. }) = requirement.check_value(&mut *transaction, http_client, discord_ctx, &me, data, &value, config).await? {
+            errors.push(message);
+        }
+        if let Some(async_kind) = requirement.request_qualifier(&mut *transaction, http_client, discord_ctx, &me, data, config).await? {
+            request_qualifier = Some(async_kind);
+        }
+    }
+    if !errors.is_empty() {
+        return Ok(Err(errors.into_iter().map(|message| format!("• {message}")).join("\n")))
+    }
+    let waitlisted = create_solo_signup(&mut *transaction, http_client, discord_ctx, config, &me, data, &value, request_qualifier).await?;
+    Ok(Ok(waitlisted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discord_labels_are_plain_text() {
+        assert_eq!(discord_label(&RawHtml(format!("Your <a href=\"https://example.com\">Twitch</a> name"))), "Your Twitch name");
+        assert_eq!(discord_label(&RawHtml(format!("Q&amp;A"))), "Q&A");
+        assert_eq!(discord_label(&RawHtml("x".repeat(100))).chars().count(), DISCORD_MAX_LABEL_LEN);
+    }
+
+    #[test]
+    fn discord_answers_are_parsed() {
+        assert!(matches!(parse_discord_bool(" Yes "), Some(BoolRadio::Yes)));
+        assert!(matches!(parse_discord_bool("n"), Some(BoolRadio::No)));
+        assert!(parse_discord_bool("maybe").is_none());
+    }
+
+    fn dropdown_options() -> Vec<DropdownOption> {
+        vec![
+            DropdownOption { value: format!("eu"), label: format!("Europe") },
+            DropdownOption { value: format!("na"), label: format!("North America") },
+        ]
+    }
+
+    #[test]
+    fn discord_dropdown_answers_are_parsed() {
+        let options = dropdown_options();
+        assert_eq!(parse_discord_dropdown(&options, " NA ").map(|option| &*option.value), Some("na"));
+        assert_eq!(parse_discord_dropdown(&options, "europe").map(|option| &*option.value), Some("eu"));
+        assert!(parse_discord_dropdown(&options, "Asia").is_none());
+        assert!(discord_dropdown_placeholder(&options, false).starts_with("one of: Europe"));
+    }
+
+    #[test]
+    fn dropdown_labels_are_looked_up() {
+        let options = dropdown_options();
+        assert_eq!(dropdown_labels(&options, "na,eu").collect_vec(), ["North America", "Europe"]);
+        assert_eq!(dropdown_labels(&options, "removed").collect_vec(), ["removed"]);
+        assert!(dropdown_labels(&options, "").next().is_none());
+    }
+
+    #[test]
+    fn flow_problems_are_reported_per_requirement() {
+        let problems = validate_flow(r#"{"requirements": [{"type": "discord"}, {"type": "textField", "label": "Name", "regex": "(", "regexErrorMessages": {}, "fallbackErrorMessage": "?"}, {"type": "nope"}]}"#).unwrap_err();
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].starts_with("Requirement 2 (textField): "));
+        assert!(problems[1].starts_with("Requirement 3 (nope): "));
+        assert!(validate_flow("[]").is_err());
+        assert!(validate_flow("{").unwrap_err()[0].starts_with("Invalid JSON: "));
+    }
+
+    #[test]
+    fn flow_choice_keys_must_be_unique() {
+        let problems = validate_flow(r#"{"requirements": [
+            {"type": "booleanChoice", "key": "hard", "label": "Hard mode"},
+            {"type": "dropdown", "key": "hard", "label": "Region", "options": [{"value": "a,b", "label": "A"}, {"value": "a,b", "label": "B"}], "multiple": true}
+        ]}"#).unwrap_err();
+        assert_eq!(problems, [
+            "Requirement 2: the key “hard” is already used by an earlier requirement.",
+            "Requirement 2: the option value “a,b” must not contain a comma since multiple options can be selected.",
+            "Requirement 2: the option value “a,b” is used more than once.",
+        ]);
+        assert!(validate_flow(r#"{"requirements": [{"type": "rules"}], "closes": "2024-01-15T23:59:59Z"}"#).is_ok());
+    }
+}
@@ -0,0 +1,587 @@
+//! A page summarizing one entrant's qualifiers, asyncs, and races in an event.
+
+use {
+    noisy_float::prelude::*,
+    racetime::model::EntrantStatusValue,
+    crate::{
+        event::{
+            Data,
+            QualifierScoreHiding,
+            Tab,
+            async_verification::VerificationStatus,
+            teams::{
+                self,
+                Qualification,
+            },
+        },
+        prelude::*,
+        time::decode_pginterval,
+    },
+};
+
+/// An entrant's placement in a racetime.gg room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RoomResult {
+    /// `None` if the entrant did not finish (yet).
+    place: Option<usize>,
+    num_entrants: usize,
+    finish_time: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchResult {
+    Win,
+    Loss,
+}
+
+/// The 1-indexed place of `finish_time` among `finish_times`, with ties sharing the better place.
+fn place(finish_time: Duration, finish_times: &[Duration]) -> usize {
+    finish_times.iter().filter(|&&other| other < finish_time).count() + 1
+}
+
+/// Decides a match from the finish times of the entrant and their opponents, or `None` if nobody finished.
+fn match_result(finish_time: Option<Duration>, opponent_finish_times: &[Option<Duration>]) -> Option<MatchResult> {
+    let best_opponent = opponent_finish_times.iter().flatten().min();
+    match (finish_time, best_opponent) {
+        (None, None) => None,
+        (Some(_), None) => Some(MatchResult::Win),
+        (None, Some(_)) => Some(MatchResult::Loss),
+        (Some(finish_time), Some(&best_opponent)) => Some(if finish_time < best_opponent { MatchResult::Win } else { MatchResult::Loss }),
+    }
+}
+
+/// How an entrant can be recognized in racetime.gg room data.
+struct RaceTimeIdentity {
+    user_ids: Vec<String>,
+    team_slug: Option<String>,
+}
+
+impl RaceTimeIdentity {
+    async fn for_team(transaction: &mut Transaction<'_, Postgres>, team: &Team) -> sqlx::Result<Self> {
+        Ok(Self {
+            user_ids: team.members(&mut *transaction).await?.into_iter().filter_map(|member| member.racetime.map(|racetime| racetime.id)).collect(),
+            team_slug: team.racetime_slug.clone(),
+        })
+    }
+
+    async fn for_entrant(transaction: &mut Transaction<'_, Postgres>, entrant: &Entrant) -> sqlx::Result<Self> {
+        Ok(match entrant {
+            Entrant::MidosHouseTeam(team) => Self::for_team(transaction, team).await?,
+            Entrant::Discord { racetime_id, .. } | Entrant::Named { racetime_id, .. } => Self {
+                user_ids: racetime_id.iter().cloned().collect(),
+                team_slug: None,
+            },
+        })
+    }
+
+    async fn room_result(&self, cache: &mut teams::Cache, room: &Url) -> Result<Option<RoomResult>, cal::Error> {
+        if room.host_str() != Some(racetime_host()) { return Ok(None) }
+        let room_data = cache.race_data(room).await?;
+        if room_data.hide_entrants { return Ok(None) }
+        let finish_times = room_data.entrants.iter()
+            .filter(|entrant| entrant.status.value == EntrantStatusValue::Done)
+            .filter_map(|entrant| entrant.finish_time)
+            .collect_vec();
+        Ok(room_data.entrants.iter().find(|entrant| if let (Some(team), Some(team_slug)) = (&entrant.team, &self.team_slug) {
+            team.slug == *team_slug
+        } else {
+            entrant.user.as_ref().is_some_and(|user| self.user_ids.contains(&user.id))
+        }).map(|entrant| {
+            let finish_time = entrant.finish_time.filter(|_| entrant.status.value == EntrantStatusValue::Done);
+            RoomResult {
+                place: finish_time.map(|finish_time| place(finish_time, &finish_times)),
+                num_entrants: room_data.entrants.len(),
+                finish_time,
+            }
+        }))
+    }
+
+    /// The entrant's result across all rooms of a race, e.g. for asyncs where each entrant has their own room.
+    async fn race_result(&self, cache: &mut teams::Cache, race: &Race) -> Result<Option<RoomResult>, cal::Error> {
+        for room in race.rooms() {
+            if let Some(result) = self.room_result(cache, &room).await? {
+                return Ok(Some(result))
+            }
+        }
+        Ok(None)
+    }
+}
+
+struct QualifierRow {
+    source: teams::RoundSource,
+    score: R64,
+    finish_time: Option<Duration>,
+    par_time: Option<Duration>,
+    counted: bool,
+}
+
+struct AsyncPlayerRow {
+    player: User,
+    time: Option<Duration>,
+    vod: Option<String>,
+    status: VerificationStatus,
+}
+
+struct AsyncRow {
+    kind: AsyncKind,
+    submitted: Option<DateTime<Utc>>,
+    results_visible: bool,
+    players: Vec<AsyncPlayerRow>,
+}
+
+struct OpenRaceRow {
+    race: Race,
+    result: RoomResult,
+}
+
+struct MatchRow {
+    race: Race,
+    opponents: Vec<Entrant>,
+    result: Option<RoomResult>,
+    outcome: Option<MatchResult>,
+}
+
+pub(super) fn race_title(race: &Race) -> String {
+    if let Some(ref custom_title) = race.custom_title {
+        custom_title.clone()
+    } else {
+        let mut title = [race.phase.as_deref(), race.round.as_deref()].into_iter().flatten().join(" ");
+        if let Some(game) = race.game {
+            if !title.is_empty() {
+                title.push_str(", ");
+            }
+            title.push_str(&format!("game {game}"));
+        }
+        title
+    }
+}
+
+pub(super) fn race_start(race: &Race) -> Option<DateTime<Utc>> {
+    race.cal_events().filter_map(|event| event.start()).min()
+}
+
+fn room_links(race: &Race) -> RawHtml<String> {
+    html! {
+        div(class = "favicon-container") {
+            @for room in race.rooms() {
+                a(class = "favicon", title = "race room", href = room.to_string(), target = "_blank") : favicon(&room);
+            }
+            @for video_url in race.video_urls.values() {
+                a(class = "favicon", title = "restream", href = video_url.to_string(), target = "_blank") : favicon(video_url);
+            }
+        }
+    }
+}
+
+fn format_place(result: &RoomResult) -> String {
+    if let Some(place) = result.place {
+        format!("{place} of {}", result.num_entrants)
+    } else {
+        format!("DNF")
+    }
+}
+
+/// Shows an entrant's qualifier scores, async results, and races in an event.
+#[rocket::get("/event/<series>/<event>/teams/<team>")]
+pub(crate) async fn get(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: Option<User>, uri: Origin<'_>, series: Series, event: &str, team: Id<Teams>) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = Team::from_id(&mut transaction, team).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    if team.series != data.series || team.event != data.event { return Err(StatusOrError::Status(Status::NotFound)) }
+    let header = data.header(&mut transaction, me.as_ref(), Tab::Teams, true).await?;
+    let is_organizer = if let Some(ref me) = me {
+        me.is_global_admin() || data.organizers(&mut transaction).await?.contains(me)
+    } else {
+        false
+    };
+    let is_member = if let Some(ref me) = me {
+        team.member_ids(&mut transaction).await?.contains(&me.id)
+    } else {
+        false
+    };
+    let now = Utc::now();
+    let mut cache = teams::Cache::new(http_client.inner().clone());
+    let qualifier_kind = data.qualifier_kind(&mut transaction).await?;
+    let all_qualifiers_ended = teams::all_qualifiers_ended(&mut transaction, http_client, &data, qualifier_kind).await?;
+    let hide_counts = !is_organizer && !all_qualifiers_ended && matches!(data.qualifier_score_hiding, QualifierScoreHiding::FullPointsCounts | QualifierScoreHiding::FullComplete);
+    let hide_points = !is_organizer && !all_qualifiers_ended && matches!(data.qualifier_score_hiding, QualifierScoreHiding::FullPoints | QualifierScoreHiding::FullPointsCounts | QualifierScoreHiding::FullComplete);
+    // qualifier results
+    let qualifiers = if let QualifierKind::Score(scoring) = qualifier_kind {
+        let signups = teams::signups_sorted(&mut transaction, &mut cache, me.as_ref(), &data, is_organizer, qualifier_kind, None, all_qualifiers_ended, true).await?;
+        // opted-out entrants are listed without a rank
+        let mut rank = 0;
+        signups.into_iter().find_map(|signup| {
+            if !signup.is_opted_out {
+                rank += 1;
+            }
+            let Qualification::Multiple { num_entered, score, round_scores, .. } = signup.qualification else { return None };
+            signup.team.is_some_and(|signup_team| signup_team.id == team.id).then(|| {
+                let counted = scoring.counted(&round_scores.iter().map(|round| round.score).collect_vec());
+                let rows = round_scores.into_iter().zip_eq(counted).map(|(round, counted)| QualifierRow {
+                    source: round.source,
+                    score: round.score,
+                    finish_time: round.finish_time,
+                    par_time: round.par_time,
+                    counted,
+                }).collect_vec();
+                (scoring, rank, num_entered, score, signup.is_opted_out, rows)
+            })
+        })
+    } else {
+        None
+    };
+    // async results
+    let mut asyncs = Vec::default();
+    for row in sqlx::query!(r#"SELECT at.kind AS "kind: AsyncKind", at.submitted, a.end_time FROM async_teams at JOIN asyncs a ON a.series = $1 AND a.event = $2 AND a.kind = at.kind WHERE at.team = $3 AND at.requested IS NOT NULL ORDER BY at.kind"#, data.series as _, &data.event, team.id as _).fetch_all(&mut *transaction).await? {
+        let is_qualifier = matches!(row.kind, AsyncKind::Qualifier1 | AsyncKind::Qualifier2 | AsyncKind::Qualifier3);
+        let results_visible = is_organizer || is_member || !is_qualifier || match data.qualifier_score_hiding {
+            QualifierScoreHiding::None => true,
+            QualifierScoreHiding::AsyncOnly => all_qualifiers_ended || row.end_time.is_some_and(|end_time| end_time <= now),
+            QualifierScoreHiding::FullPoints | QualifierScoreHiding::FullPointsCounts | QualifierScoreHiding::FullComplete => all_qualifiers_ended,
+        };
+        let mut players = Vec::default();
+        for player_row in sqlx::query!(r#"SELECT ap.player AS "player: Id<Users>", ap.time, ap.vod, ap.verification_status AS "status: VerificationStatus" FROM async_players ap JOIN team_members tm ON tm.member = ap.player WHERE tm.team = $1 AND ap.series = $2 AND ap.event = $3 AND ap.kind = $4 ORDER BY tm.role"#, team.id as _, data.series as _, &data.event, row.kind as _).fetch_all(&mut *transaction).await? {
+            // unverified times are only shown to those who could already see them elsewhere
+            if player_row.status != VerificationStatus::Verified && !is_organizer && !is_member { continue }
+            players.push(AsyncPlayerRow {
+                player: User::from_id(&mut *transaction, player_row.player).await?.expect("async player not found"),
+                time: player_row.time.and_then(|time| decode_pginterval(time).ok()),
+                vod: player_row.vod,
+                status: player_row.status,
+            });
+        }
+        asyncs.push(AsyncRow { kind: row.kind, submitted: row.submitted, results_visible, players });
+    }
+    // races
+    let identity = RaceTimeIdentity::for_team(&mut transaction, &team).await?;
+    let mut races = Race::for_event(&mut transaction, http_client, &data).await?;
+    races.retain(|race| !race.ignored);
+    races.sort_by_key(|race| (race_start(race).is_none(), race_start(race)));
+    let mut open_races = Vec::default();
+    let mut matches = Vec::default();
+    for race in races {
+        let entrants = match race.entrants {
+            Entrants::Open | Entrants::Count { .. } | Entrants::Named(_) => {
+                if let Some(result) = identity.race_result(&mut cache, &race).await? {
+                    open_races.push(OpenRaceRow { race, result });
+                }
+                continue
+            }
+            Entrants::Two(ref entrants) => &entrants[..],
+            Entrants::Three(ref entrants) => &entrants[..],
+        };
+        if !race.teams().any(|race_team| race_team.id == team.id) { continue }
+        let opponents = entrants.iter().filter(|entrant| !matches!(entrant, Entrant::MidosHouseTeam(entrant_team) if entrant_team.id == team.id)).cloned().collect_vec();
+        let result = identity.race_result(&mut cache, &race).await?;
+        let outcome = if race.is_ended() {
+            let mut opponent_finish_times = Vec::with_capacity(opponents.len());
+            for opponent in &opponents {
+                opponent_finish_times.push(RaceTimeIdentity::for_entrant(&mut transaction, opponent).await?.race_result(&mut cache, &race).await?.and_then(|result| result.finish_time));
+            }
+            match_result(result.and_then(|result| result.finish_time), &opponent_finish_times)
+        } else {
+            None
+        };
+        matches.push(MatchRow { race, opponents, result, outcome });
+    }
+    let is_empty = qualifiers.is_none() && asyncs.is_empty() && open_races.is_empty() && matches.is_empty();
+    let draft_kind = data.draft_kind();
+    let show_rank = !qualifiers.as_ref().is_some_and(|(_, _, _, _, is_opted_out, _)| *is_opted_out) && (is_organizer || all_qualifiers_ended || data.qualifier_score_hiding == QualifierScoreHiding::None);
+    let player_colspan = if let TeamConfig::Solo = data.team_config { 2 } else { 3 };
+    let title = team.name(&mut transaction).await?.map(Cow::into_owned);
+    let content = html! {
+        : header;
+        h2 {
+            : team.to_html(&mut transaction, false).await?;
+        }
+        @if let Some((scoring, rank, num_entered, score, _, rows)) = qualifiers {
+            h3 : "Qualifiers";
+            @if hide_counts {
+                p : "Qualifier results are hidden until the qualifier stage has ended.";
+            } else {
+                p {
+                    @if show_rank {
+                        : "Rank ";
+                        : rank;
+                        : ", ";
+                    }
+                    : num_entered;
+                    : " qualifier";
+                    @if num_entered != 1 {
+                        : "s";
+                    }
+                    : " entered";
+                    @if !hide_points {
+                        : format!(", {score:.2} points");
+                    }
+                    : ".";
+                }
+                @if rows.is_empty() {
+                    p : "No qualifier results yet.";
+                } else {
+                    table {
+                        thead {
+                            tr {
+                                th : "Qualifier";
+                                th : "Time";
+                                th : "Par";
+                                th : "Points";
+                                th : "Counted";
+                            }
+                        }
+                        tbody {
+                            @for (idx, row) in rows.into_iter().enumerate() {
+                                tr(class? = (!row.counted && !hide_points).then_some("dimmed")) {
+                                    td : row.source.to_string();
+                                    @if hide_points {
+                                        td : "—";
+                                        td : "—";
+                                        td : "—";
+                                        td : "—";
+                                    } else {
+                                        td {
+                                            @if let Some(finish_time) = row.finish_time {
+                                                : English.format_duration(finish_time, false);
+                                            } else {
+                                                : "DNF";
+                                            }
+                                        }
+                                        td {
+                                            @if let Some(par_time) = row.par_time {
+                                                : English.format_duration(par_time, false);
+                                            } else {
+                                                : "—";
+                                            }
+                                        }
+                                        td {
+                                            @if row.score < 0.0 {
+                                                : "(pending)";
+                                            } else {
+                                                : format!("{:.0}", row.score);
+                                            }
+                                        }
+                                        td {
+                                            @if row.counted {
+                                                : "✓";
+                                            } else if idx >= scoring.max_qualifiers_that_count() {
+                                                : "over limit";
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        @if !asyncs.is_empty() {
+            h3 : "Asyncs";
+            table {
+                thead {
+                    tr {
+                        th : "Async";
+                        @if !matches!(data.team_config, TeamConfig::Solo) {
+                            th : "Player";
+                        }
+                        th : "Time";
+                        th : "VoD";
+                    }
+                }
+                tbody {
+                    @for row in asyncs {
+                        @if row.players.is_empty() {
+                            tr {
+                                td : row.kind.display_name();
+                                td(colspan = player_colspan) {
+                                    @if row.submitted.is_some() {
+                                        : "submitted";
+                                    } else {
+                                        : "requested";
+                                    }
+                                }
+                            }
+                        } else {
+                            @for player in row.players {
+                                tr {
+                                    td : row.kind.display_name();
+                                    @if !matches!(data.team_config, TeamConfig::Solo) {
+                                        td : player.player;
+                                    }
+                                    @if row.results_visible {
+                                        td {
+                                            @if let Some(time) = player.time {
+                                                : English.format_duration(time, false);
+                                            } else {
+                                                : "DNF";
+                                            }
+                                            @match player.status {
+                                                VerificationStatus::Verified => {}
+                                                VerificationStatus::Pending => : " (awaiting verification)";
+                                                VerificationStatus::Flagged => : " (flagged)";
+                                            }
+                                        }
+                                        td {
+                                            @if let Some(ref vod) = player.vod {
+                                                @if let Some(Ok(vod_url)) = (!vod.contains(' ')).then(|| Url::parse(vod)) {
+                                                    a(href = vod_url.to_string()) : "VoD";
+                                                } else {
+                                                    : vod;
+                                                }
+                                            }
+                                        }
+                                    } else {
+                                        td : "—";
+                                        td : "—";
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        @if !open_races.is_empty() {
+            h3 : "Races";
+            table {
+                thead {
+                    tr {
+                        th : "Start";
+                        th : "Race";
+                        th : "Placement";
+                        th : "Time";
+                        th;
+                    }
+                }
+                tbody {
+                    @for row in open_races {
+                        tr {
+                            td {
+                                @if let Some(start) = race_start(&row.race) {
+                                    : format_datetime(start, DateTimeFormat { long: false, running_text: false });
+                                }
+                            }
+                            td : race_title(&row.race);
+                            @if row.race.phase.as_ref().is_some_and(|phase| phase == "Qualifier") && hide_points {
+                                td : "—";
+                                td : "—";
+                            } else {
+                                td : format_place(&row.result);
+                                td {
+                                    @if let Some(finish_time) = row.result.finish_time {
+                                        : English.format_duration(finish_time, false);
+                                    }
+                                }
+                            }
+                            td : room_links(&row.race);
+                        }
+                    }
+                }
+            }
+        }
+        @if !matches.is_empty() {
+            h3 : "Matches";
+            table {
+                thead {
+                    tr {
+                        th : "Start";
+                        th : "Round";
+                        th : "Opponents";
+                        th : "Result";
+                        th : "Draft";
+                        th;
+                    }
+                }
+                tbody {
+                    @for row in matches {
+                        tr {
+                            td {
+                                @if let Some(start) = race_start(&row.race) {
+                                    : format_datetime(start, DateTimeFormat { long: false, running_text: false });
+                                } else {
+                                    : "(not yet scheduled)";
+                                }
+                            }
+                            td : race_title(&row.race);
+                            td {
+                                @for (idx, opponent) in row.opponents.iter().enumerate() {
+                                    @if idx > 0 {
+                                        br;
+                                    }
+                                    : opponent.to_html_with_optional_discord(&mut transaction, None, None, false).await?;
+                                }
+                            }
+                            td {
+                                @match row.outcome {
+                                    Some(MatchResult::Win) => : "Win";
+                                    Some(MatchResult::Loss) => : "Loss";
+                                    None => {
+                                        @if row.race.is_ended() {
+                                            : "—";
+                                        } else {
+                                            : "upcoming";
+                                        }
+                                    }
+                                }
+                                @if let Some(result) = row.result {
+                                    @if let Some(finish_time) = result.finish_time {
+                                        br;
+                                        small : English.format_duration(finish_time, false);
+                                    }
+                                }
+                            }
+                            td {
+                                @if let Some(ref draft) = row.race.draft {
+                                    @let game = row.race.game.unwrap_or(1);
+                                    @if draft.high_seed == team.id {
+                                        small : "high seed";
+                                        br;
+                                    }
+                                    @if let Some(mode) = draft.settings.get(&*format!("game{game}_preset")).and_then(|preset| draft_kind.as_ref().and_then(|kind| kind.preset_display_name(preset))) {
+                                        : mode;
+                                    } else {
+                                        @for (name, value) in draft.settings.iter().sorted() {
+                                            : format!("{name}: {value}");
+                                            br;
+                                        }
+                                    }
+                                }
+                            }
+                            td : room_links(&row.race);
+                        }
+                    }
+                }
+            }
+        }
+        @if is_empty {
+            p : "This entrant has no results in this event yet.";
+        }
+        @if is_organizer || is_member {
+            p {
+                a(href = uri!(super::substitutes::get(data.series, &*data.event, team.id)).to_string()) : "Manage alternates and substitutions";
+            }
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: data.chests().await?, ..PageStyle::default() }, &format!("{} — {}", title.as_deref().unwrap_or("Entrant"), data.display_name), content).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn places() {
+        let finish_times = [Duration::from_secs(60), Duration::from_secs(60), Duration::from_secs(90)];
+        assert_eq!(place(Duration::from_secs(60), &finish_times), 1);
+        assert_eq!(place(Duration::from_secs(90), &finish_times), 3);
+    }
+
+    #[test]
+    fn match_results() {
+        assert_eq!(match_result(Some(Duration::from_secs(60)), &[Some(Duration::from_secs(90))]), Some(MatchResult::Win));
+        assert_eq!(match_result(Some(Duration::from_secs(90)), &[None, Some(Duration::from_secs(60))]), Some(MatchResult::Loss));
+        assert_eq!(match_result(Some(Duration::from_secs(60)), &[None]), Some(MatchResult::Win));
+        assert_eq!(match_result(None, &[Some(Duration::from_secs(60))]), Some(MatchResult::Loss));
+        assert_eq!(match_result(None, &[None, None]), None);
+    }
+}
@@ -0,0 +1,729 @@
+//! Automatic import of race schedules which partner communities publish as iCalendar feeds.
+//!
+//! Each event whose matches are created manually can configure one feed URL along with regular expressions which extract the entrants, round, and restream URL
+//! from the summary and description of each event in the feed. Events whose summary doesn't match are ignored, so feeds can also contain other events.
+//! The import runs as part of the automatic race import. Races are identified by their event's UID and are updated when the feed changes,
+//! and ignored when their event is cancelled or removed, unless their schedule is locked or a room has been opened.
+//!
+//! Recurring events aren't expanded, only the first occurrence and any individually listed occurrences are imported.
+
+use {
+    chrono::LocalResult,
+    lazy_regex::Regex,
+    crate::{
+        cal::Source,
+        event::{
+            Data,
+            Series,
+            Tab,
+            race_upload::{
+                self,
+                EntrantRef,
+                ResolveError,
+            },
+        },
+        prelude::*,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum Error {
+    #[error(transparent)] Calendar(#[from] cal::Error),
+    #[error(transparent)] Feed(#[from] FeedError),
+    #[error(transparent)] Reqwest(#[from] reqwest::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+    #[error(transparent)] Url(#[from] url::ParseError),
+    #[error(transparent)] Wheel(#[from] wheel::Error),
+    #[error("invalid pattern in iCalendar import configuration: {0}")]
+    Pattern(String),
+}
+
+impl IsNetworkError for Error {
+    fn is_network_error(&self) -> bool {
+        match self {
+            Self::Calendar(e) => e.is_network_error(),
+            Self::Feed(_) => false,
+            Self::Reqwest(e) => e.is_network_error(),
+            Self::Sql(_) => false,
+            Self::Url(_) => false,
+            Self::Wheel(e) => e.is_network_error(),
+            Self::Pattern(_) => false,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum FeedError {
+    #[error("the feed is not an iCalendar file")]
+    NotCalendar,
+}
+
+pub(crate) struct Config {
+    url: Url,
+    summary_pattern: Regex,
+    description_pattern: Option<Regex>,
+}
+
+impl Config {
+    pub(crate) async fn for_event(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> Result<Option<Self>, Error> {
+        let Some(row) = sqlx::query!("SELECT url, summary_pattern, description_pattern FROM ics_imports WHERE series = $1 AND event = $2", series as _, event).fetch_optional(&mut **transaction).await? else { return Ok(None) };
+        Ok(Some(Self {
+            url: row.url.parse()?,
+            summary_pattern: Regex::new(&row.summary_pattern).map_err(|e| Error::Pattern(e.to_string()))?,
+            description_pattern: row.description_pattern.map(|pattern| Regex::new(&pattern)).transpose().map_err(|e| Error::Pattern(e.to_string()))?,
+        }))
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct VEvent {
+    uid: Option<String>,
+    recurrence_id: Option<String>,
+    summary: String,
+    description: String,
+    start: Option<DateProperty>,
+    cancelled: bool,
+}
+
+impl VEvent {
+    /// Identifies this event across imports. Individually listed occurrences of a recurring event share its UID but have distinct recurrence IDs.
+    fn key(&self) -> Option<String> {
+        let uid = self.uid.as_ref()?;
+        Some(if let Some(ref recurrence_id) = self.recurrence_id {
+            format!("{uid}/{recurrence_id}")
+        } else {
+            uid.clone()
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct DateProperty {
+    value: String,
+    tzid: Option<String>,
+    date_only: bool,
+}
+
+/// Joins folded content lines, see [RFC 5545 section 3.1](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1).
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines = Vec::<String>::default();
+    for line in text.lines() {
+        if let Some(continuation) = line.strip_prefix([' ', '\t']) && let Some(last) = lines.last_mut() {
+            last.push_str(continuation);
+        } else if !line.is_empty() {
+            lines.push(line.to_owned());
+        }
+    }
+    lines
+}
+
+struct ContentLine<'a> {
+    name: String,
+    params: Vec<(String, String)>,
+    value: &'a str,
+}
+
+fn parse_content_line(line: &str) -> Option<ContentLine<'_>> {
+    let mut in_quotes = false;
+    let (colon, _) = line.char_indices().find(|&(_, c)| {
+        if c == '"' { in_quotes = !in_quotes }
+        c == ':' && !in_quotes
+    })?;
+    let mut parts = line[..colon].split(';');
+    let name = parts.next()?.to_ascii_uppercase();
+    let params = parts.filter_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        Some((name.to_ascii_uppercase(), value.trim_matches('"').to_owned()))
+    }).collect();
+    Some(ContentLine { name, params, value: &line[colon + 1..] })
+}
+
+fn unescape_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n' | 'N') => text.push('\n'),
+                Some(c) => text.push(c),
+                None => text.push('\\'),
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    text
+}
+
+/// Reads the `VEVENT` components of an iCalendar file, skipping any components nested inside them (such as alarms).
+fn parse_calendar(text: &str) -> Result<Vec<VEvent>, FeedError> {
+    let lines = unfold(text.trim_start_matches('\u{feff}'));
+    if !lines.first().is_some_and(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR")) {
+        return Err(FeedError::NotCalendar)
+    }
+    let mut events = Vec::default();
+    let mut current = None::<VEvent>;
+    let mut depth = 0;
+    for line in &lines {
+        let Some(ContentLine { name, params, value }) = parse_content_line(line) else { continue };
+        if current.is_none() {
+            if name == "BEGIN" && value.eq_ignore_ascii_case("VEVENT") {
+                current = Some(VEvent::default());
+            }
+            continue
+        }
+        let event = current.as_mut().expect("checked above");
+        match &*name {
+            "BEGIN" => depth += 1,
+            "END" => if depth > 0 {
+                depth -= 1;
+            } else {
+                events.extend(current.take());
+            },
+            _ if depth > 0 => {}
+            "UID" => event.uid = Some(value.to_owned()),
+            "RECURRENCE-ID" => event.recurrence_id = Some(value.to_owned()),
+            "SUMMARY" => event.summary = unescape_text(value),
+            "DESCRIPTION" => event.description = unescape_text(value),
+            "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            "DTSTART" => {
+                let param = |name: &str| params.iter().find(|(param_name, _)| param_name == name).map(|(_, value)| value.clone());
+                event.start = Some(DateProperty {
+                    value: value.to_owned(),
+                    date_only: param("VALUE").is_some_and(|value| value.eq_ignore_ascii_case("DATE")) || value.len() == 8,
+                    tzid: param("TZID"),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(events)
+}
+
+#[derive(Debug, thiserror::Error)]
+enum EntryError {
+    #[error(transparent)] Entrant(#[from] race_upload::RowError),
+    #[error(transparent)] Url(#[from] url::ParseError),
+    #[error("This event has no UID.")]
+    MissingUid,
+    #[error("There is another event with the same UID.")]
+    DuplicateUid,
+    #[error("“{0}” is not a valid start time.")]
+    StartFormat(String),
+    #[error("Unknown timezone “{0}”.")]
+    Timezone(String),
+    #[error("{0} does not exist in the event's timezone.")]
+    NonexistentLocalTime(String),
+}
+
+/// Converts an event's start to UTC. All-day events have no start time, and floating times are interpreted as UTC.
+fn parse_start(start: &DateProperty) -> Result<Option<DateTime<Utc>>, EntryError> {
+    if start.date_only { return Ok(None) }
+    let (value, is_utc) = if let Some(value) = start.value.strip_suffix('Z') { (value, true) } else { (&*start.value, false) };
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| EntryError::StartFormat(start.value.clone()))?;
+    Ok(Some(match (is_utc, &start.tzid) {
+        (false, Some(tzid)) => {
+            let timezone = tzid.parse::<Tz>().map_err(|_| EntryError::Timezone(tzid.clone()))?;
+            match timezone.from_local_datetime(&naive) {
+                LocalResult::Single(start) | LocalResult::Ambiguous(start, _) => start.with_timezone(&Utc),
+                LocalResult::None => return Err(EntryError::NonexistentLocalTime(start.value.clone())),
+            }
+        }
+        (true, _) | (false, None) => naive.and_utc(),
+    }))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Entry {
+    key: String,
+    entrants: Vec<EntrantRef>,
+    round: Option<String>,
+    start: Option<DateTime<Utc>>,
+    restream: Option<Url>,
+    cancelled: bool,
+}
+
+/// Maps an event from the feed to a race, or returns `Ok(None)` if the event's summary doesn't match the configured pattern.
+fn parse_entry(config: &Config, event: &VEvent) -> Result<Option<Entry>, EntryError> {
+    let Some(summary) = config.summary_pattern.captures(&event.summary) else { return Ok(None) };
+    let description = config.description_pattern.as_ref().and_then(|pattern| pattern.captures(&event.description));
+    let field = |name: &str| summary.name(name)
+        .or_else(|| description.as_ref().and_then(|description| description.name(name)))
+        .map(|value| value.as_str().trim())
+        .filter(|value| !value.is_empty());
+    let key = event.key().ok_or(EntryError::MissingUid)?;
+    let entrants = ["entrant1", "entrant2", "entrant3"].into_iter()
+        .filter_map(|name| field(name))
+        .map(EntrantRef::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if entrants.len() < 2 {
+        return Err(race_upload::RowError::NotEnoughEntrants.into())
+    }
+    Ok(Some(Entry {
+        round: field("round").map(str::to_owned),
+        start: event.start.as_ref().map(parse_start).transpose()?.flatten(),
+        restream: field("restream").map(Url::parse).transpose()?,
+        cancelled: event.cancelled,
+        key, entrants,
+    }))
+}
+
+/// Maps the events from the feed to races, each along with its summary.
+fn parse_entries<'a>(config: &Config, events: &'a [VEvent]) -> Vec<(&'a str, Result<Entry, EntryError>)> {
+    let mut keys = HashSet::new();
+    events.iter().filter_map(|event| {
+        let entry = parse_entry(config, event).transpose()?
+            .and_then(|entry| if keys.insert(entry.key.clone()) { Ok(entry) } else { Err(EntryError::DuplicateUid) });
+        Some((&*event.summary, entry))
+    }).collect()
+}
+
+async fn fetch_events(http_client: &reqwest::Client, config: &Config) -> Result<Vec<VEvent>, Error> {
+    let text = http_client.get(config.url.clone())
+        .send().await?
+        .detailed_error_for_status().await?
+        .text().await?;
+    Ok(parse_calendar(&text)?)
+}
+
+struct PreviewRow {
+    summary: String,
+    entry: Result<(Entry, Vec<Result<Team, ResolveError>>), EntryError>,
+    existing: Option<Id<Races>>,
+}
+
+impl PreviewRow {
+    fn teams(&self) -> Option<Vec<Team>> {
+        let (_, teams) = self.entry.as_ref().ok()?;
+        teams.iter().map(|team| team.as_ref().ok().cloned()).collect()
+    }
+}
+
+struct Preview {
+    rows: Vec<PreviewRow>,
+    /// Races which were imported earlier but whose events are no longer in the feed.
+    removed: Vec<Id<Races>>,
+}
+
+async fn preview(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &Data<'_>, config: &Config) -> Result<Preview, Error> {
+    let events = fetch_events(http_client, config).await?;
+    let team_names = race_upload::team_names(transaction, event).await?;
+    let existing = sqlx::query!(r#"SELECT id AS "id: Id<Races>", ics_uid AS "ics_uid!" FROM races WHERE series = $1 AND event = $2 AND ics_uid IS NOT NULL"#, event.series as _, &event.event)
+        .fetch_all(&mut **transaction).await?
+        .into_iter()
+        .map(|row| (row.ics_uid, row.id))
+        .collect::<HashMap<_, _>>();
+    let mut rows = Vec::default();
+    for (summary, entry) in parse_entries(config, &events) {
+        let entry = match entry {
+            Ok(entry) => {
+                let teams = race_upload::resolve_entrants(transaction, event, &team_names, &entry.entrants).await?;
+                Ok((entry, teams))
+            }
+            Err(e) => Err(e),
+        };
+        let existing = entry.as_ref().ok().and_then(|(entry, _)| existing.get(&entry.key).copied());
+        rows.push(PreviewRow { summary: summary.to_owned(), entry, existing });
+    }
+    // an empty feed is more likely to be a temporary problem on the publisher's end than an intentionally cleared schedule
+    let removed = if events.is_empty() {
+        Vec::default()
+    } else {
+        let feed_keys = events.iter().filter_map(VEvent::key).collect::<HashSet<_>>();
+        existing.into_iter().filter(|(key, _)| !feed_keys.contains(key)).map(|(_, id)| id).collect()
+    };
+    Ok(Preview { rows, removed })
+}
+
+/// Updates races which were previously imported from the event's feed, and returns the races which are new.
+///
+/// The new races should be created using [`cal::import_race`] so they get a scheduling thread. Events with errors are skipped; they're listed on the preview page.
+pub(crate) async fn races_to_import(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &Data<'_>, config: &Config) -> Result<Vec<Race>, Error> {
+    let Preview { rows, removed } = preview(transaction, http_client, event, config).await?;
+    let mut races = Vec::default();
+    for row in rows {
+        let Some(teams) = row.teams() else { continue };
+        let Ok((entry, _)) = row.entry else { continue };
+        let video_urls = entry.restream.map(|url| iter::once((English, url)).collect()).unwrap_or_default();
+        if let Some(id) = row.existing {
+            let mut race = Race::from_id(transaction, http_client, id).await?;
+            if race.schedule_locked || race.has_any_room() { continue } // stop automatically updating once a room is open
+            let mut changed = if entry.cancelled {
+                false
+            } else {
+                cal::update_imported_race(transaction, http_client, event, &mut race, teams, entry.round, entry.start, video_urls).await?
+            };
+            if race.ignored != entry.cancelled {
+                race.ignored = entry.cancelled;
+                changed = true;
+            }
+            if changed {
+                race.save(transaction).await?;
+            }
+        } else if !entry.cancelled {
+            races.push(cal::new_imported_race(transaction, http_client, event, Source::Ics { uid: entry.key }, teams, entry.round, entry.start, video_urls).await?);
+        }
+    }
+    for id in removed {
+        let mut race = Race::from_id(transaction, http_client, id).await?;
+        if race.ignored || race.schedule_locked || race.has_any_room() { continue }
+        race.ignored = true;
+        race.save(transaction).await?;
+    }
+    Ok(races)
+}
+
+enum IcsImportFormDefaults<'v> {
+    None,
+    SaveContext(Context<'v>),
+    DeleteContext(Context<'v>),
+}
+
+impl<'v> IcsImportFormDefaults<'v> {
+    fn save_errors(&self) -> Vec<&form::Error<'v>> {
+        if let Self::SaveContext(ctx) = self {
+            ctx.errors().collect()
+        } else {
+            Vec::default()
+        }
+    }
+
+    fn delete_errors(&self) -> Vec<&form::Error<'v>> {
+        if let Self::DeleteContext(ctx) = self {
+            ctx.errors().collect()
+        } else {
+            Vec::default()
+        }
+    }
+
+    fn save_value(&self, name: &str) -> Option<&str> {
+        if let Self::SaveContext(ctx) = self {
+            ctx.field_value(name)
+        } else {
+            None
+        }
+    }
+}
+
+async fn ics_import_page(mut transaction: Transaction<'_, Postgres>, http_client: &reqwest::Client, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, defaults: IcsImportFormDefaults<'_>) -> Result<RawHtml<String>, event::Error> {
+    let header = event.header(&mut transaction, me.as_ref(), Tab::Races, true).await?;
+    let is_organizer = if let Some(ref me) = me {
+        me.is_global_admin() || event.organizers(&mut transaction).await?.contains(me)
+    } else {
+        false
+    };
+    let content = if is_organizer {
+        let stored = sqlx::query!("SELECT url, summary_pattern, description_pattern FROM ics_imports WHERE series = $1 AND event = $2", event.series as _, &event.event).fetch_optional(&mut *transaction).await?;
+        let preview_html = match Config::for_event(&mut transaction, event.series, &event.event).await {
+            Ok(None) => html! {},
+            Ok(Some(ref config)) => match preview(&mut transaction, http_client, &event, config).await {
+                Ok(Preview { rows, removed }) => html! {
+                    h3 : "Preview";
+                    @if !event.auto_import {
+                        p : "Automatic imports are disabled for this event, so these races won't be imported until they are enabled.";
+                    }
+                    table {
+                        thead {
+                            tr {
+                                th : "Event";
+                                th : "Entrants";
+                                th : "Round";
+                                th : "Start";
+                                th : "Restream";
+                                th : "Status";
+                            }
+                        }
+                        tbody {
+                            @if rows.is_empty() {
+                                tr {
+                                    td(colspan = "6") {
+                                        i : "No events in the feed match the summary pattern.";
+                                    }
+                                }
+                            }
+                            @for row in &rows {
+                                tr {
+                                    td : &*row.summary;
+                                    @match row.entry {
+                                        Ok((ref entry, ref teams)) => {
+                                            td {
+                                                ul {
+                                                    @for (entrant, team) in entry.entrants.iter().zip(teams) {
+                                                        li {
+                                                            @match team {
+                                                                Ok(team) => {
+                                                                    : team.to_html(&mut transaction, false).await?;
+                                                                }
+                                                                Err(e) => {
+                                                                    bdi : entrant.to_string();
+                                                                    : " — ";
+                                                                    span(class = "error") : e.to_string();
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            td : entry.round.as_deref().unwrap_or("—");
+                                            td {
+                                                @if let Some(start) = entry.start {
+                                                    : format_datetime(start, DateTimeFormat { long: false, running_text: false });
+                                                } else {
+                                                    : "unscheduled";
+                                                }
+                                            }
+                                            td {
+                                                @if let Some(ref restream) = entry.restream {
+                                                    a(href = restream.to_string()) : restream.to_string();
+                                                } else {
+                                                    : "—";
+                                                }
+                                            }
+                                        }
+                                        Err(_) => {
+                                            td(colspan = "4");
+                                        }
+                                    }
+                                    td {
+                                        @if let Err(ref e) = row.entry {
+                                            span(class = "error") : e.to_string();
+                                        } else if row.teams().is_none() {
+                                            span(class = "error") : "Invalid entrants";
+                                        } else if let Some(id) = row.existing {
+                                            a(href = uri!(cal::edit_race(event.series, &*event.event, id, Some(uri!(get(event.series, &*event.event)))))) : "Imported";
+                                            @if row.entry.as_ref().is_ok_and(|(entry, _)| entry.cancelled) {
+                                                : " (cancelled)";
+                                            }
+                                        } else if row.entry.as_ref().is_ok_and(|(entry, _)| entry.cancelled) {
+                                            : "Cancelled";
+                                        } else {
+                                            : "New";
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    @if !removed.is_empty() {
+                        p {
+                            @if removed.len() == 1 {
+                                : "1 previously imported race is no longer in the feed and will be ignored unless its room is already open.";
+                            } else {
+                                : removed.len().to_string();
+                                : " previously imported races are no longer in the feed and will be ignored unless their rooms are already open.";
+                            }
+                        }
+                    }
+                },
+                Err(Error::Sql(e)) => return Err(e.into()),
+                Err(e) => html! {
+                    p(class = "error") {
+                        : "Failed to load the feed: ";
+                        : e.to_string();
+                    }
+                },
+            },
+            Err(Error::Sql(e)) => return Err(e.into()),
+            Err(e) => html! {
+                p(class = "error") : e.to_string();
+            },
+        };
+        let value = |name: &str, current: Option<String>| defaults.save_value(name).map(str::to_owned).or(current);
+        let mut errors = defaults.save_errors();
+        html! {
+            p {
+                : "Races can be imported from an iCalendar feed. The summary of each event in the feed is matched against a regular expression, and events which don't match are skipped. Use the named groups ";
+                code : "entrant1";
+                : ", ";
+                code : "entrant2";
+                : ", and optionally ";
+                code : "entrant3";
+                : ", ";
+                code : "round";
+                : ", and ";
+                code : "restream";
+                : " to extract race data. The description can be matched against a second regular expression using the same group names. Entrants can be given in any of the ways supported for ";
+                a(href = uri!(race_upload::get(event.series, &*event.event)).to_string()) : "uploading races";
+                : ".";
+            }
+            : full_form(uri!(post(event.series, &*event.event)), csrf, html! {
+                : form_field("url", &mut errors, html! {
+                    label(for = "url") : "Feed URL:";
+                    input(type = "text", name = "url", value? = value("url", stored.as_ref().map(|stored| stored.url.clone())));
+                });
+                : form_field("summary_pattern", &mut errors, html! {
+                    label(for = "summary_pattern") : "Summary pattern:";
+                    input(type = "text", name = "summary_pattern", value = value("summary_pattern", stored.as_ref().map(|stored| stored.summary_pattern.clone())).unwrap_or_else(|| format!("^(?:(?P<round>[^:]+): )?(?P<entrant1>.+?) vs\\.? (?P<entrant2>.+)$")));
+                });
+                : form_field("description_pattern", &mut errors, html! {
+                    label(for = "description_pattern") : "Description pattern:";
+                    input(type = "text", name = "description_pattern", value? = value("description_pattern", stored.as_ref().and_then(|stored| stored.description_pattern.clone())));
+                    label(class = "help") : " (optional, e.g. (?P<restream>https://twitch\\.tv/\\S+))";
+                });
+            }, errors, "Save");
+            @if stored.is_some() {
+                @let (errors, button) = button_form(uri!(delete(event.series, &*event.event)), csrf, defaults.delete_errors(), "Remove iCalendar Import");
+                : errors;
+                div(class = "button-row") : button;
+            }
+            : preview_html;
+        }
+    } else {
+        html! {
+            article {
+                p : "This page is only available for event organizers.";
+            }
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("iCalendar Import — {}", event.display_name), html! {
+        : header;
+        h2 : "iCalendar import";
+        : content;
+    }).await?)
+}
+
+#[rocket::get("/event/<series>/<event>/races/ics")]
+pub(crate) async fn get(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: Option<User>, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok(ics_import_page(transaction, http_client, me, uri, csrf.as_ref(), event, IcsImportFormDefaults::None).await?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct IcsImportForm {
+    #[field(default = String::new())]
+    csrf: String,
+    url: String,
+    summary_pattern: String,
+    description_pattern: String,
+}
+
+#[rocket::post("/event/<series>/<event>/races/ics", data = "<form>")]
+pub(crate) async fn post(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, IcsImportForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
+        }
+        if !matches!(event.match_source(), MatchSource::Manual) {
+            form.context.push_error(form::Error::validation("Races can only be imported from an iCalendar feed for events whose matches are created manually."));
+        }
+        let url = value.url.trim();
+        // webcal is the same protocol as HTTPS, it's just used to get calendar apps to subscribe to the feed
+        let url = if let Some(rest) = url.strip_prefix("webcal://") { format!("https://{rest}") } else { url.to_owned() };
+        if !Url::parse(&url).is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+            form.context.push_error(form::Error::validation("Please enter a valid http(s) or webcal URL.").with_name("url"));
+        }
+        match Regex::new(&value.summary_pattern) {
+            Ok(pattern) => for group in ["entrant1", "entrant2"] {
+                if !pattern.capture_names().flatten().any(|name| name == group) {
+                    form.context.push_error(form::Error::validation(format!("The pattern must have a group named {group}, e.g. (?P<{group}>.+)")).with_name("summary_pattern"));
+                }
+            },
+            Err(e) => form.context.push_error(form::Error::validation(format!("Invalid regular expression: {e}")).with_name("summary_pattern")),
+        }
+        let description_pattern = Some(value.description_pattern.trim()).filter(|pattern| !pattern.is_empty());
+        if let Some(pattern) = description_pattern && let Err(e) = Regex::new(pattern) {
+            form.context.push_error(form::Error::validation(format!("Invalid regular expression: {e}")).with_name("description_pattern"));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(ics_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, IcsImportFormDefaults::SaveContext(form.context)).await?)
+        } else {
+            sqlx::query!("
+                INSERT INTO ics_imports (series, event, url, summary_pattern, description_pattern) VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (series, event) DO UPDATE SET (url, summary_pattern, description_pattern) = (EXCLUDED.url, EXCLUDED.summary_pattern, EXCLUDED.description_pattern)
+            ", event.series as _, &event.event, url, value.summary_pattern, description_pattern).execute(&mut *transaction).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        }
+    } else {
+        RedirectOrContent::Content(ics_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, IcsImportFormDefaults::SaveContext(form.context)).await?)
+    })
+}
+
+#[rocket::post("/event/<series>/<event>/races/ics/delete", data = "<form>")]
+pub(crate) async fn delete(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if form.value.is_some() {
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(ics_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, IcsImportFormDefaults::DeleteContext(form.context)).await?)
+        } else {
+            sqlx::query!("DELETE FROM ics_imports WHERE series = $1 AND event = $2", event.series as _, &event.event).execute(&mut *transaction).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        }
+    } else {
+        RedirectOrContent::Content(ics_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, IcsImportFormDefaults::DeleteContext(form.context)).await?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\nUID:match-1@example.com\r\nDTSTART;TZID=Europe/Berlin:20260701T200000\r\nSUMMARY:Quarterfinal: Alpha vs. B\r\n eta\r\nDESCRIPTION:Restream: https://twitch.tv/example\\nCommentary: tbd\r\nBEGIN:VALARM\r\nDESCRIPTION:reminder\r\nEND:VALARM\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:match-2@example.com\r\nDTSTART:20260702T180000Z\r\nSTATUS:CANCELLED\r\nSUMMARY:hth:42 vs discord:1234\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:meeting@example.com\r\nDTSTART;VALUE=DATE:20260703\r\nSUMMARY:Organizer meeting\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+
+    fn config() -> Config {
+        Config {
+            url: Url::parse("https://example.com/schedule.ics").unwrap(),
+            summary_pattern: Regex::new(r"^(?:(?P<round>[^:]+): )?(?P<entrant1>.+?) vs\.? (?P<entrant2>.+)$").unwrap(),
+            description_pattern: Some(Regex::new(r"Restream: (?P<restream>\S+)").unwrap()),
+        }
+    }
+
+    #[test]
+    fn parses_feed() {
+        let events = parse_calendar(FEED).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].summary, "Quarterfinal: Alpha vs. Beta");
+        assert_eq!(events[0].description, "Restream: https://twitch.tv/example\nCommentary: tbd");
+        assert_eq!(events[0].start, Some(DateProperty { value: format!("20260701T200000"), tzid: Some(format!("Europe/Berlin")), date_only: false }));
+        assert!(events[1].cancelled);
+        assert!(events[2].start.as_ref().unwrap().date_only);
+        assert!(matches!(parse_calendar("<html>"), Err(FeedError::NotCalendar)));
+    }
+
+    #[test]
+    fn maps_events_to_races() {
+        let events = parse_calendar(FEED).unwrap();
+        let entries = parse_entries(&config(), &events);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(*entries[0].1.as_ref().unwrap(), Entry {
+            key: format!("match-1@example.com"),
+            entrants: vec![EntrantRef::Any(format!("Alpha")), EntrantRef::Any(format!("Beta"))],
+            round: Some(format!("Quarterfinal")),
+            start: Some(Utc.with_ymd_and_hms(2026, 7, 1, 18, 0, 0).unwrap()),
+            restream: Some(Url::parse("https://twitch.tv/example").unwrap()),
+            cancelled: false,
+        });
+        let cancelled = entries[1].1.as_ref().unwrap();
+        assert_eq!(cancelled.entrants, vec![EntrantRef::User(Id::from(42u64)), EntrantRef::Discord(UserId::new(1234))]);
+        assert_eq!(cancelled.start, Some(Utc.with_ymd_and_hms(2026, 7, 2, 18, 0, 0).unwrap()));
+        assert!(cancelled.cancelled);
+    }
+
+    #[test]
+    fn reports_entry_errors() {
+        let event = |uid: Option<&str>, start: &str, tzid: Option<&str>| VEvent {
+            uid: uid.map(str::to_owned),
+            summary: format!("A vs B"),
+            start: Some(DateProperty { value: start.to_owned(), tzid: tzid.map(str::to_owned), date_only: false }),
+            ..VEvent::default()
+        };
+        assert!(matches!(parse_entry(&config(), &event(None, "20260701T200000Z", None)), Err(EntryError::MissingUid)));
+        assert!(matches!(parse_entry(&config(), &event(Some("x"), "tomorrow", None)), Err(EntryError::StartFormat(_))));
+        assert!(matches!(parse_entry(&config(), &event(Some("x"), "20260701T200000", Some("W. Europe Standard Time"))), Err(EntryError::Timezone(_))));
+        assert!(matches!(parse_entry(&config(), &event(Some("x"), "20260329T023000", Some("Europe/Berlin"))), Err(EntryError::NonexistentLocalTime(_))));
+        let events = [event(Some("x"), "20260701T200000Z", None), event(Some("x"), "20260702T200000Z", None)];
+        assert!(matches!(parse_entries(&config(), &events)[1].1, Err(EntryError::DuplicateUid)));
+    }
+}
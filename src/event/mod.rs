@@ -18,7 +18,9 @@ use {
 };
 
 pub(crate) mod async_results;
+pub(crate) mod bracket;
 pub(crate) mod configure;
+pub(crate) mod swiss;
 
 pub(crate) type PracticeSeeds = Arc<tokio::sync::RwLock<HashMap<Uuid, PracticeSeedStatus>>>;
 
@@ -35,11 +37,24 @@ pub(crate) enum PracticeSeedStatus {
 }
 pub(crate) mod enter;
 pub(crate) mod setup;
+pub(crate) mod stages;
 pub(crate) mod teams;
+pub(crate) mod web_async;
 pub(crate) mod roles;
+pub(crate) mod async_verification;
 pub(crate) mod asyncs;
+pub(crate) mod entrant;
+pub(crate) mod qualifier_projection;
+pub(crate) mod qualifier_scoring;
 pub(crate) mod qualifiers;
+pub(crate) mod ics_import;
+pub(crate) mod race_list;
+pub(crate) mod race_upload;
+pub(crate) mod sheet_import;
 pub(crate) mod speedgaming_export;
+pub(crate) mod standings;
+pub(crate) mod substitutes;
+pub(crate) mod waitlist;
 pub(crate) mod zsr_export;
 
 #[derive(Debug, Clone, Copy, sqlx::Type)]
@@ -106,6 +121,8 @@ pub(crate) enum MatchSource<'a> {
     },
     League,
     StartGG(&'a str),
+    /// Matches are generated by Hyrule Town Hall's own bracket engine, see the [`bracket`] module.
+    Bracket,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
@@ -200,6 +217,7 @@ pub(crate) struct Data<'a> {
     pub(crate) discord_scheduling_channel: Option<ChannelId>,
     pub(crate) discord_volunteer_info_channel: Option<ChannelId>,
     pub(crate) discord_async_channel: Option<ChannelId>,
+    pub(crate) discord_standings_channel: Option<ChannelId>,
     pub(crate) rando_version: Option<VersionedBranch>,
     pub(crate) settings_string: Option<String>,
     pub(crate) single_settings: Option<seed::Settings>,
@@ -227,12 +245,19 @@ pub(crate) struct Data<'a> {
     pub(crate) listed: bool,
     pub(crate) round_modes: Option<HashMap<String, String>>,
     pub(crate) automated_asyncs: bool,
+    pub(crate) async_verification: bool,
     pub(crate) volunteer_requests_enabled: bool,
     pub(crate) volunteer_request_lead_time_hours: i32,
     pub(crate) force_custom_role_binding: bool,
     pub(crate) qualifier_score_hiding: QualifierScoreHiding,
+    pub(crate) qualifier_scoring: Option<qualifier_scoring::QualifierScoring>,
+    pub(crate) qualifier_tiebreakers: Vec<qualifier_scoring::QualifierTiebreaker>,
+    pub(crate) qualifier_cutoff: Option<i32>,
     pub(crate) qualifier_notification_role_id: Option<RoleId>,
     pub(crate) async_start_delay: Option<i32>,
+    pub(crate) native_bracket: bool,
+    pub(crate) max_entrants: Option<i32>,
+    pub(crate) waitlist_confirm_hours: i32,
 }
 
 #[derive(Debug, thiserror::Error, rocket_util::Error)]
@@ -276,6 +301,7 @@ impl<'a> Data<'a> {
             discord_scheduling_channel AS "discord_scheduling_channel: PgSnowflake<ChannelId>",
             discord_volunteer_info_channel AS "discord_volunteer_info_channel: PgSnowflake<ChannelId>",
             discord_async_channel AS "discord_async_channel: PgSnowflake<ChannelId>",
+            discord_standings_channel AS "discord_standings_channel: PgSnowflake<ChannelId>",
             rando_version AS "rando_version: Json<VersionedBranch>",
             settings_string,
             single_settings AS "single_settings: Json<seed::Settings>",
@@ -303,12 +329,19 @@ impl<'a> Data<'a> {
             listed,
             round_modes AS "round_modes: Json<HashMap<String, String>>",
             automated_asyncs,
+            async_verification,
             volunteer_requests_enabled,
             volunteer_request_lead_time_hours,
             force_custom_role_binding,
             qualifier_score_hiding AS "qualifier_score_hiding: QualifierScoreHiding",
+            qualifier_scoring AS "qualifier_scoring: Json<qualifier_scoring::QualifierScoring>",
+            qualifier_tiebreakers AS "qualifier_tiebreakers: Json<Vec<qualifier_scoring::QualifierTiebreaker>>",
+            qualifier_cutoff,
             qualifier_notification_role_id,
-            async_start_delay
+            async_start_delay,
+            native_bracket,
+            max_entrants,
+            waitlist_confirm_hours
         FROM events WHERE series = $1 AND event = $2"#, series as _, &event).fetch_optional(&mut **transaction).await?
             .map(|row| Ok::<_, DataError>(Self {
                 display_name: row.display_name,
@@ -331,6 +364,7 @@ impl<'a> Data<'a> {
                 discord_scheduling_channel: row.discord_scheduling_channel.map(|PgSnowflake(id)| id),
                 discord_volunteer_info_channel: row.discord_volunteer_info_channel.map(|PgSnowflake(id)| id),
                 discord_async_channel: row.discord_async_channel.map(|PgSnowflake(id)| id),
+                discord_standings_channel: row.discord_standings_channel.map(|PgSnowflake(id)| id),
                 rando_version: row.rando_version.map(|Json(rando_version)| rando_version),
                 settings_string: row.settings_string,
                 single_settings: if series == Series::CopaDoBrasil && event == "1" {
@@ -363,12 +397,19 @@ impl<'a> Data<'a> {
                 listed: row.listed,
                 round_modes: row.round_modes.map(|Json(round_modes)| round_modes),
                 automated_asyncs: row.automated_asyncs,
+                async_verification: row.async_verification,
                 volunteer_requests_enabled: row.volunteer_requests_enabled,
                 volunteer_request_lead_time_hours: row.volunteer_request_lead_time_hours,
                 force_custom_role_binding: row.force_custom_role_binding.unwrap_or(true),
                 qualifier_score_hiding: row.qualifier_score_hiding,
+                qualifier_scoring: row.qualifier_scoring.map(|Json(qualifier_scoring)| qualifier_scoring),
+                qualifier_tiebreakers: row.qualifier_tiebreakers.0,
+                qualifier_cutoff: row.qualifier_cutoff,
                 qualifier_notification_role_id: row.qualifier_notification_role_id.map(|id| RoleId::new(id as u64)),
                 async_start_delay: row.async_start_delay,
+                native_bracket: row.native_bracket,
+                max_entrants: row.max_entrants,
+                waitlist_confirm_hours: row.waitlist_confirm_hours,
             }))
             .transpose()
     }
@@ -552,8 +593,10 @@ impl<'a> Data<'a> {
                 },
                 Some("league.ootrandomizer.com") => MatchSource::League,
                 Some("start.gg" | "www.start.gg") => MatchSource::StartGG(&url.path()[1..]),
-                _ => MatchSource::Manual,
+                _ => if self.native_bracket { MatchSource::Bracket } else { MatchSource::Manual },
             }
+        } else if self.native_bracket {
+            MatchSource::Bracket
         } else {
             MatchSource::Manual
         }
@@ -562,18 +605,9 @@ impl<'a> Data<'a> {
     pub(crate) async fn qualifier_kind(&self, transaction: &mut Transaction<'_, Postgres>) -> Result<QualifierKind, DataError> {
         Ok(match (self.series, &*self.event) {
             (Series::SongsOfHope, "1") => QualifierKind::SongsOfHope,
-            (Series::SpeedGaming, "2023onl" | "2024onl" | "2025onl") | (Series::Standard, "8") | (Series::TwwrMain, "miniblins26" | "s9") => {
-                QualifierKind::Score(match (self.series, &*self.event) {
-                    (Series::SpeedGaming, "2023onl") => teams::QualifierScoreKind::Sgl2023Online,
-                    (Series::SpeedGaming, "2024onl") => teams::QualifierScoreKind::Sgl2024Online,
-                    (Series::SpeedGaming, "2025onl") => teams::QualifierScoreKind::Sgl2025Online,
-                    (Series::Standard, "8") => teams::QualifierScoreKind::Standard,
-                    (Series::TwwrMain, "miniblins26") => teams::QualifierScoreKind::TwwrMiniblins26,
-                    (Series::TwwrMain, "s9") => teams::QualifierScoreKind::TwwrMain,
-                    _ => unreachable!("checked by outer match"),
-                })
-            }
-            (_, _) => if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM teams WHERE series = $1 AND event = $2 AND qualifier_rank IS NOT NULL) AS "exists!""#, self.series as _, &*self.event).fetch_one(&mut **transaction).await? {
+            (_, _) => if let Some(scoring) = self.qualifier_scoring {
+                QualifierKind::Score(scoring)
+            } else if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM teams WHERE series = $1 AND event = $2 AND qualifier_rank IS NOT NULL) AS "exists!""#, self.series as _, &*self.event).fetch_one(&mut **transaction).await? {
                 QualifierKind::Rank
             } else if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM asyncs WHERE series = $1 AND event = $2 AND kind = 'qualifier') AS "exists!""#, self.series as _, &*self.event).fetch_one(&mut **transaction).await? {
                 QualifierKind::Single {
@@ -871,9 +905,9 @@ impl<'a> Data<'a> {
                 }
                 @if !self.hide_races_tab && !self.is_single_race() {
                     @if let Tab::Races = tab {
-                        a(class = "button selected", href? = is_subpage.then(|| uri!(races(self.series, &*self.event)))) : "Races";
+                        a(class = "button selected", href? = is_subpage.then(|| uri!(races(self.series, &*self.event, _)))) : "Races";
                     } else {
-                        a(class = "button", href = uri!(races(self.series, &*self.event))) : "Races";
+                        a(class = "button", href = uri!(races(self.series, &*self.event, _))) : "Races";
                     }
                 }
                 @if matches!(self.match_source(), MatchSource::StartGG(_)) && self.swiss_standings {
@@ -883,6 +917,13 @@ impl<'a> Data<'a> {
                         a(class = "button", href = uri!(swiss_standings(self.series, &*self.event))) : "Swiss Standings";
                     }
                 }
+                @if let MatchSource::Bracket = self.match_source() {
+                    @if let Tab::Bracket = tab {
+                        a(class = "button selected", href? = is_subpage.then(|| uri!(bracket::get(self.series, &*self.event)))) : "Bracket";
+                    } else {
+                        a(class = "button", href = uri!(bracket::get(self.series, &*self.event))) : "Bracket";
+                    }
+                }
                 @if matches!(self.match_source(), MatchSource::StartGG(_)) && self.startgg_double_rr {
                     @if let Some(ref url) = self.url {
                         a(class = "button", href = format!("{url}/brackets")) : "Standings";
@@ -1078,6 +1119,7 @@ pub(crate) enum Tab {
     Configure,
     Roles,
     SwissStandings,
+    Bracket,
     Setup,
     Asyncs,
     Qualifiers,
@@ -1091,16 +1133,21 @@ pub(crate) enum Error {
     #[error(transparent)] Data(#[from] DataError),
     #[error(transparent)] Discord(#[from] crate::discord_bot::Error),
     #[error(transparent)] Game(#[from] game::GameError),
+    #[error(transparent)] IcsImport(#[from] ics_import::Error),
     #[error(transparent)] Io(#[from] io::Error),
     #[error(transparent)] Json(#[from] serde_json::Error),
     #[error(transparent)] OotrWeb(#[from] ootr_web::Error),
     #[error(transparent)] Page(#[from] PageError),
+    #[error(transparent)] RaceTime(#[from] racetime_bot::Error),
     #[error(transparent)] Reqwest(#[from] reqwest::Error),
     #[error(transparent)] SeedData(#[from] seed::ExtraDataError),
     #[error(transparent)] Serenity(#[from] serenity::Error),
+    #[error(transparent)] SheetImport(#[from] sheet_import::Error),
     #[error(transparent)] Sql(#[from] sqlx::Error),
     #[error(transparent)] Url(#[from] url::ParseError),
     #[error(transparent)] Wheel(#[from] wheel::Error),
+    #[error("the winner of bracket race {0} isn't a Hyrule Town Hall team")]
+    BracketWinner(Id<Races>),
     #[error("missing user data for an event organizer")]
     OrganizerUserData,
     #[error("missing user data for a restreamer")]
@@ -1120,16 +1167,20 @@ impl IsNetworkError for Error {
             Self::Data(_) => false,
             Self::Discord(_) => false,
             Self::Game(_) => false,
+            Self::IcsImport(e) => e.is_network_error(),
             Self::Io(e) => e.is_network_error(),
             Self::Json(_) => false,
             Self::OotrWeb(e) => e.is_network_error(),
             Self::Page(e) => e.is_network_error(),
+            Self::RaceTime(_) => false,
             Self::Reqwest(e) => e.is_network_error(),
             Self::SeedData(e) => e.is_network_error(),
             Self::Serenity(_) => false,
+            Self::SheetImport(e) => e.is_network_error(),
             Self::Sql(_) => false,
             Self::Url(_) => false,
             Self::Wheel(e) => e.is_network_error(),
+            Self::BracketWinner(_) => false,
             Self::OrganizerUserData => false,
             Self::RestreamerUserData => false,
         }
@@ -1268,8 +1319,9 @@ pub(crate) async fn info(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>
     Ok(page(transaction, &me, &uri, PageStyle { chests: data.chests().await?, ..PageStyle::default() }, &data.display_name, content).await?)
 }
 
-#[rocket::get("/event/<series>/<event>/races")]
-pub(crate) async fn races(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: Option<User>, uri: Origin<'_>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<Error>> {
+#[rocket::get("/event/<series>/<event>/races?<filter..>")]
+pub(crate) async fn races(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: Option<User>, uri: Origin<'_>, series: Series, event: &str, filter: Option<race_list::Filter>) -> Result<RawHtml<String>, StatusOrError<Error>> {
+    let filter = filter.unwrap_or_default();
     let mut transaction = pool.begin().await?;
     let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
     let header = data.header(&mut transaction, me.as_ref(), Tab::Races, false).await?;
@@ -1277,18 +1329,17 @@ pub(crate) async fn races(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &Stat
         .into_iter()
         .partition::<Vec<_>, _>(|race| race.is_ended());
     past_races.reverse();
-    let any_races_ongoing_or_upcoming = !ongoing_and_upcoming_races.is_empty();
-    let (can_create, show_restream_consent, can_edit) = if let Some(ref me) = me {
+    let (can_create, show_restream_consent, can_edit, can_export) = if let Some(ref me) = me {
         let is_organizer = data.organizers(&mut transaction).await?.contains(me);
         let can_create = (is_organizer || me.is_global_admin()) && match data.match_source() {
             MatchSource::League => false,
-            MatchSource::Manual | MatchSource::Challonge { .. } | MatchSource::StartGG(_) => true,
+            MatchSource::Manual | MatchSource::Challonge { .. } | MatchSource::StartGG(_) | MatchSource::Bracket => true,
         };
         let show_restream_consent = is_organizer || data.restreamers(&mut transaction).await?.contains(me);
         let can_edit = show_restream_consent || me.is_archivist;
-        (can_create, show_restream_consent, can_edit)
+        (can_create, show_restream_consent, can_edit, is_organizer || me.is_global_admin())
     } else {
-        (false, false, false)
+        (false, false, false, false)
     };
     {
         struct ConcludedAsync {
@@ -1311,14 +1362,7 @@ pub(crate) async fn races(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &Stat
         .fetch_all(&mut *transaction)
         .await?;
         for row in concluded_asyncs {
-            let round = Some(match row.kind {
-                AsyncKind::Qualifier1 => "Qualifier Async 1".to_owned(),
-                AsyncKind::Qualifier2 => "Qualifier Async 2".to_owned(),
-                AsyncKind::Qualifier3 => "Qualifier Async 3".to_owned(),
-                AsyncKind::Seeding => "Seeding Async".to_owned(),
-                AsyncKind::Tiebreaker1 => "Tiebreaker Async 1".to_owned(),
-                AsyncKind::Tiebreaker2 => "Tiebreaker Async 2".to_owned(),
-            });
+            let round = Some(row.kind.display_name().to_owned());
             let seed_files = if let Some(hash) = row.seed_data.as_ref().and_then(|d| d.get("avianart_hash")).and_then(|v| v.as_str()).filter(|s| !s.is_empty()) {
                 Some(seed::Files::AvianartSeed { hash: hash.to_owned(), seed_hash: None })
             } else if let (Some(permalink), Some(seed_hash)) = (
@@ -1381,15 +1425,32 @@ pub(crate) async fn races(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &Stat
             });
         }
     }
+    let phases = ongoing_and_upcoming_races.iter().chain(&past_races).filter_map(|race| race.phase.clone()).unique().sorted_by(|a, b| race_list::natural_cmp(a, b)).collect_vec();
+    let rounds = ongoing_and_upcoming_races.iter().chain(&past_races).filter_map(|race| race.round.clone()).unique().sorted_by(|a, b| race_list::natural_cmp(a, b)).collect_vec();
+    let ongoing_and_upcoming_races = filter.apply(&mut transaction, ongoing_and_upcoming_races).await?;
+    let past_races = filter.apply(&mut transaction, past_races).await?;
+    let (ongoing_and_upcoming_races, upcoming_page_count) = filter.paginate(race_list::Section::Upcoming, &ongoing_and_upcoming_races);
+    let (past_races, page_count) = filter.paginate(race_list::Section::Past, &past_races);
+    let any_races_ongoing_or_upcoming = !ongoing_and_upcoming_races.is_empty();
     let content = html! {
         : header;
         //TODO copiable calendar link (with link to index for explanation?)
         @if can_create {
             div(class = "button-row") {
                 @match data.match_source() {
-                    MatchSource::Manual | MatchSource::Challonge { .. } => a(class = "button", href = uri!(crate::cal::create_race(series, event, _))) : "New Race";
+                    MatchSource::Manual => {
+                        a(class = "button", href = uri!(crate::cal::create_race(series, event, _))) : "New Race";
+                        a(class = "button", href = uri!(race_upload::get(series, event))) : "Upload Races";
+                        a(class = "button", href = uri!(sheet_import::get(series, event))) : "Sheet Import";
+                        a(class = "button", href = uri!(ics_import::get(series, event))) : "iCalendar Import";
+                    }
+                    MatchSource::Challonge { .. } => a(class = "button", href = uri!(crate::cal::create_race(series, event, _))) : "New Race";
                     //MatchSource::Challonge { .. } => a(class = "button", href = uri!(crate::cal::import_races(series, event))) : "Import"; // disabled due to Challonge pagination bug
                     MatchSource::League => {}
+                    MatchSource::Bracket => {
+                        a(class = "button", href = uri!(bracket::get(series, event))) : "Bracket";
+                        a(class = "button", href = uri!(crate::cal::create_race(series, event, _))) : "New Race";
+                    }
                     MatchSource::StartGG(_) => {
                         @if !data.auto_import {
                             a(class = "button", href = uri!(crate::cal::import_races(series, event))) : "Import";
@@ -1399,6 +1460,10 @@ pub(crate) async fn races(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &Stat
                 }
             }
         }
+        : filter.form(&data, &phases, &rounds, can_export);
+        @if filter.is_active() && !any_races_ongoing_or_upcoming && past_races.is_empty() {
+            p : "No races match this filter.";
+        }
         @if any_races_ongoing_or_upcoming {
             //TODO split into ongoing and upcoming, show headers for both
             @if let Some(ref me) = me {
@@ -1425,16 +1490,18 @@ pub(crate) async fn races(discord_ctx: &State<RwFuture<DiscordCtx>>, pool: &Stat
                            .unwrap_or_default()
                    }
                };
-                : cal::race_table(&mut transaction, Some(&*discord_ctx.read().await), http_client, &uri, Some(&data), cal::RaceTableOptions { game_count: false, show_multistreams: true, can_edit, show_restream_consent, challonge_import_ctx: None }, &ongoing_and_upcoming_races, Some(me), Some(&my_approved_roles)).await?;
+                : cal::race_table(&mut transaction, Some(&*discord_ctx.read().await), http_client, &uri, Some(&data), cal::RaceTableOptions { game_count: false, show_multistreams: true, can_edit, show_restream_consent, challonge_import_ctx: None }, ongoing_and_upcoming_races, Some(me), Some(&my_approved_roles)).await?;
             } else {
-                : cal::race_table(&mut transaction, Some(&*discord_ctx.read().await), http_client, &uri, Some(&data), cal::RaceTableOptions { game_count: false, show_multistreams: true, can_edit, show_restream_consent, challonge_import_ctx: None }, &ongoing_and_upcoming_races, None, None).await?;
+                : cal::race_table(&mut transaction, Some(&*discord_ctx.read().await), http_client, &uri, Some(&data), cal::RaceTableOptions { game_count: false, show_multistreams: true, can_edit, show_restream_consent, challonge_import_ctx: None }, ongoing_and_upcoming_races, None, None).await?;
             }
+            : filter.pagination(&data, race_list::Section::Upcoming, upcoming_page_count);
         }
         @if !past_races.is_empty() {
             @if any_races_ongoing_or_upcoming {
                 h2 : "Past races";
             }
-            : cal::race_table(&mut transaction, Some(&*discord_ctx.read().await), http_client, &uri, Some(&data), cal::RaceTableOptions { game_count: false, show_multistreams: false, can_edit, show_restream_consent: false, challonge_import_ctx: None }, &past_races, None, None).await?;
+            : cal::race_table(&mut transaction, Some(&*discord_ctx.read().await), http_client, &uri, Some(&data), cal::RaceTableOptions { game_count: false, show_multistreams: false, can_edit, show_restream_consent: false, challonge_import_ctx: None }, past_races, None, None).await?;
+            : filter.pagination(&data, race_list::Section::Past, page_count);
         }
     };
     Ok(page(transaction, &me, &uri, PageStyle { chests: data.chests().await?, ..PageStyle::default() }, &format!("Races — {}", data.display_name), content).await?)
@@ -1444,7 +1511,9 @@ pub(crate) enum StatusContext<'v> {
     None,
     RequestAsync(Context<'v>),
     SubmitAsync(Context<'v>),
+    WebAsync(Context<'v>),
     Edit(Context<'v>),
+    ConfirmWaitlist(Context<'v>),
 }
 
 impl<'v> StatusContext<'v> {
@@ -1467,6 +1536,17 @@ impl<'v> StatusContext<'v> {
             }
         }
     }
+
+    fn take_web_async(&mut self) -> Context<'v> {
+        match mem::replace(self, Self::None) {
+            Self::WebAsync(ctx) => ctx,
+            old_val => {
+                *self = old_val;
+                Context::default()
+            }
+        }
+    }
+
     fn take_edit(&mut self) -> Context<'v> {
         match mem::replace(self, Self::None) {
             Self::Edit(ctx) => ctx,
@@ -1476,12 +1556,74 @@ impl<'v> StatusContext<'v> {
             }
         }
     }
+
+    fn take_confirm_waitlist(&mut self) -> Context<'v> {
+        match mem::replace(self, Self::None) {
+            Self::ConfirmWaitlist(ctx) => ctx,
+            old_val => {
+                *self = old_val;
+                Context::default()
+            }
+        }
+    }
+}
+
+/// The seed of an async, as shown to a team which has requested it.
+pub(crate) async fn async_seed_html(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>, async_kind: AsyncKind) -> Result<RawHtml<String>, Error> {
+    let async_row = sqlx::query!(r#"SELECT is_tfb_dev, tfb_uuid, xkeys_uuid, web_id, web_gen_time, file_stem, hash1, hash2, hash3, hash4, hash5, seed_password, seed_data FROM asyncs WHERE series = $1 AND event = $2 AND kind = $3"#, data.series as _, &data.event, async_kind as _).fetch_one(&mut **transaction).await?;
+    let seed = seed::Data::from_db(
+        None,
+        None,
+        None,
+        None,
+        async_row.file_stem,
+        None,
+        async_row.web_id,
+        async_row.web_gen_time,
+        async_row.is_tfb_dev,
+        async_row.tfb_uuid,
+        async_row.xkeys_uuid,
+        async_row.seed_data,
+        async_row.hash1,
+        async_row.hash2,
+        async_row.hash3,
+        async_row.hash4,
+        async_row.hash5,
+        async_row.seed_password.as_deref(),
+        false, // no official races with progression spoilers so far
+    );
+    // Get game_id for the event's series
+    let game_id = sqlx::query_scalar!(
+        r#"
+            SELECT gs.game_id
+            FROM game_series gs
+            WHERE gs.series = $1
+        "#,
+        data.series.to_string()
+    )
+    .fetch_optional(&mut **transaction)
+    .await?
+    .flatten()
+    .unwrap_or(1); // Default to OOTR if no mapping found
+    let extra = seed.extra(Utc::now()).await?;
+    let seed_table = seed::table(stream::iter(iter::once(seed)), false, transaction, game_id).await?;
+    Ok(html! {
+        : seed_table;
+        @if let Some(password) = extra.password {
+            p { //TODO replace this hack with password support in seed::table
+                : "Password: ";
+                @for note in password {
+                    : char::from(note);
+                }
+            };
+        }
+    })
 }
 
-async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &reqwest::Client, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, data: Data<'_>, mut ctx: StatusContext<'_>) -> Result<RawHtml<String>, Error> {
+pub(crate) async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &reqwest::Client, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, data: Data<'_>, mut ctx: StatusContext<'_>) -> Result<RawHtml<String>, Error> {
     let header = data.header(&mut transaction, me.as_ref(), Tab::MyStatus, false).await?;
     let content = if let Some(ref me) = me {
-        if let Some(row) = sqlx::query!(r#"SELECT id AS "id: Id<Teams>", name, racetime_slug, role AS "role: Role", resigned, restream_consent, custom_choices AS "custom_choices: Json<HashMap<String, String>>" FROM teams, team_members WHERE
+        if let Some(row) = sqlx::query!(r#"SELECT id AS "id: Id<Teams>", name, racetime_slug, role AS "role: Role", resigned, waitlisted_at IS NOT NULL AS "waitlisted!", waitlist_deadline, restream_consent, custom_choices AS "custom_choices: Json<HashMap<String, String>>" FROM teams, team_members WHERE
             id = team
             AND series = $1
             AND event = $2
@@ -1517,6 +1659,14 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                         : ".";
                     }
                 }
+                p {
+                    a(href = uri!(entrant::get(data.series, &*data.event, row.id)).to_string()) : "View your results in this event";
+                }
+                @if !row.resigned {
+                    p {
+                        a(href = uri!(substitutes::get(data.series, &*data.event, row.id)).to_string()) : "Manage alternates and substitutions";
+                    }
+                }
                 @if row.resigned {
                     @let is_blocked = if let Some(racetime) = me.racetime.as_ref() {
                         sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM event_blocks WHERE series = $1 AND event = $2 AND racetime_id = $3) AS "exists!""#, data.series as _, &data.event, racetime.id).fetch_one(&mut *transaction).await?
@@ -1532,10 +1682,35 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                             : " if you wish.";
                         }
                     }
+                } else if row.waitlisted {
+                    @let position = waitlist::position(&mut transaction, row.id).await?;
+                    p {
+                        : "This event is full, so you are on the waitlist";
+                        @if let Some(position) = position {
+                            : " at position ";
+                            : position;
+                        }
+                        : ". You will be notified if a spot opens up.";
+                    }
+                    @let (resign_errors, resign_button) = button_form_confirm(uri!(resign_post(data.series, &*data.event, row.id)), csrf, Vec::new(), "Leave waitlist", "Are you sure you want to leave the waitlist? If you change your mind later, you will be added to the end of the waitlist.");
+                    : resign_errors;
+                    div(class = "button-row") : resign_button;
                 } else {
+                    @if let Some(deadline) = row.waitlist_deadline {
+                        @let (confirm_errors, confirm_button) = button_form(uri!(waitlist::confirm(data.series, &*data.event, row.id)), csrf, ctx.take_confirm_waitlist().errors().collect(), "Confirm spot");
+                        div(class = "bg-surface") {
+                            p {
+                                : "A spot has opened up and you have been promoted from the waitlist. Please confirm your spot by ";
+                                : format_datetime(deadline, DateTimeFormat { long: true, running_text: true });
+                                : ", otherwise it will be passed on to the next entrant on the waitlist.";
+                            }
+                            : confirm_errors;
+                            div(class = "button-row") : confirm_button;
+                        }
+                    }
                     @let qualifier_kind = data.qualifier_kind(&mut transaction).await?;
                     @let qualifier_progress = {
-                        if let QualifierKind::Score(score_kind) = qualifier_kind {
+                        if let QualifierKind::Score(scoring) = qualifier_kind {
                             let live_qualifier_count = usize::try_from(sqlx::query_scalar!(
                                 r#"SELECT COUNT(*) FROM races WHERE series = $1 AND event = $2 AND phase = 'Qualifier'"#,
                                 data.series as _,
@@ -1555,8 +1730,8 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                                         Some((
                                             num_entered,
                                             num_finished,
-                                            score_kind.max_qualifiers_that_count(),
-                                            score_kind.required_qualifiers(),
+                                            scoring.max_qualifiers_that_count(),
+                                            scoring.required_qualifiers(),
                                             score,
                                             live_qualifier_count,
                                             async_qualifier_count,
@@ -1615,6 +1790,11 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                             @if data.qualifier_score_hiding == QualifierScoreHiding::None {
                                 p : format!("Qualifier points: {score:.2}");
                             }
+                            @if data.qualifier_cutoff.is_some() && matches!(data.qualifier_score_hiding, QualifierScoreHiding::None | QualifierScoreHiding::AsyncOnly) {
+                                p {
+                                    a(href = uri!(qualifier_projection::get(data.series, &*data.event)).to_string()) : "What do I need in my remaining qualifiers?";
+                                }
+                            }
                             @if !round_scores.is_empty() {
                                 h4(style = "margin-top: 1em;") : "Your Qualifier Results";
                                 @let hide_points = data.qualifier_score_hiding != QualifierScoreHiding::None;
@@ -1649,11 +1829,11 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                         }
                     }
                     @let async_info = if let Some(async_kind) = data.active_async(&mut transaction, Some(row.id)).await? {
-                        let async_row = sqlx::query!(r#"SELECT is_tfb_dev, tfb_uuid, xkeys_uuid, web_id, web_gen_time, file_stem, hash1, hash2, hash3, hash4, hash5, seed_password, seed_data FROM asyncs WHERE series = $1 AND event = $2 AND kind = $3"#, data.series as _, &data.event, async_kind as _).fetch_one(&mut *transaction).await?;
-                        if let Some(team_row) = sqlx::query!(r#"SELECT requested AS "requested!", submitted, discord_thread FROM async_teams WHERE team = $1 AND KIND = $2 AND requested IS NOT NULL"#, row.id as _, async_kind as _).fetch_optional(&mut *transaction).await? {
+                        if let Some(team_row) = sqlx::query!(r#"SELECT requested AS "requested!", submitted FROM async_teams WHERE team = $1 AND KIND = $2 AND requested IS NOT NULL"#, row.id as _, async_kind as _).fetch_optional(&mut *transaction).await? {
                             if team_row.submitted.is_some() {
                                 None
-                            } else if let Some(thread_id) = team_row.discord_thread {
+                            } else if data.automated_asyncs {
+                                let run = web_async::status_section(&mut transaction, &data, csrf, row.id, async_kind, ctx.take_web_async()).await?;
                                 Some(html! {
                                     h3 : "Async";
                                     div(class = "bg-surface") {
@@ -1662,59 +1842,11 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                                             : format_datetime(team_row.requested, DateTimeFormat { long: true, running_text: true });
                                             : ".";
                                         }
-                                        p {
-                                            : "Your async qualifier is being handled via Discord. ";
-                                            a(href = format!("https://discord.com/channels/{}/{}",
-                                                data.discord_guild.map(|g| g.get()).unwrap_or(0),
-                                                thread_id)) : "Open Thread";
-                                        }
-                                    }
-                                })
-                            } else if data.automated_asyncs {
-                                Some(html! {
-                                    h3 : "Async";
-                                    div(class = "bg-surface") {
-                                        p : "Your async request has been received. A Discord thread will be created for you shortly.";
+                                        : run;
                                     }
                                 })
                             } else {
-                                let seed = seed::Data::from_db(
-                                    None,
-                                    None,
-                                    None,
-                                    None,
-                                    async_row.file_stem,
-                                    None,
-                                    async_row.web_id,
-                                    async_row.web_gen_time,
-                                    async_row.is_tfb_dev,
-                                    async_row.tfb_uuid,
-                                    async_row.xkeys_uuid,
-                                    async_row.seed_data,
-                                                        async_row.hash1,
-                    async_row.hash2,
-                    async_row.hash3,
-                    async_row.hash4,
-                    async_row.hash5,
-                                    async_row.seed_password.as_deref(),
-                                    false, // no official races with progression spoilers so far
-                                );
-                                // Get game_id for the event's series
-                                let game_id = sqlx::query_scalar!(
-                                    r#"
-                                        SELECT gs.game_id
-                                        FROM game_series gs
-                                        WHERE gs.series = $1
-                                    "#,
-                                    data.series.to_string()
-                                )
-                                .fetch_optional(&mut *transaction)
-                                .await?
-                                .flatten()
-                                .unwrap_or(1); // Default to OOTR if no mapping found
-                                
-                                let extra = seed.extra(Utc::now()).await?;
-                                let seed_table = seed::table(stream::iter(iter::once(seed)), false, &mut transaction, game_id).await?;
+                                let seed_html = async_seed_html(&mut transaction, &data, async_kind).await?;
                                 let ctx = ctx.take_submit_async();
                                 let mut errors = ctx.errors().collect_vec();
                                 Some(html! {
@@ -1725,15 +1857,7 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                                             : format_datetime(team_row.requested, DateTimeFormat { long: true, running_text: true });
                                             : ".";
                                         };
-                                        : seed_table;
-                                        @if let Some(password) = extra.password {
-                                            p { //TODO replace this hack with password support in seed::table
-                                                : "Password: ";
-                                                @for note in password {
-                                                    : char::from(note);
-                                                }
-                                            };
-                                        }
+                                        : seed_html;
                                         p : "After playing the async, fill out the form below.";
                                         : full_form(uri!(event::submit_async(data.series, &*data.event)), csrf, html! {
                                             @match data.team_config {
@@ -1839,9 +1963,9 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                                 match qualification {
                                     teams::Qualification::Single { qualified } | teams::Qualification::TriforceBlitz { qualified, .. } => (*qualified, false, 0, 1),
                                     teams::Qualification::Multiple { num_entered, num_finished, .. } => {
-                                        if let QualifierKind::Score(score_kind) = qualifier_kind {
-                                            let required = score_kind.required_qualifiers();
-                                            (*num_finished >= required, *num_entered >= score_kind.max_qualifiers_that_count(), *num_finished, required)
+                                        if let QualifierKind::Score(scoring) = qualifier_kind {
+                                            let required = scoring.required_qualifiers();
+                                            (*num_finished >= required, *num_entered >= scoring.max_qualifiers_that_count(), *num_finished, required)
                                         } else {
                                             (*num_finished >= 2, false, *num_finished, 2) // fallback
                                         }
@@ -1895,6 +2019,9 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                     } else {
                         None
                     };
+                    @if data.async_verification {
+                        : async_verification::status_section(&mut transaction, &data, row.id).await?;
+                    }
                     @if let Some(async_info) = async_info {
                         : async_info;
                     } else {
@@ -1969,13 +2096,13 @@ async fn status_page(mut transaction: Transaction<'_, Postgres>, http_client: &r
                             } else {
                                 p { //TODO indicate whether qualified?
                                     : "Please see the rules document for how to qualify, and "; //TODO linkify
-                                    a(href = uri!(races(data.series, &*data.event))) : "the race schedule";
+                                    a(href = uri!(races(data.series, &*data.event, _))) : "the race schedule";
                                     : " for upcoming qualifiers.";
                                 }
                             }
                             Series::SpeedGaming => p { //TODO indicate whether qualified?
                                 : "Please see the rules document for how to qualify, and "; //TODO linkify
-                                a(href = uri!(races(data.series, &*data.event))) : "the race schedule";
+                                a(href = uri!(races(data.series, &*data.event, _))) : "the race schedule";
                                 : " for upcoming qualifiers.";
                             }
                             Series::TriforceBlitz => @if data.is_started(&mut transaction).await? {
@@ -2165,6 +2292,10 @@ pub(crate) async fn status_post(pool: &State<PgPool>, http_client: &State<reqwes
                                 }
                             }
                         }
+                        // dropdown answers aren't part of this form
+                        enter::Requirement::Dropdown { key, .. } => if let Some(existing) = row.custom_choices.0.get(key.as_str()) {
+                            merged_choices.insert(key.clone(), existing.clone());
+                        },
                         _ => {}
                     }
                 }
@@ -2298,6 +2429,7 @@ pub(crate) enum AcceptError {
     #[error(transparent)] Data(#[from] DataError),
     #[error(transparent)] Discord(#[from] serenity::Error),
     #[error(transparent)] Enter(#[from] enter::Error),
+    #[error(transparent)] Event(#[from] Error),
     #[error(transparent)] Notification(#[from] crate::notification::Error),
     #[error(transparent)] Sql(#[from] sqlx::Error),
     #[error(transparent)] Teams(#[from] teams::Error),
@@ -2350,18 +2482,21 @@ pub(crate) async fn confirm_signup(config: &State<Config>, pool: &State<PgPool>,
                 sqlx::query!("INSERT INTO notifications (id, rcpt, kind, series, event, sender) VALUES ($1, $2, 'accept', $3, $4, $5)", id as _, member as _, series as _, event, me.id as _).execute(&mut *transaction).await?;
             }
             sqlx::query!("UPDATE team_members SET status = 'confirmed' WHERE team = $1 AND member = $2", team as _, me.id as _).execute(&mut *transaction).await?;
+            let mut waitlist_messages = waitlist::Messages::default();
             if !sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM team_members WHERE team = $1 AND status = 'unconfirmed') AS "exists!""#, team as _).fetch_one(&mut *transaction).await? {
                 // this confirms the team
                 // remove all members from looking_for_team
                 sqlx::query!("DELETE FROM looking_for_team WHERE EXISTS (SELECT 1 FROM team_members WHERE team = $1 AND member = user_id)", team as _).execute(&mut *transaction).await?;
                 //TODO also remove all other teams with member overlap, and notify
+                // waitlisted teams get the participant role once they've confirmed their spot
+                let waitlisted = sqlx::query_scalar!(r#"SELECT waitlisted_at IS NOT NULL AS "waitlisted!" FROM teams WHERE id = $1"#, team as _).fetch_one(&mut *transaction).await?;
                 // create and assign Discord roles
                 if let Some(discord_guild) = data.discord_guild {
                     let discord_ctx = discord_ctx.read().await;
                     for row in sqlx::query!(r#"SELECT discord_id AS "discord_id!: PgSnowflake<UserId>", role AS "role: Role" FROM users, team_members WHERE id = member AND discord_id IS NOT NULL AND team = $1"#, team as _).fetch_all(&mut *transaction).await? {
                         if let Ok(mut member) = discord_guild.member(&*discord_ctx, row.discord_id.0).await {
                             let mut roles_to_assign = member.roles.iter().copied().collect::<HashSet<_>>();
-                            if let Some(PgSnowflake(participant_role)) = sqlx::query_scalar!(r#"SELECT id AS "id: PgSnowflake<RoleId>" FROM discord_roles WHERE guild = $1 AND series = $2 AND event = $3"#, PgSnowflake(discord_guild) as _, series as _, event).fetch_optional(&mut *transaction).await? {
+                            if !waitlisted && let Some(PgSnowflake(participant_role)) = sqlx::query_scalar!(r#"SELECT id AS "id: PgSnowflake<RoleId>" FROM discord_roles WHERE guild = $1 AND series = $2 AND event = $3"#, PgSnowflake(discord_guild) as _, series as _, event).fetch_optional(&mut *transaction).await? {
                                 roles_to_assign.insert(participant_role);
                             }
                             if let Some(PgSnowflake(role_role)) = sqlx::query_scalar!(r#"SELECT id AS "id: PgSnowflake<RoleId>" FROM discord_roles WHERE guild = $1 AND role = $2"#, PgSnowflake(discord_guild) as _, row.role as _).fetch_optional(&mut *transaction).await? {
@@ -2381,8 +2516,12 @@ pub(crate) async fn confirm_signup(config: &State<Config>, pool: &State<PgPool>,
                         }
                     }
                 }
+                if waitlisted {
+                    waitlist_messages = waitlist::promote(&mut transaction, &data).await?;
+                }
             }
             transaction.commit().await?;
+            waitlist_messages.send(&*discord_ctx.read().await).await;
             RedirectOrContent::Redirect(Redirect::to(uri!(teams::get(series, event))))
         })
     } else {
@@ -2568,7 +2707,9 @@ pub(crate) async fn resign_post(config: &State<Config>, pool: &State<PgPool>, ht
             if !keep_record {
                 sqlx::query!("DELETE FROM teams WHERE id = $1", team.id as _).execute(&mut *transaction).await?;
             }
+            let waitlist_messages = waitlist::promote(&mut transaction, &data).await?;
             transaction.commit().await?;
+            waitlist_messages.send(&*discord_ctx.read().await).await;
             RedirectOrContent::Redirect(Redirect::to(uri!(teams::get(series, event))))
         })
     } else {
@@ -2726,12 +2867,14 @@ pub(crate) async fn status_opt_out(pool: &State<PgPool>, http_client: &State<req
                 organizer_channel.say(&*discord_ctx.read().await, MessageBuilder::default()
                     .mention_user(&me)
                     .push(" has decided to opt out for ")
-                    .push_safe(data.display_name)
+                    .push_safe(&data.display_name)
                     .push(".")
                     .build(),
                 ).await?;
             }
+            let waitlist_messages = waitlist::promote(&mut transaction, &data).await?;
             transaction.commit().await?;
+            waitlist_messages.send(&*discord_ctx.read().await).await;
             RedirectOrContent::Redirect(Redirect::to(uri!(status(series, event))))
         })
     } else {
@@ -3075,8 +3218,10 @@ pub(crate) async fn manage_team_post(pool: &State<PgPool>, _http_client: &State<
                 msg.push(".");
                 organizer_channel.say(&*discord_ctx.read().await, msg.build()).await?;
             }
+            let waitlist_messages = waitlist::promote(&mut transaction, &data).await?;
 
             transaction.commit().await?;
+            waitlist_messages.send(&*discord_ctx.read().await).await;
             RedirectOrContent::Redirect(Redirect::to(uri!(teams::get(series, event))))
         })
     } else {
@@ -3168,10 +3313,22 @@ pub(crate) async fn manage_team_choices_post(
             if !team_in_event {
                 return Err(StatusOrError::Status(Status::NotFound));
             }
+            let mut custom_choices = value.custom_choices.clone();
+            // dropdown answers aren't part of this form
+            if let Some(ref enter_flow) = data.enter_flow {
+                let existing = sqlx::query_scalar!(r#"SELECT custom_choices AS "custom_choices: Json<HashMap<String, String>>" FROM teams WHERE id = $1"#, team as _).fetch_one(&mut *transaction).await?;
+                for req in &enter_flow.requirements {
+                    if let enter::Requirement::Dropdown { key, .. } = req {
+                        if let Some(existing) = existing.0.get(key.as_str()) {
+                            custom_choices.insert(key.clone(), existing.clone());
+                        }
+                    }
+                }
+            }
             sqlx::query!(
                 "UPDATE teams SET restream_consent = $1, custom_choices = $2 WHERE id = $3",
                 value.restream_consent,
-                Json(&value.custom_choices) as _,
+                Json(&custom_choices) as _,
                 team as _
             )
             .execute(&mut *transaction)
@@ -3399,6 +3556,17 @@ impl AsyncKind {
             _ => None,
         }
     }
+
+    pub(crate) fn display_name(&self) -> &'static str {
+        match self {
+            Self::Qualifier1 => "Qualifier Async 1",
+            Self::Qualifier2 => "Qualifier Async 2",
+            Self::Qualifier3 => "Qualifier Async 3",
+            Self::Seeding => "Seeding Async",
+            Self::Tiebreaker1 => "Tiebreaker Async 1",
+            Self::Tiebreaker2 => "Tiebreaker Async 2",
+        }
+    }
 }
 
 #[derive(FromForm, CsrfForm)]
@@ -3421,6 +3589,7 @@ pub(crate) async fn request_async(pool: &State<PgPool>, http_client: &State<reqw
             AND event = $2
             AND member = $3
             AND NOT resigned
+            AND waitlisted_at IS NULL
             AND NOT EXISTS (SELECT 1 FROM team_members WHERE team = id AND status = 'unconfirmed')
         "#, series as _, event, me.id as _).fetch_optional(&mut *transaction).await?;
         let async_kind = if let Some(ref team) = team {
@@ -3492,6 +3661,7 @@ pub(crate) async fn submit_async(pool: &State<PgPool>, http_client: &State<reqwe
             AND event = $2
             AND member = $3
             AND NOT resigned
+            AND waitlisted_at IS NULL
             AND NOT EXISTS (SELECT 1 FROM team_members WHERE team = id AND status = 'unconfirmed')
         "#, series as _, event, me.id as _).fetch_optional(&mut *transaction).await?;
         let async_kind = if let Some(ref team) = team {
@@ -0,0 +1,211 @@
+//! A page for entrants of score-based qualifiers which shows the points they need in their remaining qualifiers to reach the qualifier cutoff.
+
+use {
+    noisy_float::prelude::*,
+    crate::{
+        event::{
+            Data,
+            QualifierScoreHiding,
+            Series,
+            Tab,
+            qualifier_scoring::{
+                Aggregate,
+                QualifierScoring,
+            },
+            teams::{
+                self,
+                MemberUser,
+                Qualification,
+                QualifierKind,
+            },
+        },
+        prelude::*,
+    },
+};
+
+#[derive(Debug, PartialEq)]
+struct Projection {
+    remaining: usize,
+    points: Option<f64>,
+}
+
+/// The score to beat, i.e. that of the entrant currently in the last qualifying spot, not counting entrants who haven't played enough qualifiers.
+///
+/// `others` are the qualifications of all other entrants, best first.
+fn cutoff_score<'a>(scoring: &QualifierScoring, others: impl IntoIterator<Item = &'a Qualification>, cutoff: usize) -> R64 {
+    let Some(idx) = cutoff.checked_sub(1) else { return r64(0.0) };
+    others.into_iter()
+        .filter_map(|qualification| if let Qualification::Multiple { num_entered, num_finished, score, .. } = *qualification {
+            scoring.has_required_qualifiers(&Aggregate { score, num_entered, num_finished }).then_some(score)
+        } else {
+            None
+        })
+        .nth(idx)
+        .unwrap_or(r64(0.0))
+}
+
+/// The number of open qualifiers the entrant can still play which would count toward their score.
+fn remaining_qualifiers(scoring: &QualifierScoring, num_entered: usize, remaining_live: i64, remaining_asyncs: i64) -> usize {
+    usize::try_from(remaining_live + remaining_asyncs).unwrap_or_default()
+        .min(scoring.max_qualifiers_that_count().saturating_sub(num_entered))
+}
+
+fn projections(scoring: &QualifierScoring, my_scores: &[R64], remaining: usize, target: R64) -> Vec<Projection> {
+    (1..=remaining).map(|remaining| Projection {
+        points: scoring.score_needed(my_scores, remaining, target),
+        remaining,
+    }).collect()
+}
+
+/// Shows the viewing entrant the points they need in their remaining qualifiers to reach the qualifier cutoff, assuming everyone else's results stay as they are.
+#[rocket::get("/event/<series>/<event>/qualifiers/projection")]
+pub(crate) async fn get(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let qualifier_kind = data.qualifier_kind(&mut transaction).await?;
+    let QualifierKind::Score(scoring) = qualifier_kind else { return Err(StatusOrError::Status(Status::NotFound)) };
+    let header = data.header(&mut transaction, Some(&me), Tab::Teams, true).await?;
+    let is_organizer = me.is_global_admin() || data.organizers(&mut transaction).await?.contains(&me);
+    let all_qualifiers_ended = teams::all_qualifiers_ended(&mut transaction, http_client, &data, qualifier_kind).await?;
+    let points_hidden = !is_organizer && !all_qualifiers_ended && matches!(data.qualifier_score_hiding, QualifierScoreHiding::FullPoints | QualifierScoreHiding::FullPointsCounts | QualifierScoreHiding::FullComplete);
+    let content = if points_hidden {
+        html! {
+            : header;
+            p : "Projections are not available while qualifier points are hidden.";
+        }
+    } else if let Some(cutoff) = data.qualifier_cutoff.and_then(|cutoff| usize::try_from(cutoff).ok()) {
+        let signups = teams::signups_sorted(&mut transaction, &mut teams::Cache::new(http_client.inner().clone()), Some(&me), &data, is_organizer, qualifier_kind, None, all_qualifiers_ended, false).await?;
+        let is_me = |signup: &teams::SignupsTeam| signup.members.iter().any(|member| matches!(&member.user, MemberUser::MidosHouse(user) if user.id == me.id));
+        if let Some((my_rank, my_signup)) = signups.iter().find_position(|signup| is_me(signup)) {
+            let Qualification::Multiple { num_entered, score, ref round_scores, .. } = my_signup.qualification else { unreachable!("QualifierKind::Score must use Qualification::Multiple") };
+            let my_scores = round_scores.iter().map(|round| round.score).collect_vec();
+            let target = cutoff_score(&scoring, signups.iter().filter(|signup| !is_me(signup)).map(|signup| &signup.qualification), cutoff);
+            let my_aggregate = scoring.aggregate(&my_scores);
+            let qualified_now = scoring.has_required_qualifiers(&my_aggregate) && my_aggregate.score > target;
+            let remaining_live = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM races WHERE series = $1 AND event = $2 AND phase = 'Qualifier' AND NOT ignored AND (start IS NULL OR start > NOW())"#, data.series as _, &data.event).fetch_one(&mut *transaction).await?;
+            let remaining_asyncs = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM asyncs a WHERE series = $1 AND event = $2 AND kind IN ('qualifier', 'qualifier2', 'qualifier3') AND (end_time IS NULL OR end_time > NOW()) AND NOT EXISTS (
+                SELECT 1 FROM async_teams at JOIN team_members tm ON tm.team = at.team JOIN teams t ON t.id = at.team WHERE tm.member = $3 AND t.series = a.series AND t.event = a.event AND at.kind = a.kind AND at.requested IS NOT NULL
+            )"#, data.series as _, &data.event, me.id as _).fetch_one(&mut *transaction).await?;
+            let remaining = remaining_qualifiers(&scoring, num_entered, remaining_live, remaining_asyncs);
+            let projections = projections(&scoring, &my_scores, remaining, target);
+            html! {
+                : header;
+                h2 : "Qualifier Projection";
+                p {
+                    : "The top ";
+                    : cutoff;
+                    : " entrants qualify. You are currently in place ";
+                    : my_rank + 1;
+                    : format!(" with {score:.2} points. ");
+                    @if target > 0.0 {
+                        : format!("The last qualifying spot currently has {target:.2} points.");
+                    } else {
+                        : "Not enough entrants have played the required qualifiers to fill all qualifying spots yet.";
+                    }
+                }
+                @if data.qualifier_score_hiding == QualifierScoreHiding::AsyncOnly && !all_qualifiers_ended && !is_organizer {
+                    p : "Results from async qualifiers which are still open are not included.";
+                }
+                @if qualified_now {
+                    p : "You are currently within the cutoff.";
+                }
+                @if projections.is_empty() {
+                    p : "You have no more qualifiers left that count toward your score.";
+                } else {
+                    p : "Assuming everyone else's results stay the same, you need the following points in each of your next qualifiers to move past the last qualifying spot:";
+                    table {
+                        thead {
+                            tr {
+                                th : "Further qualifiers";
+                                th : "Points needed in each";
+                                th : "Finish time compared to par";
+                            }
+                        }
+                        tbody {
+                            @for projection in projections {
+                                tr {
+                                    td : projection.remaining;
+                                    @if let Some(points) = projection.points {
+                                        td : format!("{points:.2}");
+                                        td {
+                                            @if points <= scoring.min_points {
+                                                : "any finish";
+                                            } else {
+                                                : format!("{:.1}% of par", scoring.par_ratio_for(points) * 100.0);
+                                                @if scoring.standard_adjustments {
+                                                    : " (approximately)";
+                                                }
+                                            }
+                                        }
+                                    } else {
+                                        td(colspan = "2") : "not reachable";
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    p {
+                        small : "Par and other entrants' scores may still change as more results come in.";
+                    }
+                }
+            }
+        } else {
+            html! {
+                : header;
+                p : "You have not entered any qualifiers for this event yet.";
+            }
+        }
+    } else {
+        html! {
+            : header;
+            p : "The organizers have not set how many entrants qualify.";
+        }
+    };
+    Ok(page(transaction, &Some(me), &uri, PageStyle { chests: data.chests().await?, ..PageStyle::default() }, &format!("Qualifier Projection — {}", data.display_name), content).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn qualification(num_entered: usize, num_finished: usize, score: f64) -> Qualification {
+        Qualification::Multiple { num_forfeited: num_entered - num_finished, score: r64(score), round_scores: Vec::default(), num_entered, num_finished }
+    }
+
+    #[test]
+    fn cutoff_skips_entrants_without_required_qualifiers() {
+        let others = [
+            qualification(5, 5, 4000.0),
+            qualification(4, 4, 3800.0),
+            qualification(6, 5, 3000.0),
+            qualification(5, 5, 2500.0),
+        ];
+        assert_eq!(cutoff_score(&QualifierScoring::STANDARD, &others, 1), r64(4000.0));
+        assert_eq!(cutoff_score(&QualifierScoring::STANDARD, &others, 2), r64(3000.0));
+        assert_eq!(cutoff_score(&QualifierScoring::STANDARD, &others, 4), r64(0.0));
+        assert_eq!(cutoff_score(&QualifierScoring::STANDARD, &others, 0), r64(0.0));
+    }
+
+    #[test]
+    fn remaining_qualifiers_are_capped() {
+        assert_eq!(remaining_qualifiers(&QualifierScoring::STANDARD, 2, 3, 1), 4);
+        assert_eq!(remaining_qualifiers(&QualifierScoring::STANDARD, 6, 3, 1), 2);
+        assert_eq!(remaining_qualifiers(&QualifierScoring::STANDARD, 9, 3, 1), 0);
+    }
+
+    #[test]
+    fn projection_requires_enough_qualifiers() {
+        let projections = projections(&QualifierScoring::STANDARD, &[], 5, r64(0.0));
+        assert_eq!(projections.len(), 5);
+        assert!(projections[..4].iter().all(|projection| projection.points.is_none()));
+        assert_eq!(projections[4], Projection { remaining: 5, points: Some(100.0) });
+    }
+
+    #[test]
+    fn projection_points_needed() {
+        // of five equal scores, the best is dropped and the other four count
+        let points = projections(&QualifierScoring::STANDARD, &[], 5, r64(2000.0))[4].points.expect("target should be reachable");
+        assert!((points - 500.0).abs() < 0.01);
+        assert_eq!(projections(&QualifierScoring::STANDARD, &[], 5, r64(4400.0))[4].points, None);
+    }
+}
@@ -0,0 +1,540 @@
+//! Configurable scoring for points-based qualifiers, stored per event in `events.qualifier_scoring`.
+
+use {
+    noisy_float::prelude::*,
+    crate::{
+        event::teams::RoundScore,
+        prelude::*,
+    },
+};
+
+/// How qualifier finish times are turned into points and how points from multiple qualifiers are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct QualifierScoring {
+    /// Par is the average of this many of the fastest finish times.
+    pub(crate) par_size: usize,
+    /// Races with at least this many entrants use `large_race_par_size` instead of `par_size`.
+    pub(crate) large_race_entrants: Option<usize>,
+    pub(crate) large_race_par_size: usize,
+    /// Calculate par from all finish times if there are fewer than required.
+    pub(crate) partial_par: bool,
+    /// Apply the jet and gamble time adjustments from the Standard tournament's scoring document.
+    pub(crate) standard_adjustments: bool,
+    /// Points for finishing exactly at par. Each percent faster or slower than par is worth 1% of this.
+    pub(crate) points_at_par: f64,
+    /// Flat bonus added to the points of each finisher.
+    pub(crate) bonus_points: f64,
+    pub(crate) round_down: bool,
+    /// Must be positive since 0 points marks a DNF.
+    pub(crate) min_points: f64,
+    pub(crate) max_points: Option<f64>,
+    /// Points for a DNF or forfeit, unless `finished_only` is set.
+    pub(crate) dnf_points: f64,
+    pub(crate) required_qualifiers: usize,
+    /// Whether the required qualifiers must be finished rather than just entered.
+    pub(crate) require_finishes: bool,
+    /// Only the first this many qualifiers entered (chronologically) count.
+    pub(crate) max_counted: Option<usize>,
+    /// List qualifiers entered after the first `max_counted` as not counted instead of ignoring them entirely.
+    pub(crate) list_uncounted: bool,
+    /// Ignore DNFs and forfeits when combining scores.
+    pub(crate) finished_only: bool,
+    /// Drop the best score once at least this many qualifiers have been entered.
+    pub(crate) drop_best_from: Option<usize>,
+    /// Drop the worst score once at least this many qualifiers have been entered.
+    pub(crate) drop_worst_from: Option<usize>,
+    /// Only count this many of the best remaining scores.
+    pub(crate) keep_best: Option<usize>,
+    /// Average the remaining scores (dividing by at least `required_qualifiers`) instead of adding them up.
+    pub(crate) average: bool,
+    /// List asyncs which don't have enough finishers for par yet as finished but pending.
+    pub(crate) count_pending_asyncs: bool,
+}
+
+impl QualifierScoring {
+    pub(crate) const STANDARD: Self = Self {
+        par_size: 7,
+        large_race_entrants: None,
+        large_race_par_size: 7,
+        partial_par: true,
+        standard_adjustments: true,
+        points_at_par: 1000.0,
+        bonus_points: 0.0,
+        round_down: false,
+        min_points: 100.0,
+        max_points: Some(1100.0),
+        dnf_points: 0.0,
+        required_qualifiers: 5,
+        require_finishes: true,
+        max_counted: Some(8),
+        list_uncounted: false,
+        finished_only: true,
+        drop_best_from: Some(2),
+        drop_worst_from: None,
+        keep_best: Some(4),
+        average: false,
+        count_pending_asyncs: false,
+    };
+
+    pub(crate) const SGL_2023_ONLINE: Self = Self {
+        par_size: 3,
+        large_race_entrants: Some(20),
+        large_race_par_size: 4,
+        partial_par: false,
+        standard_adjustments: false,
+        points_at_par: 100.0,
+        bonus_points: 0.0,
+        round_down: false,
+        min_points: 10.0,
+        max_points: Some(110.0),
+        dnf_points: 0.0,
+        required_qualifiers: 3,
+        require_finishes: false,
+        max_counted: Some(5),
+        list_uncounted: false,
+        finished_only: false,
+        drop_best_from: Some(4),
+        drop_worst_from: Some(5),
+        keep_best: None,
+        average: true,
+        count_pending_asyncs: false,
+    };
+
+    pub(crate) const SGL_2024_ONLINE: Self = Self {
+        max_counted: Some(6),
+        drop_best_from: None,
+        drop_worst_from: Some(4),
+        ..Self::SGL_2023_ONLINE
+    };
+
+    pub(crate) const SGL_2025_ONLINE: Self = Self {
+        require_finishes: true,
+        ..Self::SGL_2024_ONLINE
+    };
+
+    pub(crate) const TWWR_MAIN: Self = Self {
+        par_size: 3,
+        large_race_entrants: None,
+        large_race_par_size: 3,
+        partial_par: false,
+        standard_adjustments: false,
+        points_at_par: 1000.0,
+        bonus_points: 0.0,
+        round_down: false,
+        min_points: 100.0,
+        max_points: None,
+        dnf_points: 0.0,
+        required_qualifiers: 2,
+        require_finishes: true,
+        max_counted: Some(4),
+        list_uncounted: true,
+        finished_only: true,
+        drop_best_from: None,
+        drop_worst_from: None,
+        keep_best: Some(2),
+        average: false,
+        count_pending_asyncs: true,
+    };
+
+    pub(crate) const TWWR_MINIBLINS_2026: Self = Self {
+        bonus_points: 2000.0,
+        round_down: true,
+        ..Self::TWWR_MAIN
+    };
+
+    /// Formats used by past events, offered as starting points on the qualifiers page.
+    pub(crate) const PRESETS: [(&'static str, &'static str, Self); 6] = [
+        ("standard", "Standard tournament (season 8)", Self::STANDARD),
+        ("sgl2023", "SpeedGaming Live 2023 Online", Self::SGL_2023_ONLINE),
+        ("sgl2024", "SpeedGaming Live 2024 Online", Self::SGL_2024_ONLINE),
+        ("sgl2025", "SpeedGaming Live 2025 Online", Self::SGL_2025_ONLINE),
+        ("twwrmain", "TWWR Main Tournament", Self::TWWR_MAIN),
+        ("miniblins26", "TWWR Miniblins 2026", Self::TWWR_MINIBLINS_2026),
+    ];
+
+    pub(crate) fn preset(id: &str) -> Option<Self> {
+        Self::PRESETS.iter().find(|(preset_id, _, _)| *preset_id == id).map(|(_, _, scoring)| *scoring)
+    }
+
+    pub(crate) fn required_qualifiers(&self) -> usize {
+        self.required_qualifiers
+    }
+
+    pub(crate) fn max_qualifiers_that_count(&self) -> usize {
+        self.max_counted.unwrap_or(usize::MAX)
+    }
+
+    /// The number of finish times par is calculated from, ignoring `partial_par`.
+    pub(crate) fn par_size(&self, num_entrants: usize) -> usize {
+        if self.large_race_entrants.is_some_and(|large_race_entrants| num_entrants >= large_race_entrants) {
+            self.large_race_par_size
+        } else {
+            self.par_size
+        }
+    }
+
+    /// The finish times par is calculated from, or `None` if there aren't enough yet.
+    ///
+    /// `finish_times` must be sorted from fastest to slowest.
+    pub(crate) fn par_times<'a>(&self, finish_times: &'a [Duration], num_entrants: usize) -> Option<&'a [Duration]> {
+        let par_size = self.par_size(num_entrants);
+        if finish_times.len() >= par_size {
+            Some(&finish_times[..par_size])
+        } else if self.partial_par && !finish_times.is_empty() {
+            Some(finish_times)
+        } else {
+            None
+        }
+    }
+
+    /// The par time, given the finish times returned by [`Self::par_times`].
+    pub(crate) fn par_time(&self, par_times: &[Duration]) -> Duration {
+        par_times.iter().sum::<Duration>() / u32::try_from(par_times.len()).expect("too many entrants")
+    }
+
+    /// The points for a finish time, given the finish times returned by [`Self::par_times`].
+    pub(crate) fn points(&self, finish_time: Duration, par_times: &[Duration]) -> f64 {
+        let par_time = self.par_time(par_times);
+        let mut points = if self.standard_adjustments {
+            // https://docs.google.com/document/d/1IHrOGxFQpt3HpQ-9kQ6AVAARc04x6c96N1aHnHfHaKM/edit
+            let finish_time = TimeDelta::from_std(finish_time).expect("finish time out of range");
+            let t_average = TimeDelta::from_std(par_time).expect("finish time out of range");
+            let t_j_h = TimeDelta::minutes(8).mul_f64(1.0.min(0.0.max((TimeDelta::hours(2) + TimeDelta::minutes(30) - t_average).div_duration_f64(TimeDelta::hours(2) + TimeDelta::minutes(30) - (TimeDelta::hours(1) + TimeDelta::minutes(40))))));
+            let t_jet = TimeDelta::minutes(8).min(t_j_h.mul_f64(0.0.max((finish_time - t_average).div_duration_f64(TimeDelta::minutes(8)) * 0.35)));
+            let t_g_h = TimeDelta::from_secs_f64((par_times.iter().map(|&par_time| TimeDelta::from_std(par_time).expect("finish time out of range").abs_diff(t_average).as_secs_f64().powi(2)).sum::<f64>() / 1.max(par_times.len() - 1) as f64).sqrt());
+            let sigma_finish = t_g_h.div_duration_f64(t_average);
+            let t_gamble = TimeDelta::minutes(5).min(t_g_h.mul_f64(0.0.max((finish_time - t_average).div_duration_f64(t_g_h) * 0.0.max(sigma_finish / 0.035 - 1.0) * 0.3)));
+            (1.0 - (finish_time - t_average - TimeDelta::minutes(10).min(t_jet + t_gamble)).div_duration_f64(t_average)) * self.points_at_par
+        } else {
+            (1.0 - (finish_time.as_secs_f64() - par_time.as_secs_f64()) / par_time.as_secs_f64()) * self.points_at_par
+        };
+        if self.round_down {
+            points = points.floor();
+        }
+        points += self.bonus_points;
+        points = points.max(self.min_points);
+        if let Some(max_points) = self.max_points {
+            points = points.min(max_points);
+        }
+        points
+    }
+
+    /// An upper bound for the points of a single qualifier, used to extrapolate worst-case placements.
+    pub(crate) fn best_possible_points(&self) -> f64 {
+        self.max_points.unwrap_or(self.bonus_points + 2.0 * self.points_at_par)
+    }
+
+    /// Combines an entrant's qualifier scores, given in chronological order.
+    ///
+    /// A score of 0 is a DNF or forfeit and a negative score is an async which doesn't have a par time yet.
+    pub(crate) fn aggregate(&self, scores: &[R64]) -> Aggregate {
+        let counted = &scores[..scores.len().min(self.max_qualifiers_that_count())];
+        let listed = if self.list_uncounted { scores } else { counted };
+        let num_entered = listed.len();
+        let num_finished = listed.iter().filter(|&&score| score != 0.0).count(); // pending scores count as finished
+        let mut scores = counted.iter()
+            .copied()
+            .filter(|&score| score > 0.0 || (score == 0.0 && !self.finished_only))
+            .map(|score| if score == 0.0 { r64(self.dnf_points) } else { score })
+            .collect_vec();
+        scores.sort_unstable();
+        if self.drop_best_from.is_some_and(|drop_best_from| num_entered >= drop_best_from) {
+            scores.pop();
+        }
+        if self.drop_worst_from.is_some_and(|drop_worst_from| num_entered >= drop_worst_from) && !scores.is_empty() {
+            scores.remove(0);
+        }
+        if let Some(keep_best) = self.keep_best {
+            scores.reverse();
+            scores.truncate(keep_best);
+        }
+        let total = scores.iter().copied().sum::<R64>();
+        Aggregate {
+            score: if self.average { total / r64(scores.len().max(self.required_qualifiers).max(1) as f64) } else { total },
+            num_entered, num_finished,
+        }
+    }
+
+    /// For each of an entrant's qualifier scores, given in chronological order, whether it is part of the combined score.
+    ///
+    /// Among equal scores, earlier qualifiers are treated as the better ones.
+    pub(crate) fn counted(&self, scores: &[R64]) -> Vec<bool> {
+        let num_entered = scores.len().min(self.max_qualifiers_that_count());
+        let mut indices = (0..num_entered)
+            .filter(|&idx| scores[idx] > 0.0 || (scores[idx] == 0.0 && !self.finished_only))
+            .collect_vec();
+        indices.sort_by_key(|&idx| (if scores[idx] == 0.0 { r64(self.dnf_points) } else { scores[idx] }, Reverse(idx)));
+        if self.drop_best_from.is_some_and(|drop_best_from| num_entered >= drop_best_from) {
+            indices.pop();
+        }
+        if self.drop_worst_from.is_some_and(|drop_worst_from| num_entered >= drop_worst_from) && !indices.is_empty() {
+            indices.remove(0);
+        }
+        if let Some(keep_best) = self.keep_best {
+            indices.reverse();
+            indices.truncate(keep_best);
+        }
+        (0..scores.len()).map(|idx| indices.contains(&idx)).collect()
+    }
+
+    /// Whether an entrant with this aggregate has played enough qualifiers to be ranked.
+    pub(crate) fn has_required_qualifiers(&self, aggregate: &Aggregate) -> bool {
+        let num_counted = if self.require_finishes { aggregate.num_finished } else { aggregate.num_entered };
+        num_counted >= self.required_qualifiers
+    }
+
+    /// The lowest score an entrant needs in each of `remaining` further qualifiers
+    /// for their combined score to exceed `target` and meet the qualifier requirement,
+    /// or `None` if this isn't possible.
+    pub(crate) fn score_needed(&self, scores: &[R64], remaining: usize, target: R64) -> Option<f64> {
+        let meets_target = |points: f64| {
+            let mut scores = scores.to_owned();
+            scores.extend(iter::repeat_n(r64(points), remaining));
+            let aggregate = self.aggregate(&scores);
+            self.has_required_qualifiers(&aggregate) && aggregate.score > target
+        };
+        let (mut low, mut high) = (self.min_points, self.best_possible_points());
+        if meets_target(low) { return Some(low) }
+        if !meets_target(high) { return None }
+        // the combined score never decreases when a qualifier's points increase
+        for _ in 0..64 {
+            let mid = (low + high) / 2.0;
+            if meets_target(mid) { high = mid } else { low = mid }
+        }
+        Some(high)
+    }
+
+    /// The finish time relative to par which scores the given points, ignoring rounding, limits, and the Standard adjustments.
+    pub(crate) fn par_ratio_for(&self, points: f64) -> f64 {
+        2.0 - (points - self.bonus_points) / self.points_at_par
+    }
+
+    /// Checks the settings for values which would make the formulas meaningless.
+    pub(crate) fn validate(&self) -> Result<(), &'static str> {
+        if self.par_size == 0 || self.large_race_par_size == 0 {
+            return Err("Par must be calculated from at least one finish time.")
+        }
+        if !self.points_at_par.is_finite() || self.points_at_par <= 0.0 {
+            return Err("Points at par must be positive.")
+        }
+        if !self.min_points.is_finite() || self.min_points <= 0.0 {
+            return Err("Minimum points must be positive to distinguish finishes from DNFs.")
+        }
+        if self.max_points.is_some_and(|max_points| max_points.is_nan() || max_points < self.min_points) {
+            return Err("Maximum points must not be less than minimum points.")
+        }
+        if !(self.bonus_points.is_finite() && self.dnf_points.is_finite() && self.dnf_points >= 0.0) {
+            return Err("Bonus and DNF points must be numbers and DNF points must not be negative.")
+        }
+        if self.max_counted == Some(0) || self.keep_best == Some(0) {
+            return Err("At least one qualifier must count.")
+        }
+        if self.max_counted.is_some_and(|max_counted| self.required_qualifiers > max_counted) {
+            return Err("The number of required qualifiers can't be more than the number of qualifiers that count.")
+        }
+        Ok(())
+    }
+}
+
+/// How entrants with the same qualifier score are ordered. An event can apply several in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Sequence, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum QualifierTiebreaker {
+    /// The higher best single qualifier score ranks higher.
+    BestScore,
+    /// The entrant who finished ahead in more of the qualifiers both entered ranks higher.
+    HeadToHead,
+    /// The entrant whose last counted qualifier was finished earlier ranks higher.
+    EarliestSubmission,
+}
+
+impl QualifierTiebreaker {
+    pub(crate) fn id(&self) -> &'static str {
+        match self {
+            Self::BestScore => "best_score",
+            Self::HeadToHead => "head_to_head",
+            Self::EarliestSubmission => "earliest_submission",
+        }
+    }
+
+    pub(crate) fn from_id(id: &str) -> Option<Self> {
+        all::<Self>().find(|tiebreaker| tiebreaker.id() == id)
+    }
+
+    pub(crate) fn display_name(&self) -> &'static str {
+        match self {
+            Self::BestScore => "Best single qualifier score",
+            Self::HeadToHead => "Head-to-head in shared qualifiers",
+            Self::EarliestSubmission => "Earliest submission",
+        }
+    }
+
+    /// Compares two entrants' chronological round scores. `Less` means the first entrant ranks higher.
+    pub(crate) fn compare(&self, scoring: &QualifierScoring, rounds1: &[RoundScore], rounds2: &[RoundScore]) -> Ordering {
+        let rounds1 = &rounds1[..rounds1.len().min(scoring.max_qualifiers_that_count())];
+        let rounds2 = &rounds2[..rounds2.len().min(scoring.max_qualifiers_that_count())];
+        match self {
+            Self::BestScore => {
+                let best1 = rounds1.iter().map(|round| round.score).max();
+                let best2 = rounds2.iter().map(|round| round.score).max();
+                best2.cmp(&best1)
+            }
+            Self::HeadToHead => {
+                let (mut wins1, mut wins2) = (0, 0);
+                for round1 in rounds1 {
+                    if round1.score < 0.0 { continue } // pending
+                    if let Some(round2) = rounds2.iter().find(|round2| round2.source == round1.source && round2.score >= 0.0) {
+                        match round1.score.cmp(&round2.score) {
+                            Less => wins2 += 1,
+                            Equal => {}
+                            Greater => wins1 += 1,
+                        }
+                    }
+                }
+                wins2.cmp(&wins1)
+            }
+            Self::EarliestSubmission => {
+                let last1 = rounds1.iter().map(|round| round.submitted_at).max();
+                let last2 = rounds2.iter().map(|round| round.submitted_at).max();
+                last1.cmp(&last2)
+            }
+        }
+    }
+}
+
+/// Applies the tiebreakers in order until one of them separates the entrants.
+pub(crate) fn compare_tiebreakers(tiebreakers: &[QualifierTiebreaker], scoring: &QualifierScoring, rounds1: &[RoundScore], rounds2: &[RoundScore]) -> Ordering {
+    tiebreakers.iter().fold(Equal, |ordering, tiebreaker| ordering.then_with(|| tiebreaker.compare(scoring, rounds1, rounds2)))
+}
+
+pub(crate) struct Aggregate {
+    pub(crate) score: R64,
+    pub(crate) num_entered: usize,
+    pub(crate) num_finished: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::event::teams::RoundSource,
+        super::*,
+    };
+
+    fn scores(scores: &[f64]) -> Vec<R64> {
+        scores.iter().copied().map(r64).collect()
+    }
+
+    #[test]
+    fn presets_match_migration() {
+        let migration = include_str!("../../migrations/093_qualifier_scoring.sql");
+        for (id, _, scoring) in QualifierScoring::PRESETS {
+            let json = serde_json::to_string(&scoring).expect("failed to serialize preset");
+            assert!(migration.contains(&json), "migration is out of sync with preset {id}");
+        }
+    }
+
+    #[test]
+    fn presets_are_valid() {
+        for (id, _, scoring) in QualifierScoring::PRESETS {
+            assert_eq!(scoring.validate(), Ok(()), "invalid preset {id}");
+        }
+    }
+
+    #[test]
+    fn points_at_par() {
+        let par_times = [Duration::from_secs(3600); 3];
+        assert_eq!(QualifierScoring::TWWR_MAIN.points(Duration::from_secs(3600), &par_times), 1000.0);
+        assert_eq!(QualifierScoring::TWWR_MINIBLINS_2026.points(Duration::from_secs(3600), &par_times), 3000.0);
+        assert_eq!(QualifierScoring::SGL_2024_ONLINE.points(Duration::from_secs(3600), &par_times), 100.0);
+        assert_eq!(QualifierScoring::SGL_2024_ONLINE.points(Duration::from_secs(60), &par_times), 110.0);
+        assert_eq!(QualifierScoring::SGL_2024_ONLINE.points(Duration::from_secs(4 * 3600), &par_times), 10.0);
+        assert_eq!(QualifierScoring::TWWR_MINIBLINS_2026.points(Duration::from_secs(2 * 3600), &par_times), 2000.0);
+        assert_eq!(QualifierScoring::TWWR_MINIBLINS_2026.points(Duration::from_secs(2 * 3600 + 1), &par_times), 1999.0);
+    }
+
+    #[test]
+    fn par_size() {
+        let finish_times = [Duration::from_secs(1), Duration::from_secs(2)];
+        assert_eq!(QualifierScoring::SGL_2023_ONLINE.par_size(19), 3);
+        assert_eq!(QualifierScoring::SGL_2023_ONLINE.par_size(20), 4);
+        assert_eq!(QualifierScoring::SGL_2023_ONLINE.par_times(&finish_times, 2), None);
+        assert_eq!(QualifierScoring::STANDARD.par_times(&finish_times, 2), Some(&finish_times[..]));
+    }
+
+    #[test]
+    fn aggregate_sum_of_best() {
+        let aggregate = QualifierScoring::STANDARD.aggregate(&scores(&[500.0, 0.0, 700.0, 800.0, 900.0, 1000.0]));
+        assert_eq!(aggregate.num_entered, 6);
+        assert_eq!(aggregate.num_finished, 5);
+        assert_eq!(aggregate.score, 2900.0); // best score dropped, then 4 best remaining
+    }
+
+    #[test]
+    fn aggregate_pending_and_max_counted() {
+        let aggregate = QualifierScoring::TWWR_MAIN.aggregate(&scores(&[-1.0, 1500.0, 0.0, 1200.0, 1800.0]));
+        assert_eq!(aggregate.num_entered, 5);
+        assert_eq!(aggregate.num_finished, 4);
+        assert_eq!(aggregate.score, 2700.0);
+        let aggregate = QualifierScoring::STANDARD.aggregate(&scores(&[500.0; 10]));
+        assert_eq!(aggregate.num_entered, 8);
+        assert_eq!(aggregate.num_finished, 8);
+    }
+
+    #[test]
+    fn required_qualifiers_must_count() {
+        let scoring = QualifierScoring { required_qualifiers: 5, ..QualifierScoring::TWWR_MAIN };
+        assert!(scoring.validate().is_err());
+        let scoring = QualifierScoring { required_qualifiers: 5, max_counted: None, ..QualifierScoring::TWWR_MAIN };
+        assert_eq!(scoring.validate(), Ok(()));
+    }
+
+    #[test]
+    fn counted() {
+        assert_eq!(QualifierScoring::STANDARD.counted(&scores(&[500.0, 0.0, 700.0, 800.0, 900.0, 1000.0])), [true, false, true, true, true, false]);
+        assert_eq!(QualifierScoring::TWWR_MAIN.counted(&scores(&[-1.0, 1500.0, 0.0, 1200.0, 1800.0])), [false, true, false, true, false]);
+        assert_eq!(QualifierScoring::SGL_2024_ONLINE.counted(&scores(&[50.0, 60.0, 60.0, 90.0])), [false, true, true, true]);
+    }
+
+    #[test]
+    fn score_needed() {
+        let scoring = QualifierScoring::TWWR_MAIN;
+        // two best of the first four count, so one more finish has to beat 2500 together with the current best of 1500
+        assert!((scoring.score_needed(&scores(&[1500.0, 0.0]), 1, r64(2500.0)).unwrap() - 1000.0).abs() < 1e-6);
+        // already above the target with any finish
+        assert_eq!(scoring.score_needed(&scores(&[1500.0, 1400.0]), 1, r64(2000.0)), Some(100.0));
+        // the qualifier requirement can't be met
+        assert_eq!(scoring.score_needed(&scores(&[0.0, 0.0, 0.0]), 0, r64(0.0)), None);
+        assert_eq!(scoring.score_needed(&scores(&[0.0, 0.0, 0.0]), 1, r64(0.0)), None);
+        assert!((scoring.par_ratio_for(1000.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tiebreakers() {
+        let round = |score: f64, source: RoundSource, minutes: i64| RoundScore {
+            score: r64(score),
+            source,
+            submitted_at: DateTime::from_timestamp(minutes * 60, 0).unwrap(),
+            finish_time: None,
+            par_time: None,
+        };
+        let rounds1 = [round(900.0, RoundSource::Live(1), 0), round(1100.0, RoundSource::Live(2), 100)];
+        let rounds2 = [round(1000.0, RoundSource::Live(1), 0), round(1000.0, RoundSource::Live(3), 50)];
+        let scoring = QualifierScoring::TWWR_MAIN;
+        assert_eq!(QualifierTiebreaker::BestScore.compare(&scoring, &rounds1, &rounds2), Less);
+        assert_eq!(QualifierTiebreaker::HeadToHead.compare(&scoring, &rounds1, &rounds2), Greater);
+        assert_eq!(QualifierTiebreaker::EarliestSubmission.compare(&scoring, &rounds1, &rounds2), Greater);
+        assert_eq!(compare_tiebreakers(&[QualifierTiebreaker::HeadToHead, QualifierTiebreaker::BestScore], &scoring, &rounds1, &rounds2), Greater);
+        assert_eq!(compare_tiebreakers(&[], &scoring, &rounds1, &rounds2), Equal);
+    }
+
+    #[test]
+    fn aggregate_average() {
+        let aggregate = QualifierScoring::SGL_2023_ONLINE.aggregate(&scores(&[50.0, 60.0, 70.0, 80.0, 90.0]));
+        assert_eq!(aggregate.score, 70.0);
+        let aggregate = QualifierScoring::SGL_2023_ONLINE.aggregate(&scores(&[90.0]));
+        assert_eq!(aggregate.score, 30.0); // divided by the number of required qualifiers
+        let aggregate = QualifierScoring { dnf_points: 5.0, ..QualifierScoring::SGL_2024_ONLINE }.aggregate(&scores(&[0.0, 100.0, 100.0]));
+        assert_eq!(aggregate.num_finished, 2);
+        assert_eq!(aggregate.score, 205.0 / 3.0);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     cal::{Entrants, Race, RaceSchedule, Source},
     discord_scheduled_events::DiscordCtx,
-    event::{Data, QualifierScoreHiding, Series, Tab},
+    event::{Data, QualifierScoreHiding, Series, Tab, async_verification, qualifier_scoring::{QualifierScoring, QualifierTiebreaker}},
     prelude::*,
     seed,
     volunteer_requests,
@@ -30,16 +30,31 @@ async fn qualifiers_form(mut transaction: Transaction<'_, Postgres>, me: User, u
     .await?;
 
     let current_role_str = event.qualifier_notification_role_id.map(|id| id.get().to_string()).unwrap_or_default();
+    let scoring = event.qualifier_scoring;
+    let current_preset = match scoring {
+        None => "none",
+        Some(scoring) => QualifierScoring::PRESETS.iter().find(|(_, _, preset)| *preset == scoring).map_or("custom", |&(id, _, _)| id),
+    };
+    let selected_preset = ctx.field_value("preset").unwrap_or(current_preset);
+    let scoring_value = |name: &str, current: fn(QualifierScoring) -> Option<String>| ctx.field_value(name).map(str::to_owned).or_else(|| scoring.and_then(current)).unwrap_or_default();
+    let scoring_checked = |name: &str, current: fn(QualifierScoring) -> bool| ctx.field_value(name).map_or(scoring.is_some_and(current), |v| v == "on");
+    // all forms on this page share the context of the one that was submitted, so its errors are only shown on that form
+    let form_errors = |submitted: bool| if submitted { ctx.errors().collect_vec() } else { Vec::default() };
+    let mut role_errors = form_errors(ctx.field_value("notification_role_id").is_some());
+    let mut settings_errors = form_errors(ctx.field_value("qualifier_score_hiding").is_some());
+    let mut scoring_errors = form_errors(ctx.field_value("preset").is_some());
+    let mut seeding_race_errors = form_errors(ctx.field_value("race_start").is_some() && ctx.field_value("race_round").is_none());
+    let mut race_errors = form_errors(ctx.field_value("race_round").is_some());
     Ok(page(transaction, &Some(me), &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Qualifiers — {}", event.display_name), html! {
         : header;
         article {
             h2 : "Qualifier Announcement Ping";
             : full_form(uri!(post_notification_role(event.series, &*event.event)), csrf, html! {
-                : form_field("notification_role_id", &mut ctx.errors().collect_vec(), html! {
+                : form_field("notification_role_id", &mut role_errors, html! {
                     label(for = "notification_role_id") : "Role ID to ping when a qualifier room opens:";
                     input(type = "text", id = "notification_role_id", name = "notification_role_id", value = ctx.field_value("notification_role_id").unwrap_or(&current_role_str), placeholder = "Discord role ID (optional)", style = "width: 100%; max-width: 400px;");
                 });
-            }, ctx.errors().collect_vec(), "Save");
+            }, role_errors, "Save");
             @if event.qualifier_notification_role_id.is_some() {
                 form(action = uri!(delete_notification_role(event.series, &*event.event)).to_string(), method = "post", style = "display: inline;") {
                     input(type = "hidden", name = "csrf", value? = csrf.map(|token| token.authenticity_token()));
@@ -49,7 +64,7 @@ async fn qualifiers_form(mut transaction: Transaction<'_, Postgres>, me: User, u
 
             h2 : "Qualifier Settings";
             : full_form(uri!(post_settings(event.series, &*event.event)), csrf, html! {
-                : form_field("qualifier_score_hiding", &mut ctx.errors().collect_vec(), html! {
+                : form_field("qualifier_score_hiding", &mut settings_errors, html! {
                     label(for = "qualifier_score_hiding") : "Qualifier Score Hiding";
                     select(id = "qualifier_score_hiding", name = "qualifier_score_hiding") {
                         option(value = "none", selected? = ctx.field_value("qualifier_score_hiding").map_or(event.qualifier_score_hiding == QualifierScoreHiding::None, |v| v == "none")) : "None (show all scores)";
@@ -59,12 +74,160 @@ async fn qualifiers_form(mut transaction: Transaction<'_, Postgres>, me: User, u
                         option(value = "full_complete", selected? = ctx.field_value("qualifier_score_hiding").map_or(event.qualifier_score_hiding == QualifierScoreHiding::FullComplete, |v| v == "full_complete")) : "Full complete (hide everything)";
                     }
                 });
-                : form_field("automated_asyncs", &mut ctx.errors().collect_vec(), html! {
+                : form_field("automated_asyncs", &mut settings_errors, html! {
                     input(type = "checkbox", id = "automated_asyncs", name = "automated_asyncs", checked? = ctx.field_value("automated_asyncs").map_or(event.automated_asyncs, |v| v == "on"));
                     label(for = "automated_asyncs") : "Use automated Discord threads for qualifier asyncs";
                     label(class = "help") : " (When enabled, qualifier requests create private Discord threads with READY/countdown/FINISH buttons)";
                 });
-            }, ctx.errors().collect_vec(), "Save Settings");
+                : form_field("async_verification", &mut settings_errors, html! {
+                    input(type = "checkbox", id = "async_verification", name = "async_verification", checked? = ctx.field_value("async_verification").map_or(event.async_verification, |v| v == "on"));
+                    label(for = "async_verification") : "Require async runs to be verified";
+                    label(class = "help") : " (When enabled, submitted async times only count once an organizer or async verifier has reviewed the VOD)";
+                });
+            }, settings_errors, "Save Settings");
+            @if event.async_verification {
+                p {
+                    a(class = "button", href = uri!(async_verification::get(event.series, &*event.event)).to_string()) : "Async Verification Queue";
+                }
+            }
+
+            h2 : "Qualifier Scoring";
+            p : "How finish times in qualifiers are turned into points and how points from multiple qualifiers are combined. Points are a percentage of the points at par, based on how much faster or slower than par a finish is.";
+            : full_form(uri!(post_scoring(event.series, &*event.event)), csrf, html! {
+                : form_field("preset", &mut scoring_errors, html! {
+                    label(for = "preset") : "Format:";
+                    select(id = "preset", name = "preset") {
+                        option(value = "none", selected? = selected_preset == "none") : "Not points-based";
+                        @for (id, display_name, _) in QualifierScoring::PRESETS {
+                            option(value = id, selected? = selected_preset == id) : display_name;
+                        }
+                        option(value = "custom", selected? = selected_preset == "custom") : "Custom (use the settings below)";
+                    }
+                });
+                fieldset {
+                    legend : "Par";
+                    : form_field("par_size", &mut scoring_errors, html! {
+                        label(for = "par_size") : "Par is the average of the fastest";
+                        input(type = "number", min = "1", id = "par_size", name = "par_size", value = scoring_value("par_size", |scoring| Some(scoring.par_size.to_string())));
+                        label(for = "par_size") : "finish times";
+                    });
+                    : form_field("large_race_entrants", &mut scoring_errors, html! {
+                        label(for = "large_race_entrants") : "In races with at least";
+                        input(type = "number", min = "1", id = "large_race_entrants", name = "large_race_entrants", value = scoring_value("large_race_entrants", |scoring| scoring.large_race_entrants.map(|n| n.to_string())));
+                        label(for = "large_race_par_size") : "entrants, use the fastest";
+                        input(type = "number", min = "1", id = "large_race_par_size", name = "large_race_par_size", value = scoring_value("large_race_par_size", |scoring| Some(scoring.large_race_par_size.to_string())));
+                        label(class = "help") : "(leave the number of entrants blank to always use the above)";
+                    });
+                    : form_field("partial_par", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "partial_par", name = "partial_par", checked? = scoring_checked("partial_par", |scoring| scoring.partial_par));
+                        label(for = "partial_par") : "Calculate par from fewer finish times if there aren't enough";
+                    });
+                    : form_field("standard_adjustments", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "standard_adjustments", name = "standard_adjustments", checked? = scoring_checked("standard_adjustments", |scoring| scoring.standard_adjustments));
+                        label(for = "standard_adjustments") : "Apply the jet and gamble time adjustments from the Standard tournament";
+                    });
+                }
+                fieldset {
+                    legend : "Points";
+                    : form_field("points_at_par", &mut scoring_errors, html! {
+                        label(for = "points_at_par") : "Points at par:";
+                        input(type = "number", step = "any", id = "points_at_par", name = "points_at_par", value = scoring_value("points_at_par", |scoring| Some(scoring.points_at_par.to_string())));
+                    });
+                    : form_field("bonus_points", &mut scoring_errors, html! {
+                        label(for = "bonus_points") : "Bonus points per finish:";
+                        input(type = "number", step = "any", id = "bonus_points", name = "bonus_points", value = scoring_value("bonus_points", |scoring| Some(scoring.bonus_points.to_string())));
+                    });
+                    : form_field("round_down", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "round_down", name = "round_down", checked? = scoring_checked("round_down", |scoring| scoring.round_down));
+                        label(for = "round_down") : "Round points down to whole numbers";
+                    });
+                    : form_field("min_points", &mut scoring_errors, html! {
+                        label(for = "min_points") : "Minimum points per finish:";
+                        input(type = "number", step = "any", id = "min_points", name = "min_points", value = scoring_value("min_points", |scoring| Some(scoring.min_points.to_string())));
+                    });
+                    : form_field("max_points", &mut scoring_errors, html! {
+                        label(for = "max_points") : "Point cap:";
+                        input(type = "number", step = "any", id = "max_points", name = "max_points", value = scoring_value("max_points", |scoring| scoring.max_points.map(|points| points.to_string())));
+                        label(class = "help") : "(leave blank for no cap)";
+                    });
+                    : form_field("dnf_points", &mut scoring_errors, html! {
+                        label(for = "dnf_points") : "Points for a DNF or forfeit:";
+                        input(type = "number", step = "any", min = "0", id = "dnf_points", name = "dnf_points", value = scoring_value("dnf_points", |scoring| Some(scoring.dnf_points.to_string())));
+                    });
+                }
+                fieldset {
+                    legend : "Counted qualifiers";
+                    : form_field("required_qualifiers", &mut scoring_errors, html! {
+                        label(for = "required_qualifiers") : "Required qualifiers:";
+                        input(type = "number", min = "0", id = "required_qualifiers", name = "required_qualifiers", value = scoring_value("required_qualifiers", |scoring| Some(scoring.required_qualifiers.to_string())));
+                    });
+                    : form_field("require_finishes", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "require_finishes", name = "require_finishes", checked? = scoring_checked("require_finishes", |scoring| scoring.require_finishes));
+                        label(for = "require_finishes") : "Required qualifiers must be finished (not just entered)";
+                    });
+                    : form_field("max_counted", &mut scoring_errors, html! {
+                        label(for = "max_counted") : "Only the first";
+                        input(type = "number", min = "1", id = "max_counted", name = "max_counted", value = scoring_value("max_counted", |scoring| scoring.max_counted.map(|n| n.to_string())));
+                        label(for = "max_counted") : "qualifiers entered count";
+                        label(class = "help") : "(leave blank to count all)";
+                    });
+                    : form_field("list_uncounted", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "list_uncounted", name = "list_uncounted", checked? = scoring_checked("list_uncounted", |scoring| scoring.list_uncounted));
+                        label(for = "list_uncounted") : "List qualifiers entered after that as not counted instead of ignoring them";
+                    });
+                    : form_field("finished_only", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "finished_only", name = "finished_only", checked? = scoring_checked("finished_only", |scoring| scoring.finished_only));
+                        label(for = "finished_only") : "Ignore DNFs and forfeits instead of scoring them";
+                    });
+                    : form_field("drop_best_from", &mut scoring_errors, html! {
+                        label(for = "drop_best_from") : "Drop the best score after entering";
+                        input(type = "number", min = "1", id = "drop_best_from", name = "drop_best_from", value = scoring_value("drop_best_from", |scoring| scoring.drop_best_from.map(|n| n.to_string())));
+                        label(for = "drop_best_from") : "qualifiers";
+                        label(class = "help") : "(leave blank to never drop it)";
+                    });
+                    : form_field("drop_worst_from", &mut scoring_errors, html! {
+                        label(for = "drop_worst_from") : "Drop the worst score after entering";
+                        input(type = "number", min = "1", id = "drop_worst_from", name = "drop_worst_from", value = scoring_value("drop_worst_from", |scoring| scoring.drop_worst_from.map(|n| n.to_string())));
+                        label(for = "drop_worst_from") : "qualifiers";
+                        label(class = "help") : "(leave blank to never drop it)";
+                    });
+                    : form_field("keep_best", &mut scoring_errors, html! {
+                        label(for = "keep_best") : "Only count the best";
+                        input(type = "number", min = "1", id = "keep_best", name = "keep_best", value = scoring_value("keep_best", |scoring| scoring.keep_best.map(|n| n.to_string())));
+                        label(for = "keep_best") : "remaining scores";
+                        label(class = "help") : "(leave blank to count all)";
+                    });
+                    : form_field("average", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "average", name = "average", checked? = scoring_checked("average", |scoring| scoring.average));
+                        label(for = "average") : "Average the counted scores instead of adding them up";
+                        label(class = "help") : " (divided by at least the number of required qualifiers)";
+                    });
+                    : form_field("count_pending_asyncs", &mut scoring_errors, html! {
+                        input(type = "checkbox", id = "count_pending_asyncs", name = "count_pending_asyncs", checked? = scoring_checked("count_pending_asyncs", |scoring| scoring.count_pending_asyncs));
+                        label(for = "count_pending_asyncs") : "Count asyncs without enough finishers for par as finished (scored once par is known)";
+                    });
+                }
+                fieldset {
+                    legend : "Ranking";
+                    : form_field("qualifier_cutoff", &mut scoring_errors, html! {
+                        label(for = "qualifier_cutoff") : "Number of entrants who qualify:";
+                        input(type = "number", min = "1", id = "qualifier_cutoff", name = "qualifier_cutoff", value = ctx.field_value("qualifier_cutoff").map(str::to_owned).or_else(|| event.qualifier_cutoff.map(|cutoff| cutoff.to_string())).unwrap_or_default());
+                        label(class = "help") : "(used to show entrants what they need in the remaining qualifiers; leave blank to disable)";
+                    });
+                    @for (i, name) in ["tiebreaker1", "tiebreaker2", "tiebreaker3"].into_iter().enumerate() {
+                        : form_field(name, &mut scoring_errors, html! {
+                            label(for = name) : format!("Tiebreaker {}:", i + 1);
+                            @let selected = ctx.field_value(name).or_else(|| event.qualifier_tiebreakers.get(i).map(|tiebreaker| tiebreaker.id())).unwrap_or_default();
+                            select(id = name, name = name) {
+                                option(value = "", selected? = selected.is_empty()) : "None";
+                                @for tiebreaker in all::<QualifierTiebreaker>() {
+                                    option(value = tiebreaker.id(), selected? = selected == tiebreaker.id()) : tiebreaker.display_name();
+                                }
+                            }
+                        });
+                    }
+                }
+            }, scoring_errors, "Save Scoring");
 
             h2 : "Seeding Race";
             @if let Some(ref sr) = seeding_race {
@@ -104,15 +267,15 @@ async fn qualifiers_form(mut transaction: Transaction<'_, Postgres>, me: User, u
             } else {
                 h3 : "Create Seeding Race";
                 : full_form(uri!(post_seeding_race(event.series, &*event.event)), csrf, html! {
-                    : form_field("race_start", &mut ctx.errors().collect_vec(), html! {
+                    : form_field("race_start", &mut seeding_race_errors, html! {
                         label(for = "seeding_race_start") : "Start Time (UTC)";
                         input(type = "datetime-local", name = "race_start", id = "seeding_race_start", value = ctx.field_value("race_start").unwrap_or(""));
                     });
-                    : form_field("race_room", &mut ctx.errors().collect_vec(), html! {
+                    : form_field("race_room", &mut seeding_race_errors, html! {
                         label(for = "seeding_race_room") : "Racetime.gg Room URL (optional)";
                         input(type = "text", name = "race_room", id = "seeding_race_room", value = ctx.field_value("race_room").unwrap_or(""), placeholder = "https://racetime.gg/...", style = "width: 100%; max-width: 600px;");
                     });
-                }, ctx.errors().collect_vec(), "Create Seeding Race");
+                }, seeding_race_errors, "Create Seeding Race");
             }
 
             h2 : "Live Qualifier Races";
@@ -161,19 +324,19 @@ async fn qualifiers_form(mut transaction: Transaction<'_, Postgres>, me: User, u
             } else {
                 h3 : "Create Live Qualifier Race";
                 : full_form(uri!(post_race(event.series, &*event.event)), csrf, html! {
-                    : form_field("race_round", &mut ctx.errors().collect_vec(), html! {
+                    : form_field("race_round", &mut race_errors, html! {
                         label(for = "race_round") : "Round";
                         input(type = "text", name = "race_round", id = "race_round", value = ctx.field_value("race_round").unwrap_or(""), placeholder = "e.g. Live 1");
                     });
-                    : form_field("race_start", &mut ctx.errors().collect_vec(), html! {
+                    : form_field("race_start", &mut race_errors, html! {
                         label(for = "race_start") : "Start Time (UTC)";
                         input(type = "datetime-local", name = "race_start", id = "race_start", value = ctx.field_value("race_start").unwrap_or(""));
                     });
-                    : form_field("race_room", &mut ctx.errors().collect_vec(), html! {
+                    : form_field("race_room", &mut race_errors, html! {
                         label(for = "race_room") : "Racetime.gg Room URL (optional)";
                         input(type = "text", name = "race_room", id = "race_room", value = ctx.field_value("race_room").unwrap_or(""), placeholder = "https://racetime.gg/...", style = "width: 100%; max-width: 600px;");
                     });
-                }, ctx.errors().collect_vec(), "Create Race");
+                }, race_errors, "Create Race");
             }
         }
     }).await?)
@@ -376,6 +539,7 @@ pub(crate) struct QualifierSettingsForm {
     #[field(default = String::new())]
     qualifier_score_hiding: String,
     automated_asyncs: bool,
+    async_verification: bool,
 }
 
 #[rocket::post("/event/<series>/<event>/qualifiers/settings", data = "<form>")]
@@ -404,11 +568,20 @@ pub(crate) async fn post_settings(pool: &State<PgPool>, me: User, uri: Origin<'_
         };
 
         sqlx::query!(
-            "UPDATE events SET qualifier_score_hiding = $1, automated_asyncs = $2 WHERE series = $3 AND event = $4",
-            qualifier_score_hiding as _, value.automated_asyncs, series as _, event
+            "UPDATE events SET qualifier_score_hiding = $1, automated_asyncs = $2, async_verification = $3 WHERE series = $4 AND event = $5",
+            qualifier_score_hiding as _, value.automated_asyncs, value.async_verification, series as _, event
         )
         .execute(&mut *transaction)
         .await?;
+        if !value.async_verification {
+            // runs still waiting for review would otherwise never count
+            sqlx::query!(
+                "UPDATE async_players SET verification_status = 'verified' WHERE series = $1 AND event = $2 AND verification_status = 'pending'",
+                series as _, event
+            )
+            .execute(&mut *transaction)
+            .await?;
+        }
         transaction.commit().await?;
         RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event))))
     } else {
@@ -417,6 +590,158 @@ pub(crate) async fn post_settings(pool: &State<PgPool>, me: User, uri: Origin<'_
     })
 }
 
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct QualifierScoringForm {
+    #[field(default = String::new())]
+    csrf: String,
+    #[field(default = String::new())]
+    preset: String,
+    #[field(default = String::new())]
+    par_size: String,
+    #[field(default = String::new())]
+    large_race_entrants: String,
+    #[field(default = String::new())]
+    large_race_par_size: String,
+    partial_par: bool,
+    standard_adjustments: bool,
+    #[field(default = String::new())]
+    points_at_par: String,
+    #[field(default = String::new())]
+    bonus_points: String,
+    round_down: bool,
+    #[field(default = String::new())]
+    min_points: String,
+    #[field(default = String::new())]
+    max_points: String,
+    #[field(default = String::new())]
+    dnf_points: String,
+    #[field(default = String::new())]
+    required_qualifiers: String,
+    require_finishes: bool,
+    #[field(default = String::new())]
+    max_counted: String,
+    list_uncounted: bool,
+    finished_only: bool,
+    #[field(default = String::new())]
+    drop_best_from: String,
+    #[field(default = String::new())]
+    drop_worst_from: String,
+    #[field(default = String::new())]
+    keep_best: String,
+    average: bool,
+    count_pending_asyncs: bool,
+    #[field(default = String::new())]
+    qualifier_cutoff: String,
+    #[field(default = String::new())]
+    tiebreaker1: String,
+    #[field(default = String::new())]
+    tiebreaker2: String,
+    #[field(default = String::new())]
+    tiebreaker3: String,
+}
+
+/// Parses a numeric scoring setting, pushing a validation error if it's invalid, or empty and `required`.
+fn parse_scoring_field<T: FromStr>(context: &mut Context<'_>, name: &'static str, value: &str, required: bool) -> Option<T> {
+    let value = value.trim();
+    if value.is_empty() {
+        if required {
+            context.push_error(form::Error::validation("This field is required for custom scoring.").with_name(name));
+        }
+        None
+    } else if let Ok(value) = value.parse() {
+        Some(value)
+    } else {
+        context.push_error(form::Error::validation("Must be a number.").with_name(name));
+        None
+    }
+}
+
+#[rocket::post("/event/<series>/<event>/qualifiers/scoring", data = "<form>")]
+pub(crate) async fn post_scoring(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, QualifierScoringForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event_data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+
+    if !me.is_global_admin() && !event_data.organizers(&mut transaction).await?.contains(&me) {
+        return Err(StatusOrError::Status(Status::Forbidden));
+    }
+
+    Ok(if let Some(ref value) = form.value {
+        let scoring = match &*value.preset {
+            "none" => None,
+            "custom" => {
+                let scoring = QualifierScoring {
+                    par_size: parse_scoring_field(&mut form.context, "par_size", &value.par_size, true).unwrap_or(1),
+                    large_race_entrants: parse_scoring_field(&mut form.context, "large_race_entrants", &value.large_race_entrants, false),
+                    large_race_par_size: parse_scoring_field(&mut form.context, "large_race_par_size", &value.large_race_par_size, false).unwrap_or(1),
+                    partial_par: value.partial_par,
+                    standard_adjustments: value.standard_adjustments,
+                    points_at_par: parse_scoring_field(&mut form.context, "points_at_par", &value.points_at_par, true).unwrap_or(1.0),
+                    bonus_points: parse_scoring_field(&mut form.context, "bonus_points", &value.bonus_points, false).unwrap_or(0.0),
+                    round_down: value.round_down,
+                    min_points: parse_scoring_field(&mut form.context, "min_points", &value.min_points, true).unwrap_or(1.0),
+                    max_points: parse_scoring_field(&mut form.context, "max_points", &value.max_points, false),
+                    dnf_points: parse_scoring_field(&mut form.context, "dnf_points", &value.dnf_points, false).unwrap_or(0.0),
+                    required_qualifiers: parse_scoring_field(&mut form.context, "required_qualifiers", &value.required_qualifiers, true).unwrap_or(0),
+                    require_finishes: value.require_finishes,
+                    max_counted: parse_scoring_field(&mut form.context, "max_counted", &value.max_counted, false),
+                    list_uncounted: value.list_uncounted,
+                    finished_only: value.finished_only,
+                    drop_best_from: parse_scoring_field(&mut form.context, "drop_best_from", &value.drop_best_from, false),
+                    drop_worst_from: parse_scoring_field(&mut form.context, "drop_worst_from", &value.drop_worst_from, false),
+                    keep_best: parse_scoring_field(&mut form.context, "keep_best", &value.keep_best, false),
+                    average: value.average,
+                    count_pending_asyncs: value.count_pending_asyncs,
+                };
+                if let Err(msg) = scoring.validate() {
+                    form.context.push_error(form::Error::validation(msg));
+                }
+                Some(scoring)
+            }
+            preset => if let Some(scoring) = QualifierScoring::preset(preset) {
+                Some(scoring)
+            } else {
+                form.context.push_error(form::Error::validation("Invalid scoring format.").with_name("preset"));
+                None
+            },
+        };
+        let qualifier_cutoff = parse_scoring_field::<i32>(&mut form.context, "qualifier_cutoff", &value.qualifier_cutoff, false);
+        if qualifier_cutoff.is_some_and(|cutoff| cutoff <= 0) {
+            form.context.push_error(form::Error::validation("Must be at least 1.").with_name("qualifier_cutoff"));
+        }
+        let mut tiebreakers = Vec::default();
+        for (name, value) in [("tiebreaker1", &value.tiebreaker1), ("tiebreaker2", &value.tiebreaker2), ("tiebreaker3", &value.tiebreaker3)] {
+            if value.is_empty() { continue }
+            if let Some(tiebreaker) = QualifierTiebreaker::from_id(value) {
+                if tiebreakers.contains(&tiebreaker) {
+                    form.context.push_error(form::Error::validation("This tiebreaker is already used.").with_name(name));
+                } else {
+                    tiebreakers.push(tiebreaker);
+                }
+            } else {
+                form.context.push_error(form::Error::validation("Invalid tiebreaker.").with_name(name));
+            }
+        }
+        if form.context.errors().next().is_some() {
+            let is_started = event_data.is_started(&mut transaction).await?;
+            RedirectOrContent::Content(qualifiers_form(transaction, me, uri, csrf.as_ref(), event_data, is_started, form.context).await?)
+        } else {
+            sqlx::query!(
+                "UPDATE events SET qualifier_scoring = $1, qualifier_tiebreakers = $2, qualifier_cutoff = $3 WHERE series = $4 AND event = $5",
+                scoring.map(sqlx::types::Json) as _, sqlx::types::Json(&tiebreakers) as _, qualifier_cutoff, series as _, event
+            )
+            .execute(&mut *transaction)
+            .await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event))))
+        }
+    } else {
+        let is_started = event_data.is_started(&mut transaction).await?;
+        RedirectOrContent::Content(qualifiers_form(transaction, me, uri, csrf.as_ref(), event_data, is_started, form.context).await?)
+    })
+}
+
 #[derive(FromForm, CsrfForm)]
 pub(crate) struct NotificationRoleForm {
     #[field(default = String::new())]
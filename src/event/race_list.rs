@@ -0,0 +1,530 @@
+//! Filtering, sorting, and pagination of an event's races page, and exports of the same filtered view.
+
+use crate::{
+    event::{
+        Data,
+        Series,
+    },
+    prelude::*,
+};
+
+const PAGE_SIZE: usize = 50;
+
+#[derive(Debug, thiserror::Error, rocket_util::Error)]
+pub(crate) enum Error {
+    #[error(transparent)] Cal(#[from] cal::Error),
+    #[error(transparent)] Csv(#[from] csv::Error),
+    #[error(transparent)] Data(#[from] super::DataError),
+    #[error(transparent)] Event(#[from] super::Error),
+    #[error(transparent)] IntoInner(#[from] csv::IntoInnerError<csv::Writer<Vec<u8>>>),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+}
+
+impl<E: Into<Error>> From<E> for StatusOrError<Error> {
+    fn from(e: E) -> Self {
+        Self::Err(e.into())
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Sequence, FromFormField, UriDisplayQuery)]
+pub(crate) enum SortKey {
+    #[default]
+    Start,
+    Phase,
+    Round,
+}
+
+impl SortKey {
+    fn display_name(&self) -> &'static str {
+        match self {
+            Self::Start => "Start",
+            Self::Phase => "Phase",
+            Self::Round => "Round",
+        }
+    }
+}
+
+/// Query parameters of the races page. Dates are interpreted as UTC.
+#[derive(Default, Clone, FromForm, UriDisplayQuery)]
+pub(crate) struct Filter {
+    phase: Option<String>,
+    round: Option<String>,
+    entrant: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    restreamed: bool,
+    unscheduled: bool,
+    sort: Option<SortKey>,
+    desc: bool,
+    upcoming_page: Option<usize>,
+    page: Option<usize>,
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn parse_date(value: &Option<String>) -> Option<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(non_empty(value)?, "%Y-%m-%d").ok()?;
+    Some(date.and_time(NaiveTime::MIN).and_utc())
+}
+
+fn race_starts(race: &Race) -> Vec<DateTime<Utc>> {
+    match race.schedule {
+        RaceSchedule::Unscheduled => Vec::default(),
+        RaceSchedule::Live { start, .. } => vec![start],
+        RaceSchedule::Async { start1, start2, start3, .. } => [start1, start2, start3].into_iter().flatten().collect(),
+    }
+}
+
+/// Compares strings so that runs of digits are ordered by their numeric value, e.g. “Round 2” before “Round 10”.
+pub(crate) fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(char_a), Some(char_b)) if char_a.is_ascii_digit() && char_b.is_ascii_digit() => {
+                let digits_a = a.peeking_take_while(char::is_ascii_digit).collect::<String>();
+                let digits_b = b.peeking_take_while(char::is_ascii_digit).collect::<String>();
+                let digits_a = digits_a.trim_start_matches('0');
+                let digits_b = digits_b.trim_start_matches('0');
+                match digits_a.len().cmp(&digits_b.len()).then_with(|| digits_a.cmp(digits_b)) {
+                    Ordering::Equal => {}
+                    ordering => return ordering,
+                }
+            }
+            (Some(char_a), Some(char_b)) => match char_a.cmp(&char_b) {
+                Ordering::Equal => {
+                    a.next();
+                    b.next();
+                }
+                ordering => return ordering,
+            },
+        }
+    }
+}
+
+fn natural_cmp_option(a: Option<&str>, b: Option<&str>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => natural_cmp(a, b),
+    }
+}
+
+/// The names an entrant filter is matched against, i.e. team names and the display names of team members.
+///
+/// Loaded for all races at once so filtering and exporting don't need any queries per race.
+#[derive(Default)]
+struct EntrantNames {
+    team_members: HashMap<Id<Teams>, Vec<String>>,
+    discord_users: HashMap<UserId, String>,
+}
+
+impl EntrantNames {
+    async fn load(transaction: &mut Transaction<'_, Postgres>, races: &[Race]) -> sqlx::Result<Self> {
+        let mut team_ids = Vec::default();
+        let mut discord_ids = Vec::default();
+        for race in races {
+            for entrant in race_entrants(race) {
+                match entrant {
+                    Entrant::MidosHouseTeam(team) => team_ids.push(team.id),
+                    Entrant::Discord { id, .. } => discord_ids.push(id.get() as i64),
+                    Entrant::Named { .. } => {}
+                }
+            }
+        }
+        let mut names = Self::default();
+        if !team_ids.is_empty() {
+            for row in sqlx::query!(r#"
+                SELECT team AS "team: Id<Teams>", CASE WHEN display_source = 'racetime' THEN racetime_display_name ELSE discord_display_name END AS "name"
+                FROM team_members JOIN users ON users.id = team_members.member
+                WHERE team = ANY($1)
+            "#, &team_ids as _).fetch_all(&mut **transaction).await? {
+                names.team_members.entry(row.team).or_insert_with(Vec::default).extend(row.name);
+            }
+        }
+        if !discord_ids.is_empty() {
+            for row in sqlx::query!(r#"
+                SELECT discord_id AS "discord_id!: PgSnowflake<UserId>", CASE WHEN display_source = 'racetime' THEN racetime_display_name ELSE discord_display_name END AS "name"
+                FROM users
+                WHERE discord_id = ANY($1)
+            "#, &discord_ids).fetch_all(&mut **transaction).await? {
+                if let Some(name) = row.name {
+                    names.discord_users.insert(row.discord_id.0, name);
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    fn for_race(&self, race: &Race) -> Vec<String> {
+        let mut names = Vec::default();
+        if let Entrants::Named(ref name) = race.entrants {
+            names.push(name.clone());
+        }
+        for entrant in race_entrants(race) {
+            match entrant {
+                Entrant::MidosHouseTeam(team) => {
+                    names.extend(team.name.clone());
+                    names.extend(self.team_members.get(&team.id).into_iter().flatten().cloned());
+                }
+                Entrant::Discord { id, .. } => names.extend(self.discord_users.get(id).cloned()),
+                Entrant::Named { name, .. } => names.push(name.clone()),
+            }
+        }
+        names
+    }
+}
+
+fn race_entrants(race: &Race) -> &[Entrant] {
+    match race.entrants {
+        Entrants::Open | Entrants::Count { .. } | Entrants::Named(_) => &[],
+        Entrants::Two(ref entrants) => &entrants[..],
+        Entrants::Three(ref entrants) => &entrants[..],
+    }
+}
+
+/// The two lists of races on the races page, which are paginated separately.
+#[derive(Clone, Copy)]
+pub(crate) enum Section {
+    Upcoming,
+    Past,
+}
+
+impl Filter {
+    pub(crate) fn is_active(&self) -> bool {
+        non_empty(&self.phase).is_some()
+        || non_empty(&self.round).is_some()
+        || non_empty(&self.entrant).is_some()
+        || non_empty(&self.from).is_some()
+        || non_empty(&self.to).is_some()
+        || self.restreamed
+        || self.unscheduled
+    }
+
+    /// Checks all criteria except the entrant name, which is matched against preloaded [`EntrantNames`].
+    fn matches_race_info(&self, race: &Race) -> bool {
+        if let Some(phase) = non_empty(&self.phase) && !race.phase.as_deref().is_some_and(|race_phase| race_phase.eq_ignore_ascii_case(phase)) {
+            return false
+        }
+        if let Some(round) = non_empty(&self.round) && !race.round.as_deref().is_some_and(|race_round| race_round.eq_ignore_ascii_case(round)) {
+            return false
+        }
+        if self.restreamed && race.video_urls.is_empty() {
+            return false
+        }
+        if self.unscheduled && !matches!(race.schedule, RaceSchedule::Unscheduled) {
+            return false
+        }
+        let from = parse_date(&self.from);
+        let to = parse_date(&self.to).map(|to| to + TimeDelta::days(1));
+        if from.is_some() || to.is_some() {
+            let starts = race_starts(race);
+            if !starts.iter().any(|start| from.is_none_or(|from| *start >= from) && to.is_none_or(|to| *start < to)) {
+                return false
+            }
+        }
+        true
+    }
+
+    fn matches(&self, entrant_names: &EntrantNames, race: &Race) -> bool {
+        if !self.matches_race_info(race) {
+            return false
+        }
+        if let Some(entrant) = non_empty(&self.entrant) {
+            let entrant = entrant.to_lowercase();
+            entrant_names.for_race(race).into_iter().any(|name| name.to_lowercase().contains(&entrant))
+        } else {
+            true
+        }
+    }
+
+    /// Removes races not matching this filter and sorts the remaining ones.
+    ///
+    /// The races are expected to already be in the default order for their section of the races page.
+    pub(crate) async fn apply(&self, transaction: &mut Transaction<'_, Postgres>, races: Vec<Race>) -> sqlx::Result<Vec<Race>> {
+        let entrant_names = if non_empty(&self.entrant).is_some() {
+            EntrantNames::load(&mut *transaction, &races).await?
+        } else {
+            EntrantNames::default()
+        };
+        let mut filtered = races.into_iter().filter(|race| self.matches(&entrant_names, race)).collect_vec();
+        match self.sort.unwrap_or_default() {
+            SortKey::Start => {}
+            SortKey::Phase => filtered.sort_by(|race_a, race_b| natural_cmp_option(race_a.phase.as_deref(), race_b.phase.as_deref()).then_with(|| natural_cmp_option(race_a.round.as_deref(), race_b.round.as_deref()))),
+            SortKey::Round => filtered.sort_by(|race_a, race_b| natural_cmp_option(race_a.round.as_deref(), race_b.round.as_deref())),
+        }
+        if self.desc {
+            filtered.reverse();
+        }
+        Ok(filtered)
+    }
+
+    fn page(&self, section: Section) -> usize {
+        match section {
+            Section::Upcoming => self.upcoming_page,
+            Section::Past => self.page,
+        }.unwrap_or(1).max(1)
+    }
+
+    fn with_page(&self, section: Section, page: usize) -> Self {
+        let page = (page > 1).then_some(page);
+        match section {
+            Section::Upcoming => Self { upcoming_page: page, ..self.clone() },
+            Section::Past => Self { page, ..self.clone() },
+        }
+    }
+
+    /// Returns the races of the given section on the current page and the total number of pages.
+    pub(crate) fn paginate<'a>(&self, section: Section, races: &'a [Race]) -> (&'a [Race], usize) {
+        let page_count = races.len().div_ceil(PAGE_SIZE).max(1);
+        let page = self.page(section).min(page_count);
+        (&races[(page - 1) * PAGE_SIZE..(page * PAGE_SIZE).min(races.len())], page_count)
+    }
+
+    pub(crate) fn pagination(&self, data: &Data<'_>, section: Section, page_count: usize) -> RawHtml<String> {
+        let page = self.page(section).min(page_count);
+        html! {
+            @if page_count > 1 {
+                p(class = "button-row") {
+                    @for iter_page in 1..=page_count {
+                        @if iter_page == page {
+                            a(class = "button selected") : iter_page;
+                        } else {
+                            a(class = "button", href = uri!(super::races(data.series, &*data.event, Some(self.with_page(section, iter_page))))) : iter_page;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// A form for changing the filter, along with export links for organizers.
+    pub(crate) fn form(&self, data: &Data<'_>, phases: &[String], rounds: &[String], can_export: bool) -> RawHtml<String> {
+        let sort = self.sort.unwrap_or_default();
+        html! {
+            details(open? = self.is_active()) {
+                summary : "Filter races";
+                form(method = "get", action = uri!(super::races(data.series, &*data.event, _)).to_string()) {
+                    @if !phases.is_empty() {
+                        label {
+                            : "Phase: ";
+                            select(name = "phase") {
+                                option(value = "", selected? = non_empty(&self.phase).is_none()) : "(any)";
+                                @for phase in phases {
+                                    option(value = phase, selected? = non_empty(&self.phase) == Some(phase)) : phase;
+                                }
+                            }
+                        }
+                    }
+                    @if !rounds.is_empty() {
+                        label {
+                            : "Round: ";
+                            select(name = "round") {
+                                option(value = "", selected? = non_empty(&self.round).is_none()) : "(any)";
+                                @for round in rounds {
+                                    option(value = round, selected? = non_empty(&self.round) == Some(round)) : round;
+                                }
+                            }
+                        }
+                    }
+                    label {
+                        : "Entrant: ";
+                        input(type = "search", name = "entrant", value? = non_empty(&self.entrant));
+                    }
+                    label {
+                        : "From: ";
+                        input(type = "date", name = "from", value? = non_empty(&self.from));
+                    }
+                    label {
+                        : "To: ";
+                        input(type = "date", name = "to", value? = non_empty(&self.to));
+                    }
+                    label {
+                        input(type = "checkbox", name = "restreamed", checked? = self.restreamed);
+                        : "Restreamed only";
+                    }
+                    label {
+                        input(type = "checkbox", name = "unscheduled", checked? = self.unscheduled);
+                        : "Unscheduled only";
+                    }
+                    label {
+                        : "Sort by: ";
+                        select(name = "sort") {
+                            @for iter_sort in all::<SortKey>() {
+                                option(value = iter_sort.display_name().to_ascii_lowercase(), selected? = iter_sort == sort) : iter_sort.display_name();
+                            }
+                        }
+                    }
+                    label {
+                        input(type = "checkbox", name = "desc", checked? = self.desc);
+                        : "Reverse order";
+                    }
+                    input(type = "submit", value = "Filter");
+                }
+                @if can_export {
+                    p {
+                        : "Export this view as ";
+                        a(href = uri!(csv(data.series, &*data.event, Some(Self { upcoming_page: None, page: None, ..self.clone() })))) : "CSV";
+                        : " or ";
+                        a(href = uri!(json(data.series, &*data.event, Some(Self { upcoming_page: None, page: None, ..self.clone() })))) : "JSON";
+                        : ".";
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ExportRow {
+    id: Id<Races>,
+    phase: Option<String>,
+    round: Option<String>,
+    game: Option<i16>,
+    entrants: String,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    room: Option<Url>,
+    restream: Option<Url>,
+    canceled: bool,
+}
+
+async fn export_rows(pool: &PgPool, http_client: &reqwest::Client, me: &User, series: Series, event: &str, filter: Option<Filter>) -> Result<Vec<ExportRow>, StatusOrError<Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    if !data.organizers(&mut transaction).await?.contains(me) && !me.is_global_admin() {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let races = Race::for_event(&mut transaction, http_client, &data).await?;
+    let races = filter.unwrap_or_default().apply(&mut transaction, races).await?;
+    let entrant_names = EntrantNames::load(&mut transaction, &races).await?;
+    let mut rows = Vec::with_capacity(races.len());
+    for race in races {
+        let (start, end) = match race.schedule {
+            RaceSchedule::Unscheduled => (None, None),
+            RaceSchedule::Live { start, end, .. } => (Some(start), end),
+            RaceSchedule::Async { end1, end2, end3, .. } => (race_starts(&race).into_iter().min(), [end1, end2, end3].into_iter().flatten().max()),
+        };
+        rows.push(ExportRow {
+            id: race.id,
+            entrants: entrant_names.for_race(&race).join(", "),
+            room: race.rooms().next(),
+            restream: race.video_urls.get(&English).or_else(|| race.video_urls.values().next()).cloned(),
+            canceled: race.ignored,
+            phase: race.phase,
+            round: race.round,
+            game: race.game,
+            start,
+            end,
+        });
+    }
+    transaction.commit().await?;
+    Ok(rows)
+}
+
+#[rocket::get("/event/<series>/<event>/races.csv?<filter..>")]
+pub(crate) async fn csv(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, series: Series, event: &str, filter: Option<Filter>) -> Result<(rocket::http::ContentType, Vec<u8>), StatusOrError<Error>> {
+    let rows = export_rows(pool, http_client, &me, series, event, filter).await?;
+    let mut csv = csv::Writer::from_writer(Vec::default());
+    for row in rows {
+        csv.serialize(row)?;
+    }
+    Ok((rocket::http::ContentType::CSV, csv.into_inner()?))
+}
+
+#[rocket::get("/event/<series>/<event>/races.json?<filter..>")]
+pub(crate) async fn json(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, series: Series, event: &str, filter: Option<Filter>) -> Result<rocket::serde::json::Json<Vec<ExportRow>>, StatusOrError<Error>> {
+    Ok(rocket::serde::json::Json(export_rows(pool, http_client, &me, series, event, filter).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn race(phase: Option<&str>, round: Option<&str>, schedule: RaceSchedule) -> Race {
+        Race {
+            id: Id::dummy(),
+            series: Series::Standard,
+            event: format!("1"),
+            source: cal::Source::Manual,
+            entrants: Entrants::Open,
+            phase: phase.map(str::to_owned),
+            round: round.map(str::to_owned),
+            game: None,
+            scheduling_thread: None,
+            schedule,
+            schedule_updated_at: None,
+            fpa_invoked: false,
+            breaks_used: false,
+            draft: None,
+            seed: seed::Data::default(),
+            video_urls: HashMap::default(),
+            restreamers: HashMap::default(),
+            last_edited_by: None,
+            last_edited_at: None,
+            ignored: false,
+            schedule_locked: false,
+            notified: false,
+            async_notified_1: false,
+            async_notified_2: false,
+            async_notified_3: false,
+            discord_scheduled_event_id: None,
+            volunteer_request_sent: false,
+            volunteer_request_message_id: None,
+            scheduling_deadline: None,
+            restream_consent_required: false,
+            custom_title: None,
+            custom_create_room: true,
+            companion_race_id: None,
+        }
+    }
+
+    #[test]
+    fn matches_race_info() {
+        let live = race(Some("Top 8"), Some("Semifinals"), RaceSchedule::Live { start: Utc.with_ymd_and_hms(2026, 5, 2, 18, 0, 0).unwrap(), end: None, room: None });
+        let unscheduled = race(Some("Swiss"), Some("Round 1"), RaceSchedule::Unscheduled);
+        let filter = Filter { phase: Some(format!("top 8")), ..Filter::default() };
+        assert!(filter.matches_race_info(&live));
+        assert!(!filter.matches_race_info(&unscheduled));
+        let filter = Filter { unscheduled: true, ..Filter::default() };
+        assert!(!filter.matches_race_info(&live));
+        assert!(filter.matches_race_info(&unscheduled));
+        let filter = Filter { from: Some(format!("2026-05-02")), to: Some(format!("2026-05-02")), ..Filter::default() };
+        assert!(filter.matches_race_info(&live));
+        assert!(!filter.matches_race_info(&unscheduled));
+        let filter = Filter { from: Some(format!("2026-05-03")), ..Filter::default() };
+        assert!(!filter.matches_race_info(&live));
+        let filter = Filter { restreamed: true, ..Filter::default() };
+        assert!(!filter.matches_race_info(&live));
+    }
+
+    #[test]
+    fn paginate() {
+        let races = (0..PAGE_SIZE + 1).map(|_| race(None, None, RaceSchedule::Unscheduled)).collect_vec();
+        let (page, page_count) = Filter::default().paginate(Section::Past, &races);
+        assert_eq!(page.len(), PAGE_SIZE);
+        assert_eq!(page_count, 2);
+        let (page, _) = Filter { page: Some(5), ..Filter::default() }.paginate(Section::Past, &races);
+        assert_eq!(page.len(), 1);
+        let (page, _) = Filter { page: Some(2), ..Filter::default() }.paginate(Section::Upcoming, &races);
+        assert_eq!(page.len(), PAGE_SIZE);
+        let (page, page_count) = Filter::default().paginate(Section::Upcoming, &[]);
+        assert!(page.is_empty());
+        assert_eq!(page_count, 1);
+    }
+
+    #[test]
+    fn natural_cmp() {
+        assert_eq!(super::natural_cmp("Round 2", "Round 10"), Ordering::Less);
+        assert_eq!(super::natural_cmp("Round 10", "Round 9"), Ordering::Greater);
+        assert_eq!(super::natural_cmp("Round 02", "Round 2"), Ordering::Equal);
+        assert_eq!(super::natural_cmp("Top 8", "Swiss"), Ordering::Greater);
+        assert_eq!(super::natural_cmp("Round 1", "Round 1b"), Ordering::Less);
+    }
+}
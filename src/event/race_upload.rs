@@ -0,0 +1,588 @@
+//! Bulk creation of races from a CSV or TSV table, for events whose matches are created manually.
+//!
+//! Uploading a table first shows a preview with how each row was parsed and which teams its entrants resolved to.
+//! Races are only created once the organizer confirms a preview without errors.
+
+use {
+    csv::{
+        ReaderBuilder,
+        StringRecord,
+        Trim,
+    },
+    crate::{
+        cal::{
+            NewRaceTeamError,
+            StartDateError,
+        },
+        event::{
+            Data,
+            Series,
+            Tab,
+        },
+        prelude::*,
+    },
+};
+
+const COLUMNS: [&str; 7] = ["entrant1", "entrant2", "entrant3", "phase", "round", "game", "start"];
+
+#[derive(Debug, thiserror::Error)]
+enum TableError {
+    #[error(transparent)] Csv(#[from] csv::Error),
+    #[error("The table is empty.")]
+    Empty,
+    #[error("Unknown column “{0}”. The header row may only contain: entrant1, entrant2, entrant3, phase, round, game, start.")]
+    UnknownColumn(String),
+    #[error("Duplicate column “{0}”.")]
+    DuplicateColumn(String),
+    #[error("Missing column “{0}”.")]
+    MissingColumn(&'static str),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RowError {
+    #[error(transparent)] Csv(#[from] csv::Error),
+    #[error(transparent)] StartDate(#[from] StartDateError),
+    #[error("Each race needs at least 2 entrants.")]
+    NotEnoughEntrants,
+    #[error("“{0}” is not a valid ID.")]
+    EntrantId(String),
+    #[error("The game number must be a positive number.")]
+    Game,
+    #[error("Multiple races for the same matchup need game numbers.")]
+    MissingGame,
+    #[error("There is another race for this matchup with the same game number.")]
+    DuplicateGame,
+    #[error("A race for this matchup with the same game number already exists.")]
+    ExistingGame,
+}
+
+/// How an entrant is referred to in the table.
+///
+/// Values can be prefixed with `team:`, `hth:`, `racetime:`, or `discord:` to choose how they're looked up.
+/// Unprefixed values are tried as a team name first, then as any of the IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EntrantRef {
+    Any(String),
+    TeamName(String),
+    User(Id<Users>),
+    RaceTime(String),
+    Discord(UserId),
+}
+
+impl EntrantRef {
+    pub(crate) fn parse(value: &str) -> Result<Self, RowError> {
+        let parse_id = |id: &str| id.trim().parse::<u64>().ok().filter(|&id| id != 0).ok_or_else(|| RowError::EntrantId(value.to_owned()));
+        Ok(if let Some(name) = value.strip_prefix("team:") {
+            Self::TeamName(name.trim().to_owned())
+        } else if let Some(id) = value.strip_prefix("hth:") {
+            Self::User(Id::from(parse_id(id)?))
+        } else if let Some(id) = value.strip_prefix("racetime:").or_else(|| value.strip_prefix("rt:")) {
+            Self::RaceTime(id.trim().to_owned())
+        } else if let Some(id) = value.strip_prefix("discord:") {
+            Self::Discord(UserId::new(parse_id(id)?))
+        } else {
+            Self::Any(value.to_owned())
+        })
+    }
+}
+
+impl fmt::Display for EntrantRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any(value) => value.fmt(f),
+            Self::TeamName(name) => write!(f, "team:{name}"),
+            Self::User(id) => write!(f, "hth:{id}"),
+            Self::RaceTime(id) => write!(f, "racetime:{id}"),
+            Self::Discord(id) => write!(f, "discord:{id}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Row {
+    entrants: Vec<EntrantRef>,
+    phase: Option<String>,
+    round: Option<String>,
+    game: Option<i16>,
+    start: Option<DateTime<Utc>>,
+}
+
+/// Parses the uploaded table into rows, each with its line number.
+///
+/// The delimiter is a tab if the header row contains one, and a comma otherwise. Start times are interpreted in the given timezone.
+fn parse_table(table: &str, timezone: &str) -> Result<Vec<(u64, Result<Row, RowError>)>, TableError> {
+    let header_line = table.lines().find(|line| !line.trim().is_empty()).ok_or(TableError::Empty)?;
+    let mut reader = ReaderBuilder::new()
+        .delimiter(if header_line.contains('\t') { b'\t' } else { b',' })
+        .flexible(true)
+        .trim(Trim::All)
+        .from_reader(table.trim_start().as_bytes());
+    let mut column_indices = HashMap::new();
+    for (idx, header) in reader.headers()?.iter().enumerate() {
+        let header = header.to_ascii_lowercase();
+        let Some(&column) = COLUMNS.iter().find(|&&column| column == header) else {
+            return Err(TableError::UnknownColumn(header))
+        };
+        if column_indices.insert(column, idx).is_some() {
+            return Err(TableError::DuplicateColumn(header))
+        }
+    }
+    for column in ["entrant1", "entrant2"] {
+        if !column_indices.contains_key(column) {
+            return Err(TableError::MissingColumn(column))
+        }
+    }
+    let mut rows = Vec::default();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                rows.push((e.position().map_or(0, |position| position.line()), Err(e.into())));
+                continue
+            }
+        };
+        if record.iter().all(str::is_empty) {
+            continue
+        }
+        let line = record.position().map_or(0, |position| position.line());
+        rows.push((line, parse_row(&record, &column_indices, timezone)));
+    }
+    Ok(rows)
+}
+
+fn parse_row(record: &StringRecord, column_indices: &HashMap<&str, usize>, timezone: &str) -> Result<Row, RowError> {
+    let field = |column: &str| column_indices.get(column).and_then(|&idx| record.get(idx)).filter(|value| !value.is_empty());
+    let entrants = ["entrant1", "entrant2", "entrant3"].into_iter()
+        .filter_map(field)
+        .map(EntrantRef::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if entrants.len() < 2 {
+        return Err(RowError::NotEnoughEntrants)
+    }
+    Ok(Row {
+        phase: field("phase").map(str::to_owned),
+        round: field("round").map(str::to_owned),
+        game: field("game").map(|game| game.parse().ok().filter(|&game: &i16| game > 0).ok_or(RowError::Game)).transpose()?,
+        start: field("start").map(|start| cal::parse_start_date(start, timezone)).transpose()?,
+        entrants,
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ResolveError {
+    #[error(transparent)] NewRaceTeam(#[from] NewRaceTeamError),
+    #[error("No team or user found for “{0}”.")]
+    NotFound(String),
+    #[error("Multiple teams are named “{0}”. Use an ID instead.")]
+    AmbiguousName(String),
+    #[error("This user is not on a team in this event.")]
+    NotInEvent,
+}
+
+/// Finds the team in this event that an entrant reference refers to.
+async fn resolve_entrant(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, team_names: &[(Team, Option<String>)], entrant: &EntrantRef) -> sqlx::Result<Result<Team, ResolveError>> {
+    let by_name = |name: &str| {
+        let mut matching = team_names.iter().filter(|(_, team_name)| team_name.as_ref().is_some_and(|team_name| team_name.eq_ignore_ascii_case(name)));
+        match (matching.next(), matching.next()) {
+            (Some((team, _)), None) => Ok(Some(team.clone())),
+            (Some(_), Some(_)) => Err(ResolveError::AmbiguousName(name.to_owned())),
+            (None, _) => Ok(None),
+        }
+    };
+    let user = match entrant {
+        EntrantRef::TeamName(name) => return Ok(by_name(name).and_then(|team| team.ok_or_else(|| ResolveError::NotFound(name.clone())))),
+        EntrantRef::User(id) => User::from_id(&mut **transaction, *id).await?,
+        EntrantRef::RaceTime(id) => if let Some(team) = Team::from_racetime(transaction, event.series, &event.event, id).await? {
+            return Ok(Ok(team))
+        } else {
+            User::from_racetime(&mut **transaction, id).await?
+        },
+        EntrantRef::Discord(id) => User::from_discord(&mut **transaction, *id).await?,
+        EntrantRef::Any(value) => match by_name(value) {
+            Ok(Some(team)) => return Ok(Ok(team)),
+            Err(e) => return Ok(Err(e)),
+            Ok(None) => if let Some(id) = value.parse::<u64>().ok().filter(|&id| id != 0) {
+                if let Some(user) = User::from_id(&mut **transaction, Id::from(id)).await? {
+                    Some(user)
+                } else {
+                    User::from_discord(&mut **transaction, UserId::new(id)).await?
+                }
+            } else if let Some(team) = Team::from_racetime(transaction, event.series, &event.event, value).await? {
+                return Ok(Ok(team))
+            } else {
+                User::from_racetime(&mut **transaction, value).await?
+            },
+        },
+    };
+    let Some(user) = user else { return Ok(Err(ResolveError::NotFound(entrant.to_string()))) };
+    Ok(Team::from_event_and_member(transaction, event.series, &event.event, user.id).await?.ok_or(ResolveError::NotInEvent))
+}
+
+/// Resolves the entrants of a match, checking that they're distinct teams in this event.
+pub(crate) async fn resolve_entrants(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, team_names: &[(Team, Option<String>)], entrants: &[EntrantRef]) -> sqlx::Result<Vec<Result<Team, ResolveError>>> {
+    let mut teams = Vec::<Result<Team, ResolveError>>::with_capacity(entrants.len());
+    for entrant in entrants {
+        let team = match resolve_entrant(transaction, event, team_names, entrant).await? {
+            Ok(team) => {
+                let other_teams = teams.iter().filter_map(|team| team.as_ref().ok()).collect_vec();
+                cal::check_new_race_team(event, Some(&team), &other_teams).map(|()| team).map_err(ResolveError::from)
+            }
+            Err(e) => Err(e),
+        };
+        teams.push(team);
+    }
+    Ok(teams)
+}
+
+struct PreviewRow {
+    line: u64,
+    row: Result<(Row, Vec<Result<Team, ResolveError>>), RowError>,
+    /// Set if the row is valid on its own but conflicts with other rows.
+    conflict: Option<RowError>,
+}
+
+impl PreviewRow {
+    fn is_valid(&self) -> bool {
+        self.conflict.is_none() && self.row.as_ref().is_ok_and(|(_, teams)| teams.iter().all(Result::is_ok))
+    }
+
+    /// Identifies the match this row belongs to. Rows of the same match share a scheduling thread.
+    fn match_key(&self) -> Option<(Option<&str>, Option<&str>, Vec<Id<Teams>>)> {
+        let (row, teams) = self.row.as_ref().ok()?;
+        let mut team_ids = teams.iter().map(|team| team.as_ref().ok().map(|team| team.id)).collect::<Option<Vec<_>>>()?;
+        team_ids.sort_unstable_by_key(|&id| u64::from(id));
+        Some((row.phase.as_deref(), row.round.as_deref(), team_ids))
+    }
+}
+
+/// The teams in this event along with their names, for looking up entrants with [`resolve_entrants`].
+pub(crate) async fn team_names(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>) -> sqlx::Result<Vec<(Team, Option<String>)>> {
+    let mut team_names = Vec::default();
+    for team in Team::for_event(transaction, event.series, &event.event).await? {
+        let name = team.name(transaction).await?.map(Cow::into_owned);
+        team_names.push((team, name));
+    }
+    Ok(team_names)
+}
+
+async fn resolve_rows(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, rows: Vec<(u64, Result<Row, RowError>)>) -> sqlx::Result<Vec<PreviewRow>> {
+    let team_names = team_names(transaction, event).await?;
+    let mut preview = Vec::with_capacity(rows.len());
+    for (line, row) in rows {
+        let row = match row {
+            Ok(row) => {
+                let teams = resolve_entrants(transaction, event, &team_names, &row.entrants).await?;
+                Ok((row, teams))
+            }
+            Err(e) => Err(e),
+        };
+        preview.push(PreviewRow { line, row, conflict: None });
+    }
+    let mut matches = HashMap::<_, Vec<usize>>::default();
+    for (idx, row) in preview.iter().enumerate() {
+        if let Some(key) = row.match_key() {
+            matches.entry(key).or_default().push(idx);
+        }
+    }
+    // game numbers of the races which already exist for each match
+    let mut existing_games = HashMap::<_, Vec<Option<i16>>>::default();
+    for race in sqlx::query!(r#"SELECT phase, round, game, team1 AS "team1!: Id<Teams>", team2 AS "team2: Id<Teams>", team3 AS "team3: Id<Teams>" FROM races WHERE series = $1 AND event = $2 AND NOT ignored AND team1 IS NOT NULL"#, event.series as _, &event.event).fetch_all(&mut **transaction).await? {
+        let mut team_ids = [Some(race.team1), race.team2, race.team3].into_iter().flatten().collect_vec();
+        team_ids.sort_unstable_by_key(|&id| u64::from(id));
+        existing_games.entry((race.phase, race.round, team_ids)).or_default().push(race.game);
+    }
+    let mut conflicts = Vec::default();
+    for ((phase, round, team_ids), indices) in matches {
+        let existing = existing_games.get(&(phase.map(str::to_owned), round.map(str::to_owned), team_ids)).map(Vec::as_slice).unwrap_or_default();
+        if indices.len() == 1 && existing.is_empty() { continue }
+        let mut games = HashSet::new();
+        for idx in indices {
+            let (row, _) = preview[idx].row.as_ref().expect("only valid rows have a match key");
+            if let Some(game) = row.game.filter(|_| !existing.contains(&None)) {
+                if existing.contains(&Some(game)) {
+                    conflicts.push((idx, RowError::ExistingGame));
+                } else if !games.insert(game) {
+                    conflicts.push((idx, RowError::DuplicateGame));
+                }
+            } else {
+                conflicts.push((idx, RowError::MissingGame));
+            }
+        }
+    }
+    for (idx, conflict) in conflicts {
+        preview[idx].conflict = Some(conflict);
+    }
+    Ok(preview)
+}
+
+async fn upload_page(mut transaction: Transaction<'_, Postgres>, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, ctx: Context<'_>, preview: Option<Result<Vec<PreviewRow>, TableError>>) -> Result<RawHtml<String>, event::Error> {
+    let header = event.header(&mut transaction, me.as_ref(), Tab::Races, true).await?;
+    let is_organizer = if let Some(ref me) = me {
+        me.is_global_admin() || event.organizers(&mut transaction).await?.contains(me)
+    } else {
+        false
+    };
+    let content = if is_organizer {
+        let mut errors = ctx.errors().collect_vec();
+        let preview_html = match preview {
+            None => html! {},
+            Some(Err(e)) => html! {
+                p(class = "error") : e.to_string();
+            },
+            Some(Ok(rows)) => {
+                let num_valid = rows.iter().filter(|row| row.is_valid()).count();
+                let all_valid = num_valid == rows.len() && !rows.is_empty();
+                html! {
+                    h3 : "Preview";
+                    table {
+                        thead {
+                            tr {
+                                th : "Line";
+                                th : "Entrants";
+                                th : "Phase";
+                                th : "Round";
+                                th : "Game";
+                                th : "Start";
+                                th : "Status";
+                            }
+                        }
+                        tbody {
+                            @for row in &rows {
+                                tr {
+                                    td : row.line.to_string();
+                                    @match row.row {
+                                        Ok((ref parsed, ref teams)) => {
+                                            td {
+                                                ul {
+                                                    @for (entrant, team) in parsed.entrants.iter().zip(teams) {
+                                                        li {
+                                                            @match team {
+                                                                Ok(team) => {
+                                                                    : team.to_html(&mut transaction, false).await?;
+                                                                }
+                                                                Err(e) => {
+                                                                    bdi : entrant.to_string();
+                                                                    : " — ";
+                                                                    span(class = "error") : e.to_string();
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            td : parsed.phase.as_deref().unwrap_or("—");
+                                            td : parsed.round.as_deref().unwrap_or("—");
+                                            td : parsed.game.map_or_else(|| "—".to_owned(), |game| game.to_string());
+                                            td {
+                                                @if let Some(start) = parsed.start {
+                                                    : format_datetime(start, DateTimeFormat { long: false, running_text: false });
+                                                } else {
+                                                    : "unscheduled";
+                                                }
+                                            }
+                                        }
+                                        Err(_) => {
+                                            td(colspan = "5");
+                                        }
+                                    }
+                                    td {
+                                        @if let Err(ref e) = row.row {
+                                            span(class = "error") : e.to_string();
+                                        } else if let Some(ref conflict) = row.conflict {
+                                            span(class = "error") : conflict.to_string();
+                                        } else if row.is_valid() {
+                                            : "OK";
+                                        } else {
+                                            span(class = "error") : "Invalid entrants";
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    @if all_valid {
+                        : button_form_ext(uri!(post(event.series, &*event.event)), csrf, Vec::default(), html! {
+                            input(type = "hidden", name = "table", value? = ctx.field_value("table"));
+                            input(type = "hidden", name = "timezone", value? = ctx.field_value("timezone"));
+                            input(type = "hidden", name = "confirm", value = "on");
+                        }, &if num_valid == 1 { format!("Create 1 race") } else { format!("Create {num_valid} races") }).1;
+                    } else if rows.is_empty() {
+                        p : "The table has no races.";
+                    } else {
+                        p : "Please fix the errors above and preview again.";
+                    }
+                }
+            }
+        };
+        html! {
+            p {
+                : "Paste or upload a table with a header row and one race per row. The columns are ";
+                code : "entrant1";
+                : ", ";
+                code : "entrant2";
+                : ", and optionally ";
+                code : "entrant3";
+                : ", ";
+                code : "phase";
+                : ", ";
+                code : "round";
+                : ", ";
+                code : "game";
+                : ", and ";
+                code : "start";
+                : " (YYYY-MM-DD HH:MM in your timezone). Columns are separated by commas or tabs, so a range copied from a spreadsheet works too.";
+            }
+            p {
+                : "Entrants can be given as a team name, or as a Hyrule Town Hall user ID, racetime.gg user or team ID, or Discord user ID of a team member. To look up a value only in one of these ways, prefix it with ";
+                code : "team:";
+                : ", ";
+                code : "hth:";
+                : ", ";
+                code : "racetime:";
+                : ", or ";
+                code : "discord:";
+                : ". Rows with the same entrants, phase, and round are created as games of one match with a shared scheduling thread.";
+            }
+            : full_form(uri!(post(event.series, &*event.event)), csrf, html! {
+                : form_field("file", &mut errors, html! {
+                    label(for = "race-upload-file") : "File:";
+                    input(type = "file", id = "race-upload-file", accept = ".csv,.tsv,.txt,text/csv,text/tab-separated-values");
+                });
+                : form_field("table", &mut errors, html! {
+                    label(for = "race-upload-table") : "Table:";
+                    textarea(name = "table", id = "race-upload-table", rows = "12", style = "width: 100%;") : ctx.field_value("table");
+                });
+                input(type = "hidden", name = "timezone", id = "timezone-field", value? = ctx.field_value("timezone").map(str::to_owned).or_else(|| me.as_ref().and_then(|me| me.timezone).map(|timezone| timezone.name().to_owned())));
+            }, errors, "Preview");
+            : preview_html;
+            script(src = static_url!("race-upload.js")) {}
+        }
+    } else {
+        html! {
+            article {
+                p : "This page is only available for event organizers.";
+            }
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Upload Races — {}", event.display_name), html! {
+        : header;
+        h2 : "Upload races";
+        : content;
+    }).await?)
+}
+
+#[rocket::get("/event/<series>/<event>/races/upload")]
+pub(crate) async fn get(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok(upload_page(transaction, me, uri, csrf.as_ref(), event, Context::default(), None).await?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct UploadForm {
+    #[field(default = String::new())]
+    csrf: String,
+    #[field(default = String::new())]
+    table: String,
+    #[field(default = String::new())]
+    timezone: String,
+    #[field(default = false)]
+    confirm: bool,
+}
+
+#[rocket::post("/event/<series>/<event>/races/upload", data = "<form>")]
+pub(crate) async fn post(pool: &State<PgPool>, discord_ctx: &State<RwFuture<DiscordCtx>>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, UploadForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+        form.context.push_error(form::Error::validation("You must be an organizer of this event to add races."));
+    }
+    if !matches!(event.match_source(), MatchSource::Manual) {
+        form.context.push_error(form::Error::validation("Races can only be uploaded for events whose matches are created manually."));
+    }
+    Ok(if let Some(ref value) = form.value {
+        if value.table.trim().is_empty() {
+            form.context.push_error(form::Error::validation("Please paste or upload a table.").with_name("table"));
+        }
+        if form.context.errors().next().is_some() {
+            return Ok(RedirectOrContent::Content(upload_page(transaction, Some(me), uri, csrf.as_ref(), event, form.context, None).await?))
+        }
+        let preview = match parse_table(&value.table, &value.timezone) {
+            Ok(rows) => Ok(resolve_rows(&mut transaction, &event, rows).await?),
+            Err(e) => Err(e),
+        };
+        match preview {
+            Ok(rows) if value.confirm && !rows.is_empty() && rows.iter().all(PreviewRow::is_valid) => {
+                let mut matches = Vec::<(Vec<Team>, Option<String>, Option<String>, Vec<(Option<i16>, Option<DateTime<Utc>>)>)>::default();
+                let mut match_indices = HashMap::new();
+                for row in &rows {
+                    let key = row.match_key().expect("checked validity");
+                    let (parsed, teams) = row.row.as_ref().expect("checked validity");
+                    let idx = *match_indices.entry(key).or_insert_with(|| {
+                        matches.push((
+                            teams.iter().map(|team| team.as_ref().expect("checked validity").clone()).collect(),
+                            parsed.phase.clone(),
+                            parsed.round.clone(),
+                            Vec::default(),
+                        ));
+                        matches.len() - 1
+                    });
+                    matches[idx].3.push((parsed.game, parsed.start));
+                }
+                for (teams, phase, round, mut games) in matches {
+                    games.sort_by_key(|&(game, _)| game);
+                    transaction = cal::create_match(transaction, &*discord_ctx.read().await, http_client, &event, &teams, phase, round, &games).await?;
+                }
+                transaction.commit().await?;
+                RedirectOrContent::Redirect(Redirect::to(uri!(event::races(event.series, &*event.event, _))))
+            }
+            preview => RedirectOrContent::Content(upload_page(transaction, Some(me), uri, csrf.as_ref(), event, form.context, Some(preview)).await?),
+        }
+    } else {
+        RedirectOrContent::Content(upload_page(transaction, Some(me), uri, csrf.as_ref(), event, form.context, None).await?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_tabs_and_optional_columns() {
+        let rows = parse_table("entrant1\tentrant2\tround\tgame\nAlpha\thth:42\tRound 1\t2\n\t\t\t\n", "").unwrap();
+        assert_eq!(rows.len(), 1);
+        let (line, row) = &rows[0];
+        assert_eq!(*line, 2);
+        assert_eq!(*row.as_ref().unwrap(), Row {
+            entrants: vec![EntrantRef::Any(format!("Alpha")), EntrantRef::User(Id::from(42u64))],
+            phase: None,
+            round: Some(format!("Round 1")),
+            game: Some(2),
+            start: None,
+        });
+    }
+
+    #[test]
+    fn reports_row_errors() {
+        let rows = parse_table("Entrant1,Entrant2,Entrant3,Game,Start\nA,,,,\nA,B,discord:x,,\nA,B,,0,\nA,B,C,,2026-13-01 12:00\n", "").unwrap();
+        assert!(matches!(rows[0].1, Err(RowError::NotEnoughEntrants)));
+        assert!(matches!(rows[1].1, Err(RowError::EntrantId(_))));
+        assert!(matches!(rows[2].1, Err(RowError::Game)));
+        assert!(matches!(rows[3].1, Err(RowError::StartDate(StartDateError::Format))));
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        assert!(matches!(parse_table("entrant1,opponent\n", ""), Err(TableError::UnknownColumn(_))));
+        assert!(matches!(parse_table("entrant1,round\n", ""), Err(TableError::MissingColumn("entrant2"))));
+        assert!(matches!(parse_table("  \n", ""), Err(TableError::Empty)));
+    }
+
+    #[test]
+    fn start_uses_timezone() {
+        let rows = parse_table("entrant1,entrant2,start\nA,B,2026-07-01 20:00\n", "Europe/Berlin").unwrap();
+        assert_eq!(rows[0].1.as_ref().unwrap().start, Some(Utc.with_ymd_and_hms(2026, 7, 1, 18, 0, 0).unwrap()));
+    }
+}
@@ -24,11 +24,11 @@ use {
         game,
         cal::{Race, RaceSchedule, Entrants, Entrant},
         prelude::DiscordCtx,
+        discord_role_manager,
         volunteer_requests,
     },
     rocket_util::Origin,
     std::collections::{HashMap, HashSet},
-    serenity::model::id::RoleId,
 };
 
 #[derive(Debug, thiserror::Error, rocket_util::Error)]
@@ -841,44 +841,6 @@ impl RoleRequest {
     }
 }
 
-async fn assign_binding_discord_role(
-    pool: &mut Transaction<'_, Postgres>,
-    ctx: &DiscordCtx,
-    data: &Data<'_>,
-    binding: &RoleBinding,
-    discord_user_id: UserId,
-) -> sqlx::Result<()> {
-    let Some(discord_role_id) = binding.discord_role_id else { return Ok(()) };
-    if let Some(game_id) = binding.game_id {
-        if binding.series.is_none() && binding.event.is_none() {
-            let discord_guild = sqlx::query_scalar!(
-                r#"SELECT discord_guild AS "discord_guild: PgSnowflake<GuildId>" FROM games WHERE id = $1"#,
-                game_id,
-            )
-            .fetch_optional(&mut **pool)
-            .await?
-            .flatten()
-            .map(|PgSnowflake(id)| id);
-            if let Some(discord_guild) = discord_guild {
-                if let Ok(member) = discord_guild.member(ctx, discord_user_id).await {
-                    if let Err(e) = member.add_role(ctx, RoleId::new(discord_role_id.try_into().unwrap())).await {
-                        eprintln!("Failed to assign game Discord role {} to user {} in guild {}: {}", discord_role_id, discord_user_id, discord_guild, e);
-                    }
-                }
-            }
-            return Ok(());
-        }
-    }
-    if let Some(discord_guild) = data.discord_guild {
-        if let Ok(member) = discord_guild.member(ctx, discord_user_id).await {
-            if let Err(e) = member.add_role(ctx, RoleId::new(discord_role_id.try_into().unwrap())).await {
-                eprintln!("Failed to assign Discord role {} to user {}: {}", discord_role_id, discord_user_id, e);
-            }
-        }
-    }
-    Ok(())
-}
-
 impl Signup {
     pub(crate) async fn for_race(
         pool: &mut Transaction<'_, Postgres>,
@@ -2235,106 +2197,6 @@ pub(crate) async fn edit_role_binding(
     Ok(RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event, _, _)))))
 }
 
-/// For a game role binding approval, assigns discord roles from all active/upcoming events that
-/// have a discord_role_id override for this binding — in each event's guild, if the user is a member.
-pub(crate) async fn assign_event_override_discord_roles(
-    pool: &mut Transaction<'_, Postgres>,
-    ctx: &DiscordCtx,
-    binding_id: Id<RoleBindings>,
-    discord_user_id: UserId,
-) -> sqlx::Result<()> {
-    let rows = sqlx::query!(
-        r#"
-            SELECT e.discord_guild AS "discord_guild!", o.discord_role_id AS "discord_role_id!"
-            FROM events e
-            JOIN game_series gs ON gs.series = e.series
-            JOIN role_bindings rb ON rb.id = $1 AND rb.game_id = gs.game_id
-            JOIN event_role_binding_overrides o
-                ON o.series = e.series AND o.event = e.event AND o.role_binding_id = $1
-            WHERE (e.end_time IS NULL OR e.end_time > NOW())
-            AND e.force_custom_role_binding = false
-            AND e.discord_guild IS NOT NULL
-            AND o.discord_role_id IS NOT NULL
-        "#,
-        binding_id as _
-    )
-    .fetch_all(&mut **pool)
-    .await?;
-
-    for row in rows {
-        let guild_id = GuildId::new(row.discord_guild as u64);
-        if let Ok(member) = guild_id.member(ctx, discord_user_id).await {
-            if let Err(e) = member.add_role(ctx, RoleId::new(row.discord_role_id as u64)).await {
-                eprintln!("Failed to assign event override Discord role {} to user {} in guild {}: {}", row.discord_role_id, discord_user_id, row.discord_guild, e);
-            }
-        }
-    }
-
-    Ok(())
-}
-
-pub(crate) async fn remove_event_override_discord_roles(
-    pool: &mut Transaction<'_, Postgres>,
-    ctx: &DiscordCtx,
-    binding_id: Id<RoleBindings>,
-    discord_user_id: UserId,
-) -> sqlx::Result<()> {
-    // Remove event override discord roles from all active events
-    let override_rows = sqlx::query!(
-        r#"
-            SELECT e.discord_guild AS "discord_guild!", o.discord_role_id AS "discord_role_id!"
-            FROM events e
-            JOIN game_series gs ON gs.series = e.series
-            JOIN role_bindings rb ON rb.id = $1 AND rb.game_id = gs.game_id
-            JOIN event_role_binding_overrides o
-                ON o.series = e.series AND o.event = e.event AND o.role_binding_id = $1
-            WHERE (e.end_time IS NULL OR e.end_time > NOW())
-            AND e.force_custom_role_binding = false
-            AND e.discord_guild IS NOT NULL
-            AND o.discord_role_id IS NOT NULL
-        "#,
-        binding_id as _
-    )
-    .fetch_all(&mut **pool)
-    .await?;
-
-    for row in override_rows {
-        let guild_id = GuildId::new(row.discord_guild as u64);
-        if let Ok(member) = guild_id.member(ctx, discord_user_id).await {
-            if let Err(e) = member.remove_role(ctx, RoleId::new(row.discord_role_id as u64)).await {
-                eprintln!("Failed to remove event override Discord role {} from user {} in guild {}: {}", row.discord_role_id, discord_user_id, row.discord_guild, e);
-            }
-        }
-    }
-
-    let game_role_rows = sqlx::query!(
-        r#"
-            SELECT DISTINCT e.discord_guild AS "discord_guild!", rb.discord_role_id AS "discord_role_id!"
-            FROM events e
-            JOIN game_series gs ON gs.series = e.series
-            JOIN role_bindings rb ON rb.id = $1 AND rb.game_id = gs.game_id
-            WHERE (e.end_time IS NULL OR e.end_time > NOW())
-            AND e.force_custom_role_binding = false
-            AND e.discord_guild IS NOT NULL
-            AND rb.discord_role_id IS NOT NULL
-        "#,
-        binding_id as _
-    )
-    .fetch_all(&mut **pool)
-    .await?;
-
-    for row in game_role_rows {
-        let guild_id = GuildId::new(row.discord_guild as u64);
-        if let Ok(member) = guild_id.member(ctx, discord_user_id).await {
-            if let Err(e) = member.remove_role(ctx, RoleId::new(row.discord_role_id as u64)).await {
-                eprintln!("Failed to remove game discord role {} from user {} in guild {}: {}", row.discord_role_id, discord_user_id, row.discord_guild, e);
-            }
-        }
-    }
-
-    Ok(())
-}
-
 #[rocket::post("/event/<series>/<event>/roles/<request>/approve", data = "<form>")]
 pub(crate) async fn approve_role_request(
     pool: &State<PgPool>,
@@ -2384,43 +2246,17 @@ pub(crate) async fn approve_role_request(
             let role_request = RoleRequest::from_id(&mut transaction, request).await?
                 .ok_or(StatusOrError::Status(Status::NotFound))?;
             
-            // Get the role binding to check for Discord role ID
-            let role_binding = sqlx::query_as!(
-                RoleBinding,
-                r#"SELECT rb.id as "id: Id<RoleBindings>", rb.series as "series: Series", rb.event, rb.game_id, rb.role_type_id as "role_type_id: Id<RoleTypes>", rb.min_count, rb.max_count, rt.name as role_type_name, rb.discord_role_id, rb.auto_approve, rb.language AS "language: Language" FROM role_bindings rb JOIN role_types rt ON rb.role_type_id = rt.id WHERE rb.id = $1"#,
-                i64::from(role_request.role_binding_id) as i32
-            )
-            .fetch_optional(&mut *transaction)
-            .await?;
-
             // Update the role request status
             RoleRequest::update_status(&mut transaction, request, RoleRequestStatus::Approved).await?;
 
-            // Assign Discord roles for this approval
+            // Look up the user to update their Discord roles once the approval is saved
             let user = User::from_id(&mut *transaction, role_request.user_id).await?
                 .ok_or(StatusOrError::Status(Status::NotFound))?;
 
+            transaction.commit().await?;
             if let Some(discord_user) = user.discord {
-                let discord_ctx = discord_ctx.read().await;
-
-                if let Some(ref binding) = role_binding {
-                    if let Err(e) = assign_binding_discord_role(
-                        &mut transaction,
-                        &*discord_ctx,
-                        &data,
-                        binding,
-                        discord_user.id,
-                    ).await {
-                        eprintln!("Failed to assign base Discord role: {}", e);
-                    }
-                }
-
-                if let Err(e) = assign_event_override_discord_roles(&mut transaction, &*discord_ctx, role_request.role_binding_id, discord_user.id).await {
-                    eprintln!("Failed to assign event override Discord roles: {}", e);
-                }
+                discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, None, &[discord_user.id]).await;
             }
-
-            transaction.commit().await?;
             let redirect_url = format!("/event/{}/{}/roles?msg={}", series.slug(), event, urlencoding::encode("Role request approved successfully."));
             RedirectOrContent::Redirect(Redirect::to(redirect_url))
         }
@@ -2617,26 +2453,6 @@ pub(crate) async fn apply_for_role(
             )
             .await?;
 
-            if role_binding.auto_approve {
-                if let Some(discord_user) = me.discord.as_ref() {
-                    let discord_ctx = discord_ctx.read().await;
-
-                    if let Err(e) = assign_binding_discord_role(
-                        &mut transaction,
-                        &*discord_ctx,
-                        &data,
-                        &role_binding,
-                        discord_user.id,
-                    ).await {
-                        eprintln!("Failed to assign base Discord role: {}", e);
-                    }
-
-                    if let Err(e) = assign_event_override_discord_roles(&mut transaction, &*discord_ctx, value.role_binding_id, discord_user.id).await {
-                        eprintln!("Failed to assign event override Discord roles: {}", e);
-                    }
-                }
-            }
-
             // Only send Discord notification for non-auto-approve roles
             if !role_binding.auto_approve {
                 if let Some(organizer_channel) = data.discord_organizer_channel {
@@ -2669,6 +2485,11 @@ pub(crate) async fn apply_for_role(
             }
 
             transaction.commit().await?;
+            if role_binding.auto_approve {
+                if let Some(discord_user) = me.discord.as_ref() {
+                    discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, None, &[discord_user.id]).await;
+                }
+            }
             RedirectOrContent::Redirect(Redirect::to(uri!(volunteer_page_get(series, event, _))))
         }
     } else {
@@ -3356,42 +3177,12 @@ pub(crate) async fn signup_for_match(
                 Some(value.notes.trim().to_string())
             };
             Signup::create(&mut transaction, race_id, value.role_binding_id, me.id, notes).await?;
+            transaction.commit().await?;
             if auto_approved_role {
                 if let Some(discord_user) = me.discord.as_ref() {
-                    let role_binding = sqlx::query_as!(
-                        RoleBinding,
-                        r#"SELECT rb.id as "id: Id<RoleBindings>", rb.series as "series: Series", rb.event, rb.game_id,
-                                  rb.role_type_id as "role_type_id: Id<RoleTypes>", rb.min_count, rb.max_count,
-                                  rt.name as role_type_name, rb.discord_role_id, rb.auto_approve,
-                                  rb.language AS "language: Language"
-                               FROM role_bindings rb
-                               JOIN role_types rt ON rb.role_type_id = rt.id
-                               WHERE rb.id = $1"#,
-                        value.role_binding_id as _
-                    )
-                    .fetch_one(&mut *transaction)
-                    .await?;
-                    let discord_ctx = discord_ctx.read().await;
-                    if let Err(e) = assign_binding_discord_role(
-                        &mut transaction,
-                        &*discord_ctx,
-                        &data,
-                        &role_binding,
-                        discord_user.id,
-                    ).await {
-                        eprintln!("Failed to assign auto-approved base Discord role: {}", e);
-                    }
-                    if let Err(e) = assign_event_override_discord_roles(
-                        &mut transaction,
-                        &*discord_ctx,
-                        value.role_binding_id,
-                        discord_user.id,
-                    ).await {
-                        eprintln!("Failed to assign auto-approved event override Discord roles: {}", e);
-                    }
+                    discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, None, &[discord_user.id]).await;
                 }
             }
-            transaction.commit().await?;
 
             speedgaming_export::schedule_sync(
                 pool.inner().clone(),
@@ -5321,7 +5112,6 @@ pub(crate) async fn upsert_role_binding_override(
             ).await?;
 
             let retroactive_spawn = if discord_role_id.is_some() && discord_role_id != prev_discord_role_id {
-                let new_discord_role_id = discord_role_id.unwrap();
                 let game = game::Game::from_series(&mut transaction, series).await?;
                 let approved_requests = if let Some(game) = game {
                     RoleRequest::for_game(&mut transaction, game.id).await?
@@ -5340,7 +5130,7 @@ pub(crate) async fn upsert_role_binding_override(
                         }
                     }
                 }
-                data.discord_guild.map(|guild| (new_discord_role_id, guild, discord_user_ids))
+                data.discord_guild.map(|guild| (guild, discord_user_ids))
             } else {
                 None
             };
@@ -5348,21 +5138,12 @@ pub(crate) async fn upsert_role_binding_override(
             transaction.commit().await?;
 
             // Spawn background task to retroactively assign Discord roles after the response returns
-            if let Some((new_discord_role_id, discord_guild, discord_user_ids)) = retroactive_spawn {
-                if !discord_user_ids.is_empty() {
-                    let ctx = discord_ctx.inner().clone();
-                    tokio::spawn(async move {
-                        let ctx = ctx.read().await;
-                        let role_id = RoleId::new(new_discord_role_id.try_into().unwrap());
-                        for discord_user_id in discord_user_ids {
-                            if let Ok(member) = discord_guild.member(&*ctx, discord_user_id).await {
-                                if let Err(e) = member.add_role(&*ctx, role_id).await {
-                                    eprintln!("Failed to retroactively assign Discord role {} to user {}: {}", new_discord_role_id, discord_user_id, e);
-                                }
-                            }
-                        }
-                    });
-                }
+            if let Some((discord_guild, discord_user_ids)) = retroactive_spawn {
+                let pool = pool.inner().clone();
+                let ctx = discord_ctx.inner().clone();
+                tokio::spawn(async move {
+                    discord_role_manager::reconcile_members(&pool, &*ctx.read().await, Some(discord_guild), &discord_user_ids).await;
+                });
             }
             RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event, _, _))))
         }
@@ -5476,6 +5257,7 @@ pub(crate) async fn copy_volunteers_from_event(
 
             let mut copied_count = 0;
             let mut skipped_count = 0;
+            let mut copied_discord_users = Vec::default();
 
             for source_req in source_requests {
                 // Check if this role type exists in the target event (by name)
@@ -5497,20 +5279,10 @@ pub(crate) async fn copy_volunteers_from_event(
                         .execute(&mut *transaction)
                         .await?;
 
-                        // Assign Discord role if configured
-                        if let Some(discord_role_id) = target_binding.discord_role_id {
-                            let user = User::from_id(&mut *transaction, source_req.user_id).await?;
-                            if let Some(user) = user {
-                                if let Some(discord_user) = user.discord {
-                                    let discord_ctx = discord_ctx.read().await;
-                                    if let Some(discord_guild) = data.discord_guild {
-                                        if let Ok(member) = discord_guild.member(&*discord_ctx, discord_user.id).await {
-                                            if let Err(e) = member.add_role(&*discord_ctx, RoleId::new(discord_role_id.try_into().unwrap())).await {
-                                                eprintln!("Failed to assign Discord role {} to user {}: {}", discord_role_id, discord_user.id, e);
-                                            }
-                                        }
-                                    }
-                                }
+                        // Assign Discord role if configured, once the copied requests are saved
+                        if target_binding.discord_role_id.is_some() {
+                            if let Some(discord_user) = User::from_id(&mut *transaction, source_req.user_id).await?.and_then(|user| user.discord) {
+                                copied_discord_users.push(discord_user.id);
                             }
                         }
 
@@ -5522,6 +5294,9 @@ pub(crate) async fn copy_volunteers_from_event(
             }
 
             transaction.commit().await?;
+            if let Some(discord_guild) = data.discord_guild {
+                discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, Some(discord_guild), &copied_discord_users).await;
+            }
 
             eprintln!("Copied {} volunteers from {} to {}. Skipped {} duplicates.",
                      copied_count, source_event, event, skipped_count);
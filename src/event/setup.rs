@@ -144,6 +144,14 @@ async fn setup_form(mut transaction: Transaction<'_, Postgres>, me: Option<User>
                             ), style = "width: 100%; max-width: 600px;");
                         });
 
+                        : form_field("discord_standings_channel", &mut errors, html! {
+                            label(for = "discord_standings_channel") : "Discord Standings Channel ID";
+                            input(type = "text", id = "discord_standings_channel", name = "discord_standings_channel", value = ctx.field_value("discord_standings_channel").unwrap_or(
+                                &event.discord_standings_channel.map(|c| c.get().to_string()).unwrap_or_default()
+                            ), style = "width: 100%; max-width: 600px;");
+                            label(class = "help") : "(Updated standings are posted here after each reported result)";
+                        });
+
                         : form_field("discord_participant_role", &mut errors, html! {
                             label(for = "discord_participant_role") : "Discord Participant Role ID";
                             input(type = "text", id = "discord_participant_role", name = "discord_participant_role", value = ctx.field_value("discord_participant_role").unwrap_or(
@@ -315,12 +323,18 @@ async fn setup_form(mut transaction: Transaction<'_, Postgres>, me: Option<User>
                     h3 : "Enter Flow Configuration";
                     
                     : full_form(uri!(update_enter_flow(event.series, &*event.event)), csrf, html! {
+                        div(id = "enter-flow-editor", data_textarea = "enter_flow_json", data_preview_path = uri!(enter_flow_preview(event.series, &*event.event)).to_string()) {}
+                        h4 : "Preview";
+                        p(class = "help") : "This is how the signup form would look to you with the requirements above. Changes aren't saved until you click “Save Enter Flow”.";
+                        div(id = "enter-flow-preview", style = "max-width: 800px; padding: 10px; border: 1px solid #ddd; border-radius: 6px;") {
+                            p : "Enable JavaScript to see a preview.";
+                        }
                         : form_field("enter_flow_json", &mut errors, html! {
                             label(for = "enter_flow_json") : "Enter Flow JSON";
                             textarea(id = "enter_flow_json", name = "enter_flow_json", rows = "10", style = "font-family: monospace; width: 100%; max-width: 800px;") {
                                 : ctx.field_value("enter_flow_json").unwrap_or(&enter_flow_string);
                             }
-                            p(class = "help") : "Configure the signup requirements as JSON. Leave empty for no requirements.";
+                            p(class = "help") : "Configure the signup requirements using the editor above or as JSON. Leave empty for no requirements.";
                             
                             details {
                                 summary : "Example enter_flow configurations";
@@ -522,6 +536,7 @@ async fn setup_form(mut transaction: Transaction<'_, Postgres>, me: Option<User>
         : header;
         : content;
         script(src = static_url!("user-search.js")) {}
+        script(src = static_url!("enter-flow-edit.js")) {}
     }).await?)
 }
 
@@ -550,6 +565,7 @@ pub(crate) struct SetupForm {
     discord_organizer_channel: Option<String>,
     discord_scheduling_channel: Option<String>,
     discord_async_channel: Option<String>,
+    discord_standings_channel: Option<String>,
     discord_participant_role: Option<String>,
     listed: bool,
     emulator_settings_reminder: bool,
@@ -789,6 +805,22 @@ pub(crate) async fn post(pool: &State<PgPool>, discord_ctx: &State<RwFuture<Disc
                 None
             };
 
+            let discord_standings_channel = if let Some(channel_str) = &value.discord_standings_channel {
+                if !channel_str.is_empty() {
+                    match channel_str.parse::<u64>() {
+                        Ok(id) => Some(ChannelId::new(id)),
+                        Err(_) => {
+                            form.context.push_error(form::Error::validation("Invalid Discord standings channel ID"));
+                            None
+                        }
+                    }
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
             let discord_participant_role = if let Some(role_str) = &value.discord_participant_role {
                 if !role_str.is_empty() {
                     match role_str.parse::<u64>() {
@@ -912,8 +944,8 @@ pub(crate) async fn post(pool: &State<PgPool>, discord_ctx: &State<RwFuture<Disc
                     hide_teams_tab = $26, hide_races_tab = $27, show_qualifier_times = $28,
                     automated_asyncs = $29, show_opt_out = $30, force_custom_role_binding = $31,
                     async_start_delay = $32, startgg_double_rr = $33, fpa_enabled = $34,
-                    swiss_standings = $35, rando_version = $36
-                WHERE series = $37 AND event = $38
+                    swiss_standings = $35, rando_version = $36, discord_standings_channel = $37
+                WHERE series = $38 AND event = $39
             "#,
                 value.display_name,
                 start,
@@ -951,6 +983,7 @@ pub(crate) async fn post(pool: &State<PgPool>, discord_ctx: &State<RwFuture<Disc
                 value.fpa_enabled,
                 value.swiss_standings,
                 rando_version.unwrap() as _,
+                discord_standings_channel.map(|c| c.get() as i64),
                 event_data.series as _,
                 &event_data.event,
             ).execute(&mut *transaction).await?;
@@ -1190,10 +1223,12 @@ pub(crate) async fn update_enter_flow(pool: &State<PgPool>, me: User, uri: Origi
         } else {
             // Parse enter_flow JSON and validate against Flow struct
             let enter_flow_json = if !value.enter_flow_json.trim().is_empty() {
-                match serde_json::from_str::<enter::Flow>(&value.enter_flow_json) {
+                match enter::validate_flow(&value.enter_flow_json) {
                     Ok(_) => Some(serde_json::from_str::<serde_json::Value>(&value.enter_flow_json).expect("already validated as JSON")),
-                    Err(e) => {
-                        form.context.push_error(form::Error::validation(format!("Invalid enter flow: {e}")).with_name("enter_flow_json"));
+                    Err(problems) => {
+                        for problem in problems {
+                            form.context.push_error(form::Error::validation(format!("Invalid enter flow: {problem}")).with_name("enter_flow_json"));
+                        }
                         None
                     }
                 }
@@ -1225,6 +1260,41 @@ pub(crate) async fn update_enter_flow(pool: &State<PgPool>, me: User, uri: Origi
     })
 }
 
+/// Renders a preview of the signup form for an enter flow which hasn't been saved yet, for the editor on the setup page.
+#[rocket::post("/event/<series>/<event>/setup/enter-flow-preview", data = "<form>")]
+pub(crate) async fn enter_flow_preview(config: &State<Config>, pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, UpdateEnterFlowForm>>) -> Result<RawHtml<String>, StatusOrError<enter::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event_data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    if !me.is_global_admin() {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    let Some(ref value) = form.value else { return Err(StatusOrError::Status(Status::BadRequest)) };
+    if form.context.errors().next().is_some() {
+        return Err(StatusOrError::Status(Status::BadRequest))
+    }
+    let content = if value.enter_flow_json.trim().is_empty() {
+        html! {
+            p : "No enter flow is configured, so signups for this event won't open.";
+        }
+    } else {
+        match enter::validate_flow(&value.enter_flow_json) {
+            Ok(flow) => enter::preview(&mut transaction, http_client, discord_ctx, &me, &event_data, &flow, config).await?,
+            Err(problems) => html! {
+                p : "The enter flow can't be saved yet:";
+                ul(class = "error") {
+                    @for problem in problems {
+                        li : problem;
+                    }
+                }
+            },
+        }
+    };
+    transaction.rollback().await?;
+    Ok(content)
+}
+
 #[rocket::get("/event/setup/search-users?<query>")]
 pub(crate) async fn search_users(pool: &State<PgPool>, query: Option<&str>) -> Result<RawText<String>, StatusOrError<event::Error>> {
     let mut transaction = pool.begin().await?;
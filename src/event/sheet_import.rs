@@ -0,0 +1,678 @@
+//! Automatic import of race schedules which events publish as Google sheets.
+//!
+//! Each event whose matches are created manually can configure one sheet to import from, with a mapping from columns of a range in that sheet to race data.
+//! The import runs as part of the automatic race import for events with automatic imports enabled. Races which were imported before are identified by a key column,
+//! or by their round and entrants if no key column is configured, and are updated when the sheet changes unless their schedule is locked or a room has been opened.
+//! Races whose rows have been removed from the sheet are ignored, with the same exceptions.
+
+use {
+    chrono::LocalResult,
+    crate::{
+        cal::Source,
+        event::{
+            Data,
+            Series,
+            Tab,
+            race_upload::{
+                self,
+                EntrantRef,
+                ResolveError,
+            },
+        },
+        prelude::*,
+        sheets::{
+            self,
+            ValuesSource,
+        },
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum Error {
+    #[error(transparent)] Calendar(#[from] cal::Error),
+    #[error(transparent)] Sheets(#[from] sheets::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+    #[error("invalid timezone in sheet import configuration: {0}")]
+    Timezone(String),
+}
+
+impl IsNetworkError for Error {
+    fn is_network_error(&self) -> bool {
+        match self {
+            Self::Calendar(e) => e.is_network_error(),
+            Self::Sheets(e) => e.is_network_error(),
+            Self::Sql(_) => false,
+            Self::Timezone(_) => false,
+        }
+    }
+}
+
+pub(crate) struct Config {
+    sheet_id: String,
+    range: String,
+    key_column: Option<String>,
+    entrant_columns: Vec<String>,
+    start_column: Option<String>,
+    start_format: String,
+    timezone: Tz,
+    round_column: Option<String>,
+    restream_column: Option<String>,
+}
+
+impl Config {
+    pub(crate) async fn for_event(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> Result<Option<Self>, Error> {
+        let Some(row) = sqlx::query!("SELECT sheet_id, sheet_range, key_column, entrant_columns, start_column, start_format, timezone, round_column, restream_column FROM sheet_imports WHERE series = $1 AND event = $2", series as _, event).fetch_optional(&mut **transaction).await? else { return Ok(None) };
+        Ok(Some(Self {
+            timezone: row.timezone.parse().map_err(|_| Error::Timezone(row.timezone))?,
+            sheet_id: row.sheet_id,
+            range: row.sheet_range,
+            key_column: row.key_column,
+            entrant_columns: row.entrant_columns,
+            start_column: row.start_column,
+            start_format: row.start_format,
+            round_column: row.round_column,
+            restream_column: row.restream_column,
+        }))
+    }
+
+    async fn save(&self, transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> sqlx::Result<()> {
+        sqlx::query!("
+            INSERT INTO sheet_imports (series, event, sheet_id, sheet_range, key_column, entrant_columns, start_column, start_format, timezone, round_column, restream_column) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (series, event) DO UPDATE SET (sheet_id, sheet_range, key_column, entrant_columns, start_column, start_format, timezone, round_column, restream_column) = (EXCLUDED.sheet_id, EXCLUDED.sheet_range, EXCLUDED.key_column, EXCLUDED.entrant_columns, EXCLUDED.start_column, EXCLUDED.start_format, EXCLUDED.timezone, EXCLUDED.round_column, EXCLUDED.restream_column)
+        ", series as _, event, self.sheet_id, self.range, self.key_column, &self.entrant_columns, self.start_column, self.start_format, self.timezone.name(), self.round_column, self.restream_column).execute(&mut **transaction).await?;
+        Ok(())
+    }
+}
+
+/// Converts a column name in A1 notation (`A`, …, `Z`, `AA`, …) to a 0-based index.
+fn column_index(column: &str) -> Option<usize> {
+    let column = column.trim();
+    if column.is_empty() { return None }
+    column.chars().try_fold(0usize, |index, c| {
+        if !c.is_ascii_alphabetic() { return None }
+        index.checked_mul(26)?.checked_add(usize::from(c.to_ascii_uppercase() as u8 - b'A') + 1)
+    }).map(|index| index - 1)
+}
+
+/// The 0-based column index and 1-based row number of the top left cell of a range in A1 notation.
+fn range_start(range: &str) -> (usize, usize) {
+    let cells = match range.rsplit_once('!') {
+        Some((_, cells)) => cells,
+        None if range.contains(':') => range,
+        None => return (0, 1), // a sheet name on its own refers to the entire sheet
+    };
+    let start = cells.split(':').next().unwrap_or(cells);
+    let (column, row) = start.split_at(start.find(|c: char| c.is_ascii_digit()).unwrap_or(start.len()));
+    (column_index(column).unwrap_or_default(), row.parse().unwrap_or(1))
+}
+
+fn cell<'a>(cells: &'a [String], first_column: usize, column: &str) -> Option<&'a str> {
+    column_index(column)
+        .and_then(|idx| idx.checked_sub(first_column))
+        .and_then(|idx| cells.get(idx))
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RowError {
+    #[error(transparent)] Entrant(#[from] race_upload::RowError),
+    #[error(transparent)] Url(#[from] url::ParseError),
+    #[error("The key column is empty.")]
+    MissingKey,
+    #[error("There is another row with the same key.")]
+    DuplicateKey,
+    #[error("“{value}” does not match the start time format “{format}”.")]
+    StartFormat {
+        value: String,
+        format: String,
+    },
+    #[error("{0} does not exist in the configured timezone.")]
+    NonexistentLocalTime(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Row {
+    key: String,
+    entrants: Vec<EntrantRef>,
+    round: Option<String>,
+    start: Option<DateTime<Utc>>,
+    restream: Option<Url>,
+}
+
+/// Parses the values of the configured range into rows, each with its row number in the sheet.
+///
+/// Rows without any entrants are skipped, so the range can include blank rows or headings.
+fn parse_values(config: &Config, values: Vec<Vec<String>>) -> Vec<(usize, Result<Row, RowError>)> {
+    let (first_column, first_row) = range_start(&config.range);
+    let mut keys = HashSet::new();
+    let mut rows = Vec::default();
+    for (idx, cells) in values.into_iter().enumerate() {
+        if config.entrant_columns.iter().all(|column| cell(&cells, first_column, column).is_none()) {
+            continue
+        }
+        let row = parse_row(config, &cells, first_column).and_then(|row| if keys.insert(row.key.clone()) { Ok(row) } else { Err(RowError::DuplicateKey) });
+        rows.push((first_row + idx, row));
+    }
+    rows
+}
+
+fn parse_row(config: &Config, cells: &[String], first_column: usize) -> Result<Row, RowError> {
+    let field = |column: &Option<String>| column.as_deref().and_then(|column| cell(cells, first_column, column));
+    let entrants = config.entrant_columns.iter()
+        .filter_map(|column| cell(cells, first_column, column))
+        .map(EntrantRef::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if entrants.len() < 2 {
+        return Err(race_upload::RowError::NotEnoughEntrants.into())
+    }
+    let round = field(&config.round_column).map(str::to_owned);
+    let key = if config.key_column.is_some() {
+        field(&config.key_column).ok_or(RowError::MissingKey)?.to_owned()
+    } else {
+        iter::once(round.as_deref().unwrap_or_default())
+            .chain(config.entrant_columns.iter().map(|column| cell(cells, first_column, column).unwrap_or_default()))
+            .join("\t")
+    };
+    let start = field(&config.start_column).map(|value| {
+        let naive = NaiveDateTime::parse_from_str(value, &config.start_format).map_err(|_| RowError::StartFormat { value: value.to_owned(), format: config.start_format.clone() })?;
+        match config.timezone.from_local_datetime(&naive) {
+            LocalResult::Single(start) | LocalResult::Ambiguous(start, _) => Ok(start.with_timezone(&Utc)),
+            LocalResult::None => Err(RowError::NonexistentLocalTime(value.to_owned())),
+        }
+    }).transpose()?;
+    Ok(Row {
+        restream: field(&config.restream_column).map(Url::parse).transpose()?,
+        key, entrants, round, start,
+    })
+}
+
+async fn fetch_rows(values_source: &impl ValuesSource, config: &Config) -> Result<Vec<(usize, Result<Row, RowError>)>, sheets::Error> {
+    let values = values_source.values(&config.sheet_id, &config.range).await?;
+    Ok(parse_values(config, values))
+}
+
+struct PreviewRow {
+    row_number: usize,
+    row: Result<(Row, Vec<Result<Team, ResolveError>>), RowError>,
+    existing: Option<Id<Races>>,
+}
+
+impl PreviewRow {
+    fn teams(&self) -> Option<Vec<Team>> {
+        let (_, teams) = self.row.as_ref().ok()?;
+        teams.iter().map(|team| team.as_ref().ok().cloned()).collect()
+    }
+}
+
+struct Preview {
+    rows: Vec<PreviewRow>,
+    /// Races which were imported earlier but whose rows are no longer in the sheet.
+    removed: Vec<Id<Races>>,
+}
+
+async fn preview(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &Data<'_>, config: &Config) -> Result<Preview, Error> {
+    let rows = fetch_rows(http_client, config).await?;
+    // a row with errors may still belong to a previously imported race, and an empty range is more likely to be a mistake than an intentionally cleared schedule
+    let can_remove = !rows.is_empty() && rows.iter().all(|(_, row)| row.is_ok());
+    let team_names = race_upload::team_names(transaction, event).await?;
+    let existing = sqlx::query!(r#"SELECT id AS "id: Id<Races>", sheet_key AS "sheet_key!" FROM races WHERE series = $1 AND event = $2 AND sheet_key IS NOT NULL"#, event.series as _, &event.event)
+        .fetch_all(&mut **transaction).await?
+        .into_iter()
+        .map(|row| (row.sheet_key, row.id))
+        .collect::<HashMap<_, _>>();
+    let mut preview = Vec::with_capacity(rows.len());
+    for (row_number, row) in rows {
+        let row = match row {
+            Ok(row) => {
+                let teams = race_upload::resolve_entrants(transaction, event, &team_names, &row.entrants).await?;
+                Ok((row, teams))
+            }
+            Err(e) => Err(e),
+        };
+        let existing = row.as_ref().ok().and_then(|(row, _)| existing.get(&row.key).copied());
+        preview.push(PreviewRow { row_number, row, existing });
+    }
+    let removed = if can_remove {
+        let sheet_keys = preview.iter().filter_map(|row| row.row.as_ref().ok()).map(|(row, _)| &row.key).collect::<HashSet<_>>();
+        existing.into_iter().filter(|(key, _)| !sheet_keys.contains(key)).map(|(_, id)| id).collect()
+    } else {
+        Vec::default()
+    };
+    Ok(Preview { rows: preview, removed })
+}
+
+/// Updates races which were previously imported from the event's sheet, and returns the races which are new.
+///
+/// The new races should be created using [`cal::import_race`] so they get a scheduling thread. Rows with errors are skipped; they're listed on the preview page.
+pub(crate) async fn races_to_import(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &Data<'_>, config: &Config) -> Result<Vec<Race>, Error> {
+    let Preview { rows, removed } = preview(transaction, http_client, event, config).await?;
+    let mut races = Vec::default();
+    for preview_row in rows {
+        let Some(teams) = preview_row.teams() else { continue };
+        let Ok((row, _)) = preview_row.row else { continue };
+        let video_urls = row.restream.map(|url| iter::once((English, url)).collect()).unwrap_or_default();
+        if let Some(id) = preview_row.existing {
+            let mut race = Race::from_id(transaction, http_client, id).await?;
+            if race.schedule_locked || race.has_any_room() { continue } // stop automatically updating once a room is open
+            if cal::update_imported_race(transaction, http_client, event, &mut race, teams, row.round, row.start, video_urls).await? {
+                race.save(transaction).await?;
+            }
+        } else {
+            races.push(cal::new_imported_race(transaction, http_client, event, Source::SheetRow { key: row.key }, teams, row.round, row.start, video_urls).await?);
+        }
+    }
+    for id in removed {
+        let mut race = Race::from_id(transaction, http_client, id).await?;
+        if race.ignored || race.schedule_locked || race.has_any_room() { continue }
+        race.ignored = true;
+        race.save(transaction).await?;
+    }
+    Ok(races)
+}
+
+enum SheetImportFormDefaults<'v> {
+    None,
+    SaveContext(Context<'v>),
+    DeleteContext(Context<'v>),
+}
+
+impl<'v> SheetImportFormDefaults<'v> {
+    fn save_errors(&self) -> Vec<&form::Error<'v>> {
+        if let Self::SaveContext(ctx) = self {
+            ctx.errors().collect()
+        } else {
+            Vec::default()
+        }
+    }
+
+    fn delete_errors(&self) -> Vec<&form::Error<'v>> {
+        if let Self::DeleteContext(ctx) = self {
+            ctx.errors().collect()
+        } else {
+            Vec::default()
+        }
+    }
+
+    fn save_value(&self, name: &str) -> Option<&str> {
+        if let Self::SaveContext(ctx) = self {
+            ctx.field_value(name)
+        } else {
+            None
+        }
+    }
+}
+
+async fn sheet_import_page(mut transaction: Transaction<'_, Postgres>, http_client: &reqwest::Client, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, defaults: SheetImportFormDefaults<'_>) -> Result<RawHtml<String>, event::Error> {
+    let header = event.header(&mut transaction, me.as_ref(), Tab::Races, true).await?;
+    let is_organizer = if let Some(ref me) = me {
+        me.is_global_admin() || event.organizers(&mut transaction).await?.contains(me)
+    } else {
+        false
+    };
+    let content = if is_organizer {
+        let config = match Config::for_event(&mut transaction, event.series, &event.event).await {
+            Ok(config) => Ok(config),
+            Err(Error::Sql(e)) => return Err(e.into()),
+            Err(e) => Err(e),
+        };
+        let preview_html = match config {
+            Ok(None) => html! {},
+            Ok(Some(ref config)) => match preview(&mut transaction, http_client, &event, config).await {
+                Ok(Preview { rows, removed }) => html! {
+                    h3 : "Preview";
+                    @if !event.auto_import {
+                        p : "Automatic imports are disabled for this event, so these races won't be imported until they are enabled.";
+                    }
+                    table {
+                        thead {
+                            tr {
+                                th : "Row";
+                                th : "Entrants";
+                                th : "Round";
+                                th : "Start";
+                                th : "Restream";
+                                th : "Status";
+                            }
+                        }
+                        tbody {
+                            @if rows.is_empty() {
+                                tr {
+                                    td(colspan = "6") {
+                                        i : "The configured range has no races.";
+                                    }
+                                }
+                            }
+                            @for row in &rows {
+                                tr {
+                                    td : row.row_number.to_string();
+                                    @match row.row {
+                                        Ok((ref parsed, ref teams)) => {
+                                            td {
+                                                ul {
+                                                    @for (entrant, team) in parsed.entrants.iter().zip(teams) {
+                                                        li {
+                                                            @match team {
+                                                                Ok(team) => {
+                                                                    : team.to_html(&mut transaction, false).await?;
+                                                                }
+                                                                Err(e) => {
+                                                                    bdi : entrant.to_string();
+                                                                    : " — ";
+                                                                    span(class = "error") : e.to_string();
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            td : parsed.round.as_deref().unwrap_or("—");
+                                            td {
+                                                @if let Some(start) = parsed.start {
+                                                    : format_datetime(start, DateTimeFormat { long: false, running_text: false });
+                                                } else {
+                                                    : "unscheduled";
+                                                }
+                                            }
+                                            td {
+                                                @if let Some(ref restream) = parsed.restream {
+                                                    a(href = restream.to_string()) : restream.to_string();
+                                                } else {
+                                                    : "—";
+                                                }
+                                            }
+                                        }
+                                        Err(_) => {
+                                            td(colspan = "4");
+                                        }
+                                    }
+                                    td {
+                                        @if let Err(ref e) = row.row {
+                                            span(class = "error") : e.to_string();
+                                        } else if row.teams().is_none() {
+                                            span(class = "error") : "Invalid entrants";
+                                        } else if let Some(id) = row.existing {
+                                            a(href = uri!(cal::edit_race(event.series, &*event.event, id, Some(uri!(get(event.series, &*event.event)))))) : "Imported";
+                                        } else {
+                                            : "New";
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    @if !removed.is_empty() {
+                        p {
+                            @if removed.len() == 1 {
+                                : "1 previously imported race is no longer in the sheet and will be ignored unless its room is already open.";
+                            } else {
+                                : removed.len().to_string();
+                                : " previously imported races are no longer in the sheet and will be ignored unless their rooms are already open.";
+                            }
+                        }
+                    }
+                },
+                Err(Error::Sql(e)) => return Err(e.into()),
+                Err(e) => html! {
+                    p(class = "error") {
+                        : "Failed to load the sheet: ";
+                        : e.to_string();
+                    }
+                },
+            },
+            Err(ref e) => html! {
+                p(class = "error") : e.to_string();
+            },
+        };
+        let config = config.ok().flatten();
+        let value = |name: &str, current: Option<String>| defaults.save_value(name).map(str::to_owned).or(current);
+        let mut errors = defaults.save_errors();
+        html! {
+            p {
+                : "Races can be imported from a Google sheet which is shared with ";
+                code : "mido-s-house@mido-s-house.iam.gserviceaccount.com";
+                : ". Columns are given as letters, e.g. ";
+                code : "B";
+                : ". Entrants can be given in any of the ways supported for ";
+                a(href = uri!(race_upload::get(event.series, &*event.event)).to_string()) : "uploading races";
+                : ". Rows without entrants are skipped.";
+            }
+            : full_form(uri!(post(event.series, &*event.event)), csrf, html! {
+                : form_field("sheet_id", &mut errors, html! {
+                    label(for = "sheet_id") : "Sheet ID:";
+                    input(type = "text", name = "sheet_id", value? = value("sheet_id", config.as_ref().map(|config| config.sheet_id.clone())));
+                    label(class = "help") : " (the part of the sheet's URL after /d/)";
+                });
+                : form_field("range", &mut errors, html! {
+                    label(for = "range") : "Range:";
+                    input(type = "text", name = "range", value? = value("range", config.as_ref().map(|config| config.range.clone())));
+                    label(class = "help") : " (e.g. Schedule!A2:H, excluding any header rows)";
+                });
+                : form_field("entrant_columns", &mut errors, html! {
+                    label(for = "entrant_columns") : "Entrant columns:";
+                    input(type = "text", name = "entrant_columns", value? = value("entrant_columns", config.as_ref().map(|config| config.entrant_columns.join(", "))));
+                    label(class = "help") : " (2 or 3 columns, separated by commas)";
+                });
+                : form_field("key_column", &mut errors, html! {
+                    label(for = "key_column") : "Key column:";
+                    input(type = "text", name = "key_column", value? = value("key_column", config.as_ref().and_then(|config| config.key_column.clone())));
+                    label(class = "help") : " (a column with a unique ID for each match; if left empty, matches are identified by their round and entrants)";
+                });
+                : form_field("start_column", &mut errors, html! {
+                    label(for = "start_column") : "Start time column:";
+                    input(type = "text", name = "start_column", value? = value("start_column", config.as_ref().and_then(|config| config.start_column.clone())));
+                });
+                : form_field("start_format", &mut errors, html! {
+                    label(for = "start_format") : "Start time format:";
+                    input(type = "text", name = "start_format", value = value("start_format", config.as_ref().map(|config| config.start_format.clone())).unwrap_or_else(|| format!("%Y-%m-%d %H:%M")));
+                    label(class = "help") {
+                        : " (see ";
+                        a(href = "https://docs.rs/chrono/latest/chrono/format/strftime/index.html") : "the list of format specifiers";
+                        : ")";
+                    }
+                });
+                : form_field("timezone", &mut errors, html! {
+                    label(for = "timezone") : "Timezone:";
+                    input(type = "text", name = "timezone", value = value("timezone", config.as_ref().map(|config| config.timezone.name().to_owned())).unwrap_or_else(|| format!("UTC")));
+                    label(class = "help") : " (of the start times in the sheet, e.g. Europe/Berlin)";
+                });
+                : form_field("round_column", &mut errors, html! {
+                    label(for = "round_column") : "Round column:";
+                    input(type = "text", name = "round_column", value? = value("round_column", config.as_ref().and_then(|config| config.round_column.clone())));
+                });
+                : form_field("restream_column", &mut errors, html! {
+                    label(for = "restream_column") : "Restream URL column:";
+                    input(type = "text", name = "restream_column", value? = value("restream_column", config.as_ref().and_then(|config| config.restream_column.clone())));
+                });
+            }, errors, "Save");
+            @if config.is_some() {
+                @let (errors, button) = button_form(uri!(delete(event.series, &*event.event)), csrf, defaults.delete_errors(), "Remove Sheet Import");
+                : errors;
+                div(class = "button-row") : button;
+            }
+            : preview_html;
+        }
+    } else {
+        html! {
+            article {
+                p : "This page is only available for event organizers.";
+            }
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Sheet Import — {}", event.display_name), html! {
+        : header;
+        h2 : "Sheet import";
+        : content;
+    }).await?)
+}
+
+#[rocket::get("/event/<series>/<event>/races/sheet")]
+pub(crate) async fn get(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: Option<User>, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok(sheet_import_page(transaction, http_client, me, uri, csrf.as_ref(), event, SheetImportFormDefaults::None).await?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct SheetImportForm {
+    #[field(default = String::new())]
+    csrf: String,
+    sheet_id: String,
+    range: String,
+    entrant_columns: String,
+    key_column: String,
+    start_column: String,
+    start_format: String,
+    timezone: String,
+    round_column: String,
+    restream_column: String,
+}
+
+#[rocket::post("/event/<series>/<event>/races/sheet", data = "<form>")]
+pub(crate) async fn post(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, SheetImportForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
+        }
+        if !matches!(event.match_source(), MatchSource::Manual) {
+            form.context.push_error(form::Error::validation("Races can only be imported from a sheet for events whose matches are created manually."));
+        }
+        if value.sheet_id.trim().is_empty() {
+            form.context.push_error(form::Error::validation("This field is required.").with_name("sheet_id"));
+        }
+        let range = value.range.trim();
+        let (first_column, _) = range_start(range);
+        if range.is_empty() {
+            form.context.push_error(form::Error::validation("This field is required.").with_name("range"));
+        }
+        let mut check_column = |name: &'static str, column: &str| {
+            let column = column.trim();
+            if column.is_empty() {
+                None
+            } else if column_index(column).is_some_and(|idx| idx >= first_column) {
+                Some(column.to_ascii_uppercase())
+            } else {
+                form.context.push_error(form::Error::validation(format!("“{column}” is not a column in the range.")).with_name(name));
+                None
+            }
+        };
+        let entrant_columns = value.entrant_columns.split(',').filter_map(|column| check_column("entrant_columns", column)).collect_vec();
+        let key_column = check_column("key_column", &value.key_column);
+        let start_column = check_column("start_column", &value.start_column);
+        let round_column = check_column("round_column", &value.round_column);
+        let restream_column = check_column("restream_column", &value.restream_column);
+        if !(2..=3).contains(&entrant_columns.len()) {
+            form.context.push_error(form::Error::validation("Please enter 2 or 3 entrant columns.").with_name("entrant_columns"));
+        }
+        let timezone = value.timezone.trim().parse::<Tz>();
+        if timezone.is_err() {
+            form.context.push_error(form::Error::validation("Invalid timezone. Use a format like America/New_York or Europe/London.").with_name("timezone"));
+        }
+        if start_column.is_some() && value.start_format.trim().is_empty() {
+            form.context.push_error(form::Error::validation("This field is required when a start time column is given.").with_name("start_format"));
+        }
+        if let (Ok(timezone), None) = (timezone, form.context.errors().next()) {
+            Config {
+                sheet_id: value.sheet_id.trim().to_owned(),
+                range: range.to_owned(),
+                start_format: value.start_format.trim().to_owned(),
+                key_column, entrant_columns, start_column, timezone, round_column, restream_column,
+            }.save(&mut transaction, event.series, &event.event).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        } else {
+            RedirectOrContent::Content(sheet_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, SheetImportFormDefaults::SaveContext(form.context)).await?)
+        }
+    } else {
+        RedirectOrContent::Content(sheet_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, SheetImportFormDefaults::SaveContext(form.context)).await?)
+    })
+}
+
+#[rocket::post("/event/<series>/<event>/races/sheet/delete", data = "<form>")]
+pub(crate) async fn delete(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if form.value.is_some() {
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(sheet_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, SheetImportFormDefaults::DeleteContext(form.context)).await?)
+        } else {
+            sqlx::query!("DELETE FROM sheet_imports WHERE series = $1 AND event = $2", event.series as _, &event.event).execute(&mut *transaction).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        }
+    } else {
+        RedirectOrContent::Content(sheet_import_page(transaction, http_client, Some(me), uri, csrf.as_ref(), event, SheetImportFormDefaults::DeleteContext(form.context)).await?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(range: &str) -> Config {
+        Config {
+            sheet_id: format!("sheet"),
+            range: range.to_owned(),
+            key_column: Some(format!("B")),
+            entrant_columns: vec![format!("C"), format!("D")],
+            start_column: Some(format!("E")),
+            start_format: format!("%d/%m/%Y %H:%M"),
+            timezone: Europe::Berlin,
+            round_column: Some(format!("F")),
+            restream_column: Some(format!("G")),
+        }
+    }
+
+    #[test]
+    fn columns_and_ranges() {
+        assert_eq!(column_index("A"), Some(0));
+        assert_eq!(column_index("z"), Some(25));
+        assert_eq!(column_index("AA"), Some(26));
+        assert_eq!(column_index("A1"), None);
+        assert_eq!(range_start("Schedule!B3:G"), (1, 3));
+        assert_eq!(range_start("'Week 1'!C:G"), (2, 1));
+        assert_eq!(range_start("Schedule"), (0, 1));
+    }
+
+    #[test]
+    fn reads_rows_from_stub() {
+        let stub = sheets::Stub::default().with_values("sheet", "Schedule!B2:G", &[
+            &["m1", "Alpha", "hth:42", "01/07/2026 20:00", "Round 1", "https://twitch.tv/example"],
+            &[],
+            &["Day 2"],
+            &["m2", "Beta", "", "", "Round 2"],
+            &["m1", "Gamma", "Delta"],
+        ]);
+        let rows = futures::executor::block_on(fetch_rows(&stub, &config("Schedule!B2:G"))).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].0, 2);
+        assert_eq!(*rows[0].1.as_ref().unwrap(), Row {
+            key: format!("m1"),
+            entrants: vec![EntrantRef::Any(format!("Alpha")), EntrantRef::User(Id::from(42u64))],
+            round: Some(format!("Round 1")),
+            start: Some(Utc.with_ymd_and_hms(2026, 7, 1, 18, 0, 0).unwrap()),
+            restream: Some(Url::parse("https://twitch.tv/example").unwrap()),
+        });
+        assert_eq!(rows[1].0, 5);
+        assert!(matches!(rows[1].1, Err(RowError::Entrant(race_upload::RowError::NotEnoughEntrants))));
+        assert!(matches!(rows[2].1, Err(RowError::DuplicateKey)));
+    }
+
+    #[test]
+    fn keys_default_to_round_and_entrants() {
+        let config = Config { key_column: None, ..config("B1:G") };
+        let rows = parse_values(&config, vec![vec![String::default(), format!("A"), format!("B"), format!("tomorrow")]]);
+        assert!(matches!(rows[0].1, Err(RowError::StartFormat { .. })));
+        let rows = parse_values(&config, vec![vec![String::default(), format!("A"), format!("B"), String::default(), format!("Final")]]);
+        assert_eq!(rows[0].1.as_ref().unwrap().key, "Final\tA\tB");
+    }
+}
@@ -118,7 +118,7 @@ pub(crate) async fn get(
         : header;
         article {
             h2 : "SpeedGaming Export";
-            p : "Exports upcoming 1v1 races to one SpeedGaming event. Volunteer signup languages are selected separately.";
+            p : "Exports upcoming races to one SpeedGaming event. Volunteer signup languages are selected separately.";
 
             @if exports.is_empty() {
                 p : "No SpeedGaming exports are configured for this event.";
@@ -0,0 +1,606 @@
+//! Ordered stages of an event, such as qualifiers followed by a Swiss stage and a top cut bracket.
+//!
+//! Each bracket stage is run as a native bracket (see [`super::bracket`]) and is seeded from the ranking of the previous stage when it starts.
+//! Once a bracket stage is complete, the next one starts automatically. Stages following a qualifier stage are started by an organizer, since qualifier results aren't final until then.
+
+use crate::{
+    event::{
+        Data,
+        Series,
+        Tab,
+        bracket::{
+            self,
+            StoredBracket,
+        },
+        swiss,
+        teams::{
+            self,
+            Qualification,
+            SignupsTeam,
+        },
+    },
+    prelude::*,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, FromFormField)]
+#[sqlx(type_name = "stage_kind", rename_all = "snake_case")]
+pub(crate) enum Kind {
+    /// Ranks entrants by qualifier rank. Only allowed as the first stage.
+    #[field(value = "qualifier")]
+    Qualifier,
+    #[field(value = "bracket")]
+    Bracket,
+}
+
+pub(crate) struct Stage {
+    pub(crate) stage: i16,
+    pub(crate) name: String,
+    pub(crate) kind: Kind,
+    bracket_kind: Option<bracket::Kind>,
+    grand_final_reset: bool,
+    rounds: Option<i16>,
+    pools: i16,
+    best_of: i16,
+    /// How many entrants advance into this stage from the previous one. `None` means everyone advances.
+    advance_count: Option<i16>,
+    /// Whether `advance_count` applies to each pool of the previous stage rather than the stage as a whole.
+    advance_per_pool: bool,
+}
+
+impl Stage {
+    pub(crate) async fn for_event(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(Self, r#"SELECT
+            stage,
+            name,
+            kind AS "kind: Kind",
+            bracket_kind AS "bracket_kind: bracket::Kind",
+            grand_final_reset,
+            rounds,
+            pools,
+            best_of,
+            advance_count,
+            advance_per_pool
+        FROM event_stages WHERE series = $1 AND event = $2 ORDER BY stage"#, series as _, event).fetch_all(&mut **transaction).await
+    }
+
+    fn settings(&self) -> Option<bracket::Settings> {
+        Some(bracket::Settings {
+            kind: self.bracket_kind?,
+            grand_final_reset: self.grand_final_reset,
+            rounds: self.rounds,
+            pools: usize::try_from(self.pools).unwrap_or_default(),
+            best_of: self.best_of,
+        })
+    }
+
+    fn format_description(&self) -> String {
+        match self.kind {
+            Kind::Qualifier => format!("qualifiers"),
+            Kind::Bracket => match (self.bracket_kind, self.rounds) {
+                (Some(bracket::Kind::Swiss), Some(rounds)) => format!("Swiss, {rounds} rounds"),
+                (Some(bracket::Kind::RoundRobin), _) if self.pools > 1 => format!("round robin, {} pools", self.pools),
+                (Some(bracket_kind), _) => bracket_kind.display_name().to_owned(),
+                (None, _) => format!("bracket"),
+            },
+        }
+    }
+
+    fn advancement_description(&self) -> String {
+        match (self.advance_count, self.advance_per_pool) {
+            (None, _) => format!("everyone"),
+            (Some(count), false) => format!("top {count}"),
+            (Some(count), true) => format!("top {count} of each pool"),
+        }
+    }
+}
+
+/// Picks the entrants advancing from the given ranking of each pool, in seeding order for the next stage: all pool winners first, then all runners-up, and so on.
+fn advancing<T: Copy>(pools: &[Vec<T>], count: Option<usize>, per_pool: bool) -> Vec<T> {
+    let max_pool_len = pools.iter().map(Vec::len).max().unwrap_or_default();
+    let ranks = if per_pool { count.unwrap_or(max_pool_len).min(max_pool_len) } else { max_pool_len };
+    let interleaved = (0..ranks).flat_map(|rank| pools.iter().filter_map(move |pool| pool.get(rank).copied()));
+    if per_pool {
+        interleaved.collect()
+    } else {
+        interleaved.take(count.unwrap_or(usize::MAX)).collect()
+    }
+}
+
+/// Orders the entrants of a score-based qualifier, given in the order of [`teams::signups_sorted`], for seeding.
+///
+/// Entrants who haven't met the qualifier requirements are left out if `qualified_only` is set and seeded last otherwise.
+fn score_qualifier_seeds<T>(qualifier_kind: QualifierKind, ranked: Vec<(T, Qualification)>, qualified_only: bool) -> Vec<T> {
+    let (qualified, unqualified) = ranked.into_iter().partition::<Vec<_>, _>(|(_, qualification)| !teams::is_score_qualifier_dimmed(qualifier_kind, qualification));
+    qualified.into_iter()
+        .chain(if qualified_only { Vec::default() } else { unqualified })
+        .map(|(team, _)| team)
+        .collect()
+}
+
+/// The event's qualifier ranking, best first. Score-based qualifiers are ranked by score, others by the qualifier rank set by organizers.
+///
+/// If `qualified_only` is set, entrants who haven't qualified are left out. Otherwise, they're ranked last.
+async fn qualifier_ranking(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &Data<'_>, qualified_only: bool) -> Result<Vec<Id<Teams>>, event::Error> {
+    let qualifier_kind = event.qualifier_kind(&mut *transaction).await?;
+    if let QualifierKind::Score(_) = qualifier_kind {
+        let all_qualifiers_ended = teams::all_qualifiers_ended(&mut *transaction, http_client, event, qualifier_kind).await?;
+        let signups = teams::signups_sorted(&mut *transaction, &mut teams::Cache::new(http_client.clone()), None, event, false, qualifier_kind, None, all_qualifiers_ended, false).await?;
+        let ranked = signups.into_iter().filter_map(|SignupsTeam { team, qualification, .. }| Some((team?.id, qualification))).collect();
+        let mut seeds = score_qualifier_seeds(qualifier_kind, ranked, qualified_only);
+        if !qualified_only {
+            // entrants without any qualifier results aren't listed
+            for team in Team::for_event(&mut *transaction, event.series, &event.event).await? {
+                if !seeds.contains(&team.id) {
+                    seeds.push(team.id);
+                }
+            }
+        }
+        Ok(seeds)
+    } else {
+        let mut teams = Team::for_event(&mut *transaction, event.series, &event.event).await?;
+        if qualified_only {
+            teams.retain(|team| team.qualifier_rank.is_some());
+        }
+        teams.sort_by_key(|team| (team.qualifier_rank.is_none(), team.qualifier_rank));
+        Ok(teams.into_iter().map(|team| team.id).collect())
+    }
+}
+
+enum Seeding {
+    Ready(Vec<Id<Teams>>),
+    NotReady(String),
+}
+
+/// Determines the seeds for the stage at the given index from the ranking of the stage before it.
+async fn seeding(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, event: &Data<'_>, stages: &[Stage], idx: usize) -> Result<Seeding, event::Error> {
+    let stage = &stages[idx];
+    let Some(settings) = stage.settings() else { return Ok(Seeding::NotReady(format!("This stage has no bracket format."))) };
+    let ranking = if let Some(previous) = idx.checked_sub(1).map(|previous| &stages[previous]) {
+        match previous.kind {
+            Kind::Qualifier => vec![qualifier_ranking(&mut *transaction, http_client, event, true).await?],
+            Kind::Bracket => match StoredBracket::for_stage(&mut *transaction, event.series, &event.event, previous.stage).await? {
+                Some(stored) if stored.is_complete() => stored.ranking(),
+                Some(_) => return Ok(Seeding::NotReady(format!("{} hasn't finished yet.", previous.name))),
+                None => return Ok(Seeding::NotReady(format!("{} hasn't started yet.", previous.name))),
+            },
+        }
+    } else {
+        // first stage, seeded by qualifier results if available
+        vec![qualifier_ranking(&mut *transaction, http_client, event, false).await?]
+    };
+    let seeds = advancing(&ranking, stage.advance_count.and_then(|count| usize::try_from(count).ok()), stage.advance_per_pool);
+    Ok(match settings.validate(seeds.len()) {
+        Ok(()) => Seeding::Ready(seeds),
+        Err(e) => Seeding::NotReady(e.to_string()),
+    })
+}
+
+async fn start<'a>(mut transaction: Transaction<'a, Postgres>, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, event: &Data<'_>, stage: &Stage, seeds: Vec<Id<Teams>>) -> Result<Transaction<'a, Postgres>, event::Error> {
+    let settings = stage.settings().expect("started a stage without bracket settings");
+    let stored = StoredBracket::create(&mut transaction, event, Some(stage.stage), Some(stage.name.clone()), &settings, seeds).await?;
+    stored.create_races(transaction, discord_ctx, http_client, event).await
+}
+
+/// Starts the stage after the given one if it's a bracket stage that hasn't started yet. Called when a bracket stage is completed.
+pub(crate) async fn start_next<'a>(mut transaction: Transaction<'a, Postgres>, discord_ctx: &DiscordCtx, http_client: &reqwest::Client, event: &Data<'_>, completed_stage: i16) -> Result<Transaction<'a, Postgres>, event::Error> {
+    let stages = Stage::for_event(&mut transaction, event.series, &event.event).await?;
+    let Some(idx) = stages.iter().position(|stage| stage.stage > completed_stage) else { return Ok(transaction) };
+    let next = &stages[idx];
+    if next.kind != Kind::Bracket || StoredBracket::for_stage(&mut transaction, event.series, &event.event, next.stage).await?.is_some() { return Ok(transaction) }
+    match seeding(&mut transaction, http_client, event, &stages, idx).await? {
+        Seeding::Ready(seeds) => start(transaction, discord_ctx, http_client, event, next, seeds).await,
+        // an organizer can start the stage manually once this is resolved
+        Seeding::NotReady(_) => Ok(transaction),
+    }
+}
+
+enum StagesFormDefaults<'v> {
+    None,
+    AddContext(Context<'v>),
+    StartContext(i16, Context<'v>),
+    DeleteContext(i16, Context<'v>),
+}
+
+impl<'v> StagesFormDefaults<'v> {
+    fn start_errors(&self, for_stage: i16) -> Vec<&form::Error<'v>> {
+        match self {
+            Self::StartContext(stage, ctx) if *stage == for_stage => ctx.errors().collect(),
+            _ => Vec::default(),
+        }
+    }
+
+    fn delete_errors(&self, for_stage: i16) -> Vec<&form::Error<'v>> {
+        match self {
+            Self::DeleteContext(stage, ctx) if *stage == for_stage => ctx.errors().collect(),
+            _ => Vec::default(),
+        }
+    }
+
+    fn add_errors(&self) -> Vec<&form::Error<'v>> {
+        if let Self::AddContext(ctx) = self {
+            ctx.errors().collect()
+        } else {
+            Vec::default()
+        }
+    }
+
+    fn add_value(&self, name: &str) -> Option<&str> {
+        if let Self::AddContext(ctx) = self {
+            ctx.field_value(name)
+        } else {
+            None
+        }
+    }
+}
+
+async fn stages_page(mut transaction: Transaction<'_, Postgres>, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, defaults: StagesFormDefaults<'_>) -> Result<RawHtml<String>, event::Error> {
+    let header = event.header(&mut transaction, me.as_ref(), Tab::Bracket, true).await?;
+    let is_organizer = if let Some(ref me) = me {
+        me.is_global_admin() || event.organizers(&mut transaction).await?.contains(me)
+    } else {
+        false
+    };
+    let stages = Stage::for_event(&mut transaction, event.series, &event.event).await?;
+    let mut brackets = HashMap::new();
+    for stored in StoredBracket::for_event(&mut transaction, event.series, &event.event).await? {
+        if let Some(stage) = stored.stage {
+            brackets.insert(stage, stored);
+        }
+    }
+    let mut statuses = Vec::with_capacity(stages.len());
+    for (idx, stage) in stages.iter().enumerate() {
+        statuses.push(match stage.kind {
+            Kind::Qualifier => if stages.get(idx + 1).is_some_and(|next| brackets.contains_key(&next.stage)) { "complete" } else { "in progress" },
+            Kind::Bracket => match brackets.get(&stage.stage) {
+                Some(stored) if stored.is_complete() => "complete",
+                Some(_) => "in progress",
+                None => "upcoming",
+            },
+        });
+    }
+    // the next bracket stage that can be started manually
+    let startable = stages.iter().position(|stage| stage.kind == Kind::Bracket && !brackets.contains_key(&stage.stage));
+    let can_edit = is_organizer && !event.is_ended();
+    let mut errors = defaults.add_errors();
+    let content = html! {
+        p : "Each stage is seeded from the ranking of the previous stage. Once a bracket stage is complete, the next stage starts automatically, including its races.";
+        @if stages.is_empty() {
+            p : "This event does not have any stages yet.";
+        } else {
+            table {
+                thead {
+                    tr {
+                        th : "Stage";
+                        th : "Format";
+                        th : "Advancing from previous stage";
+                        th : "Status";
+                        th : "Advanced to next stage";
+                        @if can_edit {
+                            th;
+                        }
+                    }
+                }
+                tbody {
+                    @for (idx, (stage, status)) in stages.iter().zip(&statuses).enumerate() {
+                        tr {
+                            td : &stage.name;
+                            td : stage.format_description();
+                            td {
+                                @if idx == 0 {
+                                    : "—";
+                                } else {
+                                    : stage.advancement_description();
+                                }
+                            }
+                            td : *status;
+                            td {
+                                @if let Some(next) = stages.get(idx + 1) {
+                                    @if let Some(next_bracket) = brackets.get(&next.stage) {
+                                        : next_bracket.seeds.len();
+                                        : " entrants";
+                                    }
+                                }
+                            }
+                            @if can_edit {
+                                td {
+                                    @if startable == Some(idx) {
+                                        @let (errors, button) = button_form(uri!(start_stage(event.series, &*event.event, stage.stage)), csrf, defaults.start_errors(stage.stage), "Start");
+                                        : errors;
+                                        div(class = "button-row") : button;
+                                    }
+                                    @if idx == stages.len() - 1 && !brackets.contains_key(&stage.stage) {
+                                        @let (errors, button) = button_form(uri!(delete(event.series, &*event.event, stage.stage)), csrf, defaults.delete_errors(stage.stage), "Delete");
+                                        : errors;
+                                        div(class = "button-row") : button;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        @for stage in &stages {
+            @if let Some(stored) = brackets.get(&stage.stage) {
+                @if stored.is_complete() {
+                    h2 : format!("Final ranking: {}", stage.name);
+                    @for (pool, ranking) in stored.ranking().into_iter().enumerate() {
+                        @if ranking.len() < stored.seeds.len() {
+                            h3 : format!("Pool {}", swiss::pool_name(pool as i16));
+                        }
+                        ol {
+                            @for team in ranking {
+                                li {
+                                    @if let Some(team) = Team::from_id(&mut transaction, team).await? {
+                                        : team.to_html(&mut transaction, false).await?;
+                                    } else {
+                                        i : "(deleted team)";
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        @if can_edit {
+            h2 : "Add Stage";
+            : full_form(uri!(add(event.series, &*event.event)), csrf, html! {
+                : form_field("name", &mut errors, html! {
+                    label(for = "name") : "Name:";
+                    input(type = "text", name = "name", value? = defaults.add_value("name"));
+                    label(class = "help") : " (also used as the phase of this stage's races)";
+                });
+                : form_field("kind", &mut errors, html! {
+                    label(for = "kind") : "Kind:";
+                    select(name = "kind") {
+                        @if stages.is_empty() {
+                            option(value = "qualifier", selected? = defaults.add_value("kind") == Some("qualifier")) : "Qualifiers";
+                        }
+                        option(value = "bracket", selected? = defaults.add_value("kind") != Some("qualifier")) : "Bracket";
+                    }
+                });
+                : form_field("bracket_kind", &mut errors, html! {
+                    label(for = "bracket_kind") : "Bracket format:";
+                    select(name = "bracket_kind") {
+                        @for (value, label) in [("swiss", "Swiss"), ("round_robin", "Round robin"), ("single_elimination", "Single elimination"), ("double_elimination", "Double elimination")] {
+                            option(value = value, selected? = defaults.add_value("bracket_kind") == Some(value)) : label;
+                        }
+                    }
+                });
+                : form_field("rounds", &mut errors, html! {
+                    label(for = "rounds") : "Rounds:";
+                    input(type = "number", min = "1", name = "rounds", value? = defaults.add_value("rounds"));
+                    label(class = "help") : " (Swiss only)";
+                });
+                : form_field("pools", &mut errors, html! {
+                    label(for = "pools") : "Pools:";
+                    input(type = "number", min = "1", name = "pools", value = defaults.add_value("pools").unwrap_or("1"));
+                    label(class = "help") : " (round robin only)";
+                });
+                : form_field("grand_final_reset", &mut errors, html! {
+                    input(type = "checkbox", id = "grand_final_reset", name = "grand_final_reset", checked? = defaults.add_value("grand_final_reset").map_or(true, |value| value == "on"));
+                    label(for = "grand_final_reset") : "Grand final reset (double elimination only)";
+                });
+                : form_field("best_of", &mut errors, html! {
+                    label(for = "best_of") : "Best of:";
+                    input(type = "number", min = "1", step = "2", name = "best_of", value = defaults.add_value("best_of").map_or_else(|| event.default_game_count.to_string(), |best_of| best_of.to_owned()));
+                });
+                : form_field("advance_count", &mut errors, html! {
+                    label(for = "advance_count") : "Entrants advancing from the previous stage:";
+                    input(type = "number", min = "2", name = "advance_count", value? = defaults.add_value("advance_count"));
+                    label(class = "help") : " (leave empty to advance everyone)";
+                });
+                : form_field("advance_per_pool", &mut errors, html! {
+                    input(type = "checkbox", id = "advance_per_pool", name = "advance_per_pool", checked? = defaults.add_value("advance_per_pool") == Some("on"));
+                    label(for = "advance_per_pool") : "Count applies to each pool of the previous stage";
+                });
+            }, errors, "Add Stage");
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Stages — {}", event.display_name), html! {
+        : header;
+        : content;
+    }).await?)
+}
+
+#[rocket::get("/event/<series>/<event>/stages")]
+pub(crate) async fn get(pool: &State<PgPool>, me: Option<User>, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok(stages_page(transaction, me, uri, csrf.as_ref(), event, StagesFormDefaults::None).await?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct AddForm {
+    #[field(default = String::new())]
+    csrf: String,
+    name: String,
+    kind: Kind,
+    bracket_kind: bracket::Kind,
+    rounds: Option<i16>,
+    #[field(default = 1)]
+    pools: i16,
+    #[field(default = false)]
+    grand_final_reset: bool,
+    best_of: i16,
+    advance_count: Option<i16>,
+    #[field(default = false)]
+    advance_per_pool: bool,
+}
+
+#[rocket::post("/event/<series>/<event>/stages", data = "<form>")]
+pub(crate) async fn add(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, AddForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if event.is_ended() {
+            form.context.push_error(form::Error::validation("This event has ended and can no longer be configured"));
+        }
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
+        }
+        if !matches!(event.match_source(), MatchSource::Manual | MatchSource::Bracket) {
+            form.context.push_error(form::Error::validation("This event's matches are managed on an external site."));
+        }
+        let stages = Stage::for_event(&mut transaction, event.series, &event.event).await?;
+        if stages.is_empty() && !StoredBracket::for_event(&mut transaction, event.series, &event.event).await?.is_empty() {
+            form.context.push_error(form::Error::validation("This event already has a bracket that isn't part of a stage."));
+        }
+        let name = value.name.trim();
+        if name.is_empty() {
+            form.context.push_error(form::Error::validation("This field is required.").with_name("name"));
+        } else if stages.iter().any(|stage| stage.name == name) {
+            form.context.push_error(form::Error::validation("There is already a stage with this name.").with_name("name"));
+        }
+        if value.kind == Kind::Qualifier && !stages.is_empty() {
+            form.context.push_error(form::Error::validation("Only the first stage can be a qualifier stage.").with_name("kind"));
+        }
+        if value.advance_count.is_some_and(|count| count < 2) {
+            form.context.push_error(form::Error::validation("At least 2 entrants must advance.").with_name("advance_count"));
+        }
+        if value.advance_per_pool && !stages.last().is_some_and(|previous| previous.kind == Kind::Bracket) {
+            form.context.push_error(form::Error::validation("Only bracket stages have pools.").with_name("advance_per_pool"));
+        }
+        let settings = bracket::Settings {
+            kind: value.bracket_kind,
+            grand_final_reset: value.grand_final_reset,
+            rounds: value.rounds.filter(|_| value.bracket_kind == bracket::Kind::Swiss),
+            pools: if let bracket::Kind::RoundRobin = value.bracket_kind { usize::try_from(value.pools).unwrap_or_default() } else { 1 },
+            best_of: value.best_of,
+        };
+        if value.kind == Kind::Bracket {
+            // the number of entrants isn't known yet, so only check the settings themselves
+            if let Err(e) = settings.validate(usize::MAX) {
+                form.context.push_error(e.to_form_error());
+            }
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(stages_page(transaction, Some(me), uri, csrf.as_ref(), event, StagesFormDefaults::AddContext(form.context)).await?)
+        } else {
+            let stage = stages.last().map_or(1, |previous| previous.stage + 1);
+            let is_bracket = value.kind == Kind::Bracket;
+            sqlx::query!("INSERT INTO event_stages (series, event, stage, name, kind, bracket_kind, grand_final_reset, rounds, pools, best_of, advance_count, advance_per_pool) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+                event.series as _, &event.event, stage, name, value.kind as _, is_bracket.then_some(settings.kind) as _, settings.grand_final_reset, settings.rounds, settings.pools as i16, settings.best_of, value.advance_count, value.advance_per_pool,
+            ).execute(&mut *transaction).await?;
+            sqlx::query!("UPDATE events SET native_bracket = TRUE WHERE series = $1 AND event = $2", event.series as _, &event.event).execute(&mut *transaction).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        }
+    } else {
+        RedirectOrContent::Content(stages_page(transaction, Some(me), uri, csrf.as_ref(), event, StagesFormDefaults::AddContext(form.context)).await?)
+    })
+}
+
+#[rocket::post("/event/<series>/<event>/stages/<stage>/start", data = "<form>")]
+pub(crate) async fn start_stage(pool: &State<PgPool>, discord_ctx: &State<RwFuture<DiscordCtx>>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, stage: i16, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    let stages = Stage::for_event(&mut transaction, event.series, &event.event).await?;
+    let idx = stages.iter().position(|iter_stage| iter_stage.stage == stage).ok_or(StatusOrError::Status(Status::NotFound))?;
+    Ok(if form.value.is_some() {
+        if event.is_ended() {
+            form.context.push_error(form::Error::validation("This event has ended and can no longer be configured"));
+        }
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
+        }
+        if stages[idx].kind != Kind::Bracket {
+            form.context.push_error(form::Error::validation("Only bracket stages can be started."));
+        } else if StoredBracket::for_stage(&mut transaction, event.series, &event.event, stage).await?.is_some() {
+            form.context.push_error(form::Error::validation("This stage has already started."));
+        }
+        let seeds = match seeding(&mut transaction, http_client, &event, &stages, idx).await? {
+            Seeding::Ready(seeds) => Some(seeds),
+            Seeding::NotReady(reason) => {
+                form.context.push_error(form::Error::validation(reason));
+                None
+            }
+        };
+        if let (Some(seeds), None) = (seeds, form.context.errors().next()) {
+            let transaction = start(transaction, &*discord_ctx.read().await, http_client, &event, &stages[idx], seeds).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(bracket::get(series, &*event.event))))
+        } else {
+            RedirectOrContent::Content(stages_page(transaction, Some(me), uri, csrf.as_ref(), event, StagesFormDefaults::StartContext(stage, form.context)).await?)
+        }
+    } else {
+        RedirectOrContent::Content(stages_page(transaction, Some(me), uri, csrf.as_ref(), event, StagesFormDefaults::StartContext(stage, form.context)).await?)
+    })
+}
+
+#[rocket::post("/event/<series>/<event>/stages/<stage>/delete", data = "<form>")]
+pub(crate) async fn delete(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, stage: i16, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if form.value.is_some() {
+        if event.is_ended() {
+            form.context.push_error(form::Error::validation("This event has ended and can no longer be configured"));
+        }
+        if !event.organizers(&mut transaction).await?.contains(&me) && !me.is_global_admin() {
+            form.context.push_error(form::Error::validation("You must be an organizer to configure this event."));
+        }
+        let stages = Stage::for_event(&mut transaction, event.series, &event.event).await?;
+        if stages.last().is_none_or(|last| last.stage != stage) {
+            form.context.push_error(form::Error::validation("Only the last stage can be deleted."));
+        }
+        if StoredBracket::for_stage(&mut transaction, event.series, &event.event, stage).await?.is_some() {
+            form.context.push_error(form::Error::validation("This stage has already started."));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(stages_page(transaction, Some(me), uri, csrf.as_ref(), event, StagesFormDefaults::DeleteContext(stage, form.context)).await?)
+        } else {
+            sqlx::query!("DELETE FROM event_stages WHERE series = $1 AND event = $2 AND stage = $3", event.series as _, &event.event, stage).execute(&mut *transaction).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, &*event.event))))
+        }
+    } else {
+        RedirectOrContent::Content(stages_page(transaction, Some(me), uri, csrf.as_ref(), event, StagesFormDefaults::DeleteContext(stage, form.context)).await?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        noisy_float::prelude::*,
+        crate::event::qualifier_scoring::QualifierScoring,
+        super::*,
+    };
+
+    #[test]
+    fn top_n_interleaves_pools() {
+        let pools = [vec![1, 4, 5], vec![2, 3, 6]];
+        assert_eq!(advancing(&pools, Some(3), false), [1, 2, 4]);
+        assert_eq!(advancing(&pools, None, false), [1, 2, 4, 3, 5, 6]);
+    }
+
+    #[test]
+    fn top_k_per_pool() {
+        let pools = [vec![1, 4, 5], vec![2, 3]];
+        assert_eq!(advancing(&pools, Some(2), true), [1, 2, 4, 3]);
+        assert_eq!(advancing(&pools, Some(5), true), [1, 2, 4, 3, 5]);
+    }
+
+    fn qualification(num_entered: usize, num_finished: usize, score: f64) -> Qualification {
+        Qualification::Multiple { num_forfeited: num_entered - num_finished, score: r64(score), round_scores: Vec::default(), num_entered, num_finished }
+    }
+
+    #[test]
+    fn score_qualifier_seeds_by_score() {
+        let kind = QualifierKind::Score(QualifierScoring::STANDARD);
+        let ranked = vec![
+            (1, qualification(5, 5, 4000.0)),
+            (2, qualification(4, 3, 3500.0)),
+            (3, qualification(6, 5, 3000.0)),
+            (4, qualification(2, 2, 1500.0)),
+        ];
+        assert_eq!(score_qualifier_seeds(kind, ranked.clone(), true), [1, 3]);
+        assert_eq!(score_qualifier_seeds(kind, ranked, false), [1, 3, 2, 4]);
+    }
+}
@@ -0,0 +1,234 @@
+//! Compact standings tables for Discord, used by the `/standings` command and the optional standings channel.
+
+use crate::{
+    event::{
+        Data,
+        QualifierScoreHiding,
+        bracket::StoredBracket,
+        swiss,
+        teams::{
+            self,
+            MemberUser,
+            Qualification,
+            SignupsTeam,
+        },
+    },
+    prelude::*,
+};
+
+/// Discord messages are limited to 2000 characters, so longer tables are cut off after this many rows.
+const MAX_ROWS: usize = 32;
+const MAX_NAME_LEN: usize = 24;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum Error {
+    #[error(transparent)] Cal(#[from] cal::Error),
+    #[error(transparent)] Data(#[from] event::DataError),
+    #[error(transparent)] Serenity(#[from] serenity::Error),
+    #[error(transparent)] Sql(#[from] sqlx::Error),
+    #[error(transparent)] StartGG(#[from] startgg::Error),
+}
+
+pub(crate) enum Standings {
+    /// This event has neither Swiss or round-robin standings nor a scored qualifier.
+    Unavailable,
+    /// Qualifier standings are hidden until the qualifier stage has ended.
+    Hidden,
+    Table(String),
+}
+
+struct Row {
+    rank: Option<usize>,
+    name: String,
+    result: String,
+}
+
+fn truncate_name(name: &str) -> String {
+    if name.chars().count() > MAX_NAME_LEN {
+        format!("{}…", name.chars().take(MAX_NAME_LEN - 1).collect::<String>())
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Renders rows as a fixed-width table, without the surrounding code block.
+fn format_table(rows: &[Row], result_header: &str) -> String {
+    let rank_width = rows.iter().map(|row| row.rank.map_or(1, |rank| rank.to_string().len())).max().unwrap_or(1).max(1);
+    let name_width = rows.iter().map(|row| row.name.chars().count()).max().unwrap_or(0).max(4);
+    let mut table = format!("{:>rank_width$}  {:<name_width$}  {result_header}\n", "#", "Name");
+    for row in rows.iter().take(MAX_ROWS) {
+        let rank = row.rank.map_or_else(|| format!("—"), |rank| rank.to_string());
+        table.push_str(&format!("{rank:>rank_width$}  {:<name_width$}  {}\n", row.name, row.result));
+    }
+    if rows.len() > MAX_ROWS {
+        table.push_str(&format!("… and {} more\n", rows.len() - MAX_ROWS));
+    }
+    table
+}
+
+fn swiss_rows(standings: &[startgg::SwissStanding]) -> Vec<Row> {
+    standings.iter().map(|standing| Row {
+        rank: Some(standing.placement),
+        name: truncate_name(&standing.name),
+        result: format!("{}–{}", standing.wins, standing.losses),
+    }).collect()
+}
+
+async fn team_name(transaction: &mut Transaction<'_, Postgres>, team: Id<Teams>) -> Result<Option<String>, Error> {
+    let Some(team) = Team::from_id(&mut *transaction, team).await? else { return Ok(None) };
+    if let Some(name) = team.name {
+        return Ok(Some(name))
+    }
+    Ok(team.members(&mut *transaction).await?.first().map(|user| user.display_name().to_owned()))
+}
+
+/// Standings of the latest Swiss or round-robin bracket of an event with native brackets, with one table per pool.
+async fn bracket_standings(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>) -> Result<Standings, Error> {
+    let brackets = StoredBracket::for_event(&mut *transaction, event.series, &event.event).await?;
+    let Some(stored) = brackets.iter().rev().find(|stored| !stored.bracket.kind.is_elimination()) else { return Ok(Standings::Unavailable) };
+    let pools = stored.bracket.pools();
+    let mut tables = String::default();
+    for pool in 0..pools {
+        let mut rows = Vec::default();
+        for (idx, standing) in stored.pool_standings(pool).into_iter().enumerate() {
+            let Some(name) = team_name(&mut *transaction, standing.team).await? else { continue };
+            rows.push(Row {
+                rank: Some(idx + 1),
+                name: truncate_name(&name),
+                result: format!("{}–{}", standing.wins, standing.losses),
+            });
+        }
+        if rows.is_empty() {
+            continue
+        }
+        if pools > 1 {
+            tables.push_str(&format!("Pool {}\n", swiss::pool_name(pool)));
+        }
+        tables.push_str(&format_table(&rows, "W–L"));
+    }
+    Ok(if tables.is_empty() {
+        Standings::Unavailable
+    } else {
+        Standings::Table(tables)
+    })
+}
+
+fn signup_name(team: Option<&Team>, members: &[teams::SignupsMember]) -> Option<String> {
+    if let Some(name) = team.and_then(|team| team.name.as_ref()) {
+        return Some(name.clone())
+    }
+    members.iter().find_map(|member| match &member.user {
+        MemberUser::MidosHouse(user) => Some(user.display_name().to_owned()),
+        MemberUser::RaceTime { name, .. } => Some(name.clone()),
+        MemberUser::Deleted => Some(format!("deleted user")),
+        MemberUser::Newcomer => None,
+    })
+}
+
+/// Builds the public standings for the event, applying the event's qualifier score hiding rules for non-organizers.
+///
+/// Events with native brackets show the standings of their latest Swiss or round-robin bracket.
+/// If `reload` is set, Swiss standings are fetched from start.gg even if cached.
+pub(crate) async fn standings(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, config: &Config, startgg_token: &str, event: &Data<'_>, reload: bool) -> Result<Standings, Error> {
+    if let (true, MatchSource::StartGG(event_slug)) = (event.swiss_standings, event.match_source()) {
+        let standings = if reload {
+            startgg::reload_swiss_standings(http_client, event_slug, startgg_token).await?
+        } else {
+            startgg::swiss_standings(http_client, config, event_slug, startgg_token).await?
+        };
+        return Ok(if standings.is_empty() {
+            Standings::Unavailable
+        } else {
+            Standings::Table(format_table(&swiss_rows(&standings), "W–L"))
+        })
+    }
+    if let MatchSource::Bracket = event.match_source() {
+        // fall back to qualifier standings until the first Swiss or round-robin bracket is created
+        if let standings @ Standings::Table(_) = bracket_standings(&mut *transaction, event).await? {
+            return Ok(standings)
+        }
+    }
+    let qualifier_kind = event.qualifier_kind(&mut *transaction).await?;
+    let QualifierKind::Score(_) = qualifier_kind else { return Ok(Standings::Unavailable) };
+    let all_qualifiers_ended = teams::all_qualifiers_ended(&mut *transaction, http_client, event, qualifier_kind).await?;
+    let hiding = if all_qualifiers_ended { QualifierScoreHiding::None } else { event.qualifier_score_hiding };
+    if let QualifierScoreHiding::FullComplete = hiding {
+        return Ok(Standings::Hidden)
+    }
+    let hide_rank = hiding != QualifierScoreHiding::None;
+    let hide_counts = matches!(hiding, QualifierScoreHiding::FullPointsCounts);
+    let hide_points = matches!(hiding, QualifierScoreHiding::FullPoints | QualifierScoreHiding::FullPointsCounts);
+    let signups = teams::signups_sorted(&mut *transaction, &mut teams::Cache::new(http_client.clone()), None, event, false, qualifier_kind, None, all_qualifiers_ended, false).await?;
+    let mut rows = Vec::with_capacity(signups.len());
+    for (idx, SignupsTeam { team, members, qualification, .. }) in signups.into_iter().enumerate() {
+        let Some(name) = signup_name(team.as_ref(), &members) else { continue };
+        let Qualification::Multiple { num_entered, num_finished, score, .. } = qualification else { continue };
+        rows.push(Row {
+            rank: (!hide_rank).then_some(idx + 1),
+            name: truncate_name(&name),
+            result: match (hide_counts, hide_points) {
+                (true, _) => format!("—"),
+                (false, true) => format!("{num_finished}/{num_entered} finished"),
+                (false, false) => format!("{score:.2} ({num_finished}/{num_entered})"),
+            },
+        });
+    }
+    if hide_rank {
+        rows.sort_by(|row1, row2| row1.name.to_lowercase().cmp(&row2.name.to_lowercase()));
+    }
+    Ok(if rows.is_empty() {
+        Standings::Unavailable
+    } else {
+        Standings::Table(format_table(&rows, if hide_counts { "" } else if hide_points { "Races" } else { "Points (finished/entered)" }))
+    })
+}
+
+pub(crate) fn message(event: &Data<'_>, table: &str) -> String {
+    let mut msg = MessageBuilder::default();
+    msg.push_bold_safe(&event.display_name);
+    msg.push(match event.match_source() {
+        MatchSource::StartGG(_) if event.swiss_standings => " Swiss standings:",
+        MatchSource::Bracket => " standings:",
+        _ => " qualifier standings:",
+    });
+    msg.push_codeblock(table, None);
+    msg.build()
+}
+
+/// Posts updated standings to the event's standings channel, if one is configured.
+pub(crate) async fn post_to_channel(discord_ctx: &DiscordCtx, transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, startgg_token: &str, event: &Data<'_>) -> Result<(), Error> {
+    let Some(channel) = event.discord_standings_channel else { return Ok(()) };
+    let config = discord_ctx.data.read().await.get::<Config>().expect("config missing from Discord context").clone();
+    if let Standings::Table(table) = standings(&mut *transaction, http_client, &config, startgg_token, event, true).await? {
+        channel.say(discord_ctx, message(event, &table)).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_names_are_truncated() {
+        assert_eq!(truncate_name("short"), "short");
+        let truncated = truncate_name("a very long entrant name that does not fit");
+        assert_eq!(truncated.chars().count(), MAX_NAME_LEN);
+        assert!(truncated.ends_with('…'));
+    }
+
+    #[test]
+    fn table_columns_are_aligned() {
+        let rows = [
+            Row { rank: Some(1), name: format!("Alice"), result: format!("3–0") },
+            Row { rank: Some(10), name: format!("Bob"), result: format!("0–3") },
+        ];
+        assert_eq!(format_table(&rows, "W–L"), " #  Name   W–L\n 1  Alice  3–0\n10  Bob    0–3\n");
+    }
+
+    #[test]
+    fn long_tables_are_cut_off() {
+        let rows = (1..=MAX_ROWS + 5).map(|rank| Row { rank: Some(rank), name: format!("entrant {rank}"), result: String::default() }).collect_vec();
+        assert!(format_table(&rows, "").ends_with("… and 5 more\n"));
+    }
+}
@@ -0,0 +1,762 @@
+//! Alternates and roster changes for teams.
+//!
+//! Team members can register alternates and request that one of them substitutes for a member, either in a single race or permanently. Alternates have to
+//! accept being added before they can be requested, and substitutions only take effect once approved by an organizer. Approved race substitutions are applied whenever a team's members are looked up for that race (racetime.gg room
+//! invites, multistreams, restream exports, and result reporting), while approved permanent substitutions replace the member on the team.
+
+use crate::{
+    event::{
+        Data,
+        Tab,
+        entrant,
+    },
+    prelude::*,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "substitution_status", rename_all = "lowercase")]
+pub(crate) enum SubstitutionStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromFormField)]
+pub(crate) enum DecideAction {
+    Approve,
+    Reject,
+}
+
+struct Substitution {
+    id: i64,
+    race: Option<Id<Races>>,
+    member_out: Id<Users>,
+    member_in: Id<Users>,
+    /// `None` if the user has been deleted.
+    requested_by: Option<Id<Users>>,
+    requested_at: DateTime<Utc>,
+    status: SubstitutionStatus,
+    decided_by: Option<Id<Users>>,
+    decided_at: Option<DateTime<Utc>>,
+}
+
+/// A request which conflicts with a new substitution request, see [`request_conflict`].
+#[derive(Debug, PartialEq, Eq)]
+enum Conflict {
+    /// The member to be replaced already has a substitute for the race.
+    MemberOut,
+    /// The alternate already substitutes for another member in the race.
+    MemberIn,
+    /// A permanent substitution for the member is already waiting for a decision.
+    Permanent,
+}
+
+impl Conflict {
+    fn to_form_error(&self) -> form::Error<'static> {
+        match self {
+            Self::MemberOut => form::Error::validation("A substitution for this member in this race has already been requested."),
+            Self::MemberIn => form::Error::validation("This alternate has already been requested to substitute for another member in this race.").with_name("member_in"),
+            Self::Permanent => form::Error::validation("A permanent substitution for this member has already been requested."),
+        }
+    }
+}
+
+/// Checks a new substitution request against the team's existing ones, given as `(race, member_out, member_in, status)`.
+///
+/// Rejected requests don't block new ones, so a member or alternate can't be double-booked for a race while a request is pending or approved.
+fn request_conflict(existing: &[(Option<Id<Races>>, Id<Users>, Id<Users>, SubstitutionStatus)], race: Option<Id<Races>>, member_out: Id<Users>, member_in: Id<Users>) -> Option<Conflict> {
+    if let Some(race) = race {
+        let active = || existing.iter().filter(|&&(existing_race, _, _, status)| existing_race == Some(race) && status != SubstitutionStatus::Rejected);
+        if active().any(|&(_, existing_out, _, _)| existing_out == member_out) {
+            Some(Conflict::MemberOut)
+        } else if active().any(|&(_, _, existing_in, _)| existing_in == member_in) {
+            Some(Conflict::MemberIn)
+        } else {
+            None
+        }
+    } else if existing.iter().any(|&(existing_race, existing_out, _, status)| existing_race.is_none() && existing_out == member_out && status == SubstitutionStatus::Pending) {
+        Some(Conflict::Permanent)
+    } else {
+        None
+    }
+}
+
+/// Why a pending substitution can't be approved, apart from the event's requirements for team members.
+///
+/// `member_in_has_team` is whether the alternate has joined another team in this event, which only matters for permanent substitutions.
+fn approval_errors(members: &[Id<Users>], member_out: Id<Users>, permanent: bool, member_in_has_team: bool) -> Vec<&'static str> {
+    let mut errors = Vec::default();
+    if !members.contains(&member_out) {
+        errors.push("The member to be replaced is no longer on the team.");
+    }
+    if permanent && member_in_has_team {
+        errors.push("The alternate has since signed up for this event with a team.");
+    }
+    errors
+}
+
+fn decided_status(action: DecideAction) -> SubstitutionStatus {
+    match action {
+        DecideAction::Approve => SubstitutionStatus::Approved,
+        DecideAction::Reject => SubstitutionStatus::Rejected,
+    }
+}
+
+pub(crate) enum PageContext<'v> {
+    None,
+    AddAlternate(Context<'v>),
+    AcceptAlternate(Context<'v>),
+    RemoveAlternate(Context<'v>),
+    Request(Context<'v>),
+    Decide(Context<'v>),
+}
+
+impl<'v> PageContext<'v> {
+    fn take_add_alternate(&mut self) -> Context<'v> {
+        match mem::replace(self, Self::None) {
+            Self::AddAlternate(ctx) => ctx,
+            old_val => {
+                *self = old_val;
+                Context::default()
+            }
+        }
+    }
+
+    fn take_accept_alternate(&mut self) -> Context<'v> {
+        match mem::replace(self, Self::None) {
+            Self::AcceptAlternate(ctx) => ctx,
+            old_val => {
+                *self = old_val;
+                Context::default()
+            }
+        }
+    }
+
+    fn take_remove_alternate(&mut self) -> Context<'v> {
+        match mem::replace(self, Self::None) {
+            Self::RemoveAlternate(ctx) => ctx,
+            old_val => {
+                *self = old_val;
+                Context::default()
+            }
+        }
+    }
+
+    fn take_request(&mut self) -> Context<'v> {
+        match mem::replace(self, Self::None) {
+            Self::Request(ctx) => ctx,
+            old_val => {
+                *self = old_val;
+                Context::default()
+            }
+        }
+    }
+
+    fn take_decide(&mut self) -> Context<'v> {
+        match mem::replace(self, Self::None) {
+            Self::Decide(ctx) => ctx,
+            old_val => {
+                *self = old_val;
+                Context::default()
+            }
+        }
+    }
+}
+
+async fn alternate_ids(transaction: &mut Transaction<'_, Postgres>, team: Id<Teams>) -> sqlx::Result<Vec<Id<Users>>> {
+    sqlx::query_scalar!(r#"SELECT member AS "member: Id<Users>" FROM team_alternates WHERE team = $1 ORDER BY added_at"#, team as _).fetch_all(&mut **transaction).await
+}
+
+/// Alternates who have accepted being added and can therefore be requested as substitutes.
+async fn confirmed_alternate_ids(transaction: &mut Transaction<'_, Postgres>, team: Id<Teams>) -> sqlx::Result<Vec<Id<Users>>> {
+    sqlx::query_scalar!(r#"SELECT member AS "member: Id<Users>" FROM team_alternates WHERE team = $1 AND confirmed ORDER BY added_at"#, team as _).fetch_all(&mut **transaction).await
+}
+
+/// Checks the requirements the enter flow has for team members, since an alternate can end up racing for or joining the team.
+async fn requirement_error(http_client: &reqwest::Client, discord_ctx: &DiscordCtx, data: &Data<'_>, team: &Team, alternate: &User) -> Result<Option<&'static str>, event::Error> {
+    if let Some(ref racetime_slug) = team.racetime_slug {
+        match alternate.racetime_user_data(http_client).await? {
+            Some(Some(user)) => if !user.teams.iter().any(|team| team.slug == *racetime_slug) {
+                return Ok(Some("A member of the team's racetime.gg team is required."))
+            },
+            Some(None) => return Ok(Some("A public racetime.gg profile is required. Connect a Twitch or Patreon account to the racetime.gg account or participate in a recorded race.")),
+            None => return Ok(Some("A racetime.gg account is required. Go to the profile and select 'Connect a racetime.gg account'.")),
+        }
+    } else if alternate.racetime.is_none() {
+        return Ok(Some("A racetime.gg account is required. Go to the profile and select 'Connect a racetime.gg account'."))
+    }
+    if let Some(discord_guild) = data.discord_guild {
+        if let Some(ref discord) = alternate.discord {
+            if discord_guild.member(discord_ctx, discord.id).await.is_err() {
+                return Ok(Some("Joining the tournament's Discord server is required."))
+            }
+        } else {
+            return Ok(Some("A Discord account is required. Go to the profile and select 'Connect a Discord account'."))
+        }
+    }
+    Ok(None)
+}
+
+/// Races of this team which haven't ended yet and can therefore have a substitute.
+async fn upcoming_races(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, data: &Data<'_>, team: &Team) -> Result<Vec<Race>, event::Error> {
+    let mut races = Race::for_event(transaction, http_client, data).await?;
+    races.retain(|race| !race.ignored && !race.is_ended() && race.teams().any(|race_team| race_team.id == team.id));
+    races.sort_by_key(|race| (entrant::race_start(race).is_none(), entrant::race_start(race)));
+    Ok(races)
+}
+
+/// Organizers can manage every team, team members only their own.
+async fn access(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>, team: &Team, me: &User) -> Result<(bool, bool), event::Error> {
+    let is_organizer = me.is_global_admin() || data.organizers(&mut *transaction).await?.contains(me);
+    let is_member = team.member_ids(&mut *transaction).await?.contains(&me.id);
+    Ok((is_organizer, is_member))
+}
+
+async fn team_for_event(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>, team: Id<Teams>) -> Result<Team, StatusOrError<event::Error>> {
+    let team = Team::from_id(&mut *transaction, team).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    if team.series != data.series || team.event != data.event { return Err(StatusOrError::Status(Status::NotFound)) }
+    Ok(team)
+}
+
+async fn substitutes_page(mut transaction: Transaction<'_, Postgres>, http_client: &reqwest::Client, me: Option<User>, uri: Origin<'_>, csrf: Option<&CsrfToken>, data: Data<'_>, team: Team, mut ctx: PageContext<'_>) -> Result<RawHtml<String>, event::Error> {
+    let header = data.header(&mut transaction, me.as_ref(), Tab::Teams, true).await?;
+    let (is_organizer, is_member) = if let Some(ref me) = me { access(&mut transaction, &data, &team, me).await? } else { (false, false) };
+    let mut alternates = Vec::default();
+    for row in sqlx::query!(r#"SELECT member AS "member: Id<Users>", confirmed FROM team_alternates WHERE team = $1 ORDER BY added_at"#, team.id as _).fetch_all(&mut *transaction).await? {
+        alternates.push((User::from_id(&mut *transaction, row.member).await?.expect("database constraint violated: nonexistent team alternate"), row.confirmed));
+    }
+    let is_alternate = me.as_ref().is_some_and(|me| alternates.iter().any(|(alternate, _)| alternate == me));
+    let content = if is_organizer || is_member || is_alternate {
+        let members = team.members(&mut transaction).await?;
+        let races = upcoming_races(&mut transaction, http_client, &data, &team).await?;
+        let substitutions = sqlx::query_as!(Substitution, r#"SELECT
+            id,
+            race AS "race: Id<Races>",
+            member_out AS "member_out: Id<Users>",
+            member_in AS "member_in: Id<Users>",
+            requested_by AS "requested_by: Id<Users>",
+            requested_at,
+            status AS "status: SubstitutionStatus",
+            decided_by AS "decided_by: Id<Users>",
+            decided_at
+        FROM team_substitutions WHERE team = $1 ORDER BY status = 'pending' DESC, requested_at DESC"#, team.id as _).fetch_all(&mut *transaction).await?;
+        let add_alternate_ctx = ctx.take_add_alternate();
+        let accept_alternate_ctx = ctx.take_accept_alternate();
+        let remove_alternate_ctx = ctx.take_remove_alternate();
+        let request_ctx = ctx.take_request();
+        let decide_ctx = ctx.take_decide();
+        let decide_errors = decide_ctx.errors().collect_vec();
+        html! {
+            h3 : "Alternates";
+            p : "Alternates can substitute for a member of the team, either in a single race or permanently. They have to accept being added, and substitutions have to be approved by an organizer.";
+            @if alternates.is_empty() {
+                p : "This team has no alternates.";
+            } else {
+                @for error in accept_alternate_ctx.errors().chain(remove_alternate_ctx.errors()) {
+                    p(class = "error") : error;
+                }
+                ul {
+                    @for (alternate, confirmed) in &alternates {
+                        li {
+                            : alternate;
+                            @if !confirmed {
+                                : " (not yet accepted)";
+                            }
+                            @if !confirmed && me.as_ref().is_some_and(|me| me == alternate) {
+                                @let (errors, button) = button_form(uri!(accept_alternate(data.series, &*data.event, team.id, alternate.id)), csrf, Vec::default(), "Accept");
+                                : errors;
+                                : button;
+                            }
+                            @if is_organizer || is_member || me.as_ref().is_some_and(|me| me == alternate) {
+                                @let (errors, button) = button_form(uri!(remove_alternate(data.series, &*data.event, team.id, alternate.id)), csrf, Vec::default(), if *confirmed || is_organizer || is_member { "Remove" } else { "Decline" });
+                                : errors;
+                                : button;
+                            }
+                        }
+                    }
+                }
+            }
+            @if is_organizer || is_member {
+                @let mut errors = add_alternate_ctx.errors().collect_vec();
+                : full_form(uri!(add_alternate(data.series, &*data.event, team.id)), csrf, html! {
+                    : form_field("alternate", &mut errors, html! {
+                        label(for = "alternate") : "Alternate:";
+                        input(type = "text", name = "alternate");
+                        label(class = "help") : "(Enter the alternate's Hyrule Town Hall user ID. It can be found on their profile page. They will be asked to accept.)";
+                    });
+                }, errors, "Add alternate");
+            }
+            h3 : "Substitutions";
+            @if (is_organizer || is_member) && alternates.iter().any(|(_, confirmed)| *confirmed) {
+                @let mut errors = request_ctx.errors().collect_vec();
+                : full_form(uri!(request(data.series, &*data.event, team.id)), csrf, html! {
+                    : form_field("member_out", &mut errors, html! {
+                        label(for = "member_out") : "Replace:";
+                        select(name = "member_out") {
+                            @for member in &members {
+                                option(value = member.id.to_string()) : member.display_name();
+                            }
+                        }
+                    });
+                    : form_field("member_in", &mut errors, html! {
+                        label(for = "member_in") : "With:";
+                        select(name = "member_in") {
+                            @for (alternate, _) in alternates.iter().filter(|(_, confirmed)| *confirmed) {
+                                option(value = alternate.id.to_string()) : alternate.display_name();
+                            }
+                        }
+                    });
+                    : form_field("race", &mut errors, html! {
+                        label(for = "race") : "For:";
+                        select(name = "race") {
+                            @for race in &races {
+                                option(value = race.id.to_string()) {
+                                    : entrant::race_title(race);
+                                    @if let Some(start) = entrant::race_start(race) {
+                                        : " (";
+                                        : format_datetime(start, DateTimeFormat { long: false, running_text: false });
+                                        : ")";
+                                    }
+                                }
+                            }
+                            option(value = "") : "Permanently (replaces the member on the team)";
+                        }
+                    });
+                }, errors, "Request substitution");
+            }
+            @if substitutions.is_empty() {
+                p : "No substitutions have been requested for this team.";
+            } else {
+                table {
+                    thead {
+                        tr {
+                            th : "Race";
+                            th : "Out";
+                            th : "In";
+                            th : "Requested";
+                            th : "Status";
+                        }
+                    }
+                    tbody {
+                        @for substitution in substitutions {
+                            tr {
+                                td {
+                                    @if let Some(race) = substitution.race {
+                                        @let race = Race::from_id(&mut transaction, http_client, race).await?;
+                                        : entrant::race_title(&race);
+                                    } else {
+                                        : "permanent";
+                                    }
+                                }
+                                td : User::from_id(&mut *transaction, substitution.member_out).await?.expect("database constraint violated: nonexistent user");
+                                td : User::from_id(&mut *transaction, substitution.member_in).await?.expect("database constraint violated: nonexistent user");
+                                td {
+                                    @if let Some(requested_by) = substitution.requested_by {
+                                        : User::from_id(&mut *transaction, requested_by).await?.expect("database constraint violated: nonexistent user");
+                                        : " ";
+                                    }
+                                    : format_datetime(substitution.requested_at, DateTimeFormat { long: false, running_text: true });
+                                }
+                                td {
+                                    @match substitution.status {
+                                        SubstitutionStatus::Pending => {
+                                            @if is_organizer {
+                                                @let errors = if decide_ctx.field_value("substitution") == Some(&*substitution.id.to_string()) { decide_errors.clone() } else { Vec::default() };
+                                                : full_form(uri!(decide(data.series, &*data.event, team.id)), csrf, html! {
+                                                    input(type = "hidden", name = "substitution", value = substitution.id.to_string());
+                                                    select(name = "action") {
+                                                        option(value = "approve") : "Approve";
+                                                        option(value = "reject") : "Reject";
+                                                    }
+                                                }, errors, "Save");
+                                            } else {
+                                                : "pending approval";
+                                            }
+                                        }
+                                        SubstitutionStatus::Approved => : "approved";
+                                        SubstitutionStatus::Rejected => : "rejected";
+                                    }
+                                    @if let (Some(decided_by), Some(decided_at)) = (substitution.decided_by, substitution.decided_at) {
+                                        : " by ";
+                                        : User::from_id(&mut *transaction, decided_by).await?.expect("database constraint violated: nonexistent user");
+                                        : " ";
+                                        : format_datetime(decided_at, DateTimeFormat { long: false, running_text: true });
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        html! {
+            article {
+                p : "This page is only available for team members, their alternates, and event organizers.";
+            }
+        }
+    };
+    Ok(page(transaction, &me, &uri, PageStyle { chests: data.chests().await?, ..PageStyle::default() }, &format!("Substitutes — {}", data.display_name), html! {
+        : header;
+        h2 {
+            : "Substitutes for ";
+            : team.to_html(&mut transaction, false).await?;
+        }
+        : content;
+        p {
+            a(href = uri!(entrant::get(data.series, &*data.event, team.id)).to_string()) : "Back to entrant page";
+        }
+    }).await?)
+}
+
+#[rocket::get("/event/<series>/<event>/teams/<team>/substitutes")]
+pub(crate) async fn get(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: Option<User>, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, team: Id<Teams>) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = team_for_event(&mut transaction, &data, team).await?;
+    Ok(substitutes_page(transaction, http_client, me, uri, csrf.as_ref(), data, team, PageContext::None).await?)
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct AlternateForm {
+    #[field(default = String::new())]
+    csrf: String,
+    alternate: String,
+}
+
+#[rocket::post("/event/<series>/<event>/teams/<team>/substitutes/alternates", data = "<form>")]
+pub(crate) async fn add_alternate(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, team: Id<Teams>, form: Form<Contextual<'_, AlternateForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = team_for_event(&mut transaction, &data, team).await?;
+    let (is_organizer, is_member) = access(&mut transaction, &data, &team, &me).await?;
+    if !is_organizer && !is_member {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        let alternate = if let Ok(alternate) = value.alternate.trim().parse::<Id<Users>>() {
+            User::from_id(&mut *transaction, alternate).await?
+        } else {
+            None
+        };
+        if let Some(ref alternate) = alternate {
+            if team.member_ids(&mut transaction).await?.contains(&alternate.id) {
+                form.context.push_error(form::Error::validation("This user is already a member of the team.").with_name("alternate"));
+            } else if alternate_ids(&mut transaction, team.id).await?.contains(&alternate.id) {
+                form.context.push_error(form::Error::validation("This user is already an alternate for the team.").with_name("alternate"));
+            } else if Team::from_event_and_member(&mut transaction, data.series, &data.event, alternate.id).await?.is_some() {
+                form.context.push_error(form::Error::validation("This user is already signed up for this event with another team.").with_name("alternate"));
+            }
+        } else {
+            form.context.push_error(form::Error::validation("There is no user with this ID.").with_name("alternate"));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::AddAlternate(form.context)).await?)
+        } else {
+            let alternate = alternate.expect("validated");
+            sqlx::query!("INSERT INTO team_alternates (team, member) VALUES ($1, $2)", team.id as _, alternate.id as _).execute(&mut *transaction).await?;
+            let team_name = team.name(&mut transaction).await?.map_or_else(|| format!("a team"), Cow::into_owned);
+            transaction.commit().await?;
+            if let Some(ref discord) = alternate.discord {
+                let discord_ctx = discord_ctx.read().await;
+                let mut msg = MessageBuilder::default();
+                msg.mention_user(&me);
+                msg.push(" added you as an alternate for ");
+                msg.push_safe(&team_name);
+                msg.push(" in ");
+                msg.push_safe(&data.display_name);
+                msg.push(". You can accept or decline at <");
+                msg.push(uri!(base_uri(), get(series, event, team.id)).to_string());
+                msg.push(">.");
+                if let Ok(dm) = UserId::new(discord.id.get()).create_dm_channel(&*discord_ctx).await {
+                    let _ = dm.say(&*discord_ctx, msg.build()).await;
+                }
+            }
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event, team.id))))
+        }
+    } else {
+        RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::AddAlternate(form.context)).await?)
+    })
+}
+
+#[rocket::post("/event/<series>/<event>/teams/<team>/substitutes/alternates/<alternate>/accept", data = "<form>")]
+pub(crate) async fn accept_alternate(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, team: Id<Teams>, alternate: Id<Users>, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = team_for_event(&mut transaction, &data, team).await?;
+    if alternate != me.id {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    if form.value.is_some() {
+        if data.is_ended() {
+            form.context.push_error(form::Error::validation("This event has ended."));
+        }
+        if !sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM team_alternates WHERE team = $1 AND member = $2 AND NOT confirmed) AS "exists!""#, team.id as _, me.id as _).fetch_one(&mut *transaction).await? {
+            form.context.push_error(form::Error::validation("You haven't been added as an alternate for this team or have already accepted."));
+        } else if Team::from_event_and_member(&mut transaction, data.series, &data.event, me.id).await?.is_some() {
+            form.context.push_error(form::Error::validation("You are already signed up for this event with a team."));
+        } else if let Some(error) = requirement_error(http_client, &*discord_ctx.read().await, &data, &team, &me).await? {
+            form.context.push_error(form::Error::validation(error));
+        }
+    }
+    Ok(if form.value.is_some() && form.context.errors().next().is_none() {
+        sqlx::query!("UPDATE team_alternates SET confirmed = TRUE WHERE team = $1 AND member = $2", team.id as _, me.id as _).execute(&mut *transaction).await?;
+        transaction.commit().await?;
+        RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event, team.id))))
+    } else {
+        RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::AcceptAlternate(form.context)).await?)
+    })
+}
+
+#[rocket::post("/event/<series>/<event>/teams/<team>/substitutes/alternates/<alternate>/remove", data = "<form>")]
+pub(crate) async fn remove_alternate(pool: &State<PgPool>, http_client: &State<reqwest::Client>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, team: Id<Teams>, alternate: Id<Users>, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = team_for_event(&mut transaction, &data, team).await?;
+    let (is_organizer, is_member) = access(&mut transaction, &data, &team, &me).await?;
+    // alternates can remove themselves, e.g. to decline being added
+    if !is_organizer && !is_member && alternate != me.id {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    if form.value.is_some() {
+        if sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM team_substitutions WHERE team = $1 AND member_in = $2 AND status = 'pending') AS "exists!""#, team.id as _, alternate as _).fetch_one(&mut *transaction).await? {
+            form.context.push_error(form::Error::validation("This alternate has a pending substitution request. Please wait until it has been decided before removing them."));
+        }
+    }
+    Ok(if form.value.is_some() && form.context.errors().next().is_none() {
+        sqlx::query!("DELETE FROM team_alternates WHERE team = $1 AND member = $2", team.id as _, alternate as _).execute(&mut *transaction).await?;
+        transaction.commit().await?;
+        RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event, team.id))))
+    } else {
+        RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::RemoveAlternate(form.context)).await?)
+    })
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct RequestForm {
+    #[field(default = String::new())]
+    csrf: String,
+    member_out: Id<Users>,
+    member_in: Id<Users>,
+    /// `None` for a permanent substitution.
+    race: Option<Id<Races>>,
+}
+
+#[rocket::post("/event/<series>/<event>/teams/<team>/substitutes/request", data = "<form>")]
+pub(crate) async fn request(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, team: Id<Teams>, form: Form<Contextual<'_, RequestForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = team_for_event(&mut transaction, &data, team).await?;
+    let (is_organizer, is_member) = access(&mut transaction, &data, &team, &me).await?;
+    if !is_organizer && !is_member {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if data.is_ended() {
+            form.context.push_error(form::Error::validation("This event has ended."));
+        }
+        if !team.member_ids(&mut transaction).await?.contains(&value.member_out) {
+            form.context.push_error(form::Error::validation("This user is not a member of the team.").with_name("member_out"));
+        }
+        if !confirmed_alternate_ids(&mut transaction, team.id).await?.contains(&value.member_in) {
+            form.context.push_error(form::Error::validation("This user is not an alternate for the team or hasn't accepted yet.").with_name("member_in"));
+        }
+        let race_is_upcoming = if let Some(race) = value.race {
+            upcoming_races(&mut transaction, http_client, &data, &team).await?.iter().any(|upcoming| upcoming.id == race)
+        } else {
+            true
+        };
+        if !race_is_upcoming {
+            form.context.push_error(form::Error::validation("This race is not an upcoming race of the team.").with_name("race"));
+        } else {
+            let existing = sqlx::query!(r#"SELECT race AS "race: Id<Races>", member_out AS "member_out: Id<Users>", member_in AS "member_in: Id<Users>", status AS "status: SubstitutionStatus" FROM team_substitutions WHERE team = $1"#, team.id as _).fetch_all(&mut *transaction).await?
+                .into_iter()
+                .map(|row| (row.race, row.member_out, row.member_in, row.status))
+                .collect_vec();
+            if let Some(conflict) = request_conflict(&existing, value.race, value.member_out, value.member_in) {
+                form.context.push_error(conflict.to_form_error());
+            }
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::Request(form.context)).await?)
+        } else {
+            sqlx::query!("INSERT INTO team_substitutions (team, race, member_out, member_in, requested_by) VALUES ($1, $2, $3, $4, $5)", team.id as _, value.race as _, value.member_out as _, value.member_in as _, me.id as _).execute(&mut *transaction).await?;
+            let organizer_msg = if let Some(organizer_channel) = data.discord_organizer_channel {
+                let member_out = User::from_id(&mut *transaction, value.member_out).await?.expect("validated");
+                let member_in = User::from_id(&mut *transaction, value.member_in).await?.expect("validated");
+                let mut msg = MessageBuilder::default();
+                msg.mention_user(&me);
+                msg.push(" requested that ");
+                msg.mention_user(&member_in);
+                msg.push(" substitutes for ");
+                msg.mention_user(&member_out);
+                msg.push(" on ");
+                msg.mention_team(&mut transaction, data.discord_guild, &team).await?;
+                if value.race.is_some() {
+                    msg.push(" for a race");
+                } else {
+                    msg.push(" permanently");
+                }
+                msg.push(". Please approve or reject the request at <");
+                msg.push(uri!(base_uri(), get(series, event, team.id)).to_string());
+                msg.push(">.");
+                Some((organizer_channel, msg.build()))
+            } else {
+                None
+            };
+            transaction.commit().await?;
+            if let Some((organizer_channel, msg)) = organizer_msg {
+                // the request has already been saved, so failing to notify the organizers shouldn't fail the request
+                if let Err(e) = organizer_channel.say(&*discord_ctx.read().await, msg).await {
+                    eprintln!("failed to notify organizers of substitution request: {e}");
+                }
+            }
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event, team.id))))
+        }
+    } else {
+        RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::Request(form.context)).await?)
+    })
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct DecideForm {
+    #[field(default = String::new())]
+    csrf: String,
+    substitution: i64,
+    action: DecideAction,
+}
+
+#[rocket::post("/event/<series>/<event>/teams/<team>/substitutes/decide", data = "<form>")]
+pub(crate) async fn decide(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, team: Id<Teams>, form: Form<Contextual<'_, DecideForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = team_for_event(&mut transaction, &data, team).await?;
+    let (is_organizer, _) = access(&mut transaction, &data, &team, &me).await?;
+    if !is_organizer {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        let substitution = sqlx::query!(r#"SELECT
+            race AS "race: Id<Races>",
+            member_out AS "member_out: Id<Users>",
+            member_in AS "member_in: Id<Users>",
+            requested_by AS "requested_by: Id<Users>"
+        FROM team_substitutions WHERE id = $1 AND team = $2 AND status = 'pending' FOR UPDATE"#, value.substitution, team.id as _).fetch_optional(&mut *transaction).await?;
+        if let Some(ref substitution) = substitution {
+            if value.action == DecideAction::Approve {
+                let members = team.member_ids(&mut transaction).await?;
+                let permanent = substitution.race.is_none();
+                let member_in_has_team = permanent && Team::from_event_and_member(&mut transaction, data.series, &data.event, substitution.member_in).await?.is_some();
+                for error in approval_errors(&members, substitution.member_out, permanent, member_in_has_team) {
+                    form.context.push_error(form::Error::validation(error));
+                }
+                let member_in = User::from_id(&mut *transaction, substitution.member_in).await?.expect("database constraint violated: nonexistent substitute");
+                if let Some(error) = requirement_error(http_client, &*discord_ctx.read().await, &data, &team, &member_in).await? {
+                    form.context.push_error(form::Error::validation(format!("The alternate no longer meets the requirements for team members: {error}")));
+                }
+            }
+        } else {
+            form.context.push_error(form::Error::validation("This substitution request doesn't exist or has already been decided."));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::Decide(form.context)).await?)
+        } else {
+            let substitution = substitution.expect("validated");
+            let approve = value.action == DecideAction::Approve;
+            sqlx::query!("UPDATE team_substitutions SET status = $1, decided_by = $2, decided_at = NOW() WHERE id = $3", decided_status(value.action) as _, me.id as _, value.substitution).execute(&mut *transaction).await?;
+            if approve && substitution.race.is_none() {
+                sqlx::query!("UPDATE team_members SET member = $1, status = 'confirmed' WHERE team = $2 AND member = $3", substitution.member_in as _, team.id as _, substitution.member_out as _).execute(&mut *transaction).await?;
+                sqlx::query!("DELETE FROM team_alternates WHERE team = $1 AND member = $2", team.id as _, substitution.member_in as _).execute(&mut *transaction).await?;
+            }
+            let requested_by = if let Some(requested_by) = substitution.requested_by {
+                User::from_id(&mut *transaction, requested_by).await?
+            } else {
+                None
+            };
+            let member_in = User::from_id(&mut *transaction, substitution.member_in).await?.expect("database constraint violated: nonexistent substitute");
+            let team_name = team.name(&mut transaction).await?.map_or_else(|| format!("their team"), Cow::into_owned);
+            transaction.commit().await?;
+            let discord_ctx = discord_ctx.read().await;
+            for rcpt in requested_by.iter().chain([&member_in]) {
+                if let Some(ref discord) = rcpt.discord {
+                    let mut msg = MessageBuilder::default();
+                    msg.push("The request for ");
+                    msg.mention_user(&member_in);
+                    msg.push(if substitution.race.is_some() { " to substitute in a race of " } else { " to permanently join " });
+                    msg.push_safe(&team_name);
+                    msg.push(" in ");
+                    msg.push_safe(&data.display_name);
+                    msg.push(if approve { " has been approved." } else { " has been rejected by the organizers." });
+                    if let Ok(dm) = UserId::new(discord.id.get()).create_dm_channel(&*discord_ctx).await {
+                        let _ = dm.say(&*discord_ctx, msg.build()).await;
+                    }
+                }
+            }
+            RedirectOrContent::Redirect(Redirect::to(uri!(get(series, event, team.id))))
+        }
+    } else {
+        RedirectOrContent::Content(substitutes_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, team, PageContext::Decide(form.context)).await?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn race_requests_cant_double_book() {
+        let [alice, bob, carol, dave] = [1_u64, 2, 3, 4].map(Id::<Users>::from);
+        let [race1, race2] = [1_u64, 2].map(Id::<Races>::from);
+        let existing = [(Some(race1), alice, carol, SubstitutionStatus::Pending)];
+        assert_eq!(request_conflict(&existing, Some(race1), alice, dave), Some(Conflict::MemberOut));
+        assert_eq!(request_conflict(&existing, Some(race1), bob, carol), Some(Conflict::MemberIn));
+        assert_eq!(request_conflict(&existing, Some(race1), bob, dave), None);
+        assert_eq!(request_conflict(&existing, Some(race2), alice, carol), None);
+        let existing = [(Some(race1), alice, carol, SubstitutionStatus::Approved)];
+        assert_eq!(request_conflict(&existing, Some(race1), bob, carol), Some(Conflict::MemberIn));
+    }
+
+    #[test]
+    fn declined_requests_dont_conflict() {
+        let [alice, bob, carol] = [1_u64, 2, 3].map(Id::<Users>::from);
+        let race = Id::<Races>::from(1_u64);
+        let existing = [
+            (Some(race), alice, carol, SubstitutionStatus::Rejected),
+            (None, bob, carol, SubstitutionStatus::Rejected),
+        ];
+        assert_eq!(request_conflict(&existing, Some(race), alice, carol), None);
+        assert_eq!(request_conflict(&existing, None, bob, carol), None);
+        assert_eq!(decided_status(DecideAction::Reject), SubstitutionStatus::Rejected);
+    }
+
+    #[test]
+    fn permanent_requests() {
+        let [alice, bob, carol] = [1_u64, 2, 3].map(Id::<Users>::from);
+        let race = Id::<Races>::from(1_u64);
+        assert_eq!(request_conflict(&[(None, alice, carol, SubstitutionStatus::Pending)], None, alice, bob), Some(Conflict::Permanent));
+        // an approved permanent substitution has already replaced the member
+        assert_eq!(request_conflict(&[(None, alice, carol, SubstitutionStatus::Approved)], None, alice, bob), None);
+        // race substitutions don't block permanent ones
+        assert_eq!(request_conflict(&[(Some(race), alice, carol, SubstitutionStatus::Pending)], None, alice, carol), None);
+    }
+
+    #[test]
+    fn approval() {
+        let [alice, bob, carol] = [1_u64, 2, 3].map(Id::<Users>::from);
+        assert_eq!(decided_status(DecideAction::Approve), SubstitutionStatus::Approved);
+        assert!(approval_errors(&[alice, bob], alice, false, true).is_empty());
+        assert!(approval_errors(&[alice, bob], alice, true, false).is_empty());
+        assert_eq!(approval_errors(&[alice, bob], carol, false, false), ["The member to be replaced is no longer on the team."]);
+        assert_eq!(approval_errors(&[alice, bob], alice, true, true), ["The alternate has since signed up for this event with a team."]);
+    }
+}
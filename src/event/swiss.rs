@@ -0,0 +1,305 @@
+//! Pairings and standings for Swiss and round-robin brackets.
+//!
+//! These are stored like elimination brackets (see [`super::bracket`]), with all of their matches on the pool side.
+//! A Swiss bracket only has its current round paired, the next round is paired by [`next_round`] once all of its matches are decided.
+
+use {
+    std::hash::Hash,
+    crate::{
+        event::bracket::{
+            Bracket,
+            Kind,
+            Match,
+            Side,
+            Slot,
+        },
+        prelude::*,
+    },
+};
+
+/// Opponents' win percentages are counted as at least this much, so losing to an entrant who lost all their other matches isn't weighted too heavily.
+const MIN_OPPONENT_WIN_PERCENTAGE: f64 = 1.0 / 3.0;
+/// Limits the search for a pairing without rematches, which can take exponential time if there is none.
+const PAIRING_SEARCH_BUDGET: usize = 100_000;
+
+fn new_match<T>(pool: i16, round: i16, position: i16, best_of: i16, team1: T, team2: Option<T>) -> Match<T> {
+    let is_bye = team2.is_none();
+    Match {
+        side: Side::Pool,
+        slots: [Slot::Entrant(team1), team2.map_or(Slot::Bye, Slot::Entrant)],
+        // a bye counts as a win
+        winner_slot: is_bye.then_some(0),
+        skipped: false,
+        pool, round, position, best_of,
+    }
+}
+
+pub(crate) fn pool_name(pool: i16) -> String {
+    char::from(b'A' + pool as u8).to_string()
+}
+
+pub(crate) struct Standing<T> {
+    pub(crate) team: T,
+    pub(crate) wins: u16,
+    pub(crate) losses: u16,
+    /// Total wins of all opponents played.
+    pub(crate) buchholz: u16,
+    pub(crate) opponent_win_percentage: f64,
+}
+
+/// Ranks the given entrants by wins, then Buchholz score, then opponents' win percentage, then seed.
+pub(crate) fn standings<T: Copy + Eq + Hash>(seeds: &[T], matches: &[Match<T>]) -> Vec<Standing<T>> {
+    let mut wins = HashMap::<T, u16>::default();
+    let mut byes = HashMap::<T, u16>::default();
+    let mut losses = HashMap::<T, u16>::default();
+    let mut opponents = HashMap::<T, Vec<T>>::default();
+    for m in matches {
+        let Some(winner_slot) = m.winner_slot else { continue };
+        match m.slots {
+            [Slot::Entrant(team), Slot::Bye] | [Slot::Bye, Slot::Entrant(team)] => {
+                *wins.entry(team).or_default() += 1;
+                *byes.entry(team).or_default() += 1;
+            }
+            [Slot::Entrant(team1), Slot::Entrant(team2)] => {
+                let (winner, loser) = if winner_slot == 0 { (team1, team2) } else { (team2, team1) };
+                *wins.entry(winner).or_default() += 1;
+                *losses.entry(loser).or_default() += 1;
+                opponents.entry(team1).or_default().push(team2);
+                opponents.entry(team2).or_default().push(team1);
+            }
+            _ => {}
+        }
+    }
+    let get = |map: &HashMap<T, u16>, team: T| map.get(&team).copied().unwrap_or_default();
+    let win_percentage = |team: T| {
+        let played = opponents.get(&team).map_or(0, Vec::len);
+        if played == 0 {
+            MIN_OPPONENT_WIN_PERCENTAGE
+        } else {
+            (f64::from(get(&wins, team) - get(&byes, team)) / played as f64).max(MIN_OPPONENT_WIN_PERCENTAGE)
+        }
+    };
+    let mut standings = seeds.iter().map(|&team| {
+        let team_opponents = opponents.get(&team).map(Vec::as_slice).unwrap_or_default();
+        Standing {
+            team,
+            wins: get(&wins, team),
+            losses: get(&losses, team),
+            buchholz: team_opponents.iter().map(|&opponent| get(&wins, opponent)).sum(),
+            opponent_win_percentage: if team_opponents.is_empty() {
+                0.0
+            } else {
+                team_opponents.iter().map(|&opponent| win_percentage(opponent)).sum::<f64>() / team_opponents.len() as f64
+            },
+        }
+    }).collect_vec();
+    // stable sort, so complete ties stay in seed order
+    standings.sort_by(|standing1, standing2| standing2.wins.cmp(&standing1.wins)
+        .then_with(|| standing2.buchholz.cmp(&standing1.buchholz))
+        .then_with(|| standing2.opponent_win_percentage.total_cmp(&standing1.opponent_win_percentage))
+    );
+    standings
+}
+
+/// Pairs the given entrants, which must be sorted by score, without repeating any of the `previous` pairings unless `allow_rematches` is set.
+fn pair_remaining<T: Copy + Eq + Hash>(remaining: &[(T, u16)], previous: &HashSet<(T, T)>, allow_rematches: bool, budget: &mut usize) -> Option<Vec<[T; 2]>> {
+    let Some((&(first, score), rest)) = remaining.split_first() else { return Some(Vec::default()) };
+    let group_len = rest.iter().take_while(|&&(_, other_score)| other_score == score).count();
+    // the top half of a score group plays the bottom half, and entrants who can't be paired within their group float down to the next one
+    let start = ((group_len + 1) / 2).saturating_sub(1);
+    for idx in (start..group_len).chain(0..start).chain(group_len..rest.len()) {
+        if *budget == 0 { return None }
+        *budget -= 1;
+        let opponent = rest[idx].0;
+        if !allow_rematches && previous.contains(&(first, opponent)) { continue }
+        let mut next = rest.to_vec();
+        next.remove(idx);
+        if let Some(mut pairs) = pair_remaining(&next, previous, allow_rematches, budget) {
+            pairs.insert(0, [first, opponent]);
+            return Some(pairs)
+        }
+    }
+    None
+}
+
+/// Pairs the given entrants in rank order, ignoring previous pairings. This is the fallback if no other pairing was found within the search budget.
+fn pair_in_order<T: Copy>(ranked: &[(T, u16)]) -> Vec<[T; 2]> {
+    ranked.chunks_exact(2).map(|pair| [pair[0].0, pair[1].0]).collect()
+}
+
+fn pair_round<T: Copy + Eq + Hash>(seeds: &[T], matches: &[Match<T>], round: i16, best_of: i16) -> Vec<Match<T>> {
+    let mut previous = HashSet::new();
+    let mut had_bye = HashSet::new();
+    for m in matches {
+        match m.slots {
+            [Slot::Entrant(team1), Slot::Entrant(team2)] => {
+                previous.insert((team1, team2));
+                previous.insert((team2, team1));
+            }
+            [Slot::Entrant(team), Slot::Bye] => { had_bye.insert(team); }
+            _ => {}
+        }
+    }
+    let mut ranked = standings(seeds, matches).into_iter().map(|standing| (standing.team, standing.wins)).collect_vec();
+    let bye = if ranked.len() % 2 == 1 {
+        // the bye goes to the lowest-ranked entrant who hasn't had one yet
+        let idx = ranked.iter().rposition(|(team, _)| !had_bye.contains(team)).unwrap_or(ranked.len() - 1);
+        Some(ranked.remove(idx).0)
+    } else {
+        None
+    };
+    // each pass gets its own search budget
+    let pairs = pair_remaining(&ranked, &previous, false, &mut { PAIRING_SEARCH_BUDGET })
+        .or_else(|| pair_remaining(&ranked, &previous, true, &mut { PAIRING_SEARCH_BUDGET }))
+        .unwrap_or_else(|| pair_in_order(&ranked));
+    let mut round_matches = pairs.into_iter().enumerate().map(|(position, [team1, team2])| new_match(0, round, position as i16, best_of, team1, Some(team2))).collect_vec();
+    if let Some(team) = bye {
+        round_matches.push(new_match(0, round, round_matches.len() as i16, best_of, team, None));
+    }
+    round_matches
+}
+
+/// Lays out a Swiss bracket, with the first round paired by seed.
+pub(crate) fn first_round<T: Copy + Eq + Hash>(seeds: &[T], best_of: i16) -> Bracket<T> {
+    Bracket {
+        kind: Kind::Swiss,
+        grand_final_reset: false,
+        matches: pair_round(seeds, &[], 1, best_of),
+    }
+}
+
+/// Pairs the next round of a Swiss bracket, using the match length of the latest round.
+///
+/// Returns `None` while the latest round is still ongoing or once all rounds have been played.
+pub(crate) fn next_round<T: Copy + Eq + Hash>(bracket: &Bracket<T>, seeds: &[T], rounds: i16) -> Option<Vec<Match<T>>> {
+    let current_round = bracket.matches.iter().map(|m| m.round).max().unwrap_or(0);
+    if current_round >= rounds || bracket.matches.iter().any(|m| m.winner_slot.is_none()) { return None }
+    let best_of = bracket.matches.iter().filter(|m| m.round == current_round).map(|m| m.best_of).max().unwrap_or(1);
+    Some(pair_round(seeds, &bracket.matches, current_round + 1, best_of))
+}
+
+/// Splits seeds into pools so that each pool gets a similar spread of seeds: the first seeds go to pools A, B, C, …, the next ones back from the last pool, and so on.
+fn split_pools<T: Copy>(seeds: &[T], pools: usize) -> Vec<Vec<T>> {
+    let mut result = vec![Vec::default(); pools];
+    for (idx, &team) in seeds.iter().enumerate() {
+        let offset = idx % pools;
+        result[if (idx / pools) % 2 == 0 { offset } else { pools - 1 - offset }].push(team);
+    }
+    result
+}
+
+/// Schedules every pairing of the given entrants using the circle method, so every entrant plays at most once per round.
+fn circle_rounds<T: Copy>(entrants: &[T]) -> Vec<Vec<[T; 2]>> {
+    let mut slots = entrants.iter().copied().map(Some).collect_vec();
+    if slots.len() % 2 == 1 {
+        slots.push(None);
+    }
+    let num_slots = slots.len();
+    (1..num_slots).map(|_| {
+        let pairs = (0..num_slots / 2).filter_map(|idx| Some([slots[idx]?, slots[num_slots - 1 - idx]?])).collect();
+        // keep the first slot fixed and rotate the others
+        let last = slots.pop().expect("at least 2 slots");
+        slots.insert(1, last);
+        pairs
+    }).collect()
+}
+
+/// Lays out a round-robin bracket with the given number of pools. All rounds are scheduled up front.
+pub(crate) fn round_robin<T: Copy>(seeds: &[T], pools: usize, best_of: i16) -> Bracket<T> {
+    let mut matches = Vec::default();
+    for (pool, entrants) in split_pools(seeds, pools).into_iter().enumerate() {
+        for (round, pairs) in circle_rounds(&entrants).into_iter().enumerate() {
+            for (position, [team1, team2]) in pairs.into_iter().enumerate() {
+                matches.push(new_match(pool as i16, round as i16 + 1, position as i16, best_of, team1, Some(team2)));
+            }
+        }
+    }
+    Bracket {
+        kind: Kind::RoundRobin,
+        grand_final_reset: false,
+        matches,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decide_all(matches: &mut [Match<u8>], winner: impl Fn(u8, u8) -> u8) {
+        for m in matches {
+            if let [Slot::Entrant(team1), Slot::Entrant(team2)] = m.slots {
+                m.winner_slot = Some(if winner(team1, team2) == team1 { 0 } else { 1 });
+            }
+        }
+    }
+
+    #[test]
+    fn round_robin_pairs_everyone_once() {
+        let rounds = circle_rounds(&[1, 2, 3, 4, 5]);
+        assert_eq!(rounds.len(), 5);
+        let pairs = rounds.iter().flatten().map(|&[team1, team2]| (team1.min(team2), team1.max(team2))).collect::<HashSet<_>>();
+        assert_eq!(pairs.len(), 10);
+        assert!(rounds.iter().all(|round| round.len() == 2));
+    }
+
+    #[test]
+    fn pools_are_snake_seeded() {
+        assert_eq!(split_pools(&[1, 2, 3, 4, 5, 6, 7, 8], 2), [vec![1, 4, 5, 8], vec![2, 3, 6, 7]]);
+    }
+
+    #[test]
+    fn first_round_pairs_top_half_against_bottom_half() {
+        let bracket = first_round(&[1, 2, 3, 4, 5], 1);
+        let slots = bracket.matches.iter().map(|m| m.slots).collect_vec();
+        assert_eq!(slots, [
+            [Slot::Entrant(1), Slot::Entrant(3)],
+            [Slot::Entrant(2), Slot::Entrant(4)],
+            [Slot::Entrant(5), Slot::Bye],
+        ]);
+        assert_eq!(bracket.matches[2].winner_slot, Some(0));
+    }
+
+    #[test]
+    fn pairing_falls_back_when_search_budget_is_exhausted() {
+        let ranked = [(1, 0), (2, 0), (3, 0), (4, 0)];
+        assert_eq!(pair_remaining(&ranked, &HashSet::default(), true, &mut 0), None);
+        assert_eq!(pair_in_order(&ranked), [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn swiss_avoids_rematches_and_repeated_byes() {
+        let seeds = [1, 2, 3, 4, 5];
+        let mut bracket = first_round(&seeds, 1);
+        for round in 2..=4 {
+            decide_all(&mut bracket.matches, |team1, team2| team1.min(team2));
+            let next = next_round(&bracket, &seeds, 4).unwrap();
+            assert!(next.iter().all(|m| m.round == round));
+            for m in &next {
+                if let [Slot::Entrant(team1), Slot::Entrant(team2)] = m.slots {
+                    assert!(!bracket.matches.iter().any(|previous| previous.slots == [Slot::Entrant(team1), Slot::Entrant(team2)] || previous.slots == [Slot::Entrant(team2), Slot::Entrant(team1)]));
+                }
+            }
+            bracket.matches.extend(next);
+        }
+        let byes = bracket.matches.iter().filter_map(|m| if let [Slot::Entrant(team), Slot::Bye] = m.slots { Some(team) } else { None }).collect_vec();
+        assert_eq!(byes.iter().collect::<HashSet<_>>().len(), byes.len());
+        decide_all(&mut bracket.matches, |team1, team2| team1.min(team2));
+        assert!(next_round(&bracket, &seeds, 4).is_none());
+    }
+
+    #[test]
+    fn buchholz_breaks_ties() {
+        let mut matches = vec![
+            new_match(0, 1, 0, 1, 1, Some(4)),
+            new_match(0, 1, 1, 1, 2, Some(4)),
+            new_match(0, 2, 0, 1, 3, Some(1)),
+        ];
+        // 1 and 2 beat 4, 3 beats 1
+        decide_all(&mut matches, |team1, team2| if (team1, team2) == (3, 1) { 3 } else { team1.min(team2) });
+        let standings = standings(&[1, 2, 3, 4], &matches);
+        // 1, 2, and 3 have 1 win each, but 2 only beat a winless opponent, and 1's opponents have a better win percentage than 3's
+        assert_eq!(standings.iter().map(|standing| standing.team).collect_vec(), [1, 3, 2, 4]);
+        assert_eq!(standings.iter().map(|standing| standing.buchholz).collect_vec(), [1, 1, 0, 2]);
+        assert_eq!(standings[3].losses, 2);
+    }
+}
@@ -16,6 +16,10 @@ use {
             SignupStatus,
             Tab,
             enter,
+            qualifier_scoring::{
+                self,
+                QualifierScoring,
+            },
         },
         prelude::*,
         time::decode_pginterval,
@@ -29,37 +33,10 @@ pub(crate) enum QualifierKind {
     Single {
         show_times: bool,
     },
-    Score(QualifierScoreKind),
+    Score(QualifierScoring),
     SongsOfHope,
 }
 
-#[derive(Clone, Copy)]
-pub(crate) enum QualifierScoreKind {
-    Standard,
-    Sgl2023Online,
-    Sgl2024Online,
-    Sgl2025Online,
-    TwwrMiniblins26,
-    TwwrMain,
-}
-
-impl QualifierScoreKind {
-    pub(crate) fn required_qualifiers(self) -> usize {
-        match self {
-            Self::Standard => 5,
-            Self::Sgl2023Online | Self::Sgl2024Online | Self::Sgl2025Online => 3,
-            Self::TwwrMiniblins26 | Self::TwwrMain => 2,
-        }
-    }
-
-    pub(crate) fn max_qualifiers_that_count(self) -> usize {
-        match self {
-            Self::TwwrMiniblins26 | Self::TwwrMain => 4,
-            _ => usize::MAX,
-        }
-    }
-}
-
 pub(crate) enum MemberUser {
     MidosHouse(User),
     RaceTime {
@@ -201,7 +178,7 @@ pub(crate) struct SignupsMember {
 }
 
 /// Source of a qualifier score (live race or async).
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) enum RoundSource {
     /// Live qualifier race, with the race number (1-indexed).
     Live(usize),
@@ -230,6 +207,12 @@ impl fmt::Display for RoundSource {
 pub(crate) struct RoundScore {
     pub(crate) score: R64,
     pub(crate) source: RoundSource,
+    /// When the entrant finished or forfeited this round.
+    pub(crate) submitted_at: DateTime<Utc>,
+    /// `None` if the entrant did not finish or the round is extrapolated.
+    pub(crate) finish_time: Option<Duration>,
+    /// `None` if par could not be calculated yet.
+    pub(crate) par_time: Option<Duration>,
 }
 
 #[derive(Clone)]
@@ -261,24 +244,13 @@ pub(crate) struct SignupsTeam {
 }
 
 /// Whether a `Score`-kind entrant has not yet met the minimum qualifier requirement (dimmed/not qualified in the table).
-fn is_score_qualifier_dimmed(qualifier_kind: QualifierKind, qualification: &Qualification) -> bool {
+pub(crate) fn is_score_qualifier_dimmed(qualifier_kind: QualifierKind, qualification: &Qualification) -> bool {
     match qualifier_kind {
         QualifierKind::None | QualifierKind::Rank | QualifierKind::Single { .. } | QualifierKind::SongsOfHope => false,
-        QualifierKind::Score(QualifierScoreKind::Standard) => {
-            let Qualification::Multiple { num_finished, .. } = qualification else { unreachable!("qualification kind mismatch") };
-            *num_finished < 5
-        }
-        QualifierKind::Score(QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online) => {
-            let Qualification::Multiple { num_entered, .. } = qualification else { unreachable!("qualification kind mismatch") };
-            *num_entered < 3
-        }
-        QualifierKind::Score(QualifierScoreKind::Sgl2025Online) => {
-            let Qualification::Multiple { num_finished, .. } = qualification else { unreachable!("qualification kind mismatch") };
-            *num_finished < 3
-        }
-        QualifierKind::Score(QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain) => {
-            let Qualification::Multiple { num_finished, .. } = qualification else { unreachable!("qualification kind mismatch") };
-            *num_finished < 2
+        QualifierKind::Score(scoring) => {
+            let Qualification::Multiple { num_entered, num_finished, .. } = qualification else { unreachable!("qualification kind mismatch") };
+            let num_counted = if scoring.require_finishes { *num_finished } else { *num_entered };
+            num_counted < scoring.required_qualifiers()
         }
     }
 }
@@ -293,6 +265,12 @@ fn is_qualified(qualifier_kind: QualifierKind, qualification: &Qualification) ->
     }
 }
 
+/// Data of racetime.gg rooms which have finished or been cancelled, kept across requests since it no longer changes.
+///
+/// Entries are dropped after [`ENDED_RACE_DATA_TTL`] so rooms which are no longer looked at don't stay in memory.
+static ENDED_RACE_DATA: LazyLock<Mutex<HashMap<Url, (Instant, RaceData)>>> = LazyLock::new(Mutex::default);
+const ENDED_RACE_DATA_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
 pub(crate) struct Cache {
     http_client: reqwest::Client,
     race_data: HashMap<Url, RaceData>,
@@ -309,11 +287,24 @@ impl Cache {
     pub(crate) async fn race_data(&mut self, room: &Url) -> Result<&RaceData, cal::Error> {
         Ok(match self.race_data.entry(room.clone()) {
             hash_map::Entry::Occupied(entry) => entry.into_mut(),
-            hash_map::Entry::Vacant(entry) => entry.insert(self.http_client.get(format!("{room}/data"))
-                .send().await?
-                .detailed_error_for_status().await?
-                .json_with_text_in_error::<RaceData>().await?
-            ),
+            hash_map::Entry::Vacant(entry) => {
+                let ended_race_data = lock!(ended_race_data = ENDED_RACE_DATA; ended_race_data.get(room).filter(|(retrieved, _)| retrieved.elapsed() < ENDED_RACE_DATA_TTL).map(|(_, race_data)| race_data.clone()));
+                if let Some(race_data) = ended_race_data {
+                    entry.insert(race_data)
+                } else {
+                    let race_data = self.http_client.get(format!("{room}/data"))
+                        .send().await?
+                        .detailed_error_for_status().await?
+                        .json_with_text_in_error::<RaceData>().await?;
+                    if let RaceStatusValue::Finished | RaceStatusValue::Cancelled = race_data.status.value {
+                        lock!(ended_race_data = ENDED_RACE_DATA; {
+                            ended_race_data.retain(|_, (retrieved, _)| retrieved.elapsed() < ENDED_RACE_DATA_TTL);
+                            ended_race_data.insert(room.clone(), (Instant::now(), race_data.clone()));
+                        });
+                    }
+                    entry.insert(race_data)
+                }
+            }
         })
     }
 }
@@ -321,8 +312,8 @@ impl Cache {
 pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>, cache: &mut Cache, me: Option<&User>, data: &Data<'_>, is_organizer: bool, qualifier_kind: QualifierKind, worst_case_extrapolation: Option<&MemberUser>, all_qualifiers_ended: bool, include_opted_out: bool) -> Result<Vec<SignupsTeam>, cal::Error> {
     let now = Utc::now();
     let mut signups = match qualifier_kind {
-        QualifierKind::Score(score_kind) => {
-            let mut scores = HashMap::<_, Vec<(DateTime<Utc>, R64, RoundSource)>>::default();
+        QualifierKind::Score(scoring) => {
+            let mut scores = HashMap::<_, Vec<(DateTime<Utc>, RoundScore)>>::default();
             for race in Race::for_event(transaction, &cache.http_client, data).await? {
                 if race.phase.as_ref().is_none_or(|phase| phase != "Qualifier") { continue }
                 // Extract live race number from the round field (e.g., "Live 1" -> 1)
@@ -334,16 +325,17 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     if let Some(extrapolate_for) = worst_case_extrapolation {
                         scores.entry(MemberUser::Newcomer).or_default();
                         for (user, score) in &mut scores {
-                            score.push((now, r64(if user == extrapolate_for {
-                                0.0
-                            } else {
-                                match score_kind {
-                                    QualifierScoreKind::Standard => 1100.0,
-                                    QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => 110.0,
-                                    QualifierScoreKind::TwwrMiniblins26 => 5000.0,
-                                    QualifierScoreKind::TwwrMain => 2000.0,
-                                }
-                            }), RoundSource::Live(live_race_num)));
+                            score.push((now, RoundScore {
+                                score: r64(if user == extrapolate_for {
+                                    0.0
+                                } else {
+                                    scoring.best_possible_points()
+                                }),
+                                source: RoundSource::Live(live_race_num),
+                                submitted_at: now,
+                                finish_time: None,
+                                par_time: None,
+                            }));
                         }
                     }
                     continue
@@ -354,16 +346,17 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     if let Some(extrapolate_for) = worst_case_extrapolation {
                         scores.entry(MemberUser::Newcomer).or_default();
                         for (user, score) in &mut scores {
-                            score.push((race_start, r64(if user == extrapolate_for {
-                                0.0
-                            } else {
-                                match score_kind {
-                                    QualifierScoreKind::Standard => 1100.0,
-                                    QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => 110.0,
-                                    QualifierScoreKind::TwwrMiniblins26 => 5000.0,
-                                    QualifierScoreKind::TwwrMain => 2000.0,
-                                }
-                            }), RoundSource::Live(live_race_num)));
+                            score.push((race_start, RoundScore {
+                                score: r64(if user == extrapolate_for {
+                                    0.0
+                                } else {
+                                    scoring.best_possible_points()
+                                }),
+                                source: RoundSource::Live(live_race_num),
+                                submitted_at: race_start,
+                                finish_time: None,
+                                par_time: None,
+                            }));
                         }
                     }
                 } else {
@@ -371,24 +364,22 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                         RaceStatusValue::Open => if let Some(extrapolate_for) = worst_case_extrapolation {
                             scores.entry(MemberUser::Newcomer).or_default();
                             for (user, score) in &mut scores {
-                                score.push((race_start, r64(if user == extrapolate_for {
-                                    0.0
-                                } else {
-                                    match score_kind {
-                                        QualifierScoreKind::Standard => 1100.0,
-                                        QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => 110.0,
-                                        QualifierScoreKind::TwwrMiniblins26 => 5000.0,
-                                    QualifierScoreKind::TwwrMain => 2000.0,
-                                    }
-                                }), RoundSource::Live(live_race_num)));
+                                score.push((race_start, RoundScore {
+                                    score: r64(if user == extrapolate_for {
+                                        0.0
+                                    } else {
+                                        scoring.best_possible_points()
+                                    }),
+                                    source: RoundSource::Live(live_race_num),
+                                    submitted_at: race_start,
+                                    finish_time: None,
+                                    par_time: None,
+                                }));
                             }
                         },
                         RaceStatusValue::Cancelled => {}
                         RaceStatusValue::Invitational | RaceStatusValue::Pending | RaceStatusValue::InProgress | RaceStatusValue::Finished => {
                             let mut entrants = room_data.entrants.clone();
-                            match score_kind {
-                                _ => {}
-                            }
                             for entrant in &mut entrants {
                                 let user = entrant.user.clone().map(racetime::model::UserData::try_from).transpose()?;
                                 match entrant.status.value {
@@ -419,14 +410,13 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                             let num_entrants = entrants.len();
                             let finish_times = entrants.iter().filter_map(|entrant| entrant.finish_time).collect_vec();
                             let num_finishers = finish_times.len();
-                            let par_cutoff = match score_kind {
-                                QualifierScoreKind::Standard => 7u8,
-                                QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => if num_entrants < 20 { 3 } else { 4 },
-                                QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain => 3,
-                            };
-                            if worst_case_extrapolation.is_none() && room_data.status.value != RaceStatusValue::Finished && num_finishers < usize::from(par_cutoff) {
+                            if worst_case_extrapolation.is_none() && room_data.status.value != RaceStatusValue::Finished && num_finishers < scoring.par_size(num_entrants) {
                                 continue // scores are not yet accurate
                             }
+                            let par_times = scoring.par_times(&finish_times, num_entrants);
+                            if par_times.is_none() && num_finishers > 0 {
+                                continue // not enough finishers to calculate par
+                            }
                             for entrant in entrants {
                                 match entrant.status.value {
                                     | EntrantStatusValue::Requested
@@ -442,37 +432,18 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                                         => {}
                                 }
                                 let user = entrant.user.clone().map(racetime::model::UserData::try_from).transpose()?;
-                                scores.entry(MemberUser::from(user)).or_default().push((race_start, r64(if let Some(finish_time) = entrant.finish_time {
-                                    match score_kind {
-                                        QualifierScoreKind::Standard => {
-                                            // https://docs.google.com/document/d/1IHrOGxFQpt3HpQ-9kQ6AVAARc04x6c96N1aHnHfHaKM/edit
-                                            let finish_time = TimeDelta::from_std(finish_time).expect("finish time out of range");
-                                            let par_cutoff = usize::from(par_cutoff).min(num_entrants);
-                                            let par_times = finish_times[0..par_cutoff].iter().map(|&finish_time| TimeDelta::from_std(finish_time).expect("finish time out of range")).collect_vec();
-                                            let t_average = par_times.iter().sum::<TimeDelta>() / i32::try_from(par_cutoff).expect("too many entrants");
-                                            let t_j_h = TimeDelta::minutes(8).mul_f64(1.0.min(0.0.max((TimeDelta::hours(2) + TimeDelta::minutes(30) - t_average).div_duration_f64(TimeDelta::hours(2) + TimeDelta::minutes(30) - (TimeDelta::hours(1) + TimeDelta::minutes(40))))));
-                                            let t_jet = TimeDelta::minutes(8).min(t_j_h.mul_f64(0.0.max((finish_time - t_average).div_duration_f64(TimeDelta::minutes(8)) * 0.35)));
-                                            let t_g_h = TimeDelta::from_secs_f64((par_times.iter().map(|finish_time| finish_time.abs_diff(t_average).as_secs_f64().powi(2)).sum::<f64>() / 1.max(par_cutoff - 1) as f64).sqrt());
-                                            let sigma_finish = t_g_h.div_duration_f64(t_average);
-                                            let t_gamble = TimeDelta::minutes(5).min(t_g_h.mul_f64(0.0.max((finish_time - t_average).div_duration_f64(t_g_h) * 0.0.max(sigma_finish / 0.035 - 1.0) * 0.3)));
-                                            ((1.0 - (finish_time - t_average - TimeDelta::minutes(10).min(t_jet + t_gamble)).div_duration_f64(t_average)) * 1000.0).clamp(100.0, 1100.0)
-                                        }
-                                        QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => {
-                                            let par_time = finish_times[0..usize::from(par_cutoff)].iter().sum::<Duration>() / u32::from(par_cutoff);
-                                            (100.0 * (2.0 - (finish_time.as_secs_f64() / par_time.as_secs_f64()))).clamp(10.0, 110.0)
-                                        }
-                                        QualifierScoreKind::TwwrMiniblins26 => {
-                                            let par_time = finish_times[0..usize::from(par_cutoff)].iter().sum::<Duration>() / u32::from(par_cutoff);
-                                            (2000.0 + ((1.0 - (finish_time.as_secs_f64() - par_time.as_secs_f64()) / par_time.as_secs_f64()) * 1000.0).floor()).max(100.0)
-                                        }
-                                        QualifierScoreKind::TwwrMain => {
-                                            let par_time = finish_times[0..usize::from(par_cutoff)].iter().sum::<Duration>() / u32::from(par_cutoff);
-                                            ((1.0 - (finish_time.as_secs_f64() - par_time.as_secs_f64()) / par_time.as_secs_f64()) * 1000.0).max(100.0)
-                                        }
-                                    }
-                                } else {
-                                    0.0
-                                }), RoundSource::Live(live_race_num)));
+                                let submitted_at = entrant.finish_time.and_then(|finish_time| TimeDelta::from_std(finish_time).ok()).map_or(race_start, |finish_time| race_start + finish_time);
+                                scores.entry(MemberUser::from(user)).or_default().push((race_start, RoundScore {
+                                    score: r64(if let (Some(finish_time), Some(par_times)) = (entrant.finish_time, par_times) {
+                                        scoring.points(finish_time, par_times)
+                                    } else {
+                                        0.0
+                                    }),
+                                    source: RoundSource::Live(live_race_num),
+                                    finish_time: entrant.finish_time,
+                                    par_time: par_times.map(|par_times| scoring.par_time(par_times)),
+                                    submitted_at,
+                                }));
                             }
                         }
                     }
@@ -486,6 +457,7 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                         ap.time,
                         ap.kind AS "kind: AsyncKind",
                         at.start_time,
+                        at.submitted AS "submitted!",
                         a.end_time AS async_end_time
                     FROM async_players ap
                     INNER JOIN team_members tm ON tm.member = ap.player
@@ -494,10 +466,11 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     INNER JOIN asyncs a ON a.series = $1 AND a.event = $2 AND a.kind = ap.kind
                     WHERE ap.series = $1 AND ap.event = $2
                     AND at.submitted IS NOT NULL
+                    AND ap.verification_status = 'verified'
                 "#, data.series as _, &data.event).fetch_all(&mut **transaction).await?;
 
                 // Group by kind to calculate per-qualifier par times
-                let mut async_by_kind = HashMap::<AsyncKind, Vec<(Id<Users>, Duration, DateTime<Utc>)>>::new();
+                let mut async_by_kind = HashMap::<AsyncKind, Vec<(Id<Users>, Duration, DateTime<Utc>, DateTime<Utc>)>>::new();
                 for row in &async_results {
                     // For async_only visibility, skip results from async windows that haven't closed yet (organizers see all)
                     if !is_organizer && data.qualifier_score_hiding == QualifierScoreHiding::AsyncOnly {
@@ -508,61 +481,44 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     if let Some(ref time_interval) = row.time {
                         if let Ok(time) = decode_pginterval(time_interval.clone()) {
                             let start_time = row.start_time.unwrap_or(now);
-                            async_by_kind.entry(row.kind).or_default().push((row.player, time, start_time));
+                            async_by_kind.entry(row.kind).or_default().push((row.player, time, start_time, row.submitted));
                         }
                     }
                 }
 
                 for (async_kind, mut results) in async_by_kind {
-                    results.sort_by_key(|(_, time, _)| *time);
-                    let finish_times: Vec<Duration> = results.iter().map(|(_, t, _)| *t).collect();
+                    results.sort_by_key(|(_, time, _, _)| *time);
+                    let finish_times: Vec<Duration> = results.iter().map(|(_, t, _, _)| *t).collect();
                     let num_entrants = results.len();
-                    let par_cutoff = match score_kind {
-                        QualifierScoreKind::Standard => 7usize.min(num_entrants),
-                        QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => if num_entrants < 20 { 3 } else { 4 },
-                        QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain => 3,
-                    };
-                    if finish_times.len() < par_cutoff {
-                        if matches!(score_kind, QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain) {
+                    let Some(par_times) = scoring.par_times(&finish_times, num_entrants) else {
+                        if scoring.count_pending_asyncs {
                             // Not enough finishers for par — record entries with a
                             // sentinel score of -1 so participation counts are accurate.
                             // Scores will be recalculated when enough finishers exist.
-                            for (player_id, _finish_time, start_time) in &results {
+                            for (player_id, finish_time, start_time, submitted) in &results {
                                 let user = User::from_id(&mut **transaction, *player_id).await?.expect("async player not found");
-                                scores.entry(MemberUser::MidosHouse(user)).or_default().push((*start_time, r64(-1.0), RoundSource::Async(async_kind)));
+                                scores.entry(MemberUser::MidosHouse(user)).or_default().push((*start_time, RoundScore {
+                                    score: r64(-1.0),
+                                    source: RoundSource::Async(async_kind),
+                                    submitted_at: *submitted,
+                                    finish_time: Some(*finish_time),
+                                    par_time: None,
+                                }));
                             }
                         }
                         continue;
-                    }
+                    };
 
-                    for (player_id, finish_time, start_time) in &results {
-                        let score = match score_kind {
-                            QualifierScoreKind::Standard => {
-                                let finish_time = TimeDelta::from_std(*finish_time).expect("finish time out of range");
-                                let par_times = finish_times[0..par_cutoff].iter().map(|&ft| TimeDelta::from_std(ft).expect("finish time out of range")).collect_vec();
-                                let t_average = par_times.iter().sum::<TimeDelta>() / i32::try_from(par_cutoff).expect("too many entrants");
-                                let t_j_h = TimeDelta::minutes(8).mul_f64(1.0.min(0.0.max((TimeDelta::hours(2) + TimeDelta::minutes(30) - t_average).div_duration_f64(TimeDelta::hours(2) + TimeDelta::minutes(30) - (TimeDelta::hours(1) + TimeDelta::minutes(40))))));
-                                let t_jet = TimeDelta::minutes(8).min(t_j_h.mul_f64(0.0.max((finish_time - t_average).div_duration_f64(TimeDelta::minutes(8)) * 0.35)));
-                                let t_g_h = TimeDelta::from_secs_f64((par_times.iter().map(|ft| ft.abs_diff(t_average).as_secs_f64().powi(2)).sum::<f64>() / 1.max(par_cutoff - 1) as f64).sqrt());
-                                let sigma_finish = t_g_h.div_duration_f64(t_average);
-                                let t_gamble = TimeDelta::minutes(5).min(t_g_h.mul_f64(0.0.max((finish_time - t_average).div_duration_f64(t_g_h) * 0.0.max(sigma_finish / 0.035 - 1.0) * 0.3)));
-                                ((1.0 - (finish_time - t_average - TimeDelta::minutes(10).min(t_jet + t_gamble)).div_duration_f64(t_average)) * 1000.0).clamp(100.0, 1100.0)
-                            }
-                            QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => {
-                                let par_time = finish_times[0..par_cutoff].iter().sum::<Duration>() / par_cutoff as u32;
-                                (100.0 * (2.0 - (finish_time.as_secs_f64() / par_time.as_secs_f64()))).clamp(10.0, 110.0)
-                            }
-                            QualifierScoreKind::TwwrMiniblins26 => {
-                                let par_time = finish_times[0..par_cutoff].iter().sum::<Duration>() / par_cutoff as u32;
-                                (2000.0 + ((1.0 - (finish_time.as_secs_f64() - par_time.as_secs_f64()) / par_time.as_secs_f64()) * 1000.0).floor()).max(100.0)
-                            }
-                            QualifierScoreKind::TwwrMain => {
-                                let par_time = finish_times[0..par_cutoff].iter().sum::<Duration>() / par_cutoff as u32;
-                                ((1.0 - (finish_time.as_secs_f64() - par_time.as_secs_f64()) / par_time.as_secs_f64()) * 1000.0).max(100.0)
-                            }
-                        };
+                    for (player_id, finish_time, start_time, submitted) in &results {
+                        let score = scoring.points(*finish_time, par_times);
                         let user = User::from_id(&mut **transaction, *player_id).await?.expect("async player not found");
-                        scores.entry(MemberUser::MidosHouse(user)).or_default().push((*start_time, r64(score), RoundSource::Async(async_kind)));
+                        scores.entry(MemberUser::MidosHouse(user)).or_default().push((*start_time, RoundScore {
+                            score: r64(score),
+                            source: RoundSource::Async(async_kind),
+                            submitted_at: *submitted,
+                            finish_time: Some(*finish_time),
+                            par_time: Some(scoring.par_time(par_times)),
+                        }));
                     }
                 }
                 // Count forfeits/DNFs as entered (score 0) even though they have no time
@@ -576,7 +532,13 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     }
                     let start_time = row.start_time.unwrap_or(now);
                     let user = User::from_id(&mut **transaction, row.player).await?.expect("async player not found");
-                    scores.entry(MemberUser::MidosHouse(user)).or_default().push((start_time, r64(0.0), RoundSource::Async(row.kind)));
+                    scores.entry(MemberUser::MidosHouse(user)).or_default().push((start_time, RoundScore {
+                        score: r64(0.0),
+                        source: RoundSource::Async(row.kind),
+                        submitted_at: row.submitted,
+                        finish_time: None,
+                        par_time: None,
+                    }));
                 }
             }
             let teams = Team::for_event(&mut *transaction, data.series, &data.event).await?;
@@ -614,11 +576,11 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
             let mut signups = Vec::with_capacity(scores.len());
             for (user, mut timestamped_scores) in scores {
                 // Sort by timestamp so truncation respects chronological order
-                timestamped_scores.sort_by_key(|(ts, _, _)| *ts);
+                timestamped_scores.sort_by_key(|(ts, _)| *ts);
                 // For async_only hiding, filter out all asyncs for non-organizers from entered/finished/forfeited counts
                 if !is_organizer && data.qualifier_score_hiding == QualifierScoreHiding::AsyncOnly {
-                    timestamped_scores.retain(|(_, _, source)| {
-                        !matches!(source, RoundSource::Async(_))
+                    timestamped_scores.retain(|(_, round)| {
+                        !matches!(round.source, RoundSource::Async(_))
                     });
                 }
                 let user_opted_out = is_user_opted_out(&user);
@@ -654,78 +616,18 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     }],
                     qualification: {
                         // Extract score values and sources after timestamp-based ordering
-                        let round_scores_with_source: Vec<RoundScore> = timestamped_scores.iter().map(|(_, s, src)| RoundScore { score: *s, source: *src }).collect();
-                        let mut scores: Vec<R64> = timestamped_scores.into_iter().map(|(_, s, _)| s).collect();
-                        match score_kind {
-                            QualifierScoreKind::Standard => {
-                                scores.truncate(8); // only count the first 8 qualifiers chronologically
-                                let round_scores: Vec<RoundScore> = round_scores_with_source.into_iter().take(8).collect();
-                                let num_entered = scores.len();
-                                scores.retain(|&score| score != 0.0); // only count finished races
-                                let num_finished = scores.len();
-                                scores.sort_unstable();
-                                if num_entered >= 2 {
-                                    scores.pop(); // remove best score
-                                }
-                                scores.reverse();
-                                scores.truncate(4); // remove up to 3 worst scores
-                                Qualification::Multiple {
-                                    score: scores.iter().copied().sum::<R64>(), // overall score is sum of remaining scores
-                                    num_forfeited: num_entered - num_finished,
-                                    num_entered, num_finished, round_scores,
-                                }
-                            }
-                            QualifierScoreKind::Sgl2023Online => {
-                                scores.truncate(5); // only count the first 5 qualifiers chronologically
-                                let round_scores: Vec<RoundScore> = round_scores_with_source.into_iter().take(5).collect();
-                                let num_entered = scores.len();
-                                scores.sort_unstable();
-                                if num_entered >= 4 {
-                                    scores.pop(); // remove best score
-                                }
-                                if num_entered >= 5 {
-                                    scores.swap_remove(0); // remove worst score
-                                }
-                                let num_finished = scores.iter().filter(|score| **score != 0.0).count();
-                                Qualification::Multiple {
-                                    num_finished,
-                                    num_forfeited: num_entered - num_finished,
-                                    score: scores.iter().copied().sum::<R64>() / r64(scores.len().max(3) as f64), // overall score is average of remaining scores
-                                    num_entered, round_scores,
-                                }
-                            }
-                            QualifierScoreKind::Sgl2024Online | QualifierScoreKind::Sgl2025Online => {
-                                scores.truncate(6); // only count the first 6 qualifiers chronologically
-                                let round_scores: Vec<RoundScore> = round_scores_with_source.into_iter().take(6).collect();
-                                let num_entered = scores.len();
-                                scores.sort_unstable();
-                                if num_entered >= 4 {
-                                    scores.swap_remove(0); // remove worst score
-                                }
-                                let num_finished = scores.iter().filter(|score| **score != 0.0).count();
-                                Qualification::Multiple {
-                                    num_finished,
-                                    num_forfeited: num_entered - num_finished,
-                                    score: scores.iter().copied().sum::<R64>() / r64(scores.len().max(3) as f64), // overall score is average of remaining scores
-                                    num_entered, round_scores,
-                                }
-                            }
-                            QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain => {
-                                let max_count = score_kind.max_qualifiers_that_count();
-                                let num_entered = scores.len();
-                                let num_finished = scores.iter().filter(|&&score| score != 0.0).count(); // -1 sentinel counts as finished
-                                scores.truncate(max_count); // only first N qualifiers contribute to score
-                                let round_scores: Vec<RoundScore> = round_scores_with_source;
-                                scores.retain(|&score| score > 0.0); // only keep properly scored entries
-                                scores.sort_unstable();
-                                scores.reverse(); // highest first
-                                scores.truncate(score_kind.required_qualifiers()); // best N
-                                Qualification::Multiple {
-                                    score: scores.iter().copied().sum::<R64>(),
-                                    num_forfeited: num_entered - num_finished,
-                                    num_entered, num_finished, round_scores,
-                                }
-                            }
+                        let mut round_scores_with_source = timestamped_scores.into_iter().map(|(_, round)| round).collect_vec();
+                        if !scoring.list_uncounted {
+                            round_scores_with_source.truncate(scoring.max_qualifiers_that_count());
+                        }
+                        let scores = round_scores_with_source.iter().map(|round| round.score).collect_vec();
+                        let aggregate = scoring.aggregate(&scores);
+                        Qualification::Multiple {
+                            score: aggregate.score,
+                            num_entered: aggregate.num_entered,
+                            num_finished: aggregate.num_finished,
+                            num_forfeited: aggregate.num_entered - aggregate.num_finished,
+                            round_scores: round_scores_with_source,
                         }
                     },
                     custom_choices: HashMap::default(),
@@ -823,6 +725,7 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     series = $1
                     AND event = $2
                     AND NOT resigned
+                    AND waitlisted_at IS NULL
                     AND (
                         $3
                         OR EXISTS (SELECT 1 FROM team_members WHERE team = id AND member = $4)
@@ -856,6 +759,7 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                     series = $1
                     AND event = $2
                     AND NOT resigned
+                    AND waitlisted_at IS NULL
                     AND (
                         EXISTS (SELECT 1 FROM team_members WHERE team = id AND member = $3)
                         OR NOT EXISTS (SELECT 1 FROM team_members WHERE team = id AND status = 'unconfirmed')
@@ -888,7 +792,7 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                 for &(role, _) in roles {
                     let row = sqlx::query!(r#"
                         SELECT member AS "id: Id<Users>", status AS "status: SignupStatus", time, vod
-                        FROM team_members LEFT OUTER JOIN async_players ON (member = player AND series = $1 AND event = $2 AND kind = 'qualifier')
+                        FROM team_members LEFT OUTER JOIN async_players ON (member = player AND series = $1 AND event = $2 AND kind = 'qualifier' AND verification_status = 'verified')
                         WHERE team = $3 AND role = $4
                     "#, data.series as _, &data.event, team.team.id as _, role as _).fetch_one(&mut **transaction).await?;
                     let is_confirmed = row.status.is_confirmed();
@@ -975,36 +879,24 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
                 QualificationOrder::new(qualification1.clone(), members1, hide_time).cmp(&QualificationOrder::new(qualification2.clone(), members2, hide_time))
                 .then_with(|| team1.cmp(&team2))
             }
-            QualifierKind::Score(score_kind) => {
+            QualifierKind::Score(scoring) => {
                 if !is_organizer && matches!(data.qualifier_score_hiding, QualifierScoreHiding::FullPoints | QualifierScoreHiding::FullPointsCounts | QualifierScoreHiding::FullComplete) && !all_qualifiers_ended {
                     // When scores are fully hidden, sort alphabetically to avoid leaking rankings (except for organizers)
                     members1.iter().map(|member| &member.user).cmp(members2.iter().map(|member| &member.user))
                 } else {
-                    let (num1, score1, entered1) = match *qualification1 {
-                        Qualification::Multiple { num_entered, num_finished, score, .. } => match score_kind { //TODO determine based on enter flow
-                            QualifierScoreKind::Standard | QualifierScoreKind::Sgl2025Online => (num_finished, score, num_entered),
-                            QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain => {
-                                let max_count = score_kind.max_qualifiers_that_count();
-                                (num_finished.min(max_count), score, num_entered.min(max_count))
-                            }
-                            QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online => (num_entered, score, num_entered),
-                        },
+                    let max_count = scoring.max_qualifiers_that_count();
+                    let (num1, score1, entered1, rounds1) = match *qualification1 {
+                        Qualification::Multiple { num_entered, num_finished, score, ref round_scores, .. } => ((if scoring.require_finishes { num_finished } else { num_entered }).min(max_count), score, num_entered.min(max_count), round_scores),
                         _ => unreachable!("QualifierKind::Multiple must use Qualification::Multiple"),
                     };
-                    let (num2, score2, entered2) = match *qualification2 {
-                        Qualification::Multiple { num_entered, num_finished, score, .. } => match score_kind { //TODO determine based on enter flow
-                            QualifierScoreKind::Standard | QualifierScoreKind::Sgl2025Online => (num_finished, score, num_entered),
-                            QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain => {
-                                let max_count = score_kind.max_qualifiers_that_count();
-                                (num_finished.min(max_count), score, num_entered.min(max_count))
-                            }
-                            QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online => (num_entered, score, num_entered),
-                        },
+                    let (num2, score2, entered2, rounds2) = match *qualification2 {
+                        Qualification::Multiple { num_entered, num_finished, score, ref round_scores, .. } => ((if scoring.require_finishes { num_finished } else { num_entered }).min(max_count), score, num_entered.min(max_count), round_scores),
                         _ => unreachable!("QualifierKind::Multiple must use Qualification::Multiple"),
                     };
-                    let required_qualifiers = score_kind.required_qualifiers();
+                    let required_qualifiers = scoring.required_qualifiers();
                     num2.min(required_qualifiers).cmp(&num1.min(required_qualifiers)) // list racers closer to reaching the required number of qualifiers first
                     .then_with(|| score2.cmp(&score1)) // list racers with higher scores first
+                    .then_with(|| qualifier_scoring::compare_tiebreakers(&data.qualifier_tiebreakers, &scoring, rounds1, rounds2))
                     .then_with(|| entered2.cmp(&entered1)) // list racers with more qualifiers entered first
                     .then_with(|| members1.iter().map(|member| &member.user).cmp(members2.iter().map(|member| &member.user)))
                 }
@@ -1014,6 +906,33 @@ pub(crate) async fn signups_sorted(transaction: &mut Transaction<'_, Postgres>,
     Ok(signups)
 }
 
+/// Whether all qualifier races and asyncs of the event have ended, after which qualifier score hiding no longer applies.
+pub(crate) async fn all_qualifiers_ended(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, data: &Data<'_>, qualifier_kind: QualifierKind) -> Result<bool, cal::Error> {
+    Ok(if let QualifierKind::Score(_) = qualifier_kind {
+        let all_races_ended = Race::for_event(&mut *transaction, http_client, data).await?.into_iter().all(|race| race.phase.as_ref().is_none_or(|phase| phase != "Qualifier") || race.is_ended());
+        let all_asyncs_ended = sqlx::query_scalar!(r#"
+            SELECT NOT EXISTS(
+                SELECT 1 FROM asyncs
+                WHERE series = $1 AND event = $2
+                AND kind IN ('qualifier', 'qualifier2', 'qualifier3')
+                AND (end_time IS NULL OR end_time > NOW())
+            ) AS "all_ended!"
+        "#, data.series as _, &data.event).fetch_one(&mut *transaction).await?;
+        all_races_ended && all_asyncs_ended
+    } else if let QualifierKind::Single { .. } = qualifier_kind {
+        sqlx::query_scalar!(r#"
+            SELECT NOT EXISTS(
+                SELECT 1 FROM asyncs
+                WHERE series = $1 AND event = $2
+                AND kind = 'qualifier'
+                AND (end_time IS NULL OR end_time > NOW())
+            ) AS "all_ended!"
+        "#, data.series as _, &data.event).fetch_one(&mut *transaction).await?
+    } else {
+        true
+    })
+}
+
 #[derive(Debug, thiserror::Error, rocket_util::Error)]
 pub(crate) enum Error {
     #[error(transparent)] Cal(#[from] cal::Error),
@@ -1051,29 +970,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
         false
     };
     let qualifier_kind = data.qualifier_kind(&mut transaction).await?;
-    let all_qualifiers_ended = if let QualifierKind::Score(_) = qualifier_kind {
-        let all_races_ended = Race::for_event(&mut transaction, http_client, &data).await?.into_iter().all(|race| race.phase.as_ref().is_none_or(|phase| phase != "Qualifier") || race.is_ended());
-        let all_asyncs_ended = sqlx::query_scalar!(r#"
-            SELECT NOT EXISTS(
-                SELECT 1 FROM asyncs
-                WHERE series = $1 AND event = $2
-                AND kind IN ('qualifier', 'qualifier2', 'qualifier3')
-                AND (end_time IS NULL OR end_time > NOW())
-            ) AS "all_ended!"
-        "#, data.series as _, &data.event).fetch_one(&mut *transaction).await?;
-        all_races_ended && all_asyncs_ended
-    } else if let QualifierKind::Single { .. } = qualifier_kind {
-        sqlx::query_scalar!(r#"
-            SELECT NOT EXISTS(
-                SELECT 1 FROM asyncs
-                WHERE series = $1 AND event = $2
-                AND kind = 'qualifier'
-                AND (end_time IS NULL OR end_time > NOW())
-            ) AS "all_ended!"
-        "#, data.series as _, &data.event).fetch_one(&mut *transaction).await?
-    } else {
-        true
-    };
+    let all_qualifiers_ended = all_qualifiers_ended(&mut transaction, http_client, &data, qualifier_kind).await?;
     if let QualifierKind::Score(_) = qualifier_kind {
         if !data.is_started(&mut transaction).await? {
             if Race::for_event(&mut transaction, http_client, &data).await?.into_iter().all(|race| race.phase.as_ref().is_none_or(|phase| phase != "Qualifier") || race.is_ended()) { //TODO also show if anyone is already eligible to sign up
@@ -1116,7 +1013,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
 
     let async_results_exist = if let QualifierKind::Score(_) = qualifier_kind {
         sqlx::query_scalar!(
-            r#"SELECT EXISTS(SELECT 1 FROM async_players ap WHERE ap.series = $1 AND ap.event = $2 AND ap.kind IN ('qualifier', 'qualifier2', 'qualifier3') AND ap.time IS NOT NULL) AS "exists!""#,
+            r#"SELECT EXISTS(SELECT 1 FROM async_players ap WHERE ap.series = $1 AND ap.event = $2 AND ap.kind IN ('qualifier', 'qualifier2', 'qualifier3') AND ap.time IS NOT NULL AND ap.verification_status = 'verified') AS "exists!""#,
             data.series as _,
             &data.event
         )
@@ -1131,6 +1028,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
         && (is_organizer || is_global_admin)
         && data.qualifier_score_hiding != QualifierScoreHiding::None;
 
+    let waitlisted = super::waitlist::teams(&mut transaction, &data).await?;
     let mut footnotes = Vec::default();
     let teams_label = if let TeamConfig::Solo = data.team_config { "Entrants" } else { "Teams" };
     let has_opt_outs = signups.iter().any(|signup| signup.is_opted_out);
@@ -1180,7 +1078,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                 });
             }
         }
-        QualifierKind::Score(QualifierScoreKind::Standard | QualifierScoreKind::Sgl2025Online | QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain) => { //TODO determine based on enter flow
+        QualifierKind::Score(scoring) if scoring.require_finishes => {
             column_headers.push(html! {
                 th : "Qualifiers Entered";
             });
@@ -1191,7 +1089,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                 th : "Qualifier Points";
             });
         }
-        QualifierKind::Score(QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online) => {
+        QualifierKind::Score(_) => {
             column_headers.push(html! {
                 th : "Qualifiers Entered";
             });
@@ -1212,7 +1110,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
     if let Some(ref enter_flow) = data.enter_flow {
         for requirement in &enter_flow.requirements {
             match requirement {
-                enter::Requirement::BooleanChoice { label, .. } | enter::Requirement::RadioChoice { label, .. } => {
+                enter::Requirement::BooleanChoice { label, .. } | enter::Requirement::RadioChoice { label, .. } | enter::Requirement::Dropdown { label, .. } => {
                     column_headers.push(html! {
                         th : label;
                     });
@@ -1395,6 +1293,10 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                 td {
                                     @if let Some(ref team) = team {
                                         : team.to_html(&mut transaction, false).await?;
+                                        : " ";
+                                        small {
+                                            a(href = uri!(super::entrant::get(series, event, team.id)).to_string()) : "(history)";
+                                        }
                                     }
                                 }
                             }
@@ -1403,6 +1305,12 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                     @match user {
                                         MemberUser::MidosHouse(user) => {
                                             : user;
+                                            @if let (TeamConfig::Solo, Some(team)) = (&data.team_config, &team) {
+                                                : " ";
+                                                small {
+                                                    a(href = uri!(super::entrant::get(series, event, team.id)).to_string()) : "(history)";
+                                                }
+                                            }
                                             @if let Some(ref team) = team {
                                                 @if *is_confirmed {
                                                     @if me.as_ref().is_some_and(|me| me == user) && members.iter().any(|member| !member.is_confirmed) {
@@ -1502,30 +1410,22 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                     }
                                     td : pieces;
                                 }
-                                (QualifierKind::Score(QualifierScoreKind::Standard | QualifierScoreKind::Sgl2025Online), Qualification::Multiple { num_entered, num_finished, num_forfeited, score, .. }) => { //TODO determine based on enter flow
-                                    td(style = "text-align: right;") : num_entered;
-                                    td(style = "text-align: right;") {
-                                        : num_finished;
-                                        @if num_forfeited > 0 {
-                                            : format!(" ({})", num_forfeited);
-                                        }
-                                    }
-                                    td(style = "text-align: right;") : format!("{score:.2}");
-                                }
-                                (QualifierKind::Score(score_kind @ (QualifierScoreKind::TwwrMiniblins26 | QualifierScoreKind::TwwrMain)), Qualification::Multiple { num_entered, num_finished, num_forfeited, score, round_scores }) => {
+                                (QualifierKind::Score(scoring), Qualification::Multiple { num_entered, num_finished, num_forfeited, score, round_scores }) => {
                                     @let hide_counts = !is_organizer && matches!(data.qualifier_score_hiding, QualifierScoreHiding::FullPointsCounts | QualifierScoreHiding::FullComplete) && !all_qualifiers_ended;
                                     @let hide_points = !is_organizer && matches!(data.qualifier_score_hiding, QualifierScoreHiding::FullPoints | QualifierScoreHiding::FullPointsCounts | QualifierScoreHiding::FullComplete) && !all_qualifiers_ended;
-                                    @let max_count = score_kind.max_qualifiers_that_count();
+                                    @let max_count = scoring.max_qualifiers_that_count();
                                     td(style = "text-align: right;") {
                                         @if hide_counts { : "—"; }
                                         else { : num_entered.min(max_count); }
                                     }
-                                    td(style = "text-align: right;") {
-                                        @if hide_counts { : "—"; }
-                                        else {
-                                            : num_finished.min(max_count);
-                                            @if num_forfeited > 0 {
-                                                : format!(" ({})", num_forfeited);
+                                    @if scoring.require_finishes {
+                                        td(style = "text-align: right;") {
+                                            @if hide_counts { : "—"; }
+                                            else {
+                                                : num_finished.min(max_count);
+                                                @if num_forfeited > 0 {
+                                                    : format!(" ({})", num_forfeited);
+                                                }
                                             }
                                         }
                                     }
@@ -1535,7 +1435,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                         } else if round_scores.is_empty() {
                                             : format!("{score:.2}");
                                         } else {
-                                            @let has_pending = round_scores.iter().take(score_kind.max_qualifiers_that_count()).any(|rs| rs.score < r64(0.0));
+                                            @let has_pending = round_scores.iter().take(scoring.max_qualifiers_that_count()).any(|rs| rs.score < r64(0.0));
                                             details(class = "round-breakdown") {
                                                 summary {
                                                     : format!("{score:.2}");
@@ -1544,7 +1444,7 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                                     }
                                                 }
                                                 div(class = "round-scores") {
-                                                    @for (i, round_score) in round_scores.iter().take(score_kind.max_qualifiers_that_count()).enumerate() {
+                                                    @for (i, round_score) in round_scores.iter().take(scoring.max_qualifiers_that_count()).enumerate() {
                                                         div(style = "font-size: 0.85em;") {
                                                             : format!("Race {} ({}): ", i + 1, round_score.source);
                                                             @if round_score.score < r64(0.0) {
@@ -1561,10 +1461,6 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                         }
                                     }
                                 }
-                                (QualifierKind::Score(QualifierScoreKind::Sgl2023Online | QualifierScoreKind::Sgl2024Online), Qualification::Multiple { num_entered, num_finished: _, score, .. }) => {
-                                    td(style = "text-align: right;") : num_entered;
-                                    td(style = "text-align: right;") : format!("{score:.2}");
-                                }
                                 (_, _) => @unreachable
                             }
                             @match show_status {
@@ -1587,7 +1483,9 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                                         enter::Requirement::YesNo { .. } => {}
                                                         enter::Requirement::BooleanChoice { .. } => {}
                                                         enter::Requirement::RadioChoice { .. } => {}
+                                                        enter::Requirement::Dropdown { .. } => {}
                                                         enter::Requirement::Rules { .. } => {}
+                                                        enter::Requirement::RulesChecklist { .. } => {}
                                                         enter::Requirement::Poll { .. } => {}
                                                         enter::Requirement::RestreamConsent { .. } => {}
                                                         enter::Requirement::Qualifier { .. } => {} //TODO
@@ -1656,6 +1554,8 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                                             }
                                                         };
                                                         enter::Requirement::RslLeaderboard => {}
+                                                        enter::Requirement::RaceTimeFinishes { .. } => {}
+                                                        enter::Requirement::AccountAge { .. } => {}
                                                         enter::Requirement::External { .. } => {}
                                                     }
                                                 }
@@ -1699,6 +1599,11 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                                                 _ => {}
                                             }
                                         }
+                                        enter::Requirement::Dropdown { key, options, .. } => td {
+                                            @if let Some(selected) = custom_choices.get(key) {
+                                                : enter::dropdown_labels(options, selected).join(", ");
+                                            }
+                                        }
                                         _ => {}
                                     }
                                 }
@@ -1773,6 +1678,19 @@ pub(crate) async fn list(pool: &PgPool, http_client: &reqwest::Client, me: Optio
                 }
             }
         }
+        @if !waitlisted.is_empty() {
+            h2 : "Waitlist";
+            p {
+                : "This event is full. ";
+                : teams_label;
+                : " on the waitlist are promoted in this order as spots open up.";
+            }
+            ol {
+                @for team in &waitlisted {
+                    li : team.to_html(&mut transaction, false).await?;
+                }
+            }
+        }
         }
     };
     Ok(page(transaction, &me, &uri, PageStyle { chests: data.chests().await?, ..PageStyle::default() }, &format!("{teams_label} — {}", data.display_name), content).await?)
@@ -0,0 +1,294 @@
+//! Signup capacity limits for events with a maximum number of entrants.
+//!
+//! Once an event is full, further signups are added to a waitlist in the order they come in. When a spot opens up because a team resigns, opts out, or is
+//! removed, the next waitlisted team whose members have all confirmed is promoted and has to confirm its spot before a deadline, otherwise the spot is passed on.
+
+use crate::{
+    event::{
+        Data,
+        StatusContext,
+    },
+    prelude::*,
+};
+
+/// Locks the event's row until the end of the transaction.
+///
+/// This serializes signups and promotions for the event, so concurrent transactions can't both fill the same free spot.
+pub(crate) async fn lock(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>) -> sqlx::Result<()> {
+    sqlx::query!("SELECT 1 AS locked FROM events WHERE series = $1 AND event = $2 FOR UPDATE", data.series as _, &data.event).fetch_optional(&mut **transaction).await?;
+    Ok(())
+}
+
+/// Whether the event has reached its maximum number of entrants.
+///
+/// Signups should call [`lock`] first so the result stays accurate until they're committed.
+pub(crate) async fn is_full(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>) -> sqlx::Result<bool> {
+    let Some(max_entrants) = data.max_entrants else { return Ok(false) };
+    Ok(num_entrants(transaction, data).await? >= i64::from(max_entrants))
+}
+
+/// The number of entrants counting toward the event's maximum.
+///
+/// Teams which have resigned, are waitlisted, or have a member who opted out don't count toward the limit.
+async fn num_entrants(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>) -> sqlx::Result<i64> {
+    sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM teams WHERE
+        series = $1
+        AND event = $2
+        AND NOT resigned
+        AND waitlisted_at IS NULL
+        AND NOT EXISTS (SELECT 1 FROM team_members, users, opt_outs WHERE
+            team_members.team = teams.id
+            AND users.id = team_members.member
+            AND opt_outs.series = teams.series
+            AND opt_outs.event = teams.event
+            AND (opt_outs.user_id = users.id OR opt_outs.racetime_id = users.racetime_id)
+        )
+    "#, data.series as _, &data.event).fetch_one(&mut **transaction).await
+}
+
+/// The waitlisted teams to promote into the free spots.
+///
+/// `waitlist` is in signup order, with whether all of each team's members have confirmed. Teams with unconfirmed members are skipped until they're complete.
+fn teams_to_promote<T>(free_spots: usize, waitlist: impl IntoIterator<Item = (T, bool)>) -> Vec<T> {
+    waitlist.into_iter().filter_map(|(team, confirmed)| confirmed.then_some(team)).take(free_spots).collect()
+}
+
+/// Whether a promoted team can no longer confirm its spot.
+fn deadline_expired(deadline: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    deadline <= now
+}
+
+/// Waitlisted teams for this event, in the order they will be promoted.
+pub(crate) async fn teams(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>) -> sqlx::Result<Vec<Team>> {
+    sqlx::query_as!(Team, r#"SELECT id AS "id: Id<Teams>", series AS "series: Series", event, name, racetime_slug, startgg_id AS "startgg_id: startgg::ID", challonge_id, plural_name, restream_consent, mw_impl AS "mw_impl: mw::Impl", qualifier_rank FROM teams WHERE
+        series = $1
+        AND event = $2
+        AND NOT resigned
+        AND waitlisted_at IS NOT NULL
+        ORDER BY waitlisted_at
+    "#, data.series as _, &data.event).fetch_all(&mut **transaction).await
+}
+
+/// The team's position on the waitlist, starting at 1, or `None` if it's not waitlisted.
+pub(crate) async fn position(transaction: &mut Transaction<'_, Postgres>, team: Id<Teams>) -> sqlx::Result<Option<i64>> {
+    sqlx::query_scalar!(r#"SELECT (SELECT COUNT(*) FROM teams AS earlier WHERE
+        earlier.series = teams.series
+        AND earlier.event = teams.event
+        AND NOT earlier.resigned
+        AND earlier.waitlisted_at <= teams.waitlisted_at
+    ) AS "position!" FROM teams WHERE id = $1 AND NOT resigned AND waitlisted_at IS NOT NULL"#, team as _).fetch_optional(&mut **transaction).await
+}
+
+enum Recipient {
+    User(UserId),
+    Channel(ChannelId),
+}
+
+/// Discord messages about changes to the waitlist, to be sent once the transaction making the changes has been committed.
+#[derive(Default)]
+#[must_use = "the messages should be sent after committing the transaction"]
+pub(crate) struct Messages(Vec<(Recipient, String)>);
+
+impl Messages {
+    /// Sends the messages. Errors are only logged since the changes they announce have already been committed.
+    pub(crate) async fn send(self, discord_ctx: &DiscordCtx) {
+        for (recipient, msg) in self.0 {
+            match recipient {
+                Recipient::User(user_id) => if let Ok(dm) = user_id.create_dm_channel(discord_ctx).await {
+                    let _ = dm.say(discord_ctx, msg).await;
+                },
+                Recipient::Channel(channel) => if let Err(e) = channel.say(discord_ctx, msg).await {
+                    eprintln!("failed to send waitlist message to organizer channel: {e}");
+                },
+            }
+        }
+    }
+}
+
+/// Promotes waitlisted teams for as long as the event has free spots.
+///
+/// Promoted teams are notified on the website immediately and via the returned Discord messages, and have to confirm their spot within the event's confirmation window.
+pub(crate) async fn promote(transaction: &mut Transaction<'_, Postgres>, data: &Data<'_>) -> Result<Messages, event::Error> {
+    let mut messages = Messages::default();
+    if data.is_ended() {
+        return Ok(messages)
+    }
+    lock(&mut *transaction, data).await?;
+    // if the limit has been removed, the whole waitlist is promoted
+    let free_spots = if let Some(max_entrants) = data.max_entrants {
+        usize::try_from(i64::from(max_entrants) - num_entrants(&mut *transaction, data).await?).unwrap_or_default()
+    } else {
+        usize::MAX
+    };
+    let waitlist = sqlx::query!(r#"SELECT id AS "id: Id<Teams>", NOT EXISTS (SELECT 1 FROM team_members WHERE team = id AND status = 'unconfirmed') AS "confirmed!" FROM teams WHERE
+        series = $1
+        AND event = $2
+        AND NOT resigned
+        AND waitlisted_at IS NOT NULL
+        ORDER BY waitlisted_at
+    "#, data.series as _, &data.event).fetch_all(&mut **transaction).await?;
+    for team_id in teams_to_promote(free_spots, waitlist.into_iter().map(|row| (row.id, row.confirmed))) {
+        let waitlist_deadline = sqlx::query_scalar!(r#"UPDATE teams SET waitlisted_at = NULL, waitlist_deadline = NOW() + make_interval(hours => $2) WHERE id = $1 RETURNING waitlist_deadline AS "waitlist_deadline!""#, team_id as _, data.waitlist_confirm_hours).fetch_one(&mut **transaction).await?;
+        let team = Team::from_id(&mut *transaction, team_id).await?.expect("just updated");
+        let status_url = uri!(base_uri(), super::status(data.series, &*data.event));
+        for member in team.members(&mut *transaction).await? {
+            let notification_id = Id::<Notifications>::new(&mut *transaction).await?;
+            sqlx::query!("INSERT INTO notifications (id, rcpt, kind, series, event) VALUES ($1, $2, 'waitlist_promoted', $3, $4)", notification_id as _, member.id as _, data.series as _, &data.event).execute(&mut **transaction).await?;
+            if let Some(discord) = member.discord {
+                messages.0.push((Recipient::User(UserId::new(discord.id.get())), MessageBuilder::default()
+                    .push("A spot has opened up in ")
+                    .push_safe(&data.display_name)
+                    .push(" and you have been promoted from the waitlist. Please confirm your spot by ")
+                    .push(format!("<t:{}:F>", waitlist_deadline.timestamp()))
+                    .push(" at <")
+                    .push(status_url.to_string())
+                    .push(">, otherwise it will be passed on to the next team on the waitlist.")
+                    .build()
+                ));
+            }
+        }
+        if let Some(organizer_channel) = data.discord_organizer_channel {
+            messages.0.push((Recipient::Channel(organizer_channel), MessageBuilder::default()
+                .mention_team(&mut *transaction, data.discord_guild, &team).await?
+                .push(if team.name_is_plural() { " have been promoted from the waitlist for " } else { " has been promoted from the waitlist for " })
+                .push_safe(&data.display_name)
+                .push(".")
+                .build()
+            ));
+        }
+    }
+    Ok(messages)
+}
+
+#[rocket::post("/event/<series>/<event>/waitlist/<team>/confirm", data = "<form>")]
+pub(crate) async fn confirm(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, team: Id<Teams>, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let team = Team::from_id(&mut transaction, team).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    if team.series != series || team.event != event {
+        return Err(StatusOrError::Status(Status::NotFound))
+    }
+    let members = team.members(&mut transaction).await?;
+    if !members.contains(&me) {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    if form.value.is_some() {
+        match sqlx::query_scalar!("SELECT waitlist_deadline FROM teams WHERE id = $1 AND NOT resigned", team.id as _).fetch_optional(&mut *transaction).await?.flatten() {
+            Some(deadline) => if deadline_expired(deadline, Utc::now()) {
+                form.context.push_error(form::Error::validation("The deadline to confirm your spot has passed."));
+            },
+            None => form.context.push_error(form::Error::validation("Your team doesn't need to confirm its spot.")),
+        }
+    }
+    Ok(if form.value.is_some() && form.context.errors().next().is_none() {
+        sqlx::query!("UPDATE teams SET waitlist_deadline = NULL WHERE id = $1", team.id as _).execute(&mut *transaction).await?;
+        if let Some(discord_guild) = data.discord_guild {
+            if let Some(PgSnowflake(participant_role)) = sqlx::query_scalar!(r#"SELECT id AS "id: PgSnowflake<RoleId>" FROM discord_roles WHERE guild = $1 AND series = $2 AND event = $3"#, PgSnowflake(discord_guild) as _, series as _, event).fetch_optional(&mut *transaction).await? {
+                let discord_ctx = discord_ctx.read().await;
+                for member in &members {
+                    if let Some(discord_user) = member.discord.as_ref() {
+                        if let Ok(member) = discord_guild.member(&*discord_ctx, discord_user.id).await {
+                            let _ = member.add_role(&*discord_ctx, participant_role).await;
+                        }
+                    }
+                }
+            }
+        }
+        transaction.commit().await?;
+        RedirectOrContent::Redirect(Redirect::to(uri!(super::status(series, event))))
+    } else {
+        transaction.rollback().await?;
+        RedirectOrContent::Content(event::status_page(pool.begin().await?, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::ConfirmWaitlist(form.context)).await?)
+    })
+}
+
+/// Removes promoted teams which didn't confirm their spot in time and passes their spots on to the next teams on the waitlist.
+pub(crate) async fn manager(db_pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, shutdown: rocket::Shutdown) -> Result<(), event::Error> {
+    let mut interval = tokio::time::interval(Duration::from_secs(300));
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                let discord_ctx = discord_ctx.read().await;
+                if let Err(e) = expire_deadlines(&db_pool, &discord_ctx).await {
+                    eprintln!("Error in waitlist manager: {e}");
+                }
+            }
+            _ = shutdown.clone() => break,
+        }
+    }
+    Ok(())
+}
+
+async fn expire_deadlines(db_pool: &PgPool, discord_ctx: &DiscordCtx) -> Result<(), event::Error> {
+    let events = sqlx::query!(r#"SELECT DISTINCT series AS "series: Series", event FROM teams WHERE NOT resigned AND waitlist_deadline <= NOW()"#).fetch_all(db_pool).await?;
+    for row in events {
+        // each event gets its own transaction so an error in one event doesn't hold up the others
+        match expire_event_deadlines(db_pool, row.series, &row.event).await {
+            Ok(messages) => messages.send(discord_ctx).await,
+            Err(e) => eprintln!("Error expiring waitlist deadlines for {}/{}: {e}", row.series, row.event),
+        }
+    }
+    Ok(())
+}
+
+async fn expire_event_deadlines(db_pool: &PgPool, series: Series, event: &str) -> Result<Messages, event::Error> {
+    let mut transaction = db_pool.begin().await?;
+    let mut messages = Messages::default();
+    let Some(data) = Data::new(&mut transaction, series, event).await? else { return Ok(messages) };
+    let now = Utc::now();
+    let expired = sqlx::query!(r#"SELECT id AS "id: Id<Teams>", waitlist_deadline AS "waitlist_deadline!" FROM teams WHERE series = $1 AND event = $2 AND NOT resigned AND waitlist_deadline IS NOT NULL FOR UPDATE"#, series as _, event).fetch_all(&mut *transaction).await?
+        .into_iter()
+        .filter(|row| deadline_expired(row.waitlist_deadline, now))
+        .map(|row| row.id)
+        .collect_vec();
+    for team in expired {
+        sqlx::query!("UPDATE teams SET resigned = TRUE, waitlist_deadline = NULL WHERE id = $1", team as _).execute(&mut *transaction).await?;
+        let team = Team::from_id(&mut transaction, team).await?.expect("just updated");
+        for member in team.members(&mut transaction).await? {
+            if let Some(discord) = member.discord {
+                messages.0.push((Recipient::User(UserId::new(discord.id.get())), MessageBuilder::default()
+                    .push("You didn't confirm your spot in ")
+                    .push_safe(&data.display_name)
+                    .push(" in time, so it has been passed on to the next team on the waitlist.")
+                    .build()
+                ));
+            }
+        }
+        if let Some(organizer_channel) = data.discord_organizer_channel {
+            messages.0.push((Recipient::Channel(organizer_channel), MessageBuilder::default()
+                .mention_team(&mut transaction, data.discord_guild, &team).await?
+                .push(" did not confirm the spot in ")
+                .push_safe(&data.display_name)
+                .push(if team.name_is_plural() { " after being promoted from the waitlist and have been removed." } else { " after being promoted from the waitlist and has been removed." })
+                .build()
+            ));
+        }
+    }
+    messages.0.extend(promote(&mut transaction, &data).await?.0);
+    transaction.commit().await?;
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn promotion_order() {
+        let waitlist = [(1, true), (2, false), (3, true), (4, true)];
+        assert_eq!(teams_to_promote(0, waitlist), Vec::<i32>::default());
+        assert_eq!(teams_to_promote(1, waitlist), [1]);
+        assert_eq!(teams_to_promote(2, waitlist), [1, 3]);
+        assert_eq!(teams_to_promote(5, waitlist), [1, 3, 4]);
+    }
+
+    #[test]
+    fn deadline_expiry() {
+        let now = Utc::now();
+        assert!(deadline_expired(now - TimeDelta::minutes(1), now));
+        assert!(deadline_expired(now, now));
+        assert!(!deadline_expired(now + TimeDelta::minutes(1), now));
+    }
+}
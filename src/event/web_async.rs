@@ -0,0 +1,768 @@
+//! Automated asyncs on the website, for teams who aren't on the event's Discord server or would rather not use a thread.
+//!
+//! This is the same workflow as the Discord threads in [`crate::async_race`] and uses the same state in `async_teams`, so a team can switch between the two
+//! in the middle of a run, e.g. reveal the seed in their thread and finish on the website. All timestamps are taken on the server.
+
+use {
+    serenity::all::CreateMessage,
+    crate::{
+        async_race::{
+            self,
+            AsyncRun,
+            Pause,
+            PauseStatus,
+        },
+        event::{
+            AsyncKind,
+            Data,
+            Series,
+            StatusContext,
+            Tab,
+        },
+        prelude::*,
+    },
+};
+
+/// How long a player can take back a finish, matching the revert button in Discord threads.
+const UNDO_FINISH_WINDOW: TimeDelta = TimeDelta::seconds(30);
+/// The countdown shown after clicking the start button.
+const COUNTDOWN: TimeDelta = TimeDelta::seconds(5);
+
+struct RunState {
+    discord_thread: Option<i64>,
+    web_run: bool,
+    seed_revealed_at: Option<DateTime<Utc>>,
+    start_time: Option<DateTime<Utc>>,
+    player_finished_at: Option<DateTime<Utc>>,
+    submitted: Option<DateTime<Utc>>,
+    pauses: Vec<Pause>,
+}
+
+impl RunState {
+    /// Loads the state of a team's run, starting it first if the event's force start deadline has passed.
+    async fn load(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, team: Id<Teams>, async_kind: AsyncKind) -> sqlx::Result<Option<Self>> {
+        if let Some(delay) = event.async_start_delay.filter(|&delay| delay > 0) {
+            sqlx::query!("
+                UPDATE async_teams SET start_time = seed_revealed_at + make_interval(mins => $1)
+                WHERE team = $2 AND kind = $3 AND start_time IS NULL AND seed_revealed_at + make_interval(mins => $1) <= NOW()
+            ", delay, team as _, async_kind as _).execute(&mut **transaction).await?;
+        }
+        let Some(row) = sqlx::query!("
+            SELECT discord_thread, web_run, seed_revealed_at, start_time, player_finished_at, submitted
+            FROM async_teams WHERE team = $1 AND kind = $2 AND requested IS NOT NULL
+        ", team as _, async_kind as _).fetch_optional(&mut **transaction).await? else { return Ok(None) };
+        Ok(Some(Self {
+            discord_thread: row.discord_thread,
+            web_run: row.web_run,
+            seed_revealed_at: row.seed_revealed_at,
+            start_time: row.start_time,
+            player_finished_at: row.player_finished_at,
+            submitted: row.submitted,
+            pauses: AsyncRun::Qualifier { team_id: team.into(), async_kind }.pauses(&mut **transaction).await?,
+        }))
+    }
+
+    fn is_paused(&self) -> bool {
+        self.pauses.iter().any(|pause| pause.ended_at.is_none())
+    }
+
+    /// The time between starting and clicking finish, minus approved pauses.
+    fn finish_time(&self) -> Option<TimeDelta> {
+        Some(async_race::finish_time(self.start_time?, self.player_finished_at?, &self.pauses))
+    }
+}
+
+fn pause_status_html(pause: &Pause) -> RawHtml<String> {
+    html! {
+        @match pause.status {
+            PauseStatus::Pending => : "waiting for review";
+            PauseStatus::Approved => : "deducted";
+            PauseStatus::Rejected => : "not deducted";
+        }
+    }
+}
+
+fn pauses_html(pauses: &[Pause]) -> RawHtml<String> {
+    html! {
+        @if !pauses.is_empty() {
+            h4 : "Pauses";
+            ul {
+                @for pause in pauses {
+                    li {
+                        : format_datetime(pause.started_at, DateTimeFormat { long: false, running_text: false });
+                        @if let Some(duration) = pause.duration() {
+                            : ", ";
+                            : English.format_duration(duration.to_std().unwrap_or_default(), false);
+                            : " (";
+                            : pause_status_html(pause);
+                            : ")";
+                        } else {
+                            : " (ongoing)";
+                        }
+                        @if let Some(ref reason) = pause.reason {
+                            : ": ";
+                            : reason;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The part of the event status page for a team whose automated async is in progress.
+pub(crate) async fn status_section(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, csrf: Option<&CsrfToken>, team: Id<Teams>, async_kind: AsyncKind, ctx: Context<'_>) -> Result<RawHtml<String>, event::Error> {
+    let Some(state) = RunState::load(&mut *transaction, event, team, async_kind).await? else { return Ok(RawHtml(String::default())) };
+    let now = Utc::now();
+    let mut errors = ctx.errors().collect_vec();
+    let thread_html = if let Some(thread_id) = state.discord_thread {
+        html! {
+            p {
+                : "Your async is also being handled via Discord, you can use either. ";
+                a(href = format!("https://discord.com/channels/{}/{thread_id}", event.discord_guild.map(|guild| guild.get()).unwrap_or(0))) : "Open Thread";
+            }
+        }
+    } else if !state.web_run && state.seed_revealed_at.is_none() {
+        html! {
+            p : "A Discord thread will be created for you shortly. If you can't or don't want to use Discord, you can also do your run right here.";
+        }
+    } else {
+        html! {}
+    };
+    let run_html = if state.seed_revealed_at.is_none() {
+        let (errors, button) = button_form_ext(uri!(act(event.series, &*event.event)), csrf, mem::take(&mut errors), html! {
+            input(type = "hidden", name = "action", value = "reveal");
+        }, "Reveal Seed");
+        html! {
+            p {
+                : "Make sure you're ready to record before revealing the seed.";
+                @if let Some(delay) = event.async_start_delay.filter(|&delay| delay > 0) {
+                    : " Once the seed is revealed, you have ";
+                    : English.format_duration(Duration::from_secs(u64::try_from(delay).expect("checked above") * 60), true);
+                    : " to start your run before it's started automatically.";
+                }
+            }
+            : errors;
+            div(class = "button-row") : button;
+        }
+    } else {
+        let seed_html = event::async_seed_html(&mut *transaction, event, async_kind).await?;
+        if let Some(start_time) = state.start_time {
+            if let Some(finish_time) = state.finish_time() {
+                let members = if let Some(team) = Team::from_id(&mut *transaction, team).await? { team.members_roles(&mut *transaction).await? } else { Vec::default() };
+                let can_undo = state.player_finished_at.is_some_and(|finished| now - finished < UNDO_FINISH_WINDOW);
+                let (undo_errors, undo_button) = button_form_ext(uri!(act(event.series, &*event.event)), csrf, Vec::default(), html! {
+                    input(type = "hidden", name = "action", value = "undo_finish");
+                }, "Undo Finish");
+                html! {
+                    p {
+                        : "You finished in ";
+                        : English.format_duration(finish_time.to_std().unwrap_or_default(), true);
+                        : ".";
+                    }
+                    : pauses_html(&state.pauses);
+                    @if can_undo {
+                        : undo_errors;
+                        div(class = "button-row") : undo_button;
+                    }
+                    : seed_html;
+                    p : "Please submit your VoD to complete your run.";
+                    : full_form(uri!(submit(event.series, &*event.event)), csrf, html! {
+                        @for (i, (member, _)) in members.iter().enumerate() {
+                            : form_field(&format!("vod{}", i + 1), &mut errors, html! {
+                                label(for = format!("vod{}", i + 1)) {
+                                    @if members.len() > 1 {
+                                        : member;
+                                        : "'s VoD:";
+                                    } else {
+                                        : "VoD:";
+                                    }
+                                }
+                                input(type = "text", name = format!("vod{}", i + 1), value? = ctx.field_value(&format!("vod{}", i + 1)));
+                            });
+                        }
+                        : form_field("fpa", &mut errors, html! {
+                            label(for = "fpa") : "If you invoked the Fair Play Agreement, describe the break(s) you took below. Include the reason, starting time, and duration.";
+                            textarea(name = "fpa") : ctx.field_value("fpa");
+                        });
+                    }, errors, "Submit");
+                }
+            } else if state.is_paused() {
+                let (resume_errors, resume_button) = button_form_ext(uri!(act(event.series, &*event.event)), csrf, mem::take(&mut errors), html! {
+                    input(type = "hidden", name = "action", value = "resume");
+                }, "Resume");
+                html! {
+                    p : "Your run is paused. Click resume once you continue playing. An organizer will review the pause before it is deducted from your time.";
+                    : pauses_html(&state.pauses);
+                    : resume_errors;
+                    div(class = "button-row") : resume_button;
+                }
+            } else {
+                let (finish_errors, finish_button) = button_form_ext(uri!(act(event.series, &*event.event)), csrf, mem::take(&mut errors), html! {
+                    input(type = "hidden", name = "action", value = "finish");
+                }, "Finish");
+                let (_, pause_button) = button_form_ext(uri!(act(event.series, &*event.event)), csrf, Vec::default(), html! {
+                    input(type = "hidden", name = "action", value = "pause");
+                    input(type = "text", name = "reason", placeholder = "Reason for pausing (e.g. crash)");
+                }, "Pause");
+                let (_, forfeit_button) = button_form_confirm(uri!(forfeit(event.series, &*event.event)), csrf, Vec::default(), "Forfeit", "Are you sure you want to forfeit? This can't be undone.");
+                html! {
+                    p {
+                        : "Your run starts ";
+                        : format_datetime(start_time, DateTimeFormat { long: false, running_text: true });
+                        : ". ";
+                        span(class = "async-timer", data_start = start_time.timestamp_millis().to_string());
+                    }
+                    : seed_html;
+                    : pauses_html(&state.pauses);
+                    : finish_errors;
+                    div(class = "button-row") {
+                        : finish_button;
+                        : pause_button;
+                        : forfeit_button;
+                    }
+                    script(src = static_url!("async-run.js")) {}
+                }
+            }
+        } else {
+            let (errors, button) = button_form_ext(uri!(act(event.series, &*event.event)), csrf, mem::take(&mut errors), html! {
+                input(type = "hidden", name = "action", value = "start");
+            }, "Start Countdown");
+            let force_start = event.async_start_delay.filter(|&delay| delay > 0).and_then(|delay| Some(state.seed_revealed_at? + TimeDelta::minutes(delay.into())));
+            html! {
+                : seed_html;
+                @if let Some(force_start) = force_start {
+                    p {
+                        : "Your run will be started automatically ";
+                        : format_datetime(force_start, DateTimeFormat { long: false, running_text: true });
+                        : ".";
+                    }
+                }
+                : errors;
+                div(class = "button-row") : button;
+            }
+        }
+    };
+    Ok(html! {
+        : thread_html;
+        : run_html;
+    })
+}
+
+/// Finds the team's automated async which is currently in progress.
+async fn my_run(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, me: &User) -> Result<Option<(Team, AsyncKind, RunState)>, event::Error> {
+    if !event.automated_asyncs { return Ok(None) }
+    let Some(team) = Team::from_event_and_member(&mut *transaction, event.series, &event.event, me.id).await? else { return Ok(None) };
+    let Some(async_kind) = event.active_async(&mut *transaction, Some(team.id)).await? else { return Ok(None) };
+    let Some(state) = RunState::load(&mut *transaction, event, team.id, async_kind).await? else { return Ok(None) };
+    if state.submitted.is_some() { return Ok(None) }
+    Ok(Some((team, async_kind, state)))
+}
+
+fn push_run_error(ctx: &mut Context<'_>, e: async_race::Error) -> Result<(), event::Error> {
+    let msg = match e {
+        async_race::Error::Sql(e) => return Err(e.into()),
+        async_race::Error::Discord(e) => return Err(e.into()),
+        async_race::Error::UrlParse(e) => return Err(e.into()),
+        async_race::Error::Event(e) => return Err(e),
+        async_race::Error::Cal(e) => return Err(e.into()),
+        async_race::Error::DiscordBot(e) => return Err(e.into()),
+        async_race::Error::UnauthorizedUser => "You are not a member of this team.",
+        async_race::Error::AlreadyStarted => "Your run has already started.",
+        async_race::Error::NotStarted => "Your run hasn't started yet.",
+        async_race::Error::AlreadyFinished => "You have already finished this run.",
+        async_race::Error::Paused => "Your run is paused. Please resume it first.",
+        async_race::Error::NotPaused => "Your run isn't paused.",
+        async_race::Error::EventNotFound | async_race::Error::NoTeamFound | async_race::Error::NoTeamMembers | async_race::Error::InvalidAsyncPart | async_race::Error::NoSeedAvailable | async_race::Error::UnsupportedSeedType | async_race::Error::AlreadyReady | async_race::Error::ResetAsyncPart => "Something went wrong with your run. Please contact the organizers.",
+    };
+    ctx.push_error(form::Error::validation(msg));
+    Ok(())
+}
+
+/// Tells the organizers about a run which was completed on the website, since there is no thread for them to follow along in.
+async fn notify_organizers(transaction: &mut Transaction<'_, Postgres>, discord_ctx: &DiscordCtx, event: &Data<'_>, team: &Team, async_kind: AsyncKind, result: Option<TimeDelta>, vods: &[String]) -> Result<(), event::Error> {
+    let Some(channel) = event.discord_organizer_channel else { return Ok(()) };
+    let mut msg = MessageBuilder::default();
+    msg.push(async_kind.display_name());
+    msg.push(": ");
+    msg.mention_team(&mut *transaction, event.discord_guild, team).await?;
+    if let Some(result) = result {
+        msg.push(" finished on the website with a time of ");
+        msg.push(English.format_duration(result.to_std().unwrap_or_default(), false));
+    } else {
+        msg.push(" forfeited on the website");
+    }
+    msg.push_line(".");
+    for vod in vods {
+        msg.push_line_safe(vod);
+    }
+    channel.send_message(discord_ctx, CreateMessage::default().content(msg.build()).flags(MessageFlags::SUPPRESS_EMBEDS)).await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromFormField)]
+pub(crate) enum RunAction {
+    Reveal,
+    Start,
+    Finish,
+    #[field(value = "undo_finish")]
+    UndoFinish,
+    Pause,
+    Resume,
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct ActForm {
+    #[field(default = String::new())]
+    csrf: String,
+    action: RunAction,
+    #[field(default = String::new())]
+    reason: String,
+}
+
+#[rocket::post("/event/<series>/<event>/async/run", data = "<form>")]
+pub(crate) async fn act(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, ActForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if let Some((team, async_kind, state)) = my_run(&mut transaction, &data, &me).await? {
+            let run = AsyncRun::Qualifier { team_id: team.id.into(), async_kind };
+            // the run state is shared with the Discord bot, so updates are made outside of this transaction like the bot does
+            transaction.commit().await?;
+            match value.action {
+                RunAction::Reveal => if let Err(e) = run.verify_member(pool, me.id).await {
+                    push_run_error(&mut form.context, e)?;
+                } else {
+                    sqlx::query!("UPDATE async_teams SET seed_revealed_at = COALESCE(seed_revealed_at, NOW()), web_run = discord_thread IS NULL WHERE team = $1 AND kind = $2", team.id as _, async_kind as _).execute(&**pool).await?;
+                },
+                RunAction::Start => if let Err(e) = run.verify_member(pool, me.id).await {
+                    push_run_error(&mut form.context, e)?;
+                } else if state.seed_revealed_at.is_none() {
+                    form.context.push_error(form::Error::validation("Please reveal the seed first."));
+                } else if sqlx::query!("UPDATE async_teams SET start_time = $1 WHERE team = $2 AND kind = $3 AND start_time IS NULL", Utc::now() + COUNTDOWN, team.id as _, async_kind as _).execute(&**pool).await?.rows_affected() == 0 {
+                    // checked in the same query since a teammate might be starting the run in Discord at the same time
+                    form.context.push_error(form::Error::validation("Your run has already started."));
+                },
+                RunAction::Finish => if let Err(e) = run.verify_member(pool, me.id).await {
+                    push_run_error(&mut form.context, e)?;
+                } else if let Err(e) = run.check_finish_allowed(pool).await {
+                    push_run_error(&mut form.context, e)?;
+                } else if state.start_time.is_none_or(|start_time| start_time > Utc::now()) {
+                    form.context.push_error(form::Error::validation("Your run hasn't started yet."));
+                } else if let Err(e) = run.set_player_finished_at(pool).await {
+                    push_run_error(&mut form.context, e)?;
+                },
+                RunAction::UndoFinish => if let Err(e) = run.verify_member(pool, me.id).await {
+                    push_run_error(&mut form.context, e)?;
+                } else if state.player_finished_at.is_none_or(|finished| Utc::now() - finished >= UNDO_FINISH_WINDOW) {
+                    form.context.push_error(form::Error::validation("Your finish can no longer be undone. Please contact the organizers if you need a correction."));
+                } else {
+                    sqlx::query!("UPDATE async_teams SET player_finished_at = NULL WHERE team = $1 AND kind = $2 AND submitted IS NULL", team.id as _, async_kind as _).execute(&**pool).await?;
+                },
+                RunAction::Pause => if let Err(e) = run.verify_member(pool, me.id).await {
+                    push_run_error(&mut form.context, e)?;
+                } else if let Err(e) = run.start_pause(pool, Some(value.reason.trim()).filter(|reason| !reason.is_empty())).await {
+                    push_run_error(&mut form.context, e)?;
+                },
+                RunAction::Resume => if let Err(e) = run.verify_member(pool, me.id).await {
+                    push_run_error(&mut form.context, e)?;
+                } else {
+                    match run.end_pause(pool).await {
+                        Ok(pause_id) => if let Some(channel) = data.discord_organizer_channel {
+                            let mut transaction = pool.begin().await?;
+                            let pause = run.pauses(&mut *transaction).await?.into_iter().find(|pause| pause.id == pause_id);
+                            let mut msg = MessageBuilder::default();
+                            msg.push(async_kind.display_name());
+                            msg.push(": ");
+                            msg.mention_team(&mut transaction, data.discord_guild, &team).await?;
+                            msg.push(" logged a pause of ");
+                            msg.push(English.format_duration(pause.as_ref().and_then(Pause::duration).and_then(|duration| duration.to_std().ok()).unwrap_or_default(), false));
+                            if let Some(reason) = pause.as_ref().and_then(|pause| pause.reason.as_deref()) {
+                                msg.push(" (");
+                                msg.push_safe(reason);
+                                msg.push(")");
+                            }
+                            msg.push(" on the website. Please review it at <");
+                            msg.push(format!("{}{}", base_uri(), uri!(runs(series, event))));
+                            msg.push(">.");
+                            transaction.commit().await?;
+                            channel.say(&*discord_ctx.read().await, msg.build()).await?;
+                        },
+                        Err(e) => push_run_error(&mut form.context, e)?,
+                    }
+                },
+            }
+            if form.context.errors().next().is_some() {
+                RedirectOrContent::Content(event::status_page(pool.begin().await?, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::WebAsync(form.context)).await?)
+            } else {
+                RedirectOrContent::Redirect(Redirect::to(uri!(event::status(series, event))))
+            }
+        } else {
+            form.context.push_error(form::Error::validation("You don't have an async in progress."));
+            RedirectOrContent::Content(event::status_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::WebAsync(form.context)).await?)
+        }
+    } else {
+        RedirectOrContent::Content(event::status_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::WebAsync(form.context)).await?)
+    })
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct SubmitForm {
+    #[field(default = String::new())]
+    csrf: String,
+    #[field(default = String::new())]
+    vod1: String,
+    #[field(default = String::new())]
+    vod2: String,
+    #[field(default = String::new())]
+    vod3: String,
+    #[field(default = String::new())]
+    fpa: String,
+}
+
+#[rocket::post("/event/<series>/<event>/async/run/submit", data = "<form>")]
+pub(crate) async fn submit(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, SubmitForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        let run = my_run(&mut transaction, &data, &me).await?;
+        let mut vods = HashMap::default();
+        let mut finish_time = None;
+        if let Some((ref team, _, ref state)) = run {
+            finish_time = state.finish_time();
+            if finish_time.is_none() {
+                form.context.push_error(form::Error::validation("You haven't finished your run yet."));
+            }
+            for (i, ((member, _), vod)) in team.members_roles(&mut transaction).await?.into_iter().zip([&value.vod1, &value.vod2, &value.vod3]).enumerate() {
+                let vod = vod.trim();
+                if vod.is_empty() {
+                    form.context.push_error(form::Error::validation("Please submit a link to your VoD.").with_name(format!("vod{}", i + 1)));
+                } else {
+                    vods.insert(member.id, vod.to_owned());
+                }
+            }
+        } else {
+            form.context.push_error(form::Error::validation("You don't have an async in progress."));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(event::status_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::WebAsync(form.context)).await?)
+        } else {
+            let (team, async_kind, _) = run.expect("validated");
+            let finish_time = finish_time.expect("validated");
+            async_race::record_qualifier_result(&mut transaction, &team, async_kind, Some(finish_time.to_std().expect("finish time is clamped to zero")), &vods).await?;
+            let fpa = Some(value.fpa.trim()).filter(|fpa| !fpa.is_empty());
+            sqlx::query!("UPDATE async_teams SET fpa = $1 WHERE team = $2 AND kind = $3", fpa, team.id as _, async_kind as _).execute(&mut *transaction).await?;
+            notify_organizers(&mut transaction, &*discord_ctx.read().await, &data, &team, async_kind, Some(finish_time), &vods.into_values().collect_vec()).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(event::status(series, event))))
+        }
+    } else {
+        RedirectOrContent::Content(event::status_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::WebAsync(form.context)).await?)
+    })
+}
+
+#[rocket::post("/event/<series>/<event>/async/run/forfeit", data = "<form>")]
+pub(crate) async fn forfeit(pool: &State<PgPool>, http_client: &State<reqwest::Client>, discord_ctx: &State<RwFuture<DiscordCtx>>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, EmptyForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let data = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if form.value.is_some() {
+        if let Some((team, async_kind, _)) = my_run(&mut transaction, &data, &me).await? {
+            async_race::record_qualifier_result(&mut transaction, &team, async_kind, None, &HashMap::default()).await?;
+            notify_organizers(&mut transaction, &*discord_ctx.read().await, &data, &team, async_kind, None, &[]).await?;
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(event::status(series, event))))
+        } else {
+            form.context.push_error(form::Error::validation("You don't have an async in progress."));
+            RedirectOrContent::Content(event::status_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::WebAsync(form.context)).await?)
+        }
+    } else {
+        RedirectOrContent::Content(event::status_page(transaction, http_client, Some(me), uri, csrf.as_ref(), data, StatusContext::WebAsync(form.context)).await?)
+    })
+}
+
+struct TeamRun {
+    team: Id<Teams>,
+    kind: AsyncKind,
+    requested: DateTime<Utc>,
+    discord_thread: Option<i64>,
+    web_run: bool,
+    seed_revealed_at: Option<DateTime<Utc>>,
+    start_time: Option<DateTime<Utc>>,
+    player_finished_at: Option<DateTime<Utc>>,
+    submitted: Option<DateTime<Utc>>,
+    finish_time: Option<sqlx::postgres::types::PgInterval>,
+}
+
+async fn runs_page(mut transaction: Transaction<'_, Postgres>, me: User, uri: Origin<'_>, csrf: Option<&CsrfToken>, event: Data<'_>, ctx: Option<Context<'_>>) -> Result<RawHtml<String>, event::Error> {
+    let header = event.header(&mut transaction, Some(&me), Tab::Asyncs, true).await?;
+    let runs = sqlx::query_as!(TeamRun, r#"
+        SELECT
+            team AS "team: Id<Teams>",
+            kind AS "kind: AsyncKind",
+            requested AS "requested!",
+            discord_thread,
+            web_run,
+            seed_revealed_at,
+            start_time,
+            player_finished_at,
+            submitted,
+            finish_time
+        FROM async_teams JOIN teams ON teams.id = async_teams.team
+        WHERE teams.series = $1 AND teams.event = $2 AND requested IS NOT NULL
+        ORDER BY kind, submitted IS NOT NULL, requested
+    "#, event.series as _, &event.event).fetch_all(&mut *transaction).await?;
+    let errors = ctx.as_ref().map(|ctx| ctx.errors().collect_vec()).unwrap_or_default();
+    let mut rows = Vec::with_capacity(runs.len());
+    for run in runs {
+        let Some(team) = Team::from_id(&mut transaction, run.team).await? else { continue };
+        let team_html = team.to_html(&mut transaction, false).await?;
+        // errors from a failed override are shown on the form that was submitted
+        let run_errors = if ctx.as_ref().is_some_and(|ctx| ctx.field_value("team") == Some(&*run.team.to_string()) && ctx.field_value("kind") == Some(&*format!("{:?}", run.kind))) {
+            errors.clone()
+        } else {
+            Vec::default()
+        };
+        let pauses = AsyncRun::Qualifier { team_id: run.team.into(), async_kind: run.kind }.pauses(&mut *transaction).await?;
+        rows.push((run, team_html, pauses, run_errors));
+    }
+    Ok(page(transaction, &Some(me), &uri, PageStyle { chests: event.chests().await?, ..PageStyle::default() }, &format!("Async Runs — {}", event.display_name), html! {
+        : header;
+        h2 : "Async runs";
+        p : "Runs done on the website and in Discord threads are listed here. Resetting a run lets the team start over with the same seed; setting a result ends the run with the given time, or as a forfeit if the time is left blank. Pauses marked as deducted are subtracted from times recorded by the website and suggested when confirming a result in Discord.";
+        @if rows.is_empty() {
+            p : "No team has requested an async yet.";
+        } else {
+            table {
+                thead {
+                    tr {
+                        th : "Async";
+                        th : "Team";
+                        th : "Requested";
+                        th : "Via";
+                        th : "State";
+                        th : "Override";
+                    }
+                }
+                tbody {
+                    @for (run, team_html, pauses, run_errors) in rows {
+                        tr {
+                            td : run.kind.display_name();
+                            td : team_html;
+                            td : format_datetime(run.requested, DateTimeFormat { long: false, running_text: false });
+                            td {
+                                @if run.discord_thread.is_some() {
+                                    : "Discord";
+                                } else if run.web_run {
+                                    : "website";
+                                } else {
+                                    : "not started";
+                                }
+                            }
+                            td {
+                                @if run.submitted.is_some() {
+                                    @if let Some(time) = run.finish_time.clone().and_then(|time| decode_pginterval(time).ok()) {
+                                        : "Finished in ";
+                                        : English.format_duration(time, false);
+                                    } else {
+                                        : "Forfeited";
+                                    }
+                                } else if let (Some(start_time), Some(finished)) = (run.start_time, run.player_finished_at) {
+                                    : "Finished in ";
+                                    : English.format_duration(async_race::finish_time(start_time, finished, &pauses).to_std().expect("finish time is clamped to zero"), false);
+                                    : ", waiting for VoD";
+                                } else if let Some(start_time) = run.start_time {
+                                    : "Started ";
+                                    : format_datetime(start_time, DateTimeFormat { long: false, running_text: true });
+                                } else if let Some(seed_revealed_at) = run.seed_revealed_at {
+                                    : "Seed revealed ";
+                                    : format_datetime(seed_revealed_at, DateTimeFormat { long: false, running_text: true });
+                                } else {
+                                    : "Waiting for the team";
+                                }
+                                @for pause in &pauses {
+                                    br;
+                                    : "Paused ";
+                                    : format_datetime(pause.started_at, DateTimeFormat { long: false, running_text: true });
+                                    @if let Some(duration) = pause.duration() {
+                                        : " for ";
+                                        : English.format_duration(duration.to_std().unwrap_or_default(), false);
+                                        : " (";
+                                        : pause_status_html(pause);
+                                        : ")";
+                                    } else {
+                                        : " (ongoing)";
+                                    }
+                                    @if let Some(ref reason) = pause.reason {
+                                        : ": ";
+                                        : reason;
+                                    }
+                                    @if pause.ended_at.is_some() {
+                                        @for (status, label) in [(PauseStatus::Approved, "Deduct"), (PauseStatus::Rejected, "Don't deduct")] {
+                                            @if pause.status != status {
+                                                : button_form_ext(uri!(review_pause(event.series, &*event.event)), csrf, Vec::default(), html! {
+                                                    input(type = "hidden", name = "team", value = run.team.to_string());
+                                                    input(type = "hidden", name = "kind", value = format!("{:?}", run.kind));
+                                                    input(type = "hidden", name = "pause", value = pause.id.to_string());
+                                                    input(type = "hidden", name = "approve", value = (status == PauseStatus::Approved).to_string());
+                                                }, label).1;
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            td {
+                                : full_form(uri!(runs_post(event.series, &*event.event)), csrf, html! {
+                                    input(type = "hidden", name = "team", value = run.team.to_string());
+                                    input(type = "hidden", name = "kind", value = format!("{:?}", run.kind));
+                                    input(type = "text", name = "time", placeholder = "Time (blank for forfeit)");
+                                    input(type = "text", name = "vod", placeholder = "VoD (optional)");
+                                    select(name = "action") {
+                                        option(value = "set_result") : "Set result";
+                                        option(value = "reset") : "Reset run";
+                                    }
+                                }, run_errors, "Save");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }).await?)
+}
+
+async fn check_organizer(transaction: &mut Transaction<'_, Postgres>, event: &Data<'_>, me: &User) -> Result<(), StatusOrError<event::Error>> {
+    if !event.automated_asyncs {
+        return Err(StatusOrError::Status(Status::NotFound))
+    }
+    if !me.is_global_admin() && !event.organizers(&mut *transaction).await?.contains(me) {
+        return Err(StatusOrError::Status(Status::Forbidden))
+    }
+    Ok(())
+}
+
+#[rocket::get("/event/<series>/<event>/asyncs/runs")]
+pub(crate) async fn runs(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str) -> Result<RawHtml<String>, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    check_organizer(&mut transaction, &event, &me).await?;
+    Ok(runs_page(transaction, me, uri, csrf.as_ref(), event, None).await?)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromFormField)]
+pub(crate) enum OverrideAction {
+    #[field(value = "set_result")]
+    SetResult,
+    Reset,
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct OverrideForm {
+    #[field(default = String::new())]
+    csrf: String,
+    team: Id<Teams>,
+    kind: AsyncKind,
+    #[field(default = String::new())]
+    time: String,
+    #[field(default = String::new())]
+    vod: String,
+    action: OverrideAction,
+}
+
+#[rocket::post("/event/<series>/<event>/asyncs/runs", data = "<form>")]
+pub(crate) async fn runs_post(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, OverrideForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    check_organizer(&mut transaction, &event, &me).await?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        let team = Team::from_id(&mut transaction, value.team).await?.filter(|team| team.series == event.series && team.event == event.event);
+        if team.is_none() {
+            form.context.push_error(form::Error::validation("There is no such team in this event."));
+        }
+        let time = if value.action == OverrideAction::SetResult && !value.time.trim().is_empty() {
+            let time = parse_duration(&value.time, None);
+            if time.is_none() {
+                form.context.push_error(form::Error::validation("Duration must be formatted like '1:23:45' or '1h 23m 45s'.").with_name("time"));
+            }
+            time
+        } else {
+            None
+        };
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(runs_page(transaction, me, uri, csrf.as_ref(), event, Some(form.context)).await?)
+        } else {
+            let team = team.expect("validated");
+            match value.action {
+                OverrideAction::SetResult => {
+                    let vod = value.vod.trim();
+                    let vods = if vod.is_empty() {
+                        HashMap::default()
+                    } else {
+                        team.member_ids(&mut transaction).await?.into_iter().map(|member| (member, vod.to_owned())).collect()
+                    };
+                    async_race::record_qualifier_result(&mut transaction, &team, value.kind, time, &vods).await?;
+                }
+                OverrideAction::Reset => {
+                    sqlx::query!("
+                        UPDATE async_teams SET seed_revealed_at = NULL, start_time = NULL, player_finished_at = NULL, finish_time = NULL, submitted = NULL, fpa = NULL
+                        WHERE team = $1 AND kind = $2
+                    ", team.id as _, value.kind as _).execute(&mut *transaction).await?;
+                    sqlx::query!("DELETE FROM async_pauses WHERE team = $1 AND kind = $2", team.id as _, value.kind as _).execute(&mut *transaction).await?;
+                    sqlx::query!("
+                        DELETE FROM async_players
+                        WHERE series = $1 AND event = $2 AND kind = $3 AND player IN (SELECT member FROM team_members WHERE team = $4)
+                    ", event.series as _, &event.event, value.kind as _, team.id as _).execute(&mut *transaction).await?;
+                }
+            }
+            transaction.commit().await?;
+            RedirectOrContent::Redirect(Redirect::to(uri!(runs(series, &*event.event))))
+        }
+    } else {
+        RedirectOrContent::Content(runs_page(transaction, me, uri, csrf.as_ref(), event, Some(form.context)).await?)
+    })
+}
+
+#[derive(FromForm, CsrfForm)]
+pub(crate) struct PauseReviewForm {
+    #[field(default = String::new())]
+    csrf: String,
+    team: Id<Teams>,
+    kind: AsyncKind,
+    pause: i64,
+    approve: bool,
+}
+
+#[rocket::post("/event/<series>/<event>/asyncs/runs/pause", data = "<form>")]
+pub(crate) async fn review_pause(pool: &State<PgPool>, me: User, uri: Origin<'_>, csrf: Option<CsrfToken>, series: Series, event: &str, form: Form<Contextual<'_, PauseReviewForm>>) -> Result<RedirectOrContent, StatusOrError<event::Error>> {
+    let mut transaction = pool.begin().await?;
+    let event = Data::new(&mut transaction, series, event).await?.ok_or(StatusOrError::Status(Status::NotFound))?;
+    check_organizer(&mut transaction, &event, &me).await?;
+    let mut form = form.into_inner();
+    form.verify(&csrf);
+    Ok(if let Some(ref value) = form.value {
+        if Team::from_id(&mut transaction, value.team).await?.is_none_or(|team| team.series != event.series || team.event != event.event) {
+            form.context.push_error(form::Error::validation("There is no such team in this event."));
+        }
+        if form.context.errors().next().is_some() {
+            RedirectOrContent::Content(runs_page(transaction, me, uri, csrf.as_ref(), event, Some(form.context)).await?)
+        } else {
+            let status = if value.approve { PauseStatus::Approved } else { PauseStatus::Rejected };
+            if AsyncRun::Qualifier { team_id: value.team.into(), async_kind: value.kind }.review_pause(&mut transaction, value.pause, status, me.id).await?.is_none() {
+                form.context.push_error(form::Error::validation("This pause doesn't exist or hasn't ended yet."));
+                RedirectOrContent::Content(runs_page(transaction, me, uri, csrf.as_ref(), event, Some(form.context)).await?)
+            } else {
+                transaction.commit().await?;
+                RedirectOrContent::Redirect(Redirect::to(uri!(runs(series, &*event.event))))
+            }
+        }
+    } else {
+        RedirectOrContent::Content(runs_page(transaction, me, uri, csrf.as_ref(), event, Some(form.context)).await?)
+    })
+}
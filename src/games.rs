@@ -2,7 +2,7 @@ use crate::{
     game::{Game, GameError},
     prelude::*,
     user::User,
-    event::roles::{GameRoleBinding, RoleType, RoleRequest, RoleRequestStatus, render_language_tabs, render_language_content_box_start, render_language_content_box_end, assign_event_override_discord_roles, remove_event_override_discord_roles},
+    event::roles::{GameRoleBinding, RoleType, RoleRequest, RoleRequestStatus, render_language_tabs, render_language_content_box_start, render_language_content_box_end},
     http::{PageError, StatusOrError},
     form::{form_field, full_form, full_form_confirm, button_form, button_form_confirm},
     id::{RoleBindings, RoleRequests, RoleTypes},
@@ -10,59 +10,10 @@ use crate::{
     series::Series,
     volunteer_requests,
     volunteer_pings,
+    discord_role_manager,
 };
 use rocket::{uri, form::{Form, Contextual}};
 
-pub(crate) async fn assign_game_discord_role(
-    transaction: &mut Transaction<'_, Postgres>,
-    discord_ctx: &DiscordCtx,
-    game: &Game,
-    role_binding_id: Id<RoleBindings>,
-    discord_user_id: UserId,
-) -> sqlx::Result<()> {
-    let Some(discord_guild) = game.discord_guild else { return Ok(()) };
-    let discord_role_id = sqlx::query_scalar!(
-        r#"SELECT discord_role_id FROM role_bindings WHERE id = $1 AND game_id = $2"#,
-        role_binding_id as _,
-        game.id,
-    )
-    .fetch_optional(&mut **transaction)
-    .await?
-    .flatten();
-    let Some(discord_role_id) = discord_role_id else { return Ok(()) };
-    if let Ok(member) = discord_guild.member(discord_ctx, discord_user_id).await {
-        if let Err(e) = member.add_role(discord_ctx, RoleId::new(discord_role_id.try_into().unwrap())).await {
-            eprintln!("Failed to assign game Discord role {} to user {} in guild {}: {}", discord_role_id, discord_user_id, discord_guild, e);
-        }
-    }
-    Ok(())
-}
-
-pub(crate) async fn remove_game_discord_role(
-    transaction: &mut Transaction<'_, Postgres>,
-    discord_ctx: &DiscordCtx,
-    game: &Game,
-    role_binding_id: Id<RoleBindings>,
-    discord_user_id: UserId,
-) -> sqlx::Result<()> {
-    let Some(discord_guild) = game.discord_guild else { return Ok(()) };
-    let discord_role_id = sqlx::query_scalar!(
-        r#"SELECT discord_role_id FROM role_bindings WHERE id = $1 AND game_id = $2"#,
-        role_binding_id as _,
-        game.id,
-    )
-    .fetch_optional(&mut **transaction)
-    .await?
-    .flatten();
-    let Some(discord_role_id) = discord_role_id else { return Ok(()) };
-    if let Ok(member) = discord_guild.member(discord_ctx, discord_user_id).await {
-        if let Err(e) = member.remove_role(discord_ctx, RoleId::new(discord_role_id.try_into().unwrap())).await {
-            eprintln!("Failed to remove game Discord role {} from user {} in guild {}: {}", discord_role_id, discord_user_id, discord_guild, e);
-        }
-    }
-    Ok(())
-}
-
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum Error {
     #[error(transparent)]
@@ -1075,29 +1026,9 @@ pub(crate) async fn apply_for_game_role(
         ).await.map_err(Error::from)?;
 
         // Send Discord notification for non-auto-approve roles, and assign override discord roles if auto-approved
+        let auto_approved = role_binding.is_some_and(|binding| binding.auto_approve);
         if let Some(binding) = role_binding {
-            if binding.auto_approve {
-                if let Some(discord_user) = me.discord.as_ref() {
-                    let discord_ctx_guard = discord_ctx.read().await;
-                    if let Err(e) = assign_game_discord_role(
-                        &mut transaction,
-                        &*discord_ctx_guard,
-                        &game,
-                        value.role_binding_id,
-                        discord_user.id,
-                    ).await {
-                        eprintln!("Failed to assign game Discord role for game binding: {}", e);
-                    }
-                    if let Err(e) = assign_event_override_discord_roles(
-                        &mut transaction,
-                        &*discord_ctx_guard,
-                        value.role_binding_id,
-                        discord_user.id,
-                    ).await {
-                        eprintln!("Failed to assign event override Discord roles for game binding: {}", e);
-                    }
-                }
-            } else if !binding.auto_approve {
+            if !binding.auto_approve {
                 if let Ok(Some((_guild_id, channel_id))) = game.notification_channel(&mut transaction, binding.language).await {
                     let discord_ctx = discord_ctx.read().await;
                     let mut msg = MessageBuilder::default();
@@ -1130,6 +1061,11 @@ pub(crate) async fn apply_for_game_role(
         }
 
         transaction.commit().await.map_err(Error::from)?;
+        if auto_approved {
+            if let Some(discord_user) = me.discord.as_ref() {
+                discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, None, &[discord_user.id]).await;
+            }
+        }
         let redirect_path = match lang {
             Some(language) => format!("/games/{}?lang={}", game_name, language.short_code()),
             None => format!("/games/{}", game_name),
@@ -1202,28 +1138,10 @@ pub(crate) async fn forfeit_game_role(
             // Update the status to aborted
             RoleRequest::update_status(&mut transaction, request.id, RoleRequestStatus::Aborted).await.map_err(Error::from)?;
 
+            transaction.commit().await.map_err(Error::from)?;
             if let Some(discord_user) = me.discord.as_ref() {
-                let discord_ctx_guard = discord_ctx.read().await;
-                if let Err(e) = remove_game_discord_role(
-                    &mut transaction,
-                    &*discord_ctx_guard,
-                    &game,
-                    value.role_binding_id,
-                    discord_user.id,
-                ).await {
-                    eprintln!("Failed to remove game Discord role on forfeit: {}", e);
-                }
-                if let Err(e) = remove_event_override_discord_roles(
-                    &mut transaction,
-                    &*discord_ctx_guard,
-                    value.role_binding_id,
-                    discord_user.id,
-                ).await {
-                    eprintln!("Failed to remove event override Discord roles on forfeit: {}", e);
-                }
+                discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, None, &[discord_user.id]).await;
             }
-
-            transaction.commit().await.map_err(Error::from)?;
             RedirectOrContent::Redirect(Redirect::to(uri!(get(game_name, _))))
         } else {
             form.context.push_error(form::Error::validation(
@@ -1430,30 +1348,12 @@ pub(crate) async fn approve_game_role_request(
         // Update the role request status
         RoleRequest::update_status(&mut transaction, request, RoleRequestStatus::Approved).await.map_err(Error::from)?;
 
-        if let Some(user) = User::from_id(&mut *transaction, role_request.user_id).await.map_err(Error::from)? {
-            if let Some(discord_user) = user.discord {
-                let discord_ctx = discord_ctx.read().await;
-                if let Err(e) = assign_game_discord_role(
-                    &mut transaction,
-                    &*discord_ctx,
-                    &game,
-                    role_request.role_binding_id,
-                    discord_user.id,
-                ).await {
-                    eprintln!("Failed to assign game Discord role for approved request: {}", e);
-                }
-                if let Err(e) = assign_event_override_discord_roles(
-                    &mut transaction,
-                    &*discord_ctx,
-                    role_request.role_binding_id,
-                    discord_user.id,
-                ).await {
-                    eprintln!("Failed to assign event override Discord roles for approved game request: {}", e);
-                }
-            }
-        }
+        let discord_user = User::from_id(&mut *transaction, role_request.user_id).await.map_err(Error::from)?.and_then(|user| user.discord);
 
         transaction.commit().await.map_err(Error::from)?;
+        if let Some(discord_user) = discord_user {
+            discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, None, &[discord_user.id]).await;
+        }
     }
 
     let redirect_url = format!("/games/{}/roles?msg={}", game_name, urlencoding::encode("Role request approved successfully."));
@@ -1555,30 +1455,12 @@ pub(crate) async fn revoke_game_role_request(
         // Update the role request status to Aborted
         RoleRequest::update_status(&mut transaction, request, RoleRequestStatus::Aborted).await.map_err(Error::from)?;
 
-        if let Some(user) = User::from_id(&mut *transaction, role_request.user_id).await.map_err(Error::from)? {
-            if let Some(discord_user) = user.discord {
-                let discord_ctx = discord_ctx.read().await;
-                if let Err(e) = remove_game_discord_role(
-                    &mut transaction,
-                    &*discord_ctx,
-                    &game,
-                    role_request.role_binding_id,
-                    discord_user.id,
-                ).await {
-                    eprintln!("Failed to remove game Discord role for revoked request: {}", e);
-                }
-                if let Err(e) = remove_event_override_discord_roles(
-                    &mut transaction,
-                    &*discord_ctx,
-                    role_request.role_binding_id,
-                    discord_user.id,
-                ).await {
-                    eprintln!("Failed to remove event override Discord roles for revoked game request: {}", e);
-                }
-            }
-        }
+        let discord_user = User::from_id(&mut *transaction, role_request.user_id).await.map_err(Error::from)?.and_then(|user| user.discord);
 
         transaction.commit().await.map_err(Error::from)?;
+        if let Some(discord_user) = discord_user {
+            discord_role_manager::reconcile_members(pool, &*discord_ctx.read().await, None, &[discord_user.id]).await;
+        }
     }
 
     let redirect_url = format!("/games/{}/roles?msg={}", game_name, urlencoding::encode("Role assignment revoked."));
@@ -801,18 +801,33 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         cal::for_event,
         cal::create_race,
         cal::create_race_post,
+        event::race_upload::get,
+        event::race_upload::post,
+        event::sheet_import::get,
+        event::sheet_import::post,
+        event::sheet_import::delete,
+        event::ics_import::get,
+        event::ics_import::post,
+        event::ics_import::delete,
         cal::import_races,
         cal::import_races_post,
         cal::import_races_status,
         cal::practice_seed,
         cal::edit_race,
         cal::edit_race_post,
+        cal::revert_race_change,
+        cal::forfeit_race,
         cal::add_file_hash,
         cal::add_file_hash_post,
         event::info,
         event::races,
+        event::race_list::csv,
+        event::race_list::json,
         event::status,
         event::status_post,
+        event::web_async::act,
+        event::web_async::submit,
+        event::web_async::forfeit,
         event::find_team,
         event::find_team_post,
         event::confirm_signup,
@@ -821,6 +836,7 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         event::opt_out,
         event::opt_out_post,
         event::status_opt_out,
+        event::waitlist::confirm,
         event::manage_team,
         event::manage_team_post,
         event::manage_team_choices_post,
@@ -833,16 +849,37 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         event::practice_seed_post,
         event::practice_seed_status,
         event::swiss_standings,
+        event::bracket::get,
+        event::bracket::create,
+        event::bracket::best_of,
+        event::stages::get,
+        event::stages::add,
+        event::stages::start_stage,
+        event::stages::delete,
         event::enter::get,
         event::enter::post,
         event::teams::get,
+        event::entrant::get,
+        event::substitutes::get,
+        event::substitutes::add_alternate,
+        event::substitutes::accept_alternate,
+        event::substitutes::remove_alternate,
+        event::substitutes::request,
+        event::substitutes::decide,
         event::async_results::get,
+        event::qualifier_projection::get,
+        event::async_verification::get,
+        event::async_verification::review,
         event::asyncs::get,
         event::asyncs::post,
         event::asyncs::delete,
+        event::web_async::runs,
+        event::web_async::runs_post,
+        event::web_async::review_pause,
         event::qualifiers::get,
         event::qualifiers::post_race,
         event::qualifiers::post_settings,
+        event::qualifiers::post_scoring,
         event::qualifiers::post_notification_role,
         event::qualifiers::delete_notification_role,
         event::qualifiers::get_edit,
@@ -915,6 +952,7 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         event::setup::remove_organizer,
         event::setup::copy_organizers,
         event::setup::update_enter_flow,
+        event::setup::enter_flow_preview,
         event::setup::search_users,
         favicon::favicon_ico,
         favicon::favicon_png,
@@ -976,6 +1014,9 @@ pub(crate) async fn rocket(pool: PgPool, discord_ctx: RwFuture<DiscordCtx>, http
         admin::edit_restream_channel_form,
         admin::update_restream_channel,
         admin::delete_restream_channel,
+        admin::discord_roles,
+        admin::discord_roles_preview,
+        admin::apply_discord_roles,
         event::zsr_export::get,
         event::zsr_export::add_export,
         event::zsr_export::edit_export,
@@ -103,6 +103,14 @@ impl Table for EventDisabledRoleBindings {
     }
 }
 
+pub(crate) enum Brackets {}
+
+impl Table for Brackets {
+    fn query_exists(id: i64) -> sqlx::query::QueryScalar<'static, Postgres, bool, <Postgres as Database>::Arguments<'static>> {
+        sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM brackets WHERE id = $1) AS "exists!""#, id)
+    }
+}
+
 #[derive(Derivative, Deserialize, Serialize)]
 #[cfg_attr(unix, derive(Protocol), async_proto(where(T: Sync)))]
 #[derivative(Debug(bound = ""), Clone(bound = ""), Copy(bound = ""), PartialEq(bound = ""), Eq(bound = ""), Hash(bound = ""), PartialOrd(bound = ""), Ord(bound = ""))]
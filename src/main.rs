@@ -42,6 +42,7 @@ mod cal;
 mod challonge;
 mod config;
 mod discord_bot;
+mod discord_localization;
 mod discord_role_manager;
 mod discord_scheduled_events;
 mod draft;
@@ -148,6 +149,7 @@ enum Error {
     #[error(transparent)] AutoImport(#[from] cal::AutoImportError),
     #[error(transparent)] Base64(#[from] base64::DecodeError),
     #[error(transparent)] Config(#[from] config::Error),
+    #[error(transparent)] Event(#[from] event::Error),
     #[cfg(unix)] #[error(transparent)] Io(#[from] io::Error),
     #[error(transparent)] Racetime(#[from] racetime_bot::MainError),
     #[cfg(unix)] #[error(transparent)] Read(#[from] async_proto::ReadError),
@@ -300,6 +302,7 @@ async fn main(Args { port, subcommand }: Args) -> Result<(), Error> {
             http_client.clone(),
             insecure_http_client,
             config.league_api_key.clone(),
+            config.challonge_api_key.clone(),
             config.startgg.clone(),
             ootr_api_client,
             discord_builder.ctx_fut.clone(),
@@ -360,6 +363,11 @@ async fn main(Args { port, subcommand }: Args) -> Result<(), Error> {
             Ok(Err(e)) => Err(Error::from(e)),
             Err(e) => Err(Error::Task(e)),
         });
+        let waitlist_task = tokio::spawn(event::waitlist::manager(db_pool.clone(), discord_builder.ctx_fut.clone(), rocket.shutdown())).map(|res| match res {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(Error::from(e)),
+            Err(e) => Err(Error::Task(e)),
+        });
         let rocket_task = tokio::spawn(rocket.launch()).map(|res| match res {
             Ok(Ok(Rocket { .. })) => Ok(()),
             Ok(Err(e)) => Err(Error::from(e)),
@@ -376,7 +384,7 @@ async fn main(Args { port, subcommand }: Args) -> Result<(), Error> {
             Err(e) => Err(Error::from(e)),
         });
         #[cfg(not(unix))] let unix_socket_task = future::ok(());
-        let ((), (), (), (), (), (), (), (), (), (), (), ()) = tokio::try_join!(discord_task, import_task, racetime_task, async_race_task, racetime_room_status_task, volunteer_request_task, zsr_export_task, speedgaming_export_task, weekly_race_task, deadline_task, rocket_task, unix_socket_task)?;
+        let ((), (), (), (), (), (), (), (), (), (), (), (), ()) = tokio::try_join!(discord_task, import_task, racetime_task, async_race_task, racetime_room_status_task, volunteer_request_task, zsr_export_task, speedgaming_export_task, weekly_race_task, deadline_task, waitlist_task, rocket_task, unix_socket_task)?;
     }
     Ok(())
 }
@@ -13,6 +13,8 @@ pub(crate) enum Error {
     #[error(transparent)] Sql(#[from] sqlx::Error),
     #[error("unknown event")]
     UnknownEvent,
+    #[error("unknown team")]
+    UnknownTeam,
     #[error("unknown user")]
     UnknownUser,
 }
@@ -23,12 +25,15 @@ pub(crate) enum SimpleNotificationKind {
     Accept,
     Decline,
     Resign,
+    WaitlistPromoted,
 }
 
 pub(crate) enum Notification {
     /// A notification from the `notifications` table that can only be dismissed
     Simple(Id<Notifications>),
     TeamInvite(Id<Teams>),
+    /// The user has been added as an alternate for a team and hasn't accepted yet.
+    AlternateInvite(Id<Teams>),
 }
 
 impl Notification {
@@ -44,6 +49,13 @@ impl Notification {
                 notifications.push(Self::TeamInvite(team_id));
             }
         }
+        for team_id in sqlx::query_scalar!(r#"SELECT team AS "team: Id<Teams>" FROM team_alternates WHERE member = $1 AND NOT confirmed"#, me.id as _).fetch_all(&mut **transaction).await? {
+            let team_row = sqlx::query!(r#"SELECT series AS "series: Series", event FROM teams WHERE id = $1"#, team_id as _).fetch_one(&mut **transaction).await?;
+            let event = event::Data::new(&mut *transaction, team_row.series, team_row.event).await?.expect("enforced by database constraint");
+            if !event.is_ended() {
+                notifications.push(Self::AlternateInvite(team_id));
+            }
+        }
         Ok(notifications)
     }
 
@@ -84,6 +96,18 @@ impl Notification {
                             : ".";
                         }
                     }
+                    SimpleNotificationKind::WaitlistPromoted => {
+                        let row = sqlx::query!(r#"SELECT series AS "series!: Series", event AS "event!" FROM notifications WHERE id = $1"#, id as _).fetch_one(&mut **transaction).await?;
+                        let event = event::Data::new(&mut *transaction, row.series, row.event).await?.ok_or(Error::UnknownEvent)?;
+                        let status_url = uri!(event::status(event.series, &*event.event)).to_string();
+                        html! {
+                            : "A spot has opened up in ";
+                            : event;
+                            : " and you have been promoted from the waitlist. Please ";
+                            a(href = status_url) : "confirm your spot";
+                            : " before the deadline.";
+                        }
+                    }
                 };
                 html! {
                     : text;
@@ -93,6 +117,18 @@ impl Notification {
                 }
             }
             Self::TeamInvite(team_id) => team_invite(transaction, me, csrf, errors, source, team_id).await?,
+            Self::AlternateInvite(team_id) => {
+                let team = Team::from_id(&mut *transaction, team_id).await?.ok_or(Error::UnknownTeam)?;
+                let event = event::Data::new(&mut *transaction, team.series, team.event.clone()).await?.ok_or(Error::UnknownEvent)?;
+                html! {
+                    : "You have been added as an alternate for ";
+                    : team.to_html(&mut *transaction, true).await?;
+                    : " in ";
+                    : event;
+                    : ". ";
+                    a(href = uri!(event::substitutes::get(team.series, &*team.event, team.id)).to_string()) : "Accept or decline";
+                }
+            }
         })
     }
 }
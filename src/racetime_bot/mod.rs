@@ -167,6 +167,94 @@ pub(crate) async fn team_member_ids(http_client: &reqwest::Client, team_slug: &s
     Ok(profile.members.into_iter().map(|member| member.id).collect())
 }
 
+#[derive(Deserialize)]
+struct UserRaces {
+    num_pages: u32,
+    races: Vec<UserRace>,
+}
+
+#[derive(Deserialize)]
+struct UserRace {
+    category: UserRaceCategory,
+    opened_at: DateTime<Utc>,
+    #[serde(default)]
+    entrants: Vec<UserRaceEntrant>,
+}
+
+#[derive(Deserialize)]
+struct UserRaceCategory {
+    slug: String,
+}
+
+#[derive(Deserialize)]
+struct UserRaceEntrant {
+    user: TeamMemberProfile,
+    status: UserRaceEntrantStatus,
+}
+
+#[derive(Deserialize)]
+struct UserRaceEntrantStatus {
+    value: EntrantStatusValue,
+}
+
+/// Returns `None` if the user's race history can't be accessed, see [`user_data`].
+async fn user_races(http_client: &reqwest::Client, user_id: &str, page: u32) -> wheel::Result<Option<UserRaces>> {
+    match http_client.get(format!("https://{}/user/{user_id}/races/data", racetime_host()))
+        .query(&[("show_entrants", "true"), ("page", &page.to_string())])
+        .send().await?
+        .detailed_error_for_status().await
+    {
+        Ok(response) => response.json_with_text_in_error().await.map(Some),
+        Err(wheel::Error::ResponseStatus { inner, .. }) if inner.status() == Some(StatusCode::NOT_FOUND) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Results of [`count_finished_races`] by user ID and category, with whether the count covers the user's entire race history.
+static FINISHED_RACES: LazyLock<Mutex<HashMap<(String, String), (Instant, usize, bool)>>> = LazyLock::new(Mutex::default);
+
+/// Counts the races the user has finished in the given racetime.gg category, stopping early once `limit` is reached.
+///
+/// Since this can take many requests for users with a long race history, results are cached.
+/// A count which reached the limit can't go down again, so it's kept, while a lower count is refreshed after an hour.
+pub(crate) async fn count_finished_races(http_client: &reqwest::Client, user_id: &str, category: &str, limit: usize) -> wheel::Result<usize> {
+    let key = (user_id.to_owned(), category.to_owned());
+    let cached = lock!(finished_races = FINISHED_RACES; finished_races.get(&key).copied());
+    if let Some((retrieved, count, complete)) = cached {
+        if count >= limit || (complete && retrieved.elapsed() < Duration::from_secs(60 * 60)) { return Ok(count) }
+    }
+    let mut count = 0;
+    let mut page = 1;
+    let mut complete = true;
+    while let Some(races) = user_races(http_client, user_id, page).await? {
+        count += races.races.iter()
+            .filter(|race| race.category.slug == category)
+            .filter(|race| race.entrants.iter().any(|entrant| entrant.user.id == user_id && entrant.status.value == EntrantStatusValue::Done))
+            .count();
+        if page >= races.num_pages { break }
+        if count >= limit {
+            complete = false;
+            break
+        }
+        page += 1;
+    }
+    lock!(finished_races = FINISHED_RACES; finished_races.insert(key, (Instant::now(), count, complete)));
+    Ok(count)
+}
+
+/// When the user's first race on racetime.gg was opened, as a lower bound for the account's age since racetime.gg doesn't expose account creation dates.
+pub(crate) async fn first_race_opened_at(http_client: &reqwest::Client, user_id: &str) -> wheel::Result<Option<DateTime<Utc>>> {
+    let Some(first_page) = user_races(http_client, user_id, 1).await? else { return Ok(None) };
+    // races are listed newest first
+    let last_page = if first_page.num_pages > 1 {
+        let Some(last_page) = user_races(http_client, user_id, first_page.num_pages).await? else { return Ok(None) };
+        last_page
+    } else {
+        first_page
+    };
+    Ok(last_page.races.iter().map(|race| race.opened_at).min())
+}
+
 pub(crate) async fn parse_user(transaction: &mut Transaction<'_, Postgres>, http_client: &reqwest::Client, id_or_url: &str) -> Result<String, ParseUserError> {
     if let Ok(id) = id_or_url.parse() {
         return if let Some(user) = User::from_id(&mut **transaction, id).await? {
@@ -1705,6 +1793,7 @@ pub(crate) struct GlobalState {
     pub(crate) http_client: reqwest::Client,
     insecure_http_client: reqwest::Client,
     league_api_key: String,
+    challonge_api_key: String,
     startgg_token: String,
     ootr_api_client: Arc<ootr_web::ApiClient>,
     pub(crate) discord_ctx: RwFuture<DiscordCtx>,
@@ -1712,6 +1801,8 @@ pub(crate) struct GlobalState {
     seed_cache_tx: watch::Sender<()>,
     seed_metadata: Arc<RwLock<HashMap<String, SeedMetadata>>>,
     pub(crate) extra_room_senders: Arc<RwLock<HashMap<String, mpsc::Sender<String>>>>,
+    /// Used to cancel rooms handled by this bot from outside the race handler, keyed by room path.
+    room_cancel_senders: Arc<RwLock<HashMap<String, mpsc::Sender<()>>>>,
     restream_team_members: Arc<RwLock<HashMap<String, (Instant, HashSet<String>)>>>,
     #[cfg_attr(not(unix), allow(dead_code))]
     avianart_api_key: Option<String>,
@@ -1730,6 +1821,7 @@ impl GlobalState {
         http_client: reqwest::Client,
         insecure_http_client: reqwest::Client,
         league_api_key: String,
+        challonge_api_key: String,
         startgg_token: String,
         ootr_api_client: Arc<ootr_web::ApiClient>,
         discord_ctx: RwFuture<DiscordCtx>,
@@ -1743,8 +1835,9 @@ impl GlobalState {
                 hostname: Cow::Borrowed(racetime_host()),
                 ..racetime::HostInfo::default()
             },
-            new_room_lock, race_import_lock, racetime_config, db_pool, http_client, insecure_http_client, league_api_key, startgg_token, ootr_api_client, discord_ctx, clean_shutdown, seed_cache_tx, seed_metadata,
+            new_room_lock, race_import_lock, racetime_config, db_pool, http_client, insecure_http_client, league_api_key, challonge_api_key, startgg_token, ootr_api_client, discord_ctx, clean_shutdown, seed_cache_tx, seed_metadata,
             extra_room_senders: Arc::new(RwLock::new(HashMap::default())),
+            room_cancel_senders: Arc::new(RwLock::new(HashMap::default())),
             restream_team_members: Arc::new(RwLock::new(HashMap::default())),
             avianart_api_key,
         }
@@ -1768,6 +1861,18 @@ impl GlobalState {
         Arc::clone(&self.race_import_lock)
     }
 
+    /// Asks the race handler for the given room to cancel the race.
+    ///
+    /// Returns `false` if the room isn't currently being handled by this bot.
+    pub(crate) async fn cancel_room(&self, room: &Url) -> bool {
+        let sender = lock!(@read senders = self.room_cancel_senders; senders.get(room.path()).cloned());
+        if let Some(sender) = sender {
+            sender.send(()).await.is_ok()
+        } else {
+            false
+        }
+    }
+
     pub(crate) fn roll_twwr_seed(self: Arc<Self>, version: Option<VersionedBranch>, settings_string: String, unlock_spoiler_log: UnlockSpoilerLog) -> mpsc::Receiver<SeedRollUpdate> {
         let (update_tx, update_rx) = mpsc::channel(128);
         tokio::spawn(async move {
@@ -3312,7 +3417,7 @@ impl SeedRollUpdate {
                     let mut mw_rooms_created = 0;
                     for team in cal_event.active_teams() {
                         if let Some(mw::Impl::MidosHouse) = team.mw_impl {
-                            let members = team.members_roles(&mut transaction).await.to_racetime()?;
+                            let members = team.members_roles_for_race(&mut transaction, cal_event.race.id).await.to_racetime()?;
                             let mut reply_to = String::default();
                             for (member, role) in &members {
                                 if event.team_config.role_is_racing(*role) {
@@ -4466,7 +4571,7 @@ impl Handler {
                             let is_active_team = if_chain! {
                                 if let Some(sender) = sender;
                                 if let Some(user) = User::from_racetime(&mut *transaction, &sender.id).await.to_racetime()?;
-                                if let Some(team) = Team::from_event_and_member(&mut transaction, event.series, &event.event, user.id).await.to_racetime()?;
+                                if let Some(team) = Team::from_race_and_member(&mut transaction, event.series, &event.event, cal_event.race.id, user.id).await.to_racetime()?;
                                 then {
                                     draft.is_active_team(draft_kind, cal_event.race.game, team.id).await.to_racetime()?
                                 } else {
@@ -4797,6 +4902,7 @@ impl RaceHandler<GlobalState> for Handler {
             lock!(@read data = race_data; println!("race handler for https://{}{} started", racetime_host(), data.url));
             let res = join_handle.await;
             lock!(@read data = race_data; {
+                lock!(@write senders = global_state.room_cancel_senders; senders.remove(&data.url));
                 lock!(clean_shutdown = global_state.clean_shutdown; {
                     let room = OpenRoom::RaceTime {
                         room_url: data.url.clone(),
@@ -4963,7 +5069,7 @@ impl RaceHandler<GlobalState> for Handler {
                                 Entrants::Open | Entrants::Count { .. } | Entrants::Named(_) => [format!("Team A"), format!("Team B")],
                                 Entrants::Two([Entrant::MidosHouseTeam(ref team1), Entrant::MidosHouseTeam(ref team2)]) => {
                                     let name1 = if_chain! {
-                                        if let Ok(member) = team1.members(&mut transaction).await.to_racetime()?.into_iter().exactly_one();
+                                        if let Ok(member) = team1.members_for_race(&mut transaction, cal_event.race.id).await.to_racetime()?.into_iter().exactly_one();
                                         if let Some(ref racetime) = member.racetime;
                                         then {
                                             racetime.display_name.clone()
@@ -4972,7 +5078,7 @@ impl RaceHandler<GlobalState> for Handler {
                                         }
                                     };
                                     let name2 = if_chain! {
-                                        if let Ok(member) = team2.members(&mut transaction).await.to_racetime()?.into_iter().exactly_one();
+                                        if let Ok(member) = team2.members_for_race(&mut transaction, cal_event.race.id).await.to_racetime()?.into_iter().exactly_one();
                                         if let Some(ref racetime) = member.racetime;
                                         then {
                                             racetime.display_name.clone()
@@ -4986,8 +5092,33 @@ impl RaceHandler<GlobalState> for Handler {
                                         [name2, name1]
                                     }
                                 }
-                                Entrants::Two([_, _]) => unimplemented!("draft with non-MH teams"),
-                                Entrants::Three([_, _, _]) => unimplemented!("draft with 3 teams"),
+                                Entrants::Three([Entrant::MidosHouseTeam(ref team1), Entrant::MidosHouseTeam(ref team2), Entrant::MidosHouseTeam(ref team3)]) => {
+                                    // the draft is between the high seed and everyone else, so the other teams share the low seed's name
+                                    let mut high_seed_name = None;
+                                    let mut other_names = Vec::with_capacity(2);
+                                    for (team, fallback) in [(team1, "Team A"), (team2, "Team B"), (team3, "Team C")] {
+                                        let name = if_chain! {
+                                            if let Ok(member) = team.members_for_race(&mut transaction, cal_event.race.id).await.to_racetime()?.into_iter().exactly_one();
+                                            if let Some(ref racetime) = member.racetime;
+                                            then {
+                                                racetime.display_name.clone()
+                                            } else {
+                                                team.name(&mut transaction).await.to_racetime()?.map_or_else(|| fallback.to_owned(), Cow::into_owned)
+                                            }
+                                        };
+                                        if team.id == state.high_seed {
+                                            high_seed_name = Some(name);
+                                        } else {
+                                            other_names.push(name);
+                                        }
+                                    }
+                                    [
+                                        high_seed_name.unwrap_or_else(|| format!("Team A")),
+                                        English.join_str_opt(other_names).unwrap_or_else(|| format!("Team B")),
+                                    ]
+                                }
+                                // only used for display, so entrants without Mido's House teams get placeholder names
+                                Entrants::Two(_) | Entrants::Three(_) => [format!("Team A"), format!("Team B")],
                             }
                         };
                         (RaceState::Draft {
@@ -6141,6 +6272,20 @@ impl RaceHandler<GlobalState> for Handler {
                 }
             });
         }
+        if this.official_data.is_some() {
+            let (cancel_tx, mut cancel_rx) = mpsc::channel(1);
+            let room_path = ctx.data().await.url.clone();
+            lock!(@write senders = ctx.global_state.room_cancel_senders; senders.insert(room_path, cancel_tx));
+            let ctx = ctx.clone();
+            tokio::spawn(async move {
+                // the sender is dropped once the race handler stops, ending this task
+                if cancel_rx.recv().await.is_some() {
+                    if let Err(e) = ctx.cancel_race().await {
+                        eprintln!("failed to cancel race room https://{}{}: {e} ({e:?})", racetime_host(), ctx.data().await.url);
+                    }
+                }
+            });
+        }
         Ok(this)
     }
 
@@ -7131,7 +7276,13 @@ impl RaceHandler<GlobalState> for Handler {
                     });
                     self.password_sent = true;
                 }
-                if let Some(OfficialRaceData { ref cal_event, ref event, .. }) = self.official_data {
+                // a forfeited race is announced and reported by the organizer action that cancelled the room
+                let forfeited = if let Some(OfficialRaceData { ref cal_event, .. }) = self.official_data {
+                    sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM race_walkovers WHERE race = $1) AS "exists!""#, cal_event.race.id as _).fetch_one(&ctx.global_state.db_pool).await.to_racetime()?
+                } else {
+                    false
+                };
+                if !forfeited && let Some(OfficialRaceData { ref cal_event, ref event, .. }) = self.official_data {
                     if let cal::Source::League { id } = cal_event.race.source {
                         let form = collect![as HashMap<_, _>:
                             "id" => id.to_string(),
@@ -7167,7 +7318,7 @@ impl RaceHandler<GlobalState> for Handler {
                         .any(|e| matches!(e.status.value, EntrantStatusValue::Invited));
                     let room_url_str = format!("https://{}{}", racetime_host(), inactivity_data.url);
                     drop(inactivity_data);
-                    if active_count < 2 && any_invited {
+                    if active_count < 2 && any_invited && !forfeited {
                         if let Some(OfficialRaceData { ref cal_event, .. }) = self.official_data {
                             sqlx::query!("UPDATE races SET room = NULL WHERE room = $1", room_url_str)
                                 .execute(&ctx.global_state.db_pool)
@@ -7412,6 +7563,7 @@ pub(crate) async fn create_room(transaction: &mut Transaction<'_, Postgres>, dis
                     cal_event.is_private_async_part() || cal_event.race.video_urls.is_empty(),
                 ).await.start_with_host(host_info, &access_token, &http_client, &category_slug).await.to_racetime()?;
                 let room_url = Url::parse(&format!("https://{}/{}/{}", host_info.hostname, category_slug, race_slug)).to_racetime()?;
+                cal::set_change_context(transaction, None, cal::ChangeSource::RaceBot).await.to_racetime()?;
                 match cal_event.kind {
                     cal::EventKind::Normal => { sqlx::query!("UPDATE races SET room = $1 WHERE id = $2", room_url.to_string(), cal_event.race.id as _).execute(&mut **transaction).await.to_racetime()?; }
                     cal::EventKind::Async1 => { sqlx::query!("UPDATE races SET async_room1 = $1 WHERE id = $2", room_url.to_string(), cal_event.race.id as _).execute(&mut **transaction).await.to_racetime()?; }
@@ -7972,7 +8124,7 @@ async fn create_rooms(global_state: Arc<GlobalState>, mut shutdown: rocket::Shut
                         // Start a new transaction for querying team members
                         let mut transaction = global_state.db_pool.begin().await?;
                         for team in cal_event.active_teams() {
-                            for member in team.members(&mut transaction).await? {
+                            for member in team.members_for_race(&mut transaction, cal_event.race.id).await? {
                                 if let Some(discord) = member.discord {
                                     match discord.id.create_dm_channel(&*ctx).await {
                                         Ok(dm) => try_discord_send(|| dm.say(&*ctx, &msg), "DM team member about async race room").await,
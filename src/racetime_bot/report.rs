@@ -259,6 +259,31 @@ async fn post_result_announcement(discord_ctx: &DiscordCtx, event: &event::Data<
     }
 }
 
+/// Posts updated standings to the event's standings channel in the background so slow start.gg responses don't hold up reporting.
+fn spawn_standings_post(global_state: &GlobalState, event: &event::Data<'_>) {
+    if event.discord_standings_channel.is_none() {
+        return
+    }
+    let db_pool = global_state.db_pool.clone();
+    let http_client = global_state.http_client.clone();
+    let discord_ctx = global_state.discord_ctx.clone();
+    let startgg_token = global_state.startgg_token.clone();
+    let series = event.series;
+    let event = event.event.to_string();
+    tokio::spawn(async move {
+        let result = async {
+            let mut transaction = db_pool.begin().await?;
+            let Some(event) = event::Data::new(&mut transaction, series, &*event).await? else { return Ok(()) };
+            event::standings::post_to_channel(&*discord_ctx.read().await, &mut transaction, &http_client, &startgg_token, &event).await?;
+            transaction.commit().await?;
+            Ok::<_, event::standings::Error>(())
+        }.await;
+        if let Err(e) = result {
+            eprintln!("failed to post standings for {}/{event}: {e} ({e:?})", series.slug());
+        }
+    });
+}
+
 async fn report_1v1<'a, S: Score>(mut transaction: Transaction<'a, Postgres>, ctx: &RaceContext<GlobalState>, cal_event: &cal::Event, event: &event::Data<'_>, mut entrants: [(Entrant, S, Url); 2]) -> Result<(Transaction<'a, Postgres>, Vec<Id<Races>>), Error> {
     entrants.sort_unstable_by_key(|(_, time, _)| time.sort_key());
     let [(winner, winning_time, winning_room), (loser, losing_time, losing_room)] = entrants;
@@ -512,11 +537,152 @@ async fn report_1v1<'a, S: Score>(mut transaction: Transaction<'a, Postgres>, ct
             };
             post_result_announcement(&*ctx.global_state.discord_ctx.read().await, event, msg).await;
         }
-        return report_external_and_init_draft(transaction, &*ctx.global_state, &cal_event.race, event, winner, None, winning_room, loser, None).await;
+        return report_external_and_init_draft(transaction, &*ctx.global_state, &cal_event.race, event, winner, None, Some(winning_room), loser, None).await;
     }
     Ok((transaction, ignored_race_ids))
 }
 
+/// Reports a race between three entrants.
+///
+/// All placements are announced. For external reporting and drafts, the race is treated as a win of the first place over the second place.
+async fn report_3way<'a, S: Score>(mut transaction: Transaction<'a, Postgres>, ctx: &RaceContext<GlobalState>, cal_event: &cal::Event, event: &event::Data<'_>, mut entrants: [(Entrant, S, Url); 3]) -> Result<(Transaction<'a, Postgres>, Vec<Id<Races>>), Error> {
+    entrants.sort_unstable_by_key(|(_, time, _)| time.sort_key());
+    let single_room = entrants.iter().all(|(_, _, room)| *room == entrants[0].2);
+    if entrants.iter().all(|(_, time, _)| time.is_dnf()) {
+        if event.discord_race_results_channel.is_some() || event.discord_organizer_channel.is_some() {
+            let mut builder = MessageBuilder::default();
+            push_info_prefix(&mut builder, &cal_event.race);
+            for (i, (entrant, _, room)) in entrants.iter().enumerate() {
+                builder.push(match i {
+                    0 => "",
+                    1 => ", ",
+                    _ => ", and ",
+                });
+                builder.mention_entrant(&mut transaction, event.discord_guild, entrant).await.to_racetime()?;
+                if !single_room {
+                    builder.push(" [<");
+                    builder.push(room.to_string());
+                    builder.push(">]");
+                }
+            }
+            builder.push(" all did not finish");
+            if single_room {
+                builder.push(" <");
+                builder.push(entrants[0].2.to_string());
+                builder.push('>');
+            }
+            post_result_announcement(&*ctx.global_state.discord_ctx.read().await, event, builder.build()).await;
+        }
+        return Ok((transaction, Vec::default()))
+    }
+    let close_finish = entrants.windows(2).any(|pair| pair[1].1.time_window(&pair[0].1).is_some_and(|time_window| time_window <= event.retime_window));
+    if close_finish {
+        if let Some(organizer_channel) = event.discord_organizer_channel {
+            let mut msg = MessageBuilder::default();
+            msg.push("Race");
+            let discord_ctx = ctx.global_state.discord_ctx.read().await;
+            let mut names = Vec::with_capacity(3);
+            for (entrant, _, _) in &entrants {
+                if let Some(name) = entrant.name(&mut transaction, &*discord_ctx).await.to_racetime()? {
+                    names.push(name.into_owned());
+                }
+            }
+            let mut matchup_parts = Vec::new();
+            if names.len() == 3 {
+                matchup_parts.push(names.join(" vs. "));
+            }
+            match (&cal_event.race.phase, &cal_event.race.round) {
+                (Some(phase), Some(round)) => matchup_parts.push(format!("{phase} - {round}")),
+                (Some(phase), None) => matchup_parts.push(phase.clone()),
+                (None, Some(round)) => matchup_parts.push(round.clone()),
+                (None, None) => {}
+            }
+            if !matchup_parts.is_empty() {
+                msg.push(" (");
+                msg.push(matchup_parts.join(", "));
+                msg.push(")");
+            }
+            msg.push(" finished too close for automatic reporting:");
+            for room in entrants.iter().map(|(_, _, room)| room).unique() {
+                msg.push(" <");
+                msg.push(room.to_string());
+                msg.push('>');
+            }
+            msg.push(" — please check the results");
+            if event.discord_race_results_channel.is_some() || matches!(cal_event.race.source, cal::Source::StartGG { .. }) {
+                msg.push(" and manually ");
+                if let Some(results_channel) = event.discord_race_results_channel {
+                    msg.push("post the announcement in ");
+                    msg.mention(&results_channel);
+                }
+                if let Some(startgg_set_url) = cal_event.race.startgg_set_url().to_racetime()? {
+                    if event.discord_race_results_channel.is_some() {
+                        msg.push(" and ");
+                    }
+                    msg.push_named_link_no_preview("report the result on start.gg", startgg_set_url);
+                }
+            }
+            say_with_retry(&*discord_ctx, organizer_channel, msg.build()).await?;
+        }
+        return Ok((transaction, Vec::default()))
+    }
+    if event.discord_race_results_channel.is_some() || event.discord_organizer_channel.is_some() {
+        let mut builder = MessageBuilder::default();
+        push_info_prefix(&mut builder, &cal_event.race);
+        for (i, (entrant, time, room)) in entrants.iter().enumerate() {
+            match i {
+                0 => {}
+                1 => { builder.push(if entrants[0].0.name_is_plural() { " finish ahead of " } else { " finishes ahead of " }); }
+                _ => { builder.push(" and "); }
+            }
+            builder.mention_entrant(&mut transaction, event.discord_guild, entrant).await.to_racetime()?;
+            builder.push(" (");
+            builder.push(time.format(English));
+            builder.push(')');
+            if !single_room {
+                builder.push(" [<");
+                builder.push(room.to_string());
+                builder.push(">]");
+            }
+        }
+        if single_room {
+            builder.push(" <");
+            builder.push(entrants[0].2.to_string());
+            builder.push('>');
+        }
+        post_result_announcement(&*ctx.global_state.discord_ctx.read().await, event, builder.build()).await;
+    }
+    let [(winner, winning_time, winning_room), (runner_up, runner_up_time, _), _] = entrants;
+    report_external_and_init_draft(transaction, &*ctx.global_state, &cal_event.race, event, winner, winning_time.as_duration().flatten(), Some(winning_room), runner_up, runner_up_time.as_duration().flatten()).await
+}
+
+fn push_info_prefix(builder: &mut MessageBuilder, race: &Race) {
+    let info_prefix = match (&race.phase, &race.round) {
+        (Some(phase), Some(round)) => Some(format!("{phase} {round}")),
+        (Some(phase), None) => Some(phase.clone()),
+        (None, Some(round)) => Some(round.clone()),
+        (None, None) => None,
+    };
+    match (info_prefix, race.game) {
+        (Some(prefix), Some(game)) => {
+            builder.push_safe(prefix);
+            builder.push(", game ");
+            builder.push(game.to_string());
+            builder.push(": ");
+        }
+        (Some(prefix), None) => {
+            builder.push_safe(prefix);
+            builder.push(": ");
+        }
+        (None, Some(game)) => {
+            builder.push("game ");
+            builder.push(game.to_string());
+            builder.push(": ");
+        }
+        (None, None) => {}
+    }
+}
+
 pub(crate) async fn complete_1v1_result<'a>(
     mut transaction: Transaction<'a, Postgres>,
     global_state: &GlobalState,
@@ -575,30 +741,7 @@ pub(crate) async fn complete_1v1_result<'a>(
                 builder.build()
             } else {
                 let mut builder = MessageBuilder::default();
-                let info_prefix = match (&race.phase, &race.round) {
-                    (Some(phase), Some(round)) => Some(format!("{phase} {round}")),
-                    (Some(phase), None) => Some(phase.clone()),
-                    (None, Some(round)) => Some(round.clone()),
-                    (None, None) => None,
-                };
-                match (info_prefix, race.game) {
-                    (Some(prefix), Some(game)) => {
-                        builder.push_safe(prefix);
-                        builder.push(", game ");
-                        builder.push(game.to_string());
-                        builder.push(": ");
-                    }
-                    (Some(prefix), None) => {
-                        builder.push_safe(prefix);
-                        builder.push(": ");
-                    }
-                    (None, Some(game)) => {
-                        builder.push("game ");
-                        builder.push(game.to_string());
-                        builder.push(": ");
-                    }
-                    (None, None) => {}
-                }
+                push_info_prefix(&mut builder, race);
                 builder.mention_entrant(&mut transaction, event.discord_guild, &winner).await.to_racetime()?;
                 builder.push(" (");
                 builder.push(fmt_time(winner_time, English));
@@ -621,7 +764,137 @@ pub(crate) async fn complete_1v1_result<'a>(
         post_result_announcement(&*global_state.discord_ctx.read().await, event, msg).await;
     }
 
-    report_external_and_init_draft(transaction, global_state, race, event, winner, winner_time, winning_room, loser, loser_time).await
+    report_external_and_init_draft(transaction, global_state, race, event, winner, winner_time, Some(winning_room), loser, loser_time).await
+}
+
+/// How the result of a forfeited race is passed on after it's been announced, see [`report_forfeit`].
+#[derive(Debug, PartialEq, Eq)]
+enum ForfeitFollowUp<T> {
+    /// The race has a single winner and loser, so it's reported like a race that was played.
+    ReportWinner {
+        winner: T,
+        loser: T,
+    },
+    /// The result can't be reported automatically, so the organizers are asked to report it.
+    ManualReport,
+    /// A game in a Hyrule Town Hall bracket has no winner, so the organizers have to decide how the match continues.
+    UndecidedBracketGame,
+    /// There's no winner but also nothing to report.
+    Nothing,
+}
+
+fn forfeit_follow_up<'a, T>(winner: Option<&'a T>, losers: &'a [T], source: &cal::Source, match_source: &MatchSource<'_>) -> ForfeitFollowUp<&'a T> {
+    if let Some(winner) = winner && let [loser] = losers {
+        ForfeitFollowUp::ReportWinner { winner, loser }
+    } else {
+        match source {
+            cal::Source::Manual => if let MatchSource::Bracket = match_source { ForfeitFollowUp::UndecidedBracketGame } else { ForfeitFollowUp::Nothing },
+            cal::Source::Sheet { .. } | cal::Source::SheetRow { .. } | cal::Source::Ics { .. } | cal::Source::SpeedGaming { .. } => ForfeitFollowUp::Nothing,
+            cal::Source::Challonge { .. } | cal::Source::League { .. } | cal::Source::StartGG { .. } => ForfeitFollowUp::ManualReport,
+        }
+    }
+}
+
+/// Announces and reports a race recorded using [`cal::record_forfeit`], then commits the transaction.
+///
+/// Any racetime.gg rooms of the race are cancelled after committing so the race handlers can tell the race was forfeited.
+pub(crate) async fn report_forfeit(mut transaction: Transaction<'_, Postgres>, global_state: &GlobalState, race: &Race, event: &event::Data<'_>, kind: cal::ForfeitKind, winner: Option<&Team>, reason: Option<&str>) -> Result<(), Error> {
+    let losers = race.teams().filter(|team| winner.is_none_or(|winner| team.id != winner.id)).cloned().collect_vec();
+    let mut manual_report_needed = false;
+    let mut undecided_bracket_game = false;
+    let mut ignored_race_ids = Vec::default();
+
+    // 1. Post Discord announcement
+    if event.discord_race_results_channel.is_some() || event.discord_organizer_channel.is_some() {
+        let mut builder = MessageBuilder::default();
+        push_info_prefix(&mut builder, race);
+        if let Some(winner) = winner {
+            builder.mention_team(&mut transaction, event.discord_guild, winner).await.to_racetime()?;
+            builder.push(if winner.name_is_plural() { " win" } else { " wins" });
+            builder.push(match kind {
+                cal::ForfeitKind::Walkover => " by walkover against ",
+                cal::ForfeitKind::Disqualification => " by disqualification of ",
+                cal::ForfeitKind::DoubleForfeit => unreachable!("double forfeit with a winner"),
+            });
+        }
+        for (idx, loser) in losers.iter().enumerate() {
+            if idx > 0 {
+                builder.push(if idx == losers.len() - 1 { " and " } else { ", " });
+            }
+            builder.mention_team(&mut transaction, event.discord_guild, loser).await.to_racetime()?;
+        }
+        if winner.is_none() {
+            builder.push(" forfeited");
+        }
+        if let Some(reason) = reason {
+            builder.push(" (");
+            builder.push_safe(reason);
+            builder.push(')');
+        }
+        post_result_announcement(&*global_state.discord_ctx.read().await, event, builder.build()).await;
+    }
+
+    // 2. Report to start.gg, Challonge, the League website, or the bracket engine
+    match forfeit_follow_up(winner, &losers, &race.source, &event.match_source()) {
+        ForfeitFollowUp::ReportWinner { winner, loser } => {
+            if let (cal::Source::Challonge { ref id }, MatchSource::Challonge { community, tournament }) = (&race.source, event.match_source()) {
+                if let (None, Some(winner_id), Some(loser_id)) = (race.game, &winner.challonge_id, &loser.challonge_id) {
+                    challonge::report_winner(&global_state.http_client, &global_state.challonge_api_key, community, tournament, id, winner_id, loser_id).await.to_racetime()?;
+                } else {
+                    manual_report_needed = true;
+                }
+            } else if let cal::Source::League { .. } = race.source {
+                // the League website only accepts results with a racetime.gg room
+                manual_report_needed = true;
+            }
+            let (t, ids) = report_external_and_init_draft(transaction, global_state, race, event, Entrant::MidosHouseTeam(winner.clone()), None, None, Entrant::MidosHouseTeam(loser.clone()), None).await?;
+            transaction = t;
+            ignored_race_ids = ids;
+        }
+        ForfeitFollowUp::ManualReport => manual_report_needed = true,
+        ForfeitFollowUp::UndecidedBracketGame => undecided_bracket_game = true,
+        ForfeitFollowUp::Nothing => {}
+    }
+    transaction.commit().await.to_racetime()?;
+    if !ignored_race_ids.is_empty() {
+        let discord_ctx = global_state.discord_ctx.read().await;
+        for race_id in ignored_race_ids {
+            let _ = crate::volunteer_requests::update_volunteer_post_for_race(
+                &global_state.db_pool,
+                &discord_ctx,
+                race_id,
+            ).await;
+        }
+    }
+
+    // 3. Close racetime.gg rooms
+    let mut open_rooms = Vec::default();
+    for room in race.cal_events().filter_map(|cal_event| cal_event.room().cloned()) {
+        if !global_state.cancel_room(&room).await {
+            open_rooms.push(room);
+        }
+    }
+    if (manual_report_needed || undecided_bracket_game || !open_rooms.is_empty()) && let Some(organizer_channel) = event.discord_organizer_channel {
+        let mut msg = MessageBuilder::default();
+        msg.push("A ");
+        msg.push(kind.display_name());
+        msg.push(" was recorded for race ");
+        msg.push(race.id.to_string());
+        msg.push('.');
+        if manual_report_needed {
+            msg.push(" The result could not be reported automatically, please report it manually.");
+        }
+        if undecided_bracket_game {
+            msg.push(" Since no team won, the bracket match can't be advanced automatically, please decide how it continues.");
+        }
+        for room in open_rooms {
+            msg.push(" Please cancel <");
+            msg.push(room.to_string());
+            msg.push("> if it's still open.");
+        }
+        say_with_retry(&*global_state.discord_ctx.read().await, organizer_channel, msg.build()).await?;
+    }
+    Ok(())
 }
 
 fn partition_entries_for_race<S: Score + Clone>(
@@ -685,7 +958,7 @@ async fn report_external_and_init_draft<'a>(
     event: &event::Data<'_>,
     winner: Entrant,
     winner_time: Option<Duration>,
-    winning_room: Url,
+    winning_room: Option<Url>,
     loser: Entrant,
     loser_time: Option<Duration>,
 ) -> Result<(Transaction<'a, Postgres>, Vec<Id<Races>>), Error> {
@@ -693,15 +966,25 @@ async fn report_external_and_init_draft<'a>(
     let mut series_decided = false;
     let mut standings_changed = false;
     match race.source {
-        cal::Source::Manual | cal::Source::Sheet { .. } => {}
+        cal::Source::Manual => if let MatchSource::Bracket = event.match_source() {
+            let (t, recorded) = event::bracket::record_game(transaction, &*global_state.discord_ctx.read().await, &global_state.http_client, event, race, &winner).await.to_racetime()?;
+            transaction = t;
+            if let Some(recorded) = recorded {
+                ignored_race_ids = recorded.ignored_races;
+                series_decided = recorded.match_decided;
+                standings_changed = recorded.match_decided;
+            }
+        },
+        cal::Source::Sheet { .. } | cal::Source::SheetRow { .. } | cal::Source::Ics { .. } => {}
         cal::Source::Challonge { .. } => {} //TODO
-        cal::Source::League { id } => if let (Some(winner_rt), Some(loser_rt)) = (
+        cal::Source::League { id } => if let (Some(winning_room), Some(winner_rt), Some(loser_rt)) = (
+            &winning_room,
             match &winner {
-                Entrant::MidosHouseTeam(team) => team.members(&mut transaction).await.to_racetime()?.into_iter().exactly_one().ok().and_then(|member| member.racetime).map(|racetime| racetime.id),
+                Entrant::MidosHouseTeam(team) => team.members_for_race(&mut transaction, race.id).await.to_racetime()?.into_iter().exactly_one().ok().and_then(|member| member.racetime).map(|racetime| racetime.id),
                 Entrant::Discord { racetime_id, .. } | Entrant::Named { racetime_id, .. } => racetime_id.clone(),
             },
             match &loser {
-                Entrant::MidosHouseTeam(team) => team.members(&mut transaction).await.to_racetime()?.into_iter().exactly_one().ok().and_then(|member| member.racetime).map(|racetime| racetime.id),
+                Entrant::MidosHouseTeam(team) => team.members_for_race(&mut transaction, race.id).await.to_racetime()?.into_iter().exactly_one().ok().and_then(|member| member.racetime).map(|racetime| racetime.id),
                 Entrant::Discord { racetime_id, .. } | Entrant::Named { racetime_id, .. } => racetime_id.clone(),
             },
         ) {
@@ -842,16 +1125,26 @@ async fn report_external_and_init_draft<'a>(
                 }
             } else if let Some(organizer_channel) = event.discord_organizer_channel {
                 let mut msg = MessageBuilder::default();
-                msg.push("failed to report race result to start.gg: <");
-                msg.push(winning_room.to_string());
-                msg.push("> (winner has no start.gg entrant ID)");
+                msg.push("failed to report race result to start.gg: ");
+                if let Some(ref winning_room) = winning_room {
+                    msg.push('<');
+                    msg.push(winning_room.to_string());
+                    msg.push('>');
+                } else {
+                    msg.push("race ");
+                    msg.push(race.id.to_string());
+                }
+                msg.push(" (winner has no start.gg entrant ID)");
                 say_with_retry(&*global_state.discord_ctx.read().await, organizer_channel, msg.build()).await?;
             }
         },
         cal::Source::SpeedGaming { .. } => {}
     }
 
-    if standings_changed && event.swiss_standings {
+    if standings_changed && event.discord_standings_channel.is_some() {
+        // also refreshes the cached Swiss standings
+        spawn_standings_post(global_state, event);
+    } else if standings_changed && event.swiss_standings {
         if let MatchSource::StartGG(event_slug) = event.match_source() {
             startgg::refresh_swiss_standings(
                 global_state.http_client.clone(),
@@ -939,6 +1232,9 @@ async fn report_ffa(ctx: &RaceContext<GlobalState>, cal_event: &cal::Event, even
         builder.push('>');
         post_result_announcement(&*ctx.global_state.discord_ctx.read().await, event, builder.build()).await;
     }
+    if cal_event.race.phase.as_deref() == Some("Qualifier") {
+        spawn_standings_post(&ctx.global_state, event);
+    }
     Ok(())
 }
 
@@ -974,6 +1270,7 @@ impl Handler {
         };
         sleep(stream_delay).await;
         let mut transaction = ctx.global_state.db_pool.begin().await.to_racetime()?;
+        cal::set_change_context(&mut transaction, None, cal::ChangeSource::RaceBot).await.to_racetime()?;
         if let Some(ended_at) = data.ended_at {
             match cal_event.kind {
                 cal::EventKind::Normal => {
@@ -1068,7 +1365,7 @@ impl Handler {
             entrants.sort_by_key(|(_, t)| (t.is_none(), *t));
             for (rank, (rt_id, _)) in entrants.iter().enumerate() {
                 if let Some(user) = User::from_racetime(&mut *transaction, rt_id).await.to_racetime()? {
-                    if let Some(team) = Team::from_event_and_member(&mut transaction, event.series, &event.event, user.id).await.to_racetime()? {
+                    if let Some(team) = Team::from_race_and_member(&mut transaction, event.series, &event.event, cal_event.race.id, user.id).await.to_racetime()? {
                         sqlx::query!("UPDATE teams SET qualifier_rank = $1 WHERE id = $2", rank as i16 + 1, team.id as _)
                             .execute(&mut *transaction).await.to_racetime()?;
                     }
@@ -1096,7 +1393,7 @@ impl Handler {
                                 if let Some(rt_user) = &entrant.user {
                                     let mapped = if_chain! {
                                         if let Some(user) = User::from_racetime(&mut *transaction, &rt_user.id).await.to_racetime()?;
-                                        if let Some(team) = Team::from_event_and_member(&mut transaction, event.series, &event.event, user.id).await.to_racetime()?;
+                                        if let Some(team) = Team::from_race_and_member(&mut transaction, event.series, &event.event, cal_event.race.id, user.id).await.to_racetime()?;
                                         then {
                                             Entrant::MidosHouseTeam(team)
                                         } else {
@@ -1121,7 +1418,7 @@ impl Handler {
                                 if let Some(rt_user) = &entrant.user {
                                     entries.push((if_chain! {
                                         if let Some(user) = User::from_racetime(&mut *transaction, &rt_user.id).await.to_racetime()?;
-                                        if let Some(team) = Team::from_event_and_member(&mut transaction, event.series, &event.event, user.id).await.to_racetime()?;
+                                        if let Some(team) = Team::from_race_and_member(&mut transaction, event.series, &event.event, cal_event.race.id, user.id).await.to_racetime()?;
                                         then {
                                             Entrant::MidosHouseTeam(team)
                                         } else {
@@ -1222,7 +1519,7 @@ impl Handler {
                                 if let Some(rt_user) = &entrant.user {
                                     teams.push((if_chain! {
                                         if let Some(user) = User::from_racetime(&mut *transaction, &rt_user.id).await.to_racetime()?;
-                                        if let Some(team) = Team::from_event_and_member(&mut transaction, event.series, &event.event, user.id).await.to_racetime()?;
+                                        if let Some(team) = Team::from_race_and_member(&mut transaction, event.series, &event.event, cal_event.race.id, user.id).await.to_racetime()?;
                                         then {
                                             Entrant::MidosHouseTeam(team)
                                         } else {
@@ -1235,12 +1532,19 @@ impl Handler {
                                     }, tfb_scores.remove(&rt_user.id).expect("missing TFB score"), room.clone()));
                                 }
                             }
-                            if let Ok(teams) = teams.try_into() {
-                                let (t, ids) = report_1v1(transaction, ctx, cal_event, event, teams).await?;
-                                transaction = t;
-                                ignored_race_ids = ids;
-                            } else { //TODO separate function for reporting 3-entrant results
-                                report_ffa(ctx, cal_event, event, room).await?;
+                            match <[_; 2]>::try_from(teams) {
+                                Ok(teams) => {
+                                    let (t, ids) = report_1v1(transaction, ctx, cal_event, event, teams).await?;
+                                    transaction = t;
+                                    ignored_race_ids = ids;
+                                }
+                                Err(teams) => if let Ok(teams) = <[_; 3]>::try_from(teams) {
+                                    let (t, ids) = report_3way(transaction, ctx, cal_event, event, teams).await?;
+                                    transaction = t;
+                                    ignored_race_ids = ids;
+                                } else {
+                                    report_ffa(ctx, cal_event, event, room).await?;
+                                },
                             }
                         } else {
                             let mut teams = Vec::with_capacity(data.entrants.len());
@@ -1248,7 +1552,7 @@ impl Handler {
                                 if let Some(rt_user) = &entrant.user {
                                     teams.push((if_chain! {
                                         if let Some(user) = User::from_racetime(&mut *transaction, &rt_user.id).await.to_racetime()?;
-                                        if let Some(team) = Team::from_event_and_member(&mut transaction, event.series, &event.event, user.id).await.to_racetime()?;
+                                        if let Some(team) = Team::from_race_and_member(&mut transaction, event.series, &event.event, cal_event.race.id, user.id).await.to_racetime()?;
                                         then {
                                             Entrant::MidosHouseTeam(team)
                                         } else {
@@ -1261,12 +1565,19 @@ impl Handler {
                                     }, entrant.finish_time, room.clone()));
                                 }
                             }
-                            if let Ok(teams) = teams.try_into() {
-                                let (t, ids) = report_1v1(transaction, ctx, cal_event, event, teams).await?;
-                                transaction = t;
-                                ignored_race_ids = ids;
-                            } else { //TODO separate function for reporting 3-entrant results
-                                report_ffa(ctx, cal_event, event, room).await?;
+                            match <[_; 2]>::try_from(teams) {
+                                Ok(teams) => {
+                                    let (t, ids) = report_1v1(transaction, ctx, cal_event, event, teams).await?;
+                                    transaction = t;
+                                    ignored_race_ids = ids;
+                                }
+                                Err(teams) => if let Ok(teams) = <[_; 3]>::try_from(teams) {
+                                    let (t, ids) = report_3way(transaction, ctx, cal_event, event, teams).await?;
+                                    transaction = t;
+                                    ignored_race_ids = ids;
+                                } else {
+                                    report_ffa(ctx, cal_event, event, room).await?;
+                                },
                             }
                         }
                     }
@@ -1330,14 +1641,19 @@ impl Handler {
                                     all_teams_found = false;
                                 }
                             }
-                            if_chain! {
-                                if all_teams_found;
-                                if let Ok(teams) = teams.try_into();
-                                then {
+                            match (all_teams_found, <[_; 2]>::try_from(teams)) {
+                                (true, Ok(teams)) => {
                                     let (t, ids) = report_1v1(transaction, ctx, cal_event, event, teams).await?;
                                     transaction = t;
                                     ignored_race_ids = ids;
-                                } else { //TODO separate function for reporting 3-entrant results
+                                }
+                                (true, Err(teams)) if teams.len() == 3 => {
+                                    let teams = <[_; 3]>::try_from(teams).expect("checked length");
+                                    let (t, ids) = report_3way(transaction, ctx, cal_event, event, teams).await?;
+                                    transaction = t;
+                                    ignored_race_ids = ids;
+                                }
+                                _ => {
                                     let room = Url::parse(&format!("https://{}{}", racetime_host(), data.url)).to_racetime()?;
                                     report_ffa(ctx, cal_event, event, room).await?;
                                 }
@@ -1356,14 +1672,19 @@ impl Handler {
                                     all_teams_found = false;
                                 }
                             }
-                            if_chain! {
-                                if all_teams_found;
-                                if let Ok(teams) = teams.try_into();
-                                then {
+                            match (all_teams_found, <[_; 2]>::try_from(teams)) {
+                                (true, Ok(teams)) => {
                                     let (t, ids) = report_1v1(transaction, ctx, cal_event, event, teams).await?;
                                     transaction = t;
                                     ignored_race_ids = ids;
-                                } else { //TODO separate function for reporting 3-entrant results
+                                }
+                                (true, Err(teams)) if teams.len() == 3 => {
+                                    let teams = <[_; 3]>::try_from(teams).expect("checked length");
+                                    let (t, ids) = report_3way(transaction, ctx, cal_event, event, teams).await?;
+                                    transaction = t;
+                                    ignored_race_ids = ids;
+                                }
+                                _ => {
                                     let room = Url::parse(&format!("https://{}{}", racetime_host(), data.url)).to_racetime()?;
                                     report_ffa(ctx, cal_event, event, room).await?;
                                 }
@@ -1389,3 +1710,29 @@ impl Handler {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_forfeit_reports_winner() {
+        assert_eq!(forfeit_follow_up(Some(&1), &[2], &cal::Source::Manual, &MatchSource::Bracket), ForfeitFollowUp::ReportWinner { winner: &1, loser: &2 });
+        assert_eq!(forfeit_follow_up(Some(&1), &[2], &cal::Source::League { id: 1 }, &MatchSource::League), ForfeitFollowUp::ReportWinner { winner: &1, loser: &2 });
+    }
+
+    #[test]
+    fn single_forfeit_with_multiple_losers_needs_manual_report() {
+        assert_eq!(forfeit_follow_up(Some(&1), &[2, 3], &cal::Source::StartGG { event: String::default(), set: startgg::ID(String::default()) }, &MatchSource::StartGG("")), ForfeitFollowUp::ManualReport);
+        assert_eq!(forfeit_follow_up(Some(&1), &[2, 3], &cal::Source::Manual, &MatchSource::Manual), ForfeitFollowUp::Nothing);
+    }
+
+    #[test]
+    fn double_forfeit() {
+        assert_eq!(forfeit_follow_up(None, &[1, 2], &cal::Source::Manual, &MatchSource::Bracket), ForfeitFollowUp::UndecidedBracketGame);
+        assert_eq!(forfeit_follow_up(None, &[1, 2], &cal::Source::Manual, &MatchSource::Manual), ForfeitFollowUp::Nothing);
+        assert_eq!(forfeit_follow_up(None, &[1, 2], &cal::Source::Ics { uid: String::default() }, &MatchSource::Manual), ForfeitFollowUp::Nothing);
+        assert_eq!(forfeit_follow_up(None, &[1, 2], &cal::Source::Challonge { id: String::default() }, &MatchSource::Challonge { community: None, tournament: "" }), ForfeitFollowUp::ManualReport);
+        assert_eq!(forfeit_follow_up(None, &[1, 2], &cal::Source::League { id: 1 }, &MatchSource::League), ForfeitFollowUp::ManualReport);
+    }
+}
@@ -496,6 +496,14 @@ impl<'v> EnterFormDefaults<'v> {
             Self::Values { .. } => None,
         }
     }
+
+    /// Like `field_value`, but returns all values for fields which can have multiple values.
+    pub(crate) fn field_values(&self, name: &str) -> Vec<&str> {
+        match self {
+            Self::Context(ctx) => ctx.field_values(name).collect(),
+            Self::Values { .. } => Vec::default(),
+        }
+    }
 }
 
 #[allow(unused_qualifications)] // rocket endpoint and uri macros don't work with relative module paths
@@ -334,7 +334,7 @@ pub(crate) async fn info(transaction: &mut Transaction<'_, Postgres>, data: &Dat
                             a(href = "https://discord.gg/qrGf6yNY4C") : "The Silver Gauntlets Discord";
                         }
                         li {
-                            a(href = uri!(event::races(Series::Standard, "8"))) : "Qualifier Schedule";
+                            a(href = uri!(event::races(Series::Standard, "8", _))) : "Qualifier Schedule";
                         }
                         li {
                             a(href = "https://www.start.gg/tournament/ocarina-of-time-randomizer-standard-tournament-season-8/event/main-tournament") : "Brackets";
@@ -494,7 +494,7 @@ pub(crate) async fn info(transaction: &mut Transaction<'_, Postgres>, data: &Dat
                     }
                     p {
                         : "Dates for all Qualifiers can be found here: ";
-                        a(href = uri!(event::races(Series::Standard, "8"))) : "Season 8 Qualifier Schedule";
+                        a(href = uri!(event::races(Series::Standard, "8", _))) : "Season 8 Qualifier Schedule";
                     }
                     p {
                         img(src = static_url!("event/s/8-qualifier-schedule.png"), style = "max-width: 100%; max-height: 100vh;");
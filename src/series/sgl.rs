@@ -40,7 +40,7 @@ impl RestreamMatch {
                     false
                 }
                 Entrants::Three(entrants) => {
-                    if self.players.len() == 2 {
+                    if self.players.len() == 3 {
                         for players in self.players.iter().permutations(3) {
                             for (entrant, player) in entrants.iter().zip_eq(players) {
                                 if !player.matches(&mut *transaction, http_client, entrant).await? {
@@ -1,7 +1,7 @@
 //! Utilities for working with Google Sheets.
 
-// This module is only used for events which publish their race schedules as Google sheets.
-// Allow it to remain unused between those events rather than deleting and restoring it each time.
+// Some of the helpers in this module are only used by specific events' exports.
+// Allow them to remain unused between those events rather than deleting and restoring them each time.
 #![allow(unused)]
 
 use {
@@ -118,6 +118,43 @@ pub(crate) async fn values(http_client: reqwest::Client, sheet_id: &str, range:
     })
 }
 
+/// Something that can provide the values of a range in a sheet.
+///
+/// Implemented for [`reqwest::Client`] using the Google Sheets API, and for [`Stub`] in tests.
+pub(crate) trait ValuesSource {
+    async fn values(&self, sheet_id: &str, range: &str) -> Result<Vec<Vec<String>>, Error>;
+}
+
+impl ValuesSource for reqwest::Client {
+    async fn values(&self, sheet_id: &str, range: &str) -> Result<Vec<Vec<String>>, Error> {
+        self::values(self.clone(), sheet_id, range).await
+    }
+}
+
+/// A local stand-in for the Google Sheets API which serves fixed values.
+///
+/// Ranges that weren't added are returned as empty, like an empty range in a real sheet.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct Stub {
+    values: HashMap<(String, String), Vec<Vec<String>>>,
+}
+
+#[cfg(test)]
+impl Stub {
+    pub(crate) fn with_values(mut self, sheet_id: &str, range: &str, values: &[&[&str]]) -> Self {
+        self.values.insert((sheet_id.to_owned(), range.to_owned()), values.iter().map(|row| row.iter().map(|&cell| cell.to_owned()).collect()).collect());
+        self
+    }
+}
+
+#[cfg(test)]
+impl ValuesSource for Stub {
+    async fn values(&self, sheet_id: &str, range: &str) -> Result<Vec<Vec<String>>, Error> {
+        Ok(self.values.get(&(sheet_id.to_owned(), range.to_owned())).cloned().unwrap_or_default())
+    }
+}
+
 // ============================================================================
 // Write Operations for ZSR Export
 // ============================================================================
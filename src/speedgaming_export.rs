@@ -42,7 +42,8 @@ pub(crate) enum Error {
     #[error("SpeedGaming returned an invalid episode ID")] InvalidEpisodeId,
     #[error("SpeedGaming may have accepted the submission: {0}")] AmbiguousSubmission(String),
     #[error("event not found")] EventNotFound,
-    #[error("SpeedGaming exports only support 1v1 races")] NotOneVsOne,
+    #[error("SpeedGaming exports only support races with a fixed list of entrants")] UnknownEntrants,
+    #[error("SpeedGaming exports only support live races")] NotLive,
     #[error("runner does not have a current Discord username")] MissingDiscordUsername,
     #[error("team does not have exactly one racing member")] InvalidTeam,
 }
@@ -319,11 +320,12 @@ async fn runner_identity(
     transaction: &mut Transaction<'_, Postgres>,
     http_client: &reqwest::Client,
     event_data: &event::Data<'_>,
+    race: Id<Races>,
     entrant: &Entrant,
 ) -> Result<RunnerIdentity, Error> {
     match entrant {
         Entrant::MidosHouseTeam(team) => {
-            let user = team.members_roles(transaction).await?.into_iter()
+            let user = team.members_roles_for_race(transaction, race).await?.into_iter()
                 .filter(|(_, role)| event_data.team_config.role_is_racing(*role))
                 .map(|(user, _)| user)
                 .exactly_one()
@@ -367,8 +369,8 @@ async fn runner_identity(
 
 struct MatchSubmission {
     slug: String,
-    runner1: RunnerIdentity,
-    runner2: RunnerIdentity,
+    /// The first runner is the one whose Discord username is submitted.
+    runners: Vec<RunnerIdentity>,
     start: DateTime<Utc>,
     note: String,
 }
@@ -410,20 +412,21 @@ async fn build_match_submission(
     export: &ExportConfig,
     event_data: &event::Data<'_>,
 ) -> Result<MatchSubmission, Error> {
-    let Entrants::Two(entrants) = &race.entrants else { return Err(Error::NotOneVsOne) };
-    let mut runner1 = runner_identity(transaction, http_client, event_data, &entrants[0]).await?;
-    let mut runner2 = runner_identity(transaction, http_client, event_data, &entrants[1]).await?;
-    if runner1.discord_username.is_none() && runner2.discord_username.is_some() {
-        mem::swap(&mut runner1, &mut runner2);
-    }
-    if runner1.discord_username.is_none() {
-        return Err(Error::MissingDiscordUsername)
+    let entrants = match &race.entrants {
+        Entrants::Two(entrants) => &entrants[..],
+        Entrants::Three(entrants) => &entrants[..],
+        Entrants::Open | Entrants::Count { .. } | Entrants::Named(_) => return Err(Error::UnknownEntrants),
+    };
+    let mut runners = Vec::with_capacity(entrants.len());
+    for entrant in entrants {
+        runners.push(runner_identity(transaction, http_client, event_data, race.id, entrant).await?);
     }
-    let RaceSchedule::Live { start, .. } = race.schedule else { return Err(Error::NotOneVsOne) };
+    let discord_idx = runners.iter().position(|runner| runner.discord_username.is_some()).ok_or(Error::MissingDiscordUsername)?;
+    runners[..=discord_idx].rotate_right(1);
+    let RaceSchedule::Live { start, .. } = race.schedule else { return Err(Error::NotLive) };
     Ok(MatchSubmission {
         slug: export.slug.clone(),
-        runner1,
-        runner2,
+        runners,
         start: start + TimeDelta::minutes(export.delay_minutes.into()),
         note: race_note(race),
     })
@@ -432,24 +435,30 @@ async fn build_match_submission(
 async fn submit_match(http_client: &reqwest::Client, submission: &MatchSubmission) -> Result<i64, Error> {
     let url = format!("{BASE_URL}/{}/submit/", submission.slug);
     let form = get_form(http_client, &url, false).await?;
-    let discord_username = submission.runner1.discord_username.as_deref().ok_or(Error::MissingDiscordUsername)?;
+    let (runner1, other_runners) = submission.runners.split_first().ok_or(Error::UnknownEntrants)?;
+    let discord_username = runner1.discord_username.as_deref().ok_or(Error::MissingDiscordUsername)?;
     let (date, time, am_pm) = speedgaming_form_time(submission.start);
-    let fields = [
-        ("csrfmiddlewaretoken", form.csrf),
-        ("eventslug", submission.slug.clone()),
-        ("person1id", "0".to_owned()),
-        ("discordtag1", discord_username.to_owned()),
-        ("displayname1", submission.runner1.display_name.clone()),
-        ("publicstream1", submission.runner1.twitch_name.clone().unwrap_or_default()),
-        ("person2id", "0".to_owned()),
-        ("displayname2", submission.runner2.display_name.clone()),
-        ("whendate", date),
-        ("whentime", time),
-        ("whenampm", am_pm),
-        ("whentimezone", String::new()),
-        ("note", submission.note.clone()),
-        ("submit", "Submit Match".to_owned()),
+    let mut fields = vec![
+        (format!("csrfmiddlewaretoken"), form.csrf),
+        (format!("eventslug"), submission.slug.clone()),
+        (format!("person1id"), "0".to_owned()),
+        (format!("discordtag1"), discord_username.to_owned()),
+        (format!("displayname1"), runner1.display_name.clone()),
+        (format!("publicstream1"), runner1.twitch_name.clone().unwrap_or_default()),
     ];
+    for (idx, runner) in other_runners.iter().enumerate() {
+        let person = idx + 2;
+        fields.push((format!("person{person}id"), "0".to_owned()));
+        fields.push((format!("displayname{person}"), runner.display_name.clone()));
+    }
+    fields.extend([
+        (format!("whendate"), date),
+        (format!("whentime"), time),
+        (format!("whenampm"), am_pm),
+        (format!("whentimezone"), String::new()),
+        (format!("note"), submission.note.clone()),
+        (format!("submit"), "Submit Match".to_owned()),
+    ]);
     let response = http_client.post(&url).header(COOKIE, form.cookie).form(&fields).send().await
         .map_err(|error| Error::AmbiguousSubmission(error.to_string()))?
         .error_for_status()
@@ -946,6 +946,20 @@ pub(crate) async fn refresh_swiss_standings(
     });
 }
 
+/// Like [`refresh_swiss_standings`], but waits for the new standings so they can be posted right away.
+pub(crate) async fn reload_swiss_standings(
+    http_client: &reqwest::Client,
+    event_slug: &str,
+    startgg_token: &str,
+) -> Result<Vec<SwissStanding>, Error> {
+    invalidate_swiss_standings_queries(event_slug).await;
+    let standings = fetch_swiss_standings(http_client, event_slug, startgg_token).await?;
+    lock!(cache = SWISS_STANDINGS_CACHE; {
+        cache.insert(event_slug.to_owned(), standings.clone());
+    });
+    Ok(standings)
+}
+
 /// Computes Swiss standings for a Startgg Swiss event. The completed standings remain cached
 /// until a successfully reported race result starts a background refresh.
 pub(crate) async fn swiss_standings(
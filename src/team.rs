@@ -41,7 +41,7 @@ impl Team {
     }
 
     pub(crate) async fn for_event(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str) -> sqlx::Result<Vec<Self>> {
-        sqlx::query_as!(Self, r#"SELECT id AS "id: Id<Teams>", series AS "series: Series", event, name, racetime_slug, startgg_id AS "startgg_id: startgg::ID", challonge_id, plural_name, restream_consent, mw_impl AS "mw_impl: mw::Impl", qualifier_rank FROM teams WHERE series = $1 AND event = $2 AND NOT resigned"#, series as _, event).fetch_all(&mut **transaction).await
+        sqlx::query_as!(Self, r#"SELECT id AS "id: Id<Teams>", series AS "series: Series", event, name, racetime_slug, startgg_id AS "startgg_id: startgg::ID", challonge_id, plural_name, restream_consent, mw_impl AS "mw_impl: mw::Impl", qualifier_rank FROM teams WHERE series = $1 AND event = $2 AND NOT resigned AND waitlisted_at IS NULL"#, series as _, event).fetch_all(&mut **transaction).await
     }
 
     pub(crate) async fn for_member(transaction: &mut Transaction<'_, Postgres>, member_id: Id<Users>) -> sqlx::Result<Vec<Self>> {
@@ -52,6 +52,18 @@ impl Team {
         sqlx::query_as!(Self, r#"SELECT id AS "id: Id<Teams>", series AS "series: Series", event, name, racetime_slug, teams.startgg_id AS "startgg_id: startgg::ID", challonge_id, plural_name, restream_consent, mw_impl AS "mw_impl: mw::Impl", qualifier_rank FROM teams, team_members WHERE series = $1 AND event = $2 AND NOT resigned AND id = team AND member = $3"#, series as _, event, member_id as _).fetch_optional(&mut **transaction).await
     }
 
+    /// Like `from_event_and_member`, but also finds the team if the user is substituting for one of its members in the given race.
+    ///
+    /// Members who have been substituted out of the race are not matched.
+    pub(crate) async fn from_race_and_member(transaction: &mut Transaction<'_, Postgres>, series: Series, event: &str, race: Id<Races>, member_id: Id<Users>) -> sqlx::Result<Option<Self>> {
+        if let Some(team) = sqlx::query_as!(Self, r#"SELECT teams.id AS "id: Id<Teams>", series AS "series: Series", event, name, racetime_slug, startgg_id AS "startgg_id: startgg::ID", challonge_id, plural_name, restream_consent, mw_impl AS "mw_impl: mw::Impl", qualifier_rank FROM teams, team_substitutions WHERE series = $1 AND event = $2 AND NOT resigned AND teams.id = team AND race = $3 AND member_in = $4 AND status = 'approved'"#, series as _, event, race as _, member_id as _).fetch_optional(&mut **transaction).await? {
+            return Ok(Some(team))
+        }
+        let Some(team) = Self::from_event_and_member(&mut *transaction, series, event, member_id).await? else { return Ok(None) };
+        let substituted_out = sqlx::query_scalar!(r#"SELECT EXISTS (SELECT 1 FROM team_substitutions WHERE team = $1 AND race = $2 AND member_out = $3 AND status = 'approved') AS "exists!""#, team.id as _, race as _, member_id as _).fetch_one(&mut **transaction).await?;
+        Ok((!substituted_out).then_some(team))
+    }
+
     pub(crate) fn dummy() -> Self {
         Self {
             id: Id::dummy(),
@@ -167,6 +179,38 @@ impl Team {
         }
         Ok(members)
     }
+
+    /// Like `member_ids_roles`, but with approved substitutions for the given race applied.
+    async fn member_ids_roles_for_race(&self, transaction: &mut Transaction<'_, Postgres>, race: Id<Races>) -> sqlx::Result<Vec<(Id<Users>, Role)>> {
+        let members = self.member_ids_roles(&mut *transaction).await?;
+        let substitutions = sqlx::query!(r#"SELECT member_out AS "member_out: Id<Users>", member_in AS "member_in: Id<Users>" FROM team_substitutions WHERE team = $1 AND race = $2 AND status = 'approved'"#, self.id as _, race as _).fetch_all(&mut **transaction).await?
+            .into_iter()
+            .map(|row| (row.member_out, row.member_in))
+            .collect_vec();
+        Ok(apply_substitutions(members, &substitutions))
+    }
+
+    /// The members playing for this team in the given race, taking substitutions into account.
+    pub(crate) async fn members_for_race(&self, transaction: &mut Transaction<'_, Postgres>, race: Id<Races>) -> sqlx::Result<Vec<User>> {
+        Ok(self.members_roles_for_race(transaction, race).await?.into_iter().map(|(member, _)| member).collect())
+    }
+
+    /// The members playing for this team in the given race along with the roles they're filling, taking substitutions into account.
+    pub(crate) async fn members_roles_for_race(&self, transaction: &mut Transaction<'_, Postgres>, race: Id<Races>) -> sqlx::Result<Vec<(User, Role)>> {
+        let rows = self.member_ids_roles_for_race(&mut *transaction, race).await?;
+        let mut members = Vec::with_capacity(rows.len());
+        for (user_id, role) in rows {
+            members.push((User::from_id(&mut **transaction, user_id).await?.expect("database constraint violated: nonexistent team member"), role));
+        }
+        Ok(members)
+    }
+}
+
+/// Replaces team members according to substitutions, given as `(member_out, member_in)`. Substitutes take over the role of the member they replace.
+fn apply_substitutions(members: Vec<(Id<Users>, Role)>, substitutions: &[(Id<Users>, Id<Users>)]) -> Vec<(Id<Users>, Role)> {
+    members.into_iter()
+        .map(|(member, role)| (substitutions.iter().find(|&&(member_out, _)| member_out == member).map_or(member, |&(_, member_in)| member_in), role))
+        .collect()
 }
 
 impl PartialEq for Team {
@@ -188,3 +232,19 @@ impl Ord for Team {
         self.id.cmp(&other.id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitutes_take_over_roles() {
+        let [alice, bob, carol, dave] = [1_u64, 2, 3, 4].map(Id::<Users>::from);
+        let members = vec![(alice, Role::Sheikah), (bob, Role::Gerudo)];
+        assert_eq!(apply_substitutions(members.clone(), &[]), members);
+        assert_eq!(apply_substitutions(members.clone(), &[(bob, carol)]), [(alice, Role::Sheikah), (carol, Role::Gerudo)]);
+        assert_eq!(apply_substitutions(members.clone(), &[(alice, dave), (bob, carol)]), [(dave, Role::Sheikah), (carol, Role::Gerudo)]);
+        // substitutions for someone who's no longer on the team don't apply
+        assert_eq!(apply_substitutions(members.clone(), &[(carol, dave)]), members);
+    }
+}